@@ -16,6 +16,8 @@ lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
 }
 
+#[cfg(feature = "relayer")]
+pub mod relayer;
 pub mod run;
 pub mod snapshot;
 
@@ -36,6 +38,8 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    #[cfg(feature = "relayer")]
+    Relayer(relayer::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -100,6 +104,8 @@ pub async fn run_cli() -> anyhow::Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            #[cfg(feature = "relayer")]
+            Fuel::Relayer(command) => relayer::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
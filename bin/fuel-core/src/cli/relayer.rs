@@ -0,0 +1,204 @@
+use crate::cli::{
+    run::{
+        relayer::RelayerArgs,
+        CONSENSUS_KEY_ENV,
+    },
+    DEFAULT_DB_PATH,
+};
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::{
+    database::relayer::KnownRoot,
+    types::{
+        blockchain::primitives::DaBlockHeight,
+        fuel_crypto::Signature,
+        fuel_types::{
+            Bytes32,
+            Bytes64,
+        },
+    },
+};
+use std::{
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Export or import the state of the relayer to provision nodes without downloading
+/// the logs of the DA layer again.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the relayer operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Prints the messages ingested by the relayer and its finalized DA height as JSON.
+    Export {
+        #[clap(flatten)]
+        relayer_args: RelayerArgs,
+    },
+    /// Prints the root of messages ingested by the relayer up to the DA height, signed
+    /// by the consensus key of the authority, in the format of the `--known-root` of
+    /// the import.
+    #[command(arg_required_else_help = true)]
+    SignRoot {
+        /// The DA height of the root.
+        #[clap(long = "da-height")]
+        da_height: u64,
+
+        /// The consensus key of the authority of the chain.
+        #[clap(long = "consensus-key", env = CONSENSUS_KEY_ENV)]
+        consensus_key: String,
+    },
+    /// Imports the exported state of the relayer into the fresh database.
+    #[command(arg_required_else_help = true)]
+    Import {
+        /// The path to the JSON file with the exported state.
+        #[clap(long = "input")]
+        input: PathBuf,
+
+        /// The merkle root of ids of messages up to the DA height, signed by an authority
+        /// of the chain with the `sign-root` command, in the format
+        /// `<da height>:<root>:<signature>`. The import fails if the state doesn't match
+        /// any of the roots, or if no root is at the finalized DA height of the state.
+        #[clap(long = "known-root", value_parser = parse_known_root, required = true)]
+        known_roots: Vec<KnownRoot>,
+
+        /// The alias of a built-in chain configuration or the path to a JSON file with
+        /// it. Known roots must be signed by its authorities.
+        #[arg(long = "chain", default_value = "local_testnet", env)]
+        chain_config: String,
+
+        #[clap(flatten)]
+        relayer_args: RelayerArgs,
+    },
+}
+
+fn parse_known_root(input: &str) -> anyhow::Result<KnownRoot> {
+    let mut parts = input.split(':');
+    let (da_height, root, signature) =
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(da_height), Some(root), Some(signature), None) => {
+                (da_height, root, signature)
+            }
+            _ => {
+                return Err(anyhow::anyhow!("Expected `<da height>:<root>:<signature>`"))
+            }
+        };
+    let signature = Bytes64::from_str(signature)
+        .map_err(|e| anyhow::anyhow!("Invalid signature `{signature}`: {e}"))?;
+    Ok(KnownRoot {
+        da_height: DaBlockHeight(da_height.parse()?),
+        root: Bytes32::from_str(root)
+            .map_err(|e| anyhow::anyhow!("Invalid root `{root}`: {e}"))?,
+        signature: Signature::from_bytes(*signature),
+    })
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::init_logging;
+    use anyhow::Context;
+    use fuel_core::{
+        chain_config::ChainConfig,
+        database::{
+            relayer::RelayerState,
+            Database,
+        },
+        types::fuel_crypto::SecretKey,
+    };
+    init_logging().await?;
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open(&path, None).context(
+            format!("failed to open database at path {}", path.display()),
+        )?;
+    let mut db = Database::new(std::sync::Arc::new(data_source));
+
+    match command.subcommand {
+        SubCommands::Export { relayer_args } => {
+            let state = db.export_relayer_state(&relayer_args.into())?;
+            let stdout = std::io::stdout().lock();
+
+            serde_json::to_writer(stdout, &state)
+                .context("failed to dump relayer state to JSON")?;
+        }
+        SubCommands::SignRoot {
+            da_height,
+            consensus_key,
+        } => {
+            let signing_key = SecretKey::from_str(&consensus_key)
+                .context("failed to parse consensus signing key")?;
+            let known = db.sign_relayer_root(DaBlockHeight(da_height), &signing_key)?;
+            let signature = Bytes64::from(<[u8; 64]>::from(known.signature));
+            println!("{}:{}:{}", known.da_height.0, known.root, signature);
+        }
+        SubCommands::Import {
+            input,
+            known_roots,
+            chain_config,
+            relayer_args,
+        } => {
+            let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+            let file = std::fs::File::open(&input)
+                .context(format!("failed to open {}", input.display()))?;
+            let state: RelayerState =
+                serde_json::from_reader(std::io::BufReader::new(file))
+                    .context("failed to parse relayer state from JSON")?;
+
+            db.import_relayer_state(
+                &state,
+                &relayer_args.into(),
+                &known_roots,
+                &chain_conf.consensus.authorities(),
+            )?;
+            tracing::info!(
+                "Imported {} messages of the relayer up to the DA height {}",
+                state.messages.len(),
+                state.finalized_da_height
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_root_is_parsed() {
+        let root = Bytes32::from([1; 32]);
+        let signature = Bytes64::from([2; 64]);
+        let known = parse_known_root(&format!("10:{root}:{signature}")).unwrap();
+        assert_eq!(known.da_height, DaBlockHeight(10));
+        assert_eq!(known.root, root);
+        assert_eq!(known.signature, Signature::from_bytes([2; 64]));
+
+        assert!(parse_known_root("10").is_err());
+        assert!(parse_known_root(&format!("10:{root}")).is_err());
+        assert!(parse_known_root(&format!("ten:{root}:{signature}")).is_err());
+        assert!(parse_known_root(&format!("10:{root}:{signature}:{root}")).is_err());
+    }
+}
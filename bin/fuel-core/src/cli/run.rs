@@ -2,7 +2,11 @@
 use crate::{
     cli::{
         init_logging,
-        run::consensus::PoATriggerArgs,
+        run::consensus::{
+            LateBlockPolicyArg,
+            PoATriggerArgs,
+            TxOrderingArg,
+        },
         DEFAULT_DB_PATH,
     },
     FuelService,
@@ -17,9 +21,19 @@ use fuel_core::{
         default_consensus_dev_key,
         ChainConfig,
     },
-    producer::Config as ProducerConfig,
+    fuel_core_graphql_api::AdminApiToken,
+    producer::{
+        tx_selector::PriorityLane,
+        Config as ProducerConfig,
+    },
+    schema::cursor::CursorSecret,
     service::{
-        config::Trigger,
+        config::{
+            GasLimitAdjustment,
+            Standby,
+            Trigger,
+        },
+        BlockExecutorConfig,
         Config,
         DbType,
         RelayerVerifierConfig,
@@ -30,6 +44,7 @@ use fuel_core::{
     types::{
         blockchain::primitives::SecretKeyWrapper,
         fuel_tx::Address,
+        fuel_types::BlockHeight,
         fuel_vm::SecretKey,
         secrecy::{
             ExposeSecret,
@@ -53,13 +68,32 @@ use tracing::{
 pub const CONSENSUS_KEY_ENV: &str = "CONSENSUS_KEY_SECRET";
 // Default database cache is 1 GB
 const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
+// Builds without RocksDB run the node with the in-memory database
+#[cfg(feature = "rocksdb")]
+const DEFAULT_DB_TYPE: &str = "rocks-db";
+#[cfg(not(feature = "rocksdb"))]
+const DEFAULT_DB_TYPE: &str = "in-memory";
 
 #[cfg(feature = "p2p")]
 mod p2p;
 
+#[cfg(feature = "alerting")]
+mod alerting;
+#[cfg(unix)]
+mod change_capture;
 mod consensus;
+#[cfg(feature = "fork")]
+mod fork;
+mod inclusion_monitor;
+mod prewarm;
 #[cfg(feature = "relayer")]
-mod relayer;
+pub(crate) mod relayer;
+#[cfg(feature = "remote-signer")]
+mod remote_signer;
+mod restart;
+mod slow_log;
+#[cfg(feature = "version-check")]
+mod version_check;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -70,6 +104,12 @@ pub struct Command {
     #[clap(long = "port", default_value = "4000", env)]
     pub port: u16,
 
+    /// Additional addresses of the GraphQL API, e.g. `[::1]:4000` for the dual-stack
+    /// node. Additional IPv6 listeners accept only IPv6 connections, the listener of
+    /// the `--ip` keeps the default of the platform
+    #[arg(long = "additional-addrs", value_delimiter = ',', env)]
+    pub additional_addrs: Vec<net::SocketAddr>,
+
     /// Vanity name for node, used in telemetry
     #[clap(long = "service-name", default_value = "fuel-core", value_parser, env)]
     pub service_name: String,
@@ -93,7 +133,7 @@ pub struct Command {
 
     #[clap(
         long = "db-type",
-        default_value = "rocks-db",
+        default_value = DEFAULT_DB_TYPE,
         value_enum,
         ignore_case = true,
         env
@@ -109,10 +149,75 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// Wipes the database if it belongs to another chain than the `--chain` config. By
+    /// default, the node refuses to start with the database of another chain.
+    #[arg(long = "force-chain-switch", env)]
+    pub force_chain_switch: bool,
+
     /// Allows GraphQL Endpoints to arbitrarily advanced blocks. Should be used for local development only
     #[arg(long = "manual_blocks_enabled", env)]
     pub manual_blocks_enabled: bool,
 
+    /// Enables debug features of the GraphQL API, like overriding the utxo validation
+    /// per submitted transaction or the timeline of transactions. Should be used for
+    /// local development only
+    #[arg(long = "debug", env)]
+    pub debug: bool,
+
+    /// The hex-encoded 32 bytes secret used to authenticate the pagination cursors of
+    /// the GraphQL API. Nodes serving the same clients should share it, so cursors stay
+    /// valid across nodes and restarts.
+    #[arg(long = "graphql-cursor-secret", value_parser = CursorSecret::from_str, env)]
+    pub cursor_secret: Option<CursorSecret>,
+
+    /// Disables the deprecated fields of the GraphQL API before their sunset. Useful to
+    /// check that clients of the node don't depend on them anymore.
+    #[arg(long = "disable-deprecated-graphql-fields", env)]
+    pub disable_deprecated_graphql_fields: bool,
+
+    /// How long the node remembers idempotency keys of submitted transactions. Retried
+    /// submissions with the same key return the original transaction.
+    #[arg(long = "graphql-idempotency-key-ttl", default_value = "10m", env)]
+    pub graphql_idempotency_key_ttl: humantime::Duration,
+
+    /// Enables the admin API at `/v1/services` to list, inspect, stop, pause, resume and
+    /// restart sub-services of the node, at `/v1/config` to update the minimal gas
+    /// price, the limit of peers and the block time without the restart, and at
+    /// `/v1/poa` to switch the trigger, pause and resume the block production. Requires
+    /// the `--admin-api-token`
+    #[arg(long = "admin-api", requires = "admin_api_token", env)]
+    pub admin_api: bool,
+
+    /// The token required in the `Authorization: Bearer <token>` header of requests to
+    /// the admin API.
+    #[arg(
+        long = "admin-api-token",
+        requires = "admin_api",
+        value_parser = AdminApiToken::from_str,
+        env
+    )]
+    pub admin_api_token: Option<AdminApiToken>,
+
+    /// The directory with GraphQL documents, one document per `*.graphql` file. If set,
+    /// the GraphQL API executes only these documents and rejects all other requests.
+    /// The admin API reloads documents at `/v1/graphql/allowlist`. Documents used by
+    /// `fuel-core-client` are dumped by `cargo xtask dump-client-queries`
+    #[arg(long = "graphql-allowlist-dir", env)]
+    pub graphql_allowlist_dir: Option<PathBuf>,
+
+    /// The number of worker threads of the dedicated runtime for the execution of blocks
+    /// during the block production and the synchronization. Isolates the CPU-heavy
+    /// execution from the P2P and the GraphQL API. Uses the shared runtime if not set
+    #[arg(long = "execution-threads", env)]
+    pub execution_threads: Option<usize>,
+
+    /// The number of threads executing independent transactions of the produced block
+    /// concurrently. Transactions touching the same UTXOs, contracts or messages are
+    /// executed by the same thread in their order. Signatures and predicates of
+    /// validated blocks are verified on the same number of threads
+    #[arg(long = "parallel-execution-threads", default_value = "1", env)]
+    pub parallel_execution_threads: usize,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
@@ -135,6 +240,105 @@ pub struct Command {
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
 
+    /// Emits signed pre-confirmations of transactions included into the produced block
+    /// once the block is sealed, before it is committed.
+    #[arg(long = "pre-confirmations", env)]
+    pub pre_confirmations: bool,
+
+    /// The time budget of the execution of the produced block. The block that exceeds it
+    /// is produced again with fewer transactions, or empty with the interval trigger
+    #[arg(long = "poa-execution-budget", env)]
+    pub poa_execution_budget: Option<humantime::Duration>,
+
+    /// Adjusts the gas limit of produced blocks to the gas used by their transactions,
+    /// between the minimal and the maximal gas limit. Fuller blocks than the target
+    /// utilization increase it. The minimal gas limit must fit the largest transaction
+    #[arg(long = "poa-min-gas-limit", requires = "poa_max_gas_limit", env)]
+    pub poa_min_gas_limit: Option<u64>,
+
+    /// The maximal gas limit of produced blocks, see `--poa-min-gas-limit`
+    #[arg(long = "poa-max-gas-limit", requires = "poa_min_gas_limit", env)]
+    pub poa_max_gas_limit: Option<u64>,
+
+    /// The percentage of the gas limit produced blocks should use, see
+    /// `--poa-min-gas-limit`
+    #[arg(long = "poa-target-utilization", default_value = "50", env)]
+    pub poa_target_utilization: u8,
+
+    /// Reserves the percentage of the gas limit of produced blocks for transactions
+    /// paying at least `--priority-lane-min-gas-price`, so large batches of cheap
+    /// transactions can't crowd out urgent ones
+    #[arg(
+        long = "priority-lane-reserved-gas",
+        requires = "priority_lane_min_gas_price",
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        env
+    )]
+    pub priority_lane_reserved_gas: Option<u8>,
+
+    /// The minimal gas price of priority transactions, see `--priority-lane-reserved-gas`
+    #[arg(
+        long = "priority-lane-min-gas-price",
+        requires = "priority_lane_reserved_gas",
+        env
+    )]
+    pub priority_lane_min_gas_price: Option<u64>,
+
+    /// The order of transactions of produced blocks. `fifo` includes them in the order
+    /// of arrival, `fee-priority` includes the best paying first, and
+    /// `sender-round-robin` lets senders include one transaction in turns
+    #[arg(
+        long = "tx-ordering",
+        default_value = "fee-priority",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub tx_ordering: TxOrderingArg,
+
+    /// The limit of the size of produced blocks in bytes, including the header and the
+    /// coinbase. Keep it below the `--max_block_size` of the p2p network, so peers
+    /// accept produced blocks
+    #[arg(long = "max-produced-block-size", env)]
+    pub max_produced_block_size: Option<usize>,
+
+    /// Verifies produced blocks with the checks of validators, and also their roots, the
+    /// number of transactions and the gas, before they are sealed. Invalid blocks are
+    /// refused instead of being rejected by validators
+    #[arg(long = "verify-produced-blocks", env)]
+    pub verify_produced_blocks: bool,
+
+    /// Executes the next block while the produced block is imported. The result is
+    /// discarded if the next block turns out to be different, e.g. with other
+    /// transactions or time
+    #[arg(long = "speculative-execution", env)]
+    pub speculative_execution: bool,
+
+    /// Runs the node as the hot standby of the primary producer with the same consensus
+    /// key. The standby produces blocks only after the primary misses the number of
+    /// intervals of the trigger, and steps down once the primary is back
+    #[arg(long = "poa-standby-missed-intervals", env)]
+    pub poa_standby_missed_intervals: Option<u32>,
+
+    /// The number of connected peers the standby requires to take over and to produce
+    /// blocks. The standby cut off from the network can't tell the silence of the
+    /// primary from the partition
+    #[arg(long = "poa-standby-min-peers", default_value = "1", env)]
+    pub poa_standby_min_peers: usize,
+
+    /// How the interval trigger handles the block produced after the next slot already
+    /// started. `drift` produces it with the current time, `skip` waits for the next
+    /// slot, `backdate` produces it with the time of its slot, and `catch-up` produces
+    /// blocks of all missed slots with their times
+    #[arg(
+        long = "poa-late-block-policy",
+        default_value = "drift",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub poa_late_block_policy: LateBlockPolicyArg,
+
     /// Use a default insecure consensus key for testing purposes.
     /// This will not be enabled by default in the future.
     #[arg(long = "dev-keys", default_value = "true", env)]
@@ -142,10 +346,22 @@ pub struct Command {
 
     /// The block's fee recipient public key.
     ///
-    /// If not set, `consensus_key` is used as the provider of the `Address`.
+    /// If not set, the remote signer or `consensus_key` is used as the provider of the
+    /// `Address`.
     #[arg(long = "coinbase-recipient", env)]
     pub coinbase_recipient: Option<String>,
 
+    /// Replaces the fee recipient from the block height, in the `<HEIGHT>:<ADDRESS>`
+    /// format. Separate fee collection from the consensus key or rotate the recipient
+    /// without the restart at the scheduled height
+    #[arg(
+        long = "coinbase-rotation",
+        value_parser = parse_coinbase_rotation,
+        value_delimiter = ',',
+        env
+    )]
+    pub coinbase_rotations: Vec<(BlockHeight, Address)>,
+
     #[cfg(feature = "relayer")]
     #[clap(flatten)]
     pub relayer_args: relayer::RelayerArgs,
@@ -158,6 +374,29 @@ pub struct Command {
     #[cfg(feature = "p2p")]
     pub sync_args: p2p::SyncArgs,
 
+    #[cfg_attr(feature = "p2p", clap(flatten))]
+    #[cfg(feature = "p2p")]
+    pub pool_divergence_args: p2p::PoolDivergenceArgs,
+
+    #[cfg(feature = "fork")]
+    #[clap(flatten)]
+    pub fork_args: fork::ForkArgs,
+
+    #[cfg(feature = "alerting")]
+    #[clap(flatten)]
+    pub alerting_args: alerting::AlertingArgs,
+
+    #[cfg(feature = "remote-signer")]
+    #[clap(flatten)]
+    pub remote_signer_args: remote_signer::RemoteSignerArgs,
+
+    #[cfg(unix)]
+    #[clap(flatten)]
+    pub change_capture_args: change_capture::ChangeCaptureArgs,
+
+    #[clap(flatten)]
+    pub restart_args: restart::RestartArgs,
+
     #[arg(long = "metrics", env)]
     pub metrics: bool,
 
@@ -168,6 +407,39 @@ pub struct Command {
 
     #[clap(long = "tx-pool-ttl", default_value = "5m", env)]
     pub tx_pool_ttl: humantime::Duration,
+
+    /// The number of threads verifying predicates and signatures of transactions
+    /// inserted into the transaction pool
+    #[clap(long = "tx-pool-verification-threads", default_value = "1", env)]
+    pub tx_pool_verification_threads: usize,
+
+    /// Periodically compute statistics of the UTXO set with the given interval. The
+    /// statistics are served by the GraphQL API and as CSV at `/utxo-stats.csv`.
+    #[arg(long = "utxo-stats-interval", env)]
+    pub utxo_stats_interval: Option<humantime::Duration>,
+
+    /// Keep bodies of the latest N blocks, at least one. Transactions and receipts of
+    /// older blocks are pruned, while their sealed headers are kept. The retention is
+    /// advertised to peers, so they don't request pruned blocks.
+    #[arg(
+        long = "block-retention",
+        value_parser = clap::value_parser!(u32).range(1..),
+        env
+    )]
+    pub block_retention: Option<u32>,
+
+    #[clap(flatten)]
+    pub prewarm_args: prewarm::PrewarmArgs,
+
+    #[clap(flatten)]
+    pub inclusion_monitor_args: inclusion_monitor::InclusionMonitorArgs,
+
+    #[clap(flatten)]
+    pub slow_log_args: slow_log::SlowLogArgs,
+
+    #[cfg(feature = "version-check")]
+    #[clap(flatten)]
+    pub version_check_args: version_check::VersionCheckArgs,
 }
 
 impl Command {
@@ -175,39 +447,89 @@ impl Command {
         let Command {
             ip,
             port,
+            additional_addrs,
             service_name: name,
             max_database_cache_size,
             database_path,
             database_type,
             chain_config,
+            force_chain_switch,
             vm_backtrace,
             manual_blocks_enabled,
+            debug,
+            cursor_secret,
+            disable_deprecated_graphql_fields,
+            graphql_idempotency_key_ttl,
+            admin_api,
+            admin_api_token,
+            graphql_allowlist_dir,
+            execution_threads,
+            parallel_execution_threads,
             utxo_validation,
             min_gas_price,
             consensus_key,
             poa_trigger,
+            pre_confirmations,
+            poa_execution_budget,
+            poa_min_gas_limit,
+            poa_max_gas_limit,
+            poa_target_utilization,
+            priority_lane_reserved_gas,
+            priority_lane_min_gas_price,
+            tx_ordering,
+            max_produced_block_size,
+            verify_produced_blocks,
+            speculative_execution,
+            poa_standby_missed_intervals,
+            poa_standby_min_peers,
+            poa_late_block_policy,
             consensus_dev_key,
             coinbase_recipient,
+            coinbase_rotations,
             #[cfg(feature = "relayer")]
             relayer_args,
             #[cfg(feature = "p2p")]
             p2p_args,
             #[cfg(feature = "p2p")]
             sync_args,
+            #[cfg(feature = "p2p")]
+            pool_divergence_args,
+            #[cfg(feature = "fork")]
+            fork_args,
+            #[cfg(feature = "alerting")]
+            alerting_args,
+            #[cfg(feature = "remote-signer")]
+            remote_signer_args,
+            #[cfg(unix)]
+            change_capture_args,
+            restart_args,
             metrics,
             max_da_lag,
             max_wait_time,
             tx_pool_ttl,
+            tx_pool_verification_threads,
+            utxo_stats_interval,
+            block_retention,
+            prewarm_args,
+            inclusion_monitor_args,
+            slow_log_args,
+            #[cfg(feature = "version-check")]
+            version_check_args,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
 
+        let watchdog = restart_args.watchdog_deadline.map(Into::into);
+        let service_stall_threshold = restart_args.stall_threshold.map(Into::into);
+        #[cfg(feature = "relayer")]
+        let restart_policy: fuel_core::services::RestartPolicy = restart_args.into();
+
         #[cfg(feature = "p2p")]
-        let p2p_cfg = p2p_args.into_config(metrics)?;
+        let p2p_cfg = p2p_args.into_config(metrics, watchdog)?;
 
-        let trigger: Trigger = poa_trigger.into();
+        let trigger: Trigger = poa_trigger.into_trigger(chain_conf.block_production);
 
         if trigger != Trigger::Never {
             info!("Block production mode: {:?}", &trigger);
@@ -215,9 +537,21 @@ impl Command {
             info!("Block production disabled");
         }
 
+        #[cfg(feature = "remote-signer")]
+        let remote_cosigners = remote_signer_args.cosigners_config()?;
+        #[cfg(feature = "remote-signer")]
+        let remote_signer = remote_signer_args.into_config()?;
+        #[cfg(feature = "remote-signer")]
+        let remote_signer_address = remote_signer.as_ref().map(|config| config.address);
+        #[cfg(not(feature = "remote-signer"))]
+        let remote_signer_address: Option<Address> = None;
+
         // if consensus key is not configured, fallback to dev consensus key
         let consensus_key = load_consensus_key(consensus_key)?.or_else(|| {
-            if consensus_dev_key && trigger != Trigger::Never {
+            if consensus_dev_key
+                && trigger != Trigger::Never
+                && remote_signer_address.is_none()
+            {
                 let key = default_consensus_dev_key();
                 warn!(
                     "Fuel Core is using an insecure test key for consensus. Public key: {}",
@@ -236,6 +570,8 @@ impl Command {
 
         let coinbase_recipient = if let Some(coinbase_recipient) = coinbase_recipient {
             Address::from_str(coinbase_recipient.as_str()).map_err(|err| anyhow!(err))?
+        } else if let Some(address) = remote_signer_address {
+            address
         } else {
             consensus_key
                 .as_ref()
@@ -247,6 +583,15 @@ impl Command {
                 .unwrap_or_default()
         };
 
+        let block_gas_limit_adjustment = match (poa_min_gas_limit, poa_max_gas_limit) {
+            (Some(min_gas_limit), Some(max_gas_limit)) => Some(GasLimitAdjustment {
+                min_gas_limit,
+                max_gas_limit,
+                target_utilization: poa_target_utilization,
+            }),
+            _ => None,
+        };
+
         let verifier = RelayerVerifierConfig {
             max_da_lag: max_da_lag.into(),
             max_wait_time: max_wait_time.into(),
@@ -254,37 +599,105 @@ impl Command {
 
         Ok(Config {
             addr,
+            additional_addrs,
             max_database_cache_size,
             database_path,
             database_type,
             chain_conf: chain_conf.clone(),
+            force_chain_switch,
             utxo_validation,
             manual_blocks_enabled,
+            debug,
+            cursor_secret: cursor_secret.unwrap_or_default(),
+            disable_deprecated_graphql_fields,
+            graphql_idempotency_key_ttl: graphql_idempotency_key_ttl.into(),
+            admin_api,
+            admin_api_token,
+            graphql_allowlist_dir,
+            execution_threads,
+            service_stall_threshold,
             block_production: trigger,
+            pre_confirmations,
+            block_execution_budget: poa_execution_budget.map(Into::into),
+            block_gas_limit_adjustment,
+            verify_produced_blocks,
+            speculative_execution,
+            block_production_standby: poa_standby_missed_intervals.map(
+                |missed_intervals| Standby {
+                    missed_intervals,
+                    min_peers: poa_standby_min_peers,
+                },
+            ),
+            late_block_policy: poa_late_block_policy.into(),
             vm: VMConfig {
                 backtrace: vm_backtrace,
             },
-            txpool: TxPoolConfig::new(
-                chain_conf,
-                min_gas_price,
-                utxo_validation,
-                metrics,
-                tx_pool_ttl.into(),
-            ),
+            txpool: TxPoolConfig {
+                tx_timeline: debug,
+                verification_threads: tx_pool_verification_threads,
+                ..TxPoolConfig::new(
+                    chain_conf,
+                    min_gas_price,
+                    utxo_validation,
+                    metrics,
+                    tx_pool_ttl.into(),
+                )
+            },
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
+                coinbase_rotations: coinbase_rotations.into_iter().collect(),
                 metrics,
+                priority_lane: priority_lane_reserved_gas
+                    .zip(priority_lane_min_gas_price)
+                    .map(|(reserved_gas_percentage, min_gas_price)| PriorityLane {
+                        reserved_gas_percentage,
+                        min_gas_price,
+                    }),
+                tx_ordering: tx_ordering.into(),
+                max_block_size: max_produced_block_size,
+            },
+            block_executor: BlockExecutorConfig {
+                parallel_threads: parallel_execution_threads,
+                ..Default::default()
             },
-            block_executor: Default::default(),
             block_importer: Default::default(),
             #[cfg(feature = "relayer")]
-            relayer: relayer_args.into(),
+            relayer: fuel_core::relayer::Config {
+                metrics,
+                restart_policy,
+                watchdog,
+                ..relayer_args.into()
+            },
             #[cfg(feature = "p2p")]
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
+            #[cfg(feature = "p2p")]
+            pool_divergence: pool_divergence_args.into(),
+            utxo_stats: utxo_stats_interval.map(|interval| {
+                fuel_core::service::utxo_stats::Config {
+                    interval: interval.into(),
+                }
+            }),
+            block_pruning: block_retention
+                .map(|retention| fuel_core::service::block_pruning::Config { retention }),
+            prewarm: prewarm_args.into(),
+            inclusion_monitor: inclusion_monitor_args.into(),
+            slow_log: slow_log_args.into(),
+            #[cfg(feature = "alerting")]
+            alerting: alerting_args.into(),
+            #[cfg(feature = "version-check")]
+            version_check: version_check_args.into(),
+            #[cfg(feature = "fork")]
+            fork: fork_args.into(),
+            #[cfg(unix)]
+            change_capture: change_capture_args.into(),
             consensus_key,
+            #[cfg(feature = "remote-signer")]
+            remote_signer,
+            #[cfg(feature = "remote-signer")]
+            remote_cosigners,
             name,
             verifier,
         })
@@ -324,6 +737,15 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn parse_coinbase_rotation(input: &str) -> anyhow::Result<(BlockHeight, Address)> {
+    let (height, recipient) = input
+        .split_once(':')
+        .ok_or_else(|| anyhow!("expected `<HEIGHT>:<ADDRESS>`, got `{input}`"))?;
+    let height: u32 = height.parse().context("failed to parse the height")?;
+    let recipient = Address::from_str(recipient).map_err(|err| anyhow!(err))?;
+    Ok((height.into(), recipient))
+}
+
 // Attempt to load the consensus key from cli arg first, otherwise check the env.
 fn load_consensus_key(
     cli_arg: Option<String>,
@@ -0,0 +1,58 @@
+use clap::Args;
+use fuel_core::service::alerting::Config;
+
+#[derive(Debug, Clone, Args)]
+pub struct AlertingArgs {
+    /// URLs of webhooks that receive alerts about critical conditions of the node
+    /// as a JSON `POST` request. Alerting is enabled if any webhook or the PagerDuty key is set.
+    #[arg(long = "alert-webhook", value_delimiter = ',', env)]
+    pub alert_webhooks: Vec<String>,
+
+    /// The routing key of the PagerDuty Events API v2 integration.
+    #[arg(long = "alert-pagerduty-key", env)]
+    pub alert_pagerduty_key: Option<String>,
+
+    /// How often to check alerting conditions.
+    #[arg(long = "alert-interval", default_value = "10s", env)]
+    pub alert_interval: humantime::Duration,
+
+    /// Alerts if the block height doesn't change for this duration.
+    #[arg(long = "alert-block-stall", env)]
+    pub alert_block_stall: Option<humantime::Duration>,
+
+    /// Alerts if the relayer is behind the DA layer by more than this number of blocks.
+    #[arg(long = "alert-relayer-lag", env)]
+    pub alert_relayer_lag: Option<u64>,
+
+    /// Alerts if the free space on the disk of the database is less than this number of bytes.
+    #[arg(long = "alert-min-free-disk", env)]
+    pub alert_min_free_disk: Option<u64>,
+
+    /// Alerts if the number of connected peers is less than this number.
+    #[arg(long = "alert-min-peers", env)]
+    pub alert_min_peers: Option<usize>,
+
+    /// Alerts if the balance of the L1 wallet monitored by the relayer is less than
+    /// this number of gwei.
+    #[arg(long = "alert-min-wallet-balance", env)]
+    pub alert_min_wallet_balance: Option<u64>,
+}
+
+impl From<AlertingArgs> for Option<Config> {
+    fn from(args: AlertingArgs) -> Self {
+        if args.alert_webhooks.is_empty() && args.alert_pagerduty_key.is_none() {
+            return None
+        }
+
+        Some(Config {
+            interval: args.alert_interval.into(),
+            webhooks: args.alert_webhooks,
+            pagerduty_routing_key: args.alert_pagerduty_key,
+            block_production_stall: args.alert_block_stall.map(Into::into),
+            max_relayer_lag: args.alert_relayer_lag,
+            min_free_disk_space: args.alert_min_free_disk,
+            min_peers: args.alert_min_peers,
+            min_wallet_balance: args.alert_min_wallet_balance,
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use clap::Args;
+use fuel_core::service::change_capture::Config;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub struct ChangeCaptureArgs {
+    /// The path of the unix socket that streams changes of the database to external
+    /// services, like indexers, as JSON lines. Disabled if not set.
+    #[arg(long = "change-capture-socket", env)]
+    pub change_capture_socket: Option<PathBuf>,
+
+    /// The number of latest blocks whose changes are retained in memory, so subscribers
+    /// can replay them after reconnecting.
+    #[arg(long = "change-capture-retained-blocks", default_value = "1000", env)]
+    pub change_capture_retained_blocks: u32,
+}
+
+impl From<ChangeCaptureArgs> for Option<Config> {
+    fn from(args: ChangeCaptureArgs) -> Self {
+        args.change_capture_socket.map(|socket_path| Config {
+            socket_path,
+            retained_blocks: args.change_capture_retained_blocks,
+        })
+    }
+}
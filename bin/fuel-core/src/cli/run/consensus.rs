@@ -4,7 +4,14 @@ use clap::{
     ArgGroup,
     ValueEnum,
 };
-use fuel_core::service::config::Trigger as PoATrigger;
+use fuel_core::{
+    chain_config::BlockProduction,
+    producer::tx_selector::TxOrdering,
+    service::config::{
+        LateBlockPolicy,
+        Trigger as PoATrigger,
+    },
+};
 use humantime::Duration;
 
 #[derive(Debug, Clone, clap::Args)]
@@ -15,6 +22,26 @@ pub struct PoATriggerArgs {
     hybrid: Hybrid,
     #[clap(flatten)]
     interval: Interval,
+    #[clap(flatten)]
+    adaptive: Adaptive,
+}
+
+impl PoATriggerArgs {
+    /// Returns the trigger set by the arguments. If no poa flags are set, the trigger
+    /// follows the `block_production` of the chain config, or defaults to the instant trigger.
+    pub fn into_trigger(self, chain_block_production: Option<BlockProduction>) -> PoATrigger {
+        match chain_block_production {
+            Some(block_production) if !self.is_set() => block_production.into(),
+            _ => self.into(),
+        }
+    }
+
+    fn is_set(&self) -> bool {
+        self.instant.instant.is_some()
+            || self.interval.period.is_some()
+            || self.hybrid.min_time.is_some()
+            || self.adaptive.adaptive_min_time.is_some()
+    }
 }
 
 // Convert from arg struct to PoATrigger enum
@@ -34,30 +61,42 @@ impl From<PoATriggerArgs> for PoATrigger {
                 max_tx_idle_time: idle_time.into(),
                 max_block_time: max_time.into(),
             },
+            PoATriggerArgs {
+                adaptive:
+                    Adaptive {
+                        adaptive_min_time: Some(min_time),
+                        adaptive_max_time: Some(max_time),
+                    },
+                ..
+            } => PoATrigger::Adaptive {
+                min_block_time: min_time.into(),
+                max_block_time: max_time.into(),
+            },
             PoATriggerArgs {
                 interval: Interval { period: Some(p) },
                 ..
             } => PoATrigger::Interval {
                 block_time: p.into(),
             },
-            PoATriggerArgs { instant, .. } if instant.instant == Boolean::True => {
-                PoATrigger::Instant
+            PoATriggerArgs { instant, .. } if instant.instant == Some(Boolean::False) => {
+                PoATrigger::Never
             }
-            _ => PoATrigger::Never,
+            _ => PoATrigger::Instant,
         }
     }
 }
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "hybrid-mode"]),
+    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "hybrid-mode", "adaptive-mode"]),
 )]
 struct Instant {
     /// Use instant block production mode.
     /// Newly submitted txs will immediately trigger the production of the next block.
     /// Cannot be combined with other poa flags.
-    #[arg(long = "poa-instant", default_value = "true", value_parser, env)]
-    instant: Boolean,
+    /// Enabled by default unless the chain config defines the `block_production`.
+    #[arg(long = "poa-instant", value_parser, env)]
+    instant: Option<Boolean>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -66,12 +105,48 @@ enum Boolean {
     False,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LateBlockPolicyArg {
+    Drift,
+    Skip,
+    Backdate,
+    CatchUp,
+}
+
+impl From<LateBlockPolicyArg> for LateBlockPolicy {
+    fn from(arg: LateBlockPolicyArg) -> Self {
+        match arg {
+            LateBlockPolicyArg::Drift => LateBlockPolicy::Drift,
+            LateBlockPolicyArg::Skip => LateBlockPolicy::Skip,
+            LateBlockPolicyArg::Backdate => LateBlockPolicy::Backdate,
+            LateBlockPolicyArg::CatchUp => LateBlockPolicy::CatchUp,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxOrderingArg {
+    Fifo,
+    FeePriority,
+    SenderRoundRobin,
+}
+
+impl From<TxOrderingArg> for TxOrdering {
+    fn from(arg: TxOrderingArg) -> Self {
+        match arg {
+            TxOrderingArg::Fifo => TxOrdering::Fifo,
+            TxOrderingArg::FeePriority => TxOrdering::FeePriority,
+            TxOrderingArg::SenderRoundRobin => TxOrdering::SenderRoundRobin,
+        }
+    }
+}
+
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
     group = ArgGroup::new("hybrid-mode")
             .args(&["min_time", "idle_time", "max_time"])
             .multiple(true)
-            .conflicts_with_all(&["interval-mode", "instant-mode"]),
+            .conflicts_with_all(&["interval-mode", "instant-mode", "adaptive-mode"]),
 )]
 struct Hybrid {
     /// Hybrid trigger option.
@@ -100,7 +175,7 @@ struct Hybrid {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("interval-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "hybrid-mode"]),
+    group = ArgGroup::new("interval-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "hybrid-mode", "adaptive-mode"]),
 )]
 struct Interval {
     /// Interval trigger option.
@@ -110,6 +185,27 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+#[clap(
+    group = ArgGroup::new("adaptive-mode")
+            .args(&["adaptive_min_time", "adaptive_max_time"])
+            .multiple(true)
+            .conflicts_with_all(&["interval-mode", "instant-mode", "hybrid-mode"]),
+)]
+struct Adaptive {
+    /// Adaptive trigger option.
+    /// Sets the interval between blocks when the txpool has at least a full block of
+    /// transactions. The interval grows as the txpool drains.
+    /// Cannot be combined with other poa mode options (instant, interval or hybrid).
+    #[arg(long = "poa-adaptive-min-time", requires = "adaptive_max_time", env)]
+    adaptive_min_time: Option<Duration>,
+    /// Adaptive trigger option.
+    /// Sets the interval between blocks when the txpool is empty.
+    /// Cannot be combined with other poa mode options (instant, interval or hybrid).
+    #[arg(long = "poa-adaptive-max-time", requires = "adaptive_min_time", env)]
+    adaptive_max_time: Option<Duration>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,9 +236,24 @@ mod tests {
     #[test_case(&["", "--poa-instant=true", "--poa-hybrid-min-time=1s", "--poa-hybrid-idle-time=2s", "--poa-hybrid-max-time=3s"] => Err(()); "can't set hybrid and instant at the same time")]
     #[test_case(&["", "--poa-instant=true", "--poa-interval-period=1s"] => Err(()); "can't set interval and instant at the same time")]
     #[test_case(&["", "--poa-hybrid-min-time=1s"] => Err(()); "can't set hybrid min time without idle and max")]
+    #[test_case(&["", "--poa-adaptive-min-time=1s", "--poa-adaptive-max-time=5s"] => Ok(Trigger::Adaptive { min_block_time: StdDuration::from_secs(1), max_block_time: StdDuration::from_secs(5) }); "uses adaptive mode if set")]
+    #[test_case(&["", "--poa-adaptive-min-time=1s", "--poa-interval-period=1s"] => Err(()); "can't set adaptive and interval at the same time")]
     fn parse(args: &[&str]) -> Result<Trigger, ()> {
         Command::try_parse_from(args)
             .map_err(|_| ())
             .map(|c| c.trigger.into())
     }
+
+    #[test_case(&[""] => Trigger::Interval { block_time: StdDuration::from_secs(2)}; "follows chain config if not set")]
+    #[test_case(&["", "--poa-interval-period=3s"] => Trigger::Interval { block_time: StdDuration::from_secs(3)}; "overrides chain config")]
+    #[test_case(&["", "--poa-instant=false"] => Trigger::Never; "disables production of chain config")]
+    fn parse_with_chain_config(args: &[&str]) -> Trigger {
+        let block_production = BlockProduction::Interval {
+            block_time: StdDuration::from_secs(2),
+        };
+        Command::try_parse_from(args)
+            .unwrap()
+            .trigger
+            .into_trigger(Some(block_production))
+    }
 }
@@ -0,0 +1,28 @@
+use clap::Args;
+use fuel_core::service::ForkConfig;
+
+#[derive(Debug, Clone, Args)]
+pub struct ForkArgs {
+    /// The address of the remote node to fork the state from, for example
+    /// `http://localhost:4000`. The state of the remote network is fetched on
+    /// the first access and cached in memory, so it requires `--db-type in-memory`.
+    /// Fetches fail once the remote node produces blocks after the fork height.
+    /// Should be used for local development only.
+    #[arg(long = "fork-url", env)]
+    pub fork_url: Option<String>,
+
+    /// The height of the block to fork from. The latest height of the remote node is used
+    /// if not set. The remote node serves only its latest state, so the fork fails if the
+    /// height isn't the latest one.
+    #[arg(long = "fork-height", requires = "fork_url", env)]
+    pub fork_height: Option<u32>,
+}
+
+impl From<ForkArgs> for Option<ForkConfig> {
+    fn from(args: ForkArgs) -> Self {
+        args.fork_url.map(|url| ForkConfig {
+            url,
+            height: args.fork_height.map(Into::into),
+        })
+    }
+}
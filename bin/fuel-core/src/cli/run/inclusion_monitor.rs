@@ -0,0 +1,37 @@
+use clap::Args;
+use fuel_core::service::inclusion_monitor::Config;
+
+#[derive(Debug, Clone, Args)]
+pub struct InclusionMonitorArgs {
+    /// Enables the monitor of the inclusion of transactions. It tracks how long
+    /// transactions wait in the pool before the inclusion into blocks, to detect the
+    /// censorship by the block producer. The statistics are served by the GraphQL API.
+    #[arg(long = "inclusion-monitor", env)]
+    pub inclusion_monitor: bool,
+
+    /// Transactions with the lower gas price are not tracked by the monitor.
+    #[arg(long = "inclusion-monitor-min-gas-price", default_value = "0", env)]
+    pub inclusion_monitor_min_gas_price: u64,
+
+    /// Transactions waiting longer than the threshold are reported as delayed.
+    #[arg(long = "inclusion-monitor-delay-threshold", default_value = "60s", env)]
+    pub inclusion_monitor_delay_threshold: humantime::Duration,
+
+    /// The maximum number of pending and included transactions remembered by the
+    /// monitor.
+    #[arg(long = "inclusion-monitor-max-tracked", default_value = "10000", env)]
+    pub inclusion_monitor_max_tracked: usize,
+}
+
+impl From<InclusionMonitorArgs> for Option<Config> {
+    fn from(args: InclusionMonitorArgs) -> Self {
+        if !args.inclusion_monitor {
+            return None
+        }
+        Some(Config {
+            min_gas_price: args.inclusion_monitor_min_gas_price,
+            delay_threshold: args.inclusion_monitor_delay_threshold.into(),
+            max_tracked: args.inclusion_monitor_max_tracked,
+        })
+    }
+}
@@ -45,9 +45,14 @@ pub struct P2PArgs {
     #[clap(long = "address", env)]
     pub address: Option<IpAddr>,
 
-    /// Optional address of your local node made reachable for other nodes in the network.
-    #[clap(long = "public_address", env)]
-    pub public_address: Option<Multiaddr>,
+    /// Additional IP addresses to listen on with the same port, e.g. the IPv6 address of
+    /// the dual-stack node. IPv6 listeners accept only IPv6 connections
+    #[clap(long = "additional_addresses", value_delimiter = ',', env)]
+    pub additional_addresses: Vec<IpAddr>,
+
+    /// Addresses of your local node made reachable for other nodes in the network.
+    #[clap(long = "public_address", value_delimiter = ',', env)]
+    pub public_addresses: Vec<Multiaddr>,
 
     /// p2p network's TCP Port
     #[clap(long = "peering_port", default_value = "30333", env)]
@@ -174,6 +179,24 @@ pub struct SyncArgs {
     /// The maximum number of get transaction requests to make in a single batch.
     #[clap(long = "sync_max_get_txns", default_value = "10", env)]
     pub max_get_txns_requests: usize,
+    /// The number of distinct peers that must serve the same invalid block
+    /// before the sync halts.
+    #[clap(long = "sync_max_invalid_block_peers", default_value = "3", env)]
+    pub max_invalid_block_peers: usize,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct PoolDivergenceArgs {
+    /// Enables the periodic comparison of the transaction pool with pools of peers,
+    /// the value is the interval between samples.
+    #[clap(long = "pool_divergence_interval", env)]
+    pub pool_divergence_interval: Option<humantime::Duration>,
+    /// The maximum number of peers sampled per interval.
+    #[clap(long = "pool_divergence_peers", default_value = "3", env)]
+    pub pool_divergence_peers: usize,
+    /// The maximum number of transactions in the sketch of the pool shared with peers.
+    #[clap(long = "pool_divergence_sketch_size", default_value = "1024", env)]
+    pub pool_divergence_sketch_size: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -206,14 +229,28 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             max_get_header_requests: value.max_get_header_requests,
             max_get_txns_requests: value.max_get_txns_requests,
+            max_invalid_block_peers: value.max_invalid_block_peers,
         }
     }
 }
 
+impl From<PoolDivergenceArgs> for Option<fuel_core::service::pool_divergence::Config> {
+    fn from(value: PoolDivergenceArgs) -> Self {
+        value.pool_divergence_interval.map(|interval| {
+            fuel_core::service::pool_divergence::Config {
+                interval: interval.into(),
+                peers_per_sample: value.pool_divergence_peers,
+                sketch_size: value.pool_divergence_sketch_size,
+            }
+        })
+    }
+}
+
 impl P2PArgs {
     pub fn into_config(
         self,
         metrics: bool,
+        watchdog: Option<Duration>,
     ) -> anyhow::Result<Option<Config<NotInitialized>>> {
         let local_keypair = {
             match self.keypair {
@@ -270,8 +307,10 @@ impl P2PArgs {
                 address: self
                     .address
                     .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
-                public_address: self.public_address,
+                additional_addresses: self.additional_addresses,
+                public_addresses: self.public_addresses,
                 tcp_port: self.peering_port,
+                memory_transport: false,
                 max_block_size: self.max_block_size,
                 bootstrap_nodes: self.bootstrap_nodes,
                 reserved_nodes: self.reserved_nodes,
@@ -293,6 +332,8 @@ impl P2PArgs {
                 ),
                 info_interval: Some(Duration::from_secs(self.info_interval)),
                 identify_interval: Some(Duration::from_secs(self.identify_interval)),
+                capabilities: Default::default(),
+                watchdog,
                 metrics,
                 state: NotInitialized,
             })
@@ -0,0 +1,47 @@
+use anyhow::anyhow;
+use clap::Args;
+use fuel_core::{
+    service::prewarm::Config,
+    types::fuel_tx::Address,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Args)]
+pub struct PrewarmArgs {
+    /// The number of the latest blocks loaded into the caches of the database before
+    /// the API starts serving requests. The code of contracts called by transactions
+    /// of these blocks is loaded too.
+    #[arg(long = "prewarm-blocks", env)]
+    pub prewarm_blocks: Option<u32>,
+
+    /// The busy owners whose coins are loaded into the caches of the database before
+    /// the API starts serving requests.
+    #[arg(
+        long = "prewarm-owners",
+        value_parser = parse_address,
+        value_delimiter = ',',
+        env
+    )]
+    pub prewarm_owners: Vec<Address>,
+
+    /// The maximum number of coins loaded per owner.
+    #[arg(long = "prewarm-coins-per-owner", default_value = "100", env)]
+    pub prewarm_coins_per_owner: usize,
+}
+
+fn parse_address(input: &str) -> anyhow::Result<Address> {
+    Address::from_str(input).map_err(|e| anyhow!(e))
+}
+
+impl From<PrewarmArgs> for Option<Config> {
+    fn from(args: PrewarmArgs) -> Self {
+        if args.prewarm_blocks.is_none() && args.prewarm_owners.is_empty() {
+            return None
+        }
+        Some(Config {
+            blocks: args.prewarm_blocks.unwrap_or_default(),
+            owners: args.prewarm_owners,
+            coins_per_owner: args.prewarm_coins_per_owner,
+        })
+    }
+}
@@ -44,6 +44,11 @@ pub struct RelayerArgs {
 
     #[clap(long = "relayer-eth-sync-log-freq-s", default_value_t = Config::DEFAULT_SYNCING_LOG_FREQ.as_secs(), env)]
     pub syncing_log_frequency_secs: u64,
+
+    /// The address of the L1 wallet that pays for commitments to the DA layer.
+    /// If set, the relayer monitors its balance.
+    #[arg(long = "relayer-wallet-address", value_parser = parse_h160, env)]
+    pub wallet_address: Option<H160>,
 }
 
 pub fn parse_h160(input: &str) -> Result<H160, <H160 as FromStr>::Err> {
@@ -61,6 +66,9 @@ impl From<RelayerArgs> for Config {
             sync_minimum_duration: Duration::from_secs(args.sync_minimum_duration_secs),
             syncing_call_frequency: Duration::from_secs(args.syncing_call_frequency_secs),
             syncing_log_frequency: Duration::from_secs(args.syncing_log_frequency_secs),
+            wallet_address: args.wallet_address,
+            restart_policy: Default::default(),
+            watchdog: None,
             metrics: false,
         }
     }
@@ -0,0 +1,59 @@
+use anyhow::anyhow;
+use clap::Args;
+use fuel_core::{
+    service::remote_signer::Config,
+    types::fuel_tx::Address,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Args)]
+pub struct RemoteSignerArgs {
+    /// The URL of the remote signing service that holds the consensus key, e.g. the proxy
+    /// in front of the KMS. Produced blocks are signed by the service, and the consensus
+    /// key isn't loaded into the memory of the node.
+    #[arg(long = "remote-signer-url", requires = "remote_signer_address", env)]
+    pub remote_signer_url: Option<String>,
+
+    /// The address of the consensus key held by the remote signing service.
+    #[arg(long = "remote-signer-address", requires = "remote_signer_url", env)]
+    pub remote_signer_address: Option<String>,
+
+    /// The time to wait for the signature from the remote signing service.
+    #[arg(long = "remote-signer-timeout", default_value = "5s", env)]
+    pub remote_signer_timeout: humantime::Duration,
+
+    /// The remote signing service of another member of the committee of the threshold
+    /// PoA in the format `<address>@<url>`. Produced blocks are sealed once the
+    /// threshold of the committee signed them.
+    #[arg(long = "remote-cosigner", value_delimiter = ',', env)]
+    pub remote_cosigners: Vec<String>,
+}
+
+impl RemoteSignerArgs {
+    pub fn into_config(self) -> anyhow::Result<Option<Config>> {
+        match (self.remote_signer_url, self.remote_signer_address) {
+            (Some(url), Some(address)) => Ok(Some(Config {
+                url,
+                address: Address::from_str(&address).map_err(|e| anyhow!(e))?,
+                timeout: self.remote_signer_timeout.into(),
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn cosigners_config(&self) -> anyhow::Result<Vec<Config>> {
+        self.remote_cosigners
+            .iter()
+            .map(|cosigner| {
+                let (address, url) = cosigner.split_once('@').ok_or_else(|| {
+                    anyhow!("Expected `<address>@<url>`, but got `{cosigner}`")
+                })?;
+                Ok(Config {
+                    url: url.to_string(),
+                    address: Address::from_str(address).map_err(|e| anyhow!(e))?,
+                    timeout: self.remote_signer_timeout.into(),
+                })
+            })
+            .collect()
+    }
+}
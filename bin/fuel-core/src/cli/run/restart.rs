@@ -0,0 +1,71 @@
+use clap::{
+    Args,
+    ValueEnum,
+};
+use fuel_core::services::{
+    Backoff,
+    RestartPolicy,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum RestartPolicyArg {
+    Never,
+    Always,
+    OnError,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RestartArgs {
+    /// How the relayer reacts to failures. `never` stops the service on a panic,
+    /// `always` restarts it after a panic, `on-error` restarts it after an error or
+    /// a panic. Restarts build a fresh task after the exponential backoff.
+    #[arg(
+        long = "service-restart-policy",
+        default_value = "never",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub restart_policy: RestartPolicyArg,
+
+    /// The delay before the first restart. It doubles with each following failure.
+    #[arg(long = "service-restart-backoff", default_value = "1s", env)]
+    pub restart_backoff: humantime::Duration,
+
+    /// The maximum delay between restarts.
+    #[arg(long = "service-restart-max-backoff", default_value = "1m", env)]
+    pub restart_max_backoff: humantime::Duration,
+
+    /// The number of failures in a row after which the service stops with the
+    /// `on-error` policy.
+    #[arg(long = "service-restart-max-attempts", default_value = "10", env)]
+    pub restart_max_attempts: u32,
+
+    /// If set, an iteration of the relayer or the p2p task that runs longer is cancelled
+    /// and handled as a failure. The relayer reacts to it according to the restart
+    /// policy. The deadline should exceed the longest time the service may
+    /// legitimately wait for events.
+    #[arg(long = "service-watchdog-deadline", env)]
+    pub watchdog_deadline: Option<humantime::Duration>,
+
+    /// If set, an iteration of the task of any service that works longer, not counting
+    /// waits for events, is reported as stalled in the `service_stalled_iterations`
+    /// metric and in logs.
+    #[arg(long = "service-stall-threshold", env)]
+    pub stall_threshold: Option<humantime::Duration>,
+}
+
+impl From<RestartArgs> for RestartPolicy {
+    fn from(args: RestartArgs) -> Self {
+        let backoff = Backoff {
+            initial: args.restart_backoff.into(),
+            max: args.restart_max_backoff.into(),
+            max_attempts: args.restart_max_attempts,
+        };
+        match args.restart_policy {
+            RestartPolicyArg::Never => RestartPolicy::Never,
+            RestartPolicyArg::Always => RestartPolicy::Always(backoff),
+            RestartPolicyArg::OnError => RestartPolicy::OnError(backoff),
+        }
+    }
+}
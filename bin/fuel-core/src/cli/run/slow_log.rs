@@ -0,0 +1,42 @@
+use clap::Args;
+use fuel_core::service::slow_log::Config;
+
+#[derive(Debug, Clone, Args)]
+pub struct SlowLogArgs {
+    /// Enables the log of slow operations. GraphQL requests and commits of storage
+    /// transactions taking longer than thresholds are recorded and served by the
+    /// `/v1/slow-log` endpoint of the admin API.
+    #[arg(long = "slow-log", env)]
+    pub slow_log: bool,
+
+    /// GraphQL requests taking longer are recorded.
+    #[arg(long = "slow-log-graphql-threshold", default_value = "1s", env)]
+    pub slow_log_graphql_threshold: humantime::Duration,
+
+    /// Commits of storage transactions taking longer are recorded.
+    #[arg(long = "slow-log-storage-threshold", default_value = "500ms", env)]
+    pub slow_log_storage_threshold: humantime::Duration,
+
+    /// The maximum number of recorded operations. The oldest operations are forgotten
+    /// first.
+    #[arg(long = "slow-log-capacity", default_value = "1000", env)]
+    pub slow_log_capacity: usize,
+
+    /// Logs recorded operations in addition to recording them.
+    #[arg(long = "slow-log-tracing", env)]
+    pub slow_log_tracing: bool,
+}
+
+impl From<SlowLogArgs> for Option<Config> {
+    fn from(args: SlowLogArgs) -> Self {
+        if !args.slow_log {
+            return None
+        }
+        Some(Config {
+            graphql_threshold: args.slow_log_graphql_threshold.into(),
+            storage_threshold: args.slow_log_storage_threshold.into(),
+            capacity: args.slow_log_capacity,
+            log: args.slow_log_tracing,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use clap::Args;
+use fuel_core::service::version_check::{
+    Config,
+    DEFAULT_MANIFEST_URL,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct VersionCheckArgs {
+    /// Disables the periodic check of the running version against the manifest of
+    /// supported versions of the network.
+    #[arg(long = "disable-version-check", env)]
+    pub disable_version_check: bool,
+
+    /// The URL of the JSON manifest with the minimum and the recommended versions
+    /// of networks.
+    #[arg(long = "version-manifest-url", default_value = DEFAULT_MANIFEST_URL, env)]
+    pub version_manifest_url: String,
+
+    /// How often to fetch the manifest.
+    #[arg(long = "version-check-interval", default_value = "1h", env)]
+    pub version_check_interval: humantime::Duration,
+}
+
+impl From<VersionCheckArgs> for Option<Config> {
+    fn from(args: VersionCheckArgs) -> Self {
+        if args.disable_version_check {
+            return None
+        }
+
+        Some(Config {
+            manifest_url: args.version_manifest_url,
+            interval: args.version_check_interval.into(),
+        })
+    }
+}
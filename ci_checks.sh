@@ -8,4 +8,5 @@ cargo make check --locked &&
 cargo make check --all-features --locked &&
 cargo test --all-features --workspace &&
 cargo make test --no-default-features &&
+cargo build -p fuel-core-bin --no-default-features &&
 cargo test --manifest-path version-compatibility/Cargo.toml --workspace
\ No newline at end of file
@@ -1,14 +1,20 @@
+mod block_production;
 mod chain;
 mod coin;
 mod consensus;
 mod contract;
+mod dev_accounts;
+mod fee_overrides;
 mod message;
 mod state;
 
+pub use block_production::*;
 pub use chain::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
+pub use dev_accounts::*;
+pub use fee_overrides::*;
 pub use message::*;
 pub use state::*;
 
@@ -22,10 +28,14 @@ mod tests {
             UtxoId,
         },
         fuel_types::{
+            Address,
             AssetId,
             Bytes32,
         },
-        fuel_vm::Contract,
+        fuel_vm::{
+            Contract,
+            SecretKey,
+        },
     };
     use rand::{
         prelude::StdRng,
@@ -37,15 +47,23 @@ mod tests {
         env::temp_dir,
         fs::write,
         path::PathBuf,
+        time::Duration,
     };
 
     use super::{
+        block_production::BlockProduction,
         chain::ChainConfig,
         coin::CoinConfig,
         contract::ContractConfig,
+        dev_accounts::{
+            DevAccountsConfig,
+            MAX_DEV_ACCOUNTS,
+        },
+        fee_overrides::FeeOverrides,
         message::MessageConfig,
         state::StateConfig,
     };
+    use crate::GenesisCommitment;
 
     #[test]
     fn from_str_loads_from_file() {
@@ -107,6 +125,116 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn can_roundtrip_block_production_config() {
+        let config = ChainConfig {
+            block_production: Some(BlockProduction::Hybrid {
+                min_block_time: Duration::from_secs(1),
+                max_tx_idle_time: Duration::from_millis(1500),
+                max_block_time: Duration::from_secs(10),
+            }),
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""min_block_time":1000"#));
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[test]
+    fn dev_accounts_are_derived_from_mnemonic() {
+        const MNEMONIC: &str =
+            "test test test test test test test test test test test junk";
+        let mut config = ChainConfig {
+            dev_accounts: Some(DevAccountsConfig {
+                mnemonic: MNEMONIC.to_string(),
+                count: 3,
+                balance: 100,
+            }),
+            ..ChainConfig::local_testnet()
+        };
+
+        config.apply_dev_accounts().unwrap();
+
+        let coins = config.initial_state.unwrap().coins.unwrap();
+        let dev_coins = &coins[coins.len() - 3..];
+        for (index, coin) in dev_coins.iter().enumerate() {
+            let secret = SecretKey::new_from_mnemonic_phrase_with_path(
+                MNEMONIC,
+                &DevAccountsConfig::derivation_path(index as u32),
+            )
+            .unwrap();
+            assert_eq!(coin.owner, Address::from(*secret.public_key().hash()));
+            assert_eq!(coin.amount, 100);
+        }
+        assert!(config.dev_accounts.is_none());
+    }
+
+    #[test]
+    fn fee_overrides_are_applied() {
+        let mut config = ChainConfig {
+            fee_overrides: Some(FeeOverrides {
+                gas_per_byte: Some(7),
+                gas_price_factor: None,
+                max_gas_per_tx: Some(1_000_000),
+                gas_costs: [("add".to_string(), serde_json::json!(3))].into(),
+            }),
+            ..ChainConfig::local_testnet()
+        };
+        let gas_price_factor = config.transaction_parameters.gas_price_factor;
+        let root = config.root().unwrap();
+
+        config.apply_fee_overrides().unwrap();
+
+        assert_eq!(config.transaction_parameters.gas_per_byte, 7);
+        assert_eq!(config.transaction_parameters.gas_price_factor, gas_price_factor);
+        assert_eq!(config.transaction_parameters.max_gas_per_tx, 1_000_000);
+        assert_eq!(config.gas_costs.add, 3);
+        assert!(config.fee_overrides.is_none());
+        // The root doesn't depend on whether the overrides are applied already
+        assert_eq!(config.root().unwrap(), root);
+        assert_ne!(ChainConfig::local_testnet().root().unwrap(), root);
+    }
+
+    #[test]
+    fn fee_overrides_reject_unknown_gas_costs() {
+        let mut config = ChainConfig {
+            fee_overrides: Some(FeeOverrides {
+                gas_costs: [("unknown".to_string(), serde_json::json!(3))].into(),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        };
+
+        assert!(config.apply_fee_overrides().is_err());
+    }
+
+    #[test]
+    fn fee_overrides_reject_zero_max_gas_per_tx() {
+        let mut config = ChainConfig {
+            fee_overrides: Some(FeeOverrides {
+                max_gas_per_tx: Some(0),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        };
+
+        assert!(config.apply_fee_overrides().is_err());
+    }
+
+    #[test]
+    fn dev_accounts_are_limited() {
+        let dev_accounts = DevAccountsConfig {
+            mnemonic: "test test test test test test test test test test test junk"
+                .to_string(),
+            count: MAX_DEV_ACCOUNTS + 1,
+            balance: 100,
+        };
+
+        assert!(dev_accounts.secret_keys().is_err());
+    }
+
     #[test]
     fn snapshot_simple_contract() {
         let config = test_config_contract(false, false, false, false);
@@ -0,0 +1,46 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::{
+    serde_as,
+    DurationMilliSeconds,
+};
+use std::time::Duration;
+
+/// The block production cadence expected by all nodes of the network.
+/// Producers use it as the default trigger, and validators reject blocks
+/// whose timestamps are closer than the minimum block time of the cadence.
+/// All durations are in milliseconds.
+#[serde_as]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum BlockProduction {
+    /// A new block is produced instantly when transactions are available.
+    Instant,
+    /// A new block is produced periodically.
+    Interval {
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        block_time: Duration,
+    },
+    /// A new block is produced when transactions are idle for `max_tx_idle_time`,
+    /// but not earlier than `min_block_time` and not later than `max_block_time`.
+    Hybrid {
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        min_block_time: Duration,
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        max_tx_idle_time: Duration,
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        max_block_time: Duration,
+    },
+}
+
+impl BlockProduction {
+    /// The minimum time between timestamps of two consecutive blocks.
+    pub fn min_block_time(&self) -> Duration {
+        match self {
+            BlockProduction::Instant => Duration::ZERO,
+            BlockProduction::Interval { block_time } => *block_time,
+            BlockProduction::Hybrid { min_block_time, .. } => *min_block_time,
+        }
+    }
+}
@@ -43,7 +43,10 @@ use std::{
 
 use crate::{
     config::{
+        block_production::BlockProduction,
         coin::CoinConfig,
+        dev_accounts::DevAccountsConfig,
+        fee_overrides::FeeOverrides,
         state::StateConfig,
     },
     default_consensus_dev_key,
@@ -71,6 +74,19 @@ pub struct ChainConfig {
     #[serde_as(as = "FromInto<GasCostsValues>")]
     pub gas_costs: GasCosts,
     pub consensus: ConsensusConfig,
+    /// The block production cadence of the network. Nodes may override the trigger,
+    /// but validators reject blocks produced faster than the cadence allows.
+    #[serde(default)]
+    pub block_production: Option<BlockProduction>,
+    /// Funded accounts derived from the mnemonic. The coins of the accounts are added
+    /// to the `initial_state` when the config is loaded. Only for development networks.
+    #[serde(default)]
+    pub dev_accounts: Option<DevAccountsConfig>,
+    /// Overrides of the intrinsic costs of the fee model. They are applied to the
+    /// `transaction_parameters` and the `gas_costs` when the node starts, see
+    /// [`ChainConfig::apply_fee_overrides`].
+    #[serde(default)]
+    pub fee_overrides: Option<FeeOverrides>,
 }
 
 impl Default for ChainConfig {
@@ -83,7 +99,11 @@ impl Default for ChainConfig {
             gas_costs: GasCosts::default(),
             consensus: ConsensusConfig::PoA {
                 signing_key: Input::owner(&default_consensus_dev_key().public_key()),
+                key_rotations: vec![],
             },
+            block_production: None,
+            dev_accounts: None,
+            fee_overrides: None,
         }
     }
 }
@@ -98,17 +118,7 @@ impl ChainConfig {
         let initial_coins = (0..5)
             .map(|_| {
                 let secret = fuel_core_types::fuel_crypto::SecretKey::random(&mut rng);
-                let address = Address::from(*secret.public_key().hash());
-                let bech32_data = Bytes32::new(*address).to_base32();
-                let bech32_encoding =
-                    bech32::encode(FUEL_BECH32_HRP, bech32_data, Bech32m).unwrap();
-                tracing::info!(
-                    "PrivateKey({:#x}), Address({:#x} [bech32: {}]), Balance({})",
-                    secret,
-                    address,
-                    bech32_encoding,
-                    TESTNET_INITIAL_BALANCE
-                );
+                Self::log_account(&secret, TESTNET_INITIAL_BALANCE);
                 Self::initial_coin(secret, TESTNET_INITIAL_BALANCE, None)
             })
             .collect_vec();
@@ -123,6 +133,64 @@ impl ChainConfig {
         }
     }
 
+    /// Adds the coins of the accounts derived from the `dev_accounts` mnemonic to the
+    /// `initial_state` and prints the keys of the accounts.
+    pub fn apply_dev_accounts(&mut self) -> anyhow::Result<()> {
+        if let Some(dev_accounts) = self.dev_accounts.take() {
+            tracing::info!("Dev Accounts");
+            let coins = dev_accounts
+                .secret_keys()?
+                .into_iter()
+                .map(|secret| {
+                    Self::log_account(&secret, dev_accounts.balance);
+                    Self::initial_coin(secret, dev_accounts.balance, None)
+                })
+                .collect_vec();
+            self.initial_state
+                .get_or_insert_with(Default::default)
+                .coins
+                .get_or_insert_with(Default::default)
+                .extend(coins);
+        }
+        Ok(())
+    }
+
+    /// Returns the `transaction_parameters` and the `gas_costs` with the `fee_overrides`
+    /// applied, whether they are applied to the config already or not.
+    pub fn fee_parameters(&self) -> anyhow::Result<(ConsensusParameters, GasCosts)> {
+        let mut params = self.transaction_parameters;
+        let mut gas_costs = self.gas_costs.clone();
+        if let Some(fee_overrides) = &self.fee_overrides {
+            fee_overrides.apply(&mut params, &mut gas_costs)?;
+        }
+        Ok((params, gas_costs))
+    }
+
+    /// Applies the `fee_overrides` to the `transaction_parameters` and the `gas_costs`,
+    /// so the txpool, the block producer and the executor use the same fee model. The
+    /// node applies them on start, so configs built in code are covered too.
+    pub fn apply_fee_overrides(&mut self) -> anyhow::Result<()> {
+        let (params, gas_costs) = self.fee_parameters()?;
+        self.transaction_parameters = params;
+        self.gas_costs = gas_costs;
+        self.fee_overrides = None;
+        Ok(())
+    }
+
+    fn log_account(secret: &SecretKey, balance: u64) {
+        let address = Address::from(*secret.public_key().hash());
+        let bech32_data = Bytes32::new(*address).to_base32();
+        let bech32_encoding =
+            bech32::encode(FUEL_BECH32_HRP, bech32_data, Bech32m).unwrap();
+        tracing::info!(
+            "PrivateKey({:#x}), Address({:#x} [bech32: {}]), Balance({})",
+            secret,
+            address,
+            bech32_encoding,
+            balance
+        );
+    }
+
     pub fn initial_coin(
         secret: SecretKey,
         amount: u64,
@@ -153,14 +221,22 @@ impl FromStr for ChainConfig {
                 // Attempt to load chain config from path
                 let path = PathBuf::from(s.to_string());
                 let contents = std::fs::read(path)?;
-                serde_json::from_slice(&contents).map_err(|e| {
+                let load_error = |e: anyhow::Error| {
                     std::io::Error::new(
                         ErrorKind::InvalidData,
-                        anyhow::Error::new(e).context(format!(
+                        e.context(format!(
                             "an error occurred while loading the chain config file {s}"
                         )),
                     )
-                })
+                };
+                let mut config: ChainConfig = serde_json::from_slice(&contents)
+                    .map_err(|e| load_error(e.into()))?;
+                config.apply_dev_accounts().map_err(load_error)?;
+                // Invalid overrides are reported with the path of the file, but they are
+                // applied when the node starts.
+                config.fee_parameters().map_err(load_error)?;
+                config.consensus.validate().map_err(load_error)?;
+                Ok(config)
             }
         }
     }
@@ -176,19 +252,29 @@ impl GenesisCommitment for ChainConfig {
             block_gas_limit,
             // Skip the `initial_state` bec
             initial_state: _,
-            transaction_parameters,
-            gas_costs,
+            transaction_parameters: _,
+            gas_costs: _,
             consensus,
+            block_production,
+            // The coins of dev accounts are part of the `initial_state`
+            dev_accounts: _,
+            // The overrides are hashed as part of the parameters they override
+            fee_overrides: _,
         } = self;
+        let (transaction_parameters, gas_costs) = self.fee_parameters()?;
 
         // TODO: Hash settlement configuration when it will be available.
-        let config_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(chain_name.as_bytes())
             .chain(block_gas_limit.to_be_bytes())
             .chain(transaction_parameters.root()?)
             .chain(gas_costs.root()?)
-            .chain(consensus.root()?)
-            .finalize();
+            .chain(consensus.root()?);
+        // The cadence is hashed only if it is set to keep the root of existing networks.
+        if let Some(block_production) = block_production {
+            hasher = hasher.chain(postcard::to_stdvec(block_production)?);
+        }
+        let config_hash = *hasher.finalize();
 
         Ok(config_hash)
     }
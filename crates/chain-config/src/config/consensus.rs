@@ -1,4 +1,10 @@
-use fuel_core_types::fuel_types::Address;
+use fuel_core_types::{
+    blockchain::consensus::seal::Committee,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -6,5 +12,208 @@ use serde::{
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    /// The PoA with the single authority. The `signing_key` signs blocks until the first
+    /// of `key_rotations`, see [`KeyRotation`].
+    PoA {
+        signing_key: Address,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        key_rotations: Vec<KeyRotation>,
+    },
+    /// The PoA with the rotating set of authorities. Each height is the slot of one
+    /// authority, see [`round_robin_authority`].
+    PoARoundRobin {
+        authorities: Vec<Address>,
+    },
+    /// The PoA where the committee of authorities seals each block. The block is valid
+    /// if at least `threshold` distinct `members` signed it.
+    PoAThreshold {
+        threshold: u16,
+        members: Vec<Address>,
+    },
+}
+
+impl ConsensusConfig {
+    /// Returns the authority that should sign the block at the `height`. Returns `None`
+    /// if the set of authorities is empty.
+    pub fn authority_at(&self, height: BlockHeight) -> Option<Address> {
+        match self {
+            ConsensusConfig::PoA {
+                signing_key,
+                key_rotations,
+            } => Some(scheduled_key(*signing_key, key_rotations, height)),
+            ConsensusConfig::PoARoundRobin { authorities } => {
+                round_robin_authority(authorities, height)
+            }
+            ConsensusConfig::PoAThreshold { .. } => {
+                self.committee().map(|committee| committee.address())
+            }
+        }
+    }
+
+    /// Returns all keys that sign blocks of the chain at some height, including the
+    /// rotated ones.
+    pub fn authorities(&self) -> Vec<Address> {
+        match self {
+            ConsensusConfig::PoA {
+                signing_key,
+                key_rotations,
+            } => std::iter::once(*signing_key)
+                .chain(key_rotations.iter().map(|rotation| rotation.signing_key))
+                .collect(),
+            ConsensusConfig::PoARoundRobin { authorities } => authorities.clone(),
+            ConsensusConfig::PoAThreshold { members, .. } => members.clone(),
+        }
+    }
+
+    /// Checks that the consensus can produce blocks, e.g. that the threshold of the
+    /// committee is reachable.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match self.committee() {
+            Some(committee) => committee.validate(),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the committee of the threshold PoA. Returns `None` for other kinds of
+    /// the consensus.
+    pub fn committee(&self) -> Option<Committee> {
+        match self {
+            ConsensusConfig::PoAThreshold { threshold, members } => Some(Committee {
+                threshold: *threshold,
+                members: members.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The key that signs blocks of the PoA from the `height`, so the compromised key can be
+/// replaced without the restart of the chain. Followers verify each block against the
+/// key scheduled for its height.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct KeyRotation {
+    pub height: BlockHeight,
+    pub signing_key: Address,
+}
+
+/// Returns the key scheduled for the `height`: the key of the latest rotation at or below
+/// the `height`, or the `signing_key` before the first rotation. Of rotations at the same
+/// height, the later one wins.
+pub fn scheduled_key(
+    signing_key: Address,
+    key_rotations: &[KeyRotation],
+    height: BlockHeight,
+) -> Address {
+    key_rotations
+        .iter()
+        .filter(|rotation| rotation.height <= height)
+        .max_by_key(|rotation| rotation.height)
+        .map_or(signing_key, |rotation| rotation.signing_key)
+}
+
+/// Returns the authority scheduled for the `height`. The genesis block isn't signed, so
+/// authorities take turns starting from the first one at the height `1`. The schedule
+/// doesn't skip authorities, the chain waits for the block of the scheduled one.
+pub fn round_robin_authority(
+    authorities: &[Address],
+    height: BlockHeight,
+) -> Option<Address> {
+    if authorities.is_empty() {
+        return None
+    }
+    let slot = (*height).saturating_sub(1) as usize % authorities.len();
+    Some(authorities[slot])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorities_take_turns_by_height() {
+        let authorities = vec![Address::from([1; 32]), Address::from([2; 32])];
+        let config = ConsensusConfig::PoARoundRobin {
+            authorities: authorities.clone(),
+        };
+
+        let scheduled: Vec<_> = (1u32..=4)
+            .map(|height| config.authority_at(height.into()).unwrap())
+            .collect();
+
+        assert_eq!(
+            scheduled,
+            vec![authorities[0], authorities[1], authorities[0], authorities[1]]
+        );
+        assert_eq!(round_robin_authority(&[], 1u32.into()), None);
+    }
+
+    #[test]
+    fn rotated_key_signs_blocks_from_its_height() {
+        let config = ConsensusConfig::PoA {
+            signing_key: Address::from([1; 32]),
+            key_rotations: vec![
+                KeyRotation {
+                    height: 10u32.into(),
+                    signing_key: Address::from([2; 32]),
+                },
+                KeyRotation {
+                    height: 20u32.into(),
+                    signing_key: Address::from([3; 32]),
+                },
+            ],
+        };
+
+        let scheduled: Vec<_> = [1u32, 9, 10, 19, 20, 100]
+            .into_iter()
+            .map(|height| config.authority_at(height.into()).unwrap())
+            .collect();
+
+        assert_eq!(
+            scheduled,
+            vec![
+                Address::from([1; 32]),
+                Address::from([1; 32]),
+                Address::from([2; 32]),
+                Address::from([2; 32]),
+                Address::from([3; 32]),
+                Address::from([3; 32]),
+            ]
+        );
+    }
+
+    #[test]
+    fn committee_is_the_authority_at_each_height() {
+        let config = ConsensusConfig::PoAThreshold {
+            threshold: 2,
+            members: vec![Address::from([1; 32]), Address::from([2; 32])],
+        };
+        let other = ConsensusConfig::PoAThreshold {
+            threshold: 1,
+            members: vec![Address::from([1; 32]), Address::from([2; 32])],
+        };
+        let committee = config.committee().unwrap().address();
+
+        assert_eq!(config.authority_at(1u32.into()), Some(committee));
+        assert_eq!(config.authority_at(2u32.into()), Some(committee));
+        assert_ne!(other.authority_at(1u32.into()), Some(committee));
+    }
+
+    #[test]
+    fn committee_with_unreachable_threshold_is_rejected() {
+        let members = vec![Address::from([1; 32]), Address::from([2; 32])];
+        let config = |threshold| ConsensusConfig::PoAThreshold {
+            threshold,
+            members: members.clone(),
+        };
+
+        assert!(config(0).validate().is_err());
+        assert!(config(1).validate().is_ok());
+        assert!(config(2).validate().is_ok());
+        assert!(config(3).validate().is_err());
+        let duplicated = ConsensusConfig::PoAThreshold {
+            threshold: 2,
+            members: vec![Address::from([1; 32]), Address::from([1; 32])],
+        };
+        assert!(duplicated.validate().is_err());
+    }
 }
@@ -0,0 +1,60 @@
+use crate::TESTNET_INITIAL_BALANCE;
+use fuel_core_types::fuel_vm::SecretKey;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The maximum number of accounts derived from the mnemonic.
+pub const MAX_DEV_ACCOUNTS: u32 = 1000;
+
+/// Funded accounts derived from the BIP39 mnemonic at genesis, like the default
+/// accounts of local development networks of other chains.
+///
+/// The keys of the accounts are known to anyone with the mnemonic, so it must be used
+/// only for development networks.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct DevAccountsConfig {
+    /// The BIP39 mnemonic of the accounts.
+    pub mnemonic: String,
+    /// The number of accounts derived from the mnemonic.
+    pub count: u32,
+    /// The balance of the base asset of each account.
+    #[serde(default = "default_balance")]
+    pub balance: u64,
+}
+
+fn default_balance() -> u64 {
+    TESTNET_INITIAL_BALANCE
+}
+
+impl DevAccountsConfig {
+    /// Returns the derivation path of the account with the `index`, the same as wallets
+    /// of the Fuel network use.
+    pub fn derivation_path(index: u32) -> String {
+        format!("m/44'/1179993420'/{}'/0/0", index)
+    }
+
+    /// Derives the secret keys of the accounts from the mnemonic.
+    pub fn secret_keys(&self) -> anyhow::Result<Vec<SecretKey>> {
+        if self.count > MAX_DEV_ACCOUNTS {
+            return Err(anyhow::anyhow!(
+                "The number of dev accounts {} is above the maximum {}",
+                self.count,
+                MAX_DEV_ACCOUNTS
+            ))
+        }
+
+        (0..self.count)
+            .map(|index| {
+                SecretKey::new_from_mnemonic_phrase_with_path(
+                    &self.mnemonic,
+                    &Self::derivation_path(index),
+                )
+                .map_err(|e| {
+                    anyhow::anyhow!("Failed to derive the dev account {}: {:?}", index, e)
+                })
+            })
+            .collect()
+    }
+}
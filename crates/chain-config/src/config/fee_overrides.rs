@@ -0,0 +1,80 @@
+use anyhow::anyhow;
+use fuel_core_types::{
+    fuel_tx::ConsensusParameters,
+    fuel_types::Word,
+    fuel_vm::{
+        GasCosts,
+        GasCostsValues,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+
+/// Overrides of the intrinsic costs of the fee model on top of the
+/// `transaction_parameters` and the `gas_costs` of the chain config, so networks can
+/// tune their fee economics without repeating every parameter.
+///
+/// The fee of the transaction is the gas of its metered bytes plus its gas limit, priced
+/// by the gas price and divided by the gas price factor. The gas used by predicates and
+/// scripts is defined by the gas costs of operations.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct FeeOverrides {
+    /// The gas charged per metered byte of the transaction.
+    pub gas_per_byte: Option<Word>,
+    /// The divisor converting the fee in gas into the amount of the base asset.
+    pub gas_price_factor: Option<Word>,
+    /// The maximum gas limit of the transaction, which bounds its maximum fee.
+    pub max_gas_per_tx: Option<Word>,
+    /// The gas costs of operations by their name in the `gas_costs`, e.g. `"add": 2` or
+    /// `"call": { "base": 10, "dep_per_unit": 1 }`.
+    #[serde(default)]
+    pub gas_costs: BTreeMap<String, serde_json::Value>,
+}
+
+impl FeeOverrides {
+    /// Applies the overrides to the `params` and the `gas_costs`.
+    pub fn apply(
+        &self,
+        params: &mut ConsensusParameters,
+        gas_costs: &mut GasCosts,
+    ) -> anyhow::Result<()> {
+        if let Some(gas_per_byte) = self.gas_per_byte {
+            params.gas_per_byte = gas_per_byte;
+        }
+        if let Some(gas_price_factor) = self.gas_price_factor {
+            if gas_price_factor == 0 {
+                return Err(anyhow!("The `gas_price_factor` can't be zero"))
+            }
+            params.gas_price_factor = gas_price_factor;
+        }
+        if let Some(max_gas_per_tx) = self.max_gas_per_tx {
+            if max_gas_per_tx == 0 {
+                return Err(anyhow!("The `max_gas_per_tx` can't be zero"))
+            }
+            params.max_gas_per_tx = max_gas_per_tx;
+        }
+
+        if !self.gas_costs.is_empty() {
+            let values: GasCostsValues = gas_costs.clone().into();
+            let mut values = serde_json::to_value(values)?;
+            let values_map = values
+                .as_object_mut()
+                .ok_or_else(|| anyhow!("The gas costs are not a map"))?;
+            for (name, cost) in &self.gas_costs {
+                let value = values_map
+                    .get_mut(name)
+                    .ok_or_else(|| anyhow!("Unknown gas cost `{}`", name))?;
+                *value = cost.clone();
+            }
+            let values: GasCostsValues = serde_json::from_value(values)
+                .map_err(|e| anyhow!("Invalid gas cost override: {}", e))?;
+            *gas_costs = values.into();
+        }
+        Ok(())
+    }
+}
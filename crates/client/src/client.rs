@@ -12,7 +12,6 @@ use anyhow::Context;
 #[cfg(feature = "subscriptions")]
 use cynic::StreamingOperation;
 use cynic::{
-    http::ReqwestExt,
     GraphQlResponse,
     Id,
     MutationBuilder,
@@ -20,6 +19,7 @@ use cynic::{
     QueryBuilder,
 };
 use fuel_core_types::{
+    blockchain::SealedBlock,
     fuel_asm::{
         Instruction,
         RegisterId,
@@ -38,6 +38,11 @@ use fuel_core_types::{
 #[cfg(feature = "subscriptions")]
 use futures::StreamExt;
 use itertools::Itertools;
+use middleware::{
+    Middleware,
+    Middlewares,
+    ResponseInfo,
+};
 use reqwest::cookie::CookieStore;
 use schema::{
     balance::BalanceArgs,
@@ -49,10 +54,14 @@ use schema::{
     contract::{
         Contract,
         ContractByIdArgs,
+        ContractLogsArgs,
+        ContractStorageSlotArgs,
     },
     tx::{
+        TransactionsByBlockHeightArgs,
         TxArg,
         TxIdArgs,
+        TxStageTime,
     },
     Bytes,
     ContinueTx,
@@ -92,19 +101,29 @@ use std::{
         FromStr,
     },
     sync::Arc,
+    time::Instant,
 };
 use tai64::Tai64;
 use tracing as _;
 use types::{
+    BlockTransactionResponse,
+    ErrorExtensions,
+    ResponseError,
+    ResponseErrors,
     TransactionResponse,
     TransactionStatus,
 };
 
 use self::schema::{
     block::ProduceBlockArgs,
-    message::MessageProofArgs,
+    message::{
+        MessageProofArgs,
+        MessageProofsArgs,
+    },
 };
 
+pub mod documents;
+pub mod middleware;
 pub mod schema;
 pub mod types;
 
@@ -113,6 +132,7 @@ pub struct FuelClient {
     client: reqwest::Client,
     cookie: Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    middlewares: Middlewares,
 }
 
 impl FromStr for FuelClient {
@@ -135,6 +155,7 @@ impl FromStr for FuelClient {
             client,
             cookie,
             url,
+            middlewares: Default::default(),
         })
     }
 }
@@ -167,6 +188,39 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Adds the `middleware` that is called around every HTTP request of the client,
+    /// e.g. to add authentication or tracing headers. Middlewares are called in the
+    /// order of addition.
+    pub fn with_middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: Middleware + 'static,
+    {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sends the `request` with headers of middlewares and reports the outcome to them.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> io::Result<reqwest::Response> {
+        let request = request
+            .headers(self.middlewares.headers()?)
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let path = request.url().path().to_string();
+
+        let start = Instant::now();
+        let result = self.client.execute(request).await;
+        self.middlewares.observe(&ResponseInfo {
+            path,
+            status: result.as_ref().ok().map(|response| response.status()),
+            elapsed: start.elapsed(),
+        });
+
+        result.map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+
     async fn query<ResponseData, Vars>(
         &self,
         q: Operation<ResponseData, Vars>,
@@ -176,24 +230,48 @@ impl FuelClient {
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
         let response = self
-            .client
-            .post(self.url.clone())
-            .run_graphql(q)
+            .send(self.client.post(self.url.clone()).json(&q))
+            .await?;
+
+        Self::decode_http_response(response).await
+    }
+
+    async fn decode_http_response<R>(response: reqwest::Response) -> io::Result<R>
+    where
+        R: serde::de::DeserializeOwned + 'static,
+    {
+        let status = response.status();
+        let body = response
+            .text()
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        // The `cynic::http::ReqwestExt` ignores the `extensions` of the errors,
+        // so the response is decoded manually to preserve error codes.
+        let response = serde_json::from_str::<GraphQlResponse<R, ErrorExtensions>>(&body)
+            .map_err(|e| {
+                if status.is_success() {
+                    io::Error::new(io::ErrorKind::Other, e)
+                } else {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("Server returned {status}: {body}"),
+                    )
+                }
+            })?;
 
         Self::decode_response(response)
     }
 
-    fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
+    fn decode_response<R>(response: GraphQlResponse<R, ErrorExtensions>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
     {
         match (response.data, response.errors) {
             (Some(d), _) => Ok(d),
-            (_, Some(e)) => Err(from_strings_errors_to_std_error(
-                e.into_iter().map(|e| e.message).collect(),
-            )),
+            (_, Some(e)) => Err(ResponseErrors(
+                e.into_iter().map(ResponseError::from).collect(),
+            )
+            .into()),
             _ => Err(io::Error::new(io::ErrorKind::Other, "Invalid response")),
         }
     }
@@ -247,6 +325,21 @@ impl FuelClient {
                 })?;
         }
 
+        for (name, value) in self.middlewares.headers()?.iter() {
+            let value = value.to_str().map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("Unable convert header value to string {e:?}"),
+                )
+            })?;
+            client_builder = client_builder.header(name.as_str(), value).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("Failed to add header from the middleware to client {e:?}"),
+                )
+            })?;
+        }
+
         let client = client_builder.build_with_conn(
             hyper_rustls::HttpsConnectorBuilder::new()
                 .with_webpki_roots()
@@ -265,7 +358,9 @@ impl FuelClient {
                 tracing::debug!("Got result: {result:?}");
                 let r = match result {
                     Ok(es::SSE::Event(es::Event { data, .. })) => {
-                        match serde_json::from_str::<GraphQlResponse<ResponseData>>(&data)
+                        match serde_json::from_str::<
+                            GraphQlResponse<ResponseData, ErrorExtensions>,
+                        >(&data)
                         {
                             Ok(resp) => {
                                 match Self::decode_response(resp) {
@@ -282,10 +377,7 @@ impl FuelClient {
                                             _ => Some(Ok(resp)),
                                         }
                                     }
-                                    Err(e) => Some(Err(io::Error::new(
-                                        io::ErrorKind::Other,
-                                        format!("Decode error: {e:?}"),
-                                    ))),
+                                    Err(e) => Some(Err(e)),
                                 }
                             }
                             Err(e) => Some(Err(io::Error::new(
@@ -311,11 +403,59 @@ impl FuelClient {
         self.query(query).await.map(|r| r.health)
     }
 
+    /// Returns the health of every subsystem of the node.
+    pub async fn services_health(&self) -> io::Result<Vec<schema::ServiceHealth>> {
+        let query = schema::ServicesHealthQuery::build(());
+        self.query(query).await.map(|r| r.services_health)
+    }
+
     pub async fn node_info(&self) -> io::Result<schema::node_info::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info)
     }
 
+    /// Returns the build of the node and the hash of its consensus-relevant
+    /// configuration.
+    pub async fn build_info(&self) -> io::Result<schema::node_info::BuildInfo> {
+        let query = schema::node_info::QueryBuildInfo::build(());
+        self.query(query).await.map(|r| r.build_info)
+    }
+
+    /// Returns the version of the schema served by the node and its deprecated fields.
+    pub async fn schema_version(&self) -> io::Result<schema::node_info::SchemaVersion> {
+        let query = schema::node_info::QuerySchemaVersion::build(());
+        self.query(query).await.map(|r| r.schema_version)
+    }
+
+    /// Returns the latest statistics of the UTXO set if the node computes them.
+    pub async fn utxo_statistics(
+        &self,
+    ) -> io::Result<Option<schema::utxo_stats::UtxoStatistics>> {
+        let query = schema::utxo_stats::UtxoStatisticsQuery::build(());
+        self.query(query).await.map(|r| r.utxo_statistics)
+    }
+
+    /// Returns how long tracked transactions wait for the inclusion into blocks if the
+    /// node monitors the inclusion.
+    pub async fn inclusion_statistics(
+        &self,
+    ) -> io::Result<Option<schema::inclusion::InclusionStatistics>> {
+        let query = schema::inclusion::InclusionStatisticsQuery::build(());
+        self.query(query).await.map(|r| r.inclusion_statistics)
+    }
+
+    /// Returns how long the transaction waits or waited for the inclusion into the block
+    /// if the node monitors the inclusion and tracks the transaction.
+    pub async fn transaction_inclusion(
+        &self,
+        id: &str,
+    ) -> io::Result<Option<schema::inclusion::TransactionInclusion>> {
+        let query = schema::inclusion::TransactionInclusionQuery::build(TxIdArgs {
+            id: id.parse()?,
+        });
+        self.query(query).await.map(|r| r.transaction_inclusion)
+    }
+
     pub async fn chain_info(&self) -> io::Result<schema::chain::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain)
@@ -345,16 +485,85 @@ impl FuelClient {
             .collect()
     }
 
+    /// Returns the size and the fee breakdown of the transaction at the minimal gas
+    /// price of the node.
+    pub async fn transaction_cost(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<schema::tx::TransactionCost> {
+        let tx = tx.clone().to_bytes();
+        let query =
+            schema::tx::TransactionCostQuery::build(schema::tx::TransactionCostArgs {
+                tx: HexString(Bytes(tx)),
+            });
+        self.query(query).await.map(|r| r.transaction_cost)
+    }
+
     pub async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
+        self.submit_opt(tx, None).await
+    }
+
+    /// Submit with options to override the node behavior.
+    /// Requires the node to run in the debug mode.
+    pub async fn submit_opt(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<TransactionId> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::Submit::build(TxArg {
             tx: HexString(Bytes(tx)),
+            utxo_validation,
+            idempotency_key: None,
+        });
+
+        let id = self.query(query).await.map(|r| r.submit)?.id;
+        Ok(id)
+    }
+
+    /// Submits the transaction with the idempotency key. The retried submission with
+    /// the same key returns the id of the originally submitted transaction instead of
+    /// the error, so it is safe to retry it after network failures.
+    pub async fn submit_with_idempotency_key(
+        &self,
+        tx: &Transaction,
+        idempotency_key: &str,
+    ) -> io::Result<TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::Submit::build(TxArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation: None,
+            idempotency_key: Some(idempotency_key.to_string()),
         });
 
         let id = self.query(query).await.map(|r| r.submit)?.id;
         Ok(id)
     }
 
+    /// Submits the transaction in the binary form instead of the hex string inside of
+    /// the GraphQL document. Preferable for large transactions, like deployments of big
+    /// contracts.
+    pub async fn submit_binary(&self, tx: &Transaction) -> io::Result<TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let mut url = self.url.clone();
+        url.set_path("/v1/submit");
+        let response = self
+            .send(
+                self.client
+                    .post(url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+                    .body(tx),
+            )
+            .await?;
+
+        let id = Self::decode_http_response::<schema::tx::Submit>(response)
+            .await?
+            .submit
+            .id;
+        Ok(id)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Submit the transaction and wait for it to be included into a block.
     ///
@@ -485,6 +694,17 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Get the lifecycle stages of a recent transaction, requires the node in the debug mode
+    pub async fn transaction_timeline(
+        &self,
+        id: &str,
+    ) -> io::Result<Option<Vec<TxStageTime>>> {
+        let query =
+            schema::tx::TransactionTimelineQuery::build(TxIdArgs { id: id.parse()? });
+
+        Ok(self.query(query).await?.transaction_timeline)
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &str) -> io::Result<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: id.parse()? });
@@ -529,6 +749,26 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the pre-confirmations issued by the block producer of the node
+    pub async fn subscribe_pre_confirmations(
+        &self,
+    ) -> io::Result<
+        impl futures::Stream<Item = io::Result<schema::block::PreConfirmation>>,
+    > {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::PreConfirmationsSubscription::build(());
+
+        tracing::debug!("subscribing");
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|response| Ok(response?.pre_confirmations));
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -582,6 +822,40 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions of the block at the `height` with their
+    /// statuses. Receipts of transactions are fetched only if `with_receipts` is set.
+    /// Only the forward pagination is supported.
+    pub async fn transactions_by_block_height(
+        &self,
+        height: u32,
+        with_receipts: bool,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<BlockTransactionResponse, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "transactions of the block support only the forward pagination",
+            ))
+        }
+        let args = TransactionsByBlockHeightArgs::from((height.into(), request));
+
+        let transactions = if with_receipts {
+            let query = schema::tx::TransactionsByBlockHeightQuery::build(args);
+            self.query(query)
+                .await?
+                .transactions_by_block_height
+                .try_into()?
+        } else {
+            let query =
+                schema::tx::TransactionsWithoutReceiptsByBlockHeightQuery::build(args);
+            self.query(query)
+                .await?
+                .transactions_by_block_height
+                .try_into()?
+        };
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &str) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: id.parse()? });
 
@@ -605,11 +879,24 @@ impl FuelClient {
         &self,
         blocks_to_produce: u64,
         start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        self.produce_blocks_at_da_height(blocks_to_produce, start_timestamp, None)
+            .await
+    }
+
+    /// Produces blocks like [`Self::produce_blocks`], but at the pinned `da_height`
+    /// instead of the latest finalized DA height.
+    pub async fn produce_blocks_at_da_height(
+        &self,
+        blocks_to_produce: u64,
+        start_timestamp: Option<u64>,
+        da_height: Option<u64>,
     ) -> io::Result<BlockHeight> {
         let query = schema::block::BlockMutation::build(ProduceBlockArgs {
             blocks_to_produce: blocks_to_produce.into(),
             start_timestamp: start_timestamp
                 .map(|timestamp| Tai64Timestamp::from(Tai64(timestamp))),
+            da_height: da_height.map(Into::into),
         });
 
         let new_height = self.query(query).await?.produce_blocks;
@@ -640,6 +927,77 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Returns the version of the executor that produced the block at the `height`.
+    /// `None` if the block doesn't exist or its version isn't recorded.
+    pub async fn block_executor_version(&self, height: u64) -> io::Result<Option<u32>> {
+        let query = schema::block::BlockExecutorVersionQuery::build(BlockByHeightArgs {
+            height: Some(U64(height)),
+        });
+
+        let version = self
+            .query(query)
+            .await?
+            .block
+            .and_then(|block| block.executor_version);
+
+        Ok(version.map(Into::into))
+    }
+
+    /// Returns the template of the next block with the `gas_limit` at the `block_time`,
+    /// or at the current time of the node. Requires the node in the debug mode.
+    pub async fn next_block_template(
+        &self,
+        gas_limit: u64,
+        block_time: Option<u64>,
+    ) -> io::Result<schema::block::BlockTemplate> {
+        let query = schema::block::NextBlockTemplateQuery::build(
+            schema::block::NextBlockTemplateArgs {
+                gas_limit: gas_limit.into(),
+                block_time: block_time.map(|time| Tai64Timestamp::from(Tai64(time))),
+            },
+        );
+        self.query(query).await.map(|r| r.next_block_template)
+    }
+
+    /// Submits the `block` built from the block template and sealed by the caller.
+    /// Returns the header of the imported block. Requires the node in the debug mode.
+    pub async fn submit_block(
+        &self,
+        block: &SealedBlock,
+    ) -> io::Result<schema::block::Header> {
+        let block = postcard::to_stdvec(block)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let query =
+            schema::block::SubmitBlockMutation::build(schema::block::SubmitBlockArgs {
+                block: HexString(Bytes(block)),
+            });
+        self.query(query).await.map(|r| r.submit_block)
+    }
+
+    /// Downloads the receipts of all transactions of the block and verifies the
+    /// `MessageOut` receipts against the message receipt commitment of its header. The
+    /// header doesn't commit to other receipts, so the caller still trusts the node for
+    /// them. Returns `None` if the block doesn't exist.
+    pub async fn verified_block_receipts(
+        &self,
+        id: &str,
+    ) -> io::Result<Option<Vec<Receipt>>> {
+        let block = match self.block(id).await? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+
+        let mut receipts = vec![];
+        for tx in &block.transactions {
+            let tx_receipts = self.receipts(&tx.id.to_string()).await?;
+            receipts.extend(tx_receipts.unwrap_or_default());
+        }
+        crate::proofs::verify_receipts(&block.header, &receipts)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+        Ok(Some(receipts))
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -731,10 +1089,44 @@ impl FuelClient {
                 asset: asset_id,
             });
 
-        let balance = self.query(query).await.unwrap().contract_balance.amount;
+        let balance = self.query(query).await?.contract_balance.amount;
         Ok(balance.into())
     }
 
+    /// Returns the value of the storage slot of the contract, `None` if the slot is not set.
+    pub async fn contract_storage_slot(
+        &self,
+        id: &str,
+        key: &str,
+    ) -> io::Result<Option<schema::Bytes32>> {
+        let query =
+            schema::contract::ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+                contract: id.parse()?,
+                key: key.parse()?,
+            });
+
+        let value = self.query(query).await?.contract_storage_slot;
+        Ok(value)
+    }
+
+    /// Returns logs emitted by the contract in blocks from `start_height` to `end_height`
+    /// inclusive.
+    pub async fn contract_logs(
+        &self,
+        id: &str,
+        start_height: u32,
+        end_height: u32,
+    ) -> io::Result<Vec<schema::contract::ContractLog>> {
+        let query = schema::contract::ContractLogsQuery::build(ContractLogsArgs {
+            contract: id.parse()?,
+            start_height: start_height.into(),
+            end_height: end_height.into(),
+        });
+
+        let logs = self.query(query).await?.contract_logs;
+        Ok(logs)
+    }
+
     pub async fn balance(&self, owner: &str, asset_id: Option<&str>) -> io::Result<u64> {
         let owner: schema::Address = owner.parse()?;
         let asset_id: schema::AssetId = match asset_id {
@@ -812,6 +1204,37 @@ impl FuelClient {
 
         Ok(proof)
     }
+
+    /// Request merkle proofs of several output messages of the same transaction.
+    /// The proofs follow the order of `message_ids` and are `None` for messages that
+    /// can't be proven.
+    pub async fn message_proofs(
+        &self,
+        transaction_id: &str,
+        message_ids: &[&str],
+        commit_block_id: Option<&str>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Vec<Option<schema::message::MessageProof>>> {
+        let transaction_id: schema::TransactionId = transaction_id.parse()?;
+        let message_ids = message_ids
+            .iter()
+            .map(|message_id| message_id.parse())
+            .collect::<Result<Vec<schema::MessageId>, _>>()?;
+        let commit_block_id: Option<schema::BlockId> = commit_block_id
+            .map(|commit_block_id| commit_block_id.parse())
+            .transpose()?;
+        let commit_block_height = commit_block_height.map(Into::into);
+        let query = schema::message::MessageProofsQuery::build(MessageProofsArgs {
+            transaction_id,
+            message_ids,
+            commit_block_id,
+            commit_block_height,
+        });
+
+        let proofs = self.query(query).await?.message_proofs;
+
+        Ok(proofs)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
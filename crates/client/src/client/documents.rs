@@ -0,0 +1,425 @@
+//! GraphQL documents of all operations sent by the [`FuelClient`](super::FuelClient).
+//! The node in the allowlist mode executes only registered documents, so operators
+//! dump these documents into the allowlist directory of the node.
+//!
+//! The text of the document doesn't depend on values of variables, so operations are
+//! built with placeholder arguments.
+
+use super::{
+    schema,
+    schema::{
+        balance::BalanceArgs,
+        block::{
+            BlockByHeightArgs,
+            BlockByIdArgs,
+            NextBlockTemplateArgs,
+            ProduceBlockArgs,
+            SubmitBlockArgs,
+        },
+        coins::CoinByIdArgs,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractByIdArgs,
+            ContractLogsArgs,
+            ContractStorageSlotArgs,
+        },
+        message::{
+            MessageProofArgs,
+            MessageProofsArgs,
+        },
+        tx::{
+            DryRunArg,
+            TransactionCostArgs,
+            TxArg,
+            TxIdArgs,
+        },
+        Bytes,
+        ContinueTxArgs,
+        ExecuteArgs,
+        HexString,
+        IdArg,
+        MemoryArgs,
+        RegisterArgs,
+        SetBreakpointArgs,
+        SetSingleSteppingArgs,
+        StartTxArgs,
+        U32,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+use cynic::{
+    Id,
+    MutationBuilder,
+    Operation,
+    QueryBuilder,
+};
+
+/// Returns the name and the document of every operation of the client. Names are unique
+/// and match methods of the client that send the operation.
+pub fn client_documents() -> Vec<(&'static str, String)> {
+    let documents = vec![
+        query("health", schema::Health::build(())),
+        query("services_health", schema::ServicesHealthQuery::build(())),
+        query("node_info", schema::node_info::QueryNodeInfo::build(())),
+        query("build_info", schema::node_info::QueryBuildInfo::build(())),
+        query(
+            "schema_version",
+            schema::node_info::QuerySchemaVersion::build(()),
+        ),
+        query(
+            "utxo_statistics",
+            schema::utxo_stats::UtxoStatisticsQuery::build(()),
+        ),
+        query(
+            "inclusion_statistics",
+            schema::inclusion::InclusionStatisticsQuery::build(()),
+        ),
+        query(
+            "transaction_inclusion",
+            schema::inclusion::TransactionInclusionQuery::build(tx_id()),
+        ),
+        query("chain_info", schema::chain::ChainQuery::build(())),
+        query(
+            "dry_run",
+            schema::tx::DryRun::build(DryRunArg {
+                tx: hex_string(),
+                utxo_validation: None,
+                block_height: None,
+            }),
+        ),
+        query(
+            "transaction_cost",
+            schema::tx::TransactionCostQuery::build(TransactionCostArgs {
+                tx: hex_string(),
+            }),
+        ),
+        query(
+            "submit",
+            schema::tx::Submit::build(TxArg {
+                tx: hex_string(),
+                utxo_validation: None,
+                idempotency_key: None,
+            }),
+        ),
+        query("start_session", schema::StartSession::build(())),
+        query("end_session", schema::EndSession::build(id_arg())),
+        query("reset", schema::Reset::build(id_arg())),
+        query(
+            "execute",
+            schema::Execute::build(ExecuteArgs {
+                id: id(),
+                op: String::new(),
+            }),
+        ),
+        query(
+            "register",
+            schema::Register::build(RegisterArgs {
+                id: id(),
+                register: U64(0),
+            }),
+        ),
+        query(
+            "memory",
+            schema::Memory::build(MemoryArgs {
+                id: id(),
+                start: U64(0),
+                size: U64(0),
+            }),
+        ),
+        query(
+            "set_breakpoint",
+            schema::SetBreakpoint::build(SetBreakpointArgs {
+                id: id(),
+                bp: schema::Breakpoint {
+                    contract: Default::default(),
+                    pc: U64(0),
+                },
+            }),
+        ),
+        query(
+            "set_single_stepping",
+            schema::SetSingleStepping::build(SetSingleSteppingArgs {
+                id: id(),
+                enable: false,
+            }),
+        ),
+        query(
+            "start_tx",
+            schema::StartTx::build(StartTxArgs {
+                id: id(),
+                tx: String::new(),
+            }),
+        ),
+        query(
+            "continue_tx",
+            schema::ContinueTx::build(ContinueTxArgs { id: id() }),
+        ),
+        query("transaction", schema::tx::TransactionQuery::build(tx_id())),
+        query(
+            "transaction_timeline",
+            schema::tx::TransactionTimelineQuery::build(tx_id()),
+        ),
+        query(
+            "transactions",
+            schema::tx::TransactionsQuery::build(pagination().into()),
+        ),
+        query(
+            "transactions_by_owner",
+            schema::tx::TransactionsByOwnerQuery::build(
+                (Default::default(), pagination()).into(),
+            ),
+        ),
+        query(
+            "transactions_by_block_height",
+            schema::tx::TransactionsByBlockHeightQuery::build(
+                (U32(0), pagination()).into(),
+            ),
+        ),
+        query(
+            "transactions_without_receipts_by_block_height",
+            schema::tx::TransactionsWithoutReceiptsByBlockHeightQuery::build(
+                (U32(0), pagination()).into(),
+            ),
+        ),
+        query(
+            "produce_blocks",
+            schema::block::BlockMutation::build(ProduceBlockArgs {
+                start_timestamp: None,
+                blocks_to_produce: U64(0),
+                da_height: None,
+            }),
+        ),
+        query(
+            "block",
+            schema::block::BlockByIdQuery::build(BlockByIdArgs { id: None }),
+        ),
+        query(
+            "block_by_height",
+            schema::block::BlockByHeightQuery::build(BlockByHeightArgs { height: None }),
+        ),
+        query(
+            "block_executor_version",
+            schema::block::BlockExecutorVersionQuery::build(BlockByHeightArgs {
+                height: None,
+            }),
+        ),
+        query(
+            "next_block_template",
+            schema::block::NextBlockTemplateQuery::build(NextBlockTemplateArgs {
+                gas_limit: U64(0),
+                block_time: None,
+            }),
+        ),
+        query(
+            "submit_block",
+            schema::block::SubmitBlockMutation::build(SubmitBlockArgs {
+                block: hex_string(),
+            }),
+        ),
+        query(
+            "blocks",
+            schema::block::BlocksQuery::build(pagination().into()),
+        ),
+        query(
+            "coin",
+            schema::coins::CoinByIdQuery::build(CoinByIdArgs {
+                utxo_id: Default::default(),
+            }),
+        ),
+        query(
+            "coins",
+            schema::coins::CoinsQuery::build(
+                (Default::default(), Default::default(), pagination()).into(),
+            ),
+        ),
+        query(
+            "coins_to_spend",
+            schema::coins::CoinsToSpendQuery::build(
+                (Default::default(), vec![], None).into(),
+            ),
+        ),
+        query(
+            "contract",
+            schema::contract::ContractByIdQuery::build(ContractByIdArgs {
+                id: Default::default(),
+            }),
+        ),
+        query(
+            "contract_balance",
+            schema::contract::ContractBalanceQuery::build(ContractBalanceQueryArgs {
+                id: Default::default(),
+                asset: Default::default(),
+            }),
+        ),
+        query(
+            "contract_storage_slot",
+            schema::contract::ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+                contract: Default::default(),
+                key: Default::default(),
+            }),
+        ),
+        query(
+            "contract_logs",
+            schema::contract::ContractLogsQuery::build(ContractLogsArgs {
+                contract: Default::default(),
+                start_height: U32(0),
+                end_height: U32(0),
+            }),
+        ),
+        query(
+            "balance",
+            schema::balance::BalanceQuery::build(BalanceArgs {
+                owner: Default::default(),
+                asset_id: Default::default(),
+            }),
+        ),
+        query(
+            "balances",
+            schema::balance::BalancesQuery::build(
+                (Default::default(), pagination()).into(),
+            ),
+        ),
+        query(
+            "contract_balances",
+            schema::contract::ContractBalancesQuery::build(
+                (Default::default(), pagination()).into(),
+            ),
+        ),
+        query(
+            "messages",
+            schema::message::OwnedMessageQuery::build((None, pagination()).into()),
+        ),
+        query(
+            "message_proof",
+            schema::message::MessageProofQuery::build(MessageProofArgs {
+                transaction_id: Default::default(),
+                message_id: Default::default(),
+                commit_block_id: None,
+                commit_block_height: None,
+            }),
+        ),
+        query(
+            "message_proofs",
+            schema::message::MessageProofsQuery::build(MessageProofsArgs {
+                transaction_id: Default::default(),
+                message_ids: vec![],
+                commit_block_id: None,
+                commit_block_height: None,
+            }),
+        ),
+    ];
+    documents
+        .into_iter()
+        .chain(subscription_documents())
+        .collect()
+}
+
+#[cfg(feature = "subscriptions")]
+fn subscription_documents() -> Vec<(&'static str, String)> {
+    use cynic::SubscriptionBuilder;
+    vec![
+        subscription(
+            "subscribe_transaction_status",
+            schema::tx::StatusChangeSubscription::build(tx_id()),
+        ),
+        subscription(
+            "subscribe_pre_confirmations",
+            schema::block::PreConfirmationsSubscription::build(()),
+        ),
+    ]
+}
+
+#[cfg(not(feature = "subscriptions"))]
+fn subscription_documents() -> Vec<(&'static str, String)> {
+    vec![]
+}
+
+fn query<ResponseData, Vars>(
+    name: &'static str,
+    operation: Operation<ResponseData, Vars>,
+) -> (&'static str, String) {
+    (name, operation.query)
+}
+
+/// The streaming operation doesn't expose its document, so it is taken from the
+/// serialized request.
+#[cfg(feature = "subscriptions")]
+fn subscription<ResponseData, Vars: serde::Serialize>(
+    name: &'static str,
+    operation: cynic::StreamingOperation<ResponseData, Vars>,
+) -> (&'static str, String) {
+    let request =
+        serde_json::to_value(&operation).expect("The operation is serializable");
+    let document = request["query"]
+        .as_str()
+        .expect("The request contains the document");
+    (name, document.to_string())
+}
+
+fn id() -> Id {
+    Id::new("")
+}
+
+fn id_arg() -> IdArg {
+    IdArg { id: id() }
+}
+
+fn tx_id() -> TxIdArgs {
+    TxIdArgs {
+        id: Default::default(),
+    }
+}
+
+fn hex_string() -> HexString {
+    HexString(Bytes(vec![]))
+}
+
+fn pagination() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 0,
+        direction: PageDirection::Forward,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn names_of_documents_are_unique() {
+        let documents = client_documents();
+        let names: HashSet<_> = documents.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names.len(), documents.len());
+    }
+
+    /// Returns the types of operations built in the `source`, without their paths.
+    fn built_operations(source: &str) -> HashSet<&str> {
+        let mut prefixes: Vec<_> = source.split("::build(").collect();
+        // The text after the last operation.
+        prefixes.pop();
+        prefixes
+            .into_iter()
+            .filter_map(|prefix| {
+                prefix
+                    .rsplit(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .next()
+            })
+            .filter(|operation| !operation.is_empty())
+            .collect()
+    }
+
+    #[test]
+    fn every_operation_of_the_client_has_a_document() {
+        let documented = built_operations(include_str!("documents.rs"));
+        let sent = built_operations(include_str!("../client.rs"));
+
+        let mut missing: Vec<_> = sent.difference(&documented).collect();
+        missing.sort();
+        assert!(missing.is_empty(), "Missing documents: {missing:?}");
+        assert!(sent.contains("Submit"));
+    }
+}
@@ -0,0 +1,166 @@
+use reqwest::header::{
+    HeaderMap,
+    HeaderValue,
+    AUTHORIZATION,
+};
+use std::{
+    fmt,
+    io,
+    sync::Arc,
+    time::Duration,
+};
+
+/// The hook into HTTP requests of the `FuelClient`, e.g. to authenticate requests to
+/// the hosted node or to trace and measure them.
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent. Headers inserted into the `headers` are sent
+    /// with the request. The error aborts the request.
+    fn on_request(&self, _headers: &mut HeaderMap) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Called after the response to the request is received or the request failed.
+    /// Subscriptions are not observed.
+    fn on_response(&self, _response: &ResponseInfo) {}
+}
+
+/// The outcome of the HTTP request observed by the [`Middleware`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseInfo {
+    /// The path of the endpoint, e.g. `/graphql`.
+    pub path: String,
+    /// The status of the response. `None` if the request failed without the response.
+    pub status: Option<reqwest::StatusCode>,
+    /// The time from sending the request to receiving the headers of the response.
+    pub elapsed: Duration,
+}
+
+/// Adds the same headers to every request.
+#[derive(Debug, Clone, Default)]
+pub struct Headers(pub HeaderMap);
+
+impl Headers {
+    /// Adds the `Authorization: Bearer <token>` header.
+    pub fn bearer(token: &str) -> io::Result<Self> {
+        let mut value = HeaderValue::from_str(&format!("Bearer {token}"))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        value.set_sensitive(true);
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, value);
+        Ok(Self(headers))
+    }
+}
+
+impl Middleware for Headers {
+    fn on_request(&self, headers: &mut HeaderMap) -> io::Result<()> {
+        headers.extend(self.0.clone());
+        Ok(())
+    }
+}
+
+/// Middlewares of the client, applied in the order of addition.
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn Middleware>>);
+
+impl fmt::Debug for Middlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Middlewares").field(&self.0.len()).finish()
+    }
+}
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    /// Returns headers that middlewares add to the request.
+    pub(crate) fn headers(&self) -> io::Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        for middleware in &self.0 {
+            middleware.on_request(&mut headers)?;
+        }
+        Ok(headers)
+    }
+
+    pub(crate) fn observe(&self, response: &ResponseInfo) {
+        for middleware in &self.0 {
+            middleware.on_response(response);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        headers: Arc<Mutex<Vec<HeaderMap>>>,
+        responses: Arc<Mutex<Vec<ResponseInfo>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn on_request(&self, headers: &mut HeaderMap) -> io::Result<()> {
+            self.headers.lock().unwrap().push(headers.clone());
+            Ok(())
+        }
+
+        fn on_response(&self, response: &ResponseInfo) {
+            self.responses.lock().unwrap().push(response.clone());
+        }
+    }
+
+    struct Failing;
+
+    impl Middleware for Failing {
+        fn on_request(&self, _: &mut HeaderMap) -> io::Result<()> {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                "The request is refused",
+            ))
+        }
+    }
+
+    #[test]
+    fn middlewares_see_headers_of_previous_ones() {
+        let recorder = Recorder::default();
+        let mut middlewares = Middlewares::default();
+        middlewares.push(Arc::new(Headers::bearer("secret").unwrap()));
+        middlewares.push(Arc::new(recorder.clone()));
+
+        let headers = middlewares.headers().unwrap();
+
+        assert_eq!(headers[AUTHORIZATION], "Bearer secret");
+        assert!(headers[AUTHORIZATION].is_sensitive());
+        let recorded = recorder.headers.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0][AUTHORIZATION], "Bearer secret");
+    }
+
+    #[test]
+    fn failed_middleware_aborts_request() {
+        let mut middlewares = Middlewares::default();
+        middlewares.push(Arc::new(Failing));
+
+        assert!(middlewares.headers().is_err());
+    }
+
+    #[test]
+    fn every_middleware_observes_response() {
+        let (first, second) = (Recorder::default(), Recorder::default());
+        let mut middlewares = Middlewares::default();
+        middlewares.push(Arc::new(first.clone()));
+        middlewares.push(Arc::new(second.clone()));
+        let response = ResponseInfo {
+            path: "/graphql".to_string(),
+            status: Some(reqwest::StatusCode::OK),
+            elapsed: Duration::from_millis(1),
+        };
+
+        middlewares.observe(&response);
+
+        assert_eq!(*first.responses.lock().unwrap(), vec![response.clone()]);
+        assert_eq!(*second.responses.lock().unwrap(), vec![response]);
+    }
+}
@@ -24,10 +24,12 @@ pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod inclusion;
 pub mod message;
 pub mod node_info;
 pub mod primitives;
 pub mod tx;
+pub mod utxo_stats;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
@@ -35,6 +37,28 @@ pub struct Health {
     pub health: bool,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct ServicesHealthQuery {
+    pub services_health: Vec<ServiceHealth>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ServiceHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    pub reason: Option<String>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
 pub struct StartSession {
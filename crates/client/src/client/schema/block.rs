@@ -1,17 +1,26 @@
 use crate::client::{
     schema::{
         schema,
+        Address,
         BlockId,
         ConnectionArgs,
+        HexString,
         PageInfo,
         Signature,
         Tai64Timestamp,
+        TransactionId,
         U32,
         U64,
     },
     PaginatedResult,
 };
-use fuel_core_types::fuel_crypto;
+use fuel_core_types::{
+    blockchain::{
+        block::Block as FuelBlock,
+        consensus::poa,
+    },
+    fuel_crypto,
+};
 
 use super::{
     tx::TransactionIdFragment,
@@ -61,6 +70,25 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+/// Queries the executor version separately from the [`Block`], because nodes before
+/// the versions were recorded don't have the field.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockByHeightArgs"
+)]
+pub struct BlockExecutorVersionQuery {
+    #[arguments(height: $height)]
+    pub block: Option<BlockExecutorVersion>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockExecutorVersion {
+    pub executor_version: Option<U32>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -92,6 +120,7 @@ pub struct Block {
     pub id: BlockId,
     pub header: Header,
     pub consensus: Consensus,
+    pub pruned: bool,
     pub transactions: Vec<TransactionIdFragment>,
 }
 
@@ -105,6 +134,7 @@ pub struct BlockIdFragment {
 pub struct ProduceBlockArgs {
     pub start_timestamp: Option<Tai64Timestamp>,
     pub blocks_to_produce: U64,
+    pub da_height: Option<U64>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -114,10 +144,65 @@ pub struct ProduceBlockArgs {
     graphql_type = "Mutation"
 )]
 pub struct BlockMutation {
-    #[arguments(blocksToProduce: $blocks_to_produce, startTimestamp: $start_timestamp)]
+    #[arguments(
+        blocksToProduce: $blocks_to_produce,
+        startTimestamp: $start_timestamp,
+        daHeight: $da_height
+    )]
     pub produce_blocks: U32,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NextBlockTemplateArgs {
+    pub gas_limit: U64,
+    pub block_time: Option<Tai64Timestamp>,
+}
+
+/// Retrieves the template of the next block from the node in the debug mode
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "NextBlockTemplateArgs"
+)]
+pub struct NextBlockTemplateQuery {
+    #[arguments(gasLimit: $gas_limit, blockTime: $block_time)]
+    pub next_block_template: BlockTemplate,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockTemplate {
+    pub header: Header,
+    pub transactions: Vec<TransactionIdFragment>,
+    pub gas_used: U64,
+    pub raw_block: HexString,
+}
+
+impl BlockTemplate {
+    /// Decodes the unsealed block of the template, so it can be sealed and submitted.
+    pub fn block(&self) -> Result<FuelBlock, postcard::Error> {
+        postcard::from_bytes(&self.raw_block.0 .0)
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SubmitBlockArgs {
+    pub block: HexString,
+}
+
+/// Submits the sealed block built from the block template to the node in the debug mode
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitBlockArgs"
+)]
+pub struct SubmitBlockMutation {
+    #[arguments(block: $block)]
+    pub submit_block: Header,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Header {
@@ -130,6 +215,7 @@ pub struct Header {
     pub height: U32,
     pub prev_root: Bytes32,
     pub time: Tai64Timestamp,
+    pub unix_time: U64,
     pub application_hash: Bytes32,
 }
 
@@ -138,6 +224,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    PoAThresholdConsensus(PoAThresholdConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -157,8 +244,43 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+/// The seal of the block produced by the committee of the threshold PoA. The `committee`
+/// is the address of the committee of the chain config.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PoAThresholdConsensus {
+    pub committee: Address,
+    pub signatures: Vec<Signature>,
+}
+
+/// The promise of the block producer that transactions are selected into the block.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PreConfirmation {
+    pub transaction_ids: Vec<TransactionId>,
+    pub height: U32,
+    pub signature: Signature,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct PreConfirmationsSubscription {
+    pub pre_confirmations: PreConfirmation,
+}
+
+impl From<PreConfirmation> for poa::PreConfirmation {
+    fn from(value: PreConfirmation) -> Self {
+        Self {
+            tx_ids: value.transaction_ids.into_iter().map(Into::into).collect(),
+            height: value.height.into(),
+            signature: value.signature.into_signature(),
+        }
+    }
+}
+
 impl Block {
-    /// Returns the block producer public key, if any.
+    /// Returns the block producer public key, if any. Blocks sealed by the committee
+    /// don't have the single producer key.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
         let message = self.header.id.clone().into_message();
         match &self.consensus {
@@ -168,7 +290,7 @@ impl Block {
                 let producer_pub_key = signature.recover(&message);
                 producer_pub_key.ok()
             }
-            Consensus::Unknown => None,
+            Consensus::PoAThresholdConsensus(_) | Consensus::Unknown => None,
         }
     }
 }
@@ -176,6 +298,7 @@ impl Block {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::schema::Bytes;
 
     #[test]
     fn block_by_id_query_gql_output() {
@@ -195,12 +318,41 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn block_executor_version_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockExecutorVersionQuery::build(BlockByHeightArgs {
+            height: Some(U64(0)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
         let operation = BlockMutation::build(ProduceBlockArgs {
             blocks_to_produce: U64(0),
             start_timestamp: None,
+            da_height: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn next_block_template_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = NextBlockTemplateQuery::build(NextBlockTemplateArgs {
+            gas_limit: U64(1000),
+            block_time: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn submit_block_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = SubmitBlockMutation::build(SubmitBlockArgs {
+            block: HexString(Bytes(vec![])),
         });
         insta::assert_snapshot!(operation.query)
     }
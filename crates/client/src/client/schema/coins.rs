@@ -125,6 +125,7 @@ pub struct CoinEdge {
 pub struct Coin {
     pub amount: U64,
     pub block_created: U32,
+    pub tx_created_idx: U64,
     pub asset_id: AssetId,
     pub utxo_id: UtxoId,
     pub maturity: U32,
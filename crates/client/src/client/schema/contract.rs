@@ -1,11 +1,15 @@
 use crate::client::{
     schema::{
         schema,
+        tx::transparent_receipt::Receipt,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        TransactionId,
+        U32,
         U64,
     },
     PageDirection,
@@ -54,6 +58,49 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotArgs"
+)]
+pub struct ContractStorageSlotQuery {
+    #[arguments(contract: $contract, key: $key)]
+    pub contract_storage_slot: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractLogsArgs {
+    pub contract: ContractId,
+    pub start_height: U32,
+    pub end_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractLog {
+    pub block_height: U32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractLogsArgs"
+)]
+pub struct ContractLogsQuery {
+    #[arguments(contract: $contract, startHeight: $start_height, endHeight: $end_height)]
+    pub contract_logs: Vec<ContractLog>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -159,4 +206,14 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_storage_slot_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
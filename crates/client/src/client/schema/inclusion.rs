@@ -0,0 +1,72 @@
+use crate::client::schema::{
+    schema,
+    tx::TxIdArgs,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct InclusionStatistics {
+    pub pending: U64,
+    pub overdue: U64,
+    pub included: U64,
+    pub delayed: U64,
+    pub max_delay_ms: U64,
+    pub average_delay_ms: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct InclusionStatisticsQuery {
+    pub inclusion_statistics: Option<InclusionStatistics>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum InclusionStatus {
+    Pending,
+    Included,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionInclusion {
+    pub status: InclusionStatus,
+    pub delay_ms: U64,
+    pub delayed: bool,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionInclusionQuery {
+    #[arguments(id: $id)]
+    pub transaction_inclusion: Option<TransactionInclusion>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::schema::TransactionId;
+
+    #[test]
+    fn inclusion_statistics_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = InclusionStatisticsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_inclusion_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionInclusionQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -85,6 +85,22 @@ pub struct MessageProofQuery {
     pub message_proof: Option<MessageProof>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageProofsArgs"
+)]
+pub struct MessageProofsQuery {
+    #[arguments(
+        transactionId: $transaction_id,
+        messageIds: $message_ids,
+        commitBlockId: $commit_block_id,
+        commitBlockHeight: $commit_block_height
+    )]
+    pub message_proofs: Vec<Option<MessageProof>>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MerkleProof {
@@ -137,6 +153,20 @@ pub struct MessageProofArgs {
     pub commit_block_height: Option<U32>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageProofsArgs {
+    /// Transaction id that contains the output messages.
+    pub transaction_id: TransactionId,
+    /// Message ids of the output messages that require a proof.
+    pub message_ids: Vec<MessageId>,
+    /// The block id of the commitment block.
+    /// If it is `None`, the `commit_block_height` should be `Some`.
+    pub commit_block_id: Option<BlockId>,
+    /// The block height of the commitment block.
+    /// If it is `None`, the `commit_block_id` should be `Some`.
+    pub commit_block_height: Option<U32>,
+}
+
 impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnectionArgs {
     fn from(r: (Option<Address>, PaginationRequest<String>)) -> Self {
         match r.1.direction {
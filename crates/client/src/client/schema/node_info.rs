@@ -1,5 +1,7 @@
 use crate::client::schema::{
     schema,
+    Bytes32,
+    U32,
     U64,
 };
 
@@ -12,6 +14,7 @@ pub struct NodeInfo {
     pub max_tx: U64,
     pub max_depth: U64,
     pub node_version: String,
+    pub relayer_wallet_balance: Option<U64>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -20,6 +23,46 @@ pub struct QueryNodeInfo {
     pub node_info: NodeInfo,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BuildInfo {
+    pub version: String,
+    pub git_sha: String,
+    pub rustc_version: String,
+    pub features: Vec<String>,
+    pub config_hash: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryBuildInfo {
+    pub build_info: BuildInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DeprecatedField {
+    pub type_name: String,
+    pub field: String,
+    pub since: U32,
+    pub sunset: U32,
+    pub reason: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SchemaVersion {
+    pub version: U32,
+    pub deprecated_fields_enabled: bool,
+    pub deprecations: Vec<DeprecatedField>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QuerySchemaVersion {
+    pub schema_version: SchemaVersion,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +73,18 @@ mod tests {
         let operation = QueryNodeInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn build_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryBuildInfo::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn schema_version_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QuerySchemaVersion::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
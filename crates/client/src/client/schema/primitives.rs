@@ -8,6 +8,10 @@ use cynic::impl_scalar;
 use fuel_core_types::{
     fuel_tx::InstructionResult,
     fuel_types::BlockHeight,
+    time::{
+        tai64_to_unix,
+        unix_to_tai64,
+    },
 };
 use serde::{
     de::Error,
@@ -267,6 +271,7 @@ macro_rules! number_scalar {
 
 number_scalar!(U64, u64);
 number_scalar!(U32, u32);
+number_scalar!(U128, u128);
 
 impl From<U64> for InstructionResult {
     fn from(s: U64) -> Self {
@@ -301,12 +306,12 @@ impl_scalar!(Tai64Timestamp, schema::Tai64Timestamp);
 impl Tai64Timestamp {
     /// Convert Unix timestamp to `Tai64Timestamp`.
     pub fn from_unix(secs: i64) -> Self {
-        Tai64Timestamp(Tai64::from_unix(secs))
+        Tai64Timestamp(unix_to_tai64(secs))
     }
 
-    /// Convert `Tai64Timestamp` to unix timestamp.
+    /// Convert `Tai64Timestamp` to unix timestamp, the same way the node does.
     pub fn to_unix(self) -> i64 {
-        self.0.to_unix()
+        tai64_to_unix(self.0)
     }
 }
 
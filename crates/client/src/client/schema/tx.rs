@@ -9,8 +9,13 @@ use crate::client::{
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        U32,
+        U64,
+    },
+    types::{
+        BlockTransactionResponse,
+        TransactionResponse,
     },
-    types::TransactionResponse,
     PageDirection,
     PaginatedResult,
     PaginationRequest,
@@ -48,6 +53,37 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+/// Retrieves the lifecycle stages of the transaction from the node in the debug mode
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionTimelineQuery {
+    #[arguments(id: $id)]
+    pub transaction_timeline: Option<Vec<TxStageTime>>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TxStage {
+    Received,
+    Validated,
+    Gossiped,
+    Selected,
+    Executed,
+    Committed,
+    SqueezedOut,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxStageTime {
+    pub stage: TxStage,
+    pub time: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -82,6 +118,24 @@ impl TryFrom<TransactionConnection> for PaginatedResult<TransactionResponse, Str
     }
 }
 
+impl TryFrom<TransactionConnection>
+    for PaginatedResult<BlockTransactionResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: TransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<BlockTransactionResponse>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct TransactionEdge {
@@ -256,6 +310,89 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByBlockHeightArgs {
+    /// The height of the block
+    pub height: U32,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Retrieve the first n transactions of the block (forward pagination)
+    pub first: Option<i32>,
+}
+
+impl From<(U32, PaginationRequest<String>)> for TransactionsByBlockHeightArgs {
+    fn from(r: (U32, PaginationRequest<String>)) -> Self {
+        TransactionsByBlockHeightArgs {
+            height: r.0,
+            after: r.1.cursor,
+            first: Some(r.1.results as i32),
+        }
+    }
+}
+
+/// Retrieves transactions of the block with their statuses and receipts
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByBlockHeightArgs"
+)]
+pub struct TransactionsByBlockHeightQuery {
+    #[arguments(height: $height, after: $after, first: $first)]
+    pub transactions_by_block_height: TransactionConnection,
+}
+
+/// Retrieves transactions of the block with their statuses, but without receipts
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByBlockHeightArgs"
+)]
+pub struct TransactionsWithoutReceiptsByBlockHeightQuery {
+    #[arguments(height: $height, after: $after, first: $first)]
+    pub transactions_by_block_height: TransactionWithStatusConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "TransactionConnection", schema_path = "./assets/schema.sdl")]
+pub struct TransactionWithStatusConnection {
+    pub edges: Vec<TransactionWithStatusEdge>,
+    pub page_info: PageInfo,
+}
+
+impl TryFrom<TransactionWithStatusConnection>
+    for PaginatedResult<BlockTransactionResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: TransactionWithStatusConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<BlockTransactionResponse>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "TransactionEdge", schema_path = "./assets/schema.sdl")]
+pub struct TransactionWithStatusEdge {
+    pub cursor: String,
+    pub node: TransactionWithStatus,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Transaction", schema_path = "./assets/schema.sdl")]
+pub struct TransactionWithStatus {
+    pub raw_payload: HexString,
+    pub status: Option<TransactionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -272,6 +409,8 @@ pub struct StatusChangeSubscription {
 #[derive(cynic::QueryVariables)]
 pub struct TxArg {
     pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(cynic::QueryVariables)]
@@ -298,10 +437,47 @@ pub struct DryRun {
     variables = "TxArg"
 )]
 pub struct Submit {
-    #[arguments(tx: $tx)]
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation, idempotencyKey: $idempotency_key)]
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct TransactionCostArgs {
+    pub tx: HexString,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionLimit {
+    pub parameter: String,
+    pub value: U64,
+    pub limit: U64,
+    pub exceeded: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionCost {
+    pub serialized_size: U64,
+    pub metered_bytes: U64,
+    pub gas_price: U64,
+    pub base_fee: U64,
+    pub max_fee: U64,
+    pub limits: Vec<TransactionLimit>,
+}
+
+/// Retrieves the size and the fee breakdown of the transaction
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionCostArgs"
+)]
+pub struct TransactionCostQuery {
+    #[arguments(tx: $tx)]
+    pub transaction_cost: TransactionCost,
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -352,6 +528,31 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transactions_by_block_height_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByBlockHeightQuery::build(TransactionsByBlockHeightArgs {
+                height: U32(0),
+                after: None,
+                first: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transactions_without_receipts_by_block_height_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionsWithoutReceiptsByBlockHeightQuery::build(
+            TransactionsByBlockHeightArgs {
+                height: U32(0),
+                after: None,
+                first: None,
+            },
+        );
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -363,12 +564,24 @@ pub mod tests {
         insta::assert_snapshot!(query.query)
     }
 
+    #[test]
+    fn transaction_cost_gql_output() {
+        use cynic::QueryBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let query = TransactionCostQuery::build(TransactionCostArgs {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
         let mut tx = fuel_tx::Transaction::default();
         let query = Submit::build(TxArg {
             tx: HexString(Bytes(tx.to_bytes())),
+            utxo_validation: None,
+            idempotency_key: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -0,0 +1,49 @@
+use crate::client::schema::{
+    schema,
+    AssetId,
+    U128,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct UtxoStatistics {
+    pub height: U32,
+    pub count: U64,
+    pub assets: Vec<AssetStatistics>,
+    pub histogram: Vec<AmountBucket>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetStatistics {
+    pub asset_id: AssetId,
+    pub count: U64,
+    pub total_amount: U128,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AmountBucket {
+    pub max_amount: U64,
+    pub count: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct UtxoStatisticsQuery {
+    pub utxo_statistics: Option<UtxoStatistics>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utxo_statistics_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = UtxoStatisticsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
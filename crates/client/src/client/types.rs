@@ -2,20 +2,34 @@ use crate::client::schema::{
     tx::{
         OpaqueTransaction,
         TransactionStatus as SchemaTxStatus,
+        TransactionWithStatus,
     },
     ConversionError,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Receipt,
+        Transaction,
+    },
     fuel_types::bytes::Deserializable,
     fuel_vm::ProgramState,
+    time::tai64_to_unix,
 };
 use serde::{
     Deserialize,
     Serialize,
 };
+use std::{
+    fmt,
+    io,
+};
 use tai64::Tai64;
 
+pub use fuel_core_types::services::graphql_api::{
+    ErrorCode,
+    PanicDetails,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionResponse {
     pub transaction: Transaction,
@@ -43,6 +57,21 @@ pub enum TransactionStatus {
     },
 }
 
+impl TransactionStatus {
+    /// Returns the time of the status in seconds since the unix epoch. The squeezed out
+    /// transaction has no time.
+    pub fn unix_time(&self) -> Option<i64> {
+        match self {
+            TransactionStatus::Submitted { submitted_at } => {
+                Some(tai64_to_unix(*submitted_at))
+            }
+            TransactionStatus::Success { time, .. }
+            | TransactionStatus::Failure { time, .. } => Some(tai64_to_unix(*time)),
+            TransactionStatus::SqueezedOut { .. } => None,
+        }
+    }
+}
+
 impl TryFrom<SchemaTxStatus> for TransactionStatus {
     type Error = ConversionError;
 
@@ -90,3 +119,131 @@ impl TryFrom<OpaqueTransaction> for TransactionResponse {
         })
     }
 }
+
+/// The transaction of the block with its status and, if requested, its receipts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockTransactionResponse {
+    pub transaction: Transaction,
+    pub status: TransactionStatus,
+    pub receipts: Option<Vec<Receipt>>,
+}
+
+impl TryFrom<OpaqueTransaction> for BlockTransactionResponse {
+    type Error = ConversionError;
+
+    fn try_from(mut value: OpaqueTransaction) -> Result<Self, Self::Error> {
+        let receipts = value
+            .receipts
+            .take()
+            .map(|receipts| {
+                receipts
+                    .into_iter()
+                    .map(TryInto::<Receipt>::try_into)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let TransactionResponse {
+            transaction,
+            status,
+        } = value.try_into()?;
+
+        Ok(Self {
+            transaction,
+            status,
+            receipts,
+        })
+    }
+}
+
+impl TryFrom<TransactionWithStatus> for BlockTransactionResponse {
+    type Error = ConversionError;
+
+    fn try_from(value: TransactionWithStatus) -> Result<Self, Self::Error> {
+        let bytes = value.raw_payload.0 .0;
+        let transaction = Transaction::from_bytes(bytes.as_slice())
+            .map_err(ConversionError::TransactionFromBytesError)?;
+        let status = value
+            .status
+            .ok_or_else(|| ConversionError::MissingField("status".to_string()))?
+            .try_into()?;
+
+        Ok(Self {
+            transaction,
+            status,
+            receipts: None,
+        })
+    }
+}
+
+/// The `extensions` of the GraphQL error returned by the node.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorExtensions {
+    pub code: Option<String>,
+    pub reason: Option<String>,
+    pub valid_at_height: Option<u32>,
+    pub valid_at_da_height: Option<u64>,
+}
+
+/// The error returned by the node in the GraphQL response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseError {
+    pub message: String,
+    /// The code is `None` if the node didn't provide it or it is unknown to the client.
+    pub code: Option<ErrorCode>,
+    pub reason: Option<String>,
+    /// The height of the first block that can include the transaction rejected because
+    /// of immature coins.
+    pub valid_at_height: Option<u32>,
+    /// The DA height from which blocks can include the transaction rejected because of
+    /// messages that are not relayed yet.
+    pub valid_at_da_height: Option<u64>,
+}
+
+impl From<cynic::GraphQlError<ErrorExtensions>> for ResponseError {
+    fn from(error: cynic::GraphQlError<ErrorExtensions>) -> Self {
+        let extensions = error.extensions.unwrap_or_default();
+        Self {
+            message: error.message,
+            code: extensions.code.and_then(|code| code.parse().ok()),
+            reason: extensions.reason,
+            valid_at_height: extensions.valid_at_height,
+            valid_at_da_height: extensions.valid_at_da_height,
+        }
+    }
+}
+
+/// All errors returned by the node in the GraphQL response.
+///
+/// The client returns it inside of the [`io::Error`], use
+/// [`ResponseErrors::from_io_error`] to access it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct ResponseErrors(pub Vec<ResponseError>);
+
+impl ResponseErrors {
+    /// Returns the response errors if the `error` was caused by them.
+    pub fn from_io_error(error: &io::Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref::<Self>()
+    }
+
+    /// Returns `true` if any of the errors has the `code`.
+    pub fn has_code(&self, code: ErrorCode) -> bool {
+        self.0.iter().any(|error| error.code == Some(code))
+    }
+}
+
+impl fmt::Display for ResponseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Response errors")?;
+        for error in self.0.iter() {
+            write!(f, "; {}", error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ResponseErrors> for io::Error {
+    fn from(errors: ResponseErrors) -> Self {
+        io::Error::new(io::ErrorKind::Other, errors)
+    }
+}
@@ -1,4 +1,5 @@
 #![deny(unused_crate_dependencies)]
 pub mod client;
+pub mod proofs;
 #[cfg(feature = "dap")]
 pub mod schema;
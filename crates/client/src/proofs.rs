@@ -0,0 +1,329 @@
+//! Verification of the proofs returned by the node.
+//!
+//! The functions are pure: they only trust the block id provided by the caller and
+//! recompute every other hash from the returned data, following the same rules the
+//! node uses to produce the proofs.
+
+use crate::client::schema::{
+    block::Header,
+    message::{
+        MerkleProof,
+        MessageProof,
+    },
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_crypto::Hasher,
+    fuel_merkle,
+    fuel_tx::{
+        input::message::compute_message_id,
+        Receipt,
+    },
+    fuel_types::{
+        Bytes32,
+        MessageId,
+    },
+};
+use std::ops::Deref;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProofError {
+    #[error("the fields of the header don't hash to its id {0}")]
+    InvalidHeader(Bytes32),
+    #[error("the commit block {actual} is not the trusted block {expected}")]
+    UntrustedCommitBlock { expected: Bytes32, actual: Bytes32 },
+    #[error(
+        "the message block {message_height} is not below the commit block {commit_height}"
+    )]
+    BlockNotCommitted {
+        message_height: u32,
+        commit_height: u32,
+    },
+    #[error("the message {0} is not included in the message block")]
+    InvalidMessageProof(MessageId),
+    #[error("the message block {0} is not included in the history of the commit block")]
+    InvalidBlockProof(Bytes32),
+    #[error("the receipts don't match the message receipt root of the block {0}")]
+    InvalidReceipts(Bytes32),
+}
+
+/// Computes the id of the block from the fields of its header.
+pub fn header_id(header: &Header) -> Bytes32 {
+    // Order matters and is the same as the spec.
+    let mut hasher = Hasher::default();
+    hasher.input(header.da_height.0.to_be_bytes());
+    hasher.input(header.transactions_count.0.to_be_bytes());
+    hasher.input(header.message_receipt_count.0.to_be_bytes());
+    hasher.input(Bytes32::from(header.transactions_root.clone()).as_ref());
+    hasher.input(Bytes32::from(header.message_receipt_root.clone()).as_ref());
+    let application_hash = hasher.digest();
+
+    let mut hasher = Hasher::default();
+    hasher.input(Bytes32::from(header.prev_root.clone()).as_ref());
+    hasher.input(header.height.0.to_be_bytes());
+    hasher.input(header.time.0 .0.to_be_bytes());
+    hasher.input(application_hash.as_ref());
+    hasher.digest()
+}
+
+/// Verifies that the fields of the `header` hash to its id and returns the id.
+pub fn verify_header(header: &Header) -> Result<Bytes32, ProofError> {
+    let id = header_id(header);
+    let claimed = Bytes32::from(header.id.clone());
+    if id != claimed {
+        return Err(ProofError::InvalidHeader(claimed))
+    }
+    Ok(id)
+}
+
+/// Verifies that the `MessageOut` receipts among the `receipts` of all transactions of
+/// the block, in the order of execution, match the message receipt commitment of its
+/// `header`. The header doesn't commit to other receipts, so they are not verified.
+pub fn verify_receipts(header: &Header, receipts: &[Receipt]) -> Result<(), ProofError> {
+    let id = verify_header(header)?;
+    let message_receipt_count = receipts
+        .iter()
+        .filter(|receipt| receipt.message_id().is_some())
+        .count() as u64;
+    let root = Block::recompute_receipts_root(receipts);
+    if message_receipt_count != header.message_receipt_count.0
+        || root != Bytes32::from(header.message_receipt_root.clone())
+    {
+        return Err(ProofError::InvalidReceipts(id))
+    }
+    Ok(())
+}
+
+/// Verifies that the `leaf` is the leaf of the binary merkle tree with `leaf_count`
+/// leaves and the `root`.
+pub fn verify_merkle_proof(
+    root: &Bytes32,
+    leaf: &[u8],
+    proof: &MerkleProof,
+    leaf_count: u64,
+) -> bool {
+    let proof_set: Vec<[u8; 32]> = proof
+        .proof_set
+        .iter()
+        .map(|node| *Bytes32::from(node.clone()))
+        .collect();
+    fuel_merkle::binary::verify(
+        root.deref(),
+        leaf,
+        &proof_set,
+        proof.proof_index.0,
+        leaf_count,
+    )
+}
+
+/// Verifies the `proof` of the message against the `trusted_block_id` of the commit
+/// block and returns the id of the proven message.
+pub fn verify_message_proof(
+    proof: &MessageProof,
+    trusted_block_id: &Bytes32,
+) -> Result<MessageId, ProofError> {
+    let commit_block_id = verify_header(&proof.commit_block_header)?;
+    if commit_block_id != *trusted_block_id {
+        return Err(ProofError::UntrustedCommitBlock {
+            expected: *trusted_block_id,
+            actual: commit_block_id,
+        })
+    }
+
+    let message_block_id = verify_header(&proof.message_block_header)?;
+    let message_height = proof.message_block_header.height.0;
+    let commit_height = proof.commit_block_header.height.0;
+    if message_height >= commit_height {
+        return Err(ProofError::BlockNotCommitted {
+            message_height,
+            commit_height,
+        })
+    }
+
+    let message_id = compute_message_id(
+        &proof.sender.clone().into(),
+        &proof.recipient.clone().into(),
+        &proof.nonce.clone().into(),
+        proof.amount.0,
+        &proof.data,
+    );
+    if !verify_merkle_proof(
+        &proof.message_block_header.message_receipt_root.clone().into(),
+        message_id.as_ref(),
+        &proof.message_proof,
+        proof.message_block_header.message_receipt_count.0,
+    ) {
+        return Err(ProofError::InvalidMessageProof(message_id))
+    }
+
+    // The history of the commit block contains all blocks below it.
+    if !verify_merkle_proof(
+        &proof.commit_block_header.prev_root.clone().into(),
+        message_block_id.as_ref(),
+        &proof.block_proof,
+        commit_height as u64,
+    ) {
+        return Err(ProofError::InvalidBlockProof(message_block_id))
+    }
+
+    Ok(message_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::schema::{
+        primitives::{
+            Bytes,
+            Tai64Timestamp,
+        },
+        HexString,
+        U32,
+        U64,
+    };
+    use fuel_core_types::fuel_merkle::binary::in_memory::MerkleTree;
+
+    fn header(height: u32, prev_root: Bytes32, message_receipt_root: Bytes32) -> Header {
+        let mut header = Header {
+            id: Default::default(),
+            da_height: U64(7),
+            transactions_count: U64(1),
+            message_receipt_count: U64(2),
+            transactions_root: Bytes32::from([1; 32]).into(),
+            message_receipt_root: message_receipt_root.into(),
+            height: U32(height),
+            prev_root: prev_root.into(),
+            time: Tai64Timestamp::from_unix(1_000 + height as i64),
+            unix_time: U64(1_000 + height as u64),
+            application_hash: Default::default(),
+        };
+        header.id = header_id(&header).into();
+        header
+    }
+
+    fn proof(index: u64, tree_leaves: &[&[u8]]) -> (Bytes32, MerkleProof) {
+        let mut tree = MerkleTree::new();
+        for leaf in tree_leaves {
+            tree.push(leaf);
+        }
+        let (root, proof_set) = tree.prove(index).unwrap();
+        let proof = MerkleProof {
+            proof_set: proof_set
+                .into_iter()
+                .map(|node| Bytes32::from(node).into())
+                .collect(),
+            proof_index: U64(index),
+        };
+        (root.into(), proof)
+    }
+
+    fn message_proof() -> MessageProof {
+        let sender = fuel_core_types::fuel_types::Address::from([2; 32]);
+        let recipient = fuel_core_types::fuel_types::Address::from([3; 32]);
+        let nonce = fuel_core_types::fuel_types::Nonce::from([4; 32]);
+        let data = vec![5, 6, 7];
+        let message_id = compute_message_id(&sender, &recipient, &nonce, 100, &data);
+        let other_message_id = MessageId::from([8; 32]);
+        let (message_receipt_root, message_proof) =
+            proof(1, &[other_message_id.as_ref(), message_id.as_ref()]);
+
+        let genesis = header(0, Bytes32::zeroed(), Bytes32::zeroed());
+        let genesis_id = header_id(&genesis);
+        let message_block = header(1, Bytes32::zeroed(), message_receipt_root);
+        let message_block_id = header_id(&message_block);
+        let (history_root, block_proof) =
+            proof(1, &[genesis_id.as_ref(), message_block_id.as_ref()]);
+        let commit_block = header(2, history_root, Bytes32::zeroed());
+
+        MessageProof {
+            message_proof,
+            block_proof,
+            message_block_header: message_block,
+            commit_block_header: commit_block,
+            sender: sender.into(),
+            recipient: recipient.into(),
+            nonce: nonce.into(),
+            amount: U64(100),
+            data: HexString(Bytes(data)),
+        }
+    }
+
+    fn message_out(nonce: u8) -> Receipt {
+        Receipt::message_out(
+            Default::default(),
+            Default::default(),
+            100,
+            [nonce; 32].into(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn verify_receipts_checks_message_receipt_root() {
+        let receipts = vec![
+            message_out(1),
+            Receipt::ret(Default::default(), 0, 0, 0),
+            message_out(2),
+        ];
+        let header = header(
+            1,
+            Bytes32::zeroed(),
+            Block::recompute_receipts_root(&receipts),
+        );
+        let mut reordered = receipts.clone();
+        reordered.swap(0, 2);
+
+        assert_eq!(verify_receipts(&header, &receipts), Ok(()));
+        assert!(matches!(
+            verify_receipts(&header, &reordered),
+            Err(ProofError::InvalidReceipts(_))
+        ));
+        assert!(matches!(
+            verify_receipts(&header, &receipts[..1]),
+            Err(ProofError::InvalidReceipts(_))
+        ));
+    }
+
+    #[test]
+    fn verify_message_proof_accepts_valid_proof() {
+        let proof = message_proof();
+        let trusted = Bytes32::from(proof.commit_block_header.id.clone());
+
+        let result = verify_message_proof(&proof, &trusted);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn verify_message_proof_rejects_untrusted_commit_block() {
+        let proof = message_proof();
+
+        let result = verify_message_proof(&proof, &Bytes32::zeroed());
+
+        assert!(matches!(result, Err(ProofError::UntrustedCommitBlock { .. })));
+    }
+
+    #[test]
+    fn verify_message_proof_rejects_modified_message() {
+        let mut proof = message_proof();
+        let trusted = Bytes32::from(proof.commit_block_header.id.clone());
+        proof.amount = U64(101);
+
+        let result = verify_message_proof(&proof, &trusted);
+
+        assert!(matches!(result, Err(ProofError::InvalidMessageProof(_))));
+    }
+
+    #[test]
+    fn verify_message_proof_rejects_modified_header() {
+        let mut proof = message_proof();
+        let trusted = Bytes32::from(proof.commit_block_header.id.clone());
+        proof.message_block_header.message_receipt_count = U64(3);
+
+        let result = verify_message_proof(&proof, &trusted);
+
+        assert!(matches!(result, Err(ProofError::InvalidHeader(_))));
+    }
+}
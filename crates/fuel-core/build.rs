@@ -0,0 +1,48 @@
+use std::{
+    env,
+    process::Command,
+};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=FUEL_CORE_GIT_SHA");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+
+    // The SHA changes with the checkout or a new commit.
+    if let Some(git_dir) = output("git", &["rev-parse", "--git-dir"]) {
+        for path in ["HEAD", "refs", "packed-refs"] {
+            println!("cargo:rerun-if-changed={git_dir}/{path}");
+        }
+    }
+    // Reproducible builds don't have the git repository, so the SHA can be provided
+    // by the environment.
+    let git_sha = env::var("FUEL_CORE_GIT_SHA")
+        .ok()
+        .or_else(|| output("git", &["rev-parse", "HEAD"]))
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=FUEL_CORE_GIT_SHA={git_sha}");
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version =
+        output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=FUEL_CORE_RUSTC_VERSION={rustc_version}");
+
+    // Sorted, so the same set of features always produces the same value.
+    let mut features: Vec<_> = env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .map(|feature| feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+    println!("cargo:rustc-env=FUEL_CORE_FEATURES={}", features.join(","));
+}
+
+/// Returns the trimmed standard output of the successful command.
+fn output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None
+    }
+    let output = String::from_utf8(output.stdout).ok()?;
+    Some(output.trim().to_string())
+}
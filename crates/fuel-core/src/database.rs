@@ -1,10 +1,19 @@
 use crate::{
     database::transaction::DatabaseTransaction,
+    service::slow_log::SlowLog,
     state::{
+        change_capture::{
+            ChangeCaptureStore,
+            ChangeFeed,
+        },
         in_memory::memory_store::MemoryStore,
+        slow_log::SlowLogStore,
         DataSource,
+        WriteOperation,
     },
 };
+#[cfg(any(test, feature = "test-helpers"))]
+use crate::state::in_memory::memory_store::MemorySnapshot;
 use fuel_core_chain_config::{
     ChainConfigDb,
     CoinConfig,
@@ -56,16 +65,18 @@ mod block;
 mod code_root;
 mod contracts;
 mod message;
+mod pruning;
 mod receipts;
-#[cfg(feature = "relayer")]
-mod relayer;
 mod sealed_block;
 mod state;
 
 pub(crate) mod coin;
 
 pub mod balances;
+pub mod bloom;
 pub mod metadata;
+#[cfg(feature = "relayer")]
+pub mod relayer;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
@@ -130,6 +141,10 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// See [`FuelBlockBlooms`](storage::FuelBlockBlooms)
+    FuelBlockBlooms = 25,
+    /// See [`FuelBlockExecutorVersions`](storage::FuelBlockExecutorVersions)
+    FuelBlockExecutorVersions = 26,
 }
 
 impl Column {
@@ -207,9 +222,42 @@ impl Database {
         }
     }
 
+    /// Publishes every write to the database into the `feed`. Writes via clones of the
+    /// database created before the call are not captured.
+    pub fn with_change_capture(self, feed: ChangeFeed) -> DatabaseResult<Self> {
+        let store = ChangeCaptureStore::new(self.data, feed)?;
+        Ok(Self {
+            data: Arc::new(store),
+            _drop: self._drop,
+        })
+    }
+
+    /// Records slow commits into the `slow_log` and counts rows read by GraphQL
+    /// requests. Clones of the database created before the call are not tracked.
+    pub fn with_slow_log(self, slow_log: SlowLog) -> Self {
+        Self {
+            data: Arc::new(SlowLogStore::new(self.data, slow_log)),
+            _drop: self._drop,
+        }
+    }
+
     pub fn transaction(&self) -> DatabaseTransaction {
         self.into()
     }
+
+    /// Returns the copy of the uncommitted state of the transaction on top of the same
+    /// storage. The copy isn't affected by the commit of the transaction, so it can be
+    /// read while the transaction is committed. The database that isn't a transaction
+    /// is shared as is.
+    pub fn fork(&self) -> DatabaseResult<Self> {
+        match self.data.fork() {
+            Some(data) => Ok(Self {
+                data: data?,
+                _drop: self._drop.clone(),
+            }),
+            None => Ok(self.clone()),
+        }
+    }
 }
 
 /// Mutable methods.
@@ -266,6 +314,38 @@ impl Database {
             .take(key, column)
             .map(|value| value.map(|value| value.deref().clone()))
     }
+
+    /// Removes all entries of all columns, including the metadata. The metadata is
+    /// removed last, so the interrupted wipe leaves the database marked as the database
+    /// of the previous chain, and the wipe is repeated on the next start.
+    pub fn wipe(&self) -> DatabaseResult<()> {
+        let columns = enum_iterator::all::<Column>()
+            .filter(|column| *column != Column::Metadata)
+            .chain(core::iter::once(Column::Metadata));
+        for column in columns {
+            self.wipe_column(column)?;
+        }
+        Ok(())
+    }
+
+    fn wipe_column(&self, column: Column) -> DatabaseResult<()> {
+        const BATCH_SIZE: usize = 10_000;
+        loop {
+            let keys = self
+                .data
+                .iter_all(column, None, None, IterDirection::Forward)
+                .take(BATCH_SIZE)
+                .map(|item| item.map(|(key, _)| key))
+                .collect::<DatabaseResult<Vec<_>>>()?;
+            if keys.is_empty() {
+                return Ok(())
+            }
+            let mut removals = keys
+                .into_iter()
+                .map(|key| (key, column, WriteOperation::Remove));
+            self.data.batch_write(&mut removals)?;
+        }
+    }
 }
 
 /// Read-only methods.
@@ -374,6 +454,44 @@ impl Database {
     }
 }
 
+/// Checkpoints of the state for tests.
+#[cfg(any(test, feature = "test-helpers"))]
+impl Database {
+    /// Returns the copy of all columns of the database.
+    pub fn snapshot(&self) -> DatabaseResult<MemorySnapshot> {
+        let mut snapshot = MemorySnapshot::default();
+        for column in enum_iterator::all::<Column>() {
+            snapshot.inner[column.as_usize()] = self
+                .data
+                .iter_all(column, None, None, IterDirection::Forward)
+                .collect::<DatabaseResult<_>>()?;
+        }
+        Ok(snapshot)
+    }
+
+    /// Replaces the content of the database with the `snapshot`.
+    pub fn restore(&self, snapshot: &MemorySnapshot) -> DatabaseResult<()> {
+        for column in enum_iterator::all::<Column>() {
+            let mut entries = self
+                .data
+                .iter_all(column, None, None, IterDirection::Forward)
+                .map(|item| item.map(|(key, _)| (key, column, WriteOperation::Remove)))
+                .collect::<DatabaseResult<Vec<_>>>()?;
+            entries.extend(snapshot.inner[column.as_usize()].iter().map(
+                |(key, value)| (key.clone(), column, WriteOperation::Insert(value.clone())),
+            ));
+            self.data.batch_write(&mut entries.into_iter())?;
+        }
+        Ok(())
+    }
+
+    /// Creates the new in-memory database with the content of the `snapshot`.
+    /// It allows to branch several scenarios from the same checkpoint.
+    pub fn from_snapshot(snapshot: MemorySnapshot) -> Self {
+        Self::new(Arc::new(MemoryStore::from(snapshot)))
+    }
+}
+
 impl Transactional for Database {
     type Storage = Database;
 
@@ -457,3 +575,93 @@ fn column_keys_not_exceed_count() {
         assert!(column.as_usize() < Column::COUNT);
     }
 }
+
+#[test]
+fn restore_reverts_changes_after_snapshot() {
+    let db = Database::in_memory();
+    db.write(b"kept", Column::Metadata, b"before").unwrap();
+    db.write(b"changed", Column::Coins, b"before").unwrap();
+    let snapshot = db.snapshot().unwrap();
+
+    db.write(b"changed", Column::Coins, b"after").unwrap();
+    db.write(b"added", Column::Messages, b"after").unwrap();
+    db.restore(&snapshot).unwrap();
+
+    assert_eq!(
+        db.read_alloc(b"kept", Column::Metadata).unwrap(),
+        Some(b"before".to_vec())
+    );
+    assert_eq!(
+        db.read_alloc(b"changed", Column::Coins).unwrap(),
+        Some(b"before".to_vec())
+    );
+    assert!(!db.contains_key(b"added", Column::Messages).unwrap());
+}
+
+#[test]
+fn branches_from_snapshot_are_independent() {
+    let db = Database::in_memory();
+    db.write(b"prefix", Column::Metadata, b"shared").unwrap();
+    let snapshot = db.snapshot().unwrap();
+
+    let branch_a = Database::from_snapshot(snapshot.clone());
+    let branch_b = Database::from_snapshot(snapshot);
+    branch_a.write(b"suffix", Column::Metadata, b"a").unwrap();
+
+    assert!(branch_a.contains_key(b"prefix", Column::Metadata).unwrap());
+    assert!(branch_b.contains_key(b"prefix", Column::Metadata).unwrap());
+    assert!(!branch_b.contains_key(b"suffix", Column::Metadata).unwrap());
+    assert!(!db.contains_key(b"suffix", Column::Metadata).unwrap());
+}
+
+#[test]
+fn wipe_removes_metadata_with_all_columns() {
+    let db = Database::in_memory();
+    db.write(b"chain_id", Column::Metadata, b"old").unwrap();
+    db.write(b"coin", Column::Coins, b"value").unwrap();
+    db.write(b"message", Column::Messages, b"value").unwrap();
+
+    db.wipe().unwrap();
+
+    assert!(!db.contains_key(b"chain_id", Column::Metadata).unwrap());
+    assert!(!db.contains_key(b"coin", Column::Coins).unwrap());
+    assert!(!db.contains_key(b"message", Column::Messages).unwrap());
+}
+
+#[test]
+fn fork_keeps_changes_of_committed_transaction() {
+    use fuel_core_storage::transactional::Transaction;
+
+    let db = Database::in_memory();
+    let mut parent = db.transaction();
+    parent.write(b"parent", Column::Metadata, b"value").unwrap();
+
+    let fork = parent.fork().unwrap();
+    parent.commit().unwrap();
+    fork.write(b"fork", Column::Metadata, b"value").unwrap();
+
+    assert!(db.contains_key(b"parent", Column::Metadata).unwrap());
+    assert!(fork.contains_key(b"parent", Column::Metadata).unwrap());
+    assert!(!db.contains_key(b"fork", Column::Metadata).unwrap());
+    assert!(!parent.contains_key(b"fork", Column::Metadata).unwrap());
+}
+
+#[test]
+fn speculative_transaction_is_committed_on_top_of_parent() {
+    use crate::database::transaction::SpeculativeTransaction;
+    use fuel_core_storage::transactional::Transaction;
+
+    let db = Database::in_memory();
+    let mut parent = db.transaction();
+    parent.write(b"parent", Column::Metadata, b"value").unwrap();
+
+    let child = parent.fork().unwrap().transaction();
+    child.write(b"child", Column::Metadata, b"value").unwrap();
+    let mut speculative = SpeculativeTransaction::new(child, db.clone());
+
+    parent.commit().unwrap();
+    speculative.commit().unwrap();
+
+    assert!(db.contains_key(b"parent", Column::Metadata).unwrap());
+    assert!(db.contains_key(b"child", Column::Metadata).unwrap());
+}
@@ -1,6 +1,7 @@
 use crate::database::{
     storage::{
         DenseMerkleMetadata,
+        FuelBlockExecutorVersions,
         FuelBlockMerkleData,
         FuelBlockMerkleMetadata,
         FuelBlockSecondaryKeyBlockHeights,
@@ -36,6 +37,10 @@ use fuel_core_types::{
     },
     entities::message::MerkleProof,
     fuel_merkle::binary::MerkleTree,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     tai64::Tai64,
 };
@@ -149,6 +154,18 @@ impl Database {
         Ok(block.header().time().to_owned())
     }
 
+    /// Returns the version of the executor that produced the block at `height`.
+    /// Returns `None` for blocks executed before the versions were recorded.
+    pub fn block_executor_version(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<u32>> {
+        Ok(self
+            .storage::<FuelBlockExecutorVersions>()
+            .get(height)?
+            .map(|version| version.into_owned()))
+    }
+
     pub fn get_block_id(&self, height: &BlockHeight) -> StorageResult<Option<BlockId>> {
         Database::get(
             self,
@@ -210,30 +227,62 @@ impl Database {
         Ok(ids)
     }
 
-    /// Retrieve the full block and all associated transactions
+    /// Retrieve the full block and all associated transactions.
+    /// Returns `None` if the transactions of the block are pruned.
     pub(crate) fn get_full_block(
         &self,
         block_id: &BlockId,
     ) -> StorageResult<Option<Block>> {
         let db_block = self.storage::<FuelBlocks>().get(block_id)?;
         if let Some(block) = db_block {
+            if self.is_block_pruned(block.header().height())? {
+                return Ok(None)
+            }
             // fetch all the transactions
             // TODO: optimize with multi-key get
-            let txs = block
-                .transactions()
-                .iter()
-                .map(|tx_id| {
-                    self.storage::<Transactions>()
-                        .get(tx_id)
-                        .and_then(|tx| tx.ok_or(not_found!(Transactions)))
-                        .map(Cow::into_owned)
-                })
-                .try_collect()?;
-            Ok(Some(block.into_owned().uncompress(txs)))
+            let block = block
+                .into_owned()
+                .uncompress_with(|tx_id| self.get_transaction(tx_id))?
+                .ok_or_else(|| {
+                    StorageError::Other(anyhow::anyhow!(
+                        "The transactions of the block {block_id} don't match its header"
+                    ))
+                })?;
+            Ok(Some(block))
         } else {
             Ok(None)
         }
     }
+
+    /// Retrieve the transactions of the block without loading its header and consensus.
+    /// Returns `None` if the transactions of the block are pruned.
+    pub(crate) fn get_block_transactions(
+        &self,
+        block_id: &BlockId,
+    ) -> StorageResult<Option<Vec<Transaction>>> {
+        let db_block = self.storage::<FuelBlocks>().get(block_id)?;
+        if let Some(block) = &db_block {
+            if self.is_block_pruned(block.header().height())? {
+                return Ok(None)
+            }
+        }
+        db_block
+            .map(|block| {
+                block
+                    .transactions()
+                    .iter()
+                    .map(|tx_id| self.get_transaction(tx_id))
+                    .try_collect()
+            })
+            .transpose()
+    }
+
+    fn get_transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
+        self.storage::<Transactions>()
+            .get(tx_id)
+            .and_then(|tx| tx.ok_or(not_found!(Transactions)))
+            .map(Cow::into_owned)
+    }
 }
 
 impl MerkleRootStorage<BlockHeight, FuelBlocks> for Database {
@@ -300,7 +349,10 @@ mod tests {
             },
             primitives::Empty,
         },
-        fuel_tx::ConsensusParameters,
+        fuel_tx::{
+            ConsensusParameters,
+            UniqueIdentifier,
+        },
         fuel_vm::crypto::ephemeral_merkle_root,
     };
     use test_case::test_case;
@@ -372,6 +424,73 @@ mod tests {
         assert!(matches!(err, fuel_core_storage::Error::NotFound(_, _)));
     }
 
+    #[test]
+    fn uncompress_rejects_mismatched_transactions() {
+        let params = ConsensusParameters::DEFAULT;
+        let tx = Transaction::default();
+        let other_tx = Transaction::script(
+            1,
+            Default::default(),
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let block =
+            PartialFuelBlock::new(PartialBlockHeader::default(), vec![tx.clone()])
+                .generate(&[]);
+        let compressed = block.compress(&params);
+
+        assert_eq!(
+            compressed.clone().uncompress(vec![tx], &params),
+            Some(block)
+        );
+        assert_eq!(
+            compressed.clone().uncompress(vec![other_tx], &params),
+            None
+        );
+        assert_eq!(compressed.uncompress(vec![], &params), None);
+    }
+
+    #[test]
+    fn get_full_block_rejects_transactions_mismatching_header() {
+        let mut database = Database::default();
+        let params = ConsensusParameters::DEFAULT;
+        let tx = Transaction::default();
+        let other_tx = Transaction::script(
+            1,
+            Default::default(),
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let block =
+            PartialFuelBlock::new(PartialBlockHeader::default(), vec![tx.clone()])
+                .generate(&[]);
+        StorageMutate::<FuelBlocks>::insert(
+            &mut database,
+            &block.id(),
+            &block.compress(&params),
+        )
+        .unwrap();
+        StorageMutate::<Transactions>::insert(&mut database, &tx.id(&params), &tx)
+            .unwrap();
+        assert_eq!(
+            database.get_full_block(&block.id()).unwrap(),
+            Some(block.clone())
+        );
+
+        // The body stored under the id of the transaction doesn't match the root.
+        StorageMutate::<Transactions>::insert(&mut database, &tx.id(&params), &other_tx)
+            .unwrap();
+        assert!(database.get_full_block(&block.id()).is_err());
+    }
+
     const TEST_BLOCKS_COUNT: usize = 10;
 
     fn insert_test_ascending_blocks(
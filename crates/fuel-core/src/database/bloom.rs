@@ -0,0 +1,112 @@
+use crate::database::{
+    storage::FuelBlockBlooms,
+    Database,
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::BlockHeight,
+};
+
+/// The size of the bloom filter in bytes.
+const BLOOM_SIZE: usize = 256;
+/// The number of bits set per inserted id.
+const BLOOM_HASHES: usize = 3;
+
+/// The bloom filter of owners and contracts involved in transactions of the block.
+/// It allows history queries to skip blocks that don't involve the requested id
+/// without loading their transactions.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlockBloom(Vec<u8>);
+
+impl Default for BlockBloom {
+    fn default() -> Self {
+        Self(vec![0; BLOOM_SIZE])
+    }
+}
+
+impl BlockBloom {
+    /// Adds the `id` to the filter.
+    pub fn insert(&mut self, id: &[u8; 32]) {
+        for (byte, mask) in Self::bits(id) {
+            self.0[byte] |= mask;
+        }
+    }
+
+    /// Returns `false` if the `id` was never inserted into the filter.
+    /// Returns `true` if the `id` may have been inserted.
+    pub fn may_contain(&self, id: &[u8; 32]) -> bool {
+        if self.0.len() != BLOOM_SIZE {
+            // The filter is corrupted, so it can't exclude anything.
+            return true
+        }
+        Self::bits(id).all(|(byte, mask)| self.0[byte] & mask != 0)
+    }
+
+    /// Returns the byte index and the mask of every bit of the `id`.
+    fn bits(id: &[u8; 32]) -> impl Iterator<Item = (usize, u8)> {
+        let hash = Hasher::hash(id);
+        (0..BLOOM_HASHES).map(move |i| {
+            let bit = u16::from_be_bytes([hash[2 * i], hash[2 * i + 1]]) as usize
+                % (BLOOM_SIZE * 8);
+            (bit / 8, 1 << (bit % 8))
+        })
+    }
+}
+
+impl Database {
+    /// Returns `false` only if no transaction of the block at `height` involves the `id`.
+    /// Blocks without the bloom filter, like blocks imported before filters existed,
+    /// may involve any id.
+    pub fn block_may_involve(
+        &self,
+        height: &BlockHeight,
+        id: &[u8; 32],
+    ) -> StorageResult<bool> {
+        let bloom = self.storage::<FuelBlockBlooms>().get(height)?;
+        Ok(bloom.map_or(true, |bloom| bloom.may_contain(id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+
+    #[test]
+    fn bloom_contains_inserted_ids() {
+        let mut bloom = BlockBloom::default();
+        let ids: Vec<[u8; 32]> = (0..50u8).map(|i| [i; 32]).collect();
+
+        for id in ids.iter() {
+            bloom.insert(id);
+        }
+
+        assert!(ids.iter().all(|id| bloom.may_contain(id)));
+    }
+
+    #[test]
+    fn empty_bloom_contains_nothing() {
+        let bloom = BlockBloom::default();
+
+        assert!(!bloom.may_contain(&[1; 32]));
+    }
+
+    #[test]
+    fn block_may_involve_checks_stored_bloom() {
+        let mut db = Database::default();
+        let mut bloom = BlockBloom::default();
+        bloom.insert(&[1; 32]);
+        db.storage::<FuelBlockBlooms>()
+            .insert(&1u32.into(), &bloom)
+            .unwrap();
+
+        assert!(db.block_may_involve(&1u32.into(), &[1; 32]).unwrap());
+        assert!(!db.block_may_involve(&1u32.into(), &[2; 32]).unwrap());
+        // Blocks without the filter can't be skipped.
+        assert!(db.block_may_involve(&2u32.into(), &[2; 32]).unwrap());
+    }
+}
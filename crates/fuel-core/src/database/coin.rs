@@ -138,6 +138,12 @@ impl Database {
         Ok(coin)
     }
 
+    /// Iterates over all unspent coins in the order of their ids.
+    pub fn all_coins(&self) -> impl Iterator<Item = DatabaseResult<CompressedCoin>> + '_ {
+        self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None)
+            .map(|coin| coin.map(|(_, coin)| coin))
+    }
+
     pub fn get_coin_config(&self) -> DatabaseResult<Option<Vec<CoinConfig>>> {
         let configs = self
             .iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None)
@@ -5,9 +5,17 @@ use crate::database::{
     Result as DatabaseResult,
 };
 use fuel_core_chain_config::ChainConfig;
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_asm::Word,
+};
 
 pub(crate) const DB_VERSION_KEY: &[u8] = b"version";
 pub(crate) const CHAIN_NAME_KEY: &[u8] = b"chain_name";
+pub(crate) const PRUNED_HEIGHT_KEY: &[u8] = b"pruned_height";
+pub(crate) const CHAIN_ID_KEY: &[u8] = b"chain_id";
+pub(crate) const GENESIS_BLOCK_ID_KEY: &[u8] = b"genesis_block_id";
+pub(crate) const BLOCK_GAS_LIMIT_KEY: &[u8] = b"block_gas_limit";
 
 /// Can be used to perform migrations in the future.
 pub(crate) const DB_VERSION: u32 = 0x00;
@@ -45,4 +53,38 @@ impl Database {
     pub fn get_chain_name(&self) -> DatabaseResult<Option<String>> {
         self.get(CHAIN_NAME_KEY, Column::Metadata)
     }
+
+    /// Returns the chain id recorded on the first start of the node.
+    pub fn get_chain_id(&self) -> DatabaseResult<Option<u64>> {
+        self.get(CHAIN_ID_KEY, Column::Metadata)
+    }
+
+    /// Returns the id of the genesis block recorded on the first start of the node.
+    pub fn get_genesis_block_id(&self) -> DatabaseResult<Option<BlockId>> {
+        self.get(GENESIS_BLOCK_ID_KEY, Column::Metadata)
+    }
+
+    /// Records the identity of the chain that the database belongs to.
+    pub fn record_chain(
+        &self,
+        chain_id: u64,
+        genesis_block_id: &BlockId,
+    ) -> DatabaseResult<()> {
+        let _: Option<u64> = self.insert(CHAIN_ID_KEY, Column::Metadata, &chain_id)?;
+        let _: Option<BlockId> =
+            self.insert(GENESIS_BLOCK_ID_KEY, Column::Metadata, genesis_block_id)?;
+        Ok(())
+    }
+
+    /// Returns the gas limit of the next produced block adjusted to the demand.
+    pub fn get_block_gas_limit(&self) -> DatabaseResult<Option<Word>> {
+        self.get(BLOCK_GAS_LIMIT_KEY, Column::Metadata)
+    }
+
+    /// Records the gas limit of the next produced block adjusted to the demand.
+    pub fn set_block_gas_limit(&self, gas_limit: Word) -> DatabaseResult<()> {
+        let _: Option<Word> =
+            self.insert(BLOCK_GAS_LIMIT_KEY, Column::Metadata, &gas_limit)?;
+        Ok(())
+    }
 }
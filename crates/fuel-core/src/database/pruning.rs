@@ -0,0 +1,233 @@
+use crate::database::{
+    metadata::PRUNED_HEIGHT_KEY,
+    transactions::{
+        transaction_owners,
+        TransactionIndex,
+    },
+    Column,
+    Database,
+};
+use fuel_core_storage::{
+    not_found,
+    tables::{
+        FuelBlocks,
+        Receipts,
+        Transactions,
+    },
+    transactional::Transaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::ops::DerefMut;
+
+impl Database {
+    /// Returns the height below which bodies of blocks are pruned.
+    /// Headers and consensus of pruned blocks are retained, so the chain can still
+    /// be verified by light clients.
+    pub fn pruned_height(&self) -> StorageResult<Option<BlockHeight>> {
+        Ok(self.get(PRUNED_HEIGHT_KEY, Column::Metadata)?)
+    }
+
+    /// Returns `true` if the body of the block at `height` is pruned.
+    pub fn is_block_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Ok(self
+            .pruned_height()?
+            .map_or(false, |pruned_height| *height < pruned_height))
+    }
+
+    /// Removes transactions, receipts and the index of transactions by owners of blocks
+    /// below the `height`, but at most of `max_blocks` blocks at once, so the large
+    /// backlog is pruned in bounded database transactions. Returns the height below
+    /// which blocks are pruned after the call. The sealed headers of pruned blocks stay
+    /// in the database.
+    pub fn prune_block_bodies(
+        &self,
+        height: &BlockHeight,
+        max_blocks: u32,
+    ) -> StorageResult<BlockHeight> {
+        let start = self.pruned_height()?.unwrap_or_default();
+        if *height <= start {
+            return Ok(start)
+        }
+        let end = BlockHeight::from((**height).min(start.saturating_add(max_blocks)));
+
+        let mut db_transaction = self.transaction();
+        let db = db_transaction.deref_mut();
+        for block_height in *start..*end {
+            let block_height = BlockHeight::from(block_height);
+            let block_id = match db.get_block_id(&block_height)? {
+                Some(block_id) => block_id,
+                None => continue,
+            };
+            let block = db
+                .storage::<FuelBlocks>()
+                .get(&block_id)?
+                .ok_or(not_found!(FuelBlocks))?
+                .into_owned();
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                if let Some(tx) = db.storage::<Transactions>().remove(tx_id)? {
+                    for owner in transaction_owners(&tx) {
+                        db.remove_tx_id_owner(
+                            &owner,
+                            block_height,
+                            tx_idx as TransactionIndex,
+                        )?;
+                    }
+                }
+                db.storage::<Receipts>().remove(tx_id)?;
+            }
+        }
+        let _: Option<BlockHeight> =
+            db.insert(PRUNED_HEIGHT_KEY, Column::Metadata, &end)?;
+        db_transaction.commit()?;
+        Ok(end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageInspect;
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            ConsensusParameters,
+            Output,
+            Transaction as FuelTransaction,
+            UniqueIdentifier,
+        },
+        fuel_types::Address,
+    };
+
+    fn insert_block(database: &mut Database, height: u32, tx: FuelTransaction) {
+        let params = ConsensusParameters::DEFAULT;
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress(&params))
+            .unwrap();
+        database
+            .storage::<Transactions>()
+            .insert(&tx.id(&params), &tx)
+            .unwrap();
+    }
+
+    #[test]
+    fn prune_block_bodies_keeps_headers() {
+        let params = ConsensusParameters::DEFAULT;
+        let mut database = Database::default();
+        let old_tx = FuelTransaction::default();
+        let new_tx = FuelTransaction::script(
+            1,
+            Default::default(),
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        insert_block(&mut database, 0, old_tx.clone());
+        insert_block(&mut database, 1, new_tx.clone());
+
+        let pruned = database.prune_block_bodies(&1u32.into(), 10).unwrap();
+
+        assert_eq!(pruned, 1u32.into());
+        assert!(database.is_block_pruned(&0u32.into()).unwrap());
+        assert!(!database.is_block_pruned(&1u32.into()).unwrap());
+        assert!(!StorageInspect::<Transactions>::contains_key(
+            &database,
+            &old_tx.id(&params)
+        )
+        .unwrap());
+        assert!(StorageInspect::<Transactions>::contains_key(
+            &database,
+            &new_tx.id(&params)
+        )
+        .unwrap());
+        let block_id = database.get_block_id(&0u32.into()).unwrap().unwrap();
+        assert!(
+            StorageInspect::<FuelBlocks>::contains_key(&database, &block_id).unwrap()
+        );
+    }
+
+    #[test]
+    fn prune_block_bodies_is_bounded() {
+        let mut database = Database::default();
+        for height in 0..5u32 {
+            let tx = FuelTransaction::script(
+                height as u64,
+                Default::default(),
+                Default::default(),
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+            );
+            insert_block(&mut database, height, tx);
+        }
+
+        assert_eq!(
+            database.prune_block_bodies(&5u32.into(), 2).unwrap(),
+            2u32.into()
+        );
+        assert!(database.is_block_pruned(&1u32.into()).unwrap());
+        assert!(!database.is_block_pruned(&2u32.into()).unwrap());
+
+        assert_eq!(
+            database.prune_block_bodies(&5u32.into(), 2).unwrap(),
+            4u32.into()
+        );
+        assert_eq!(
+            database.prune_block_bodies(&5u32.into(), 2).unwrap(),
+            5u32.into()
+        );
+        assert_eq!(
+            database.prune_block_bodies(&5u32.into(), 2).unwrap(),
+            5u32.into()
+        );
+    }
+
+    #[test]
+    fn prune_block_bodies_removes_owners_index() {
+        let params = ConsensusParameters::DEFAULT;
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        let tx = FuelTransaction::script(
+            1,
+            Default::default(),
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![Output::coin(owner, 10, Default::default())],
+            vec![],
+        );
+        insert_block(&mut database, 0, tx.clone());
+        database
+            .record_tx_id_owner(&owner, 0u32.into(), 0, &tx.id(&params))
+            .unwrap();
+        assert_eq!(database.owned_transactions(owner, None, None).count(), 1);
+
+        database.prune_block_bodies(&1u32.into(), 10).unwrap();
+
+        assert_eq!(database.owned_transactions(owner, None, None).count(), 0);
+    }
+}
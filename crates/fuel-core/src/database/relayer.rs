@@ -1,5 +1,40 @@
-use crate::database::Column;
-use fuel_core_relayer::ports::RelayerMetadata;
+use crate::database::{
+    Column,
+    Database,
+};
+use fuel_core_relayer::{
+    ports::{
+        RelayerDb,
+        RelayerMetadata,
+    },
+    Config,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::seal::{
+            SealScheme,
+            Secp256k1,
+        },
+        primitives::DaBlockHeight,
+    },
+    entities::message::Message,
+    fuel_crypto::{
+        Hasher,
+        Message as SignedMessage,
+        SecretKey,
+        Signature,
+    },
+    fuel_merkle::binary::in_memory::MerkleTree,
+    fuel_types::{
+        Address,
+        Bytes20,
+        Bytes32,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use super::storage::DatabaseColumn;
 
@@ -8,3 +43,485 @@ impl DatabaseColumn for RelayerMetadata {
         Column::RelayerMetadata
     }
 }
+
+/// The state of the relayer exported from one node to provision other nodes without
+/// downloading the logs of the DA layer again.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayerState {
+    /// The contracts on the DA layer that emitted the events.
+    pub contracts: Vec<Bytes20>,
+    /// The DA height at which the contracts were deployed.
+    pub da_deploy_height: DaBlockHeight,
+    /// The finalized DA height up to which the events were ingested.
+    pub finalized_da_height: DaBlockHeight,
+    /// All messages sent from the DA layer, including spent ones, ordered by their
+    /// nonces.
+    pub messages: Vec<Message>,
+    /// The merkle root of computed ids of the `messages`.
+    pub messages_root: Bytes32,
+}
+
+/// The merkle root of messages up to the DA height, signed by the consensus key of an
+/// authority of the chain. The authority computes the root from its own relayer, so the
+/// root doesn't depend on the node that exported the state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KnownRoot {
+    /// The DA height of the root.
+    pub da_height: DaBlockHeight,
+    /// The merkle root of computed ids of messages sent up to the `da_height`.
+    pub root: Bytes32,
+    /// The signature of the `da_height` and the `root` by the authority.
+    pub signature: Signature,
+}
+
+impl KnownRoot {
+    /// Separates known roots from other messages signed by authorities.
+    pub const DOMAIN: &'static [u8] = b"FUEL_RELAYER_ROOT";
+
+    /// Signs the `root` of messages up to the `da_height` with the consensus key.
+    pub fn sign(
+        da_height: DaBlockHeight,
+        root: Bytes32,
+        signing_key: &SecretKey,
+    ) -> Self {
+        let signature = Secp256k1::seal(signing_key, &signed_message(da_height, root));
+        Self {
+            da_height,
+            root,
+            signature,
+        }
+    }
+
+    /// Returns the authority that signed the root.
+    pub fn signer(&self) -> anyhow::Result<Address> {
+        Secp256k1::signer(&self.signature, &signed_message(self.da_height, self.root))
+    }
+}
+
+impl Database {
+    /// Exports all messages ingested by the relayer and its finalized DA height. The
+    /// `config` describes the contracts that emitted the messages.
+    pub fn export_relayer_state(&self, config: &Config) -> anyhow::Result<RelayerState> {
+        let finalized_da_height = self.get_finalized_da_height()?;
+        let messages = self
+            .all_messages(None, None)
+            .collect::<Result<Vec<_>, _>>()?;
+        let messages_root = messages_root(messages.iter());
+
+        Ok(RelayerState {
+            contracts: contracts(config),
+            da_deploy_height: config.da_deploy_height,
+            finalized_da_height,
+            messages,
+            messages_root,
+        })
+    }
+
+    /// Signs the root of messages ingested by the relayer up to the `da_height` with the
+    /// consensus key of the authority, for nodes importing the relayer state.
+    pub fn sign_relayer_root(
+        &self,
+        da_height: DaBlockHeight,
+        signing_key: &SecretKey,
+    ) -> anyhow::Result<KnownRoot> {
+        let finalized_da_height = self.get_finalized_da_height()?;
+        anyhow::ensure!(
+            da_height <= finalized_da_height,
+            "The relayer ingested events only up to the DA height {}, below {}",
+            finalized_da_height,
+            da_height
+        );
+        let messages = self
+            .all_messages(None, None)
+            .filter(|message| {
+                message
+                    .as_ref()
+                    .map_or(true, |message| message.da_height <= da_height)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let root = messages_root(messages.iter());
+        Ok(KnownRoot::sign(da_height, root, signing_key))
+    }
+
+    /// Imports the `state` exported from another node after checking that it belongs
+    /// to the contracts of the `config`, isn't corrupted and matches the `known_roots`.
+    /// The root of the exported messages is stored in the file itself, so it proves
+    /// nothing. The `known_roots` must be signed by one of the `authorities` of the
+    /// chain, and one of them should be at the finalized DA height of the `state` to
+    /// cover all its messages. The relayer continues from the finalized DA height of
+    /// the `state`.
+    pub fn import_relayer_state(
+        &mut self,
+        state: &RelayerState,
+        config: &Config,
+        known_roots: &[KnownRoot],
+        authorities: &[Address],
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            state.contracts == contracts(config)
+                && state.da_deploy_height == config.da_deploy_height,
+            "The relayer state is exported for the contracts {:?} deployed at the DA \
+            height {}, but the node listens to {:?} deployed at {}",
+            state.contracts,
+            state.da_deploy_height,
+            contracts(config),
+            config.da_deploy_height
+        );
+        anyhow::ensure!(
+            state
+                .messages
+                .windows(2)
+                .all(|pair| pair[0].id() < pair[1].id()),
+            "Messages of the relayer state are not ordered by their nonces"
+        );
+        if let Some(message) = state
+            .messages
+            .iter()
+            .find(|message| message.da_height > state.finalized_da_height)
+        {
+            anyhow::bail!(
+                "The message {} is sent at the DA height {} above the finalized height \
+                {}",
+                message.id(),
+                message.da_height,
+                state.finalized_da_height
+            );
+        }
+
+        let root = messages_root(state.messages.iter());
+        anyhow::ensure!(
+            root == state.messages_root,
+            "The root {} of messages doesn't match the exported root {}",
+            root,
+            state.messages_root
+        );
+        anyhow::ensure!(
+            known_roots
+                .iter()
+                .any(|known| known.da_height == state.finalized_da_height),
+            "The relayer state isn't verified: none of the known roots is at the \
+            finalized DA height {} of the state",
+            state.finalized_da_height
+        );
+        for known in known_roots {
+            let signer = known.signer()?;
+            anyhow::ensure!(
+                authorities.contains(&signer),
+                "The known root at the DA height {} is signed by {}, which isn't an \
+                authority of the chain",
+                known.da_height,
+                signer
+            );
+            anyhow::ensure!(
+                known.da_height <= state.finalized_da_height,
+                "The known root at the DA height {} is above the finalized height {} \
+                of the relayer state",
+                known.da_height,
+                state.finalized_da_height
+            );
+            let root = messages_root(
+                state
+                    .messages
+                    .iter()
+                    .filter(|message| message.da_height <= known.da_height),
+            );
+            anyhow::ensure!(
+                root == known.root,
+                "The root {} of messages up to the DA height {} doesn't match the known \
+                root {}",
+                root,
+                known.da_height,
+                known.root
+            );
+        }
+
+        if let Ok(current) = self.get_finalized_da_height() {
+            anyhow::ensure!(
+                current < state.finalized_da_height,
+                "The relayer already ingested events up to the DA height {}, which is \
+                not below the height {} of the relayer state",
+                current,
+                state.finalized_da_height
+            );
+        }
+        self.insert_messages(&state.finalized_da_height, &state.messages)?;
+        Ok(())
+    }
+}
+
+fn contracts(config: &Config) -> Vec<Bytes20> {
+    config
+        .eth_v2_listening_contracts
+        .iter()
+        .map(|contract| Bytes20::new(contract.0))
+        .collect()
+}
+
+fn signed_message(da_height: DaBlockHeight, root: Bytes32) -> SignedMessage {
+    let mut hasher = Hasher::default();
+    hasher.input(KnownRoot::DOMAIN);
+    hasher.input(da_height.0.to_be_bytes());
+    hasher.input(root.as_ref());
+    SignedMessage::from_bytes(*hasher.digest())
+}
+
+fn messages_root<'a>(messages: impl Iterator<Item = &'a Message>) -> Bytes32 {
+    let mut tree = MerkleTree::new();
+    for message in messages {
+        tree.push(message.message_id().as_ref());
+    }
+    tree.root().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::SpentMessages,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        fuel_tx::Input,
+        fuel_types::Nonce,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn signing_key(seed: u64) -> SecretKey {
+        SecretKey::random(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn messages() -> Vec<Message> {
+        (1..=4u64)
+            .map(|i| Message {
+                nonce: i.into(),
+                da_height: i.into(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn database_with_messages() -> Database {
+        let mut db = Database::default();
+        db.insert_messages(&5u64.into(), &messages()).unwrap();
+        db
+    }
+
+    /// The authority of the chain with its own relayer, independent of the exporter.
+    struct Authority {
+        db: Database,
+        signing_key: SecretKey,
+    }
+
+    impl Authority {
+        fn new() -> Self {
+            Self {
+                db: database_with_messages(),
+                signing_key: signing_key(1),
+            }
+        }
+
+        fn address(&self) -> Address {
+            Input::owner(&self.signing_key.public_key())
+        }
+
+        fn known_root(&self, da_height: u64) -> KnownRoot {
+            self.db
+                .sign_relayer_root(da_height.into(), &self.signing_key)
+                .unwrap()
+        }
+    }
+
+    #[test]
+    fn exported_relayer_state_is_imported_into_fresh_database() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+        assert_eq!(state.messages.len(), 4);
+        assert_eq!(state.finalized_da_height, 5u64.into());
+
+        let mut fresh = Database::default();
+        fresh
+            .import_relayer_state(
+                &state,
+                &config,
+                &[authority.known_root(2), authority.known_root(5)],
+                &[authority.address()],
+            )
+            .unwrap();
+
+        assert_eq!(fresh.get_finalized_da_height().unwrap(), 5u64.into());
+        assert_eq!(fresh.export_relayer_state(&config).unwrap(), state);
+    }
+
+    #[test]
+    fn import_rejects_state_missing_messages_of_authority() {
+        let config = Config::default();
+        let authority = Authority::new();
+        // The exporter lost a message, so its own root is consistent with its state.
+        let mut exporter = Database::default();
+        let mut lost = messages();
+        lost.remove(2);
+        exporter.insert_messages(&5u64.into(), &lost).unwrap();
+        let state = exporter.export_relayer_state(&config).unwrap();
+
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[authority.known_root(5)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+        // The root below the lost message matches, but doesn't cover the whole state.
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[authority.known_root(2)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn import_rejects_root_not_signed_by_authority() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+        let other = signing_key(2);
+        let known =
+            KnownRoot::sign(state.finalized_da_height, state.messages_root, &other);
+
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[known],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn import_rejects_tampered_messages() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let mut state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+        state.messages[1].amount = 100;
+
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[authority.known_root(5)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn import_rejects_mismatching_known_root() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+        let known = authority.known_root(2);
+        let known = KnownRoot::sign(3u64.into(), known.root, &authority.signing_key);
+
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[known, authority.known_root(5)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn import_requires_known_root_at_finalized_height() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+        let result = Database::default().import_relayer_state(
+            &state,
+            &config,
+            &[authority.known_root(4)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn export_includes_spent_and_genesis_messages() {
+        let config = Config {
+            da_deploy_height: 2u64.into(),
+            ..Config::default()
+        };
+        let mut db = database_with_messages();
+        let spent = Nonce::from(3u64);
+        db.storage::<SpentMessages>().insert(&spent, &()).unwrap();
+
+        let state = db.export_relayer_state(&config).unwrap();
+
+        let nonces: Vec<_> = state.messages.iter().map(|message| message.nonce).collect();
+        assert_eq!(
+            nonces,
+            vec![1u64.into(), 2u64.into(), 3u64.into(), 4u64.into()]
+        );
+    }
+
+    #[test]
+    fn import_rejects_state_of_other_contracts() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+        let other_config = Config {
+            eth_v2_listening_contracts: vec![Default::default()],
+            ..Config::default()
+        };
+
+        let result = Database::default().import_relayer_state(
+            &state,
+            &other_config,
+            &[authority.known_root(5)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn import_rejects_node_ahead_of_state() {
+        let config = Config::default();
+        let authority = Authority::new();
+        let state = database_with_messages()
+            .export_relayer_state(&config)
+            .unwrap();
+
+        let mut db = database_with_messages();
+        let result = db.import_relayer_state(
+            &state,
+            &config,
+            &[authority.known_root(5)],
+            &[authority.address()],
+        );
+        assert!(result.is_err());
+    }
+}
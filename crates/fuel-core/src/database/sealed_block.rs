@@ -17,7 +17,6 @@ use fuel_core_types::{
         consensus::{
             Consensus,
             Genesis,
-            Sealed,
         },
         primitives::BlockId,
         SealedBlock,
@@ -116,8 +115,6 @@ impl Database {
         &self,
         block_id: &BlockId,
     ) -> StorageResult<Option<Vec<Transaction>>> {
-        Ok(self
-            .get_sealed_block_by_id(block_id)?
-            .map(|Sealed { entity: block, .. }| block.into_inner().1))
+        self.get_block_transactions(block_id)
     }
 }
@@ -1,4 +1,5 @@
 use crate::database::{
+    bloom::BlockBloom,
     Column,
     Database,
 };
@@ -82,6 +83,28 @@ impl Mappable for FuelBlockSecondaryKeyBlockHeights {
     type OwnedValue = Self::Value;
 }
 
+/// The table of bloom filters of ids involved in Fuel blocks.
+/// It links the `BlockHeight` to the [`BlockBloom`] of the block.
+pub struct FuelBlockBlooms;
+
+impl Mappable for FuelBlockBlooms {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = BlockBloom;
+    type OwnedValue = Self::Value;
+}
+
+/// The table of versions of the executor that produced Fuel blocks.
+/// It links the `BlockHeight` to the version of the executor.
+pub struct FuelBlockExecutorVersions;
+
+impl Mappable for FuelBlockExecutorVersions {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = u32;
+    type OwnedValue = Self::Value;
+}
+
 /// The table of BMT data for Fuel blocks.
 pub struct FuelBlockMerkleData;
 
@@ -160,6 +183,18 @@ impl DatabaseColumn for FuelBlockSecondaryKeyBlockHeights {
     }
 }
 
+impl DatabaseColumn for FuelBlockBlooms {
+    fn column() -> Column {
+        Column::FuelBlockBlooms
+    }
+}
+
+impl DatabaseColumn for FuelBlockExecutorVersions {
+    fn column() -> Column {
+        Column::FuelBlockExecutorVersions
+    }
+}
+
 impl DatabaseColumn for FuelBlockMerkleData {
     fn column() -> Column {
         Column::FuelBlockMerkleData
@@ -1,5 +1,8 @@
 use crate::{
-    database::Database,
+    database::{
+        Database,
+        Result as DatabaseResult,
+    },
     state::in_memory::transaction::MemoryTransactionView,
 };
 use fuel_core_storage::{
@@ -23,6 +26,26 @@ pub struct DatabaseTransaction {
     database: Database,
 }
 
+impl DatabaseTransaction {
+    fn from_view(view: MemoryTransactionView) -> Self {
+        let data = Arc::new(view);
+        Self {
+            changes: data.clone(),
+            database: Database {
+                data,
+                _drop: Default::default(),
+            },
+        }
+    }
+
+    /// Returns the transaction with the same uncommitted changes on top of the
+    /// `database`.
+    pub fn rebase(&self, database: &Database) -> DatabaseResult<Self> {
+        let view = self.changes.rebase(database.data.clone())?;
+        Ok(Self::from_view(view))
+    }
+}
+
 impl AsRef<Database> for DatabaseTransaction {
     fn as_ref(&self) -> &Database {
         &self.database
@@ -64,13 +87,43 @@ impl Transaction<Database> for DatabaseTransaction {
 
 impl From<&Database> for DatabaseTransaction {
     fn from(source: &Database) -> Self {
-        let data = Arc::new(MemoryTransactionView::new(source.data.clone()));
+        Self::from_view(MemoryTransactionView::new(source.data.clone()))
+    }
+}
+
+/// The transaction executed on top of the uncommitted state of the parent transaction,
+/// e.g. of the previous block that is being imported. The parent is committed into the
+/// `database` first, so the changes are moved on top of the `database` during the commit.
+#[derive(Debug)]
+pub struct SpeculativeTransaction {
+    transaction: DatabaseTransaction,
+    database: Database,
+}
+
+impl SpeculativeTransaction {
+    pub fn new(transaction: DatabaseTransaction, database: Database) -> Self {
         Self {
-            changes: data.clone(),
-            database: Database {
-                data,
-                _drop: Default::default(),
-            },
+            transaction,
+            database,
         }
     }
 }
+
+impl AsRef<Database> for SpeculativeTransaction {
+    fn as_ref(&self) -> &Database {
+        self.transaction.as_ref()
+    }
+}
+
+impl AsMut<Database> for SpeculativeTransaction {
+    fn as_mut(&mut self) -> &mut Database {
+        self.transaction.as_mut()
+    }
+}
+
+impl Transaction<Database> for SpeculativeTransaction {
+    fn commit(&mut self) -> StorageResult<()> {
+        let mut transaction = self.transaction.rebase(&self.database)?;
+        transaction.commit()
+    }
+}
@@ -11,7 +11,17 @@ use fuel_core_storage::{
 use fuel_core_types::{
     self,
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
         Bytes32,
+        Input,
+        Output,
         Transaction,
         TxPointer,
     },
@@ -84,6 +94,20 @@ impl Database {
         )
     }
 
+    /// Removes the index of the transaction by the `owner`, e.g. when the transaction
+    /// is pruned.
+    pub fn remove_tx_id_owner(
+        &self,
+        owner: &Address,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> DatabaseResult<Option<Bytes32>> {
+        self.remove(
+            &owned_tx_index_key(owner, block_height, tx_idx),
+            Column::TransactionsByOwnerBlockIdx,
+        )
+    }
+
     pub fn update_tx_status(
         &self,
         id: &Bytes32,
@@ -119,6 +143,33 @@ fn owned_tx_index_key(
     default
 }
 
+/// Returns the owners of coins spent and created by the `tx`, by which the executor
+/// indexes the transaction. Each owner is returned once.
+pub fn transaction_owners(tx: &Transaction) -> Vec<Address> {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Mint(tx) => (&[][..], tx.outputs().as_slice()),
+    };
+    let mut owners: Vec<_> = inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned { owner, .. })
+            | Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(*owner),
+            _ => None,
+        })
+        .chain(outputs.iter().filter_map(|output| match output {
+            Output::Coin { to, .. }
+            | Output::Change { to, .. }
+            | Output::Variable { to, .. } => Some(*to),
+            _ => None,
+        }))
+        .collect();
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
 ////////////////////////////////////// Not storage part //////////////////////////////////////
 
 pub type TransactionIndex = u16;
@@ -1,13 +1,25 @@
 use crate::{
     database::{
-        transaction::DatabaseTransaction,
+        bloom::BlockBloom,
+        storage::{
+            FuelBlockBlooms,
+            FuelBlockExecutorVersions,
+        },
+        transaction::{
+            DatabaseTransaction,
+            SpeculativeTransaction,
+        },
         transactions::TransactionIndex,
         vm_database::VmDatabase,
         Database,
     },
     service::Config,
 };
-use fuel_core_executor::refs::ContractRef;
+use fuel_core_executor::{
+    partition::partition,
+    refs::ContractRef,
+    signatures::verify_batch,
+};
 use fuel_core_storage::{
     tables::{
         Coins,
@@ -65,6 +77,7 @@ use fuel_core_types::{
         Address,
         AssetId,
         Bytes32,
+        Checkable,
         Input,
         Mint,
         Output,
@@ -121,6 +134,10 @@ use std::{
         Deref,
         DerefMut,
     },
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
 };
 use tracing::{
     debug,
@@ -129,6 +146,11 @@ use tracing::{
 
 mod ports;
 
+/// The version of the state transition function implemented by the executor. It is
+/// recorded for every produced block, so replay tools can pick the executor for
+/// historical blocks. It must be increased with every change of the execution rules.
+pub const EXECUTOR_VERSION: u32 = 1;
+
 /// ! The executor is used for block production and validation. Given a block, it will execute all
 /// the transactions contained in the block and persist changes to the underlying database as needed.
 /// In production mode, block fields like transaction commitments are set based on the executed txs.
@@ -146,6 +168,8 @@ where
 /// Data that is generated after executing all transactions.
 struct ExecutionData {
     coinbase: u64,
+    /// The recipient of fees of the block, also returned by the `CB` opcode.
+    coinbase_recipient: Address,
     message_ids: Vec<MessageId>,
     tx_status: Vec<TransactionExecutionStatus>,
     skipped_transactions: Vec<(Transaction, ExecutorError)>,
@@ -189,7 +213,52 @@ where
         &self,
         block: ExecutionBlock,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
-        self.execute_inner(block, &self.database)
+        let (result, db_transaction) =
+            self.execute_inner(block, &self.database, None)?.into();
+        Ok(UncommittedResult::new(
+            result,
+            StorageTransaction::new(db_transaction),
+        ))
+    }
+
+    /// Executes the block like [`Self::execute_without_commit`], but stops with
+    /// [`ExecutorError::Cancelled`] before the next transaction once the `cancel` is set.
+    pub fn execute_cancellable(
+        &self,
+        block: ExecutionBlock,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let (result, db_transaction) = self
+            .execute_inner(block, &self.database, Some(cancel))?
+            .into();
+        Ok(UncommittedResult::new(
+            result,
+            StorageTransaction::new(db_transaction),
+        ))
+    }
+
+    /// Executes the block on top of the uncommitted state of the `parent`, e.g. of the
+    /// previous block that is being imported. The `parent` should not be committed
+    /// during the execution, see [`Database::fork`]. The result can be committed only
+    /// after the `parent` is committed into the inner `Database`.
+    ///
+    /// The execution stops with [`ExecutorError::Cancelled`] before the next transaction
+    /// once the `cancel` is set.
+    pub fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        parent: &Database,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let (result, db_transaction) =
+            self.execute_inner(block, parent, Some(cancel))?.into();
+        Ok(UncommittedResult::new(
+            result,
+            StorageTransaction::new(SpeculativeTransaction::new(
+                db_transaction,
+                self.database.clone(),
+            )),
+        ))
     }
 
     pub fn dry_run(
@@ -251,7 +320,8 @@ where
         &self,
         block: ExecutionBlock,
         database: &Database,
-    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        cancel: Option<&AtomicBool>,
+    ) -> ExecutorResult<UncommittedResult<DatabaseTransaction>> {
         // Compute the block id before execution if there is one.
         let pre_exec_block_id = block.id();
 
@@ -264,7 +334,7 @@ where
 
         // Execute all transactions.
         let execution_data =
-            self.execute_transactions(&mut block_db_transaction, block.as_mut())?;
+            self.execute_transactions(&mut block_db_transaction, block.as_mut(), cancel)?;
 
         let ExecutionData {
             coinbase,
@@ -320,34 +390,46 @@ where
                     .block
                     .compress(&self.config.chain_conf.transaction_parameters),
             )?;
+        // The block doesn't carry the version of its producer, so only the version of
+        // blocks produced by this node is known. Validated blocks could be produced by
+        // another version that follows the same rules.
+        if pre_exec_block_id.is_none() {
+            block_db_transaction
+                .deref_mut()
+                .storage::<FuelBlockExecutorVersions>()
+                .insert(result.block.header().height(), &EXECUTOR_VERSION)?;
+        }
 
         // Get the complete fuel block.
-        Ok(UncommittedResult::new(
-            result,
-            StorageTransaction::new(block_db_transaction),
-        ))
+        Ok(UncommittedResult::new(result, block_db_transaction))
     }
 
     #[tracing::instrument(skip_all)]
-    /// Execute all transactions on the fuel block.
+    /// Execute all transactions on the fuel block. The execution is cancelled between
+    /// transactions once the `cancel` is set.
     fn execute_transactions(
         &self,
         block_db_transaction: &mut DatabaseTransaction,
         block: ExecutionType<&mut PartialFuelBlock>,
+        cancel: Option<&AtomicBool>,
     ) -> ExecutorResult<ExecutionData> {
+        // Split out the execution kind and partial block.
+        let (execution_kind, block) = block.split();
+
+        let block_height = *block.header.height();
+
         let mut data = ExecutionData {
             coinbase: 0,
+            coinbase_recipient: self
+                .config
+                .block_producer
+                .coinbase_recipient_at(block_height),
             message_ids: Vec::new(),
             tx_status: Vec::new(),
             skipped_transactions: Vec::new(),
         };
         let execution_data = &mut data;
 
-        // Split out the execution kind and partial block.
-        let (execution_kind, block) = block.split();
-
-        let block_height = *block.header.height();
-
         // Clean block from transactions and gather them from scratch.
         let mut iter = ::core::mem::take(&mut block.transactions).into_iter();
 
@@ -359,7 +441,7 @@ where
                 Transaction::mint(
                     TxPointer::new(block_height, Default::default()),
                     vec![Output::coin(
-                        self.config.block_producer.coinbase_recipient,
+                        execution_data.coinbase_recipient,
                         0, // We will set it later
                         AssetId::BASE,
                     )],
@@ -371,7 +453,13 @@ where
                 } else {
                     return Err(ExecutorError::CoinbaseIsNotFirstTransaction)
                 };
-                self.check_coinbase(block_height, mint, None)?
+                let mint = self.check_coinbase(block_height, mint, None)?;
+                // The recipient is chosen by the producer of the block, so transactions
+                // are validated against it instead of the local configuration.
+                if let Some(Output::Coin { to, .. }) = mint.outputs().first() {
+                    execution_data.coinbase_recipient = *to;
+                }
+                mint
             }
         };
 
@@ -383,57 +471,83 @@ where
             0
         };
 
-        let mut filtered_transactions: Vec<_> = iter
-            .filter_map(|transaction| {
-                let mut filter_tx = |mut tx, idx| {
-                    let mut tx_db_transaction = block_db_transaction.transaction();
-                    let result = self.execute_transaction(
-                        idx,
-                        &mut tx,
-                        &block.header,
-                        execution_data,
-                        execution_kind,
-                        &mut tx_db_transaction,
-                    );
-
-                    if let Err(err) = result {
-                        return match execution_kind {
-                            ExecutionKind::Production => {
-                                // If, during block production, we get an invalid transaction,
-                                // remove it from the block and continue block creation. An invalid
-                                // transaction means that the caller didn't validate it first, so
-                                // maybe something is wrong with validation rules in the `TxPool`
-                                // (or in another place that should validate it). Or we forgot to
-                                // clean up some dependent/conflict transactions. But it definitely
-                                // means that something went wrong, and we must fix it.
-                                execution_data.skipped_transactions.push((tx, err));
-                                None
-                            }
-                            ExecutionKind::DryRun | ExecutionKind::Validation => {
-                                Some(Err(err))
+        let transactions: Vec<_> = iter.collect();
+        if execution_kind == ExecutionKind::Validation && self.config.utxo_validation {
+            self.verify_signatures(&transactions)?;
+        }
+        if execution_kind == ExecutionKind::Validation {
+            self.verify_predicates(&transactions, block_height)?;
+        }
+        let executed_in_parallel = match execution_kind {
+            ExecutionKind::Production => self.execute_in_parallel(
+                &transactions,
+                tx_index,
+                &block.header,
+                execution_data,
+                block_db_transaction,
+                cancel,
+            )?,
+            ExecutionKind::DryRun | ExecutionKind::Validation => None,
+        };
+
+        let mut filtered_transactions: Vec<_> = match executed_in_parallel {
+            Some(transactions) => transactions,
+            None => transactions
+                .into_iter()
+                .filter_map(|transaction| {
+                    let mut filter_tx = |mut tx, idx| {
+                        let mut tx_db_transaction = block_db_transaction.transaction();
+                        let result = self.execute_transaction(
+                            idx,
+                            &mut tx,
+                            &block.header,
+                            execution_data,
+                            execution_kind,
+                            &mut tx_db_transaction,
+                        );
+
+                        if let Err(err) = result {
+                            return match execution_kind {
+                                ExecutionKind::Production => {
+                                    // If, during block production, we get an invalid transaction,
+                                    // remove it from the block and continue block creation. An invalid
+                                    // transaction means that the caller didn't validate it first, so
+                                    // maybe something is wrong with validation rules in the `TxPool`
+                                    // (or in another place that should validate it). Or we forgot to
+                                    // clean up some dependent/conflict transactions. But it definitely
+                                    // means that something went wrong, and we must fix it.
+                                    execution_data.skipped_transactions.push((tx, err));
+                                    None
+                                }
+                                ExecutionKind::DryRun | ExecutionKind::Validation => {
+                                    Some(Err(err))
+                                }
                             }
                         }
-                    }
 
-                    if let Err(err) = tx_db_transaction.commit() {
-                        return Some(Err(err.into()))
-                    }
-                    Some(Ok(tx))
-                };
+                        if let Err(err) = tx_db_transaction.commit() {
+                            return Some(Err(err.into()))
+                        }
+                        Some(Ok(tx))
+                    };
 
-                let filtered_tx = filter_tx(transaction, tx_index);
-                if filtered_tx.is_some() {
-                    tx_index += 1;
-                }
-                filtered_tx
-            })
-            .try_collect()?;
+                    if is_cancelled(cancel) {
+                        return Some(Err(ExecutorError::Cancelled))
+                    }
+                    let filtered_tx = filter_tx(transaction, tx_index);
+                    if filtered_tx.is_some() {
+                        tx_index += 1;
+                    }
+                    filtered_tx
+                })
+                .try_collect()?,
+        };
 
         // After the execution of all transactions in production mode, we can set the final fee.
         if execution_kind == ExecutionKind::Production {
             coinbase_tx.outputs_mut().clear();
             coinbase_tx.outputs_mut().push(Output::coin(
-                self.config.block_producer.coinbase_recipient,
+                execution_data.coinbase_recipient,
                 execution_data.coinbase,
                 AssetId::BASE,
             ));
@@ -458,6 +572,235 @@ where
         Ok(data)
     }
 
+    /// Verifies signatures of `transactions` of the validated block in batch. If the
+    /// batch is invalid, transactions are verified one by one to find the invalid one.
+    fn verify_signatures(&self, transactions: &[Transaction]) -> ExecutorResult<()> {
+        let params = &self.config.chain_conf.transaction_parameters;
+        let threads = self.config.block_executor.parallel_threads;
+        if verify_batch(transactions, params, threads) {
+            return Ok(())
+        }
+        for tx in transactions {
+            match tx {
+                Transaction::Script(script) => script.check_signatures(params),
+                Transaction::Create(create) => create.check_signatures(params),
+                Transaction::Mint(_) => Ok(()),
+            }
+            .map_err(TransactionValidityError::from)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies predicates of `transactions` of the validated block on several threads.
+    /// Results are collected in the order of the block, so the first invalid transaction
+    /// is reported regardless of the scheduling of threads.
+    fn verify_predicates(
+        &self,
+        transactions: &[Transaction],
+        block_height: BlockHeight,
+    ) -> ExecutorResult<()> {
+        let with_predicates: Vec<_> = transactions
+            .iter()
+            .filter(|tx| has_predicates(tx))
+            .collect();
+        let verify = |chunk: &[&Transaction]| -> ExecutorResult<()> {
+            chunk.iter().try_for_each(|tx| match tx {
+                Transaction::Script(script) => {
+                    self.verify_predicates_of(script, block_height)
+                }
+                Transaction::Create(create) => {
+                    self.verify_predicates_of(create, block_height)
+                }
+                Transaction::Mint(_) => Ok(()),
+            })
+        };
+        let threads = self.config.block_executor.parallel_threads.max(1);
+        if threads == 1 || with_predicates.len() < 2 {
+            return verify(&with_predicates)
+        }
+
+        let chunk_size = (with_predicates.len() + threads - 1) / threads;
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = with_predicates
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || verify(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+        results.into_iter().collect()
+    }
+
+    /// Verifies predicates of the `tx`. The transaction that fails basic checks is left
+    /// for the execution to report.
+    fn verify_predicates_of<Tx>(
+        &self,
+        tx: &Tx,
+        block_height: BlockHeight,
+    ) -> ExecutorResult<()>
+    where
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        match tx.clone().into_checked_basic(
+            block_height,
+            &self.config.chain_conf.transaction_parameters,
+        ) {
+            Ok(checked_tx) => self.verify_tx_predicates(checked_tx),
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Executes partitions of independent transactions on separate threads and merges
+    /// their changes in the order of partitions. Returns `None` if the block should be
+    /// executed sequentially: the parallel execution is disabled, there is nothing to
+    /// parallelize, or some transaction fails. The failed transaction is skipped and
+    /// shifts indexes of following transactions, so they are executed again. The
+    /// cancelled execution also returns `None`, the sequential execution reports it.
+    fn execute_in_parallel(
+        &self,
+        transactions: &[Transaction],
+        first_index: u16,
+        header: &PartialBlockHeader,
+        execution_data: &mut ExecutionData,
+        block_db_transaction: &mut DatabaseTransaction,
+        cancel: Option<&AtomicBool>,
+    ) -> ExecutorResult<Option<Vec<Transaction>>> {
+        let threads = self.config.block_executor.parallel_threads;
+        if threads < 2 || transactions.len() < 2 {
+            return Ok(None)
+        }
+        let partitions = partition(
+            transactions,
+            &self.config.chain_conf.transaction_parameters,
+            threads,
+        );
+        if partitions.len() < 2 {
+            return Ok(None)
+        }
+
+        let coinbase_recipient = execution_data.coinbase_recipient;
+        let parent: &DatabaseTransaction = block_db_transaction;
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = partitions
+                .iter()
+                .map(|partition| {
+                    scope.spawn(move || {
+                        self.execute_partition(
+                            partition,
+                            transactions,
+                            first_index,
+                            header,
+                            coinbase_recipient,
+                            parent,
+                            cancel,
+                        )
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+        let results: Vec<_> = match results.into_iter().collect::<Option<_>>() {
+            Some(results) => results,
+            None => return Ok(None),
+        };
+
+        // Changes are merged only after all partitions are executed successfully.
+        let mut executed: Vec<_> = vec![];
+        let mut partition_db_transactions = vec![];
+        for (partition_db_transaction, partition_executed) in results {
+            partition_db_transactions.push(partition_db_transaction);
+            executed.extend(partition_executed);
+        }
+        executed.sort_by_key(|(index, _, _)| *index);
+
+        let mut coinbase = execution_data.coinbase;
+        let mut transactions = Vec::with_capacity(executed.len());
+        let mut tx_status = vec![];
+        let mut message_ids = vec![];
+        for (_, tx, data) in executed {
+            coinbase = match coinbase.checked_add(data.coinbase) {
+                Some(coinbase) => coinbase,
+                // The sequential execution skips the transaction overflowing the fee.
+                None => return Ok(None),
+            };
+            transactions.push(tx);
+            tx_status.extend(data.tx_status);
+            message_ids.extend(data.message_ids);
+        }
+
+        // Partitions don't touch the same keys, so their changes don't overlap.
+        for mut partition_db_transaction in partition_db_transactions {
+            partition_db_transaction.commit()?;
+        }
+        execution_data.coinbase = coinbase;
+        execution_data.tx_status.extend(tx_status);
+        execution_data.message_ids.extend(message_ids);
+        Ok(Some(transactions))
+    }
+
+    /// Executes transactions of the `partition` in their order on top of the `parent`
+    /// database transaction. Returns `None` if any transaction fails or the execution
+    /// is cancelled.
+    #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
+    fn execute_partition(
+        &self,
+        partition: &[usize],
+        transactions: &[Transaction],
+        first_index: u16,
+        header: &PartialBlockHeader,
+        coinbase_recipient: Address,
+        parent: &DatabaseTransaction,
+        cancel: Option<&AtomicBool>,
+    ) -> Option<(
+        DatabaseTransaction,
+        Vec<(usize, Transaction, ExecutionData)>,
+    )> {
+        let mut partition_db_transaction = parent.transaction();
+        let mut executed = Vec::with_capacity(partition.len());
+        for index in partition {
+            if is_cancelled(cancel) {
+                return None
+            }
+            let idx = u16::try_from(*index).ok()?.checked_add(first_index)?;
+            let mut tx = transactions[*index].clone();
+            let mut data = ExecutionData {
+                coinbase: 0,
+                coinbase_recipient,
+                message_ids: Vec::new(),
+                tx_status: Vec::new(),
+                skipped_transactions: Vec::new(),
+            };
+            let mut tx_db_transaction = partition_db_transaction.transaction();
+            self.execute_transaction(
+                idx,
+                &mut tx,
+                header,
+                &mut data,
+                ExecutionKind::Production,
+                &mut tx_db_transaction,
+            )
+            .ok()?;
+            tx_db_transaction.commit().ok()?;
+            executed.push((*index, tx, data));
+        }
+        Some((partition_db_transaction, executed))
+    }
+
     fn execute_transaction(
         &self,
         idx: u16,
@@ -530,6 +873,7 @@ where
             TransactionExecutionStatus {
                 id: coinbase_id,
                 result: TransactionExecutionResult::Success { result: None },
+                gas_used: 0,
             },
         );
         if block_db_transaction
@@ -617,7 +961,10 @@ where
         let min_fee = checked_tx.metadata().min_fee();
         let max_fee = checked_tx.metadata().max_fee();
 
-        self.verify_tx_predicates(checked_tx.clone())?;
+        // predicates of validated blocks are verified in parallel before the execution
+        if execution_kind != ExecutionKind::Validation {
+            self.verify_tx_predicates(checked_tx.clone())?;
+        }
 
         if self.config.utxo_validation {
             // validate transaction has at least one coin
@@ -632,11 +979,14 @@ where
                 *header.height(),
                 header.da_height,
             )?;
-            // validate transaction signature
-            checked_tx
-                .transaction()
-                .check_signatures(&self.config.chain_conf.transaction_parameters)
-                .map_err(TransactionValidityError::from)?;
+            // validate transaction signature, signatures of validated blocks are
+            // verified in batch before the execution
+            if execution_kind != ExecutionKind::Validation {
+                checked_tx
+                    .transaction()
+                    .check_signatures(&self.config.chain_conf.transaction_parameters)
+                    .map_err(TransactionValidityError::from)?;
+            }
         }
 
         // execute transaction
@@ -647,7 +997,7 @@ where
         let vm_db = VmDatabase::new(
             sub_db_view.clone(),
             &header.consensus,
-            self.config.block_producer.coinbase_recipient,
+            execution_data.coinbase_recipient,
         );
         let mut vm = Interpreter::with_storage(
             vm_db,
@@ -673,6 +1023,8 @@ where
         // update block commitment
         let tx_fee =
             self.total_fee_paid(min_fee, max_fee, tx.price(), vm_result.receipts())?;
+        let gas_used =
+            self.total_gas_used(checked_tx.transaction(), vm_result.receipts())?;
 
         // Check or set the executed transaction.
         match execution_kind {
@@ -762,6 +1114,7 @@ where
         execution_data.tx_status.push(TransactionExecutionStatus {
             id: tx_id,
             result: status,
+            gas_used,
         });
         execution_data
             .message_ids
@@ -784,10 +1137,12 @@ where
                     // TODO: Check that fields are equal. We already do that check
                     //  in the `fuel-core-txpool`, so we need to reuse the code here.
                     if let Some(coin) = db.storage::<Coins>().get(utxo_id)? {
-                        if block_height < coin.tx_pointer.block_height() + coin.maturity {
-                            return Err(TransactionValidityError::CoinHasNotMatured(
-                                *utxo_id,
-                            )
+                        let matures_at = coin.tx_pointer.block_height() + coin.maturity;
+                        if block_height < matures_at {
+                            return Err(TransactionValidityError::CoinHasNotMatured {
+                                utxo_id: *utxo_id,
+                                matures_at,
+                            }
                             .into())
                         }
                     } else {
@@ -837,9 +1192,10 @@ where
                         .map_err(|e| ExecutorError::RelayerError(e.into()))?
                     {
                         if message.da_height > block_da_height {
-                            return Err(TransactionValidityError::MessageSpendTooEarly(
-                                *nonce,
-                            )
+                            return Err(TransactionValidityError::MessageSpendTooEarly {
+                                nonce: *nonce,
+                                da_height: message.da_height,
+                            }
                             .into())
                         }
                         if message.sender != *sender {
@@ -879,6 +1235,15 @@ where
                             )
                             .into())
                         }
+                    } else if let Some(message) = db.storage::<Messages>().get(nonce)? {
+                        // The relayer doesn't return messages newer than the block, but
+                        // they may be already relayed, e.g. for the dry run that uses
+                        // the DA height of the latest block.
+                        return Err(TransactionValidityError::MessageSpendTooEarly {
+                            nonce: *nonce,
+                            da_height: message.da_height,
+                        }
+                        .into())
                     } else {
                         return Err(
                             TransactionValidityError::MessageDoesNotExist(*nonce).into()
@@ -999,6 +1364,31 @@ where
         Ok(min_fee)
     }
 
+    /// Returns the gas actually used by the `tx`: its minimal gas and the gas used by
+    /// its script, if any.
+    fn total_gas_used<Tx: ExecutableTransaction>(
+        &self,
+        tx: &Tx,
+        receipts: &[Receipt],
+    ) -> ExecutorResult<Word> {
+        let min_gas = TransactionFee::checked_from_tx(
+            &self.config.chain_conf.transaction_parameters,
+            tx,
+        )
+        .ok_or(ExecutorError::FeeOverflow)?
+        .min_gas();
+        let script_gas = receipts
+            .iter()
+            .find_map(|r| match r {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+        min_gas
+            .checked_add(script_gas)
+            .ok_or(ExecutorError::FeeOverflow)
+    }
+
     /// Computes all zeroed or variable inputs.
     /// In production mode, updates the inputs with computed values.
     /// In validation mode, compares the inputs with computed inputs.
@@ -1397,13 +1787,15 @@ where
     }
 
     /// Associate all transactions within a block to their respective UTXO owners
+    /// and build the bloom filter of owners and contracts involved in the block.
     fn index_tx_owners_for_block(
         &self,
         block: &Block,
         block_db_transaction: &mut DatabaseTransaction,
     ) -> ExecutorResult<()> {
+        let block_height = *block.header().height();
+        let mut bloom = BlockBloom::default();
         for (tx_idx, tx) in block.transactions().iter().enumerate() {
-            let block_height = *block.header().height();
             let mut inputs = &[][..];
             let outputs;
             let tx_id = tx.id(&self.config.chain_conf.transaction_parameters);
@@ -1426,13 +1818,20 @@ where
                 outputs,
                 &tx_id,
                 tx_idx as u16,
+                &mut bloom,
                 block_db_transaction.deref_mut(),
             )?;
         }
+        block_db_transaction
+            .deref_mut()
+            .storage::<FuelBlockBlooms>()
+            .insert(&block_height, &bloom)?;
         Ok(())
     }
 
     /// Index the tx id by owner for all of the inputs and outputs
+    /// and add owners and contracts to the `bloom` of the block.
+    #[allow(clippy::too_many_arguments)]
     fn persist_owners_index(
         &self,
         block_height: BlockHeight,
@@ -1440,14 +1839,20 @@ where
         outputs: &[Output],
         tx_id: &Bytes32,
         tx_idx: u16,
+        bloom: &mut BlockBloom,
         db: &mut Database,
     ) -> ExecutorResult<()> {
         let mut owners = vec![];
         for input in inputs {
-            if let Input::CoinSigned(CoinSigned { owner, .. })
-            | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-            {
-                owners.push(owner);
+            match input {
+                Input::CoinSigned(CoinSigned { owner, .. })
+                | Input::CoinPredicate(CoinPredicate { owner, .. }) => {
+                    owners.push(owner);
+                }
+                Input::Contract(Contract { contract_id, .. }) => {
+                    bloom.insert(contract_id);
+                }
+                _ => {}
             }
         }
 
@@ -1458,7 +1863,10 @@ where
                 | Output::Variable { to, .. } => {
                     owners.push(to);
                 }
-                Output::Contract { .. } | Output::ContractCreated { .. } => {}
+                Output::ContractCreated { contract_id, .. } => {
+                    bloom.insert(contract_id);
+                }
+                Output::Contract { .. } => {}
             }
         }
 
@@ -1467,6 +1875,7 @@ where
         owners.dedup();
 
         for owner in owners {
+            bloom.insert(owner);
             db.record_tx_id_owner(
                 owner,
                 block_height,
@@ -1540,6 +1949,26 @@ impl Fee for CreateCheckedMetadata {
     }
 }
 
+fn is_cancelled(cancel: Option<&AtomicBool>) -> bool {
+    cancel.map_or(false, |cancel| cancel.load(Ordering::Relaxed))
+}
+
+fn has_predicates(tx: &Transaction) -> bool {
+    let inputs = match tx {
+        Transaction::Script(script) => script.inputs(),
+        Transaction::Create(create) => create.inputs(),
+        Transaction::Mint(_) => return false,
+    };
+    inputs.iter().any(|input| {
+        matches!(
+            input,
+            Input::CoinPredicate(_)
+                | Input::MessageCoinPredicate(_)
+                | Input::MessageDataPredicate(_)
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1555,6 +1984,7 @@ mod tests {
             field::{
                 Inputs,
                 Outputs,
+                Witnesses,
             },
             Buildable,
             Chargeable,
@@ -1729,6 +2159,22 @@ mod tests {
         assert!(skipped_transactions.is_empty());
     }
 
+    #[test]
+    fn speculative_execution_stops_once_cancelled() {
+        let database = Database::default();
+        let producer = Executor::test(database.clone(), Config::local_node());
+        let block = test_block(10);
+        let cancel = AtomicBool::new(true);
+
+        let result = producer.execute_speculatively(
+            ExecutionBlock::Production(block.into()),
+            &database,
+            &cancel,
+        );
+
+        assert!(matches!(result, Err(ExecutorError::Cancelled)));
+    }
+
     // Ensure transaction commitment != default after execution
     #[test]
     fn executor_commits_transactions_to_block() {
@@ -1770,6 +2216,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parallel_execution_produces_the_same_block_as_sequential() {
+        let transactions = (1..=10u64)
+            .map(|i| {
+                TxBuilder::new(i)
+                    .gas_limit(10)
+                    .coin_input(AssetId::default(), i * 100)
+                    .coin_output(AssetId::default(), i * 50)
+                    .change_output(AssetId::default())
+                    .build()
+                    .transaction()
+                    .clone()
+                    .into()
+            })
+            .collect_vec();
+        let mut block = Block::default();
+        *block.transactions_mut() = transactions;
+
+        let produce = |parallel_threads| {
+            let mut config = Config::local_node();
+            config.block_executor.parallel_threads = parallel_threads;
+            Executor::test(Default::default(), config)
+                .execute_and_commit(ExecutionBlock::Production(block.clone().into()))
+                .unwrap()
+        };
+        let sequential = produce(1);
+        let parallel = produce(4);
+
+        assert!(parallel.skipped_transactions.is_empty());
+        assert_eq!(parallel.block, sequential.block);
+    }
+
     mod coinbase {
         use super::*;
         use fuel_core_types::fuel_asm::GTFArgs;
@@ -2017,6 +2495,39 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn coinbase_recipient_follows_rotations() {
+            let mut producer = Executor::test(Default::default(), Config::local_node());
+            producer.config.block_producer.coinbase_recipient = Address::from([1u8; 32]);
+            producer
+                .config
+                .block_producer
+                .coinbase_rotations
+                .insert(2u32.into(), Address::from([2u8; 32]));
+            // The validator doesn't know about the rotation.
+            let validator = Executor::test(Default::default(), Config::local_node());
+
+            for (height, expected) in [(1u32, [1u8; 32]), (2, [2; 32]), (3, [2; 32])] {
+                let mut block = Block::default();
+                block.header_mut().consensus.height = height.into();
+                let ExecutionResult { block, .. } = producer
+                    .execute_and_commit(ExecutionBlock::Production(block.into()))
+                    .unwrap();
+
+                let recipient = match &block.transactions()[0] {
+                    Transaction::Mint(mint) => match mint.outputs().first() {
+                        Some(Output::Coin { to, .. }) => *to,
+                        _ => panic!("The coinbase should have the coin output"),
+                    },
+                    _ => panic!("The coinbase should be the first transaction"),
+                };
+                assert_eq!(recipient, Address::from(expected));
+                assert!(validator
+                    .execute_and_commit(ExecutionBlock::Validation(block))
+                    .is_ok());
+            }
+        }
+
         #[test]
         fn invalidate_is_not_first() {
             let mint = Transaction::mint(TxPointer::new(Default::default(), 1), vec![]);
@@ -2207,6 +2718,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Production(&mut block),
+                None,
             )
             .unwrap();
         let produce_result = &skipped_transactions[0].1;
@@ -2221,6 +2733,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Validation(&mut block),
+                None,
             )
             .unwrap();
 
@@ -2230,6 +2743,7 @@ mod tests {
         let verify_result = verifier.execute_transactions(
             &mut block_db_transaction,
             ExecutionType::Validation(&mut block),
+            None,
         );
         assert!(matches!(
             verify_result,
@@ -2256,6 +2770,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Production(&mut block),
+                None,
             )
             .unwrap();
         let produce_result = &skipped_transactions[0].1;
@@ -2270,6 +2785,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Validation(&mut block),
+                None,
             )
             .unwrap();
 
@@ -2279,6 +2795,7 @@ mod tests {
         let verify_result = verifier.execute_transactions(
             &mut block_db_transaction,
             ExecutionType::Validation(&mut block.clone()),
+            None,
         );
         assert!(matches!(
             verify_result,
@@ -2333,6 +2850,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Production(&mut block),
+                None,
             )
             .unwrap();
         let produce_result = &skipped_transactions[0].1;
@@ -2349,6 +2867,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Validation(&mut block),
+                None,
             )
             .unwrap();
 
@@ -2358,6 +2877,7 @@ mod tests {
         let verify_result = verifier.execute_transactions(
             &mut block_db_transaction,
             ExecutionType::Validation(&mut block),
+            None,
         );
         assert!(matches!(
             verify_result,
@@ -2367,6 +2887,105 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn executor_invalidates_blocks_with_invalid_signature() {
+        let transactions = (1..=4u64)
+            .map(|i| {
+                TxBuilder::new(i)
+                    .gas_limit(1)
+                    .coin_input(AssetId::default(), 10)
+                    .change_output(AssetId::default())
+                    .build()
+                    .transaction()
+                    .clone()
+                    .into()
+            })
+            .collect_vec();
+        let mut block = Block::default();
+        *block.transactions_mut() = transactions;
+
+        let producer = Executor::test(Default::default(), Config::local_node());
+        let ExecutionResult { mut block, .. } = producer
+            .execute_and_commit(ExecutionBlock::Production(block.into()))
+            .unwrap();
+
+        // replace the signature of the third transaction after the coinbase
+        if let Transaction::Script(script) = &mut block.transactions_mut()[3] {
+            script.witnesses_mut()[0] = vec![0; 64].into();
+        }
+
+        let mut config = Config {
+            utxo_validation: true,
+            ..Config::local_node()
+        };
+        config.block_executor.parallel_threads = 2;
+        let verifier = Executor::test(Default::default(), config);
+        let verify_result =
+            verifier.execute_and_commit(ExecutionBlock::Validation(block));
+        assert!(matches!(
+            verify_result,
+            Err(ExecutorError::TransactionValidity(
+                TransactionValidityError::Validation(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn executor_invalidates_blocks_with_invalid_predicate() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let predicate_input = |predicate: Vec<u8>, utxo_id| {
+            let owner = Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT);
+            Input::coin_predicate(
+                utxo_id,
+                owner,
+                10,
+                AssetId::default(),
+                Default::default(),
+                Default::default(),
+                predicate,
+                vec![],
+            )
+        };
+        let transactions = (0..4)
+            .map(|_| {
+                let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+                TransactionBuilder::script(vec![], vec![])
+                    .gas_limit(100)
+                    .add_input(predicate_input(predicate, rng.gen()))
+                    .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+                    .finalize_as_transaction()
+            })
+            .collect_vec();
+        let mut block = Block::default();
+        *block.transactions_mut() = transactions;
+
+        let producer = Executor::test(Default::default(), Config::local_node());
+        let ExecutionResult { mut block, .. } = producer
+            .execute_and_commit(ExecutionBlock::Production(block.into()))
+            .unwrap();
+
+        // replace the predicate of the third transaction after the coinbase with the
+        // one that always fails
+        if let Transaction::Script(script) = &mut block.transactions_mut()[3] {
+            let utxo_id = *script.inputs()[0].utxo_id().unwrap();
+            let predicate = op::ret(RegId::ZERO).to_bytes().to_vec();
+            script.inputs_mut()[0] = predicate_input(predicate, utxo_id);
+        }
+        let invalid_tx_id = block.transactions()[3].id(&ConsensusParameters::DEFAULT);
+
+        let mut config = Config::local_node();
+        config.block_executor.parallel_threads = 2;
+        let verifier = Executor::test(Default::default(), config);
+        let verify_result =
+            verifier.execute_and_commit(ExecutionBlock::Validation(block));
+        assert!(matches!(
+            verify_result,
+            Err(ExecutorError::TransactionValidity(
+                TransactionValidityError::InvalidPredicate(id)
+            )) if id == invalid_tx_id
+        ));
+    }
+
     // corrupt a produced block by randomizing change amount
     // and verify that the executor invalidates the tx
     #[test]
@@ -3389,6 +4008,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Production(&mut block),
+                None,
             )
             .unwrap();
         assert_eq!(skipped_transactions.len(), 0);
@@ -3447,6 +4067,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Production(&mut block),
+                None,
             )
             .unwrap();
         assert_eq!(skipped_transactions.len(), 0);
@@ -3529,8 +4150,8 @@ mod tests {
         assert!(matches!(
             err,
             &ExecutorError::TransactionValidity(
-                TransactionValidityError::MessageSpendTooEarly(_)
-            )
+                TransactionValidityError::MessageSpendTooEarly { da_height, .. }
+            ) if da_height == message.da_height
         ));
 
         // Produced block is valid
@@ -3545,7 +4166,7 @@ mod tests {
         assert!(matches!(
             res,
             Err(ExecutorError::TransactionValidity(
-                TransactionValidityError::MessageSpendTooEarly(_)
+                TransactionValidityError::MessageSpendTooEarly { .. }
             ))
         ));
     }
@@ -3574,6 +4195,7 @@ mod tests {
             .execute_transactions(
                 &mut block_db_transaction,
                 ExecutionType::Production(&mut block),
+                None,
             )
             .unwrap();
         // One of two transactions is skipped.
@@ -3592,6 +4214,7 @@ mod tests {
         exec.execute_transactions(
             &mut block_db_transaction,
             ExecutionType::Validation(&mut block),
+            None,
         )
         .unwrap();
 
@@ -3602,6 +4225,7 @@ mod tests {
         let res = exec.execute_transactions(
             &mut block_db_transaction,
             ExecutionType::Validation(&mut block),
+            None,
         );
         assert!(matches!(
             res,
@@ -4,7 +4,7 @@ use fuel_core_types::{
     fuel_types::Nonce,
 };
 
-pub trait RelayerPort {
+pub trait RelayerPort: Send + Sync {
     /// Get a message from the relayer if it has been
     /// synced and is <= the given da height.
     fn get_message(
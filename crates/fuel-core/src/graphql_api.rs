@@ -1,3 +1,8 @@
+use crate::{
+    schema::cursor::CursorSecret,
+    service::slow_log::SlowLog,
+};
+use allowlist::QueryAllowlist;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -5,26 +10,91 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
+    fuel_types::Bytes32,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    convert::Infallible,
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    net::SocketAddr,
+    str::FromStr,
+    time::Duration,
+};
+use subtle::ConstantTimeEq;
 
+pub mod allowlist;
+pub mod error;
+pub mod idempotency;
 pub mod ports;
 #[cfg(feature = "metrics")]
 pub(crate) mod prometheus;
 pub mod service;
+pub(crate) mod slow_log;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    pub additional_addrs: Vec<SocketAddr>,
     pub utxo_validation: bool,
     pub manual_blocks_enabled: bool,
+    pub debug: bool,
     pub vm_backtrace: bool,
-    pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
     pub transaction_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    pub cursor_secret: CursorSecret,
+    /// Rejects requests to deprecated fields before their sunset.
+    pub disable_deprecated_fields: bool,
+    /// How long the node remembers idempotency keys of submitted transactions.
+    pub idempotency_key_ttl: Duration,
+    /// Serves the admin API to inspect and control sub-services of the node.
+    pub admin_api: bool,
+    /// Requires the token in the `Authorization: Bearer` header of admin requests.
+    pub admin_api_token: Option<AdminApiToken>,
+    /// Records slow requests. The recorded operations are served by the admin API.
+    pub slow_log: Option<SlowLog>,
+    /// Executes only the allowed documents, rejecting all other requests.
+    pub query_allowlist: Option<QueryAllowlist>,
+    /// The hash of the consensus-relevant configuration of the node.
+    pub config_hash: Bytes32,
+}
+
+/// The token that authenticates requests to the admin API.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AdminApiToken(String);
+
+impl AdminApiToken {
+    pub fn new(token: String) -> Self {
+        Self(token)
+    }
+
+    /// Returns whether the `Authorization` header carries the token. The comparison
+    /// takes the same time for any token of the same length, so the token can't be
+    /// guessed byte by byte from the timing of responses.
+    pub fn authorizes(&self, authorization: &str) -> bool {
+        match authorization.strip_prefix("Bearer ") {
+            Some(token) => token.as_bytes().ct_eq(self.0.as_bytes()).into(),
+            None => false,
+        }
+    }
+}
+
+impl Debug for AdminApiToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AdminApiToken(..)")
+    }
+}
+
+impl FromStr for AdminApiToken {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s.to_string()))
+    }
 }
 
 pub trait IntoApiResult<T> {
@@ -0,0 +1,262 @@
+//! The allowlist mode of the GraphQL API. Only documents registered in the directory
+//! are executed, all other requests are rejected before the validation, so the public
+//! node serves only known and reviewed queries.
+//!
+//! Every `*.graphql` file of the directory is one document. Documents are compiled once
+//! when they are loaded: they are parsed to reject invalid files early and normalized,
+//! so the request matches the document regardless of its formatting and comments. The
+//! documents used by `fuel-core-client` are dumped by `cargo xtask dump-client-queries`.
+
+use crate::fuel_core_graphql_api::error::{
+    coded_error,
+    ErrorCode,
+};
+use anyhow::Context;
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextParseQuery,
+    },
+    parser::types::ExecutableDocument,
+    ServerResult,
+    Variables,
+};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        RwLock,
+    },
+};
+
+/// The extension of files with documents.
+const DOCUMENT_EXTENSION: &str = "graphql";
+
+/// The documents allowed to be executed. Clones share the documents, so the reload is
+/// visible to all of them.
+#[derive(Clone, Debug)]
+pub struct QueryAllowlist {
+    dir: PathBuf,
+    documents: Arc<RwLock<HashSet<String>>>,
+}
+
+impl QueryAllowlist {
+    /// Loads documents from the `dir`. Fails if any document is invalid.
+    pub fn load(dir: PathBuf) -> anyhow::Result<Self> {
+        let documents = load_documents(&dir)?;
+        tracing::info!(
+            "Loaded {} allowed GraphQL documents from {:?}",
+            documents.len(),
+            dir
+        );
+        Ok(Self {
+            dir,
+            documents: Arc::new(RwLock::new(documents)),
+        })
+    }
+
+    /// Replaces documents with the current content of the directory and returns their
+    /// number. If any document is invalid, the previous documents stay allowed.
+    pub fn reload(&self) -> anyhow::Result<usize> {
+        let documents = load_documents(&self.dir)?;
+        let len = documents.len();
+        *self.documents.write().expect("poisoned") = documents;
+        tracing::info!("Reloaded {} allowed GraphQL documents", len);
+        Ok(len)
+    }
+
+    /// Returns the number of allowed documents.
+    pub fn len(&self) -> usize {
+        self.documents.read().expect("poisoned").len()
+    }
+
+    /// Returns whether no documents are allowed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the `query` is one of allowed documents.
+    pub fn allows(&self, query: &str) -> bool {
+        self.documents
+            .read()
+            .expect("poisoned")
+            .contains(&normalize(query))
+    }
+}
+
+fn load_documents(dir: &Path) -> anyhow::Result<HashSet<String>> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read the allowlist directory {dir:?}"))?;
+    let mut documents = HashSet::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|extension| extension.to_str())
+            != Some(DOCUMENT_EXTENSION)
+        {
+            continue
+        }
+        let document = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read the document {path:?}"))?;
+        let document = compile(&document)
+            .with_context(|| format!("The document {path:?} is invalid"))?;
+        documents.insert(document);
+    }
+    Ok(documents)
+}
+
+/// Parses the `document` and returns its normalized form.
+fn compile(document: &str) -> anyhow::Result<String> {
+    async_graphql::parser::parse_query(document)?;
+    Ok(normalize(document))
+}
+
+/// Returns tokens of the `document` separated by single spaces. Comments, whitespaces
+/// and commas are insignificant in GraphQL, so they are dropped.
+fn normalize(document: &str) -> String {
+    let mut tokens: Vec<String> = vec![];
+    let mut chars = document.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' || c == '\r' {
+                        break
+                    }
+                }
+            }
+            '"' => {
+                let mut token = String::from('"');
+                let block = if chars.next_if_eq(&'"').is_some() {
+                    token.push('"');
+                    if chars.next_if_eq(&'"').is_none() {
+                        // The empty string.
+                        tokens.push(token);
+                        continue
+                    }
+                    token.push('"');
+                    true
+                } else {
+                    false
+                };
+                // The number of consecutive closing quotes.
+                let mut quotes = 0;
+                while let Some(c) = chars.next() {
+                    token.push(c);
+                    if c == '\\' {
+                        token.extend(chars.next());
+                        quotes = 0;
+                    } else if c == '"' {
+                        quotes += 1;
+                        if !block || quotes == 3 {
+                            break
+                        }
+                    } else {
+                        quotes = 0;
+                    }
+                }
+                tokens.push(token);
+            }
+            c if c.is_whitespace() || c == ',' || c == '\u{feff}' => {}
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '-' => {
+                // Numbers may have the sign, the fraction and the exponent.
+                let number = c.is_ascii_digit() || c == '-';
+                let mut token = String::from(c);
+                while let Some(c) = chars.next_if(|c| {
+                    c.is_ascii_alphanumeric()
+                        || *c == '_'
+                        || (number && matches!(c, '.' | '+' | '-'))
+                }) {
+                    token.push(c);
+                }
+                tokens.push(token);
+            }
+            c => tokens.push(c.to_string()),
+        }
+    }
+    tokens.join(" ")
+}
+
+/// Rejects documents that are not in the [`QueryAllowlist`].
+pub(crate) struct AllowlistExtension {
+    pub allowlist: QueryAllowlist,
+}
+
+impl ExtensionFactory for AllowlistExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(AllowlistExtension {
+            allowlist: self.allowlist.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for AllowlistExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        if !self.allowlist.allows(query) {
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "query_not_allowed",
+                "The query is not in the allowlist of the node",
+            )
+            .into_server_error(Default::default()))
+        }
+        next.run(ctx, query, variables).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatting_and_comments_are_ignored() {
+        let document = "query Chain($height: U64) {\n  # The latest block\n  \
+            block(height: $height) { id, header { height } }\n}";
+        let compact =
+            "query Chain($height:U64){block(height:$height){id header{height}}}";
+
+        assert_eq!(normalize(document), normalize(compact));
+        assert_eq!(
+            normalize(compact),
+            "query Chain ( $ height : U64 ) { block ( height : $ height ) \
+            { id header { height } } }"
+        );
+    }
+
+    #[test]
+    fn strings_are_kept_as_is() {
+        assert_eq!(
+            normalize(r#"{ a(x: "b,  #c\"") b(y: "") c(z: """ d "" """) }"#),
+            r#"{ a ( x : "b,  #c\"" ) b ( y : "" ) c ( z : """ d "" """ ) }"#
+        );
+    }
+
+    #[test]
+    fn different_documents_are_different() {
+        assert_ne!(
+            normalize("{ chain { name } }"),
+            normalize("{ chain { daHeight } }")
+        );
+        assert_eq!(normalize("{ a(x: -1.5e+3) }"), "{ a ( x : -1.5e+3 ) }");
+        assert_eq!(normalize("{ ...on A }"), normalize("{ ... on A }"));
+    }
+
+    #[test]
+    fn invalid_document_is_rejected() {
+        assert!(compile("{ chain { name }").is_err());
+        assert!(compile("{ chain { name } }").is_ok());
+    }
+}
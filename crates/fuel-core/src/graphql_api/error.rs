@@ -0,0 +1,296 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextResolve,
+        ResolveInfo,
+    },
+    ErrorExtensionValues,
+    ErrorExtensions,
+    ServerError,
+    ServerResult,
+    Value,
+};
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::{
+    fuel_tx::CheckError,
+    services::{
+        executor::{
+            Error as ExecutorError,
+            TransactionValidityError,
+        },
+        graphql_api::{
+            ERROR_CODE_EXTENSION,
+            ERROR_REASON_EXTENSION,
+            ERROR_VALID_AT_DA_HEIGHT_EXTENSION,
+            ERROR_VALID_AT_HEIGHT_EXTENSION,
+        },
+        txpool::Error as TxPoolError,
+    },
+};
+use std::{
+    fmt::Display,
+    sync::Arc,
+};
+
+pub use fuel_core_types::services::graphql_api::ErrorCode;
+
+/// Creates a new error with the `code` and `reason` extensions.
+pub fn coded_error(
+    code: ErrorCode,
+    reason: &str,
+    message: impl Display,
+) -> async_graphql::Error {
+    async_graphql::Error::new(message.to_string()).extend_with(|_, extensions| {
+        set_code(extensions, code, reason);
+    })
+}
+
+fn set_code(extensions: &mut ErrorExtensionValues, code: ErrorCode, reason: &str) {
+    extensions.set(ERROR_CODE_EXTENSION, code.as_str());
+    extensions.set(ERROR_REASON_EXTENSION, reason);
+}
+
+/// Converts the error of the service into the GraphQL error with the [`ErrorCode`].
+pub trait IntoCodedError {
+    /// Classifies the error and attaches the `code` and `reason` extensions.
+    fn into_coded_error(self) -> async_graphql::Error;
+}
+
+impl IntoCodedError for StorageError {
+    fn into_coded_error(self) -> async_graphql::Error {
+        match &self {
+            StorageError::NotFound(entity, _) => {
+                // The `entity` is a full path to the type, the last segment is enough.
+                let entity = entity.rsplit("::").next().unwrap_or(entity);
+                coded_error(ErrorCode::NotFound, entity, self)
+            }
+            _ => coded_error(ErrorCode::Internal, "storage", self),
+        }
+    }
+}
+
+impl IntoCodedError for TxPoolError {
+    fn into_coded_error(self) -> async_graphql::Error {
+        let valid_at = txpool_valid_at(&self);
+        with_valid_at(
+            coded_error(ErrorCode::TxValidation, self.reason(), self),
+            valid_at,
+        )
+    }
+}
+
+impl IntoCodedError for anyhow::Error {
+    fn into_coded_error(self) -> async_graphql::Error {
+        if let Some(error) = self.downcast_ref::<TxPoolError>() {
+            let valid_at = txpool_valid_at(error);
+            with_valid_at(
+                coded_error(ErrorCode::TxValidation, error.reason(), self),
+                valid_at,
+            )
+        } else if self.downcast_ref::<CheckError>().is_some() {
+            coded_error(ErrorCode::TxValidation, "invalid_transaction", self)
+        } else if let Some(error) = self.downcast_ref::<ExecutorError>() {
+            let (reason, valid_at) = executor_reason(error);
+            with_valid_at(coded_error(ErrorCode::TxValidation, reason, self), valid_at)
+        } else {
+            match self.downcast::<StorageError>() {
+                Ok(error) => error.into_coded_error(),
+                Err(error) => coded_error(ErrorCode::Internal, "internal", error),
+            }
+        }
+    }
+}
+
+/// The extension of the error with the height from which the transaction becomes valid.
+type ValidAt = (&'static str, u64);
+
+fn txpool_valid_at(error: &TxPoolError) -> Option<ValidAt> {
+    match error {
+        TxPoolError::NotInsertedInputUtxoIdNotMatured { matures_at, .. } => {
+            Some((ERROR_VALID_AT_HEIGHT_EXTENSION, **matures_at as u64))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the reason of the execution error and the height from which the transaction
+/// becomes valid if its inputs are not spendable yet.
+fn executor_reason(error: &ExecutorError) -> (&'static str, Option<ValidAt>) {
+    match error {
+        ExecutorError::TransactionValidity(
+            TransactionValidityError::CoinHasNotMatured { matures_at, .. },
+        ) => (
+            "coin_not_matured",
+            Some((ERROR_VALID_AT_HEIGHT_EXTENSION, **matures_at as u64)),
+        ),
+        ExecutorError::TransactionValidity(
+            TransactionValidityError::MessageSpendTooEarly { da_height, .. },
+        ) => (
+            "message_not_relayed",
+            Some((ERROR_VALID_AT_DA_HEIGHT_EXTENSION, **da_height)),
+        ),
+        _ => ("execution", None),
+    }
+}
+
+fn with_valid_at(
+    error: async_graphql::Error,
+    valid_at: Option<ValidAt>,
+) -> async_graphql::Error {
+    match valid_at {
+        Some((extension, height)) => {
+            error.extend_with(|_, extensions| extensions.set(extension, height))
+        }
+        None => error,
+    }
+}
+
+/// The extension attaches the [`ErrorCode`] to all errors returned by resolvers that were
+/// not classified explicitly. Errors of arguments parsing are marked as
+/// [`ErrorCode::InvalidInput`], all other errors are [`ErrorCode::Internal`].
+pub(crate) struct ErrorCodeExtension;
+
+impl ExtensionFactory for ErrorCodeExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ErrorCodeExtension)
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for ErrorCodeExtension {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        next.run(ctx, info).await.map_err(with_default_code)
+    }
+}
+
+fn with_default_code(mut error: ServerError) -> ServerError {
+    let extensions = error.extensions.get_or_insert_with(Default::default);
+    if extensions.get(ERROR_CODE_EXTENSION).is_none() {
+        if is_input_value_error(&error.message) {
+            set_code(extensions, ErrorCode::InvalidInput, "invalid_argument");
+        } else {
+            set_code(extensions, ErrorCode::Internal, "internal");
+        }
+    }
+    error
+}
+
+/// The `async_graphql` doesn't provide a way to distinguish errors of the arguments parsing,
+/// so we rely on the messages produced by `InputValueError`.
+fn is_input_value_error(message: &str) -> bool {
+    message.starts_with("Failed to parse")
+        || message.starts_with("Expected input type")
+        || message.starts_with("Invalid value for argument")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::not_found;
+
+    fn code_and_reason(error: &async_graphql::Error) -> (Value, Value) {
+        let extensions = error.extensions.as_ref().expect("Extensions are set");
+        (
+            extensions.get(ERROR_CODE_EXTENSION).cloned().unwrap(),
+            extensions.get(ERROR_REASON_EXTENSION).cloned().unwrap(),
+        )
+    }
+
+    #[test]
+    fn not_found_storage_error_has_not_found_code() {
+        let error = not_found!("Block").into_coded_error();
+
+        assert_eq!(
+            code_and_reason(&error),
+            (Value::from("NOT_FOUND"), Value::from("Block"))
+        );
+    }
+
+    #[test]
+    fn txpool_error_inside_anyhow_has_tx_validation_code() {
+        let error: anyhow::Error = TxPoolError::NotInsertedGasPriceTooLow.into();
+        let error = error.into_coded_error();
+
+        assert_eq!(
+            code_and_reason(&error),
+            (
+                Value::from("TX_VALIDATION"),
+                Value::from("gas_price_too_low")
+            )
+        );
+        assert_eq!(
+            error.message,
+            TxPoolError::NotInsertedGasPriceTooLow.to_string()
+        );
+    }
+
+    #[test]
+    fn immature_coin_error_has_valid_at_height() {
+        let error: anyhow::Error = ExecutorError::TransactionValidity(
+            TransactionValidityError::CoinHasNotMatured {
+                utxo_id: Default::default(),
+                matures_at: 10u32.into(),
+            },
+        )
+        .into();
+        let error = error.into_coded_error();
+
+        assert_eq!(
+            code_and_reason(&error),
+            (
+                Value::from("TX_VALIDATION"),
+                Value::from("coin_not_matured")
+            )
+        );
+        let extensions = error.extensions.as_ref().unwrap();
+        assert_eq!(
+            extensions.get(ERROR_VALID_AT_HEIGHT_EXTENSION),
+            Some(&Value::from(10u64))
+        );
+    }
+
+    #[test]
+    fn unknown_error_has_internal_code() {
+        let error = anyhow::anyhow!("Something went wrong").into_coded_error();
+
+        assert_eq!(
+            code_and_reason(&error),
+            (Value::from("INTERNAL"), Value::from("internal"))
+        );
+    }
+
+    #[test]
+    fn default_code_doesnt_override_existing_code() {
+        let error = coded_error(ErrorCode::NotFound, "Block", "The block is not found")
+            .into_server_error(Default::default());
+        let error = with_default_code(error);
+
+        let extensions = error.extensions.unwrap();
+        assert_eq!(
+            extensions.get(ERROR_CODE_EXTENSION),
+            Some(&Value::from("NOT_FOUND"))
+        );
+    }
+
+    #[test]
+    fn default_code_for_argument_errors_is_invalid_input() {
+        let error = ServerError::new(
+            r#"Failed to parse "HexString": Invalid hex"#,
+            Default::default(),
+        );
+        let error = with_default_code(error);
+
+        let extensions = error.extensions.unwrap();
+        assert_eq!(
+            extensions.get(ERROR_CODE_EXTENSION),
+            Some(&Value::from("INVALID_INPUT"))
+        );
+    }
+}
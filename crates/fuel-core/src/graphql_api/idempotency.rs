@@ -0,0 +1,237 @@
+use fuel_core_types::fuel_tx::TxId;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    mem::size_of,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The maximum number of remembered idempotency keys. The oldest keys are forgotten
+/// first when the limit is reached.
+pub const MAX_IDEMPOTENCY_KEYS: usize = 10_000;
+/// The maximum memory used by remembered idempotency keys in bytes. The oldest keys are
+/// forgotten first when the limit is reached.
+pub const MAX_IDEMPOTENCY_KEYS_SIZE: usize = 2 * 1024 * 1024;
+/// The maximum length of the idempotency key in bytes.
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 128;
+
+/// Remembers ids of transactions submitted with idempotency keys for the `ttl`, so
+/// clients can safely retry the submission with the same key.
+#[derive(Clone)]
+pub struct IdempotencyKeys {
+    ttl: Duration,
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: HashMap<String, Entry>,
+    /// The keys in the order of insertion, which is also the order of expiration.
+    order: VecDeque<(String, Instant)>,
+    /// The memory used by the keys in the `order` and their entries.
+    size: usize,
+}
+
+struct Entry {
+    id: TxId,
+    status: SubmissionStatus,
+    inserted_at: Instant,
+}
+
+/// The status of the submission holding the idempotency key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionStatus {
+    /// The transaction is being inserted into the pool.
+    Pending,
+    /// The transaction was inserted into the pool.
+    Submitted,
+}
+
+impl IdempotencyKeys {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            inner: Default::default(),
+        }
+    }
+
+    /// Reserves the `key` for the transaction with the `id` before its submission.
+    /// Returns the id and the status of the transaction that already holds the `key` if
+    /// it isn't expired, then the `key` isn't reserved. The first reservation wins if the
+    /// key is reserved concurrently.
+    pub fn reserve(&self, key: String, id: TxId) -> Option<(TxId, SubmissionStatus)> {
+        let mut inner = self.inner.lock().expect("The lock is poisoned");
+        let now = Instant::now();
+        let size = entry_size(&key);
+        inner.prune(now, self.ttl, size);
+        if let Some(entry) = inner.entries.get(&key) {
+            return Some((entry.id, entry.status))
+        }
+        inner.size += size;
+        inner.order.push_back((key.clone(), now));
+        inner.entries.insert(
+            key,
+            Entry {
+                id,
+                status: SubmissionStatus::Pending,
+                inserted_at: now,
+            },
+        );
+        None
+    }
+
+    /// Marks the `key` reserved for the transaction with the `id` as submitted, because
+    /// the transaction was inserted into the pool.
+    pub fn confirm(&self, key: &str, id: &TxId) {
+        let mut inner = self.inner.lock().expect("The lock is poisoned");
+        match inner.entries.get_mut(key) {
+            Some(entry) if entry.id == *id => entry.status = SubmissionStatus::Submitted,
+            _ => {}
+        }
+    }
+
+    /// Releases the `key` reserved for the transaction with the `id`, because its
+    /// submission failed.
+    pub fn release(&self, key: &str, id: &TxId) {
+        let mut inner = self.inner.lock().expect("The lock is poisoned");
+        if matches!(inner.entries.get(key), Some(entry) if entry.id == *id) {
+            inner.entries.remove(key);
+        }
+    }
+}
+
+impl Inner {
+    /// Forgets expired keys and the oldest keys until there is room for the new key of
+    /// the `size`.
+    fn prune(&mut self, now: Instant, ttl: Duration, size: usize) {
+        while let Some((key, inserted_at)) = self.order.front() {
+            let expired = now.duration_since(*inserted_at) >= ttl;
+            if !expired
+                && self.order.len() < MAX_IDEMPOTENCY_KEYS
+                && self.size + size <= MAX_IDEMPOTENCY_KEYS_SIZE
+            {
+                break
+            }
+            let inserted_at = *inserted_at;
+            let key = key.clone();
+            self.order.pop_front();
+            self.size = self.size.saturating_sub(entry_size(&key));
+            if self
+                .entries
+                .get(&key)
+                .map_or(false, |entry| entry.inserted_at == inserted_at)
+            {
+                self.entries.remove(&key);
+            }
+        }
+    }
+}
+
+/// The memory used by the `key` in the order of insertion and by its entry.
+fn entry_size(key: &str) -> usize {
+    2 * key.len() + size_of::<(String, Instant)>() + size_of::<(String, Entry)>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reserve(keys: &IdempotencyKeys, key: &str, id: TxId) -> Option<TxId> {
+        keys.reserve(key.to_string(), id).map(|(id, _)| id)
+    }
+
+    #[test]
+    fn returns_transaction_of_reserved_key() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        assert_eq!(reserve(&keys, "key", TxId::from([1; 32])), None);
+
+        assert_eq!(
+            reserve(&keys, "key", TxId::from([2; 32])),
+            Some(TxId::from([1; 32]))
+        );
+        assert_eq!(reserve(&keys, "other", TxId::from([2; 32])), None);
+    }
+
+    #[test]
+    fn released_key_can_be_reserved_again() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        reserve(&keys, "key", TxId::from([1; 32]));
+        // Only the transaction holding the key releases it.
+        keys.release("key", &TxId::from([2; 32]));
+        assert_eq!(
+            reserve(&keys, "key", TxId::from([2; 32])),
+            Some(TxId::from([1; 32]))
+        );
+
+        keys.release("key", &TxId::from([1; 32]));
+
+        assert_eq!(reserve(&keys, "key", TxId::from([2; 32])), None);
+    }
+
+    #[test]
+    fn forgets_expired_keys() {
+        let keys = IdempotencyKeys::new(Duration::ZERO);
+        reserve(&keys, "key", TxId::from([1; 32]));
+
+        assert_eq!(reserve(&keys, "key", TxId::from([2; 32])), None);
+    }
+
+    #[test]
+    fn forgets_oldest_keys_over_limit() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        for i in 0..=MAX_IDEMPOTENCY_KEYS {
+            reserve(&keys, &i.to_string(), TxId::from([1; 32]));
+        }
+
+        let last = MAX_IDEMPOTENCY_KEYS.to_string();
+        assert_eq!(reserve(&keys, "0", TxId::from([2; 32])), None);
+        assert_eq!(
+            reserve(&keys, &last, TxId::from([2; 32])),
+            Some(TxId::from([1; 32]))
+        );
+    }
+
+    #[test]
+    fn confirmed_key_is_submitted() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        let id = TxId::from([1; 32]);
+        keys.reserve("key".to_string(), id);
+        assert_eq!(
+            keys.reserve("key".to_string(), id),
+            Some((id, SubmissionStatus::Pending))
+        );
+
+        keys.confirm("key", &id);
+
+        assert_eq!(
+            keys.reserve("key".to_string(), id),
+            Some((id, SubmissionStatus::Submitted))
+        );
+    }
+
+    #[test]
+    fn forgets_oldest_keys_over_size() {
+        let keys = IdempotencyKeys::new(Duration::from_secs(60));
+        let key = |i: usize| format!("{:0>1$}", i, MAX_IDEMPOTENCY_KEY_LENGTH);
+        let capacity = MAX_IDEMPOTENCY_KEYS_SIZE / entry_size(&key(0));
+        assert!(capacity < MAX_IDEMPOTENCY_KEYS);
+        for i in 0..=capacity {
+            reserve(&keys, &key(i), TxId::from([1; 32]));
+        }
+
+        assert_eq!(reserve(&keys, &key(0), TxId::from([2; 32])), None);
+        assert_eq!(
+            reserve(&keys, &key(capacity), TxId::from([2; 32])),
+            Some(TxId::from([1; 32]))
+        );
+    }
+}
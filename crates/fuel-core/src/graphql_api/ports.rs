@@ -1,5 +1,24 @@
+use crate::service::{
+    inclusion_monitor::{
+        InclusionStatistics,
+        TxInclusion,
+    },
+    utxo_stats::UtxoSetStatistics,
+    version_check::VersionAdvisory,
+};
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_poa::{
+    service::{
+        ManualGasLimit,
+        ProducedBlock,
+    },
+    Trigger,
+};
+use fuel_core_producer::block_producer::BlockTemplate;
+use fuel_core_services::{
+    stream::BoxStream,
+    Health,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -10,6 +29,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -21,11 +41,21 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxUpdate;
+use fuel_core_txpool::{
+    service::{
+        PoolEvent,
+        TxUpdate,
+    },
+    timeline::TxStageTime,
+};
 use fuel_core_types::{
-    blockchain::primitives::{
-        BlockId,
-        DaBlockHeight,
+    blockchain::{
+        consensus::poa::PreConfirmation,
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
+        SealedBlock,
     },
     entities::message::{
         MerkleProof,
@@ -49,6 +79,7 @@ use fuel_core_types::{
         graphql_api::ContractBalance,
         txpool::{
             InsertionResult,
+            SkipReason,
             TransactionStatus,
         },
     },
@@ -85,6 +116,21 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
 
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+
+    /// Returns `false` only if no transaction of the block at `height` involves the `id`
+    /// of the owner or the contract.
+    fn block_may_involve(
+        &self,
+        height: &BlockHeight,
+        id: &[u8; 32],
+    ) -> StorageResult<bool>;
+
+    /// Returns `true` if the transactions of the block at `height` are pruned and only
+    /// its sealed header is available.
+    fn is_block_pruned(&self, height: &BlockHeight) -> StorageResult<bool>;
+
+    /// Returns the version of the executor that produced the block at `height`.
+    fn block_executor_version(&self, height: &BlockHeight) -> StorageResult<Option<u32>>;
 }
 
 /// Trait that specifies all the getters required for transactions.
@@ -136,6 +182,7 @@ pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsInfo, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -157,11 +204,31 @@ pub trait TxPoolPort: Send + Sync {
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
-    fn insert(&self, txs: Vec<Arc<Transaction>>) -> Vec<anyhow::Result<InsertionResult>>;
+    /// Returns why the block producer skipped the transaction, if it was removed from
+    /// the pool for this reason recently.
+    fn skipped_reason(&self, id: TxId) -> Option<SkipReason>;
+
+    /// Returns the lifecycle stages of the transaction if the timeline is enabled.
+    fn timeline(&self, id: TxId) -> Option<Vec<TxStageTime>>;
+
+    fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> Vec<anyhow::Result<InsertionResult>>;
 
     fn tx_update_subscribe(
         &self,
     ) -> BoxStream<anyhow::Result<TxUpdate, BroadcastStreamRecvError>>;
+
+    /// Subscribes to every event of the pool.
+    fn pool_events_subscribe(
+        &self,
+    ) -> BoxStream<anyhow::Result<PoolEvent, BroadcastStreamRecvError>>;
+
+    /// Returns the current minimal gas price of new transactions. It may change at
+    /// runtime via the admin API.
+    fn min_gas_price(&self) -> u64;
 }
 
 #[async_trait]
@@ -174,15 +241,81 @@ pub trait DryRunExecution {
     ) -> anyhow::Result<Vec<Receipt>>;
 }
 
-pub trait BlockProducerPort: Send + Sync + DryRunExecution {}
+#[async_trait]
+pub trait BlockProducerPort: Send + Sync + DryRunExecution {
+    /// Returns the template of the block at the `height` with the `max_gas` without
+    /// sealing it.
+    async fn block_template(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: u64,
+    ) -> anyhow::Result<BlockTemplate>;
+}
+
+#[async_trait]
+pub trait BlockImporterPort: Send + Sync {
+    /// Verifies the consensus of the sealed `block`, executes it and commits it.
+    async fn import_block(&self, block: SealedBlock) -> anyhow::Result<()>;
+}
 
 #[async_trait::async_trait]
 pub trait ConsensusModulePort: Send + Sync {
+    /// Produces `number_of_blocks` blocks with the `gas_limit` and returns their gas.
+    /// Blocks don't include events of the DA layer above the pinned `da_height`.
     async fn manually_produce_blocks(
         &self,
         start_time: Option<Tai64>,
         number_of_blocks: u32,
-    ) -> anyhow::Result<()>;
+        gas_limit: ManualGasLimit,
+        da_height: Option<DaBlockHeight>,
+    ) -> anyhow::Result<Vec<ProducedBlock>>;
+
+    /// Returns the stream of pre-confirmations issued by the local block producer.
+    fn pre_confirmations(&self) -> anyhow::Result<BoxStream<PreConfirmation>>;
+
+    /// Switches the block production to the `trigger` without restarting the service.
+    async fn set_trigger(&self, trigger: Trigger) -> anyhow::Result<()>;
+
+    /// Pauses or resumes the sealing of blocks.
+    async fn set_paused(&self, paused: bool) -> anyhow::Result<()>;
+}
+
+/// Trait that specifies the summary of the node served by the `/status` endpoint.
+/// All getters should be cheap because load balancers call the endpoint at high frequency.
+pub trait NodeStatusPort: Send + Sync {
+    fn latest_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Returns `None` if the node doesn't sync blocks from the network.
+    fn is_synced(&self) -> Option<bool>;
+
+    /// Returns `None` if the P2P is disabled.
+    fn peer_count(&self) -> Option<usize>;
+
+    /// Returns the balance of the L1 wallet monitored by the relayer in gwei.
+    /// Returns `None` if no wallet is monitored or the balance is not known yet.
+    fn relayer_wallet_balance(&self) -> Option<u64>;
+
+    /// Returns the name and the health of every sub-service of the node.
+    fn services_health(&self) -> Vec<(&'static str, Health)>;
+
+    /// Returns `None` if the version check is disabled or the network is unknown.
+    fn version_advisory(&self) -> Option<VersionAdvisory>;
+}
+
+/// Trait that provides the statistics of the UTXO set computed in the background.
+pub trait UtxoStatisticsPort: Send + Sync {
+    /// Returns `None` if the statistics are disabled or not computed yet.
+    fn utxo_statistics(&self) -> Option<Arc<UtxoSetStatistics>>;
+}
+
+/// Trait that provides how long transactions wait for the inclusion into blocks.
+pub trait InclusionMonitorPort: Send + Sync {
+    /// Returns `None` if the monitoring is disabled.
+    fn inclusion_statistics(&self) -> Option<InclusionStatistics>;
+
+    /// Returns `None` if the monitoring is disabled or the transaction is not tracked.
+    fn transaction_inclusion(&self, tx_id: &TxId) -> Option<TxInclusion>;
 }
 
 /// Trait that specifies queries supported by the database.
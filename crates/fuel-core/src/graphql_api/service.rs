@@ -1,39 +1,76 @@
 #[cfg(feature = "metrics")]
 use crate::graphql_api::prometheus::PrometheusExtension;
 use crate::{
-    fuel_core_graphql_api::ports::{
-        BlockProducerPort,
-        ConsensusModulePort,
-        DatabasePort,
-        TxPoolPort,
+    fuel_core_graphql_api::{
+        allowlist::{
+            AllowlistExtension,
+            QueryAllowlist,
+        },
+        error::ErrorCodeExtension,
+        idempotency::IdempotencyKeys,
+        ports::{
+            BlockImporterPort,
+            BlockProducerPort,
+            ConsensusModulePort,
+            DatabasePort,
+            InclusionMonitorPort,
+            NodeStatusPort,
+            TxPoolPort,
+            UtxoStatisticsPort,
+        },
+    },
+    graphql_api::{
+        slow_log::SlowLogExtension,
+        AdminApiToken,
+        Config,
     },
-    graphql_api::Config,
     schema::{
+        scalars::TransactionId,
+        tx::submit_transaction,
         CoreSchema,
         CoreSchemaBuilder,
     },
-    service::metrics::metrics,
+    service::{
+        metrics::metrics,
+        registry::ServiceRegistry,
+        slow_log::SlowLog,
+    },
 };
 use async_graphql::{
     http::{
         playground_source,
         GraphQLPlaygroundConfig,
     },
+    value,
     Request,
     Response,
 };
 use axum::{
+    body::{
+        Body,
+        Bytes,
+    },
     extract::{
         DefaultBodyLimit,
         Extension,
+        Path,
     },
     http::{
         header::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            AUTHORIZATION,
+            CONTENT_TYPE,
         },
+        HeaderMap,
         HeaderValue,
+        Request as HttpRequest,
+        StatusCode,
+    },
+    middleware::{
+        self,
+        Next,
     },
     response::{
         sse::Event,
@@ -48,13 +85,22 @@ use axum::{
     Json,
     Router,
 };
+use fuel_core_poa::Trigger;
 use fuel_core_services::{
+    ConfigUpdate,
     RunnableService,
     RunnableTask,
     StateWatcher,
 };
 use futures::Stream;
+use serde::Deserialize;
 use serde_json::json;
+use socket2::{
+    Domain,
+    Protocol,
+    Socket,
+    Type,
+};
 use std::{
     future::Future,
     net::{
@@ -62,6 +108,8 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
+    time::Duration,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
@@ -74,23 +122,30 @@ pub type Service = fuel_core_services::ServiceRunner<GraphqlService>;
 pub type Database = Box<dyn DatabasePort>;
 
 pub type BlockProducer = Box<dyn BlockProducerPort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
-pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type ConsensusModule = Arc<dyn ConsensusModulePort>;
+pub type NodeStatus = Arc<dyn NodeStatusPort>;
+pub type UtxoStatistics = Arc<dyn UtxoStatisticsPort>;
+pub type InclusionMonitor = Arc<dyn InclusionMonitorPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
+    /// The address bound for the `addr` of the config.
     pub bound_address: SocketAddr,
+    /// All bound addresses, starting with the `bound_address`.
+    pub bound_addresses: Vec<SocketAddr>,
 }
 
 pub struct GraphqlService {
-    bound_address: SocketAddr,
+    bound_addresses: Vec<SocketAddr>,
 }
 
 pub struct ServerParams {
     router: Router,
-    listener: TcpListener,
+    listeners: Vec<TcpListener>,
 }
 
 pub struct Task {
@@ -108,7 +163,8 @@ impl RunnableService for GraphqlService {
 
     fn shared_data(&self) -> Self::SharedData {
         SharedState {
-            bound_address: self.bound_address,
+            bound_address: self.bound_addresses[0],
+            bound_addresses: self.bound_addresses.clone(),
         }
     }
 
@@ -117,18 +173,26 @@ impl RunnableService for GraphqlService {
         state: &StateWatcher,
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
-        let mut state = state.clone();
-        let ServerParams { router, listener } = params;
-
-        let server = axum::Server::from_tcp(listener)
-            .unwrap()
-            .serve(router.into_make_service())
-            .with_graceful_shutdown(async move {
-                state
-                    .while_started()
-                    .await
-                    .expect("The service is destroyed");
-            });
+        let ServerParams { router, listeners } = params;
+
+        let servers = listeners
+            .into_iter()
+            .map(|listener| {
+                let mut state = state.clone();
+                let server = axum::Server::from_tcp(listener)?
+                    .serve(router.clone().into_make_service())
+                    .with_graceful_shutdown(async move {
+                        state
+                            .while_started()
+                            .await
+                            .expect("The service is destroyed");
+                    });
+                Ok(server)
+            })
+            .collect::<hyper::Result<Vec<_>>>()?;
+        // All servers share the router, so the API is the same on every address.
+        let server =
+            async move { futures::future::try_join_all(servers).await.map(|_| ()) };
 
         Ok(Task {
             server: Box::pin(server),
@@ -160,21 +224,56 @@ pub fn new_service(
     database: Database,
     txpool: TxPool,
     producer: BlockProducer,
+    block_importer: BlockImporter,
     consensus_module: ConsensusModule,
+    node_status: NodeStatus,
+    utxo_statistics: UtxoStatistics,
+    inclusion_monitor: InclusionMonitor,
+    registry: ServiceRegistry,
 ) -> anyhow::Result<Service> {
-    let network_addr = config.addr;
+    let network_addrs: Vec<_> = std::iter::once(config.addr)
+        .chain(config.additional_addrs.iter().copied())
+        .collect();
+    anyhow::ensure!(
+        !config.admin_api || config.admin_api_token.is_some(),
+        "The admin API requires the admin API token"
+    );
+    let admin_api_token = config.admin_api_token.clone().filter(|_| config.admin_api);
+    let slow_log = config.slow_log.clone();
+    let query_allowlist = config.query_allowlist.clone();
+    let idempotency_keys = IdempotencyKeys::new(config.idempotency_key_ttl);
 
     let builder = schema
-        .data(config)
+        .data(config.clone())
+        .data(idempotency_keys.clone())
         .data(database)
-        .data(txpool)
+        .data(txpool.clone())
         .data(producer)
-        .data(consensus_module);
+        .data(block_importer)
+        .data(consensus_module.clone())
+        .data(node_status.clone())
+        .data(utxo_statistics.clone())
+        .data(inclusion_monitor);
     let builder = builder.extension(async_graphql::extensions::Tracing);
+    let builder = builder.extension(ErrorCodeExtension);
 
     #[cfg(feature = "metrics")]
     let builder = builder.extension(PrometheusExtension {});
 
+    let builder = match &query_allowlist {
+        Some(allowlist) => builder.extension(AllowlistExtension {
+            allowlist: allowlist.clone(),
+        }),
+        None => builder,
+    };
+
+    let builder = match &slow_log {
+        Some(slow_log) => builder.extension(SlowLogExtension {
+            slow_log: slow_log.clone(),
+        }),
+        None => builder,
+    };
+
     let schema = builder.finish();
 
     let router = Router::new()
@@ -184,9 +283,51 @@ pub fn new_service(
             "/graphql-sub",
             post(graphql_subscription_handler).options(ok),
         )
+        .route("/v1/submit", post(submit_binary_handler).options(ok))
         .route("/metrics", get(metrics))
         .route("/health", get(health))
+        .route("/ready", get(ready))
+        .route("/status", get(status))
+        .route("/utxo-stats.csv", get(utxo_stats_csv));
+    let router = if let Some(admin_api_token) = admin_api_token {
+        let admin_router = Router::new()
+            .route("/v1/services", get(list_services))
+            .route("/v1/services/:name", get(inspect_service))
+            .route("/v1/services/:name/:action", post(control_service))
+            .route("/v1/config", post(reload_config))
+            .route("/v1/poa/trigger", post(set_trigger))
+            .route("/v1/poa/pause", post(pause_block_production))
+            .route("/v1/poa/resume", post(resume_block_production));
+        let admin_router = match slow_log {
+            Some(slow_log) => admin_router
+                .route("/v1/slow-log", get(slow_operations))
+                .layer(Extension(slow_log)),
+            None => admin_router,
+        };
+        let admin_router = match query_allowlist {
+            Some(allowlist) => admin_router
+                .route("/v1/graphql/allowlist", post(reload_query_allowlist))
+                .layer(Extension(allowlist)),
+            None => admin_router,
+        };
+        let admin_router = admin_router.route_layer(middleware::from_fn(
+            move |request: HttpRequest<Body>, next: Next<Body>| {
+                authorize(admin_api_token.clone(), request, next)
+            },
+        ));
+        router.merge(admin_router)
+    } else {
+        router
+    };
+    let router = router
         .layer(Extension(schema))
+        .layer(Extension(config))
+        .layer(Extension(idempotency_keys))
+        .layer(Extension(txpool))
+        .layer(Extension(node_status))
+        .layer(Extension(utxo_statistics))
+        .layer(Extension(consensus_module))
+        .layer(Extension(registry))
         .layer(TraceLayer::new_for_http())
         .layer(SetResponseHeaderLayer::<_>::overriding(
             ACCESS_CONTROL_ALLOW_ORIGIN,
@@ -202,17 +343,42 @@ pub fn new_service(
         ))
         .layer(DefaultBodyLimit::disable());
 
-    let listener = TcpListener::bind(network_addr)?;
-    let bound_address = listener.local_addr()?;
-
-    tracing::info!("Binding GraphQL provider to {}", bound_address);
+    let mut listeners = vec![];
+    let mut bound_addresses = vec![];
+    for (index, network_addr) in network_addrs.into_iter().enumerate() {
+        // The primary listener keeps the default of the platform, e.g. the IPv6
+        // wildcard address may accept IPv4 connections too.
+        let listener = bind(network_addr, index > 0)?;
+        let bound_address = listener.local_addr()?;
+        tracing::info!("Binding GraphQL provider to {}", bound_address);
+        listeners.push(listener);
+        bound_addresses.push(bound_address);
+    }
 
     Ok(Service::new_with_params(
-        GraphqlService { bound_address },
-        ServerParams { router, listener },
+        GraphqlService { bound_addresses },
+        ServerParams { router, listeners },
     ))
 }
 
+/// Binds the listener to the `addr`. If `only_v6` is set, the IPv6 listener accepts
+/// only IPv6 connections, so the IPv4 and the IPv6 wildcard addresses can be bound to
+/// the same port.
+fn bind(addr: SocketAddr, only_v6: bool) -> std::io::Result<TcpListener> {
+    let socket =
+        Socket::new(Domain::for_address(addr), Type::STREAM, Some(Protocol::TCP))?;
+    if only_v6 && addr.is_ipv6() {
+        socket.set_only_v6(true)?;
+    }
+    // The same as `TcpListener::bind` does on unix, so the port can be reused right
+    // after the restart of the node.
+    #[cfg(unix)]
+    socket.set_reuse_address(true)?;
+    socket.bind(&addr.into())?;
+    socket.listen(1024)?;
+    Ok(socket.into())
+}
+
 async fn graphql_playground() -> impl IntoResponse {
     Html(playground_source(GraphQLPlaygroundConfig::new("/graphql")))
 }
@@ -221,6 +387,257 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Reports whether all sub-services of the node are ready, e.g. the P2P bound its
+/// listeners and the relayer synced with the DA layer. Responds with
+/// `503 Service Unavailable` and the names of sub-services that are not ready yet.
+async fn ready(registry: Extension<ServiceRegistry>) -> impl IntoResponse {
+    let not_ready = registry.not_ready();
+    let status_code = if not_ready.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status_code,
+        Json(json!({
+            "ready": not_ready.is_empty(),
+            "not_ready": not_ready,
+        })),
+    )
+}
+
+/// The summary of the node for health checks of load balancers. It doesn't touch the GraphQL
+/// engine, so it is cheap to call it at high frequency. Unhealthy nodes respond with
+/// `503 Service Unavailable`.
+async fn status(node_status: Extension<NodeStatus>) -> impl IntoResponse {
+    let height = node_status.latest_height().ok().map(|height| *height);
+    let synced = node_status.is_synced();
+    let peers = node_status.peer_count();
+
+    // The node that syncs blocks from the network is healthy only if it caught up
+    // with peers and is still connected to them.
+    let healthy = height.is_some()
+        && match synced {
+            Some(synced) => synced && peers.unwrap_or_default() > 0,
+            None => true,
+        };
+    let status_code = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status_code,
+        Json(json!({
+            "height": height,
+            "synced": synced,
+            "peers": peers,
+            "healthy": healthy,
+        })),
+    )
+}
+
+/// Exports the latest statistics of the UTXO set as CSV. Responds with
+/// `404 Not Found` if the statistics are disabled or not computed yet.
+async fn utxo_stats_csv(utxo_statistics: Extension<UtxoStatistics>) -> impl IntoResponse {
+    match utxo_statistics.utxo_statistics() {
+        Some(statistics) => (
+            StatusCode::OK,
+            [(CONTENT_TYPE, HeaderValue::from_static("text/csv"))],
+            statistics.to_csv(),
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Rejects requests to the admin API without the `token` with `401 Unauthorized`.
+async fn authorize(
+    token: AdminApiToken,
+    request: HttpRequest<Body>,
+    next: Next<Body>,
+) -> axum::response::Response {
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .map_or(false, |authorization| token.authorizes(authorization));
+    if !authorized {
+        return StatusCode::UNAUTHORIZED.into_response()
+    }
+    next.run(request).await
+}
+
+/// Lists recorded slow operations from the oldest to the newest.
+async fn slow_operations(slow_log: Extension<SlowLog>) -> impl IntoResponse {
+    Json(slow_log.operations())
+}
+
+/// Lists sub-services of the node with their states, health and the latest transitions.
+async fn list_services(registry: Extension<ServiceRegistry>) -> impl IntoResponse {
+    Json(registry.list())
+}
+
+/// Responds with `404 Not Found` if the sub-service is unknown.
+async fn inspect_service(
+    registry: Extension<ServiceRegistry>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    match registry.inspect(&name) {
+        Some(service) => Json(service).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Applies the `stop`, `pause`, `resume` or `restart` action to the sub-service and
+/// responds with the sub-service. Responds with `409 Conflict` if the action is not
+/// possible in the current state of the sub-service.
+async fn control_service(
+    registry: Extension<ServiceRegistry>,
+    Path((name, action)): Path<(String, String)>,
+) -> impl IntoResponse {
+    if registry.inspect(&name).is_none() {
+        return StatusCode::NOT_FOUND.into_response()
+    }
+    let result = match action.as_str() {
+        "stop" => registry.stop(&name).map(|_| ()),
+        "pause" => registry.pause(&name),
+        "resume" => registry.resume(&name),
+        "restart" => registry.restart(&name),
+        _ => return StatusCode::NOT_FOUND.into_response(),
+    };
+    match result {
+        Ok(()) => Json(registry.inspect(&name)).into_response(),
+        Err(e) => (StatusCode::CONFLICT, e.to_string()).into_response(),
+    }
+}
+
+/// The partial update of the configuration. Omitted fields keep their current values,
+/// and fields that can't be reloaded are rejected.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigUpdateRequest {
+    min_gas_price: Option<u64>,
+    max_peers: Option<u32>,
+    /// The interval between blocks in milliseconds.
+    block_time_ms: Option<u64>,
+}
+
+/// Applies the partial update of the configuration to running sub-services without the
+/// restart. Responds with `409 Conflict` and applies nothing if any sub-service rejects
+/// the update, or if no sub-service can apply some field of it.
+async fn reload_config(
+    registry: Extension<ServiceRegistry>,
+    Json(request): Json<ConfigUpdateRequest>,
+) -> impl IntoResponse {
+    let update = ConfigUpdate {
+        min_gas_price: request.min_gas_price,
+        max_peers: request.max_peers,
+        block_time: request.block_time_ms.map(Duration::from_millis),
+    };
+    match registry.reload(&update) {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::CONFLICT, e.to_string()).into_response(),
+    }
+}
+
+/// Reloads documents of the GraphQL allowlist from its directory. Invalid documents
+/// are rejected with `422 Unprocessable Entity`, keeping the previous documents.
+async fn reload_query_allowlist(
+    allowlist: Extension<QueryAllowlist>,
+) -> impl IntoResponse {
+    match allowlist.reload() {
+        Ok(documents) => Json(json!({ "documents": documents })).into_response(),
+        Err(e) => (StatusCode::UNPROCESSABLE_ENTITY, format!("{e:#}")).into_response(),
+    }
+}
+
+/// The trigger of the block production with timings in milliseconds.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+enum TriggerRequest {
+    Never,
+    Instant,
+    Interval {
+        block_time_ms: u64,
+    },
+    Hybrid {
+        min_block_time_ms: u64,
+        max_tx_idle_time_ms: u64,
+        max_block_time_ms: u64,
+    },
+    Adaptive {
+        min_block_time_ms: u64,
+        max_block_time_ms: u64,
+    },
+}
+
+impl From<TriggerRequest> for Trigger {
+    fn from(request: TriggerRequest) -> Self {
+        match request {
+            TriggerRequest::Never => Trigger::Never,
+            TriggerRequest::Instant => Trigger::Instant,
+            TriggerRequest::Interval { block_time_ms } => Trigger::Interval {
+                block_time: Duration::from_millis(block_time_ms),
+            },
+            TriggerRequest::Hybrid {
+                min_block_time_ms,
+                max_tx_idle_time_ms,
+                max_block_time_ms,
+            } => Trigger::Hybrid {
+                min_block_time: Duration::from_millis(min_block_time_ms),
+                max_tx_idle_time: Duration::from_millis(max_tx_idle_time_ms),
+                max_block_time: Duration::from_millis(max_block_time_ms),
+            },
+            TriggerRequest::Adaptive {
+                min_block_time_ms,
+                max_block_time_ms,
+            } => Trigger::Adaptive {
+                min_block_time: Duration::from_millis(min_block_time_ms),
+                max_block_time: Duration::from_millis(max_block_time_ms),
+            },
+        }
+    }
+}
+
+/// Switches the running block production to another trigger, e.g. from `never` to
+/// `interval`, without the restart. Responds with `409 Conflict` if the block
+/// production is disabled or rejects the trigger.
+async fn set_trigger(
+    consensus_module: Extension<ConsensusModule>,
+    Json(request): Json<TriggerRequest>,
+) -> impl IntoResponse {
+    match consensus_module.set_trigger(request.into()).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::CONFLICT, e.to_string()).into_response(),
+    }
+}
+
+/// Stops sealing blocks until the block production is resumed. The producer keeps
+/// following the transaction pool meanwhile. Responds with `409 Conflict` if the block
+/// production is disabled.
+async fn pause_block_production(
+    consensus_module: Extension<ConsensusModule>,
+) -> impl IntoResponse {
+    match consensus_module.set_paused(true).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::CONFLICT, e.to_string()).into_response(),
+    }
+}
+
+/// Resumes the paused block production. The next block is scheduled by the trigger
+/// from now. Responds with `409 Conflict` if the block production is disabled.
+async fn resume_block_production(
+    consensus_module: Extension<ConsensusModule>,
+) -> impl IntoResponse {
+    match consensus_module.set_paused(false).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::CONFLICT, e.to_string()).into_response(),
+    }
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
@@ -228,6 +645,42 @@ async fn graphql_handler(
     schema.execute(req.0).await.into()
 }
 
+/// The header with the idempotency key of the binary submission.
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Submits the transaction serialized in the binary body of the request. Large
+/// transactions, like deployments of big contracts, don't pay for the hex encoding and
+/// don't hit the limits of proxies on the size of GraphQL documents. The response is the
+/// same as the response of the `submit` mutation.
+async fn submit_binary_handler(
+    txpool: Extension<TxPool>,
+    config: Extension<Config>,
+    idempotency_keys: Extension<IdempotencyKeys>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Json<Response> {
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|key| key.to_str().ok())
+        .map(ToString::to_string);
+    let submitted = submit_transaction(
+        &txpool,
+        &config,
+        &idempotency_keys,
+        &body,
+        None,
+        idempotency_key,
+    );
+    let response = match submitted {
+        Ok(tx) => {
+            let id = TransactionId::from(tx.1);
+            Response::new(value!({ "submit": { "id": id.to_string() } }))
+        }
+        Err(e) => Response::from_errors(vec![e.into_server_error(Default::default())]),
+    };
+    Json(response)
+}
+
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
@@ -0,0 +1,107 @@
+use crate::service::slow_log::{
+    count_rows,
+    OperationKind,
+    SlowLog,
+};
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextPrepareRequest,
+        NextRequest,
+    },
+    Request,
+    Response,
+    ServerResult,
+};
+use fuel_core_types::fuel_crypto::Hasher;
+use std::sync::{
+    Arc,
+    Mutex,
+};
+use tokio::time::Instant;
+
+/// The query of the unnamed operation is recorded up to this number of characters.
+const MAX_QUERY_LENGTH: usize = 256;
+
+/// Records GraphQL requests that take longer than the threshold into the [`SlowLog`].
+pub(crate) struct SlowLogExtension {
+    pub slow_log: SlowLog,
+}
+
+impl ExtensionFactory for SlowLogExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(SlowLogExtInner {
+            slow_log: self.slow_log.clone(),
+            operation: Default::default(),
+        })
+    }
+}
+
+/// The operation and the hash of its variables.
+type Operation = (String, Option<String>);
+
+pub(crate) struct SlowLogExtInner {
+    slow_log: SlowLog,
+    operation: Mutex<Option<Operation>>,
+}
+
+#[async_trait::async_trait]
+impl Extension for SlowLogExtInner {
+    async fn request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextRequest<'_>,
+    ) -> Response {
+        let start_time = Instant::now();
+        let (response, rows) = count_rows(next.run(ctx)).await;
+        let duration = start_time.elapsed();
+
+        if duration >= self.slow_log.graphql_threshold() {
+            let (operation, variables_hash) = self
+                .operation
+                .lock()
+                .expect("poisoned")
+                .take()
+                .unwrap_or_default();
+            self.slow_log.record(
+                OperationKind::Graphql,
+                operation,
+                variables_hash,
+                duration,
+                rows,
+            );
+        }
+        response
+    }
+
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let operation = match &request.operation_name {
+            Some(operation_name) => operation_name.clone(),
+            None => request
+                .query
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .chars()
+                .take(MAX_QUERY_LENGTH)
+                .collect(),
+        };
+        let variables_hash = if request.variables.is_empty() {
+            None
+        } else {
+            serde_json::to_vec(&request.variables)
+                .ok()
+                .map(|variables| format!("{:x}", Hasher::hash(variables)))
+        };
+        *self.operation.lock().expect("poisoned") = Some((operation, variables_hash));
+
+        next.run(ctx, request).await
+    }
+}
@@ -10,6 +10,8 @@ pub use fuel_core_producer as producer;
 #[cfg(feature = "relayer")]
 #[doc(no_inline)]
 pub use fuel_core_relayer as relayer;
+#[doc(no_inline)]
+pub use fuel_core_services as services;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_sync as sync;
@@ -28,6 +30,8 @@ pub mod query;
 pub mod schema;
 pub mod service;
 pub mod state;
+#[cfg(all(feature = "p2p", feature = "test-helpers"))]
+pub mod test_harness;
 
 // In the future this module will be a separate crate for `fuel-core-graphql-api`.
 mod graphql_api;
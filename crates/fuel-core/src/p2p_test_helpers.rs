@@ -1,7 +1,10 @@
 //! # Helpers for creating networks of nodes
 
 use crate::{
-    chain_config::ChainConfig,
+    chain_config::{
+        ChainConfig,
+        ConsensusConfig,
+    },
     database::Database,
     p2p::Multiaddr,
     service::{
@@ -14,6 +17,10 @@ use crate::{
 use fuel_core_p2p::{
     codecs::postcard::PostcardCodec,
     network_service::FuelP2PService,
+    test_peer::{
+        Behavior,
+        TestPeer,
+    },
     PeerId,
 };
 use fuel_core_poa::Trigger;
@@ -236,11 +243,10 @@ pub async fn make_nodes(
                         chain_config.clone(),
                     );
                     if let Some(BootstrapSetup { pub_key, .. }) = boot {
-                        match &mut node_config.chain_conf.consensus {
-                            crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                                *signing_key = pub_key;
-                            }
-                        }
+                        node_config.chain_conf.consensus = ConsensusConfig::PoA {
+                            signing_key: pub_key,
+                            key_rotations: vec![],
+                        };
                     }
                     Bootstrap::new(&node_config).await
                 }
@@ -267,11 +273,10 @@ pub async fn make_nodes(
 
         if let Some((ProducerSetup { secret, .. }, txs)) = s {
             let pub_key = secret.public_key();
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = Input::owner(&pub_key);
-                }
-            }
+            node_config.chain_conf.consensus = ConsensusConfig::PoA {
+                signing_key: Input::owner(&pub_key),
+                key_rotations: vec![],
+            };
 
             node_config.consensus_key = Some(Secret::new(secret.into()));
 
@@ -296,11 +301,10 @@ pub async fn make_nodes(
         node_config.p2p.as_mut().unwrap().bootstrap_nodes = boots.clone();
 
         if let Some(ValidatorSetup { pub_key, .. }) = s {
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = pub_key;
-                }
-            }
+            node_config.chain_conf.consensus = ConsensusConfig::PoA {
+                signing_key: pub_key,
+                key_rotations: vec![],
+            };
         }
         validators.push(make_node(node_config, Vec::with_capacity(0)).await)
     }
@@ -335,6 +339,26 @@ async fn make_node(node_config: Config, test_txs: Vec<Transaction>) -> Node {
     }
 }
 
+/// Starts the test peer in the network of the node with the `node_config`. Returns the
+/// peer with its addresses, so nodes can bootstrap from it.
+pub async fn make_test_peer(
+    node_config: &Config,
+    behavior: Behavior,
+) -> (TestPeer, Vec<Multiaddr>) {
+    let mut peer = TestPeer::new(extract_p2p_config(node_config), behavior).unwrap();
+
+    // Wait for listener addresses.
+    while peer.listeners().next().is_none() {
+        peer.next_event().await;
+    }
+
+    let listeners = peer
+        .listeners()
+        .map(|addr| map_listener_address(&peer.local_peer_id(), addr))
+        .collect();
+    (peer, listeners)
+}
+
 fn extract_p2p_config(node_config: &Config) -> fuel_core_p2p::config::Config {
     let bootstrap_config = node_config.p2p.clone();
     let db = Database::in_memory();
@@ -1,4 +1,7 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::{
+    graphql_api::ports::DatabasePort,
+    query::SimpleBlockData,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -9,17 +12,25 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
+        Receipts,
     },
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::{
+    fuel_tx::Receipt,
     fuel_types::{
         AssetId,
+        BlockHeight,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractLog,
+    },
 };
 
 pub trait ContractQueryData: Send + Sync {
@@ -35,12 +46,27 @@ pub trait ContractQueryData: Send + Sync {
         asset_id: AssetId,
     ) -> StorageResult<ContractBalance>;
 
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Bytes32>;
+
     fn contract_balances(
         &self,
         contract_id: ContractId,
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns logs emitted by the contract in blocks from `start` to `end` inclusive.
+    /// Blocks whose bloom filter excludes the contract are skipped without loading.
+    fn contract_logs(
+        &self,
+        contract_id: ContractId,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> StorageResult<Vec<ContractLog>>;
 }
 
 impl<D: DatabasePort + ?Sized> ContractQueryData for D {
@@ -91,6 +117,20 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
         })
     }
 
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Bytes32> {
+        let value = self
+            .storage::<ContractsState>()
+            .get(&(&contract_id, &key).into())?
+            .ok_or(not_found!(ContractsState))?
+            .into_owned();
+
+        Ok(value)
+    }
+
     fn contract_balances(
         &self,
         contract_id: ContractId,
@@ -99,4 +139,44 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_logs(
+        &self,
+        contract_id: ContractId,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> StorageResult<Vec<ContractLog>> {
+        let (latest_height, _) = self.ids_of_latest_block()?;
+        let end = end.min(latest_height);
+        let mut logs = vec![];
+        for height in *start..=*end {
+            let block_height = BlockHeight::from(height);
+            if !self.block_may_involve(&block_height, &contract_id)? {
+                continue
+            }
+            let block_id = self.block_id(&block_height)?;
+            let block = self.block(&block_id)?;
+            for tx_id in block.transactions() {
+                let receipts = self
+                    .storage::<Receipts>()
+                    .get(tx_id)?
+                    .map(|receipts| receipts.into_owned())
+                    .unwrap_or_default();
+                for receipt in receipts {
+                    let id = match &receipt {
+                        Receipt::Log { id, .. } | Receipt::LogData { id, .. } => id,
+                        _ => continue,
+                    };
+                    if *id == contract_id {
+                        logs.push(ContractLog {
+                            block_height,
+                            tx_id: *tx_id,
+                            receipt,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(logs)
+    }
 }
@@ -142,28 +142,48 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     message_id: MessageId,
     commit_block_id: BlockId,
 ) -> StorageResult<Option<MessageProof>> {
-    // Check if the receipts for this transaction actually contain this message id or exit.
-    let receipt = database
-        .receipts(&transaction_id)?
-        .into_iter()
-        .find_map(|r| match r {
-            Receipt::MessageOut {
-                sender,
-                recipient,
-                nonce,
-                amount,
-                data,
-                ..
-            } if r.message_id() == Some(message_id) => {
-                Some((sender, recipient, nonce, amount, data))
-            }
-            _ => None,
-        });
+    let mut proofs =
+        message_proofs(database, transaction_id, &[message_id], commit_block_id)?;
+    Ok(proofs.pop().flatten())
+}
 
-    let (sender, recipient, nonce, amount, data) = match receipt {
-        Some(r) => r,
-        None => return Ok(None),
-    };
+/// Generate output proofs for several messages of the same transaction.
+///
+/// The blocks, the message receipts tree and the block proof are loaded once and shared
+/// by all proofs. The result follows the order of `message_ids` and contains `None` for
+/// messages that can't be proven.
+pub fn message_proofs<T: MessageProofData + ?Sized>(
+    database: &T,
+    transaction_id: Bytes32,
+    message_ids: &[MessageId],
+    commit_block_id: BlockId,
+) -> StorageResult<Vec<Option<MessageProof>>> {
+    let unproven = || message_ids.iter().map(|_| None).collect();
+
+    // Check if the receipts for this transaction actually contain the message ids.
+    let receipts = database.receipts(&transaction_id)?;
+    let messages: Vec<_> = message_ids
+        .iter()
+        .map(|message_id| {
+            receipts.iter().find_map(|r| match r {
+                Receipt::MessageOut {
+                    sender,
+                    recipient,
+                    nonce,
+                    amount,
+                    data,
+                    ..
+                } if r.message_id() == Some(*message_id) => {
+                    Some((*sender, *recipient, *nonce, *amount, data.clone()))
+                }
+                _ => None,
+            })
+        })
+        .collect();
+
+    if messages.iter().all(Option::is_none) {
+        return Ok(unproven())
+    }
 
     // Get the block id from the transaction status if it's ready.
     let message_block_id = match database
@@ -171,7 +191,7 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         .into_api_result::<TransactionStatus, StorageError>()?
     {
         Some(TransactionStatus::Success { block_id, .. }) => block_id,
-        _ => return Ok(None),
+        _ => return Ok(unproven()),
     };
 
     // Get the message fuel block header.
@@ -180,14 +200,14 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         .into_api_result::<CompressedBlock, StorageError>()?
     {
         Some(t) => t.into_inner(),
-        None => return Ok(None),
+        None => return Ok(unproven()),
     };
 
-    let message_proof =
-        match message_receipts_proof(database, message_id, &message_block_txs)? {
-            Some(proof) => proof,
-            None => return Ok(None),
-        };
+    let message_receipts = message_receipt_ids(database, &message_block_txs)?;
+    let mut tree = MerkleTree::new();
+    for id in &message_receipts {
+        tree.push(id.as_ref());
+    }
 
     // Get the commit fuel block header.
     let commit_block_header = match database
@@ -195,7 +215,7 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         .into_api_result::<CompressedBlock, StorageError>()?
     {
         Some(t) => t.into_inner().0,
-        None => return Ok(None),
+        None => return Ok(unproven()),
     };
 
     let verifiable_commit_block_height = *commit_block_header.height() - 1u32.into();
@@ -204,64 +224,52 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         &verifiable_commit_block_height,
     )?;
 
-    Ok(Some(MessageProof {
-        message_proof,
-        block_proof,
-        message_block_header,
-        commit_block_header,
-        sender,
-        recipient,
-        nonce,
-        amount,
-        data,
-    }))
+    let proofs = messages
+        .into_iter()
+        .zip(message_ids)
+        .map(|(message, message_id)| {
+            let (sender, recipient, nonce, amount, data) = message?;
+            let proof_index =
+                message_receipts.iter().position(|id| id == message_id)? as u64;
+            let (_, proof_set) = tree.prove(proof_index)?;
+
+            Some(MessageProof {
+                message_proof: MerkleProof {
+                    proof_set,
+                    proof_index,
+                },
+                block_proof: block_proof.clone(),
+                message_block_header: message_block_header.clone(),
+                commit_block_header: commit_block_header.clone(),
+                sender,
+                recipient,
+                nonce,
+                amount,
+                data,
+            })
+        })
+        .collect();
+
+    Ok(proofs)
 }
 
-fn message_receipts_proof<T: MessageProofData + ?Sized>(
+/// Returns the ids of the output messages of the block in the order of the leaves of
+/// the message receipts tree.
+fn message_receipt_ids<T: MessageProofData + ?Sized>(
     database: &T,
-    message_id: MessageId,
     message_block_txs: &[Bytes32],
-) -> StorageResult<Option<MerkleProof>> {
+) -> StorageResult<Vec<MessageId>> {
     // Get the message receipts from the block.
     let leaves: Vec<Vec<Receipt>> = message_block_txs
         .iter()
         .map(|id| database.receipts(id))
         .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
         .try_collect()?;
-    let leaves = leaves.into_iter()
-        // Flatten the receipts after filtering on output messages
-        // and mapping to message ids.
-        .flat_map(|receipts|
-            receipts.into_iter().filter_map(|r| r.message_id()));
-
-    // Build the merkle proof from the above iterator.
-    let mut tree = MerkleTree::new();
-
-    let mut proof_index = None;
-
-    for (index, id) in leaves.enumerate() {
-        // Check if this is the message id being proved.
-        if message_id == id {
-            // Save the index of this message to use as the proof index.
-            proof_index = Some(index as u64);
-        }
-
-        // Build the merkle tree.
-        tree.push(id.as_ref());
-    }
 
-    // If we found the leaf proof index then return the proof.
-    match proof_index {
-        Some(proof_index) => {
-            // Generate the actual merkle proof.
-            match tree.prove(proof_index) {
-                Some((_, proof_set)) => Ok(Some(MerkleProof {
-                    proof_set,
-                    proof_index,
-                })),
-                None => Ok(None),
-            }
-        }
-        None => Ok(None),
-    }
+    // Flatten the receipts after filtering on output messages
+    // and mapping to message ids.
+    Ok(leaves
+        .into_iter()
+        .flat_map(|receipts| receipts.into_iter().filter_map(|r| r.message_id()))
+        .collect())
 }
@@ -80,13 +80,23 @@ mockall::mock! {
     }
 }
 
-#[tokio::test]
-async fn can_build_message_proof() {
+struct ProofSetup {
+    data: MockProofDataStorage,
+    transaction_id: Bytes32,
+    message_id: MessageId,
+    other_message_id: MessageId,
+    commit_block: CompressedBlock,
+    message_block: CompressedBlock,
+    block_proof: MerkleProof,
+}
+
+fn proof_setup() -> ProofSetup {
     use mockall::predicate::*;
     let commit_block_height = BlockHeight::from(2u32);
     let message_block_height = BlockHeight::from(1u32);
     let expected_receipt = receipt(Some(11));
     let message_id = expected_receipt.message_id().unwrap();
+    let other_message_id = receipt(Some(10)).message_id().unwrap();
     let receipts: [Receipt; 4] = [
         receipt(Some(10)),
         receipt(None),
@@ -197,6 +207,29 @@ async fn can_build_message_proof() {
         }
     });
 
+    ProofSetup {
+        data,
+        transaction_id,
+        message_id,
+        other_message_id,
+        commit_block,
+        message_block,
+        block_proof,
+    }
+}
+
+#[tokio::test]
+async fn can_build_message_proof() {
+    let ProofSetup {
+        data,
+        transaction_id,
+        message_id,
+        commit_block,
+        message_block,
+        block_proof,
+        ..
+    } = proof_setup();
+
     let data: Box<dyn MessageProofData> = Box::new(data);
 
     let proof =
@@ -218,3 +251,36 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[tokio::test]
+async fn can_build_message_proofs_for_many_messages() {
+    let ProofSetup {
+        data,
+        transaction_id,
+        message_id,
+        other_message_id,
+        commit_block,
+        block_proof,
+        ..
+    } = proof_setup();
+    let unknown_message_id = receipt(Some(99)).message_id().unwrap();
+    let data: Box<dyn MessageProofData> = Box::new(data);
+
+    let proofs = message_proofs(
+        data.deref(),
+        transaction_id,
+        &[message_id, unknown_message_id, other_message_id],
+        commit_block.id(),
+    )
+    .unwrap();
+
+    assert_eq!(proofs.len(), 3);
+    let first = proofs[0].as_ref().unwrap();
+    assert_eq!(first.message_id(), message_id);
+    assert!(proofs[1].is_none());
+    let third = proofs[2].as_ref().unwrap();
+    assert_eq!(third.message_id(), other_message_id);
+    assert_ne!(first.message_proof, third.message_proof);
+    assert_eq!(first.block_proof, block_proof);
+    assert_eq!(third.block_proof, block_proof);
+}
@@ -235,9 +235,12 @@ where
         mut db_statuses,
     } = input;
     let mut state = MockTxnStatusChangeState::new();
-    state
-        .expect_get_tx_status()
-        .returning(move |_| db_statuses.next().unwrap().map(|t| t.map(|t| t.into())));
+    state.expect_get_tx_status().returning(move |id| {
+        db_statuses
+            .next()
+            .unwrap()
+            .map(|t| t.map(|t| crate::schema::tx::types::TransactionStatus::new(id, t)))
+    });
     let ids = status_updates.to_vec();
     let reached_end = Arc::new(AtomicBool::new(false));
     let re = reached_end.clone();
@@ -1,12 +1,17 @@
+use crate::{
+    fuel_core_graphql_api::Config as GraphQLConfig,
+    schema::cursor::Cursor,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
-        query,
+        query_with,
         Connection,
         CursorType,
         Edge,
         EmptyFields,
     },
+    Context,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -24,13 +29,17 @@ pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod cursor;
 #[cfg(feature = "dap")]
 pub mod dap;
+pub mod deprecation;
 pub mod health;
+pub mod inclusion;
 pub mod message;
 pub mod node_info;
 pub mod scalars;
 pub mod tx;
+pub mod utxo_stats;
 
 #[cfg(feature = "dap")]
 #[derive(MergedObject, Default)]
@@ -46,6 +55,9 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    deprecation::SchemaVersionQuery,
+    utxo_stats::UtxoStatisticsQuery,
+    inclusion::InclusionQuery,
 );
 
 #[cfg(not(feature = "dap"))]
@@ -61,6 +73,9 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    deprecation::SchemaVersionQuery,
+    utxo_stats::UtxoStatisticsQuery,
+    inclusion::InclusionQuery,
 );
 
 #[cfg(feature = "dap")]
@@ -72,7 +87,11 @@ pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
 pub struct Mutation(tx::TxMutation, block::BlockMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    block::PreConfirmationSubscription,
+    tx::PoolEventsSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -87,12 +106,15 @@ pub fn build_schema() -> CoreSchemaBuilder {
 }
 
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
+    ctx: &Context<'_>,
     after: Option<String>,
     before: Option<String>,
     first: Option<i32>,
     last: Option<i32>,
     entries: F,
-) -> async_graphql::Result<Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields>>
+) -> async_graphql::Result<
+    Connection<Cursor<SchemaKey>, SchemaValue, EmptyFields, EmptyFields>,
+>
 where
     SchemaKey: CursorType + Send + Sync,
     <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
@@ -129,12 +151,23 @@ where
         (_, _, _, _) => { /* Other combinations are allowed */ }
     };
 
-    query(
+    let secret = ctx.data_unchecked::<GraphQLConfig>().cursor_secret;
+
+    query_with(
         after,
         before,
         first,
         last,
-        |after: Option<SchemaKey>, before: Option<SchemaKey>, first, last| async move {
+        |after: Option<String>, before: Option<String>, first, last| async move {
+            let decode = |cursor: Option<String>| {
+                cursor
+                    .map(|cursor| secret.decode::<SchemaKey>(&cursor))
+                    .transpose()
+                    .map_err(|e| anyhow!(e))
+            };
+            let after = decode(after)?;
+            let before = decode(before)?;
+
             let (count, direction) = if let Some(first) = first {
                 (first, IterDirection::Forward)
             } else if let Some(last) = last {
@@ -203,10 +236,10 @@ where
             connection.edges.extend(
                 entries
                     .into_iter()
-                    .map(|(key, value)| Edge::new(key, value)),
+                    .map(|(key, value)| Edge::new(Cursor::new(key, secret), value)),
             );
 
-            Ok::<Connection<SchemaKey, SchemaValue>, anyhow::Error>(connection)
+            Ok::<Connection<Cursor<SchemaKey>, SchemaValue>, anyhow::Error>(connection)
         },
     )
     .await
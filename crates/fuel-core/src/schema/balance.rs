@@ -1,13 +1,21 @@
 use crate::{
-    fuel_core_graphql_api::service::Database,
+    fuel_core_graphql_api::{
+        error::{
+            coded_error,
+            ErrorCode,
+        },
+        service::Database,
+    },
     query::BalanceQueryData,
-    schema::scalars::{
-        Address,
-        AssetId,
-        U64,
+    schema::{
+        cursor::Cursor,
+        scalars::{
+            Address,
+            AssetId,
+            U64,
+        },
     },
 };
-use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -70,18 +78,30 @@ impl BalanceQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<AssetId, Balance, EmptyFields, EmptyFields>>
-    {
+    ) -> async_graphql::Result<
+        Connection<Cursor<AssetId>, Balance, EmptyFields, EmptyFields>,
+    > {
         if before.is_some() || after.is_some() {
-            return Err(anyhow!("pagination is not yet supported").into())
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "pagination",
+                "pagination is not yet supported",
+            ))
         }
         let query: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |_, direction| {
-            let owner = filter.owner.into();
-            Ok(query.balances(owner, direction).map(|result| {
-                result.map(|balance| (balance.asset_id.into(), balance.into()))
-            }))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |_, direction| {
+                let owner = filter.owner.into();
+                Ok(query.balances(owner, direction).map(|result| {
+                    result.map(|balance| (balance.asset_id.into(), balance.into()))
+                }))
+            },
+        )
         .await
     }
 }
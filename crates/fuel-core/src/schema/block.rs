@@ -4,7 +4,13 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        error::{
+            coded_error,
+            ErrorCode,
+        },
         service::{
+            BlockImporter,
+            BlockProducer,
             ConsensusModule,
             Database,
         },
@@ -17,16 +23,19 @@ use crate::{
         SimpleTransactionData,
     },
     schema::{
+        cursor::Cursor,
         scalars::{
+            Address,
             BlockId,
+            HexString,
             Signature,
+            TransactionId,
             U32,
             U64,
         },
         tx::types::Transaction,
     },
 };
-use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -35,8 +44,11 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
+use fuel_core_poa::service::ManualGasLimit;
+use fuel_core_producer::block_producer::BlockTemplate as CoreBlockTemplate;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -48,20 +60,39 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        consensus::poa::PreConfirmation as CorePreConfirmation,
         header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        UniqueIdentifier,
     },
     fuel_types,
     fuel_types::BlockHeight,
+    tai64::Tai64,
+    time::tai64_to_unix,
+};
+use futures::{
+    Stream,
+    StreamExt,
 };
 
 pub struct Block(pub(crate) CompressedBlock);
 
 pub struct Header(pub(crate) BlockHeader);
 
+/// The block that the block producer would produce right now, executed but not sealed.
+pub struct BlockTemplate {
+    template: CoreBlockTemplate,
+    transaction_parameters: ConsensusParameters,
+}
+
 #[derive(Union)]
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    PoAThreshold(PoAThresholdConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -84,6 +115,15 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+/// The seal of the block produced by the committee of the threshold `PoA` consensus.
+#[derive(SimpleObject)]
+pub struct PoAThresholdConsensus {
+    /// The address of the committee of the chain config that sealed the block.
+    committee: Address,
+    /// Signatures of members of the committee that sealed the block.
+    signatures: Vec<Signature>,
+}
+
 #[Object]
 impl Block {
     async fn id(&self) -> BlockId {
@@ -103,11 +143,35 @@ impl Block {
         Ok(consensus.into())
     }
 
+    /// Whether the transactions of the block are pruned. The header and the consensus
+    /// of pruned blocks are retained, so they can still be verified by light clients.
+    async fn pruned(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let query: &Database = ctx.data_unchecked();
+        Ok(query.is_block_pruned(self.0.header().height())?)
+    }
+
+    /// The version of the executor that produced the state of the block. Replay tools
+    /// use it to pick the executor for historical blocks. `null` for blocks produced by
+    /// other nodes, for blocks produced before the versions were recorded and for the
+    /// genesis block.
+    async fn executor_version(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U32>> {
+        let query: &Database = ctx.data_unchecked();
+        let version = query.block_executor_version(self.0.header().height())?;
+        Ok(version.map(Into::into))
+    }
+
+    /// The transactions of the block. Empty if the block is pruned.
     async fn transactions(
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &Database = ctx.data_unchecked();
+        if query.is_block_pruned(self.0.header().height())? {
+            return Ok(vec![])
+        }
         self.0
             .transactions()
             .iter()
@@ -167,6 +231,11 @@ impl Header {
         Tai64Timestamp(self.0.time())
     }
 
+    /// The block producer time in seconds since the unix epoch.
+    async fn unix_time(&self) -> U64 {
+        (tai64_to_unix(self.0.time()).max(0) as u64).into()
+    }
+
     /// Hash of the application header.
     async fn application_hash(&self) -> Bytes32 {
         (*self.0.application_hash()).into()
@@ -181,6 +250,37 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BlockTemplate {
+    /// The header of the block with roots of its transactions and messages.
+    async fn header(&self) -> Header {
+        self.template.block.header().clone().into()
+    }
+
+    /// Transactions of the block, starting with the coinbase.
+    async fn transactions(&self) -> Vec<Transaction> {
+        self.template
+            .block
+            .transactions()
+            .iter()
+            .map(|tx| {
+                Transaction::from_tx(tx.id(&self.transaction_parameters), tx.clone())
+            })
+            .collect()
+    }
+
+    /// The sum of the maximal gas of transactions of the block.
+    async fn gas_used(&self) -> U64 {
+        self.template.gas_used.into()
+    }
+
+    /// The block encoded with `postcard`. The builder seals it and submits it with the
+    /// `submitBlock` mutation.
+    async fn raw_block(&self) -> async_graphql::Result<HexString> {
+        Ok(HexString(postcard::to_stdvec(&self.template.block)?))
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -195,7 +295,9 @@ impl BlockQuery {
         let data: &Database = ctx.data_unchecked();
         let id = match (id, height) {
             (Some(_), Some(_)) => {
-                return Err(async_graphql::Error::new(
+                return Err(coded_error(
+                    ErrorCode::InvalidInput,
+                    "ambiguous_arguments",
                     "Can't provide both an id and a height",
                 ))
             }
@@ -206,13 +308,48 @@ impl BlockQuery {
                 data.block_id(&height.into())
             }
             (None, None) => {
-                return Err(async_graphql::Error::new("Missing either id or height"))
+                return Err(coded_error(
+                    ErrorCode::InvalidInput,
+                    "missing_arguments",
+                    "Missing either id or height",
+                ))
             }
         };
 
         id.and_then(|id| data.block(&id)).into_api_result()
     }
 
+    /// Returns the template of the next block with the `gas_limit` at the `block_time`,
+    /// the current time if it is not set. Transactions of the template are not selected,
+    /// so they stay in the pool. Available only if the node runs in the debug mode.
+    async fn next_block_template(
+        &self,
+        ctx: &Context<'_>,
+        gas_limit: U64,
+        block_time: Option<Tai64Timestamp>,
+    ) -> async_graphql::Result<BlockTemplate> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.debug {
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "debug_disabled",
+                "Debug mode must be enabled to query the block template",
+            ))
+        }
+        let query: &Database = ctx.data_unchecked();
+        let producer = ctx.data_unchecked::<BlockProducer>();
+
+        let height = query.latest_block_height()? + 1u32.into();
+        let block_time = block_time.map_or_else(Tai64::now, |time| time.0);
+        let template = producer
+            .block_template(height, block_time, gas_limit.into())
+            .await?;
+        Ok(BlockTemplate {
+            template,
+            transaction_parameters: config.transaction_parameters,
+        })
+    }
+
     async fn blocks(
         &self,
         ctx: &Context<'_>,
@@ -220,11 +357,17 @@ impl BlockQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<U32, Block, EmptyFields, EmptyFields>> {
+    ) -> async_graphql::Result<Connection<Cursor<U32>, Block, EmptyFields, EmptyFields>>
+    {
         let db: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(db, start.map(Into::into), direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(db, start.map(Into::into), direction)),
+        )
         .await
     }
 }
@@ -253,11 +396,17 @@ impl HeaderQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<U32, Header, EmptyFields, EmptyFields>> {
+    ) -> async_graphql::Result<Connection<Cursor<U32>, Header, EmptyFields, EmptyFields>>
+    {
         let db: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(db, start.map(Into::into), direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(db, start.map(Into::into), direction)),
+        )
         .await
     }
 }
@@ -286,27 +435,38 @@ impl BlockMutation {
     /// Sequentially produces `blocks_to_produce` blocks. The first block starts with
     /// `start_timestamp`. If the block production in the [`crate::service::Config`] is
     /// `Trigger::Interval { block_time }`, produces blocks with `block_time ` intervals between
-    /// them. The `start_timestamp` is the timestamp in seconds.
+    /// them. The `start_timestamp` is the timestamp in seconds. If the `da_height` is
+    /// set, blocks are produced at this DA height instead of the latest finalized one, so
+    /// the DA advancement can be held back during the incident of the relayer.
     async fn produce_blocks(
         &self,
         ctx: &Context<'_>,
         start_timestamp: Option<Tai64Timestamp>,
         blocks_to_produce: U64,
+        da_height: Option<U64>,
     ) -> async_graphql::Result<U32> {
         let query: &Database = ctx.data_unchecked();
         let consensus_module = ctx.data_unchecked::<ConsensusModule>();
         let config = ctx.data_unchecked::<GraphQLConfig>().clone();
 
         if !config.manual_blocks_enabled {
-            return Err(
-                anyhow!("Manual Blocks must be enabled to use this endpoint").into(),
-            )
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "manual_blocks_disabled",
+                "Manual Blocks must be enabled to use this endpoint",
+            ))
         }
 
         let start_time = start_timestamp.map(|timestamp| timestamp.0);
         let blocks_to_produce: u64 = blocks_to_produce.into();
+        let da_height = da_height.map(|height| u64::from(height).into());
         consensus_module
-            .manually_produce_blocks(start_time, blocks_to_produce as u32)
+            .manually_produce_blocks(
+                start_time,
+                blocks_to_produce as u32,
+                ManualGasLimit::Config,
+                da_height,
+            )
             .await?;
 
         query
@@ -314,6 +474,79 @@ impl BlockMutation {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Imports the block built from the block template. The `block` is the sealed block
+    /// encoded with `postcard`. The node verifies its consensus and executes it like the
+    /// block received from the network. Available only if the node runs in the debug
+    /// mode.
+    async fn submit_block(
+        &self,
+        ctx: &Context<'_>,
+        block: HexString,
+    ) -> async_graphql::Result<Header> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.debug {
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "debug_disabled",
+                "Debug mode must be enabled to submit blocks",
+            ))
+        }
+        let block: SealedBlock = postcard::from_bytes(&block.0).map_err(|e| {
+            coded_error(ErrorCode::InvalidInput, "invalid_block_bytes", e)
+        })?;
+        let header = block.entity.header().clone();
+
+        let importer = ctx.data_unchecked::<BlockImporter>();
+        importer.import_block(block).await?;
+        Ok(header.into())
+    }
+}
+
+/// The promise of the block producer that transactions are included into the sealed
+/// block, before the block is committed.
+pub struct PreConfirmation(CorePreConfirmation);
+
+#[Object]
+impl PreConfirmation {
+    /// The ids of the selected transactions in the order of the block.
+    async fn transaction_ids(&self) -> Vec<TransactionId> {
+        self.0.tx_ids.iter().copied().map(Into::into).collect()
+    }
+
+    /// The height of the block that includes the transactions.
+    async fn height(&self) -> U32 {
+        self.0.height.into()
+    }
+
+    /// The signature of the block producer over the domain tag, the chain id, the
+    /// height, the number of transactions and their ids.
+    async fn signature(&self) -> Signature {
+        self.0.signature.into()
+    }
+}
+
+#[derive(Default)]
+pub struct PreConfirmationSubscription;
+
+#[Subscription]
+impl PreConfirmationSubscription {
+    /// Returns a stream of pre-confirmations of transactions selected into the block
+    /// by the block producer of this node, before the block is committed.
+    ///
+    /// Requires the node to produce blocks with enabled pre-confirmations.
+    async fn pre_confirmations(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<impl Stream<Item = PreConfirmation>> {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let stream = consensus_module
+            .pre_confirmations()
+            .map_err(|e| {
+                coded_error(ErrorCode::Unsupported, "block_production_disabled", e)
+            })?;
+        Ok(stream.map(PreConfirmation))
+    }
 }
 
 impl From<CompressedBlock> for Block {
@@ -352,6 +585,12 @@ impl From<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             }),
+            CoreConsensus::PoAThreshold(poa) => {
+                Consensus::PoAThreshold(PoAThresholdConsensus {
+                    committee: poa.seal.committee.into(),
+                    signatures: poa.seal.signatures.into_iter().map(Into::into).collect(),
+                })
+            }
         }
     }
 }
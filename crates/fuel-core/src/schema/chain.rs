@@ -9,6 +9,7 @@ use crate::{
     },
     schema::{
         block::Block,
+        deprecation::DeprecationGuard,
         scalars::{
             U32,
             U64,
@@ -98,6 +99,10 @@ impl ChainInfo {
         Ok(latest_block)
     }
 
+    #[graphql(
+        deprecation = "Use `latestBlock.header.height` instead. Sunset in the schema version 2.",
+        guard = "DeprecationGuard::new(\"ChainInfo\", \"baseChainHeight\")"
+    )]
     async fn base_chain_height(&self, ctx: &Context<'_>) -> U32 {
         let query: &Database = ctx.data_unchecked();
 
@@ -108,6 +113,10 @@ impl ChainInfo {
         height.into()
     }
 
+    #[graphql(
+        deprecation = "Always returns 0. Sunset in the schema version 2.",
+        guard = "DeprecationGuard::new(\"ChainInfo\", \"peerCount\")"
+    )]
     async fn peer_count(&self) -> u16 {
         0
     }
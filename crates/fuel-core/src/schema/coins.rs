@@ -12,16 +12,18 @@ use crate::{
         asset_query::AssetSpendTarget,
         CoinQueryData,
     },
-    schema::scalars::{
-        Address,
-        AssetId,
-        Nonce,
-        UtxoId,
-        U32,
-        U64,
+    schema::{
+        cursor::Cursor,
+        scalars::{
+            Address,
+            AssetId,
+            Nonce,
+            UtxoId,
+            U32,
+            U64,
+        },
     },
 };
-use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -164,31 +166,35 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
-        // Rocksdb doesn't support reverse iteration over a prefix
-        if matches!(last, Some(last) if last > 0) {
-            return Err(anyhow!("reverse pagination isn't supported for this coins").into())
-        }
-
+    ) -> async_graphql::Result<Connection<Cursor<UtxoId>, Coin, EmptyFields, EmptyFields>>
+    {
         let query: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let owner: fuel_tx::Address = filter.owner.into();
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
-                .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
-                        if coin.asset_id != filter_asset_id.0 {
-                            return None
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let owner: fuel_tx::Address = filter.owner.into();
+                let coins = query
+                    .owned_coins(&owner, (*start).map(Into::into), direction)
+                    .filter_map(|result| {
+                        if let (Ok(coin), Some(filter_asset_id)) =
+                            (&result, &filter.asset_id)
+                        {
+                            if coin.asset_id != filter_asset_id.0 {
+                                return None
+                            }
                         }
-                    }
 
-                    Some(result)
-                })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+                        Some(result)
+                    })
+                    .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
-            Ok(coins)
-        })
+                Ok(coins)
+            },
+        )
         .await
     }
 
@@ -1,18 +1,28 @@
 use crate::{
     fuel_core_graphql_api::{
+        error::{
+            coded_error,
+            ErrorCode,
+        },
         service::Database,
         IntoApiResult,
     },
     query::ContractQueryData,
-    schema::scalars::{
-        AssetId,
-        ContractId,
-        HexString,
-        Salt,
-        U64,
+    schema::{
+        cursor::Cursor,
+        scalars::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            HexString,
+            Salt,
+            TransactionId,
+            U32,
+            U64,
+        },
+        tx::receipt::Receipt,
     },
 };
-use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -27,6 +37,9 @@ use fuel_core_types::{
     services::graphql_api,
 };
 
+/// The maximum number of blocks scanned by one `contractLogs` query.
+const MAX_LOGS_BLOCK_RANGE: u32 = 10_000;
+
 pub struct Contract(pub(crate) fuel_types::ContractId);
 
 impl From<fuel_types::ContractId> for Contract {
@@ -71,6 +84,71 @@ impl ContractQuery {
         let data: &Database = ctx.data_unchecked();
         data.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the value of the storage slot of the contract, `null` if the slot is not set.
+    async fn contract_storage_slot(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> async_graphql::Result<Option<Bytes32>> {
+        let data: &Database = ctx.data_unchecked();
+        data.contract_storage_slot(contract.0, key.0)
+            .into_api_result()
+    }
+
+    /// Returns logs emitted by the contract in blocks from `start_height` to `end_height`
+    /// inclusive. Blocks that don't involve the contract are skipped via bloom filters.
+    async fn contract_logs(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        start_height: U32,
+        end_height: U32,
+    ) -> async_graphql::Result<Vec<ContractLog>> {
+        if start_height.0 > end_height.0 {
+            return Err(coded_error(
+                ErrorCode::InvalidInput,
+                "invalid_range",
+                "The start height is greater than the end height",
+            ))
+        }
+        if end_height.0 - start_height.0 >= MAX_LOGS_BLOCK_RANGE {
+            return Err(coded_error(
+                ErrorCode::InvalidInput,
+                "range_too_large",
+                format!(
+                    "The range can't contain more than {} blocks",
+                    MAX_LOGS_BLOCK_RANGE
+                ),
+            ))
+        }
+
+        let data: &Database = ctx.data_unchecked();
+        let logs = data.contract_logs(
+            contract.0,
+            start_height.into(),
+            end_height.into(),
+        )?;
+        Ok(logs.into_iter().map(ContractLog).collect())
+    }
+}
+
+pub struct ContractLog(graphql_api::ContractLog);
+
+#[Object]
+impl ContractLog {
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.tx_id.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        Receipt(self.0.receipt.clone())
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -134,33 +212,32 @@ impl ContractBalanceQuery {
         last: Option<i32>,
         before: Option<String>,
     ) -> async_graphql::Result<
-        Connection<AssetId, ContractBalance, EmptyFields, EmptyFields>,
+        Connection<Cursor<AssetId>, ContractBalance, EmptyFields, EmptyFields>,
     > {
         let query: &Database = ctx.data_unchecked();
-
-        // Rocksdb doesn't support reverse iteration over a prefix
-        if matches!(last, Some(last) if last > 0) {
-            return Err(
-                anyhow!("reverse pagination isn't supported for this resource").into(),
-            )
-        }
-
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let balances = query
-                .contract_balances(
-                    filter.contract.into(),
-                    (*start).map(Into::into),
-                    direction,
-                )
-                .map(move |balance| {
-                    let balance = balance?;
-                    let asset_id = balance.asset_id;
-
-                    Ok((asset_id.into(), balance.into()))
-                });
-
-            Ok(balances)
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let balances = query
+                    .contract_balances(
+                        filter.contract.into(),
+                        (*start).map(Into::into),
+                        direction,
+                    )
+                    .map(move |balance| {
+                        let balance = balance?;
+                        let asset_id = balance.asset_id;
+
+                        Ok((asset_id.into(), balance.into()))
+                    });
+
+                Ok(balances)
+            },
+        )
         .await
     }
 }
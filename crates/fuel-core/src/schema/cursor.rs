@@ -0,0 +1,222 @@
+//! Opaque cursors of the paginated queries.
+//!
+//! The cursor returned to the client is `hex(version || key || mac)`, where `key` is
+//! the encoding of the storage key and `mac` is the truncated HMAC-SHA256 of
+//! `version || key`. The version allows changing the key encoding without
+//! misinterpreting cursors issued by older nodes, and the MAC rejects cursors that
+//! were modified or produced by a node with another secret.
+
+use async_graphql::connection::CursorType;
+use fuel_core_types::fuel_crypto::Hasher;
+use std::{
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    str::FromStr,
+};
+
+/// The version of the cursor format. Bump it when the encoding of any key changes.
+pub const CURSOR_VERSION: u8 = 1;
+
+const MAC_SIZE: usize = 16;
+const BLOCK_SIZE: usize = 64;
+
+/// The secret used to authenticate cursors. Nodes behind the same load balancer
+/// should share it, so cursors stay valid across nodes and restarts.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CursorSecret([u8; 32]);
+
+impl CursorSecret {
+    pub fn new(secret: [u8; 32]) -> Self {
+        Self(secret)
+    }
+
+    /// Encodes the `key` into the opaque cursor.
+    pub fn encode<K: CursorType>(&self, key: &K) -> String {
+        let mut bytes = vec![CURSOR_VERSION];
+        bytes.extend_from_slice(key.encode_cursor().as_bytes());
+        let mac = self.mac(&bytes);
+        bytes.extend_from_slice(&mac);
+        hex::encode(bytes)
+    }
+
+    /// Decodes the key from the opaque cursor. Returns an error instead of panicking on
+    /// malformed, tampered or stale cursors.
+    pub fn decode<K>(&self, cursor: &str) -> Result<K, String>
+    where
+        K: CursorType,
+        K::Error: core::fmt::Display,
+    {
+        let bytes = hex::decode(cursor).map_err(|_| {
+            format!("The cursor `{}` is not valid, restart the pagination", cursor)
+        })?;
+        if bytes.len() < 1 + MAC_SIZE {
+            return Err(format!(
+                "The cursor `{}` is not valid, restart the pagination",
+                cursor
+            ))
+        }
+
+        let (payload, mac) = bytes.split_at(bytes.len() - MAC_SIZE);
+        if payload[0] != CURSOR_VERSION {
+            return Err(format!(
+                "The cursor has version {} while the node supports version {}, \
+                restart the pagination",
+                payload[0], CURSOR_VERSION
+            ))
+        }
+
+        let expected = self.mac(payload);
+        let equal = expected
+            .iter()
+            .zip(mac)
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+        if !equal {
+            return Err(format!(
+                "The cursor `{}` was not issued by this node, restart the pagination",
+                cursor
+            ))
+        }
+
+        let key = std::str::from_utf8(&payload[1..])
+            .map_err(|_| "The cursor contains a malformed key".to_string())?;
+        K::decode_cursor(key)
+            .map_err(|e| format!("The cursor contains a malformed key: {}", e))
+    }
+
+    fn mac(&self, data: &[u8]) -> [u8; MAC_SIZE] {
+        let mut key = [0u8; BLOCK_SIZE];
+        key[..self.0.len()].copy_from_slice(&self.0);
+        let inner_pad = key.map(|byte| byte ^ 0x36);
+        let outer_pad = key.map(|byte| byte ^ 0x5c);
+
+        let inner = Hasher::default().chain(inner_pad).chain(data).finalize();
+        let outer = Hasher::default().chain(outer_pad).chain(inner).finalize();
+
+        let mut mac = [0u8; MAC_SIZE];
+        mac.copy_from_slice(&outer[..MAC_SIZE]);
+        mac
+    }
+}
+
+impl Default for CursorSecret {
+    /// The well-known secret. It protects cursors from accidental corruption but not
+    /// from forgery, which is acceptable because cursors only point into public data.
+    fn default() -> Self {
+        Self(*Hasher::hash(b"fuel-core/graphql/cursor"))
+    }
+}
+
+impl Debug for CursorSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CursorSecret(..)")
+    }
+}
+
+impl FromStr for CursorSecret {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.strip_prefix("0x").unwrap_or(s);
+        let bytes = hex::decode(value).map_err(|e| e.to_string())?;
+        let secret = bytes
+            .try_into()
+            .map_err(|_| "The cursor secret must be 32 bytes".to_string())?;
+        Ok(Self(secret))
+    }
+}
+
+/// The key of the paginated entry paired with the secret to encode it.
+#[derive(Clone, Debug)]
+pub struct Cursor<K> {
+    pub key: K,
+    secret: CursorSecret,
+}
+
+impl<K> Cursor<K> {
+    pub fn new(key: K, secret: CursorSecret) -> Self {
+        Self { key, secret }
+    }
+}
+
+impl<K: CursorType> CursorType for Cursor<K> {
+    type Error = String;
+
+    /// Cursors are decoded by the pagination layer with the secret of the node.
+    fn decode_cursor(_: &str) -> Result<Self, Self::Error> {
+        Err("Opaque cursors require the secret of the node to be decoded".to_string())
+    }
+
+    fn encode_cursor(&self) -> String {
+        self.secret.encode(&self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::scalars::{
+        Bytes32,
+        SortedTxCursor,
+        U32,
+    };
+    use fuel_core_types::{
+        fuel_types,
+        fuel_types::BlockHeight,
+    };
+
+    #[test]
+    fn cursor_roundtrip() {
+        let secret = CursorSecret::default();
+        let key = SortedTxCursor::new(
+            BlockHeight::from(12u32),
+            Bytes32::from(fuel_types::Bytes32::from([3; 32])),
+        );
+
+        let cursor = secret.encode(&key);
+
+        assert_eq!(secret.decode::<SortedTxCursor>(&cursor), Ok(key));
+    }
+
+    #[test]
+    fn decode_rejects_legacy_cursor() {
+        let secret = CursorSecret::default();
+
+        let result = secret.decode::<U32>("1234");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_rejects_other_version() {
+        let secret = CursorSecret::default();
+        let mut bytes = hex::decode(secret.encode(&U32(7))).unwrap();
+        bytes[0] = CURSOR_VERSION + 1;
+
+        let result = secret.decode::<U32>(&hex::encode(bytes));
+
+        assert!(result.unwrap_err().contains("version"));
+    }
+
+    #[test]
+    fn decode_rejects_other_secret() {
+        let cursor = CursorSecret::new([1; 32]).encode(&U32(7));
+
+        let result = CursorSecret::new([2; 32]).decode::<U32>(&cursor);
+
+        assert!(result.unwrap_err().contains("not issued by this node"));
+    }
+
+    #[test]
+    fn decode_rejects_tampered_key() {
+        let secret = CursorSecret::default();
+        let mut bytes = hex::decode(secret.encode(&U32(7))).unwrap();
+        bytes[1] = b'8';
+
+        let result = secret.decode::<U32>(&hex::encode(bytes));
+
+        assert!(result.is_err());
+    }
+}
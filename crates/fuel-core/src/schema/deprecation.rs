@@ -0,0 +1,155 @@
+//! Versioning and deprecation of the GraphQL API.
+//!
+//! Deprecated fields are marked with the `@deprecated` directive and listed in
+//! [`DEPRECATED_FIELDS`] with the schema version that deprecated them and the version
+//! that removes them. The directive is the only source of the deprecation reason. A
+//! field stays in the schema until its sunset version, so clients have a predictable
+//! window to migrate. Operators can disable deprecated fields earlier to check that
+//! their clients don't depend on them anymore.
+
+use crate::{
+    fuel_core_graphql_api::{
+        error::{
+            coded_error,
+            ErrorCode,
+        },
+        Config as GraphQLConfig,
+    },
+    schema::scalars::U32,
+};
+use async_graphql::{
+    registry::Deprecation,
+    Context,
+    Guard,
+    Object,
+};
+
+/// The version of the schema. Bump it when the deprecated fields with the sunset at the
+/// new version are removed.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The field of the schema that will be removed in the `sunset` version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeprecatedField {
+    pub type_name: &'static str,
+    pub field: &'static str,
+    pub since: u32,
+    pub sunset: u32,
+}
+
+/// All deprecated fields of the schema.
+pub const DEPRECATED_FIELDS: &[DeprecatedField] = &[
+    DeprecatedField {
+        type_name: "ChainInfo",
+        field: "baseChainHeight",
+        since: 1,
+        sunset: 2,
+    },
+    DeprecatedField {
+        type_name: "ChainInfo",
+        field: "peerCount",
+        since: 1,
+        sunset: 2,
+    },
+];
+
+/// Rejects the deprecated field if the node disables deprecated fields.
+pub struct DeprecationGuard {
+    type_name: &'static str,
+    field: &'static str,
+}
+
+impl DeprecationGuard {
+    pub fn new(type_name: &'static str, field: &'static str) -> Self {
+        Self { type_name, field }
+    }
+}
+
+#[async_trait::async_trait]
+impl Guard for DeprecationGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if config.disable_deprecated_fields {
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "deprecated",
+                format!(
+                    "The deprecated field `{}.{}` is disabled on this node",
+                    self.type_name, self.field
+                ),
+            ))
+        }
+        Ok(())
+    }
+}
+
+#[Object]
+impl DeprecatedField {
+    /// The name of the type that contains the field.
+    async fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// The name of the field.
+    async fn field(&self) -> &str {
+        self.field
+    }
+
+    /// The schema version that deprecated the field.
+    async fn since(&self) -> U32 {
+        self.since.into()
+    }
+
+    /// The schema version that removes the field.
+    async fn sunset(&self) -> U32 {
+        self.sunset.into()
+    }
+
+    /// Why the field is deprecated and what to use instead.
+    async fn reason(&self, ctx: &Context<'_>) -> String {
+        let field = ctx
+            .schema_env
+            .registry
+            .types
+            .get(self.type_name)
+            .and_then(|ty| ty.field_by_name(self.field));
+        match field.map(|field| &field.deprecation) {
+            Some(Deprecation::Deprecated {
+                reason: Some(reason),
+            }) => reason.to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+pub struct SchemaVersion;
+
+#[Object]
+impl SchemaVersion {
+    /// The version of the schema served by the node.
+    async fn version(&self) -> U32 {
+        SCHEMA_VERSION.into()
+    }
+
+    /// Whether the node serves deprecated fields. Requests to disabled fields fail with
+    /// the `UNSUPPORTED` error code.
+    async fn deprecated_fields_enabled(&self, ctx: &Context<'_>) -> bool {
+        !ctx.data_unchecked::<GraphQLConfig>().disable_deprecated_fields
+    }
+
+    /// The deprecated fields with the versions that remove them.
+    async fn deprecations(&self) -> Vec<DeprecatedField> {
+        DEPRECATED_FIELDS.to_vec()
+    }
+}
+
+#[derive(Default)]
+pub struct SchemaVersionQuery;
+
+#[Object]
+impl SchemaVersionQuery {
+    /// Returns the version of the schema and its deprecated fields.
+    async fn schema_version(&self) -> SchemaVersion {
+        SchemaVersion
+    }
+}
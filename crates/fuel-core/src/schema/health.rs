@@ -1,4 +1,11 @@
-use async_graphql::Object;
+use crate::fuel_core_graphql_api::service::NodeStatus;
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    SimpleObject,
+};
+use fuel_core_services::Health;
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +16,47 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the health of every subsystem of the node.
+    async fn services_health(&self, ctx: &Context<'_>) -> Vec<ServiceHealth> {
+        let node_status = ctx.data_unchecked::<NodeStatus>();
+        node_status
+            .services_health()
+            .into_iter()
+            .map(|(name, health)| ServiceHealth::new(name, health))
+            .collect()
+    }
+}
+
+/// The health status of the subsystem of the node.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+/// The health of the subsystem of the node.
+#[derive(SimpleObject)]
+pub struct ServiceHealth {
+    /// The name of the subsystem.
+    name: String,
+    status: HealthStatus,
+    /// Why the subsystem is not healthy.
+    reason: Option<String>,
+}
+
+impl ServiceHealth {
+    fn new(name: &str, health: Health) -> Self {
+        let (status, reason) = match health {
+            Health::Healthy => (HealthStatus::Healthy, None),
+            Health::Degraded(reason) => (HealthStatus::Degraded, Some(reason)),
+            Health::Unhealthy(reason) => (HealthStatus::Unhealthy, Some(reason)),
+        };
+        Self {
+            name: name.to_string(),
+            status,
+            reason,
+        }
+    }
 }
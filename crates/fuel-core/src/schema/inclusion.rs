@@ -0,0 +1,120 @@
+use crate::{
+    fuel_core_graphql_api::service::InclusionMonitor,
+    schema::scalars::{
+        TransactionId,
+        U32,
+        U64,
+    },
+    service::inclusion_monitor::{
+        InclusionStatistics as Statistics,
+        TxInclusion,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    SimpleObject,
+};
+
+#[derive(Default)]
+pub struct InclusionQuery;
+
+#[Object]
+impl InclusionQuery {
+    /// Returns how long tracked transactions wait for the inclusion into blocks.
+    /// Returns `null` if the inclusion monitor is disabled.
+    async fn inclusion_statistics(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Option<InclusionStatistics> {
+        let monitor = ctx.data_unchecked::<InclusionMonitor>();
+        monitor.inclusion_statistics().map(Into::into)
+    }
+
+    /// Returns how long the transaction waits or waited for the inclusion into the
+    /// block. Returns `null` if the inclusion monitor is disabled, or the transaction
+    /// isn't tracked because of the low gas price or is already forgotten.
+    async fn transaction_inclusion(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> Option<TransactionInclusion> {
+        let monitor = ctx.data_unchecked::<InclusionMonitor>();
+        monitor.transaction_inclusion(&id.0).map(Into::into)
+    }
+}
+
+/// The statistics of the inclusion of transactions paying at least the minimal gas
+/// price tracked by the inclusion monitor.
+#[derive(SimpleObject)]
+pub struct InclusionStatistics {
+    /// The number of tracked transactions waiting for the inclusion, including ones
+    /// expired in the pool.
+    pending: U64,
+    /// The number of pending transactions waiting longer than the threshold.
+    overdue: U64,
+    /// The number of tracked transactions included into blocks.
+    included: U64,
+    /// The number of included transactions that waited longer than the threshold.
+    delayed: U64,
+    /// The longest wait of included transactions in milliseconds.
+    max_delay_ms: U64,
+    /// The average wait of included transactions in milliseconds.
+    average_delay_ms: U64,
+}
+
+impl From<Statistics> for InclusionStatistics {
+    fn from(statistics: Statistics) -> Self {
+        Self {
+            pending: statistics.pending.into(),
+            overdue: statistics.overdue.into(),
+            included: statistics.included.into(),
+            delayed: statistics.delayed.into(),
+            max_delay_ms: (statistics.max_delay.as_millis() as u64).into(),
+            average_delay_ms: (statistics.average_delay.as_millis() as u64).into(),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum InclusionStatus {
+    /// The transaction waits for the inclusion, or expired in the pool before it.
+    Pending,
+    /// The transaction is included into the committed block.
+    Included,
+}
+
+#[derive(SimpleObject)]
+pub struct TransactionInclusion {
+    status: InclusionStatus,
+    /// How long the transaction waits or waited for the inclusion in milliseconds.
+    delay_ms: U64,
+    /// Whether the transaction waits or waited longer than the threshold.
+    delayed: bool,
+    /// The height of the block with the transaction if it is included.
+    height: Option<U32>,
+}
+
+impl From<TxInclusion> for TransactionInclusion {
+    fn from(inclusion: TxInclusion) -> Self {
+        match inclusion {
+            TxInclusion::Pending { delay, overdue } => Self {
+                status: InclusionStatus::Pending,
+                delay_ms: (delay.as_millis() as u64).into(),
+                delayed: overdue,
+                height: None,
+            },
+            TxInclusion::Included {
+                delay,
+                height,
+                delayed,
+            } => Self {
+                status: InclusionStatus::Included,
+                delay_ms: (delay.as_millis() as u64).into(),
+                delayed,
+                height: Some(height.into()),
+            },
+        }
+    }
+}
@@ -13,11 +13,21 @@ use super::{
     },
 };
 use crate::{
-    fuel_core_graphql_api::service::Database,
+    fuel_core_graphql_api::{
+        error::{
+            coded_error,
+            ErrorCode,
+            IntoCodedError,
+        },
+        service::Database,
+    },
     query::MessageQueryData,
-    schema::scalars::{
-        BlockId,
-        U32,
+    schema::{
+        cursor::Cursor,
+        scalars::{
+            BlockId,
+            U32,
+        },
     },
 };
 use anyhow::anyhow;
@@ -29,7 +39,10 @@ use async_graphql::{
     Context,
     Object,
 };
-use fuel_core_types::entities;
+use fuel_core_types::{
+    blockchain::primitives,
+    entities,
+};
 
 pub struct Message(pub(crate) entities::message::Message);
 
@@ -73,10 +86,12 @@ impl MessageQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<HexString, Message, EmptyFields, EmptyFields>>
-    {
+    ) -> async_graphql::Result<
+        Connection<Cursor<HexString>, Message, EmptyFields, EmptyFields>,
+    > {
         let query: &Database = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -89,14 +104,6 @@ impl MessageQuery {
                 };
 
                 let messages = if let Some(owner) = owner {
-                    // Rocksdb doesn't support reverse iteration over a prefix
-                    if matches!(last, Some(last) if last > 0) {
-                        return Err(anyhow!(
-                            "reverse pagination isn't supported for this resource"
-                        )
-                        .into())
-                    }
-
                     query.owned_messages(&owner.0, start, direction)
                 } else {
                     query.all_messages(start, direction)
@@ -123,16 +130,7 @@ impl MessageQuery {
         commit_block_height: Option<U32>,
     ) -> async_graphql::Result<Option<MessageProof>> {
         let data: &Database = ctx.data_unchecked();
-        let block_id = match (commit_block_id, commit_block_height) {
-            (Some(commit_block_id), None) => commit_block_id.0.into(),
-            (None, Some(commit_block_height)) => {
-                let block_height = commit_block_height.0.into();
-                data.block_id(&block_height)?
-            }
-            _ => Err(anyhow::anyhow!(
-                "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
-            ))?,
-        };
+        let block_id = commit_block(data, commit_block_id, commit_block_height)?;
 
         Ok(crate::query::message_proof(
             data.deref(),
@@ -142,7 +140,54 @@ impl MessageQuery {
         )?
         .map(MessageProof))
     }
+
+    /// Returns the proofs of many messages of the same transaction in the order of
+    /// `message_ids`. The proof is `null` for messages that can't be proven.
+    async fn message_proofs(
+        &self,
+        ctx: &Context<'_>,
+        transaction_id: TransactionId,
+        message_ids: Vec<MessageId>,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<U32>,
+    ) -> async_graphql::Result<Vec<Option<MessageProof>>> {
+        let data: &Database = ctx.data_unchecked();
+        let block_id = commit_block(data, commit_block_id, commit_block_height)?;
+        let message_ids: Vec<_> = message_ids.into_iter().map(Into::into).collect();
+
+        Ok(crate::query::message_proofs(
+            data.deref(),
+            transaction_id.into(),
+            &message_ids,
+            block_id,
+        )?
+        .into_iter()
+        .map(|proof| proof.map(MessageProof))
+        .collect())
+    }
+}
+
+fn commit_block(
+    data: &Database,
+    commit_block_id: Option<BlockId>,
+    commit_block_height: Option<U32>,
+) -> async_graphql::Result<primitives::BlockId> {
+    let block_id = match (commit_block_id, commit_block_height) {
+        (Some(commit_block_id), None) => commit_block_id.0.into(),
+        (None, Some(commit_block_height)) => {
+            let block_height = commit_block_height.0.into();
+            data.block_id(&block_height)
+                .map_err(IntoCodedError::into_coded_error)?
+        }
+        _ => Err(coded_error(
+            ErrorCode::InvalidInput,
+            "ambiguous_arguments",
+            "Either `commit_block_id` or `commit_block_height` must be provided exclusively",
+        ))?,
+    };
+    Ok(block_id)
 }
+
 pub struct MerkleProof(pub(crate) entities::message::MerkleProof);
 
 #[Object]
@@ -1,8 +1,25 @@
-use super::scalars::U64;
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use super::scalars::{
+    Bytes32,
+    U64,
+};
+use crate::{
+    fuel_core_graphql_api::{
+        service::{
+            NodeStatus,
+            TxPool,
+        },
+        Config as GraphQLConfig,
+    },
+    service::{
+        build_info,
+        version_check,
+    },
+};
 use async_graphql::{
     Context,
+    Enum,
     Object,
+    SimpleObject,
 };
 
 pub struct NodeInfo {
@@ -12,6 +29,8 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    relayer_wallet_balance: Option<U64>,
+    version_advisory: Option<VersionAdvisory>,
 }
 
 #[Object]
@@ -39,6 +58,64 @@ impl NodeInfo {
     async fn node_version(&self) -> String {
         self.node_version.to_owned()
     }
+
+    /// The balance in gwei of the L1 wallet monitored by the relayer, if any.
+    async fn relayer_wallet_balance(&self) -> Option<U64> {
+        self.relayer_wallet_balance
+    }
+
+    /// The advice for the running version of the node from the manifest of supported
+    /// versions of the network, if the version check is enabled.
+    async fn version_advisory(&self) -> Option<&VersionAdvisory> {
+        self.version_advisory.as_ref()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "version_check::VersionStatus")]
+pub enum VersionStatus {
+    /// The running version is at least the recommended version.
+    UpToDate,
+    /// The running version is supported, but the upgrade is recommended.
+    UpgradeRecommended,
+    /// The running version is below the minimum version of the network.
+    Unsupported,
+}
+
+#[derive(SimpleObject)]
+pub struct VersionAdvisory {
+    status: VersionStatus,
+    running_version: String,
+    minimum_version: String,
+    recommended_version: String,
+    /// The note for operators from the manifest.
+    message: Option<String>,
+}
+
+impl From<version_check::VersionAdvisory> for VersionAdvisory {
+    fn from(advisory: version_check::VersionAdvisory) -> Self {
+        Self {
+            status: advisory.status.into(),
+            running_version: advisory.running_version,
+            minimum_version: advisory.minimum_version,
+            recommended_version: advisory.recommended_version,
+            message: advisory.message,
+        }
+    }
+}
+
+/// The build of the node. Nodes with the same build and the same `config_hash` validate
+/// blocks in the same way.
+#[derive(SimpleObject)]
+pub struct BuildInfo {
+    version: String,
+    /// The git commit of the build, or `unknown`.
+    git_sha: String,
+    rustc_version: String,
+    /// The sorted cargo features of the build.
+    features: Vec<String>,
+    /// The hash of the chain config, the UTXO validation and the version of the executor.
+    config_hash: Bytes32,
 }
 
 #[derive(Default)]
@@ -48,16 +125,35 @@ pub struct NodeQuery {}
 impl NodeQuery {
     async fn node_info(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeInfo> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
+        let node_status = ctx.data_unchecked::<NodeStatus>();
+        let txpool = ctx.data_unchecked::<TxPool>();
 
         const VERSION: &str = env!("CARGO_PKG_VERSION");
 
         Ok(NodeInfo {
             utxo_validation: config.utxo_validation,
             vm_backtrace: config.vm_backtrace,
-            min_gas_price: config.min_gas_price.into(),
+            min_gas_price: txpool.min_gas_price().into(),
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            relayer_wallet_balance: node_status.relayer_wallet_balance().map(Into::into),
+            version_advisory: node_status.version_advisory().map(Into::into),
         })
     }
+
+    /// The build of the node and the hash of its consensus-relevant configuration.
+    async fn build_info(&self, ctx: &Context<'_>) -> BuildInfo {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        BuildInfo {
+            version: build_info::VERSION.to_owned(),
+            git_sha: build_info::GIT_SHA.to_owned(),
+            rustc_version: build_info::RUSTC_VERSION.to_owned(),
+            features: build_info::features()
+                .into_iter()
+                .map(ToOwned::to_owned)
+                .collect(),
+            config_hash: config.config_hash.into(),
+        }
+    }
 }
@@ -1,5 +1,15 @@
 use crate::{
     fuel_core_graphql_api::{
+        error::{
+            coded_error,
+            ErrorCode,
+            IntoCodedError,
+        },
+        idempotency::{
+            IdempotencyKeys,
+            SubmissionStatus,
+            MAX_IDEMPOTENCY_KEY_LENGTH,
+        },
         service::{
             BlockProducer,
             Database,
@@ -11,19 +21,23 @@ use crate::{
     query::{
         transaction_status_change,
         BlockQueryData,
+        SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
         TxnStatusChangeState,
     },
-    schema::scalars::{
-        Address,
-        HexString,
-        SortedTxCursor,
-        TransactionId,
-        TxPointer,
+    schema::{
+        cursor::Cursor,
+        scalars::{
+            Address,
+            HexString,
+            SortedTxCursor,
+            TransactionId,
+            TxPointer,
+            U32,
+        },
     },
 };
-use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -48,6 +62,7 @@ use fuel_core_types::{
 };
 use futures::{
     Stream,
+    StreamExt,
     TryStreamExt,
 };
 use itertools::Itertools;
@@ -55,10 +70,19 @@ use std::{
     iter,
     sync::Arc,
 };
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use types::Transaction;
 
-use self::types::TransactionStatus;
+use self::{
+    cost::TransactionCost,
+    types::{
+        PoolEvent,
+        TransactionStatus,
+        TxStageTime,
+    },
+};
 
+pub mod cost;
 pub mod input;
 pub mod output;
 pub mod receipt;
@@ -88,6 +112,48 @@ impl TxQuery {
         }
     }
 
+    /// Returns the time of each lifecycle stage of the recent transaction.
+    /// Available only if the node runs in the debug mode.
+    async fn transaction_timeline(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<Vec<TxStageTime>>> {
+        let config = ctx.data_unchecked::<Config>();
+        if !config.debug {
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "debug_disabled",
+                "Debug mode must be enabled to query the transaction timeline",
+            ))
+        }
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(txpool
+            .timeline(id.0)
+            .map(|stages| stages.into_iter().map(TxStageTime).collect()))
+    }
+
+    /// Returns the size and the fee breakdown of the transaction at the minimal gas price
+    /// of the node, along with the consensus parameters that limit it.
+    async fn transaction_cost(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<TransactionCost> {
+        let config = ctx.data_unchecked::<Config>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let tx = FuelTx::from_bytes(&tx.0).map_err(invalid_transaction_bytes)?;
+
+        TransactionCost::new(tx, &config.transaction_parameters, txpool.min_gas_price())
+            .ok_or_else(|| {
+                coded_error(
+                    ErrorCode::InvalidInput,
+                    "invalid_transaction",
+                    "The transaction doesn't pay fees or its fee overflows",
+                )
+            })
+    }
+
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -96,11 +162,12 @@ impl TxQuery {
         last: Option<i32>,
         before: Option<String>,
     ) -> async_graphql::Result<
-        Connection<SortedTxCursor, Transaction, EmptyFields, EmptyFields>,
+        Connection<Cursor<SortedTxCursor>, Transaction, EmptyFields, EmptyFields>,
     > {
         let db_query: &Database = ctx.data_unchecked();
         let tx_query: &Database = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -158,20 +225,15 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
-    {
-        // Rocksdb doesn't support reverse iteration over a prefix
-        if matches!(last, Some(last) if last > 0) {
-            return Err(
-                anyhow!("reverse pagination isn't supported for this resource").into(),
-            )
-        }
-
+    ) -> async_graphql::Result<
+        Connection<Cursor<TxPointer>, Transaction, EmptyFields, EmptyFields>,
+    > {
         let query: &Database = ctx.data_unchecked();
         let config = ctx.data_unchecked::<Config>();
         let owner = fuel_types::Address::from(owner);
 
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -192,6 +254,43 @@ impl TxQuery {
         )
         .await
     }
+
+    /// Returns transactions of the block at the `height` in the order of the block
+    /// without fetching the rest of the block. The status and receipts of each
+    /// transaction are fetched only if they are selected.
+    async fn transactions_by_block_height(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<Cursor<U32>, Transaction, EmptyFields, EmptyFields>,
+    > {
+        let query: &Database = ctx.data_unchecked();
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            None,
+            first,
+            None,
+            |start: &Option<U32>, _| {
+                let block = query.block(&query.block_id(&height.into())?)?;
+                let (_, tx_ids) = block.into_inner();
+                // The cursor is the index of the transaction in the block.
+                let skip = start.map(usize::from).unwrap_or_default();
+                let txs = tx_ids.into_iter().enumerate().skip(skip).map(
+                    move |(index, tx_id)| {
+                        let tx = query.transaction(&tx_id)?;
+                        Ok((U32(index as u32), Transaction::from_tx(tx_id, tx)))
+                    },
+                );
+                Ok(txs)
+            },
+        )
+        .await
+    }
 }
 
 #[derive(Default)]
@@ -212,10 +311,13 @@ impl TxMutation {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let config = ctx.data_unchecked::<Config>();
 
-        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        let mut tx = FuelTx::from_bytes(&tx.0).map_err(invalid_transaction_bytes)?;
         tx.precompute(&config.transaction_parameters);
 
-        let receipts = block_producer.dry_run_tx(tx, None, utxo_validation).await?;
+        let receipts = block_producer
+            .dry_run_tx(tx, None, utxo_validation)
+            .await
+            .map_err(IntoCodedError::into_coded_error)?;
         Ok(receipts.iter().map(Into::into).collect())
     }
 
@@ -224,21 +326,111 @@ impl TxMutation {
         &self,
         ctx: &Context<'_>,
         tx: HexString,
+        // Overrides the utxo validation of the node for this transaction.
+        // Available only if the node runs in the debug mode.
+        utxo_validation: Option<bool>,
+        // The retried submission with the same key returns the original transaction
+        // instead of the error, while the node remembers the key.
+        idempotency_key: Option<String>,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
-        let mut tx = FuelTx::from_bytes(&tx.0)?;
-        tx.precompute(&config.transaction_parameters);
-        // TODO: use spawn_blocking here
-        let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx.clone())])
-            .into_iter()
-            .try_collect()?;
-        let id = tx.id(&config.transaction_parameters);
-
-        let tx = Transaction(tx, id);
-        Ok(tx)
+        let idempotency_keys = ctx.data_unchecked::<IdempotencyKeys>();
+        submit_transaction(
+            txpool,
+            config,
+            idempotency_keys,
+            &tx.0,
+            utxo_validation,
+            idempotency_key,
+        )
+    }
+}
+
+/// Decodes the transaction from the `tx` bytes and inserts it into the pool. Shared by
+/// the `submit` mutation and the binary submission endpoint.
+pub(crate) fn submit_transaction(
+    txpool: &TxPool,
+    config: &Config,
+    idempotency_keys: &IdempotencyKeys,
+    tx: &[u8],
+    utxo_validation: Option<bool>,
+    idempotency_key: Option<String>,
+) -> async_graphql::Result<Transaction> {
+    if utxo_validation.is_some() && !config.debug {
+        return Err(coded_error(
+            ErrorCode::Unsupported,
+            "debug_disabled",
+            "Debug mode must be enabled to override the utxo validation",
+        ))
+    }
+    if let Some(key) = &idempotency_key {
+        if key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+            return Err(coded_error(
+                ErrorCode::InvalidInput,
+                "idempotency_key_too_long",
+                format!(
+                    "The idempotency key is longer than {} bytes",
+                    MAX_IDEMPOTENCY_KEY_LENGTH
+                ),
+            ))
+        }
+    }
+    let mut tx = FuelTx::from_bytes(tx).map_err(invalid_transaction_bytes)?;
+    tx.precompute(&config.transaction_parameters);
+    let id = tx.id(&config.transaction_parameters);
+
+    let tx = Arc::new(tx);
+    // The key is reserved before the insertion, so only one of concurrent
+    // submissions with the same key reaches the pool.
+    if let Some(key) = &idempotency_key {
+        match idempotency_keys.reserve(key.clone(), id) {
+            Some((submitted_id, _)) if submitted_id != id => {
+                return Err(coded_error(
+                    ErrorCode::InvalidInput,
+                    "idempotency_key_reused",
+                    format!(
+                        "The idempotency key was used for the transaction {}",
+                        submitted_id
+                    ),
+                ))
+            }
+            Some((_, SubmissionStatus::Pending)) => {
+                return Err(coded_error(
+                    ErrorCode::InvalidInput,
+                    "idempotency_key_pending",
+                    "The transaction with the idempotency key is still being submitted",
+                ))
+            }
+            // The same id means the same transaction, so the decoded one is returned.
+            Some((_, SubmissionStatus::Submitted)) => {
+                return Ok(Transaction(tx.as_ref().clone(), id))
+            }
+            None => {}
+        }
+    }
+
+    // TODO: use spawn_blocking here
+    let inserted: Result<Vec<_>, _> = txpool
+        .insert(vec![tx.clone()], utxo_validation)
+        .into_iter()
+        .try_collect();
+    if let Err(e) = inserted {
+        if let Some(key) = &idempotency_key {
+            idempotency_keys.release(key, &id);
+        }
+        return Err(e.into_coded_error())
+    }
+    if let Some(key) = &idempotency_key {
+        idempotency_keys.confirm(key, &id);
     }
+
+    let tx = Transaction(tx.as_ref().clone(), id);
+    Ok(tx)
+}
+
+fn invalid_transaction_bytes(error: std::io::Error) -> async_graphql::Error {
+    coded_error(ErrorCode::InvalidInput, "invalid_transaction_bytes", error)
 }
 
 #[derive(Default)]
@@ -279,6 +471,37 @@ impl TxStatusSubscription {
     }
 }
 
+#[derive(Default)]
+pub struct PoolEventsSubscription;
+
+#[Subscription]
+impl PoolEventsSubscription {
+    /// Returns a stream of every event of the transaction pool for the analytics of the
+    /// mempool. Available only if the admin API of the node is enabled.
+    ///
+    /// The node buffers a bounded number of events for every subscriber. The subscriber
+    /// that is polled slower than events arrive misses the oldest of them and receives
+    /// the `LAGGED` event with the number of missed events instead.
+    async fn pool_events(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<impl Stream<Item = PoolEvent>> {
+        let config = ctx.data_unchecked::<Config>();
+        if !config.admin_api {
+            return Err(coded_error(
+                ErrorCode::Unsupported,
+                "admin_api_disabled",
+                "The admin API must be enabled to subscribe to events of the pool",
+            ))
+        }
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(txpool.pool_events_subscribe().map(|event| match event {
+            Ok(event) => PoolEvent::Pool(event),
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => PoolEvent::Lagged(skipped),
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl<'a> TxnStatusChangeState for StreamState<'a> {
     async fn get_tx_status(
@@ -0,0 +1,147 @@
+use crate::schema::scalars::U64;
+use async_graphql::SimpleObject;
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            BytecodeWitnessIndex,
+            Inputs,
+            Outputs,
+            Script as ScriptField,
+            ScriptData,
+            StorageSlots,
+            Witnesses,
+        },
+        Chargeable,
+        ConsensusParameters,
+        Transaction as FuelTx,
+        TransactionFee,
+    },
+    fuel_types::{
+        bytes::SerializableVec,
+        Word,
+    },
+};
+
+/// The size and the fee breakdown of the transaction.
+#[derive(SimpleObject)]
+pub struct TransactionCost {
+    /// The size of the serialized transaction in bytes.
+    serialized_size: U64,
+    /// The number of bytes charged by the fee, without the witnesses.
+    metered_bytes: U64,
+    /// The gas price used to compute the fees, the minimal gas price of the node.
+    gas_price: U64,
+    /// The fee for the metered bytes, paid even if the transaction doesn't use gas.
+    base_fee: U64,
+    /// The fee if the transaction uses the whole gas limit.
+    max_fee: U64,
+    /// The consensus parameters that limit the transaction.
+    limits: Vec<TransactionLimit>,
+}
+
+/// The value of the transaction limited by the consensus parameter.
+#[derive(SimpleObject)]
+pub struct TransactionLimit {
+    /// The name of the consensus parameter.
+    parameter: String,
+    /// The value of the transaction.
+    value: U64,
+    /// The maximum value allowed by the consensus parameter.
+    limit: U64,
+    /// Whether the value exceeds the limit and the transaction will be rejected.
+    exceeded: bool,
+}
+
+impl TransactionLimit {
+    fn new(parameter: &str, value: Word, limit: Word) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            value: value.into(),
+            limit: limit.into(),
+            exceeded: value > limit,
+        }
+    }
+}
+
+impl TransactionCost {
+    /// Computes the cost of the `tx` at the `gas_price`. Returns `None` for transactions
+    /// that don't pay fees and if the fee overflows.
+    pub fn new(
+        mut tx: FuelTx,
+        params: &ConsensusParameters,
+        gas_price: Word,
+    ) -> Option<Self> {
+        let (metered_bytes, gas_limit, limits) = match &tx {
+            FuelTx::Script(script) => {
+                let mut limits = common_limits(script, params);
+                limits.push(TransactionLimit::new(
+                    "maxScriptLength",
+                    script.script().len() as Word,
+                    params.max_script_length,
+                ));
+                limits.push(TransactionLimit::new(
+                    "maxScriptDataLength",
+                    script.script_data().len() as Word,
+                    params.max_script_data_length,
+                ));
+                (script.metered_bytes_size(), script.limit(), limits)
+            }
+            FuelTx::Create(create) => {
+                let mut limits = common_limits(create, params);
+                let bytecode_size = create
+                    .witnesses()
+                    .get(*create.bytecode_witness_index() as usize)
+                    .map(|witness| witness.as_ref().len())
+                    .unwrap_or_default();
+                limits.push(TransactionLimit::new(
+                    "contractMaxSize",
+                    bytecode_size as Word,
+                    params.contract_max_size,
+                ));
+                limits.push(TransactionLimit::new(
+                    "maxStorageSlots",
+                    create.storage_slots().len() as Word,
+                    params.max_storage_slots,
+                ));
+                (create.metered_bytes_size(), create.limit(), limits)
+            }
+            FuelTx::Mint(_) => return None,
+        };
+
+        let fee = TransactionFee::checked_from_values(
+            params,
+            metered_bytes as Word,
+            gas_limit,
+            gas_price,
+        )?;
+
+        Some(Self {
+            serialized_size: (tx.to_bytes().len() as Word).into(),
+            metered_bytes: (metered_bytes as Word).into(),
+            gas_price: gas_price.into(),
+            base_fee: fee.bytes().into(),
+            max_fee: fee.total().into(),
+            limits,
+        })
+    }
+}
+
+fn common_limits<T>(tx: &T, params: &ConsensusParameters) -> Vec<TransactionLimit>
+where
+    T: Chargeable + Inputs + Outputs + Witnesses,
+{
+    vec![
+        TransactionLimit::new("maxGasPerTx", tx.limit(), params.max_gas_per_tx),
+        TransactionLimit::new("maxInputs", tx.inputs().len() as Word, params.max_inputs),
+        TransactionLimit::new(
+            "maxOutputs",
+            tx.outputs().len() as Word,
+            params.max_outputs,
+        ),
+        TransactionLimit::new(
+            "maxWitnesses",
+            tx.witnesses().len() as Word,
+            params.max_witnesses,
+        ),
+    ]
+}
@@ -7,6 +7,7 @@ use crate::schema::{
         ContractId,
         HexString,
         Nonce,
+        U32,
         U64,
     },
 };
@@ -19,6 +20,7 @@ use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
     fuel_types::bytes::SerializableVec,
+    services::graphql_api,
 };
 
 #[derive(Copy, Clone, Debug, Display, Enum, Eq, PartialEq)]
@@ -142,6 +144,48 @@ impl Receipt {
     async fn contract_id(&self) -> Option<ContractId> {
         self.0.contract_id().map(|id| ContractId(*id))
     }
+    /// The decoded reason and location of the panic. `null` for other receipts.
+    async fn panic_details(&self) -> Option<PanicDetails> {
+        graphql_api::PanicDetails::from_receipt(&self.0).map(PanicDetails)
+    }
+}
+
+pub struct PanicDetails(pub graphql_api::PanicDetails);
+
+#[Object]
+impl PanicDetails {
+    /// The reason of the panic, e.g. `OutOfGas` or `ContractNotInInputs`.
+    async fn reason(&self) -> String {
+        self.0.reason.to_string()
+    }
+    /// The contract whose code panicked. `null` if the script panicked.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.0.contract_id.map(ContractId)
+    }
+    /// The contract absent from inputs of the transaction, if it caused the panic.
+    async fn missing_contract_id(&self) -> Option<ContractId> {
+        self.0.missing_contract_id.map(ContractId)
+    }
+    /// The program counter of the instruction that trapped.
+    async fn pc(&self) -> U64 {
+        self.0.pc.into()
+    }
+    /// The start of the code that panicked, `pc - is` is the offset of the instruction.
+    async fn is(&self) -> U64 {
+        self.0.is.into()
+    }
+    /// The opcode of the instruction that trapped, e.g. `CALL`. `null` if the
+    /// instruction can't be decoded.
+    async fn opcode(&self) -> Option<String> {
+        self.0
+            .instruction
+            .as_ref()
+            .map(|instruction| format!("{:?}", instruction.opcode()))
+    }
+    /// The raw instruction that trapped.
+    async fn raw_instruction(&self) -> U32 {
+        self.0.raw_instruction.into()
+    }
 }
 
 impl From<&fuel_tx::Receipt> for Receipt {
@@ -1,7 +1,10 @@
 use super::{
     input::Input,
     output::Output,
-    receipt::Receipt,
+    receipt::{
+        PanicDetails,
+        Receipt,
+    },
 };
 use crate::{
     fuel_core_graphql_api::{
@@ -39,6 +42,7 @@ use async_graphql::{
     Union,
 };
 use fuel_core_storage::Error as StorageError;
+use fuel_core_txpool::service::PoolEvent as PoolServiceEvent;
 use fuel_core_types::{
     blockchain::primitives,
     fuel_tx::{
@@ -63,6 +67,7 @@ use fuel_core_types::{
     fuel_types::bytes::SerializableVec,
     fuel_vm::ProgramState as VmProgramState,
     services::{
+        graphql_api,
         txpool,
         txpool::TransactionStatus as TxStatus,
     },
@@ -159,6 +164,7 @@ impl SuccessStatus {
 
 #[derive(Debug)]
 pub struct FailureStatus {
+    tx_id: fuel_tx::TxId,
     block_id: primitives::BlockId,
     time: Tai64,
     reason: String,
@@ -184,6 +190,19 @@ impl FailureStatus {
     async fn program_state(&self) -> Option<ProgramState> {
         self.state.map(Into::into)
     }
+
+    /// The decoded reason and location of the panic, if the transaction panicked.
+    async fn panic_details(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PanicDetails>> {
+        let query: &Database = ctx.data_unchecked();
+        let receipts = query
+            .receipts(&self.tx_id)
+            .into_api_result::<Vec<_>, async_graphql::Error>()?
+            .unwrap_or_default();
+        Ok(graphql_api::PanicDetails::from_receipts(&receipts).map(PanicDetails))
+    }
 }
 
 #[derive(Debug)]
@@ -198,8 +217,8 @@ impl SqueezedOutStatus {
     }
 }
 
-impl From<TxStatus> for TransactionStatus {
-    fn from(s: TxStatus) -> Self {
+impl TransactionStatus {
+    pub fn new(tx_id: fuel_tx::TxId, s: TxStatus) -> Self {
         match s {
             TxStatus::Submitted { time } => {
                 TransactionStatus::Submitted(SubmittedStatus(time))
@@ -222,6 +241,7 @@ impl From<TxStatus> for TransactionStatus {
                 time,
                 result,
             } => TransactionStatus::Failed(FailureStatus {
+                tx_id,
                 block_id,
                 reason,
                 time,
@@ -254,6 +274,7 @@ impl From<TransactionStatus> for TxStatus {
                 reason,
                 time,
                 state: result,
+                ..
             }) => TxStatus::Failed {
                 block_id,
                 reason,
@@ -499,6 +520,144 @@ impl Transaction {
     }
 }
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "fuel_core_txpool::TxStage")]
+pub enum TxStage {
+    Received,
+    Validated,
+    Gossiped,
+    Selected,
+    Executed,
+    Committed,
+    SqueezedOut,
+}
+
+pub struct TxStageTime(pub(crate) fuel_core_txpool::timeline::TxStageTime);
+
+#[Object]
+impl TxStageTime {
+    async fn stage(&self) -> TxStage {
+        self.0.stage.into()
+    }
+
+    /// The number of milliseconds since the UNIX epoch when the stage was reached.
+    async fn time(&self) -> U64 {
+        (self.0.time.as_millis() as u64).into()
+    }
+}
+
+/// The kind of the event of the transaction pool.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum PoolEventKind {
+    Inserted,
+    Replaced,
+    Evicted,
+    Selected,
+    Committed,
+    Lagged,
+}
+
+/// The event of the transaction pool. Fields unrelated to the `kind` of the event are
+/// `null`.
+pub enum PoolEvent {
+    Pool(PoolServiceEvent),
+    /// The subscriber lagged behind and missed the number of events.
+    Lagged(u64),
+}
+
+#[Object]
+impl PoolEvent {
+    async fn kind(&self) -> PoolEventKind {
+        match self {
+            PoolEvent::Pool(PoolServiceEvent::Inserted { .. }) => PoolEventKind::Inserted,
+            PoolEvent::Pool(PoolServiceEvent::Replaced { .. }) => PoolEventKind::Replaced,
+            PoolEvent::Pool(PoolServiceEvent::Evicted { .. }) => PoolEventKind::Evicted,
+            PoolEvent::Pool(PoolServiceEvent::Selected { .. }) => PoolEventKind::Selected,
+            PoolEvent::Pool(PoolServiceEvent::Committed { .. }) => {
+                PoolEventKind::Committed
+            }
+            PoolEvent::Lagged(_) => PoolEventKind::Lagged,
+        }
+    }
+
+    /// The transaction of the event. `null` for the `LAGGED` event.
+    async fn transaction_id(&self) -> Option<TransactionId> {
+        match self {
+            PoolEvent::Pool(event) => Some(TransactionId(*event.tx_id())),
+            PoolEvent::Lagged(_) => None,
+        }
+    }
+
+    /// The gas price of the inserted transaction.
+    async fn gas_price(&self) -> Option<U64> {
+        match self {
+            PoolEvent::Pool(PoolServiceEvent::Inserted { gas_price, .. }) => {
+                Some((*gas_price).into())
+            }
+            _ => None,
+        }
+    }
+
+    /// The maximum gas of the inserted transaction.
+    async fn max_gas(&self) -> Option<U64> {
+        match self {
+            PoolEvent::Pool(PoolServiceEvent::Inserted { max_gas, .. }) => {
+                Some((*max_gas).into())
+            }
+            _ => None,
+        }
+    }
+
+    /// The metered size of the inserted transaction in bytes.
+    async fn metered_bytes_size(&self) -> Option<U64> {
+        match self {
+            PoolEvent::Pool(PoolServiceEvent::Inserted {
+                metered_bytes_size, ..
+            }) => Some((*metered_bytes_size as u64).into()),
+            _ => None,
+        }
+    }
+
+    /// The transaction that pushed out the replaced transaction.
+    async fn replaced_by(&self) -> Option<TransactionId> {
+        match self {
+            PoolEvent::Pool(PoolServiceEvent::Replaced { by, .. }) => {
+                Some(TransactionId(*by))
+            }
+            _ => None,
+        }
+    }
+
+    /// Why the evicted transaction is removed from the pool.
+    async fn reason(&self) -> Option<String> {
+        match self {
+            PoolEvent::Pool(PoolServiceEvent::Evicted { reason, .. }) => {
+                Some(reason.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// The height of the block that the transaction is selected into or committed in.
+    async fn height(&self) -> Option<U32> {
+        match self {
+            PoolEvent::Pool(
+                PoolServiceEvent::Selected { height, .. }
+                | PoolServiceEvent::Committed { height, .. },
+            ) => Some((*height).into()),
+            _ => None,
+        }
+    }
+
+    /// The number of events that the lagging subscriber missed.
+    async fn skipped(&self) -> Option<U64> {
+        match self {
+            PoolEvent::Lagged(skipped) => Some((*skipped).into()),
+            PoolEvent::Pool(_) => None,
+        }
+    }
+}
+
 #[tracing::instrument(level = "debug", skip(query, txpool), ret, err)]
 pub(super) async fn get_tx_status(
     id: fuel_core_types::fuel_types::Bytes32,
@@ -509,12 +668,18 @@ pub(super) async fn get_tx_status(
         .status(&id)
         .into_api_result::<txpool::TransactionStatus, StorageError>()?
     {
-        Some(status) => Ok(Some(status.into())),
+        Some(status) => Ok(Some(TransactionStatus::new(id, status))),
         None => match txpool.submission_time(id) {
             Some(submitted_time) => Ok(Some(TransactionStatus::Submitted(
                 SubmittedStatus(submitted_time),
             ))),
-            _ => Ok(None),
+            // Squeezed out status is never stored in the database, but the reason of
+            // skipped transactions is kept by the pool for a while.
+            None => Ok(txpool.skipped_reason(id).map(|reason| {
+                TransactionStatus::SqueezedOut(SqueezedOutStatus {
+                    reason: txpool::Error::SkippedTransaction(reason).to_string(),
+                })
+            })),
         },
     }
 }
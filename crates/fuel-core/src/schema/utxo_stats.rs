@@ -0,0 +1,94 @@
+use crate::{
+    fuel_core_graphql_api::service::UtxoStatistics as UtxoStatisticsPort,
+    schema::scalars::{
+        AssetId,
+        U128,
+        U32,
+        U64,
+    },
+    service::utxo_stats::{
+        bucket_upper_bound,
+        UtxoSetStatistics,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+    SimpleObject,
+};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct UtxoStatisticsQuery;
+
+#[Object]
+impl UtxoStatisticsQuery {
+    /// Returns the latest statistics of the UTXO set computed in the background.
+    /// Returns `null` if the statistics are disabled or not computed yet.
+    async fn utxo_statistics(&self, ctx: &Context<'_>) -> Option<UtxoStatistics> {
+        let utxo_statistics = ctx.data_unchecked::<UtxoStatisticsPort>();
+        utxo_statistics.utxo_statistics().map(UtxoStatistics)
+    }
+}
+
+pub struct UtxoStatistics(Arc<UtxoSetStatistics>);
+
+#[Object]
+impl UtxoStatistics {
+    /// The height of the latest block when the computation started.
+    async fn height(&self) -> U32 {
+        self.0.height.into()
+    }
+
+    /// The number of unspent coins.
+    async fn count(&self) -> U64 {
+        self.0.count.into()
+    }
+
+    /// The statistics of every asset, sorted by the asset id.
+    async fn assets(&self) -> Vec<AssetStatistics> {
+        self.0
+            .assets
+            .iter()
+            .map(|asset| AssetStatistics {
+                asset_id: asset.asset_id.into(),
+                count: asset.count.into(),
+                total_amount: asset.total_amount.into(),
+            })
+            .collect()
+    }
+
+    /// The number of coins per decimal order of magnitude of the amount.
+    async fn histogram(&self) -> Vec<AmountBucket> {
+        self.0
+            .histogram
+            .iter()
+            .enumerate()
+            .map(|(bucket, count)| AmountBucket {
+                max_amount: bucket_upper_bound(bucket).into(),
+                count: (*count).into(),
+            })
+            .collect()
+    }
+
+    /// The statistics rendered as CSV.
+    async fn csv(&self) -> String {
+        self.0.to_csv()
+    }
+}
+
+/// The statistics of the unspent coins of the asset.
+#[derive(SimpleObject)]
+pub struct AssetStatistics {
+    asset_id: AssetId,
+    count: U64,
+    total_amount: U128,
+}
+
+/// The number of coins with the amount not greater than `max_amount` and greater than
+/// the `max_amount` of the previous bucket.
+#[derive(SimpleObject)]
+pub struct AmountBucket {
+    max_amount: U64,
+    count: U64,
+}
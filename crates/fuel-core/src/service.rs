@@ -2,16 +2,25 @@ use crate::{
     database::Database,
     service::adapters::P2PAdapter,
 };
+#[cfg(feature = "p2p")]
+use fuel_core_p2p::capabilities::BlockRetention;
 use fuel_core_services::{
+    ConfigUpdate,
     RunnableService,
     RunnableTask,
+    ServiceError,
+    ServiceOrchestrator,
     ServiceRunner,
     State,
     StateWatcher,
 };
 use std::net::SocketAddr;
+#[cfg(feature = "fork")]
+use std::sync::Arc;
 use tracing::log::warn;
 
+#[cfg(feature = "fork")]
+pub use config::ForkConfig;
 pub use config::{
     Config,
     DbType,
@@ -20,14 +29,34 @@ pub use config::{
 pub use fuel_core_services::Service as ServiceTrait;
 
 pub use fuel_core_consensus_module::RelayerVerifierConfig;
+pub use fuel_core_executor::Config as BlockExecutorConfig;
 
 use self::adapters::BlockImporterAdapter;
+#[cfg(feature = "alerting")]
+use self::alerting::AlertSink;
 
 pub mod adapters;
+#[cfg(feature = "alerting")]
+pub mod alerting;
+pub mod block_pruning;
+pub mod build_info;
+#[cfg(unix)]
+pub mod change_capture;
 pub mod config;
 pub mod genesis;
+pub mod inclusion_monitor;
 pub mod metrics;
+#[cfg(feature = "p2p")]
+pub mod pool_divergence;
+pub mod prewarm;
+pub mod registry;
+#[cfg(feature = "remote-signer")]
+pub mod remote_signer;
+pub mod runtime;
+pub mod slow_log;
 pub mod sub_services;
+pub mod utxo_stats;
+pub mod version_check;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -41,9 +70,16 @@ pub struct SharedState {
     pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
     /// The GraphQL shared state.
     pub graph_ql: crate::fuel_core_graphql_api::service::SharedState,
+    /// The health of every sub-service.
+    pub services_health: fuel_core_services::ServicesHealth,
+    /// The registry of sub-services.
+    pub registry: registry::ServiceRegistry,
     /// Subscribe to new block production.
     pub block_importer: BlockImporterAdapter,
     #[cfg(feature = "test-helpers")]
+    /// The block production of the service.
+    pub poa_adapter: adapters::PoAAdapter,
+    #[cfg(feature = "test-helpers")]
     /// The config of the service.
     pub config: Config,
 }
@@ -65,8 +101,12 @@ impl FuelService {
     /// Creates a `FuelService` instance from service config
     #[tracing::instrument(skip_all, fields(name = %config.name))]
     pub fn new(database: Database, mut config: Config) -> anyhow::Result<Self> {
+        Self::make_config_consistent(&mut config)?;
         database.init(&config.chain_conf)?;
-        Self::make_config_consistent(&mut config);
+        build_info::report(&config)?;
+        #[cfg(feature = "metrics")]
+        fuel_core_metrics::services_metrics::SERVICES_METRICS
+            .set_stall_threshold(config.service_stall_threshold);
         let task = Task::new(database, config)?;
         let runner = ServiceRunner::new(task);
         let shared = runner.shared.clone();
@@ -80,6 +120,13 @@ impl FuelService {
 
     /// Creates and starts fuel node instance from service config
     pub async fn new_node(config: Config) -> anyhow::Result<Self> {
+        #[cfg(feature = "fork")]
+        if let Some(fork) = config.fork.clone() {
+            let mut config = config;
+            let database = Self::forked_database(&fork, &mut config).await?;
+            return Self::from_database(database, config).await
+        }
+
         // initialize database
         let database = match config.database_type {
             #[cfg(feature = "rocksdb")]
@@ -102,6 +149,51 @@ impl FuelService {
         Self::from_database(database, config).await
     }
 
+    #[cfg(feature = "fork")]
+    /// Creates the in-memory database forked from the remote node and
+    /// starts the chain from the fork height. The remote node serves only its latest
+    /// state, so the fork height must be the latest height of the remote node.
+    async fn forked_database(
+        fork: &ForkConfig,
+        config: &mut Config,
+    ) -> anyhow::Result<Database> {
+        use crate::state::{
+            fork::{
+                graphql::GraphQLRemoteState,
+                ForkedStore,
+            },
+            in_memory::memory_store::MemoryStore,
+        };
+
+        // The fetched state is cached only in memory, so the forked node can't resume
+        // from the persistent database without fetching the removed keys again.
+        anyhow::ensure!(
+            config.database_type == DbType::InMemory,
+            "The forked node supports only the `{}` database, not `{}`",
+            DbType::InMemory,
+            config.database_type
+        );
+
+        let latest_height = GraphQLRemoteState::latest_height_of(&fork.url).await?;
+        let height = fork.height.unwrap_or(latest_height);
+        anyhow::ensure!(
+            height == latest_height,
+            "The remote node serves only the state at its latest height {}, the state at \
+            the fork height {} isn't available",
+            *latest_height,
+            *height
+        );
+        let remote = GraphQLRemoteState::new(&fork.url, height)?;
+
+        config
+            .chain_conf
+            .initial_state
+            .get_or_insert_with(Default::default)
+            .height = Some(height);
+        let store = ForkedStore::new(Arc::new(MemoryStore::default()), Arc::new(remote));
+        Ok(Database::new(Arc::new(store)))
+    }
+
     /// Creates and starts fuel node instance from service config and a pre-existing database
     pub async fn from_database(
         database: Database,
@@ -131,8 +223,19 @@ impl FuelService {
         Ok(())
     }
 
+    /// Returns the errors that stopped sub-services, e.g. panics of their tasks, along
+    /// with the names of sub-services. The node stops when any sub-service stops, so
+    /// the error of the sub-service explains why the node stopped.
+    pub fn sub_services_errors(&self) -> Vec<(&'static str, ServiceError)> {
+        self.shared.registry.errors()
+    }
+
     // TODO: Rework our configs system to avoid nesting of the same configs.
-    fn make_config_consistent(config: &mut Config) {
+    fn make_config_consistent(config: &mut Config) -> anyhow::Result<()> {
+        // The overrides are applied to both configs, so they are consistent if they
+        // were before.
+        config.chain_conf.apply_fee_overrides()?;
+        config.txpool.chain_config.apply_fee_overrides()?;
         if config.txpool.chain_config != config.chain_conf {
             warn!("The `ChainConfig` of `TxPool` was inconsistent");
             config.txpool.chain_config = config.chain_conf.clone();
@@ -145,6 +248,20 @@ impl FuelService {
             warn!("The `utxo_validation` of `BlockProducer` was inconsistent");
             config.block_producer.utxo_validation = config.utxo_validation;
         }
+        if let Some(pruning) = &config.block_pruning {
+            pruning.validate()?;
+        }
+        // Peers request from the node only blocks that it keeps.
+        #[cfg(feature = "p2p")]
+        if let Some(p2p) = &mut config.p2p {
+            p2p.capabilities.retention = match &config.block_pruning {
+                Some(pruning) => BlockRetention::Pruned {
+                    blocks: pruning.retention,
+                },
+                None => BlockRetention::Archive,
+            };
+        }
+        Ok(())
     }
 }
 
@@ -174,6 +291,14 @@ impl ServiceTrait for FuelService {
         self.runner.await_stop().await
     }
 
+    fn pause(&self) -> anyhow::Result<()> {
+        self.runner.pause()
+    }
+
+    fn resume(&self) -> anyhow::Result<()> {
+        self.runner.resume()
+    }
+
     fn state(&self) -> State {
         self.runner.state()
     }
@@ -181,13 +306,30 @@ impl ServiceTrait for FuelService {
     fn state_watcher(&self) -> StateWatcher {
         self.runner.state_watcher()
     }
-}
 
-pub type SubServices = Vec<Box<dyn ServiceTrait + Send + Sync + 'static>>;
+    fn error(&self) -> Option<ServiceError> {
+        self.runner.error()
+    }
+
+    fn validate_reload(&self, update: &ConfigUpdate) -> anyhow::Result<ConfigUpdate> {
+        self.runner.validate_reload(update)
+    }
+
+    fn reload(&self, update: &ConfigUpdate) {
+        self.runner.reload(update)
+    }
+}
 
 pub struct Task {
-    /// The list of started sub services.
-    services: SubServices,
+    /// The started sub services.
+    services: ServiceOrchestrator,
+    /// The runtime of CPU-heavy sub services. It is dropped after them.
+    _execution_runtime: Option<runtime::ExecutionRuntime>,
+    /// Notifies operators if any sub service stops with an error.
+    #[cfg(feature = "alerting")]
+    notifier: Option<alerting::Notifier>,
+    /// Loads the data into the caches of the database before sub-services start.
+    prewarm: Option<(Database, prewarm::Config)>,
     /// The address bound by the system for serving the API
     pub shared: SharedState,
 }
@@ -195,23 +337,89 @@ pub struct Task {
 impl Task {
     /// Private inner method for initializing the fuel service task
     pub fn new(database: Database, config: Config) -> anyhow::Result<Task> {
+        #[cfg(unix)]
+        let (database, change_capture) = match &config.change_capture {
+            Some(change_capture) => {
+                let feed = change_capture.feed();
+                let database = database.with_change_capture(feed.clone())?;
+                (database, Some(change_capture::new_service(change_capture, feed)))
+            }
+            None => (database, None),
+        };
+
+        let slow_log = config.slow_log.clone().map(slow_log::SlowLog::new);
+        let database = match &slow_log {
+            Some(slow_log) => database.with_slow_log(slow_log.clone()),
+            None => database,
+        };
+
+        if let Some(mismatch) = genesis::chain_mismatch(&config, &database)? {
+            if !config.force_chain_switch {
+                return Err(anyhow::anyhow!(
+                    "{mismatch}. Start the node with `--force-chain-switch` to wipe the \
+                    database and switch to the configured chain"
+                ))
+            }
+            warn!("{mismatch}. Wiping the database to switch to the configured chain");
+            database.wipe()?;
+            database.init(&config.chain_conf)?;
+        }
+
         // initialize state
         genesis::maybe_initialize_state(&config, &database)?;
 
+        let execution_runtime = config
+            .execution_threads
+            .map(runtime::ExecutionRuntime::new)
+            .transpose()?;
+
+        let prewarm = config
+            .prewarm
+            .clone()
+            .map(|prewarm| (database.clone(), prewarm));
+
         // initialize sub services
-        let (services, shared) = sub_services::init_sub_services(&config, &database)?;
-        Ok(Task { services, shared })
+        #[allow(unused_mut)]
+        let (mut services, shared) = sub_services::init_sub_services(
+            &config,
+            &database,
+            execution_runtime.as_ref().map(|runtime| runtime.handle()),
+            slow_log,
+        )?;
+        #[cfg(unix)]
+        if let Some(change_capture) = change_capture {
+            services.add(
+                "change_capture",
+                shared.registry.register("change_capture", change_capture)?,
+                &[],
+            )?;
+        }
+        #[cfg(feature = "alerting")]
+        let notifier = config
+            .alerting
+            .as_ref()
+            .map(|alerting| alerting::Notifier::new(config.name.clone(), alerting));
+        Ok(Task {
+            services,
+            _execution_runtime: execution_runtime,
+            #[cfg(feature = "alerting")]
+            notifier,
+            prewarm,
+            shared,
+        })
     }
 
     #[cfg(test)]
-    pub fn sub_services(&mut self) -> &mut SubServices {
-        &mut self.services
+    pub fn sub_services(&mut self) -> &[fuel_core_services::BoxedService] {
+        self.services.services()
     }
 }
 
 #[async_trait::async_trait]
 impl RunnableService for Task {
     const NAME: &'static str = "FuelService";
+    // The node is ready when all sub-services are ready.
+    const READY_ON_START: bool = false;
     type SharedData = SharedState;
     type Task = Task;
     type TaskParams = ();
@@ -220,14 +428,49 @@ impl RunnableService for Task {
         self.shared.clone()
     }
 
+    fn validate_reload(
+        shared: &Self::SharedData,
+        update: &ConfigUpdate,
+    ) -> anyhow::Result<ConfigUpdate> {
+        shared.registry.validate_reload(update)?;
+        Ok(update.clone())
+    }
+
+    fn reload(shared: &Self::SharedData, update: &ConfigUpdate) {
+        shared.registry.apply_reload(update)
+    }
+
     async fn into_task(
-        self,
-        _: &StateWatcher,
+        mut self,
+        watcher: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
-        for service in &self.services {
-            service.start_and_await().await?;
+        // The API is served by one of sub-services, so the caches are warm before it
+        // accepts the first request.
+        if let Some((database, config)) = self.prewarm.take() {
+            let started = std::time::Instant::now();
+            let result = tokio::task::spawn_blocking(move || {
+                prewarm::prewarm(&database, &config)
+            })
+            .await?;
+            match result {
+                Ok(stats) => tracing::info!(
+                    "Pre-warmed the database in {:?}: {:?}",
+                    started.elapsed(),
+                    stats
+                ),
+                Err(e) => warn!("Failed to pre-warm the database: {}", e),
+            }
         }
+        self.services.start_and_await().await?;
+        let ready = watcher.ready_signal();
+        let registry = self.shared.registry.clone();
+        tokio::spawn(async move {
+            match registry.await_ready().await {
+                Ok(()) => ready.ready(),
+                Err(e) => warn!("The node didn't become ready: {}", e),
+            }
+        });
         Ok(self)
     }
 }
@@ -236,16 +479,24 @@ impl RunnableService for Task {
 impl RunnableTask for Task {
     #[tracing::instrument(skip_all)]
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
-        let mut stop_signals = vec![];
-        for service in &self.services {
-            stop_signals.push(service.await_stop())
-        }
-        stop_signals.push(Box::pin(watcher.while_started()));
-
-        let (result, _, _) = futures::future::select_all(stop_signals).await;
+        let result = tokio::select! {
+            result = self.services.await_any_stop() => result.map(|(_, state)| state),
+            result = watcher.while_started() => result,
+        };
 
-        if let Err(err) = result {
-            tracing::error!("Got an error during listen for shutdown: {}", err);
+        match result {
+            #[cfg(feature = "alerting")]
+            Ok(State::StoppedWithError(error)) => {
+                if let Some(notifier) = &self.notifier {
+                    notifier
+                        .send(&alerting::Alert::ServiceStopped { error })
+                        .await;
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                tracing::error!("Got an error during listen for shutdown: {}", err);
+            }
         }
 
         // We received the stop signal from any of one source, so stop this service and
@@ -255,16 +506,7 @@ impl RunnableTask for Task {
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        for service in self.services {
-            let result = service.stop_and_await().await;
-
-            if let Err(err) = result {
-                tracing::error!(
-                    "Got and error during awaiting for stop of the service: {}",
-                    err
-                );
-            }
-        }
+        self.services.stop_and_await().await;
         Ok(())
     }
 }
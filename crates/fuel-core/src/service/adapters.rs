@@ -3,19 +3,26 @@ use crate::{
     service::Config,
 };
 use fuel_core_consensus_module::block_verifier::Verifier;
-use fuel_core_txpool::service::SharedState as TxPoolSharedState;
+use fuel_core_txpool::{
+    service::SharedState as TxPoolSharedState,
+    TxTimeline,
+};
 use std::sync::Arc;
 
+#[cfg(feature = "alerting")]
+pub mod alerting;
 pub mod block_importer;
 pub mod consensus_module;
 pub mod executor;
 pub mod graphql_api;
+pub mod inclusion_monitor;
 #[cfg(feature = "p2p")]
 pub mod p2p;
 pub mod producer;
 #[cfg(feature = "p2p")]
 pub mod sync;
 pub mod txpool;
+pub mod utxo_stats;
 
 #[derive(Clone)]
 pub struct PoAAdapter {
@@ -37,6 +44,8 @@ impl TxPoolAdapter {
 pub struct ExecutorAdapter {
     pub relayer: MaybeRelayerAdapter,
     pub config: Config,
+    /// Records the execution of transactions during the block production.
+    pub tx_timeline: TxTimeline,
 }
 
 #[derive(Clone)]
@@ -53,17 +62,61 @@ pub struct MaybeRelayerAdapter {
     pub da_deploy_height: fuel_core_types::blockchain::primitives::DaBlockHeight,
 }
 
+#[derive(Clone)]
+pub struct InclusionMonitorAdapter {
+    pub service: Option<crate::service::inclusion_monitor::SharedState>,
+}
+
+#[derive(Clone)]
+pub struct UtxoStatisticsAdapter {
+    pub service: Option<crate::service::utxo_stats::SharedState>,
+}
+
 #[derive(Clone)]
 pub struct BlockProducerAdapter {
     pub block_producer: Arc<fuel_core_producer::Producer<Database>>,
 }
 
+#[cfg(feature = "alerting")]
+#[derive(Clone)]
+pub struct AlertingAdapter {
+    pub database: Database,
+    /// The path to the database on the disk, if any.
+    pub database_path: Option<std::path::PathBuf>,
+    #[cfg(feature = "relayer")]
+    pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
+    #[cfg(feature = "p2p")]
+    pub network: Option<fuel_core_p2p::service::SharedState>,
+}
+
+#[derive(Clone)]
+pub struct NodeStatusAdapter {
+    pub database: Database,
+    pub services_health: fuel_core_services::ServicesHealth,
+    #[cfg(feature = "relayer")]
+    pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
+    #[cfg(feature = "p2p")]
+    pub network: Option<fuel_core_p2p::service::SharedState>,
+    #[cfg(feature = "p2p")]
+    pub sync: Option<fuel_core_sync::service::SharedState>,
+    #[cfg(feature = "version-check")]
+    pub version_check: Option<crate::service::version_check::SharedState>,
+}
+
 #[derive(Clone)]
 pub struct BlockImporterAdapter {
     pub block_importer:
         Arc<fuel_core_importer::Importer<Database, ExecutorAdapter, VerifierAdapter>>,
 }
 
+/// Imports blocks submitted via the GraphQL API. Unlike blocks from the network, their
+/// seal is not verified by the synchronizer, so the adapter verifies it itself.
+#[derive(Clone)]
+pub struct SubmittedBlockAdapter {
+    pub block_importer: BlockImporterAdapter,
+    pub block_verifier: VerifierAdapter,
+}
+
 #[cfg(feature = "p2p")]
 #[derive(Clone)]
 pub struct P2PAdapter {
@@ -0,0 +1,79 @@
+use super::AlertingAdapter;
+use crate::service::alerting::NodeStatus;
+use fuel_core_types::fuel_types::BlockHeight;
+
+#[async_trait::async_trait]
+impl NodeStatus for AlertingAdapter {
+    fn latest_block_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.database.latest_height()?)
+    }
+
+    fn relayer_lag(&self) -> anyhow::Result<Option<u64>> {
+        #[cfg(feature = "relayer")]
+        {
+            match &self.relayer {
+                Some(relayer) => relayer.da_lag(),
+                None => Ok(None),
+            }
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(None)
+        }
+    }
+
+    fn available_disk_space(&self) -> anyhow::Result<Option<u64>> {
+        match &self.database_path {
+            #[cfg(unix)]
+            Some(path) => Ok(Some(available_space(path)?)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn peer_count(&self) -> anyhow::Result<Option<usize>> {
+        #[cfg(feature = "p2p")]
+        {
+            match &self.network {
+                Some(network) => Ok(Some(network.get_peer_ids().await?.len())),
+                None => Ok(None),
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(None)
+        }
+    }
+
+    fn wallet_balance(&self) -> anyhow::Result<Option<u64>> {
+        #[cfg(feature = "relayer")]
+        {
+            Ok(self
+                .relayer
+                .as_ref()
+                .and_then(|relayer| relayer.wallet_balance()))
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(None)
+        }
+    }
+}
+
+/// Returns the number of bytes available to unprivileged users on the file system of the `path`.
+#[cfg(unix)]
+fn available_space(path: &std::path::Path) -> std::io::Result<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes())?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: The `path` is a valid C string, and the `stat` is a valid pointer to the `statvfs`.
+    let result = unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error())
+    }
+    // SAFETY: The `statvfs` initialized the `stat` on success.
+    let stat = unsafe { stat.assume_init() };
+    #[allow(clippy::unnecessary_cast)]
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
@@ -14,16 +14,26 @@ use fuel_core_consensus_module::block_verifier::{
 };
 use fuel_core_producer::ports::BlockProducerDatabase;
 use fuel_core_storage::{
-    tables::FuelBlocks,
+    tables::{
+        FuelBlocks,
+        Receipts,
+    },
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::{
     blockchain::header::BlockHeader,
-    fuel_tx::Bytes32,
+    fuel_tx::{
+        Bytes32,
+        Receipt,
+        TxId,
+    },
     fuel_types::BlockHeight,
 };
-use std::sync::Arc;
+use std::{
+    borrow::Cow,
+    sync::Arc,
+};
 
 pub mod poa;
 
@@ -52,4 +62,9 @@ impl fuel_core_poa::ports::Database for Database {
     fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32> {
         self.storage::<FuelBlocks>().root(height).map(Into::into)
     }
+
+    fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>> {
+        let receipts = self.storage::<Receipts>().get(tx_id)?;
+        Ok(receipts.map(Cow::into_owned).unwrap_or_default())
+    }
 }
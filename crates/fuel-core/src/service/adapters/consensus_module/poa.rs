@@ -4,6 +4,7 @@ use crate::{
     service::adapters::{
         BlockImporterAdapter,
         BlockProducerAdapter,
+        P2PAdapter,
         PoAAdapter,
         TxPoolAdapter,
     },
@@ -12,19 +13,33 @@ use anyhow::anyhow;
 use fuel_core_poa::{
     ports::{
         BlockImporter,
+        PeerToPeerPort,
         TransactionPool,
     },
-    service::SharedState,
+    service::{
+        ManualGasLimit,
+        ProducedBlock,
+        SharedState,
+    },
+    Trigger,
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
+    blockchain::{
+        consensus::poa::PreConfirmation,
+        header::BlockHeader,
+        primitives::DaBlockHeight,
+    },
     fuel_asm::Word,
     fuel_tx::TxId,
     fuel_types::BlockHeight,
     services::{
         block_importer::UncommittedResult as UncommittedImporterResult,
-        executor::UncommittedResult,
+        executor::{
+            Error as ExecutorError,
+            UncommittedResult,
+        },
         txpool::{
             ArcPoolTx,
             TxStatus,
@@ -32,6 +47,7 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use std::time::Duration;
 
 impl PoAAdapter {
     pub fn new(shared_state: Option<SharedState>) -> Self {
@@ -45,11 +61,44 @@ impl ConsensusModulePort for PoAAdapter {
         &self,
         start_time: Option<Tai64>,
         number_of_blocks: u32,
-    ) -> anyhow::Result<()> {
+        gas_limit: ManualGasLimit,
+        da_height: Option<DaBlockHeight>,
+    ) -> anyhow::Result<Vec<ProducedBlock>> {
         self.shared_state
             .as_ref()
             .ok_or(anyhow!("The block production is disabled"))?
-            .manually_produce_block(start_time, number_of_blocks)
+            .manually_produce_block(start_time, number_of_blocks, gas_limit, da_height)
+            .await
+    }
+
+    fn pre_confirmations(&self) -> anyhow::Result<BoxStream<PreConfirmation>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        let receiver = self
+            .shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .pre_confirmations();
+        Ok(Box::pin(
+            BroadcastStream::new(receiver).filter_map(|result| result.ok()),
+        ))
+    }
+
+    async fn set_trigger(&self, trigger: Trigger) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .set_trigger(trigger)
+            .await
+    }
+
+    async fn set_paused(&self, paused: bool) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .set_paused(paused)
             .await
     }
 }
@@ -63,8 +112,8 @@ impl TransactionPool for TxPoolAdapter {
         self.service.total_consumable_gas()
     }
 
-    fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
-        self.service.remove_txs(ids)
+    fn remove_txs(&self, ids: Vec<(TxId, ExecutorError)>) -> Vec<ArcPoolTx> {
+        self.service.remove_skipped_txs(ids)
     }
 
     fn transaction_status_events(&self) -> BoxStream<TxStatus> {
@@ -88,11 +137,42 @@ impl fuel_core_poa::ports::BlockProducer for BlockProducerAdapter {
         height: BlockHeight,
         block_time: Tai64,
         max_gas: Word,
+        da_height: Option<DaBlockHeight>,
     ) -> anyhow::Result<UncommittedResult<StorageTransaction<Database>>> {
         self.block_producer
-            .produce_and_execute_block(height, block_time, max_gas)
+            .produce_and_execute_block(height, block_time, max_gas, da_height)
+            .await
+    }
+
+    async fn produce_and_execute_block_within(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+        da_height: Option<DaBlockHeight>,
+        budget: Duration,
+    ) -> anyhow::Result<Option<UncommittedResult<StorageTransaction<Database>>>> {
+        self.block_producer
+            .produce_and_execute_block_within(
+                height, block_time, max_gas, da_height, budget,
+            )
             .await
     }
+
+    fn speculate_next_block(
+        &self,
+        parent: &StorageTransaction<Database>,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<()> {
+        self.block_producer.speculate_next_block(
+            parent.as_ref(),
+            height,
+            block_time,
+            max_gas,
+        )
+    }
 }
 
 impl BlockImporter for BlockImporterAdapter {
@@ -106,4 +186,53 @@ impl BlockImporter for BlockImporterAdapter {
             .commit_result(result)
             .map_err(Into::into)
     }
+
+    fn record_gas_limit(
+        &self,
+        transaction: &mut StorageTransaction<Self::Database>,
+        gas_limit: Word,
+    ) -> anyhow::Result<()> {
+        transaction
+            .as_mut()
+            .set_block_gas_limit(gas_limit)
+            .map_err(Into::into)
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockHeader> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(|result| result.sealed_block.entity.header().clone()),
+        )
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl PeerToPeerPort for P2PAdapter {
+    fn connected_peers(&self) -> usize {
+        self.service
+            .as_ref()
+            .map_or(0, |service| service.connected_peers_count())
+    }
+
+    fn highest_peer_height(&self) -> Option<BlockHeight> {
+        self.service
+            .as_ref()
+            .and_then(|service| service.highest_peer_height())
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+impl PeerToPeerPort for P2PAdapter {
+    fn connected_peers(&self) -> usize {
+        0
+    }
+
+    fn highest_peer_height(&self) -> Option<BlockHeight> {
+        None
+    }
 }
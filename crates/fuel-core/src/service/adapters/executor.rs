@@ -19,6 +19,7 @@ use fuel_core_types::{
         UncommittedResult,
     },
 };
+use std::sync::atomic::AtomicBool;
 
 use super::MaybeRelayerAdapter;
 
@@ -35,6 +36,33 @@ impl ExecutorAdapter {
         executor.execute_without_commit(block)
     }
 
+    pub(crate) fn _execute_cancellable(
+        &self,
+        block: ExecutionBlock,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.execute_cancellable(block, cancel)
+    }
+
+    pub(crate) fn _execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        parent: &Database,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.execute_speculatively(block, parent, cancel)
+    }
+
     pub(crate) fn _dry_run(
         &self,
         block: ExecutionBlock,
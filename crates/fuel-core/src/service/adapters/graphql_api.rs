@@ -4,6 +4,7 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
         DatabaseBlocks,
         DatabaseChain,
@@ -14,12 +15,24 @@ use crate::{
         DatabasePort,
         DatabaseTransactions,
         DryRunExecution,
+        NodeStatusPort,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::{
+        adapters::{
+            NodeStatusAdapter,
+            SubmittedBlockAdapter,
+            TxPoolAdapter,
+        },
+        version_check::VersionAdvisory,
+    },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_producer::block_producer::BlockTemplate;
+use fuel_core_services::{
+    stream::BoxStream,
+    Health,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -31,16 +44,24 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_txpool::{
-    service::TxUpdate,
+    service::{
+        PoolEvent,
+        TxUpdate,
+    },
+    timeline::TxStageTime,
     types::{
         ContractId,
         TxId,
     },
 };
 use fuel_core_types::{
-    blockchain::primitives::{
-        BlockId,
-        DaBlockHeight,
+    blockchain::{
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
+        SealedBlock,
+        SealedBlockHeader,
     },
     entities::message::{
         MerkleProof,
@@ -62,6 +83,7 @@ use fuel_core_types::{
         graphql_api::ContractBalance,
         txpool::{
             InsertionResult,
+            SkipReason,
             TransactionStatus,
         },
     },
@@ -98,6 +120,25 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockId"))??)
     }
+
+    fn block_may_involve(
+        &self,
+        height: &BlockHeight,
+        id: &[u8; 32],
+    ) -> StorageResult<bool> {
+        Database::block_may_involve(self, height, id)
+    }
+
+    fn is_block_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Database::is_block_pruned(self, height)
+    }
+
+    fn block_executor_version(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<u32>> {
+        Database::block_executor_version(self, height)
+    }
 }
 
 impl DatabaseTransactions for Database {
@@ -218,8 +259,21 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
-    fn insert(&self, txs: Vec<Arc<Transaction>>) -> Vec<anyhow::Result<InsertionResult>> {
-        self.service.insert(txs)
+    fn skipped_reason(&self, id: TxId) -> Option<SkipReason> {
+        self.service.skipped_reason(&id)
+    }
+
+    fn timeline(&self, id: TxId) -> Option<Vec<TxStageTime>> {
+        self.service.tx_timeline().get(&id)
+    }
+
+    fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.service
+            .insert_with_utxo_validation(txs, utxo_validation)
     }
 
     fn tx_update_subscribe(
@@ -227,6 +281,76 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> BoxStream<Result<TxUpdate, BroadcastStreamRecvError>> {
         Box::pin(BroadcastStream::new(self.service.tx_update_subscribe()))
     }
+
+    fn pool_events_subscribe(
+        &self,
+    ) -> BoxStream<Result<PoolEvent, BroadcastStreamRecvError>> {
+        Box::pin(BroadcastStream::new(self.service.pool_events_subscribe()))
+    }
+
+    fn min_gas_price(&self) -> u64 {
+        self.service.min_gas_price()
+    }
+}
+
+impl NodeStatusPort for NodeStatusAdapter {
+    fn latest_height(&self) -> StorageResult<BlockHeight> {
+        self.database.latest_height()
+    }
+
+    fn is_synced(&self) -> Option<bool> {
+        #[cfg(feature = "p2p")]
+        {
+            self.sync.as_ref().map(|sync| sync.is_synced())
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            None
+        }
+    }
+
+    fn peer_count(&self) -> Option<usize> {
+        #[cfg(feature = "p2p")]
+        {
+            self.network
+                .as_ref()
+                .map(|network| network.connected_peers_count())
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            None
+        }
+    }
+
+    fn relayer_wallet_balance(&self) -> Option<u64> {
+        #[cfg(feature = "relayer")]
+        {
+            self.relayer
+                .as_ref()
+                .and_then(|relayer| relayer.wallet_balance())
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            None
+        }
+    }
+
+    fn services_health(&self) -> Vec<(&'static str, Health)> {
+        self.services_health.report()
+    }
+
+    fn version_advisory(&self) -> Option<VersionAdvisory> {
+        #[cfg(feature = "version-check")]
+        {
+            self.version_check
+                .as_ref()
+                .and_then(|version_check| version_check.latest())
+        }
+        #[cfg(not(feature = "version-check"))]
+        {
+            None
+        }
+    }
 }
 
 impl DatabaseMessageProof for Database {
@@ -253,6 +377,33 @@ impl DryRunExecution for BlockProducerAdapter {
     }
 }
 
-impl BlockProducerPort for BlockProducerAdapter {}
+#[async_trait]
+impl BlockProducerPort for BlockProducerAdapter {
+    async fn block_template(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: u64,
+    ) -> anyhow::Result<BlockTemplate> {
+        self.block_producer
+            .block_template(height, block_time, max_gas)
+            .await
+    }
+}
+
+#[async_trait]
+impl BlockImporterPort for SubmittedBlockAdapter {
+    async fn import_block(&self, block: SealedBlock) -> anyhow::Result<()> {
+        let header = SealedBlockHeader {
+            entity: block.entity.header().clone(),
+            consensus: block.consensus.clone(),
+        };
+        anyhow::ensure!(
+            self.block_verifier.block_verifier.verify_consensus(&header),
+            "The block is not sealed by the authority of its height"
+        );
+        self.block_importer.execute_and_commit(block).await
+    }
+}
 
 use super::BlockProducerAdapter;
@@ -0,0 +1,40 @@
+use super::{
+    InclusionMonitorAdapter,
+    TxPoolAdapter,
+};
+use crate::{
+    fuel_core_graphql_api::ports::InclusionMonitorPort,
+    service::inclusion_monitor::{
+        InclusionStatistics,
+        PoolEntry,
+        PoolTransactions,
+        TxInclusion,
+    },
+};
+use fuel_core_types::fuel_tx::TxId;
+
+impl InclusionMonitorPort for InclusionMonitorAdapter {
+    fn inclusion_statistics(&self) -> Option<InclusionStatistics> {
+        self.service.as_ref().map(|service| service.statistics())
+    }
+
+    fn transaction_inclusion(&self, tx_id: &TxId) -> Option<TxInclusion> {
+        self.service
+            .as_ref()
+            .and_then(|service| service.transaction(tx_id))
+    }
+}
+
+impl PoolTransactions for TxPoolAdapter {
+    fn pool_transactions(&self) -> Vec<PoolEntry> {
+        self.service
+            .pending_txs()
+            .into_iter()
+            .map(|info| PoolEntry {
+                tx_id: info.id(),
+                gas_price: info.price(),
+                inserted_at: info.created().into_std(),
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,15 @@
-use super::BlockImporterAdapter;
-use crate::database::Database;
+use super::{
+    BlockImporterAdapter,
+    P2PAdapter,
+    TxPoolAdapter,
+};
+use crate::{
+    database::Database,
+    service::pool_divergence::{
+        PendingTransactions,
+        PoolSketchNetwork,
+    },
+};
 use fuel_core_p2p::ports::{
     BlockHeightImporter,
     P2pDb,
@@ -12,8 +22,12 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
+    services::p2p::PoolSketch,
 };
 
 impl P2pDb for Database {
@@ -52,3 +66,38 @@ impl BlockHeightImporter for BlockImporterAdapter {
         )
     }
 }
+
+impl PendingTransactions for TxPoolAdapter {
+    fn pending_ids(&self) -> Vec<TxId> {
+        self.service.pending_ids()
+    }
+}
+
+#[async_trait::async_trait]
+impl PoolSketchNetwork for P2PAdapter {
+    fn update_pool_sketch(&self, sketch: PoolSketch) {
+        if let Some(service) = &self.service {
+            service.update_pool_sketch(sketch);
+        }
+    }
+
+    async fn peer_ids(&self) -> anyhow::Result<Vec<Vec<u8>>> {
+        if let Some(service) = &self.service {
+            let peer_ids = service.get_peer_ids().await?;
+            Ok(peer_ids.into_iter().map(|peer_id| peer_id.to_bytes()).collect())
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn pool_sketch_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Option<PoolSketch>> {
+        if let Some(service) = &self.service {
+            service.get_pool_sketch_from_peer(peer_id).await
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -7,7 +7,14 @@ use crate::{
         TxPoolAdapter,
     },
 };
-use fuel_core_producer::ports::TxPool;
+use fuel_core_producer::{
+    ports::TxPool,
+    tx_selector::{
+        BlockLimits,
+        TxSelector,
+    },
+};
+use fuel_core_txpool::TxStage;
 use fuel_core_storage::{
     not_found,
     tables::FuelBlocks,
@@ -36,7 +43,10 @@ use fuel_core_types::{
 };
 use std::{
     borrow::Cow,
-    sync::Arc,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
 };
 
 impl BlockProducerAdapter {
@@ -51,10 +61,24 @@ impl BlockProducerAdapter {
 impl TxPool for TxPoolAdapter {
     fn get_includable_txs(
         &self,
-        _block_height: BlockHeight,
-        max_gas: u64,
+        block_height: BlockHeight,
+        limits: BlockLimits,
+        selector: &dyn TxSelector,
+    ) -> Vec<ArcPoolTx> {
+        self.service
+            .select_transactions_with(block_height, |candidates| {
+                selector.select(candidates, limits)
+            })
+    }
+
+    fn peek_includable_txs(
+        &self,
+        block_height: BlockHeight,
+        limits: BlockLimits,
+        selector: &dyn TxSelector,
     ) -> Vec<ArcPoolTx> {
-        self.service.select_transactions(max_gas)
+        let candidates = self.service.includable_transactions(block_height);
+        selector.select(candidates, limits)
     }
 }
 
@@ -63,8 +87,26 @@ impl fuel_core_producer::ports::Executor<Database> for ExecutorAdapter {
     fn execute_without_commit(
         &self,
         block: ExecutionBlock,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let result = self._execute_cancellable(block, cancel)?;
+        for status in result.result().tx_status.iter() {
+            self.tx_timeline.record(status.id, TxStage::Executed);
+        }
+        Ok(result)
+    }
+
+    fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        parent: &Database,
+        cancel: &AtomicBool,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
-        self._execute_without_commit(block)
+        let result = self._execute_speculatively(block, parent, cancel)?;
+        for status in result.result().tx_status.iter() {
+            self.tx_timeline.record(status.id, TxStage::Executed);
+        }
+        Ok(result)
     }
 
     fn dry_run(
@@ -118,4 +160,8 @@ impl fuel_core_producer::ports::BlockProducerDatabase for Database {
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         self.latest_height()
     }
+
+    fn fork(&self) -> StorageResult<Self> {
+        Ok(Database::fork(self)?)
+    }
 }
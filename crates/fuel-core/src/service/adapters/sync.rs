@@ -7,6 +7,8 @@ use fuel_core_services::stream::BoxStream;
 use fuel_core_sync::ports::{
     BlockImporterPort,
     ConsensusPort,
+    ImportError,
+    PeerReportReason,
     PeerToPeerPort,
 };
 use fuel_core_types::{
@@ -20,7 +22,11 @@ use fuel_core_types::{
     },
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
-    services::p2p::SourcePeer,
+    services::p2p::{
+        peer_reputation::NegativePeerReport,
+        PeerId,
+        SourcePeer,
+    },
 };
 
 #[async_trait::async_trait]
@@ -72,6 +78,19 @@ impl PeerToPeerPort for P2PAdapter {
             Ok(None)
         }
     }
+
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            let report = match report {
+                // The block can't be imported, so the peer is banned.
+                PeerReportReason::InvalidBlock => NegativePeerReport::Fatal,
+                PeerReportReason::InvalidTransactions => NegativePeerReport::Major,
+            };
+            service.report_peer(peer, report, "sync")
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -87,8 +106,17 @@ impl BlockImporterPort for BlockImporterAdapter {
                 }),
         )
     }
-    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
-        self.execute_and_commit(block).await
+    async fn execute_and_commit(&self, block: SealedBlock) -> Result<(), ImportError> {
+        self.execute_and_commit(block).await.map_err(|err| {
+            let invalid_block = err
+                .downcast_ref::<fuel_core_importer::Error>()
+                .map_or(false, |err| err.is_invalid_block());
+            if invalid_block {
+                ImportError::InvalidBlock(err)
+            } else {
+                ImportError::Local(err)
+            }
+        })
     }
 }
 
@@ -0,0 +1,36 @@
+use super::UtxoStatisticsAdapter;
+use crate::{
+    database::Database,
+    fuel_core_graphql_api::ports::UtxoStatisticsPort,
+    service::utxo_stats::{
+        UtxoSetStatistics,
+        UtxoSource,
+    },
+};
+use fuel_core_storage::iter::{
+    BoxedIter,
+    IntoBoxedIter,
+};
+use fuel_core_types::{
+    entities::coins::coin::CompressedCoin,
+    fuel_types::BlockHeight,
+};
+use std::sync::Arc;
+
+impl UtxoSource for Database {
+    fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+        Database::latest_height(self).map_err(Into::into)
+    }
+
+    fn coins(&self) -> BoxedIter<'_, anyhow::Result<CompressedCoin>> {
+        self.all_coins()
+            .map(|coin| coin.map_err(Into::into))
+            .into_boxed()
+    }
+}
+
+impl UtxoStatisticsPort for UtxoStatisticsAdapter {
+    fn utxo_statistics(&self) -> Option<Arc<UtxoSetStatistics>> {
+        self.service.as_ref().and_then(|service| service.latest())
+    }
+}
@@ -0,0 +1,518 @@
+//! The alerting service periodically checks critical conditions of the node and
+//! notifies operators via webhooks. It covers operators without a Prometheus stack,
+//! who don't see alerts based on metrics.
+
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
+use tokio::time::{
+    Instant,
+    MissedTickBehavior,
+};
+
+pub type Service<Status, Sink> = ServiceRunner<Task<Status, Sink>>;
+
+/// The endpoint of the PagerDuty Events API v2.
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// How often to check conditions.
+    pub interval: Duration,
+    /// URLs that receive alerts as JSON via the `POST` request.
+    pub webhooks: Vec<String>,
+    /// The routing key of the PagerDuty Events API integration.
+    pub pagerduty_routing_key: Option<String>,
+    /// Alerts if the block height doesn't change for this duration.
+    pub block_production_stall: Option<Duration>,
+    /// Alerts if the relayer is behind the DA layer by more than this number of blocks.
+    pub max_relayer_lag: Option<u64>,
+    /// Alerts if the free space on the disk of the database is less than this number of bytes.
+    pub min_free_disk_space: Option<u64>,
+    /// Alerts if the number of connected peers is less than this number.
+    pub min_peers: Option<usize>,
+    /// Alerts if the balance of the L1 wallet monitored by the relayer is less than
+    /// this number of gwei.
+    pub min_wallet_balance: Option<u64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(10),
+            webhooks: vec![],
+            pagerduty_routing_key: None,
+            block_production_stall: None,
+            max_relayer_lag: None,
+            min_free_disk_space: None,
+            min_peers: None,
+            min_wallet_balance: None,
+        }
+    }
+}
+
+/// The critical condition of the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Alert {
+    /// The service stopped with an error, and the node is shutting down.
+    ServiceStopped { error: String },
+    /// The block height didn't change for the `duration`.
+    BlockProductionStalled {
+        height: BlockHeight,
+        duration: Duration,
+    },
+    /// The relayer is behind the DA layer.
+    RelayerBehind { blocks: u64 },
+    /// The free space on the disk of the database is low.
+    LowDiskSpace { available: u64 },
+    /// The number of connected peers is low.
+    LowPeerCount { peers: usize },
+    /// The balance of the L1 wallet is low, and commitments may stop soon.
+    LowWalletBalance { gwei: u64 },
+}
+
+impl Alert {
+    /// The kind of the alert. Only one alert of each kind is active at a time.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Alert::ServiceStopped { .. } => "service_stopped",
+            Alert::BlockProductionStalled { .. } => "block_production_stalled",
+            Alert::RelayerBehind { .. } => "relayer_behind",
+            Alert::LowDiskSpace { .. } => "low_disk_space",
+            Alert::LowPeerCount { .. } => "low_peer_count",
+            Alert::LowWalletBalance { .. } => "low_wallet_balance",
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        match self {
+            Alert::ServiceStopped { error } => {
+                format!("The service stopped with an error: {}", error)
+            }
+            Alert::BlockProductionStalled { height, duration } => format!(
+                "The block height {} didn't change for {}s",
+                **height,
+                duration.as_secs()
+            ),
+            Alert::RelayerBehind { blocks } => {
+                format!("The relayer is behind the DA layer by {} blocks", blocks)
+            }
+            Alert::LowDiskSpace { available } => {
+                format!(
+                    "Only {} bytes are free on the disk of the database",
+                    available
+                )
+            }
+            Alert::LowPeerCount { peers } => {
+                format!("Only {} peers are connected", peers)
+            }
+            Alert::LowWalletBalance { gwei } => {
+                format!("Only {} gwei are left on the L1 wallet", gwei)
+            }
+        }
+    }
+}
+
+/// The source of conditions of the node.
+#[async_trait::async_trait]
+pub trait NodeStatus: Send + Sync {
+    fn latest_block_height(&self) -> anyhow::Result<BlockHeight>;
+
+    /// Returns `None` if the relayer is disabled or didn't reach the DA layer yet.
+    fn relayer_lag(&self) -> anyhow::Result<Option<u64>>;
+
+    /// Returns `None` if the database is not stored on the disk.
+    fn available_disk_space(&self) -> anyhow::Result<Option<u64>>;
+
+    /// Returns `None` if the P2P is disabled.
+    async fn peer_count(&self) -> anyhow::Result<Option<usize>>;
+
+    /// Returns `None` if no wallet is monitored or the balance is not known yet.
+    fn wallet_balance(&self) -> anyhow::Result<Option<u64>>;
+}
+
+/// The receiver of alerts.
+#[async_trait::async_trait]
+pub trait AlertSink: Send + Sync {
+    async fn send(&self, alert: &Alert);
+}
+
+/// Sends alerts to webhooks and PagerDuty.
+#[derive(Clone)]
+pub struct Notifier {
+    client: reqwest::Client,
+    /// The name of the node used as the source of alerts.
+    node: String,
+    webhooks: Vec<String>,
+    pagerduty_routing_key: Option<String>,
+}
+
+impl Notifier {
+    pub fn new(node: String, config: &Config) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            node,
+            webhooks: config.webhooks.clone(),
+            pagerduty_routing_key: config.pagerduty_routing_key.clone(),
+        }
+    }
+
+    async fn post(&self, url: &str, body: serde_json::Value) -> anyhow::Result<()> {
+        self.client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AlertSink for Notifier {
+    async fn send(&self, alert: &Alert) {
+        tracing::warn!("Alert `{}`: {}", alert.kind(), alert.summary());
+
+        for url in self.webhooks.iter() {
+            let body = serde_json::json!({
+                "node": self.node,
+                "alert": alert.kind(),
+                "summary": alert.summary(),
+            });
+            if let Err(e) = self.post(url, body).await {
+                tracing::error!("Failed to send the alert to the webhook {}: {}", url, e);
+            }
+        }
+
+        if let Some(routing_key) = &self.pagerduty_routing_key {
+            let body = serde_json::json!({
+                "routing_key": routing_key,
+                "event_action": "trigger",
+                "dedup_key": format!("{}-{}", self.node, alert.kind()),
+                "payload": {
+                    "summary": alert.summary(),
+                    "source": self.node,
+                    "severity": "critical",
+                },
+            });
+            if let Err(e) = self.post(PAGERDUTY_EVENTS_URL, body).await {
+                tracing::error!("Failed to send the alert to PagerDuty: {}", e);
+            }
+        }
+    }
+}
+
+pub struct Task<Status, Sink> {
+    config: Config,
+    status: Status,
+    sink: Sink,
+    timer: tokio::time::Interval,
+    /// The last seen block height and the time when it was seen first.
+    last_height: Option<(BlockHeight, Instant)>,
+    /// Kinds of alerts that are already sent and whose conditions still hold.
+    active: HashSet<&'static str>,
+}
+
+impl<Status, Sink> Task<Status, Sink>
+where
+    Status: NodeStatus,
+    Sink: AlertSink,
+{
+    /// Returns alerts for all conditions that hold now.
+    async fn conditions(&mut self) -> anyhow::Result<Vec<Alert>> {
+        let mut alerts = vec![];
+
+        if let Some(stall) = self.config.block_production_stall {
+            let height = self.status.latest_block_height()?;
+            let now = Instant::now();
+            let since = match self.last_height {
+                Some((last_height, since)) if last_height == height => since,
+                _ => {
+                    self.last_height = Some((height, now));
+                    now
+                }
+            };
+            let duration = now.duration_since(since);
+            if duration >= stall {
+                alerts.push(Alert::BlockProductionStalled { height, duration });
+            }
+        }
+
+        if let Some(max_lag) = self.config.max_relayer_lag {
+            if let Some(blocks) = self.status.relayer_lag()? {
+                if blocks > max_lag {
+                    alerts.push(Alert::RelayerBehind { blocks });
+                }
+            }
+        }
+
+        if let Some(min_space) = self.config.min_free_disk_space {
+            if let Some(available) = self.status.available_disk_space()? {
+                if available < min_space {
+                    alerts.push(Alert::LowDiskSpace { available });
+                }
+            }
+        }
+
+        if let Some(min_peers) = self.config.min_peers {
+            if let Some(peers) = self.status.peer_count().await? {
+                if peers < min_peers {
+                    alerts.push(Alert::LowPeerCount { peers });
+                }
+            }
+        }
+
+        if let Some(min_balance) = self.config.min_wallet_balance {
+            if let Some(gwei) = self.status.wallet_balance()? {
+                if gwei < min_balance {
+                    alerts.push(Alert::LowWalletBalance { gwei });
+                }
+            }
+        }
+
+        Ok(alerts)
+    }
+
+    /// Checks conditions and sends alerts that became active since the last check.
+    pub async fn check(&mut self) -> anyhow::Result<Vec<Alert>> {
+        let alerts = self.conditions().await?;
+        let fired: Vec<_> = alerts
+            .iter()
+            .filter(|alert| !self.active.contains(alert.kind()))
+            .cloned()
+            .collect();
+        for alert in fired.iter() {
+            self.sink.send(alert).await;
+        }
+        self.active = alerts.iter().map(Alert::kind).collect();
+        Ok(fired)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Status, Sink> RunnableService for Task<Status, Sink>
+where
+    Status: NodeStatus + 'static,
+    Sink: AlertSink + 'static,
+{
+    const NAME: &'static str = "Alerting";
+
+    type SharedData = ();
+    type Task = Task<Status, Sink>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.timer.reset();
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Status, Sink> RunnableTask for Task<Status, Sink>
+where
+    Status: NodeStatus + 'static,
+    Sink: AlertSink + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.timer.tick() => {
+                // The failure to check conditions shouldn't stop the node.
+                if let Err(e) = self.check().await {
+                    tracing::warn!("Failed to check alerting conditions: {}", e);
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        Ok(())
+    }
+}
+
+pub fn new_service<Status, Sink>(
+    config: Config,
+    status: Status,
+    sink: Sink,
+) -> Service<Status, Sink>
+where
+    Status: NodeStatus + 'static,
+    Sink: AlertSink + 'static,
+{
+    let mut timer = tokio::time::interval(config.interval);
+    timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    Service::new(Task {
+        config,
+        status,
+        sink,
+        timer,
+        last_height: None,
+        active: HashSet::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+
+    #[derive(Clone, Default)]
+    struct FakeStatus {
+        height: Arc<Mutex<u32>>,
+        relayer_lag: Option<u64>,
+        disk_space: Option<u64>,
+        peers: Arc<Mutex<Option<usize>>>,
+        wallet_balance: Option<u64>,
+    }
+
+    #[async_trait::async_trait]
+    impl NodeStatus for FakeStatus {
+        fn latest_block_height(&self) -> anyhow::Result<BlockHeight> {
+            Ok((*self.height.lock().unwrap()).into())
+        }
+
+        fn relayer_lag(&self) -> anyhow::Result<Option<u64>> {
+            Ok(self.relayer_lag)
+        }
+
+        fn available_disk_space(&self) -> anyhow::Result<Option<u64>> {
+            Ok(self.disk_space)
+        }
+
+        async fn peer_count(&self) -> anyhow::Result<Option<usize>> {
+            Ok(*self.peers.lock().unwrap())
+        }
+
+        fn wallet_balance(&self) -> anyhow::Result<Option<u64>> {
+            Ok(self.wallet_balance)
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct FakeSink(Arc<Mutex<Vec<Alert>>>);
+
+    #[async_trait::async_trait]
+    impl AlertSink for FakeSink {
+        async fn send(&self, alert: &Alert) {
+            self.0.lock().unwrap().push(alert.clone());
+        }
+    }
+
+    fn task(config: Config, status: FakeStatus) -> Task<FakeStatus, FakeSink> {
+        Task {
+            config,
+            status,
+            sink: Default::default(),
+            timer: tokio::time::interval(Duration::from_secs(1)),
+            last_height: None,
+            active: HashSet::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_fires_alerts_for_violated_thresholds() {
+        let config = Config {
+            max_relayer_lag: Some(10),
+            min_free_disk_space: Some(1000),
+            min_peers: Some(2),
+            min_wallet_balance: Some(500),
+            ..Default::default()
+        };
+        let status = FakeStatus {
+            relayer_lag: Some(11),
+            disk_space: Some(1000),
+            peers: Arc::new(Mutex::new(Some(1))),
+            wallet_balance: Some(499),
+            ..Default::default()
+        };
+        let mut task = task(config, status);
+
+        let fired = task.check().await.unwrap();
+
+        assert_eq!(
+            fired,
+            vec![
+                Alert::RelayerBehind { blocks: 11 },
+                Alert::LowPeerCount { peers: 1 },
+                Alert::LowWalletBalance { gwei: 499 },
+            ]
+        );
+        assert_eq!(task.sink.0.lock().unwrap().clone(), fired);
+    }
+
+    #[tokio::test]
+    async fn check_fires_alert_once_until_condition_is_resolved() {
+        let config = Config {
+            min_peers: Some(2),
+            ..Default::default()
+        };
+        let status = FakeStatus {
+            peers: Arc::new(Mutex::new(Some(1))),
+            ..Default::default()
+        };
+        let mut task = task(config, status.clone());
+
+        assert_eq!(task.check().await.unwrap().len(), 1);
+        assert!(task.check().await.unwrap().is_empty());
+
+        *status.peers.lock().unwrap() = Some(2);
+        assert!(task.check().await.unwrap().is_empty());
+
+        *status.peers.lock().unwrap() = Some(0);
+        assert_eq!(
+            task.check().await.unwrap(),
+            vec![Alert::LowPeerCount { peers: 0 }]
+        );
+        assert_eq!(task.sink.0.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_fires_alert_if_block_height_stalls() {
+        let config = Config {
+            block_production_stall: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let status = FakeStatus::default();
+        let mut task = task(config, status.clone());
+
+        assert!(task.check().await.unwrap().is_empty());
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let fired = task.check().await.unwrap();
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].kind(), "block_production_stalled");
+
+        // The new block resolves the alert.
+        *status.height.lock().unwrap() = 1;
+        assert!(task.check().await.unwrap().is_empty());
+        assert!(task.active.is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+//! The service prunes bodies of blocks older than the retention after each imported
+//! block, so the node keeps transactions and receipts only of the latest blocks. The
+//! sealed headers of pruned blocks stay in the database, see
+//! [`Database::prune_block_bodies`].
+
+use crate::database::Database;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+pub type Service = ServiceRunner<Task>;
+
+/// The maximum number of blocks pruned in one database transaction. The first pruning
+/// after enabling the retention on the long chain covers many blocks, so it is split.
+const PRUNING_BATCH_SIZE: u32 = 1_000;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The number of the latest blocks whose bodies are kept. At least the latest block
+    /// is kept.
+    pub retention: u32,
+}
+
+impl Config {
+    /// Checks that the retention keeps at least the latest block.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.retention > 0,
+            "The block retention must keep at least the latest block"
+        );
+        Ok(())
+    }
+
+    /// Returns the height below which bodies of blocks are pruned once the block at the
+    /// `latest` height is imported, or `None` if all blocks are retained.
+    pub fn pruned_height(&self, latest: BlockHeight) -> Option<BlockHeight> {
+        (*latest)
+            .checked_add(1)?
+            .checked_sub(self.retention)
+            .filter(|height| *height > 0)
+            .map(Into::into)
+    }
+}
+
+pub struct Task {
+    config: Config,
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl Task {
+    async fn prune(&self, latest: BlockHeight) -> anyhow::Result<()> {
+        let height = match self.config.pruned_height(latest) {
+            Some(height) => height,
+            None => return Ok(()),
+        };
+        loop {
+            let database = self.database.clone();
+            let pruned = tokio::task::spawn_blocking(move || {
+                database.prune_block_bodies(&height, PRUNING_BATCH_SIZE)
+            })
+            .await??;
+            if pruned >= height {
+                return Ok(())
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "BlockPruning";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // Blocks imported before the start are pruned with the first imported block.
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.blocks.recv() => {
+                match result {
+                    Ok(result) => {
+                        let height = *result.sealed_block.entity.header().height();
+                        if let Err(e) = self.prune(height).await {
+                            tracing::error!(
+                                "Failed to prune block bodies after the block {}: {}",
+                                *height,
+                                e
+                            );
+                        }
+                        should_continue = true;
+                    }
+                    // The next block prunes all blocks below the retention, including
+                    // the missed ones.
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        should_continue = true;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        should_continue = false;
+                    }
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+) -> Service {
+    Service::new(Task {
+        config,
+        database,
+        blocks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latest_blocks_are_retained() {
+        let config = Config { retention: 10 };
+
+        assert_eq!(config.pruned_height(0u32.into()), None);
+        assert_eq!(config.pruned_height(9u32.into()), None);
+        assert_eq!(config.pruned_height(10u32.into()), Some(1u32.into()));
+        assert_eq!(config.pruned_height(100u32.into()), Some(91u32.into()));
+    }
+
+    #[test]
+    fn zero_retention_is_rejected() {
+        assert!(Config { retention: 0 }.validate().is_err());
+        assert!(Config { retention: 1 }.validate().is_ok());
+    }
+}
@@ -0,0 +1,51 @@
+//! The information about the build of the running node. Along with the hash of the
+//! consensus-relevant configuration, it lets operators of the fleet verify that all
+//! validators run the same software with the same rules.
+
+use crate::service::Config;
+
+/// The version of the node.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The git commit of the build, or `unknown` if the source was not a git checkout.
+pub const GIT_SHA: &str = env!("FUEL_CORE_GIT_SHA");
+/// The version of the compiler that built the node.
+pub const RUSTC_VERSION: &str = env!("FUEL_CORE_RUSTC_VERSION");
+/// The comma-separated cargo features of the `fuel-core` crate.
+const FEATURES: &str = env!("FUEL_CORE_FEATURES");
+
+/// Returns the sorted cargo features enabled for the `fuel-core` crate.
+pub fn features() -> Vec<&'static str> {
+    FEATURES
+        .split(',')
+        .filter(|feature| !feature.is_empty())
+        .collect()
+}
+
+/// Logs the build of the node with the hash of its consensus-relevant configuration,
+/// warns about settings that differ from the chain config, and exposes the build in
+/// metrics.
+pub fn report(config: &Config) -> anyhow::Result<()> {
+    let config_hash = config.consensus_config_hash()?;
+    tracing::info!(
+        "Running fuel-core {} ({}, {}) with the consensus config hash {:x}",
+        VERSION,
+        GIT_SHA,
+        RUSTC_VERSION,
+        config_hash
+    );
+    for warning in config.consensus_config_warnings() {
+        tracing::warn!("{}", warning);
+    }
+
+    #[cfg(feature = "metrics")]
+    fuel_core_metrics::version_metrics::VERSION_METRICS.set_build_info(
+        fuel_core_metrics::version_metrics::BuildLabels {
+            version: VERSION.to_string(),
+            git_sha: GIT_SHA.to_string(),
+            rustc_version: RUSTC_VERSION.to_string(),
+            features: FEATURES.to_string(),
+            config_hash: format!("{config_hash:x}"),
+        },
+    );
+    Ok(())
+}
@@ -0,0 +1,324 @@
+//! Streams the change data capture of the database to external services over the unix
+//! socket. Indexers can mirror the state of the node from the stream instead of querying
+//! the GraphQL API.
+//!
+//! The subscriber sends one JSON line with the request, e.g. `{"from_height": 10}`, or
+//! the empty line to receive only new changes. The node responds with one JSON line per
+//! change set, shown formatted:
+//!
+//! ```json
+//! {
+//!   "sequence": 0,
+//!   "height": 10,
+//!   "changes": [{ "column": "Coins", "column_id": 3, "key": "0a..", "value": null }]
+//! }
+//! ```
+//!
+//! Keys and values are hex-encoded as they are stored in the database, the `null` value
+//! means the removal of the key. The node sends `{"error":"..."}` and closes the
+//! connection if the request is invalid or the subscriber falls behind.
+
+use crate::state::change_capture::{
+    ChangeFeed,
+    ChangeSet,
+    Subscription,
+};
+use anyhow::anyhow;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::path::PathBuf;
+use tokio::{
+    io::{
+        AsyncBufRead,
+        AsyncBufReadExt,
+        AsyncWrite,
+        AsyncWriteExt,
+        BufReader,
+    },
+    net::{
+        UnixListener,
+        UnixStream,
+    },
+    sync::broadcast::error::RecvError,
+    task::JoinSet,
+};
+
+pub type Service = ServiceRunner<ChangeCaptureService>;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The path of the unix socket. The stale socket file is replaced on the start.
+    pub socket_path: PathBuf,
+    /// The number of latest blocks whose change sets are retained for the replay.
+    pub retained_blocks: u32,
+}
+
+impl Config {
+    /// Creates the feed that the database publishes changes into.
+    pub fn feed(&self) -> ChangeFeed {
+        ChangeFeed::new(self.retained_blocks)
+    }
+}
+
+/// The request of the subscriber.
+#[derive(Debug, Default, Deserialize)]
+pub struct Request {
+    /// Replays retained change sets starting from the height.
+    pub from_height: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChangeSetMessage {
+    sequence: u64,
+    height: u32,
+    changes: Vec<ChangeMessage>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChangeMessage {
+    column: String,
+    column_id: usize,
+    key: String,
+    value: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorMessage {
+    error: String,
+}
+
+impl From<&ChangeSet> for ChangeSetMessage {
+    fn from(change_set: &ChangeSet) -> Self {
+        Self {
+            sequence: change_set.sequence,
+            height: *change_set.height,
+            changes: change_set
+                .changes
+                .iter()
+                .map(|change| ChangeMessage {
+                    column: format!("{:?}", change.column),
+                    column_id: change.column.as_usize(),
+                    key: hex::encode(&change.key),
+                    value: change
+                        .value
+                        .as_ref()
+                        .map(|value| hex::encode(value.as_slice())),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct ChangeCaptureService {
+    socket_path: PathBuf,
+    feed: ChangeFeed,
+}
+
+pub struct Task {
+    listener: UnixListener,
+    socket_path: PathBuf,
+    feed: ChangeFeed,
+    connections: JoinSet<()>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for ChangeCaptureService {
+    const NAME: &'static str = "ChangeCapture";
+
+    type SharedData = ChangeFeed;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.feed.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        match std::fs::remove_file(&self.socket_path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        }
+        let listener = UnixListener::bind(&self.socket_path)?;
+        tracing::info!(
+            "Streaming changes of the database to {}",
+            self.socket_path.display()
+        );
+        Ok(Task {
+            listener,
+            socket_path: self.socket_path,
+            feed: self.feed,
+            connections: JoinSet::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            accepted = self.listener.accept() => {
+                let (stream, _) = accepted?;
+                self.connections.spawn(serve(stream, self.feed.clone()));
+                should_continue = true;
+            }
+
+            Some(_) = self.connections.join_next() => {
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        self.connections.shutdown().await;
+        let _ = std::fs::remove_file(&self.socket_path);
+        Ok(())
+    }
+}
+
+async fn serve(stream: UnixStream, feed: ChangeFeed) {
+    let (reader, mut writer) = stream.into_split();
+    if let Err(e) = stream_changes(BufReader::new(reader), &mut writer, feed).await {
+        tracing::debug!("The change capture subscriber is disconnected: {}", e);
+        let error = ErrorMessage {
+            error: e.to_string(),
+        };
+        let _ = write_line(&mut writer, &error).await;
+    }
+}
+
+async fn stream_changes<R, W>(
+    mut reader: R,
+    writer: &mut W,
+    feed: ChangeFeed,
+) -> anyhow::Result<()>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut line = String::new();
+    reader.read_line(&mut line).await?;
+    let request = if line.trim().is_empty() {
+        Request::default()
+    } else {
+        serde_json::from_str::<Request>(line.trim())
+            .map_err(|e| anyhow!("Invalid request: {}", e))?
+    };
+
+    let Subscription {
+        replay,
+        mut receiver,
+    } = feed.subscribe(request.from_height.map(Into::into))?;
+    for change_set in replay {
+        write_line(writer, &ChangeSetMessage::from(change_set.as_ref())).await?;
+    }
+    loop {
+        match receiver.recv().await {
+            Ok(change_set) => {
+                write_line(writer, &ChangeSetMessage::from(change_set.as_ref())).await?;
+            }
+            Err(RecvError::Lagged(skipped)) => {
+                return Err(anyhow!(
+                    "The subscriber fell behind by {} change sets, resubscribe with \
+                    the replay from the height of the last applied change set",
+                    skipped
+                ))
+            }
+            Err(RecvError::Closed) => return Ok(()),
+        }
+    }
+}
+
+async fn write_line<W, T>(writer: &mut W, message: &T) -> anyhow::Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let mut line = serde_json::to_vec(message)?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+    Ok(())
+}
+
+pub fn new_service(config: &Config, feed: ChangeFeed) -> Service {
+    Service::new(ChangeCaptureService {
+        socket_path: config.socket_path.clone(),
+        feed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::Column,
+        state::{
+            change_capture::ChangeCaptureStore,
+            in_memory::memory_store::MemoryStore,
+            KeyValueStore,
+        },
+    };
+    use fuel_core_services::Service as ServiceTrait;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn subscriber_receives_replayed_and_new_changes() {
+        let config = Config {
+            socket_path: std::env::temp_dir().join(format!(
+                "fuel-core-change-capture-{}.sock",
+                std::process::id()
+            )),
+            retained_blocks: 10,
+        };
+        let feed = config.feed();
+        let store =
+            ChangeCaptureStore::new(Arc::new(MemoryStore::default()), feed.clone())
+                .unwrap();
+        let height = 0u32.to_be_bytes();
+        store
+            .put(&height, Column::FuelBlockSecondaryKeyBlockHeights, Arc::new(vec![]))
+            .unwrap();
+        let service = new_service(&config, feed);
+        service.start_and_await().await.unwrap();
+
+        let mut stream = UnixStream::connect(&config.socket_path).await.unwrap();
+        stream.write_all(b"{\"from_height\":0}\n").await.unwrap();
+        let mut lines = BufReader::new(stream).lines();
+        let replayed: serde_json::Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        store.delete(&[1], Column::Coins).unwrap();
+        let new: serde_json::Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+
+        assert_eq!(replayed["sequence"], 0);
+        assert_eq!(
+            replayed["changes"][0]["column"],
+            "FuelBlockSecondaryKeyBlockHeights"
+        );
+        assert_eq!(replayed["changes"][0]["key"], "00000000");
+        assert_eq!(new["sequence"], 1);
+        assert_eq!(new["height"], 0);
+        assert_eq!(new["changes"][0]["key"], "01");
+        assert_eq!(new["changes"][0]["value"], serde_json::Value::Null);
+        service.stop_and_await().await.unwrap();
+    }
+}
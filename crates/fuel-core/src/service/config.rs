@@ -1,10 +1,21 @@
+use crate::schema::cursor::CursorSecret;
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
+    ConsensusConfig,
+    GenesisCommitment,
+};
+#[cfg(feature = "fork")]
+use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_poa::{
+    ports::BlockSigner,
+    TriggerLimits,
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
     secrecy::Secret,
 };
 use std::{
@@ -13,6 +24,7 @@ use std::{
         SocketAddr,
     },
     path::PathBuf,
+    sync::Arc,
     time::Duration,
 };
 use strum_macros::{
@@ -27,19 +39,72 @@ use fuel_core_p2p::config::{
     NotInitialized,
 };
 
-pub use fuel_core_poa::Trigger;
+pub use fuel_core_poa::{
+    GasLimitAdjustment,
+    LateBlockPolicy,
+    Standby,
+    Trigger,
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    /// Additional addresses of the GraphQL API, e.g. the IPv6 address of the
+    /// dual-stack node or addresses of other interfaces.
+    pub additional_addrs: Vec<SocketAddr>,
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub chain_conf: ChainConfig,
+    /// Wipes the database if it belongs to another chain than the `chain_conf` instead of
+    /// refusing to start.
+    pub force_chain_switch: bool,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub manual_blocks_enabled: bool,
+    /// Enables debug features of the GraphQL API, like overriding `utxo_validation`
+    /// during the submission of transactions or querying the timeline of transactions.
+    /// Should be used for local development only.
+    pub debug: bool,
+    /// The secret used to authenticate the pagination cursors of the GraphQL API.
+    pub cursor_secret: CursorSecret,
+    /// Disables the deprecated fields of the GraphQL API before their sunset.
+    pub disable_deprecated_graphql_fields: bool,
+    /// How long the GraphQL API remembers idempotency keys of submitted transactions.
+    pub graphql_idempotency_key_ttl: Duration,
+    /// Serves the admin API to inspect and control sub-services of the node.
+    pub admin_api: bool,
+    /// Requires the token in requests to the admin API.
+    pub admin_api_token: Option<crate::graphql_api::AdminApiToken>,
+    /// The directory with GraphQL documents allowed to be executed. All other requests
+    /// to the GraphQL API are rejected if it is set.
+    pub graphql_allowlist_dir: Option<PathBuf>,
+    /// Runs the block production and the import of blocks on the dedicated runtime with
+    /// the number of worker threads. Uses the runtime of the node if not set.
+    pub execution_threads: Option<usize>,
+    /// Reports iterations of tasks of sub-services that run longer as stalled in metrics.
+    pub service_stall_threshold: Option<Duration>,
     pub block_production: Trigger,
+    /// Emits signed pre-confirmations of transactions included into the produced block.
+    pub pre_confirmations: bool,
+    /// The time budget of the execution of the produced block, see
+    /// [`fuel_core_poa::Config::execution_budget`].
+    pub block_execution_budget: Option<Duration>,
+    /// Adjusts the gas limit of produced blocks to the demand, starting from the
+    /// `block_gas_limit` of the chain config.
+    pub block_gas_limit_adjustment: Option<GasLimitAdjustment>,
+    /// Verifies produced blocks before they are sealed, see
+    /// [`fuel_core_poa::Config::verify_before_seal`].
+    pub verify_produced_blocks: bool,
+    /// Executes the next block while the produced block is imported, see
+    /// [`fuel_core_poa::Config::speculative_execution`].
+    pub speculative_execution: bool,
+    /// Runs the block production as the hot standby of the primary producer, see
+    /// [`fuel_core_poa::Config::standby`].
+    pub block_production_standby: Option<Standby>,
+    /// How the `Interval` trigger handles late blocks, see
+    /// [`fuel_core_poa::Config::late_block_policy`].
+    pub late_block_policy: LateBlockPolicy,
     pub vm: VMConfig,
     pub txpool: fuel_core_txpool::Config,
     pub block_producer: fuel_core_producer::Config,
@@ -51,7 +116,44 @@ pub struct Config {
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
+    /// Samples pools of peers to detect the partitioning of the transaction gossiping.
+    #[cfg(feature = "p2p")]
+    pub pool_divergence: Option<crate::service::pool_divergence::Config>,
+    /// Periodically computes the statistics of the UTXO set.
+    pub utxo_stats: Option<crate::service::utxo_stats::Config>,
+    /// Prunes transactions and receipts of blocks beyond the retention.
+    pub block_pruning: Option<crate::service::block_pruning::Config>,
+    /// Tracks how long sufficiently paying transactions wait for the inclusion to
+    /// detect the censorship by the block producer.
+    pub inclusion_monitor: Option<crate::service::inclusion_monitor::Config>,
+    /// Loads the latest blocks, hot contracts and coins of busy owners into the caches
+    /// of the database before the API starts serving requests.
+    pub prewarm: Option<crate::service::prewarm::Config>,
+    /// Records GraphQL requests and commits of storage transactions that take longer
+    /// than thresholds. Recorded operations are served by the admin API.
+    pub slow_log: Option<crate::service::slow_log::Config>,
+    /// Notifies operators about critical conditions of the node via webhooks.
+    #[cfg(feature = "alerting")]
+    pub alerting: Option<crate::service::alerting::Config>,
+    /// Checks the running version of the node against the manifest of supported
+    /// versions of the network.
+    #[cfg(feature = "version-check")]
+    pub version_check: Option<crate::service::version_check::Config>,
+    /// Forks the state of the remote network instead of starting from the genesis state.
+    #[cfg(feature = "fork")]
+    pub fork: Option<ForkConfig>,
+    /// Streams changes of the database to external services over the unix socket.
+    #[cfg(unix)]
+    pub change_capture: Option<crate::service::change_capture::Config>,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// Signs produced blocks with the key held by the remote signing service instead of
+    /// the `consensus_key`.
+    #[cfg(feature = "remote-signer")]
+    pub remote_signer: Option<crate::service::remote_signer::Config>,
+    /// The remote signing services of other members of the committee of the threshold
+    /// PoA. Produced blocks are sealed by the committee together with the local signer.
+    #[cfg(feature = "remote-signer")]
+    pub remote_cosigners: Vec<crate::service::remote_signer::Config>,
     pub name: String,
     pub verifier: fuel_core_consensus_module::RelayerVerifierConfig,
 }
@@ -63,6 +165,7 @@ impl Config {
         let min_gas_price = 0;
         Self {
             addr: SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 0),
+            additional_addrs: vec![],
             // Set the cache for tests = 10MB
             max_database_cache_size: 10 * 1024 * 1024,
             database_path: Default::default(),
@@ -71,8 +174,25 @@ impl Config {
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
             chain_conf: chain_conf.clone(),
+            force_chain_switch: false,
             manual_blocks_enabled: false,
+            debug: false,
+            cursor_secret: Default::default(),
+            disable_deprecated_graphql_fields: false,
+            graphql_idempotency_key_ttl: Duration::from_secs(10 * 60),
+            admin_api: false,
+            admin_api_token: None,
+            graphql_allowlist_dir: None,
+            execution_threads: None,
+            service_stall_threshold: None,
             block_production: Trigger::Instant,
+            pre_confirmations: false,
+            block_execution_budget: None,
+            block_gas_limit_adjustment: None,
+            verify_produced_blocks: false,
+            speculative_execution: false,
+            block_production_standby: None,
+            late_block_policy: LateBlockPolicy::Drift,
             vm: Default::default(),
             utxo_validation,
             txpool: fuel_core_txpool::Config {
@@ -91,37 +211,250 @@ impl Config {
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
+            #[cfg(feature = "p2p")]
+            pool_divergence: None,
+            utxo_stats: None,
+            block_pruning: None,
+            inclusion_monitor: None,
+            prewarm: None,
+            slow_log: None,
+            #[cfg(feature = "alerting")]
+            alerting: None,
+            #[cfg(feature = "version-check")]
+            version_check: None,
+            #[cfg(feature = "fork")]
+            fork: None,
+            #[cfg(unix)]
+            change_capture: None,
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            #[cfg(feature = "remote-signer")]
+            remote_signer: None,
+            #[cfg(feature = "remote-signer")]
+            remote_cosigners: vec![],
             name: String::default(),
             verifier: Default::default(),
         }
     }
+
+    /// Returns the signer of produced blocks. The remote signer takes precedence over
+    /// the `consensus_key`. If the chain is sealed by the committee, the signer seals
+    /// blocks together with remote cosigners.
+    pub fn block_signer(&self) -> Option<Arc<dyn BlockSigner>> {
+        let signer = self.local_block_signer()?;
+        let committee = match self.chain_conf.consensus.committee() {
+            Some(committee) => committee,
+            None => return Some(signer),
+        };
+        #[allow(unused_mut)]
+        let mut members = vec![signer];
+        #[cfg(feature = "remote-signer")]
+        members.extend(self.remote_cosigners.iter().map(|cosigner| {
+            let cosigner =
+                crate::service::remote_signer::RemoteSigner::new(cosigner.clone());
+            Arc::new(cosigner) as Arc<dyn BlockSigner>
+        }));
+        Some(Arc::new(fuel_core_poa::ThresholdSigner::new(
+            committee, members,
+        )))
+    }
+
+    /// Returns the hash of the configuration that affects the validity of blocks: the
+    /// chain config, the UTXO validation and the version of the executor. Nodes with
+    /// different hashes may disagree on the same block.
+    pub fn consensus_config_hash(&self) -> anyhow::Result<Bytes32> {
+        let hash = Hasher::default()
+            .chain(self.chain_conf.root()?)
+            .chain([self.utxo_validation as u8])
+            .chain(crate::executor::EXECUTOR_VERSION.to_be_bytes())
+            .finalize();
+        Ok(hash)
+    }
+
+    /// Returns the settings of the node that differ from the expectations of the chain
+    /// config. Unlike errors, they don't prevent the start, but other nodes of the
+    /// network may see blocks of this node differently.
+    pub fn consensus_config_warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        let block_gas_limit = self.chain_conf.block_gas_limit;
+
+        if let (Some(cadence), false) =
+            (self.chain_conf.block_production, self.manual_blocks_enabled)
+        {
+            let trigger = Trigger::from(cadence);
+            if self.block_production != trigger {
+                warnings.push(format!(
+                    "The block production trigger {:?} differs from the trigger {:?} of \
+                    the cadence of the chain config",
+                    self.block_production, trigger
+                ));
+            }
+        }
+
+        if let Some(adjustment) = &self.block_gas_limit_adjustment {
+            let range = adjustment.min_gas_limit..=adjustment.max_gas_limit;
+            if !range.contains(&block_gas_limit) {
+                warnings.push(format!(
+                    "The gas limit adjustment between {} and {} excludes the block gas \
+                    limit {} of the chain config",
+                    adjustment.min_gas_limit, adjustment.max_gas_limit, block_gas_limit
+                ));
+            }
+        }
+
+        let max_gas_per_tx = self.chain_conf.transaction_parameters.max_gas_per_tx;
+        if max_gas_per_tx > block_gas_limit {
+            warnings.push(format!(
+                "The maximal gas {max_gas_per_tx} of the transaction is above the block \
+                gas limit {block_gas_limit}, such transactions never fit into the block"
+            ));
+        }
+
+        warnings
+    }
+
+    fn local_block_signer(&self) -> Option<Arc<dyn BlockSigner>> {
+        #[cfg(feature = "remote-signer")]
+        if let Some(remote_signer) = &self.remote_signer {
+            let signer = crate::service::remote_signer::RemoteSigner::new(
+                remote_signer.clone(),
+            );
+            return Some(Arc::new(signer))
+        }
+        self.consensus_key
+            .clone()
+            .map(|key| Arc::new(key) as Arc<dyn BlockSigner>)
+    }
 }
 
 impl TryFrom<&Config> for fuel_core_poa::Config {
     type Error = anyhow::Error;
 
     fn try_from(config: &Config) -> Result<Self, Self::Error> {
-        // If manual block production then require trigger never or instant.
+        // Validators reject blocks produced faster than the cadence of the chain config,
+        // so the misconfigured producer fails on start instead.
+        let trigger_limits = TriggerLimits {
+            manual_blocks_enabled: config.manual_blocks_enabled,
+            cadence: config.chain_conf.block_production,
+        };
+        trigger_limits.check(&config.block_production)?;
+
+        if let Some(adjustment) = &config.block_gas_limit_adjustment {
+            anyhow::ensure!(
+                adjustment.min_gas_limit <= adjustment.max_gas_limit,
+                "The minimal gas limit {} is above the maximal gas limit {}",
+                adjustment.min_gas_limit,
+                adjustment.max_gas_limit
+            );
+            anyhow::ensure!(
+                (1..=100).contains(&adjustment.target_utilization),
+                "The target utilization {}% of the gas limit is not between 1% and 100%",
+                adjustment.target_utilization
+            );
+            // Otherwise, the limit may shrink below the largest transactions, and they
+            // never fit into blocks again while the demand is low.
+            let max_gas_per_tx = config.chain_conf.transaction_parameters.max_gas_per_tx;
+            anyhow::ensure!(
+                adjustment.min_gas_limit >= max_gas_per_tx,
+                "The minimal gas limit {} is below the maximal gas {} of the transaction",
+                adjustment.min_gas_limit,
+                max_gas_per_tx
+            );
+        }
+
+        if let Some(standby) = &config.block_production_standby {
+            anyhow::ensure!(
+                config.block_production.max_block_time().is_some(),
+                "The standby producer requires the trigger with the interval to count \
+                missed blocks of the primary producer, not {:?}",
+                config.block_production
+            );
+            anyhow::ensure!(
+                standby.missed_intervals > 0,
+                "The standby producer should wait for at least one missed interval"
+            );
+            anyhow::ensure!(
+                standby.min_peers > 0,
+                "The standby producer should require at least one connected peer, so it \
+                doesn't take over while cut off from the network"
+            );
+        }
+
+        // The chain config may be built in the code, not only loaded from the file.
+        config.chain_conf.consensus.validate()?;
+
+        let authorities = match &config.chain_conf.consensus {
+            ConsensusConfig::PoA { .. } | ConsensusConfig::PoAThreshold { .. } => vec![],
+            ConsensusConfig::PoARoundRobin { authorities } => authorities.clone(),
+        };
+        let key_rotations = match &config.chain_conf.consensus {
+            ConsensusConfig::PoA { key_rotations, .. } => key_rotations.clone(),
+            _ => vec![],
+        };
+        // Pre-confirmations are signed by the single block producer.
         anyhow::ensure!(
-            !config.manual_blocks_enabled
-                || matches!(
-                    config.block_production,
-                    Trigger::Never | Trigger::Instant | Trigger::Interval { .. }
-                ),
-            "Cannot use manual block production unless trigger mode is never, instant or interval."
+            !config.pre_confirmations
+                || config.chain_conf.consensus.committee().is_none(),
+            "Pre-confirmations are not supported by the chain sealed by the committee"
         );
-
+        let signer = config.block_signer();
+        // The producer outside of the round-robin would never have its slot.
+        if let (Some(signer), false) = (&signer, authorities.is_empty()) {
+            let address = signer.address();
+            anyhow::ensure!(
+                authorities.contains(&address),
+                "The consensus key {address} is not one of the authorities of the chain \
+                config"
+            );
+        }
         Ok(fuel_core_poa::Config {
             trigger: config.block_production,
-            block_gas_limit: config.chain_conf.block_gas_limit,
-            signing_key: config.consensus_key.clone(),
+            block_gas_limit: match &config.block_gas_limit_adjustment {
+                Some(adjustment) => config
+                    .chain_conf
+                    .block_gas_limit
+                    .clamp(adjustment.min_gas_limit, adjustment.max_gas_limit),
+                None => config.chain_conf.block_gas_limit,
+            },
+            signer,
+            authorities,
+            key_rotations,
+            pre_confirmations: config.pre_confirmations,
             metrics: false,
             consensus_params: config.chain_conf.transaction_parameters,
+            runtime: None,
+            execution_budget: config.block_execution_budget,
+            gas_limit_adjustment: config.block_gas_limit_adjustment,
+            // The produced block is verified with the same config as on followers.
+            verify_before_seal: config.verify_produced_blocks.then(|| {
+                fuel_core_poa::verifier::Config::new(
+                    &config.chain_conf,
+                    config.manual_blocks_enabled,
+                )
+            }),
+            speculative_execution: config.speculative_execution,
+            standby: config.block_production_standby,
+            // The view of the network is provided by the P2P service.
+            p2p: None,
+            late_block_policy: config.late_block_policy,
+            trigger_limits,
         })
     }
 }
 
+/// The configuration of the fork of the remote network. The state of the remote network is
+/// fetched lazily and new blocks are produced on top of it, starting from the fork height.
+/// The state is fetched only while the remote node stays at the fork height, and only
+/// into the in-memory database.
+#[cfg(feature = "fork")]
+#[derive(Clone, Debug)]
+pub struct ForkConfig {
+    /// The URL of the remote node.
+    pub url: String,
+    /// The height of the block to fork from, the latest height of the remote node if not set.
+    /// The remote node serves only its latest state, so other heights are rejected.
+    pub height: Option<BlockHeight>,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
@@ -73,34 +73,47 @@ pub fn maybe_initialize_state(
         import_genesis_block(config, database)?;
     }
 
+    // Databases created before the chain was recorded trust the config on the first use.
+    if database.get_chain_id()?.is_none() {
+        let chain_id = config.chain_conf.transaction_parameters.chain_id;
+        database.record_chain(chain_id, &genesis_block(config).id())?;
+    }
+
     Ok(())
 }
 
-fn import_genesis_block(
+/// Returns the description of the mismatch if the database belongs to another chain than
+/// the chain defined by the config.
+pub fn chain_mismatch(
     config: &Config,
-    original_database: &Database,
-) -> anyhow::Result<()> {
-    // start a db transaction for bulk-writing
-    let mut database_transaction = Transactional::transaction(original_database);
-
-    let database = database_transaction.as_mut();
-    // Initialize the chain id and height.
-
-    let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
+    database: &Database,
+) -> anyhow::Result<Option<String>> {
+    let chain_id = config.chain_conf.transaction_parameters.chain_id;
+    if let Some(recorded) = database.get_chain_id()? {
+        if recorded != chain_id {
+            return Ok(Some(format!(
+                "The database belongs to the chain {recorded}, but the node is \
+                configured for the chain {chain_id}"
+            )))
+        }
+    }
 
-    let genesis = Genesis {
-        chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
-    };
+    let genesis_block_id = genesis_block(config).id();
+    if let Some(recorded) = database.get_genesis_block_id()? {
+        if recorded != genesis_block_id {
+            return Ok(Some(format!(
+                "The genesis block of the database is {recorded:#x}, but the chain \
+                config defines the genesis block {genesis_block_id:#x}"
+            )))
+        }
+    }
+    Ok(None)
+}
 
-    let block = Block::new(
+/// Builds the genesis block defined by the chain config. It doesn't depend on the
+/// content of the database, so the id of the block identifies the chain.
+fn genesis_block(config: &Config) -> Block {
+    Block::new(
         PartialBlockHeader {
             application: ApplicationHeader::<Empty> {
                 // TODO: Set `da_height` based on the chain config.
@@ -125,8 +138,34 @@ fn import_genesis_block(
         // Genesis block doesn't have any transaction.
         vec![],
         &[],
-    );
+    )
+}
 
+fn import_genesis_block(
+    config: &Config,
+    original_database: &Database,
+) -> anyhow::Result<()> {
+    // start a db transaction for bulk-writing
+    let mut database_transaction = Transactional::transaction(original_database);
+
+    let database = database_transaction.as_mut();
+    // Initialize the chain id and height.
+
+    let chain_config_hash = config.chain_conf.root()?.into();
+    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
+    let contracts_root =
+        init_contracts(database, &config.chain_conf.initial_state)?.into();
+    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
+    let messages_root = messages_root.into();
+
+    let genesis = Genesis {
+        chain_config_hash,
+        coins_root,
+        contracts_root,
+        messages_root,
+    };
+
+    let block = genesis_block(config);
     let block_id = block.id();
     database.storage::<FuelBlocks>().insert(
         &block_id,
@@ -386,6 +425,7 @@ mod tests {
     use crate::service::{
         config::Config,
         FuelService,
+        ServiceTrait,
     };
     use fuel_core_chain_config::{
         ChainConfig,
@@ -757,6 +797,51 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn node_refuses_database_of_another_chain() {
+        let db = Database::default();
+        let service = FuelService::from_database(db.clone(), Config::local_node())
+            .await
+            .unwrap();
+        service.stop_and_await().await.unwrap();
+
+        let mut service_config = Config::local_node();
+        service_config.chain_conf.transaction_parameters.chain_id += 1;
+        let init_result = FuelService::from_database(db.clone(), service_config).await;
+
+        assert!(init_result.is_err());
+        assert_eq!(db.get_chain_id().unwrap(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn force_chain_switch_wipes_database_of_another_chain() {
+        let db = Database::default();
+        let service = FuelService::from_database(db.clone(), Config::local_node())
+            .await
+            .unwrap();
+        service.stop_and_await().await.unwrap();
+
+        let test_height = BlockHeight::from(10u32);
+        let service_config = Config {
+            chain_conf: ChainConfig {
+                initial_state: Some(StateConfig {
+                    height: Some(test_height),
+                    ..Default::default()
+                }),
+                ..ChainConfig::local_testnet()
+            },
+            force_chain_switch: true,
+            ..Config::local_node()
+        };
+        let genesis_block_id = genesis_block(&service_config).id();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        assert_eq!(test_height, db.latest_height().unwrap());
+        assert_eq!(db.get_genesis_block_id().unwrap(), Some(genesis_block_id));
+    }
+
     fn get_coins(db: &Database, owner: &Address) -> Vec<Coin> {
         db.owned_coins_ids(owner, None, None)
             .map(|r| {
@@ -0,0 +1,652 @@
+//! The watchdog tracks how long valid transactions paying at least the configured gas
+//! price wait in the pool before the inclusion into a committed block. Transactions that
+//! wait much longer than others are a sign of the censorship by the block producer, which
+//! users and operators of decentralized setups want to detect.
+
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_txpool::{
+    service::PoolEvent,
+    Error as TxPoolError,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+use tokio::sync::broadcast;
+
+pub type Service<Pool> = ServiceRunner<Task<Pool>>;
+
+/// The transaction waiting in the pool.
+#[derive(Clone, Copy, Debug)]
+pub struct PoolEntry {
+    pub tx_id: TxId,
+    pub gas_price: Word,
+    /// The moment of the insertion into the pool.
+    pub inserted_at: Instant,
+}
+
+/// The pool the monitor reconciles with after missing some of its events.
+pub trait PoolTransactions: Send + Sync {
+    fn pool_transactions(&self) -> Vec<PoolEntry>;
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Transactions with the lower gas price are not tracked, because the producer is
+    /// free to postpone them.
+    pub min_gas_price: Word,
+    /// Transactions waiting longer than the threshold are reported as delayed.
+    pub delay_threshold: Duration,
+    /// The maximum number of tracked transactions, pending and included ones
+    /// separately. The oldest transactions are forgotten first.
+    pub max_tracked: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_gas_price: 0,
+            delay_threshold: Duration::from_secs(60),
+            max_tracked: 10_000,
+        }
+    }
+}
+
+/// The aggregate statistics of the inclusion of tracked transactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InclusionStatistics {
+    /// The number of tracked transactions waiting for the inclusion, including ones
+    /// expired in the pool.
+    pub pending: u64,
+    /// The number of pending transactions waiting longer than the threshold.
+    pub overdue: u64,
+    /// The number of tracked transactions included into blocks.
+    pub included: u64,
+    /// The number of included transactions that waited longer than the threshold.
+    pub delayed: u64,
+    /// The longest wait of included transactions.
+    pub max_delay: Duration,
+    /// The average wait of included transactions.
+    pub average_delay: Duration,
+}
+
+/// The inclusion status of the tracked transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxInclusion {
+    /// The transaction waits for the inclusion for the `delay`.
+    Pending { delay: Duration, overdue: bool },
+    /// The transaction is included into the block at the `height` after the `delay`.
+    Included {
+        delay: Duration,
+        height: BlockHeight,
+        delayed: bool,
+    },
+}
+
+#[derive(Debug)]
+struct Pending {
+    inserted_at: Instant,
+    /// The pool removed the transaction after its time to live. It stays unresolved,
+    /// because the producer could include it before.
+    expired: bool,
+}
+
+#[derive(Debug)]
+struct Included {
+    delay: Duration,
+    height: BlockHeight,
+}
+
+/// Follows events of the pool and remembers when tracked transactions were inserted
+/// and how long they waited for the inclusion.
+#[derive(Debug)]
+pub struct InclusionMonitor {
+    config: Config,
+    pending: HashMap<TxId, Pending>,
+    /// The pending transactions in the order of insertion.
+    pending_order: VecDeque<TxId>,
+    included: HashMap<TxId, Included>,
+    /// The included transactions in the order of inclusion.
+    included_order: VecDeque<TxId>,
+    included_count: u64,
+    delayed_count: u64,
+    total_delay: Duration,
+    max_delay: Duration,
+}
+
+impl InclusionMonitor {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            pending: HashMap::new(),
+            pending_order: VecDeque::new(),
+            included: HashMap::new(),
+            included_order: VecDeque::new(),
+            included_count: 0,
+            delayed_count: 0,
+            total_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    /// Applies the pool `event` that happened at the moment `now`.
+    pub fn on_event(&mut self, event: &PoolEvent, now: Instant) {
+        match event {
+            PoolEvent::Inserted {
+                tx_id, gas_price, ..
+            } => {
+                if *gas_price >= self.config.min_gas_price {
+                    self.track_pending(*tx_id, now);
+                }
+            }
+            PoolEvent::Replaced { tx_id, by } => {
+                // The replacement pays more and continues the wait of the replaced
+                // transaction, so the producer can't reset the wait by the replacement.
+                if let Some(pending) = self.forget_pending(tx_id) {
+                    self.track_pending(*by, pending.inserted_at);
+                }
+            }
+            PoolEvent::Evicted {
+                tx_id,
+                reason: TxPoolError::TTLReason,
+            } => {
+                if let Some(pending) = self.pending.get_mut(tx_id) {
+                    pending.expired = true;
+                }
+            }
+            PoolEvent::Evicted { tx_id, .. } => {
+                // The transaction became invalid, the producer can't include it.
+                self.forget_pending(tx_id);
+            }
+            PoolEvent::Selected { .. } => {}
+            PoolEvent::Committed { tx_id, height } => {
+                let pending = match self.forget_pending(tx_id) {
+                    Some(pending) => pending,
+                    None => return,
+                };
+                let delay = now.saturating_duration_since(pending.inserted_at);
+                self.on_included(*tx_id, delay, *height);
+            }
+        }
+    }
+
+    /// Reconciles tracked transactions with the `pool` after missed events. The
+    /// transactions of the pool are tracked since their insertion, and tracked ones
+    /// missing in the pool are forgotten, because their outcome is unknown. Expired
+    /// transactions stay unresolved.
+    pub fn reconcile(&mut self, pool: &[PoolEntry]) {
+        let in_pool = pool.iter().map(|tx| tx.tx_id).collect::<HashSet<_>>();
+        let gone = self
+            .pending
+            .iter()
+            .filter(|(tx_id, pending)| !pending.expired && !in_pool.contains(*tx_id))
+            .map(|(tx_id, _)| *tx_id)
+            .collect::<Vec<_>>();
+        for tx_id in gone.iter() {
+            self.forget_pending(tx_id);
+        }
+
+        let mut pool = pool
+            .iter()
+            .filter(|tx| tx.gas_price >= self.config.min_gas_price)
+            .collect::<Vec<_>>();
+        pool.sort_by_key(|tx| tx.inserted_at);
+        for tx in pool {
+            self.track_pending(tx.tx_id, tx.inserted_at);
+        }
+    }
+
+    /// Tracks the transaction since the `inserted_at`. The transaction tracked already
+    /// keeps the earlier moment and becomes unexpired, because it is in the pool again.
+    fn track_pending(&mut self, tx_id: TxId, inserted_at: Instant) {
+        match self.pending.get_mut(&tx_id) {
+            Some(pending) => {
+                pending.inserted_at = pending.inserted_at.min(inserted_at);
+                pending.expired = false;
+            }
+            None => {
+                self.pending.insert(
+                    tx_id,
+                    Pending {
+                        inserted_at,
+                        expired: false,
+                    },
+                );
+                self.pending_order.push_back(tx_id);
+                if self.pending_order.len() > self.config.max_tracked {
+                    if let Some(oldest) = self.pending_order.pop_front() {
+                        self.pending.remove(&oldest);
+                    }
+                }
+            }
+        }
+    }
+
+    fn forget_pending(&mut self, tx_id: &TxId) -> Option<Pending> {
+        let pending = self.pending.remove(tx_id)?;
+        self.pending_order.retain(|id| id != tx_id);
+        Some(pending)
+    }
+
+    fn on_included(&mut self, tx_id: TxId, delay: Duration, height: BlockHeight) {
+        #[cfg(feature = "metrics")]
+        fuel_core_metrics::txpool_metrics::TXPOOL_METRICS
+            .inclusion_delay_histogram
+            .observe(delay.as_secs_f64());
+
+        self.included_count += 1;
+        self.total_delay = self.total_delay.saturating_add(delay);
+        self.max_delay = self.max_delay.max(delay);
+        if delay > self.config.delay_threshold {
+            self.delayed_count += 1;
+            tracing::warn!(
+                "The transaction {} was included at the height {} after {:?}",
+                tx_id,
+                height,
+                delay
+            );
+        }
+
+        self.included.insert(tx_id, Included { delay, height });
+        self.included_order.push_back(tx_id);
+        if self.included_order.len() > self.config.max_tracked {
+            if let Some(oldest) = self.included_order.pop_front() {
+                self.included.remove(&oldest);
+            }
+        }
+    }
+
+    /// Returns the statistics of tracked transactions at the moment `now`.
+    pub fn statistics(&self, now: Instant) -> InclusionStatistics {
+        let overdue = self
+            .pending
+            .values()
+            .filter(|pending| {
+                now.saturating_duration_since(pending.inserted_at)
+                    > self.config.delay_threshold
+            })
+            .count();
+        let average_delay = match self.included_count {
+            0 => Duration::ZERO,
+            count => {
+                Duration::from_secs_f64(self.total_delay.as_secs_f64() / count as f64)
+            }
+        };
+
+        InclusionStatistics {
+            pending: self.pending.len() as u64,
+            overdue: overdue as u64,
+            included: self.included_count,
+            delayed: self.delayed_count,
+            max_delay: self.max_delay,
+            average_delay,
+        }
+    }
+
+    /// Returns `None` if the transaction is not tracked or already forgotten.
+    pub fn transaction(&self, tx_id: &TxId, now: Instant) -> Option<TxInclusion> {
+        let threshold = self.config.delay_threshold;
+        if let Some(pending) = self.pending.get(tx_id) {
+            let delay = now.saturating_duration_since(pending.inserted_at);
+            return Some(TxInclusion::Pending {
+                delay,
+                overdue: delay > threshold,
+            })
+        }
+        self.included
+            .get(tx_id)
+            .map(|included| TxInclusion::Included {
+                delay: included.delay,
+                height: included.height,
+                delayed: included.delay > threshold,
+            })
+    }
+}
+
+/// Shares the tracked inclusion of transactions with other services.
+#[derive(Clone)]
+pub struct SharedState {
+    monitor: Arc<Mutex<InclusionMonitor>>,
+}
+
+impl SharedState {
+    pub fn statistics(&self) -> InclusionStatistics {
+        self.monitor
+            .lock()
+            .expect("The inclusion monitor is poisoned")
+            .statistics(Instant::now())
+    }
+
+    pub fn transaction(&self, tx_id: &TxId) -> Option<TxInclusion> {
+        self.monitor
+            .lock()
+            .expect("The inclusion monitor is poisoned")
+            .transaction(tx_id, Instant::now())
+    }
+}
+
+pub struct Task<Pool> {
+    events: broadcast::Receiver<PoolEvent>,
+    pool: Pool,
+    shared: SharedState,
+}
+
+impl<Pool> Task<Pool>
+where
+    Pool: PoolTransactions,
+{
+    fn monitor(&self) -> MutexGuard<'_, InclusionMonitor> {
+        self.shared
+            .monitor
+            .lock()
+            .expect("The inclusion monitor is poisoned")
+    }
+}
+
+#[async_trait::async_trait]
+impl<Pool> RunnableService for Task<Pool>
+where
+    Pool: PoolTransactions + 'static,
+{
+    const NAME: &'static str = "InclusionMonitor";
+
+    type SharedData = SharedState;
+    type Task = Task<Pool>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // The subscription misses transactions inserted before the start.
+        let pool = self.pool.pool_transactions();
+        self.monitor().reconcile(&pool);
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Pool> RunnableTask for Task<Pool>
+where
+    Pool: PoolTransactions + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            event = self.events.recv() => {
+                match event {
+                    Ok(event) => {
+                        self.monitor().on_event(&event, Instant::now());
+                        should_continue = true;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            "The inclusion monitor missed {} events of the pool",
+                            skipped
+                        );
+                        let pool = self.pool.pool_transactions();
+                        self.monitor().reconcile(&pool);
+                        should_continue = true;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        should_continue = false;
+                    }
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        Ok(())
+    }
+}
+
+pub fn new_service<Pool>(
+    config: Config,
+    events: broadcast::Receiver<PoolEvent>,
+    pool: Pool,
+) -> Service<Pool>
+where
+    Pool: PoolTransactions + 'static,
+{
+    Service::new(Task {
+        events,
+        pool,
+        shared: SharedState {
+            monitor: Arc::new(Mutex::new(InclusionMonitor::new(config))),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            min_gas_price: 10,
+            delay_threshold: Duration::from_secs(5),
+            max_tracked: 2,
+        }
+    }
+
+    fn inserted(id: u8, gas_price: Word) -> PoolEvent {
+        PoolEvent::Inserted {
+            tx_id: TxId::from([id; 32]),
+            gas_price,
+            max_gas: 0,
+            metered_bytes_size: 0,
+        }
+    }
+
+    fn committed(id: u8, height: u32) -> PoolEvent {
+        PoolEvent::Committed {
+            tx_id: TxId::from([id; 32]),
+            height: height.into(),
+        }
+    }
+
+    #[test]
+    fn inclusion_delays_are_aggregated() {
+        let start = Instant::now();
+        let mut monitor = InclusionMonitor::new(config());
+        monitor.on_event(&inserted(1, 10), start);
+        monitor.on_event(&inserted(2, 20), start);
+        // Underpriced transactions are not tracked
+        monitor.on_event(&inserted(3, 9), start);
+
+        monitor.on_event(&committed(1, 1), start + Duration::from_secs(2));
+        monitor.on_event(&committed(2, 2), start + Duration::from_secs(8));
+        monitor.on_event(&committed(3, 2), start + Duration::from_secs(8));
+
+        assert_eq!(
+            monitor.statistics(start + Duration::from_secs(8)),
+            InclusionStatistics {
+                pending: 0,
+                overdue: 0,
+                included: 2,
+                delayed: 1,
+                max_delay: Duration::from_secs(8),
+                average_delay: Duration::from_secs(5),
+            }
+        );
+        assert_eq!(
+            monitor.transaction(&TxId::from([2; 32]), start),
+            Some(TxInclusion::Included {
+                delay: Duration::from_secs(8),
+                height: 2u32.into(),
+                delayed: true,
+            })
+        );
+        assert_eq!(monitor.transaction(&TxId::from([3; 32]), start), None);
+    }
+
+    #[test]
+    fn pending_transactions_become_overdue() {
+        let start = Instant::now();
+        let mut monitor = InclusionMonitor::new(config());
+        monitor.on_event(&inserted(1, 10), start);
+        monitor.on_event(&inserted(2, 10), start + Duration::from_secs(3));
+
+        let now = start + Duration::from_secs(6);
+        let statistics = monitor.statistics(now);
+        assert_eq!(statistics.pending, 2);
+        assert_eq!(statistics.overdue, 1);
+        assert_eq!(
+            monitor.transaction(&TxId::from([1; 32]), now),
+            Some(TxInclusion::Pending {
+                delay: Duration::from_secs(6),
+                overdue: true,
+            })
+        );
+    }
+
+    #[test]
+    fn removed_and_oldest_transactions_are_forgotten() {
+        let start = Instant::now();
+        let mut monitor = InclusionMonitor::new(config());
+        monitor.on_event(&inserted(1, 10), start);
+        monitor.on_event(
+            &PoolEvent::Evicted {
+                tx_id: TxId::from([1; 32]),
+                reason: TxPoolError::Removed,
+            },
+            start,
+        );
+        monitor.on_event(&inserted(2, 10), start);
+        monitor.on_event(&inserted(3, 10), start);
+        monitor.on_event(&inserted(4, 10), start);
+
+        assert_eq!(monitor.transaction(&TxId::from([1; 32]), start), None);
+        assert_eq!(monitor.transaction(&TxId::from([2; 32]), start), None);
+        assert_eq!(monitor.statistics(start).pending, 2);
+
+        // The removed transaction is not counted as included
+        monitor.on_event(&committed(1, 1), start);
+        assert_eq!(monitor.statistics(start).included, 0);
+    }
+
+    #[test]
+    fn expired_and_replaced_transactions_stay_unresolved() {
+        let start = Instant::now();
+        let mut monitor = InclusionMonitor::new(config());
+        monitor.on_event(&inserted(1, 10), start);
+        monitor.on_event(&inserted(2, 10), start);
+        monitor.on_event(
+            &PoolEvent::Evicted {
+                tx_id: TxId::from([1; 32]),
+                reason: TxPoolError::TTLReason,
+            },
+            start + Duration::from_secs(1),
+        );
+        monitor.on_event(
+            &PoolEvent::Replaced {
+                tx_id: TxId::from([2; 32]),
+                by: TxId::from([3; 32]),
+            },
+            start + Duration::from_secs(2),
+        );
+        monitor.on_event(&inserted(3, 20), start + Duration::from_secs(2));
+
+        let now = start + Duration::from_secs(6);
+        assert_eq!(monitor.statistics(now).pending, 2);
+        assert_eq!(monitor.statistics(now).overdue, 2);
+        assert_eq!(
+            monitor.transaction(&TxId::from([1; 32]), now),
+            Some(TxInclusion::Pending {
+                delay: Duration::from_secs(6),
+                overdue: true,
+            })
+        );
+        assert_eq!(monitor.transaction(&TxId::from([2; 32]), now), None);
+
+        // The replacement waited since the insertion of the replaced transaction
+        monitor.on_event(&committed(3, 1), now);
+        assert_eq!(
+            monitor.transaction(&TxId::from([3; 32]), now),
+            Some(TxInclusion::Included {
+                delay: Duration::from_secs(6),
+                height: 1u32.into(),
+                delayed: true,
+            })
+        );
+    }
+
+    #[test]
+    fn missed_events_are_reconciled_with_pool() {
+        let start = Instant::now();
+        let mut monitor = InclusionMonitor::new(config());
+        monitor.on_event(&inserted(1, 10), start);
+        monitor.on_event(&inserted(2, 10), start);
+        monitor.on_event(
+            &PoolEvent::Evicted {
+                tx_id: TxId::from([2; 32]),
+                reason: TxPoolError::TTLReason,
+            },
+            start,
+        );
+
+        // The events of the transaction 1 leaving the pool and of the insertion of
+        // the transaction 3 are missed
+        monitor.reconcile(&[
+            PoolEntry {
+                tx_id: TxId::from([3; 32]),
+                gas_price: 10,
+                inserted_at: start + Duration::from_secs(1),
+            },
+            PoolEntry {
+                tx_id: TxId::from([4; 32]),
+                gas_price: 9,
+                inserted_at: start,
+            },
+        ]);
+
+        let now = start + Duration::from_secs(3);
+        assert_eq!(monitor.transaction(&TxId::from([1; 32]), now), None);
+        assert!(monitor.transaction(&TxId::from([2; 32]), now).is_some());
+        assert_eq!(
+            monitor.transaction(&TxId::from([3; 32]), now),
+            Some(TxInclusion::Pending {
+                delay: Duration::from_secs(2),
+                overdue: false,
+            })
+        );
+        assert_eq!(monitor.transaction(&TxId::from([4; 32]), now), None);
+    }
+}
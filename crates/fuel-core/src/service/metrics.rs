@@ -1,19 +1,36 @@
+use crate::fuel_core_graphql_api::service::NodeStatus;
 use axum::{
-    body::Body,
-    http::Request,
+    extract::Extension,
     response::IntoResponse,
 };
 #[cfg(feature = "metrics")]
-use fuel_core_metrics::service::encode_metrics_response;
+use fuel_core_metrics::{
+    service::encode_metrics_response,
+    services_metrics::{
+        ServicesMetrics,
+        SERVICES_METRICS,
+    },
+};
+#[cfg(feature = "metrics")]
+use fuel_core_services::Health;
 
-pub async fn metrics(_req: Request<Body>) -> impl IntoResponse {
+pub async fn metrics(node_status: Extension<NodeStatus>) -> impl IntoResponse {
     #[cfg(feature = "metrics")]
     {
+        for (name, health) in node_status.services_health() {
+            let value = match health {
+                Health::Healthy => ServicesMetrics::HEALTHY,
+                Health::Degraded(_) => ServicesMetrics::DEGRADED,
+                Health::Unhealthy(_) => ServicesMetrics::UNHEALTHY,
+            };
+            SERVICES_METRICS.set_health(name, value);
+        }
         encode_metrics_response()
     }
     #[cfg(not(feature = "metrics"))]
     {
         use axum::http::StatusCode;
+        let _ = node_status;
         (StatusCode::NOT_FOUND, "Metrics collection disabled")
     }
 }
@@ -0,0 +1,324 @@
+//! The diagnostic service periodically compares the content of the local transaction pool
+//! with pools of connected peers. A high divergence means that the gossiping of transactions
+//! is partitioned, and some producers may build blocks without transactions seen by others.
+
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::p2p::PoolSketch,
+};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+pub type Service<Pool, Network> = ServiceRunner<Task<Pool, Network>>;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// How often to sample pools of peers.
+    pub interval: Duration,
+    /// The maximum number of peers sampled per interval.
+    pub peers_per_sample: usize,
+    /// The maximum number of transactions in the sketch of the pool.
+    pub sketch_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            peers_per_sample: 3,
+            sketch_size: 1024,
+        }
+    }
+}
+
+/// The source of ids of pending transactions in the local pool.
+pub trait PendingTransactions: Send + Sync {
+    fn pending_ids(&self) -> Vec<TxId>;
+}
+
+/// The network that exchanges sketches of pools with peers.
+#[async_trait::async_trait]
+pub trait PoolSketchNetwork: Send + Sync {
+    /// Serves the sketch of the local pool to peers.
+    fn update_pool_sketch(&self, sketch: PoolSketch);
+
+    async fn peer_ids(&self) -> anyhow::Result<Vec<Vec<u8>>>;
+
+    async fn pool_sketch_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Option<PoolSketch>>;
+}
+
+/// The divergence of the local pool with the pool of the peer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeerDivergence {
+    pub peer_id: Vec<u8>,
+    pub divergence: f64,
+}
+
+pub struct Task<Pool, Network> {
+    config: Config,
+    pool: Pool,
+    network: Network,
+    timer: tokio::time::Interval,
+    /// The index of the next peer to sample, so all peers are sampled in turn.
+    next_peer: usize,
+}
+
+impl<Pool, Network> Task<Pool, Network>
+where
+    Pool: PendingTransactions,
+    Network: PoolSketchNetwork,
+{
+    /// Publishes the sketch of the local pool and compares it with sketches of sampled peers.
+    pub async fn sample(&mut self) -> anyhow::Result<Vec<PeerDivergence>> {
+        let ids = self.pool.pending_ids();
+        let local = PoolSketch::new(ids.iter(), self.config.sketch_size);
+        self.network.update_pool_sketch(local.clone());
+
+        let peers = self.network.peer_ids().await?;
+        if peers.is_empty() {
+            return Ok(vec![])
+        }
+
+        let number_of_peers = self.config.peers_per_sample.min(peers.len());
+        let mut divergences = Vec::with_capacity(number_of_peers);
+        for i in 0..number_of_peers {
+            let peer_id = peers[(self.next_peer + i) % peers.len()].clone();
+            match self.network.pool_sketch_from_peer(peer_id.clone()).await {
+                Ok(Some(remote)) => {
+                    let divergence = local.divergence(&remote);
+                    #[cfg(feature = "metrics")]
+                    fuel_core_metrics::txpool_metrics::TXPOOL_METRICS
+                        .pool_divergence_histogram
+                        .observe(divergence);
+                    divergences.push(PeerDivergence {
+                        peer_id,
+                        divergence,
+                    });
+                }
+                Ok(None) => {
+                    tracing::debug!("The peer {:?} didn't share the pool sketch", peer_id);
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Failed to request the pool sketch from {:?}: {}",
+                        peer_id,
+                        e
+                    );
+                }
+            }
+        }
+        self.next_peer = (self.next_peer + number_of_peers) % peers.len();
+
+        for PeerDivergence {
+            peer_id,
+            divergence,
+        } in divergences.iter()
+        {
+            tracing::info!(
+                "The pool diverges from the pool of the peer {:?} by {:.2}",
+                peer_id,
+                divergence
+            );
+        }
+        Ok(divergences)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Pool, Network> RunnableService for Task<Pool, Network>
+where
+    Pool: PendingTransactions + 'static,
+    Network: PoolSketchNetwork + 'static,
+{
+    const NAME: &'static str = "PoolDivergence";
+
+    type SharedData = ();
+    type Task = Task<Pool, Network>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.timer.reset();
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Pool, Network> RunnableTask for Task<Pool, Network>
+where
+    Pool: PendingTransactions + 'static,
+    Network: PoolSketchNetwork + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.timer.tick() => {
+                // The diagnostic is best effort, so failures don't stop the service.
+                if let Err(e) = self.sample().await {
+                    tracing::warn!("Failed to sample pools of peers: {}", e);
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        Ok(())
+    }
+}
+
+pub fn new_service<Pool, Network>(
+    config: Config,
+    pool: Pool,
+    network: Network,
+) -> Service<Pool, Network>
+where
+    Pool: PendingTransactions + 'static,
+    Network: PoolSketchNetwork + 'static,
+{
+    let mut timer = tokio::time::interval(config.interval);
+    timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    Service::new(Task {
+        config,
+        pool,
+        network,
+        timer,
+        next_peer: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        collections::HashMap,
+        sync::{
+            Arc,
+            Mutex,
+        },
+    };
+
+    struct FakePool(Vec<TxId>);
+
+    impl PendingTransactions for FakePool {
+        fn pending_ids(&self) -> Vec<TxId> {
+            self.0.clone()
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeNetwork {
+        published: Arc<Mutex<Option<PoolSketch>>>,
+        peers: HashMap<Vec<u8>, Vec<TxId>>,
+    }
+
+    #[async_trait::async_trait]
+    impl PoolSketchNetwork for FakeNetwork {
+        fn update_pool_sketch(&self, sketch: PoolSketch) {
+            *self.published.lock().unwrap() = Some(sketch);
+        }
+
+        async fn peer_ids(&self) -> anyhow::Result<Vec<Vec<u8>>> {
+            let mut peers: Vec<_> = self.peers.keys().cloned().collect();
+            peers.sort();
+            Ok(peers)
+        }
+
+        async fn pool_sketch_from_peer(
+            &self,
+            peer_id: Vec<u8>,
+        ) -> anyhow::Result<Option<PoolSketch>> {
+            Ok(self
+                .peers
+                .get(&peer_id)
+                .map(|ids| PoolSketch::new(ids.iter(), usize::MAX)))
+        }
+    }
+
+    fn ids(range: std::ops::Range<u8>) -> Vec<TxId> {
+        range.map(|i| TxId::from([i; 32])).collect()
+    }
+
+    fn task(
+        pool: Vec<TxId>,
+        peers: HashMap<Vec<u8>, Vec<TxId>>,
+        peers_per_sample: usize,
+    ) -> Task<FakePool, FakeNetwork> {
+        Task {
+            config: Config {
+                peers_per_sample,
+                ..Default::default()
+            },
+            pool: FakePool(pool),
+            network: FakeNetwork {
+                peers,
+                ..Default::default()
+            },
+            timer: tokio::time::interval(Duration::from_secs(1)),
+            next_peer: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn sample_publishes_local_sketch() {
+        let mut task = task(ids(0..4), HashMap::new(), 1);
+
+        let divergences = task.sample().await.unwrap();
+
+        assert!(divergences.is_empty());
+        assert_eq!(
+            task.network.published.lock().unwrap().clone(),
+            Some(PoolSketch::new(ids(0..4).iter(), usize::MAX))
+        );
+    }
+
+    #[tokio::test]
+    async fn sample_reports_divergence_with_peers() {
+        let peers = HashMap::from([
+            (vec![1], ids(0..4)),
+            (vec![2], ids(2..6)),
+            (vec![3], ids(10..14)),
+        ]);
+        let mut task = task(ids(0..4), peers, 3);
+
+        let divergences = task.sample().await.unwrap();
+
+        let divergences: Vec<_> = divergences.into_iter().map(|d| d.divergence).collect();
+        assert_eq!(divergences, vec![0.0, 4.0 / 6.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn sample_rotates_sampled_peers() {
+        let peers = HashMap::from([(vec![1], ids(0..1)), (vec![2], ids(0..1))]);
+        let mut task = task(ids(0..1), peers, 1);
+
+        let first = task.sample().await.unwrap();
+        let second = task.sample().await.unwrap();
+
+        assert_eq!(first[0].peer_id, vec![1]);
+        assert_eq!(second[0].peer_id, vec![2]);
+    }
+}
@@ -0,0 +1,243 @@
+//! Loads the latest blocks, the code of contracts called by them and coins of busy
+//! owners before the node starts serving the API. The reads fill the caches of the
+//! database, so the first requests after the restart of the node don't pay for the
+//! cold start.
+
+use crate::database::Database;
+use fuel_core_storage::{
+    not_found,
+    tables::{
+        ContractsRawCode,
+        FuelBlocks,
+        Transactions,
+    },
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        field::Inputs,
+        input::contract::Contract,
+        Input,
+        Transaction,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ContractId,
+    },
+};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The number of the latest blocks whose headers and transactions are loaded.
+    /// The code of contracts called by these transactions is loaded too.
+    pub blocks: u32,
+    /// The owners whose coins are loaded.
+    pub owners: Vec<Address>,
+    /// The maximum number of coins loaded per owner.
+    pub coins_per_owner: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            blocks: 100,
+            owners: vec![],
+            coins_per_owner: 100,
+        }
+    }
+}
+
+/// The number of entries loaded by [`prewarm`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub blocks: usize,
+    pub transactions: usize,
+    pub contracts: usize,
+    pub coins: usize,
+}
+
+/// Reads the entries described by the `config`, so the following reads of these
+/// entries hit the caches.
+pub fn prewarm(database: &Database, config: &Config) -> StorageResult<Stats> {
+    let mut stats = Stats::default();
+    let mut contracts = BTreeSet::new();
+
+    let latest_height = match database.ids_of_latest_block()? {
+        Some((height, _)) => *height,
+        None => return Ok(stats),
+    };
+    let first_height = latest_height
+        .saturating_add(1)
+        .saturating_sub(config.blocks);
+    for height in (first_height..=latest_height).rev() {
+        let block_id = match database.get_block_id(&BlockHeight::from(height))? {
+            Some(block_id) => block_id,
+            None => continue,
+        };
+        let block = database
+            .storage::<FuelBlocks>()
+            .get(&block_id)?
+            .ok_or(not_found!(FuelBlocks))?;
+        stats.blocks += 1;
+
+        for tx_id in block.transactions() {
+            // Bodies of pruned blocks are not in the database anymore
+            if let Some(tx) = database.storage::<Transactions>().get(tx_id)? {
+                stats.transactions += 1;
+                contracts.extend(called_contracts(&tx));
+            }
+        }
+    }
+
+    for contract_id in contracts {
+        if database
+            .storage::<ContractsRawCode>()
+            .get(&contract_id)?
+            .is_some()
+        {
+            stats.contracts += 1;
+        }
+    }
+
+    for owner in &config.owners {
+        for utxo_id in database
+            .owned_coins_ids(owner, None, None)
+            .take(config.coins_per_owner)
+        {
+            database.coin(&utxo_id?)?;
+            stats.coins += 1;
+        }
+    }
+    Ok(stats)
+}
+
+fn called_contracts(tx: &Transaction) -> impl Iterator<Item = ContractId> + '_ {
+    let inputs = match tx {
+        Transaction::Script(script) => script.inputs().as_slice(),
+        Transaction::Create(create) => create.inputs().as_slice(),
+        Transaction::Mint(_) => &[],
+    };
+    inputs.iter().filter_map(|input| match input {
+        Input::Contract(Contract { contract_id, .. }) => Some(*contract_id),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::Coins,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::{
+            ConsensusParameters,
+            Contract as ContractCode,
+            TransactionBuilder,
+            UniqueIdentifier,
+            UtxoId,
+        },
+    };
+
+    fn insert_block(database: &mut Database, height: u32, tx: Transaction) {
+        let params = ConsensusParameters::DEFAULT;
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress(&params))
+            .unwrap();
+        database
+            .storage::<Transactions>()
+            .insert(&tx.id(&params), &tx)
+            .unwrap();
+    }
+
+    fn contract_call(contract_id: ContractId) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            ))
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn prewarm_loads_latest_blocks_called_contracts_and_owned_coins() {
+        let mut database = Database::default();
+        let old_contract = ContractId::from([1; 32]);
+        let hot_contract = ContractId::from([2; 32]);
+        for contract_id in [old_contract, hot_contract] {
+            database
+                .storage::<ContractsRawCode>()
+                .insert(&contract_id, ContractCode::from(vec![32u8]).as_ref())
+                .unwrap();
+        }
+        insert_block(&mut database, 0, contract_call(old_contract));
+        insert_block(&mut database, 1, contract_call(hot_contract));
+        insert_block(&mut database, 2, Transaction::default());
+
+        let owner = Address::from([3; 32]);
+        for index in 0..3 {
+            let coin = CompressedCoin {
+                owner,
+                amount: 10,
+                asset_id: Default::default(),
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            };
+            database
+                .storage::<Coins>()
+                .insert(&UtxoId::new(Default::default(), index), &coin)
+                .unwrap();
+        }
+
+        let stats = prewarm(
+            &database,
+            &Config {
+                blocks: 2,
+                owners: vec![owner],
+                coins_per_owner: 2,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            stats,
+            Stats {
+                blocks: 2,
+                transactions: 2,
+                contracts: 1,
+                coins: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn prewarm_of_empty_database_loads_nothing() {
+        let stats = prewarm(&Database::default(), &Config::default()).unwrap();
+        assert_eq!(stats, Stats::default());
+    }
+}
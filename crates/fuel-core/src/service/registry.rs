@@ -0,0 +1,254 @@
+//! The registry of sub-services of the `FuelService`. It allows operators to inspect and
+//! control sub-services at runtime via the admin API.
+
+use anyhow::anyhow;
+use fuel_core_services::{
+    BoxedService,
+    ConfigUpdate,
+    Health,
+    Service,
+    ServiceError,
+    Shared,
+    SharedMutex,
+    State,
+    StateTransition,
+};
+use serde::Serialize;
+
+/// The sub-service shared between the orchestrator and the registry.
+pub type RegisteredService = Shared<dyn Service + Send + Sync + 'static>;
+
+/// Tracks sub-services by their names. It can be cloned and shared with other services.
+#[derive(Clone)]
+pub struct ServiceRegistry(SharedMutex<Vec<(&'static str, RegisteredService)>>);
+
+impl Default for ServiceRegistry {
+    fn default() -> Self {
+        Self(SharedMutex::new(vec![]))
+    }
+}
+
+/// The snapshot of the lifecycle of the sub-service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ServiceInfo {
+    pub name: &'static str,
+    /// The current state, e.g. `Started`.
+    pub state: String,
+    /// The error if the service stopped with an error.
+    pub error: Option<String>,
+    pub healthy: bool,
+    /// Whether the service is started and reported readiness.
+    pub ready: bool,
+    /// Why the service is not healthy.
+    pub reason: Option<String>,
+    /// The latest transitions of the state as `(previous, new)` pairs, from the oldest to
+    /// the newest.
+    pub history: Vec<(String, String)>,
+}
+
+impl ServiceInfo {
+    fn new(name: &'static str, service: &RegisteredService) -> Self {
+        let state = service.state();
+        let error = match &state {
+            State::StoppedWithError(error) => Some(error.clone()),
+            _ => None,
+        };
+        let (healthy, reason) = match service.health() {
+            Health::Healthy => (true, None),
+            Health::Degraded(reason) | Health::Unhealthy(reason) => (false, Some(reason)),
+        };
+        let history = service
+            .state_watcher()
+            .history()
+            .into_iter()
+            .map(|StateTransition { previous, new }| {
+                (state_name(&previous), state_name(&new))
+            })
+            .collect();
+        Self {
+            name,
+            state: state_name(&state),
+            error,
+            healthy,
+            ready: service.is_ready(),
+            reason,
+            history,
+        }
+    }
+}
+
+fn state_name(state: &State) -> String {
+    match state {
+        State::StoppedWithError(_) => "StoppedWithError".to_string(),
+        state => format!("{state:?}"),
+    }
+}
+
+impl ServiceRegistry {
+    /// Registers the `service` under the `name` and returns it for the orchestrator.
+    /// Returns an error if another service is registered under the same `name`.
+    pub fn register<S>(
+        &self,
+        name: &'static str,
+        service: S,
+    ) -> anyhow::Result<BoxedService>
+    where
+        S: Service + Send + Sync + 'static,
+    {
+        let service: RegisteredService = Shared::new(service);
+        self.0.apply(|services| {
+            if services.iter().any(|(registered, _)| *registered == name) {
+                return Err(anyhow!("The service `{name}` is already registered"))
+            }
+            services.push((name, service.clone()));
+            Ok(())
+        })?;
+        Ok(Box::new(service))
+    }
+
+    /// Returns the lifecycle of every sub-service in the order of the registration.
+    pub fn list(&self) -> Vec<ServiceInfo> {
+        let services = self.0.apply(|services| services.clone());
+        services
+            .iter()
+            .map(|(name, service)| ServiceInfo::new(name, service))
+            .collect()
+    }
+
+    /// Returns the errors that stopped sub-services in the order of the registration.
+    pub fn errors(&self) -> Vec<(&'static str, ServiceError)> {
+        let services = self.0.apply(|services| services.clone());
+        services
+            .iter()
+            .filter_map(|(name, service)| Some((*name, service.error()?)))
+            .collect()
+    }
+
+    /// Returns names of sub-services that are not ready in the order of the registration.
+    pub fn not_ready(&self) -> Vec<&'static str> {
+        let services = self.0.apply(|services| services.clone());
+        services
+            .iter()
+            .filter(|(_, service)| !service.is_ready())
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// Waits until all sub-services registered before the call are ready. Returns an
+    /// error if any of them stops before it becomes ready.
+    pub async fn await_ready(&self) -> anyhow::Result<()> {
+        let services = self.0.apply(|services| services.clone());
+        for (name, service) in services {
+            service
+                .await_ready()
+                .await
+                .map_err(|e| anyhow!("The service `{name}` is not ready: {e}"))?;
+        }
+        Ok(())
+    }
+
+    /// Applies the `update` of the configuration to all sub-services that are not
+    /// stopped. Nothing is applied unless every service accepts the update and every
+    /// field of the update is applied by some service.
+    pub fn reload(&self, update: &ConfigUpdate) -> anyhow::Result<()> {
+        self.validate_reload(update)?;
+        self.apply_reload(update);
+        Ok(())
+    }
+
+    /// Checks the `update` of the configuration against all sub-services that are not
+    /// stopped. All rejections, including fields that no service can reload, are
+    /// returned as one error.
+    pub fn validate_reload(&self, update: &ConfigUpdate) -> anyhow::Result<()> {
+        let services = self.0.apply(|services| services.clone());
+        let mut errors = vec![];
+        let mut unhandled = update.clone();
+        for (name, service) in services
+            .iter()
+            .filter(|(_, service)| !service.state().stopped())
+        {
+            match service.validate_reload(update) {
+                Ok(handled) => unhandled = unhandled.without(&handled),
+                Err(error) => errors
+                    .push(format!("The service `{name}` rejected the update: {error}")),
+            }
+        }
+        for field in unhandled.fields() {
+            errors.push(format!("The `{field}` can't be reloaded"));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(errors.join("; ")))
+        }
+    }
+
+    /// Applies the `update` validated by [`Self::validate_reload`] to all sub-services
+    /// that are not stopped.
+    pub fn apply_reload(&self, update: &ConfigUpdate) {
+        let services = self.0.apply(|services| services.clone());
+        for (_, service) in services
+            .iter()
+            .filter(|(_, service)| !service.state().stopped())
+        {
+            service.reload(update);
+        }
+    }
+
+    /// Returns the lifecycle of the sub-service with the `name`.
+    pub fn inspect(&self, name: &str) -> Option<ServiceInfo> {
+        self.get(name)
+            .map(|(name, service)| ServiceInfo::new(name, &service))
+    }
+
+    /// Sends the stop signal to the sub-service without waiting for it to stop. The
+    /// `FuelService` stops when any of its sub-services stops, so it stops the whole
+    /// node. Returns `false` if the service was already stopped.
+    pub fn stop(&self, name: &str) -> anyhow::Result<bool> {
+        Ok(self.get_or_err(name)?.1.stop())
+    }
+
+    /// Pauses the sub-service. It keeps its shared state, so dependent services continue
+    /// to work with it.
+    pub fn pause(&self, name: &str) -> anyhow::Result<()> {
+        self.get_or_err(name)?.1.pause()
+    }
+
+    /// Resumes the paused sub-service.
+    pub fn resume(&self, name: &str) -> anyhow::Result<()> {
+        self.get_or_err(name)?.1.resume()
+    }
+
+    /// Replaces the task of the started or paused sub-service with a fresh one. The
+    /// service keeps its shared state, so dependent services continue to work with it.
+    /// Only services built with the restart policy can be restarted. The stopped service
+    /// can't be restarted, because it is consumed by its task, so the whole node should
+    /// be restarted instead.
+    pub fn restart(&self, name: &str) -> anyhow::Result<()> {
+        let (name, service) = self.get_or_err(name)?;
+        if service.state().stopped() {
+            return Err(anyhow!(
+                "The service `{name}` is stopped and can't be restarted without the \
+                restart of the node"
+            ))
+        }
+        service.restart()
+    }
+
+    fn get(&self, name: &str) -> Option<(&'static str, RegisteredService)> {
+        self.0.apply(|services| {
+            services
+                .iter()
+                .find(|(registered, _)| *registered == name)
+                .cloned()
+        })
+    }
+
+    fn get_or_err(
+        &self,
+        name: &str,
+    ) -> anyhow::Result<(&'static str, RegisteredService)> {
+        self.get(name)
+            .ok_or_else(|| anyhow!("The service `{name}` is not registered"))
+    }
+}
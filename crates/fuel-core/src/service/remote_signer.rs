@@ -0,0 +1,221 @@
+//! The signer of blocks backed by the remote signing service, e.g. the proxy in front of
+//! the KMS or the HSM, so the consensus key never enters the memory of the node.
+//!
+//! The node sends the `POST` request with the JSON body `{"address":"..","message":".."}`
+//! and expects `{"signature":".."}` in response, all values are hex-encoded. Requests to
+//! sign blocks also carry the `header` of the block, so the signing service can check
+//! the block before it signs the message, the id of the header. The signature is checked
+//! against the configured address before it seals the block.
+
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_poa::ports::BlockSigner;
+use fuel_core_types::{
+    blockchain::{
+        consensus::{
+            poa::PoASealScheme,
+            seal::SealScheme,
+        },
+        header::BlockHeader,
+    },
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_types::Address,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The URL of the signing endpoint.
+    pub url: String,
+    /// The address of the consensus key held by the signing service.
+    pub address: Address,
+    /// The time to wait for the signature. The block production is blocked meanwhile.
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Serialize)]
+struct SignRequest<'a> {
+    address: String,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    header: Option<&'a BlockHeader>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    config: Config,
+}
+
+impl RemoteSigner {
+    pub fn new(config: Config) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config,
+        }
+    }
+
+    async fn request(
+        &self,
+        message: &Message,
+        header: Option<&BlockHeader>,
+    ) -> anyhow::Result<Signature> {
+        let request = SignRequest {
+            address: hex::encode(self.config.address),
+            message: hex::encode(&message[..]),
+            header,
+        };
+        let body = self
+            .client
+            .post(&self.config.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&request)?)
+            .timeout(self.config.timeout)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let response: SignResponse = serde_json::from_slice(&body)?;
+
+        let bytes: [u8; 64] = hex::decode(response.signature.trim_start_matches("0x"))?
+            .try_into()
+            .map_err(|_| anyhow!("The remote signer returned the malformed signature"))?;
+        let signature = Signature::from_bytes(bytes);
+        let signer = PoASealScheme::signer(&signature, message)?;
+        ensure!(
+            signer == self.config.address,
+            "The remote signer signed with the key of {} instead of {}",
+            signer,
+            self.config.address
+        );
+        Ok(signature)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockSigner for RemoteSigner {
+    fn address(&self) -> Address {
+        self.config.address
+    }
+
+    async fn sign(&self, message: &Message) -> anyhow::Result<Signature> {
+        self.request(message, None).await
+    }
+
+    async fn sign_block(&self, header: &BlockHeader) -> anyhow::Result<Signature> {
+        let block_id = header.id();
+        self.request(block_id.as_message(), Some(header)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        routing::post,
+        Json,
+        Router,
+    };
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        fuel_tx::Input,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::net::TcpListener;
+
+    /// Starts the signing service with the `secret` and returns its URL. The service
+    /// refuses to sign blocks that don't match the header sent along with them.
+    fn serve(secret: SecretKey) -> String {
+        let router = Router::new().route(
+            "/sign",
+            post(move |Json(request): Json<serde_json::Value>| async move {
+                let message: [u8; 32] = hex::decode(request["message"].as_str().unwrap())
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                let message = Message::from_bytes(message);
+                if let Some(header) = request.get("header") {
+                    let mut header: BlockHeader =
+                        serde_json::from_value(header.clone()).unwrap();
+                    header.recalculate_metadata();
+                    if header.id().as_message() != &message {
+                        return Json(serde_json::json!({}))
+                    }
+                }
+                let signature = Signature::sign(&secret, &message);
+                Json(serde_json::json!({ "signature": hex::encode(&signature[..]) }))
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service());
+        tokio::spawn(server);
+        format!("http://{addr}/sign")
+    }
+
+    #[tokio::test]
+    async fn signature_of_remote_signer_is_checked_against_address() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let address = Input::owner(&secret.public_key());
+        let url = serve(secret);
+        let message = Message::new(b"block");
+
+        let signer = RemoteSigner::new(Config {
+            url: url.clone(),
+            address,
+            timeout: Duration::from_secs(5),
+        });
+        let signature = signer.sign(&message).await.unwrap();
+        let impostor = RemoteSigner::new(Config {
+            url,
+            address: Address::from([1; 32]),
+            timeout: Duration::from_secs(5),
+        });
+
+        assert_eq!(Input::owner(&signature.recover(&message).unwrap()), address);
+        assert!(impostor.sign(&message).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn remote_signer_receives_header_of_signed_block() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let address = Input::owner(&secret.public_key());
+        let signer = RemoteSigner::new(Config {
+            url: serve(secret),
+            address,
+            timeout: Duration::from_secs(5),
+        });
+        let mut header = BlockHeader::default();
+        header.consensus.height = 1u32.into();
+        header.recalculate_metadata();
+
+        let signature = signer.sign_block(&header).await.unwrap();
+
+        let block_id = header.id();
+        assert_eq!(
+            Input::owner(&signature.recover(block_id.as_message()).unwrap()),
+            address
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! The dedicated runtime for CPU-heavy sub-services. The execution of blocks by the block
+//! production and the import of blocks from the network can occupy worker threads for a
+//! long time, so running them on the separate runtime prevents them from starving the
+//! P2P and the GraphQL API.
+
+use tokio::runtime::{
+    Builder,
+    Handle,
+    Runtime,
+};
+
+pub struct ExecutionRuntime(Option<Runtime>);
+
+impl ExecutionRuntime {
+    /// Creates the runtime with the `threads` worker threads.
+    pub fn new(threads: usize) -> anyhow::Result<Self> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(threads.max(1))
+            .thread_name("fuel-core-execution")
+            .enable_all()
+            .build()?;
+        Ok(Self(Some(runtime)))
+    }
+
+    pub fn handle(&self) -> Handle {
+        self.0
+            .as_ref()
+            .expect("The runtime is only taken on drop")
+            .handle()
+            .clone()
+    }
+}
+
+impl Drop for ExecutionRuntime {
+    fn drop(&mut self) {
+        // The runtime can't be dropped in the asynchronous context, and sub-services are
+        // already stopped at this point.
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
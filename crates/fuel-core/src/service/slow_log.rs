@@ -0,0 +1,249 @@
+//! The log of slow operations. GraphQL requests and commits of storage transactions
+//! that take longer than the configured thresholds are recorded into the ring buffer,
+//! so operators can find pathological queries of the production node via the admin
+//! API instead of guessing.
+
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    future::Future,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+tokio::task_local! {
+    /// The number of rows read by the task from the storage.
+    static ROWS: Arc<AtomicUsize>;
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// GraphQL requests taking longer are recorded.
+    pub graphql_threshold: Duration,
+    /// Commits of storage transactions taking longer are recorded.
+    pub storage_threshold: Duration,
+    /// The maximum number of recorded operations. The oldest operations are forgotten
+    /// first.
+    pub capacity: usize,
+    /// Logs recorded operations with the `warn` level.
+    pub log: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            graphql_threshold: Duration::from_secs(1),
+            storage_threshold: Duration::from_millis(500),
+            capacity: 1000,
+            log: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Graphql,
+    Storage,
+}
+
+/// The operation that exceeded the threshold.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SlowOperation {
+    pub kind: OperationKind,
+    /// The name of the GraphQL operation or its query, or the columns of the storage
+    /// transaction.
+    pub operation: String,
+    /// The hash of variables of the GraphQL request, so the same slow request can be
+    /// recognized without exposing its variables.
+    pub variables_hash: Option<String>,
+    #[serde(rename = "duration_ms", serialize_with = "as_millis")]
+    pub duration: Duration,
+    /// The number of rows read by the GraphQL request or written by the storage
+    /// transaction.
+    pub rows: usize,
+    /// The UNIX timestamp in seconds of the end of the operation.
+    pub timestamp: u64,
+}
+
+fn as_millis<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_millis())
+}
+
+/// Records slow operations. It can be cloned and shared with other services.
+#[derive(Clone, Debug)]
+pub struct SlowLog {
+    config: Config,
+    operations: Arc<Mutex<VecDeque<SlowOperation>>>,
+}
+
+impl SlowLog {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            operations: Default::default(),
+        }
+    }
+
+    pub fn graphql_threshold(&self) -> Duration {
+        self.config.graphql_threshold
+    }
+
+    pub fn storage_threshold(&self) -> Duration {
+        self.config.storage_threshold
+    }
+
+    /// Records the `operation` if it took longer than the threshold of its kind.
+    pub fn record(
+        &self,
+        kind: OperationKind,
+        operation: String,
+        variables_hash: Option<String>,
+        duration: Duration,
+        rows: usize,
+    ) {
+        let threshold = match kind {
+            OperationKind::Graphql => self.config.graphql_threshold,
+            OperationKind::Storage => self.config.storage_threshold,
+        };
+        if duration < threshold {
+            return
+        }
+        if self.config.log {
+            tracing::warn!(
+                "The slow {:?} operation `{}` took {:?} and touched {} rows",
+                kind,
+                operation,
+                duration,
+                rows
+            );
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since_epoch| since_epoch.as_secs())
+            .unwrap_or_default();
+        let mut operations = self.operations.lock().expect("poisoned");
+        operations.push_back(SlowOperation {
+            kind,
+            operation,
+            variables_hash,
+            duration,
+            rows,
+            timestamp,
+        });
+        while operations.len() > self.config.capacity {
+            operations.pop_front();
+        }
+    }
+
+    /// Returns recorded operations from the oldest to the newest.
+    pub fn operations(&self) -> Vec<SlowOperation> {
+        self.operations
+            .lock()
+            .expect("poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Runs the `future` and returns its output with the number of rows it read from the
+/// storage. Reads of tasks spawned by the `future` are not counted.
+pub async fn count_rows<F: Future>(future: F) -> (F::Output, usize) {
+    let rows = Arc::new(AtomicUsize::new(0));
+    let output = ROWS.scope(rows.clone(), future).await;
+    (output, rows.load(Ordering::Relaxed))
+}
+
+/// Adds `rows` to the number of rows read by the current task, if it is counted.
+pub(crate) fn add_rows(rows: usize) {
+    let _ = ROWS.try_with(|counter| counter.fetch_add(rows, Ordering::Relaxed));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slow_log(capacity: usize) -> SlowLog {
+        SlowLog::new(Config {
+            graphql_threshold: Duration::from_millis(100),
+            storage_threshold: Duration::from_millis(10),
+            capacity,
+            log: false,
+        })
+    }
+
+    #[test]
+    fn only_operations_above_threshold_are_recorded() {
+        let slow_log = slow_log(10);
+
+        slow_log.record(
+            OperationKind::Graphql,
+            "fast".to_string(),
+            None,
+            Duration::from_millis(50),
+            1,
+        );
+        slow_log.record(
+            OperationKind::Storage,
+            "slow".to_string(),
+            None,
+            Duration::from_millis(50),
+            2,
+        );
+
+        let operations = slow_log.operations();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].operation, "slow");
+        assert_eq!(operations[0].rows, 2);
+    }
+
+    #[test]
+    fn oldest_operations_are_forgotten() {
+        let slow_log = slow_log(2);
+
+        for operation in ["first", "second", "third"] {
+            slow_log.record(
+                OperationKind::Storage,
+                operation.to_string(),
+                None,
+                Duration::from_secs(1),
+                0,
+            );
+        }
+
+        let operations: Vec<_> = slow_log
+            .operations()
+            .into_iter()
+            .map(|operation| operation.operation)
+            .collect();
+        assert_eq!(operations, vec!["second", "third"]);
+    }
+
+    #[tokio::test]
+    async fn rows_are_counted_within_the_task() {
+        add_rows(5);
+        let ((), rows) = count_rows(async {
+            add_rows(2);
+            tokio::task::yield_now().await;
+            add_rows(3);
+        })
+        .await;
+        assert_eq!(rows, 5);
+    }
+}
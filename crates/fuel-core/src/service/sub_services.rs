@@ -3,24 +3,34 @@ use super::adapters::P2PAdapter;
 
 use crate::{
     database::Database,
-    fuel_core_graphql_api::Config as GraphQLConfig,
+    fuel_core_graphql_api::{
+        allowlist::QueryAllowlist,
+        Config as GraphQLConfig,
+    },
     schema::build_schema,
     service::{
         adapters::{
             BlockImporterAdapter,
             BlockProducerAdapter,
             ExecutorAdapter,
+            InclusionMonitorAdapter,
             MaybeRelayerAdapter,
+            NodeStatusAdapter,
             PoAAdapter,
+            SubmittedBlockAdapter,
             TxPoolAdapter,
+            UtxoStatisticsAdapter,
             VerifierAdapter,
         },
+        registry::ServiceRegistry,
+        slow_log::SlowLog,
         Config,
         SharedState,
-        SubServices,
     },
 };
 use fuel_core_poa::Trigger;
+use fuel_core_services::ServiceOrchestrator;
+use fuel_core_txpool::TxTimeline;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -36,7 +46,9 @@ pub type GraphQL = crate::fuel_core_graphql_api::service::Service;
 pub fn init_sub_services(
     config: &Config,
     database: &Database,
-) -> anyhow::Result<(SubServices, SharedState)> {
+    execution_runtime: Option<tokio::runtime::Handle>,
+    slow_log: Option<SlowLog>,
+) -> anyhow::Result<(ServiceOrchestrator, SharedState)> {
     let last_block = database.get_current_block()?.ok_or(anyhow::anyhow!(
         "The blockchain is not initialized with any block"
     ))?;
@@ -61,6 +73,7 @@ pub fn init_sub_services(
     let executor = ExecutorAdapter {
         relayer: relayer_adapter.clone(),
         config: config.clone(),
+        tx_timeline: TxTimeline::disabled(),
     };
 
     let verifier =
@@ -98,25 +111,59 @@ pub fn init_sub_services(
 
     let p2p_adapter = p2p_adapter;
 
+    // The txpool checks transactions with the same fee model as the block producer and
+    // the executor.
+    let txpool_config = fuel_core_txpool::Config {
+        chain_config: config.chain_conf.clone(),
+        ..config.txpool.clone()
+    };
     let txpool = fuel_core_txpool::new_service(
-        config.txpool.clone(),
+        txpool_config,
         database.clone(),
         importer_adapter.clone(),
         p2p_adapter.clone(),
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
+    #[cfg(feature = "p2p")]
+    let pool_divergence = config
+        .pool_divergence
+        .clone()
+        .filter(|_| network.is_some())
+        .map(|pool_divergence_config| {
+            crate::service::pool_divergence::new_service(
+                pool_divergence_config,
+                tx_pool_adapter.clone(),
+                p2p_adapter.clone(),
+            )
+        });
+
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         db: database.clone(),
         txpool: Box::new(tx_pool_adapter.clone()),
-        executor: Arc::new(executor),
+        tx_selector: config.block_producer.tx_ordering.selector(),
+        executor: Arc::new(ExecutorAdapter {
+            tx_timeline: txpool.shared.tx_timeline(),
+            ..executor
+        }),
         relayer: Box::new(relayer_adapter),
         lock: Mutex::new(()),
+        speculation: Default::default(),
     };
     let producer_adapter = BlockProducerAdapter::new(block_producer);
 
-    let poa_config: fuel_core_poa::Config = config.try_into()?;
+    let mut poa_config: fuel_core_poa::Config = config.try_into()?;
+    poa_config.runtime = execution_runtime.clone();
+    poa_config.p2p = Some(Arc::new(p2p_adapter.clone()));
+    // The adjusted gas limit continues from the limit before the restart.
+    if let (Some(adjustment), Some(gas_limit)) = (
+        &poa_config.gas_limit_adjustment,
+        database.get_block_gas_limit()?,
+    ) {
+        poa_config.block_gas_limit =
+            gas_limit.clamp(adjustment.min_gas_limit, adjustment.max_gas_limit);
+    }
     let production_enabled =
         !matches!(poa_config.trigger, Trigger::Never) || config.manual_blocks_enabled;
     let poa = (production_enabled).then(|| {
@@ -137,12 +184,74 @@ pub fn init_sub_services(
                 *last_block.header().height(),
                 p2p_adapter,
                 importer_adapter.clone(),
-                verifier,
+                verifier.clone(),
                 config.sync,
+                execution_runtime,
             )
         })
         .transpose()?;
 
+    // `FuelService` starts sub-services after their dependencies and shutdowns them
+    // in the reverse order, so GraphQL is shutdown first.
+    let mut services = ServiceOrchestrator::new();
+    let registry = ServiceRegistry::default();
+
+    #[cfg(feature = "version-check")]
+    let version_check = match config.version_check.clone() {
+        Some(version_check_config) => {
+            let (_, genesis_id) = database.ids_of_genesis_block()?;
+            Some(crate::service::version_check::new_service(
+                version_check_config,
+                genesis_id,
+            ))
+        }
+        None => None,
+    };
+
+    let node_status = NodeStatusAdapter {
+        database: database.clone(),
+        services_health: services.health(),
+        #[cfg(feature = "relayer")]
+        relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
+        #[cfg(feature = "p2p")]
+        network: network.as_ref().map(|n| n.shared.clone()),
+        #[cfg(feature = "p2p")]
+        sync: sync.as_ref().map(|s| s.shared.clone()),
+        #[cfg(feature = "version-check")]
+        version_check: version_check.as_ref().map(|v| v.shared.clone()),
+    };
+
+    let utxo_stats = config
+        .utxo_stats
+        .clone()
+        .map(|utxo_stats_config| {
+            crate::service::utxo_stats::new_service(utxo_stats_config, database.clone())
+        });
+    let utxo_stats_adapter = UtxoStatisticsAdapter {
+        service: utxo_stats.as_ref().map(|service| service.shared.clone()),
+    };
+
+    let block_pruning = config.block_pruning.clone().map(|pruning_config| {
+        crate::service::block_pruning::new_service(
+            pruning_config,
+            database.clone(),
+            importer_adapter.block_importer.subscribe(),
+        )
+    });
+
+    let inclusion_monitor = config.inclusion_monitor.clone().map(|monitor_config| {
+        crate::service::inclusion_monitor::new_service(
+            monitor_config,
+            txpool.shared.pool_events_subscribe(),
+            tx_pool_adapter.clone(),
+        )
+    });
+    let inclusion_monitor_adapter = InclusionMonitorAdapter {
+        service: inclusion_monitor
+            .as_ref()
+            .map(|service| service.shared.clone()),
+    };
+
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
     let schema = {
         #[cfg(feature = "dap")]
@@ -163,22 +272,66 @@ pub fn init_sub_services(
     let graph_ql = crate::fuel_core_graphql_api::service::new_service(
         GraphQLConfig {
             addr: config.addr,
+            additional_addrs: config.additional_addrs.clone(),
             utxo_validation: config.utxo_validation,
             manual_blocks_enabled: config.manual_blocks_enabled,
+            debug: config.debug,
             vm_backtrace: config.vm.backtrace,
-            min_gas_price: config.txpool.min_gas_price,
             max_tx: config.txpool.max_tx,
             max_depth: config.txpool.max_depth,
             transaction_parameters: config.chain_conf.transaction_parameters,
             consensus_key: config.consensus_key.clone(),
+            cursor_secret: config.cursor_secret,
+            disable_deprecated_fields: config.disable_deprecated_graphql_fields,
+            idempotency_key_ttl: config.graphql_idempotency_key_ttl,
+            admin_api: config.admin_api,
+            admin_api_token: config.admin_api_token.clone(),
+            slow_log,
+            query_allowlist: config
+                .graphql_allowlist_dir
+                .clone()
+                .map(QueryAllowlist::load)
+                .transpose()?,
+            config_hash: config.consensus_config_hash()?,
         },
         schema,
         Box::new(database.clone()),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
-        Box::new(poa_adapter),
+        Box::new(SubmittedBlockAdapter {
+            block_importer: importer_adapter.clone(),
+            block_verifier: verifier,
+        }),
+        Arc::new(poa_adapter.clone()),
+        Arc::new(node_status),
+        Arc::new(utxo_stats_adapter),
+        Arc::new(inclusion_monitor_adapter),
+        registry.clone(),
     )?;
 
+    #[cfg(feature = "alerting")]
+    let alerting = config.alerting.clone().map(|alerting_config| {
+        use crate::service::{
+            adapters::AlertingAdapter,
+            alerting,
+            DbType,
+        };
+
+        let database_path = (config.database_type == DbType::RocksDb
+            && !config.database_path.as_os_str().is_empty())
+        .then(|| config.database_path.clone());
+        let status = AlertingAdapter {
+            database: database.clone(),
+            database_path,
+            #[cfg(feature = "relayer")]
+            relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
+            #[cfg(feature = "p2p")]
+            network: network.as_ref().map(|n| n.shared.clone()),
+        };
+        let notifier = alerting::Notifier::new(config.name.clone(), &alerting_config);
+        alerting::new_service(alerting_config, status, notifier)
+    });
+
     let shared = SharedState {
         txpool: txpool.shared.clone(),
         #[cfg(feature = "p2p")]
@@ -186,37 +339,99 @@ pub fn init_sub_services(
         #[cfg(feature = "relayer")]
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
+        services_health: services.health(),
+        registry: registry.clone(),
         block_importer: importer_adapter,
         #[cfg(feature = "test-helpers")]
+        poa_adapter,
+        #[cfg(feature = "test-helpers")]
         config: config.clone(),
     };
 
     #[allow(unused_mut)]
-    // `FuelService` starts and shutdowns all sub-services in the `services` order
-    let mut services: SubServices = vec![
-        // GraphQL should be shutdown first, so let's start it first.
-        Box::new(graph_ql),
-        Box::new(txpool),
-    ];
-
-    if let Some(poa) = poa {
-        services.push(Box::new(poa));
-    }
+    let mut txpool_dependencies = vec![];
+    #[allow(unused_mut)]
+    let mut poa_dependencies = vec!["txpool"];
 
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
-        services.push(Box::new(relayer));
+        services.add("relayer", registry.register("relayer", relayer)?, &[])?;
+        poa_dependencies.push("relayer");
     }
 
     #[cfg(feature = "p2p")]
     {
         if let Some(network) = network.take() {
-            services.push(Box::new(network));
+            services.add("p2p", registry.register("p2p", network), &[])?;
+            txpool_dependencies.push("p2p");
             if let Some(sync) = sync {
-                services.push(Box::new(sync));
+                services.add("sync", registry.register("sync", sync)?, &["p2p"])?;
+            }
+            if let Some(pool_divergence) = pool_divergence {
+                services.add(
+                    "pool_divergence",
+                    registry.register("pool_divergence", pool_divergence)?,
+                    &["p2p", "txpool"],
+                )?;
             }
         }
     }
 
+    services.add(
+        "txpool",
+        registry.register("txpool", txpool)?,
+        &txpool_dependencies,
+    )?;
+
+    let mut graph_ql_dependencies = vec!["txpool"];
+    if let Some(poa) = poa {
+        services.add("poa", registry.register("poa", poa)?, &poa_dependencies)?;
+        graph_ql_dependencies.push("poa");
+    }
+
+    services.add(
+        "graph_ql",
+        registry.register("graph_ql", graph_ql)?,
+        &graph_ql_dependencies,
+    )?;
+
+    if let Some(utxo_stats) = utxo_stats {
+        services.add(
+            "utxo_stats",
+            registry.register("utxo_stats", utxo_stats)?,
+            &[],
+        )?;
+    }
+
+    if let Some(block_pruning) = block_pruning {
+        services.add(
+            "block_pruning",
+            registry.register("block_pruning", block_pruning)?,
+            &[],
+        )?;
+    }
+
+    if let Some(inclusion_monitor) = inclusion_monitor {
+        services.add(
+            "inclusion_monitor",
+            registry.register("inclusion_monitor", inclusion_monitor)?,
+            &["txpool"],
+        )?;
+    }
+
+    #[cfg(feature = "alerting")]
+    if let Some(alerting) = alerting {
+        services.add("alerting", registry.register("alerting", alerting)?, &[])?;
+    }
+
+    #[cfg(feature = "version-check")]
+    if let Some(version_check) = version_check {
+        services.add(
+            "version_check",
+            registry.register("version_check", version_check)?,
+            &[],
+        )?;
+    }
+
     Ok((services, shared))
 }
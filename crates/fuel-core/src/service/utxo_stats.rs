@@ -0,0 +1,412 @@
+//! The service periodically computes statistics of the UTXO set: the number of coins,
+//! the total amount of every asset and the histogram of coin amounts. The statistics are
+//! useful for supply audits and the analysis of dust coins.
+
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    SharedSnapshot,
+    SharedState as _,
+    StateWatcher,
+};
+use fuel_core_storage::iter::BoxedIter;
+use fuel_core_types::{
+    entities::coins::coin::CompressedCoin,
+    fuel_tx::AssetId,
+    fuel_types::BlockHeight,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::MissedTickBehavior;
+
+pub type Service<Source> = ServiceRunner<Task<Source>>;
+
+/// The number of buckets in the histogram of coin amounts, one per decimal order of
+/// magnitude of `u64`.
+pub const HISTOGRAM_BUCKETS: usize = 20;
+
+/// The number of attempts to snapshot the coins between imported blocks.
+const SNAPSHOT_ATTEMPTS: usize = 10;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// How often to compute the statistics.
+    pub interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+/// The source of the unspent coins.
+pub trait UtxoSource: Clone + Send + Sync {
+    fn latest_height(&self) -> anyhow::Result<BlockHeight>;
+
+    /// Iterates over all unspent coins. The iterator is a snapshot of the coins at the
+    /// moment of its creation, so blocks imported during the iteration are not visible.
+    fn coins(&self) -> BoxedIter<'_, anyhow::Result<CompressedCoin>>;
+}
+
+/// Returns the snapshot of the coins at the latest height. The snapshot is taken again if
+/// a block is imported while it is taken, because it is unknown whether the snapshot
+/// includes the block.
+fn coins_at_latest_height<Source>(
+    source: &Source,
+) -> anyhow::Result<(BlockHeight, BoxedIter<'_, anyhow::Result<CompressedCoin>>)>
+where
+    Source: UtxoSource,
+{
+    for _ in 0..SNAPSHOT_ATTEMPTS {
+        let height = source.latest_height()?;
+        let coins = source.coins();
+        if source.latest_height()? == height {
+            return Ok((height, coins))
+        }
+    }
+    Err(anyhow::anyhow!(
+        "Blocks were imported during every attempt to snapshot the coins"
+    ))
+}
+
+/// The statistics of the UTXO set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UtxoSetStatistics {
+    /// The height of the latest block included into the statistics.
+    pub height: BlockHeight,
+    /// The number of unspent coins.
+    pub count: u64,
+    /// The statistics of every asset, sorted by the asset id.
+    pub assets: Vec<AssetStatistics>,
+    /// The number of coins per decimal order of magnitude of the amount. The bucket `i`
+    /// counts coins with the amount in the `[10^i, 10^(i+1))` range, the first bucket
+    /// also counts zero amounts.
+    pub histogram: [u64; HISTOGRAM_BUCKETS],
+}
+
+/// The statistics of the unspent coins of the asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetStatistics {
+    pub asset_id: AssetId,
+    pub count: u64,
+    pub total_amount: u128,
+}
+
+impl UtxoSetStatistics {
+    /// Computes the statistics of `coins` at the `height`.
+    pub fn compute<I>(height: BlockHeight, coins: I) -> anyhow::Result<Self>
+    where
+        I: Iterator<Item = anyhow::Result<CompressedCoin>>,
+    {
+        let mut count = 0;
+        let mut assets = BTreeMap::<AssetId, AssetStatistics>::new();
+        let mut histogram = [0; HISTOGRAM_BUCKETS];
+        for coin in coins {
+            let coin = coin?;
+            count += 1;
+            let asset = assets.entry(coin.asset_id).or_insert(AssetStatistics {
+                asset_id: coin.asset_id,
+                count: 0,
+                total_amount: 0,
+            });
+            asset.count += 1;
+            asset.total_amount += coin.amount as u128;
+            histogram[histogram_bucket(coin.amount)] += 1;
+        }
+
+        Ok(Self {
+            height,
+            count,
+            assets: assets.into_values().collect(),
+            histogram,
+        })
+    }
+
+    /// Renders the statistics as CSV. The header row is followed by one row per asset and
+    /// one row per bucket of the histogram. Histogram rows have an empty asset id and the
+    /// upper bound of the bucket instead of the total amount.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("height,kind,asset_id,count,amount\n");
+        for asset in &self.assets {
+            let _ = writeln!(
+                csv,
+                "{},asset,{:#x},{},{}",
+                *self.height, asset.asset_id, asset.count, asset.total_amount
+            );
+        }
+        for (bucket, count) in self.histogram.iter().enumerate() {
+            let _ = writeln!(
+                csv,
+                "{},histogram,,{},{}",
+                *self.height,
+                count,
+                bucket_upper_bound(bucket)
+            );
+        }
+        csv
+    }
+}
+
+/// Returns the index of the histogram bucket of the `amount`.
+fn histogram_bucket(amount: u64) -> usize {
+    amount.checked_ilog10().unwrap_or_default() as usize
+}
+
+/// Returns the largest amount counted by the histogram `bucket`.
+pub fn bucket_upper_bound(bucket: usize) -> u64 {
+    10u64
+        .checked_pow(bucket as u32 + 1)
+        .map_or(u64::MAX, |bound| bound - 1)
+}
+
+/// Shares the latest statistics with other services.
+#[derive(Clone)]
+pub struct SharedState {
+    latest: SharedSnapshot<Option<Arc<UtxoSetStatistics>>>,
+}
+
+impl SharedState {
+    /// Returns `None` if the statistics are not computed yet.
+    pub fn latest(&self) -> Option<Arc<UtxoSetStatistics>> {
+        self.latest.snapshot().clone()
+    }
+}
+
+pub struct Task<Source> {
+    source: Source,
+    timer: tokio::time::Interval,
+    shared: SharedState,
+}
+
+impl<Source> Task<Source>
+where
+    Source: UtxoSource + 'static,
+{
+    /// Computes the statistics on the blocking thread and shares them.
+    pub async fn compute(&self) -> anyhow::Result<Arc<UtxoSetStatistics>> {
+        let source = self.source.clone();
+        let statistics = tokio::task::spawn_blocking(move || {
+            let (height, coins) = coins_at_latest_height(&source)?;
+            UtxoSetStatistics::compute(height, coins)
+        })
+        .await??;
+        let statistics = Arc::new(statistics);
+        self.shared.latest.replace(Some(statistics.clone()));
+        Ok(statistics)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Source> RunnableService for Task<Source>
+where
+    Source: UtxoSource + 'static,
+{
+    const NAME: &'static str = "UtxoStatistics";
+
+    type SharedData = SharedState;
+    type Task = Task<Source>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // The first tick of the timer is immediate, so the statistics are available soon
+        // after the start.
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Source> RunnableTask for Task<Source>
+where
+    Source: UtxoSource + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.timer.tick() => {
+                match self.compute().await {
+                    Ok(statistics) => {
+                        tracing::info!(
+                            "The UTXO set at the height {} has {} coins of {} assets",
+                            *statistics.height,
+                            statistics.count,
+                            statistics.assets.len()
+                        );
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to compute the UTXO set statistics: {}",
+                            e
+                        );
+                    }
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        Ok(())
+    }
+}
+
+pub fn new_service<Source>(config: Config, source: Source) -> Service<Source>
+where
+    Source: UtxoSource + 'static,
+{
+    let mut timer = tokio::time::interval(config.interval);
+    timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    Service::new(Task {
+        source,
+        timer,
+        shared: SharedState {
+            latest: SharedSnapshot::new(None),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::iter::IntoBoxedIter;
+    use std::sync::atomic::{
+        AtomicU32,
+        Ordering,
+    };
+
+    /// Imports a block on every read of the height until the `imported` height.
+    #[derive(Clone)]
+    struct ImportingSource {
+        height: Arc<AtomicU32>,
+        imported: u32,
+    }
+
+    impl UtxoSource for ImportingSource {
+        fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+            let height = self
+                .height
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |height| {
+                    Some(height.saturating_add(1).min(self.imported))
+                })
+                .unwrap();
+            Ok(height.into())
+        }
+
+        fn coins(&self) -> BoxedIter<'_, anyhow::Result<CompressedCoin>> {
+            vec![coin(1, 5)].into_iter().into_boxed()
+        }
+    }
+
+    fn coin(asset: u8, amount: u64) -> anyhow::Result<CompressedCoin> {
+        Ok(CompressedCoin {
+            owner: Default::default(),
+            amount,
+            asset_id: AssetId::from([asset; 32]),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        })
+    }
+
+    #[test]
+    fn histogram_buckets_follow_orders_of_magnitude() {
+        assert_eq!(histogram_bucket(0), 0);
+        assert_eq!(histogram_bucket(9), 0);
+        assert_eq!(histogram_bucket(10), 1);
+        assert_eq!(histogram_bucket(999), 2);
+        assert_eq!(histogram_bucket(u64::MAX), HISTOGRAM_BUCKETS - 1);
+        assert_eq!(bucket_upper_bound(0), 9);
+        assert_eq!(bucket_upper_bound(2), 999);
+        assert_eq!(bucket_upper_bound(HISTOGRAM_BUCKETS - 1), u64::MAX);
+    }
+
+    #[test]
+    fn compute_sums_amounts_per_asset() {
+        let coins = vec![coin(2, u64::MAX), coin(1, 5), coin(2, u64::MAX), coin(1, 50)];
+
+        let statistics =
+            UtxoSetStatistics::compute(7u32.into(), coins.into_iter()).unwrap();
+
+        assert_eq!(statistics.count, 4);
+        assert_eq!(
+            statistics.assets,
+            vec![
+                AssetStatistics {
+                    asset_id: AssetId::from([1; 32]),
+                    count: 2,
+                    total_amount: 55,
+                },
+                AssetStatistics {
+                    asset_id: AssetId::from([2; 32]),
+                    count: 2,
+                    total_amount: 2 * u64::MAX as u128,
+                },
+            ]
+        );
+        assert_eq!(statistics.histogram[0], 1);
+        assert_eq!(statistics.histogram[1], 1);
+        assert_eq!(statistics.histogram[HISTOGRAM_BUCKETS - 1], 2);
+    }
+
+    #[test]
+    fn snapshot_is_taken_between_imported_blocks() {
+        let source = ImportingSource {
+            height: Arc::new(AtomicU32::new(0)),
+            imported: 3,
+        };
+
+        let (height, coins) = coins_at_latest_height(&source).unwrap();
+
+        assert_eq!(height, 3u32.into());
+        assert_eq!(coins.count(), 1);
+    }
+
+    #[test]
+    fn snapshot_is_not_taken_while_blocks_are_imported() {
+        let source = ImportingSource {
+            height: Arc::new(AtomicU32::new(0)),
+            imported: u32::MAX,
+        };
+
+        assert!(coins_at_latest_height(&source).is_err());
+    }
+
+    #[test]
+    fn csv_has_row_per_asset_and_bucket() {
+        let statistics =
+            UtxoSetStatistics::compute(7u32.into(), vec![coin(1, 5)].into_iter())
+                .unwrap();
+
+        let csv = statistics.to_csv();
+        let rows: Vec<_> = csv.lines().collect();
+
+        assert_eq!(rows.len(), 1 + 1 + HISTOGRAM_BUCKETS);
+        assert_eq!(rows[0], "height,kind,asset_id,count,amount");
+        assert_eq!(rows[1], format!("7,asset,{:#x},1,5", AssetId::from([1; 32])));
+        assert_eq!(rows[2], "7,histogram,,1,9");
+        assert_eq!(rows[3], "7,histogram,,0,99");
+    }
+}
@@ -0,0 +1,363 @@
+//! The service periodically fetches the manifest of supported versions of networks and
+//! compares the running version of the node with the minimum and the recommended
+//! versions of the network that the node belongs to. Nodes running incompatible versions
+//! fail the consensus, so operators are advised to upgrade via the node info of the
+//! GraphQL API, logs and metrics.
+//!
+//! The manifest is the JSON document:
+//! ```json
+//! {
+//!   "networks": [{
+//!     "genesis_id": "0x..",
+//!     "minimum_version": "0.20.0",
+//!     "recommended_version": "0.20.4",
+//!     "message": "The optional note for operators"
+//!   }]
+//! }
+//! ```
+
+use fuel_core_services::{
+    SharedSnapshot,
+    SharedState as _,
+};
+use fuel_core_types::blockchain::primitives::BlockId;
+use serde::Deserialize;
+use std::{
+    str::FromStr,
+    time::Duration,
+};
+
+/// The version of the running node.
+pub const RUNNING_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The manifest maintained along with releases of the node.
+pub const DEFAULT_MANIFEST_URL: &str =
+    "https://raw.githubusercontent.com/FuelLabs/fuel-core/master/deployment/versions.json";
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The URL of the manifest of supported versions.
+    pub manifest_url: String,
+    /// How often to fetch the manifest.
+    pub interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            manifest_url: DEFAULT_MANIFEST_URL.to_string(),
+            interval: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// The manifest of supported versions of networks.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Manifest {
+    pub networks: Vec<NetworkVersions>,
+}
+
+/// Supported versions of the network identified by its genesis block.
+#[derive(Clone, Debug, Deserialize)]
+pub struct NetworkVersions {
+    pub genesis_id: String,
+    pub minimum_version: String,
+    pub recommended_version: String,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionStatus {
+    UpToDate,
+    UpgradeRecommended,
+    Unsupported,
+}
+
+/// The advice for the running version of the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionAdvisory {
+    pub status: VersionStatus,
+    pub running_version: String,
+    pub minimum_version: String,
+    pub recommended_version: String,
+    pub message: Option<String>,
+}
+
+/// The `major.minor.patch` version. Pre-release and build suffixes are ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Version(u64, u64, u64);
+
+impl FromStr for Version {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let core = s
+            .trim()
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default();
+        let mut parts = core.split('.').map(u64::from_str);
+        let mut next = || -> anyhow::Result<u64> {
+            parts
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("The version `{s}` is incomplete"))?
+                .map_err(|e| anyhow::anyhow!("The version `{s}` is invalid: {e}"))
+        };
+        Ok(Self(next()?, next()?, next()?))
+    }
+}
+
+/// Returns the advice for the `running` version of the node on the network with the
+/// `genesis_id`, or `None` if the network is not in the `manifest`.
+pub fn advise(
+    manifest: &Manifest,
+    genesis_id: &BlockId,
+    running: &str,
+) -> anyhow::Result<Option<VersionAdvisory>> {
+    let network = manifest
+        .networks
+        .iter()
+        .find(|network| BlockId::from_str(&network.genesis_id).ok() == Some(*genesis_id));
+    let network = match network {
+        Some(network) => network,
+        None => return Ok(None),
+    };
+
+    let version = Version::from_str(running)?;
+    let status = if version < Version::from_str(&network.minimum_version)? {
+        VersionStatus::Unsupported
+    } else if version < Version::from_str(&network.recommended_version)? {
+        VersionStatus::UpgradeRecommended
+    } else {
+        VersionStatus::UpToDate
+    };
+    Ok(Some(VersionAdvisory {
+        status,
+        running_version: running.to_string(),
+        minimum_version: network.minimum_version.clone(),
+        recommended_version: network.recommended_version.clone(),
+        message: network.message.clone(),
+    }))
+}
+
+/// Shares the latest advice with other services.
+#[derive(Clone)]
+pub struct SharedState {
+    latest: SharedSnapshot<Option<VersionAdvisory>>,
+}
+
+impl SharedState {
+    /// Returns `None` if the manifest is not fetched yet or doesn't list the network.
+    pub fn latest(&self) -> Option<VersionAdvisory> {
+        self.latest.snapshot().clone()
+    }
+}
+
+#[cfg(feature = "version-check")]
+pub use task::*;
+
+#[cfg(feature = "version-check")]
+mod task {
+    use super::*;
+    use fuel_core_services::{
+        RunnableService,
+        RunnableTask,
+        ServiceRunner,
+        StateWatcher,
+    };
+    use tokio::time::MissedTickBehavior;
+
+    pub type Service = ServiceRunner<Task>;
+
+    pub struct Task {
+        config: Config,
+        client: reqwest::Client,
+        genesis_id: BlockId,
+        timer: tokio::time::Interval,
+        shared: SharedState,
+    }
+
+    impl Task {
+        /// Fetches the manifest and shares the advice for the running version.
+        pub async fn check(&self) -> anyhow::Result<Option<VersionAdvisory>> {
+            let manifest: Manifest = self
+                .client
+                .get(&self.config.manifest_url)
+                .timeout(Duration::from_secs(30))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            let advisory = advise(&manifest, &self.genesis_id, RUNNING_VERSION)?;
+
+            let previous = self.shared.latest();
+            let changed = previous.as_ref().map(|advisory| advisory.status)
+                != advisory.as_ref().map(|advisory| advisory.status);
+            if changed {
+                report(advisory.as_ref());
+            }
+            self.shared.latest.replace(advisory.clone());
+            Ok(advisory)
+        }
+    }
+
+    /// Logs the new status of the running version and updates the metric.
+    fn report(advisory: Option<&VersionAdvisory>) {
+        #[cfg(feature = "metrics")]
+        {
+            use fuel_core_metrics::version_metrics::{
+                VersionMetrics,
+                VERSION_METRICS,
+            };
+            let value = match advisory.map(|advisory| advisory.status) {
+                Some(VersionStatus::Unsupported) => VersionMetrics::UNSUPPORTED,
+                Some(VersionStatus::UpgradeRecommended) => {
+                    VersionMetrics::UPGRADE_RECOMMENDED
+                }
+                Some(VersionStatus::UpToDate) | None => VersionMetrics::UP_TO_DATE,
+            };
+            VERSION_METRICS.version_status.set(value);
+        }
+
+        let advisory = match advisory {
+            Some(advisory) => advisory,
+            None => return,
+        };
+        let message = advisory.message.as_deref().unwrap_or_default();
+        match advisory.status {
+            VersionStatus::Unsupported => tracing::error!(
+                "The running version {} is below the minimum version {} of the \
+                network, upgrade the node to avoid consensus failures. {}",
+                advisory.running_version,
+                advisory.minimum_version,
+                message
+            ),
+            VersionStatus::UpgradeRecommended => tracing::warn!(
+                "The running version {} is behind the recommended version {} of the \
+                network. {}",
+                advisory.running_version,
+                advisory.recommended_version,
+                message
+            ),
+            VersionStatus::UpToDate => tracing::info!(
+                "The running version {} is up to date",
+                advisory.running_version
+            ),
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableService for Task {
+        const NAME: &'static str = "VersionCheck";
+
+        type SharedData = SharedState;
+        type Task = Task;
+        type TaskParams = ();
+
+        fn shared_data(&self) -> Self::SharedData {
+            self.shared.clone()
+        }
+
+        async fn into_task(
+            self,
+            _: &StateWatcher,
+            _: Self::TaskParams,
+        ) -> anyhow::Result<Self::Task> {
+            // The first tick of the timer is immediate, so the node is checked soon
+            // after the start.
+            Ok(self)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableTask for Task {
+        async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+            let should_continue;
+            tokio::select! {
+                biased;
+
+                _ = watcher.while_started() => {
+                    should_continue = false;
+                }
+
+                _ = self.timer.tick() => {
+                    // The unavailable manifest shouldn't stop the node.
+                    if let Err(e) = self.check().await {
+                        tracing::debug!("Failed to check the version of the node: {}", e);
+                    }
+                    should_continue = true;
+                }
+            }
+            Ok(should_continue)
+        }
+
+        async fn shutdown(self) -> anyhow::Result<()> {
+            // Nothing to shut down because we don't have any temporary state that should
+            // be dumped, and we don't spawn any sub-tasks that we need to finish or await.
+            Ok(())
+        }
+    }
+
+    pub fn new_service(config: Config, genesis_id: BlockId) -> Service {
+        let mut timer = tokio::time::interval(config.interval);
+        timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        Service::new(Task {
+            config,
+            client: reqwest::Client::new(),
+            genesis_id,
+            timer,
+            shared: SharedState {
+                latest: SharedSnapshot::new(None),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(genesis_id: &BlockId) -> Manifest {
+        Manifest {
+            networks: vec![NetworkVersions {
+                genesis_id: format!("{genesis_id:#x}"),
+                minimum_version: "0.20.0".to_string(),
+                recommended_version: "0.20.4".to_string(),
+                message: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn running_version_is_compared_with_versions_of_the_network() {
+        let genesis_id = BlockId::from([1; 32]);
+        let manifest = manifest(&genesis_id);
+        let status = |running| {
+            advise(&manifest, &genesis_id, running)
+                .unwrap()
+                .unwrap()
+                .status
+        };
+
+        assert_eq!(status("0.19.9"), VersionStatus::Unsupported);
+        assert_eq!(status("0.20.0"), VersionStatus::UpgradeRecommended);
+        assert_eq!(status("0.20.4-rc.1"), VersionStatus::UpToDate);
+        assert_eq!(status("0.21.0"), VersionStatus::UpToDate);
+    }
+
+    #[test]
+    fn unknown_network_is_not_advised() {
+        let manifest = manifest(&BlockId::from([1; 32]));
+        let advisory = advise(&manifest, &BlockId::from([2; 32]), "0.1.0").unwrap();
+        assert_eq!(advisory, None);
+    }
+
+    #[test]
+    fn invalid_version_is_rejected() {
+        assert!(Version::from_str("0.20").is_err());
+        assert!(Version::from_str("0.x.1").is_err());
+        assert_eq!(Version::from_str("v1.2.3+build").unwrap(), Version(1, 2, 3));
+    }
+}
@@ -80,14 +80,39 @@ pub trait BatchOperations: KeyValueStore {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum WriteOperation {
     Insert(Value),
     Remove,
 }
 
-pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {}
+pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {
+    /// Returns the copy of uncommitted changes on top of the same data source if the
+    /// storage is the in-memory transaction, or `None` otherwise. Unlike the original,
+    /// the copy keeps the changes visible while the original is committed.
+    fn fork(&self) -> Option<DatabaseResult<DataSource>> {
+        None
+    }
+}
+
+/// Returns the smallest key greater than all keys starting with `prefix`, or `None` if
+/// the prefix consists of `u8::MAX` bytes only and no such key exists.
+pub(crate) fn prefix_upper_bound(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut upper = prefix.to_vec();
+    while let Some(last) = upper.pop() {
+        if last < u8::MAX {
+            upper.push(last + 1);
+            return Some(upper)
+        }
+    }
+    None
+}
 
+pub mod change_capture;
+pub mod fork;
 pub mod in_memory;
+#[cfg(test)]
+mod iter_tests;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
+pub mod slow_log;
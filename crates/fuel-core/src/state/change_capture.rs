@@ -0,0 +1,433 @@
+//! The change data capture of the storage. Every committed write is published as a change
+//! set of key/value changes, so external services, like indexers, can mirror the state of
+//! the node without querying the GraphQL API.
+//!
+//! Change sets of the latest blocks are retained in memory and can be replayed to
+//! subscribers that reconnect after a restart or a disconnection.
+
+use crate::{
+    database::{
+        Column,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        TransactableStorage,
+        Value,
+        WriteOperation,
+    },
+};
+use anyhow::anyhow;
+use fuel_core_storage::iter::BoxedIter;
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tokio::sync::broadcast;
+
+/// The number of change sets buffered for every subscriber. The subscriber that falls
+/// behind by more change sets loses them.
+pub const CHANGE_SETS_CAPACITY: usize = 1024;
+
+/// The change of the value of the `key` in the `column`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub column: Column,
+    pub key: Vec<u8>,
+    /// The new value, `None` if the key is removed.
+    pub value: Option<Value>,
+}
+
+/// The changes committed atomically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSet {
+    /// The number of the change set since the start of the node, starting from zero.
+    pub sequence: u64,
+    /// The height of the latest block after the commit. The change set that commits the
+    /// block has the height of this block.
+    pub height: BlockHeight,
+    pub changes: Vec<Change>,
+}
+
+/// The subscription to committed change sets.
+#[derive(Debug)]
+pub struct Subscription {
+    /// The retained change sets requested for the replay, from the oldest to the newest.
+    pub replay: Vec<Arc<ChangeSet>>,
+    /// Receives change sets committed after the subscription.
+    pub receiver: broadcast::Receiver<Arc<ChangeSet>>,
+}
+
+/// Publishes committed change sets to subscribers and retains change sets of the latest
+/// blocks for the replay. It can be cloned and shared with other services.
+#[derive(Debug, Clone)]
+pub struct ChangeFeed(Arc<Mutex<Journal>>);
+
+#[derive(Debug)]
+struct Journal {
+    retained_blocks: u32,
+    /// The height of the latest block.
+    height: Option<BlockHeight>,
+    /// The lowest height whose change sets are all retained.
+    first_replayable: BlockHeight,
+    next_sequence: u64,
+    history: VecDeque<Arc<ChangeSet>>,
+    sender: broadcast::Sender<Arc<ChangeSet>>,
+}
+
+impl ChangeFeed {
+    /// Creates the feed that retains change sets of `retained_blocks` latest blocks.
+    pub fn new(retained_blocks: u32) -> Self {
+        let (sender, _) = broadcast::channel(CHANGE_SETS_CAPACITY);
+        Self(Arc::new(Mutex::new(Journal {
+            retained_blocks,
+            height: None,
+            first_replayable: Default::default(),
+            next_sequence: 0,
+            history: VecDeque::new(),
+            sender,
+        })))
+    }
+
+    /// Subscribes to change sets committed after the call. If `from` is set, retained
+    /// change sets with the height not less than `from` are replayed first. Change sets
+    /// are upserts and removals of keys, so re-applying already applied change sets in
+    /// the same order is harmless, and subscribers can resume from the height of the
+    /// last applied change set.
+    pub fn subscribe(&self, from: Option<BlockHeight>) -> anyhow::Result<Subscription> {
+        let journal = self.0.lock().expect("poisoned");
+        let replay = match from {
+            Some(from) if from < journal.first_replayable => {
+                return Err(anyhow!(
+                    "Change sets at the height {} are not retained, the lowest height \
+                    available for the replay is {}",
+                    *from,
+                    *journal.first_replayable
+                ))
+            }
+            Some(from) => journal
+                .history
+                .iter()
+                .filter(|change_set| change_set.height >= from)
+                .cloned()
+                .collect(),
+            None => vec![],
+        };
+        Ok(Subscription {
+            replay,
+            receiver: journal.sender.subscribe(),
+        })
+    }
+
+    /// Returns the height of the latest block known to the feed.
+    pub fn height(&self) -> Option<BlockHeight> {
+        self.0.lock().expect("poisoned").height
+    }
+
+    /// Sets the height of the latest block of the storage before any change is
+    /// captured. Change sets committed before are unknown, so the replay starts from the
+    /// next block.
+    fn start(&self, height: Option<BlockHeight>) {
+        let mut journal = self.0.lock().expect("poisoned");
+        journal.height = height;
+        journal.first_replayable = height
+            .map(|height| (*height).saturating_add(1).into())
+            .unwrap_or_default();
+    }
+
+    /// Applies the `write` to the storage and publishes the `changes` if it succeeds.
+    /// The journal is locked during the write, so change sets are published in the order
+    /// of commits.
+    fn capture<R>(
+        &self,
+        changes: Vec<Change>,
+        write: impl FnOnce() -> DatabaseResult<R>,
+    ) -> DatabaseResult<R> {
+        let mut journal = self.0.lock().expect("poisoned");
+        let result = write()?;
+        journal.publish(changes);
+        Ok(result)
+    }
+}
+
+impl Journal {
+    fn publish(&mut self, changes: Vec<Change>) {
+        if changes.is_empty() {
+            return
+        }
+
+        // The block is committed along with its height in the secondary index.
+        let committed_height = changes
+            .iter()
+            .filter(|change| {
+                change.column == Column::FuelBlockSecondaryKeyBlockHeights
+                    && change.value.is_some()
+            })
+            .filter_map(|change| <[u8; 4]>::try_from(change.key.as_slice()).ok())
+            .map(|bytes| BlockHeight::from(u32::from_be_bytes(bytes)))
+            .max();
+        if let Some(committed_height) = committed_height {
+            self.height = self.height.max(Some(committed_height));
+        }
+        let height = self.height.unwrap_or_default();
+
+        let change_set = Arc::new(ChangeSet {
+            sequence: self.next_sequence,
+            height,
+            changes,
+        });
+        self.next_sequence += 1;
+        self.history.push_back(change_set.clone());
+        while let Some(oldest) = self.history.front() {
+            if (*oldest.height).saturating_add(self.retained_blocks) >= *height {
+                break
+            }
+            self.first_replayable = (*oldest.height + 1).into();
+            self.history.pop_front();
+        }
+        // Nobody may be subscribed, it is not an error.
+        let _ = self.sender.send(change_set);
+    }
+}
+
+/// The storage that publishes every write into the [`ChangeFeed`].
+#[derive(Debug)]
+pub struct ChangeCaptureStore {
+    inner: DataSource,
+    feed: ChangeFeed,
+}
+
+impl ChangeCaptureStore {
+    pub fn new(inner: DataSource, feed: ChangeFeed) -> DatabaseResult<Self> {
+        let height = inner
+            .iter_all(
+                Column::FuelBlockSecondaryKeyBlockHeights,
+                None,
+                None,
+                IterDirection::Reverse,
+            )
+            .next()
+            .transpose()?
+            .and_then(|(key, _)| <[u8; 4]>::try_from(key.as_slice()).ok())
+            .map(|bytes| u32::from_be_bytes(bytes).into());
+        feed.start(height);
+        Ok(Self { inner, feed })
+    }
+
+    fn insert(key: &[u8], column: Column, value: Value) -> Vec<Change> {
+        vec![Change {
+            column,
+            key: key.to_vec(),
+            value: Some(value),
+        }]
+    }
+
+    fn remove(key: &[u8], column: Column) -> Vec<Change> {
+        vec![Change {
+            column,
+            key: key.to_vec(),
+            value: None,
+        }]
+    }
+}
+
+impl KeyValueStore for ChangeCaptureStore {
+    fn put(
+        &self,
+        key: &[u8],
+        column: Column,
+        value: Value,
+    ) -> DatabaseResult<Option<Value>> {
+        let changes = Self::insert(key, column, value.clone());
+        self.feed.capture(changes, || self.inner.put(key, column, value))
+    }
+
+    fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
+        let changes = Self::insert(key, column, Arc::new(buf.to_vec()));
+        self.feed.capture(changes, || self.inner.write(key, column, buf))
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &[u8],
+    ) -> DatabaseResult<(usize, Option<Value>)> {
+        let changes = Self::insert(key, column, Arc::new(buf.to_vec()));
+        self.feed.capture(changes, || self.inner.replace(key, column, buf))
+    }
+
+    fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.feed.capture(Self::remove(key, column), || self.inner.take(key, column))
+    }
+
+    fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.feed.capture(Self::remove(key, column), || self.inner.delete(key, column))
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        self.inner.exists(key, column)
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        self.inner.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.inner.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        self.inner.read(key, column, buf)
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.inner.read_alloc(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl BatchOperations for ChangeCaptureStore {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Column, WriteOperation)>,
+    ) -> DatabaseResult<()> {
+        let entries: Vec<_> = entries.collect();
+        let changes = entries
+            .iter()
+            .map(|(key, column, operation)| Change {
+                column: *column,
+                key: key.clone(),
+                value: match operation {
+                    WriteOperation::Insert(value) => Some(value.clone()),
+                    WriteOperation::Remove => None,
+                },
+            })
+            .collect();
+        self.feed.capture(changes, || self.inner.batch_write(&mut entries.into_iter()))
+    }
+}
+
+impl TransactableStorage for ChangeCaptureStore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::memory_store::MemoryStore;
+
+    fn store(retained_blocks: u32) -> (ChangeCaptureStore, ChangeFeed) {
+        let feed = ChangeFeed::new(retained_blocks);
+        let store =
+            ChangeCaptureStore::new(Arc::new(MemoryStore::default()), feed.clone())
+                .unwrap();
+        (store, feed)
+    }
+
+    fn commit_block(store: &ChangeCaptureStore, height: u32) {
+        let mut entries = vec![
+            (
+                height.to_be_bytes().to_vec(),
+                Column::FuelBlockSecondaryKeyBlockHeights,
+                WriteOperation::Insert(Arc::new(vec![height as u8])),
+            ),
+            (vec![height as u8], Column::Coins, WriteOperation::Remove),
+        ]
+        .into_iter();
+        store.batch_write(&mut entries).unwrap();
+    }
+
+    #[test]
+    fn batch_is_published_as_one_change_set_with_the_block_height() {
+        let (store, feed) = store(10);
+        let mut subscription = feed.subscribe(None).unwrap();
+
+        commit_block(&store, 1);
+        store.put(&[7], Column::Messages, Arc::new(vec![42])).unwrap();
+
+        let block = subscription.receiver.try_recv().unwrap();
+        assert_eq!(block.sequence, 0);
+        assert_eq!(block.height, 1u32.into());
+        assert_eq!(block.changes.len(), 2);
+        assert_eq!(block.changes[1].value, None);
+        let message = subscription.receiver.try_recv().unwrap();
+        assert_eq!(message.sequence, 1);
+        assert_eq!(message.height, 1u32.into());
+        assert_eq!(
+            message.changes,
+            vec![Change {
+                column: Column::Messages,
+                key: vec![7],
+                value: Some(Arc::new(vec![42])),
+            }]
+        );
+        assert_eq!(store.get(&[7], Column::Messages).unwrap(), Some(Arc::new(vec![42])));
+    }
+
+    #[test]
+    fn replay_starts_from_the_requested_height() {
+        let (store, feed) = store(10);
+        for height in 0..5 {
+            commit_block(&store, height);
+        }
+
+        let subscription = feed.subscribe(Some(3u32.into())).unwrap();
+
+        let heights: Vec<_> = subscription
+            .replay
+            .iter()
+            .map(|change_set| *change_set.height)
+            .collect();
+        assert_eq!(heights, vec![3, 4]);
+    }
+
+    #[test]
+    fn pruned_heights_are_not_replayed() {
+        let (store, feed) = store(2);
+        for height in 0..5 {
+            commit_block(&store, height);
+        }
+
+        assert!(feed.subscribe(Some(1u32.into())).is_err());
+        assert_eq!(feed.subscribe(Some(2u32.into())).unwrap().replay.len(), 3);
+    }
+
+    #[test]
+    fn changes_before_the_capture_are_not_replayed() {
+        let inner: DataSource = Arc::new(MemoryStore::default());
+        inner
+            .put(
+                &5u32.to_be_bytes(),
+                Column::FuelBlockSecondaryKeyBlockHeights,
+                Arc::new(vec![]),
+            )
+            .unwrap();
+        let feed = ChangeFeed::new(10);
+        let _store = ChangeCaptureStore::new(inner, feed.clone()).unwrap();
+
+        assert_eq!(feed.height(), Some(5u32.into()));
+        assert!(feed.subscribe(Some(5u32.into())).is_err());
+        assert!(feed.subscribe(Some(6u32.into())).unwrap().replay.is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+//! The storage of the node forked from the remote network.
+//!
+//! The state of the forked columns(coins, contracts, balances, and storage slots) is fetched
+//! from the remote node lazily, on the first access to the key, and is cached in the local
+//! storage. After the first access, the local storage is the source of truth for the key,
+//! so the keys modified or removed locally are never fetched again.
+//!
+//! Iteration goes only over the local storage, so the remote state is not visible
+//! to queries by the owner until it is accessed by the key.
+
+use crate::{
+    database::{
+        Column,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        TransactableStorage,
+        Value,
+    },
+};
+use fuel_core_storage::iter::BoxedIter;
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+#[cfg(feature = "fork")]
+pub mod graphql;
+
+/// The columns fetched from the remote node.
+pub const FORKED_COLUMNS: [Column; 5] = [
+    Column::Coins,
+    Column::ContractsRawCode,
+    Column::ContractsInfo,
+    Column::ContractsAssets,
+    Column::ContractsState,
+];
+
+/// The source of the state of the remote network.
+pub trait RemoteState: Debug + Send + Sync {
+    /// Returns the value of the `key` in the `column`, encoded the same way as the local
+    /// storage encodes it. Returns `None` if the remote node doesn't have the value.
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>>;
+}
+
+#[derive(Debug)]
+pub struct ForkedStore {
+    local: DataSource,
+    remote: Arc<dyn RemoteState>,
+    /// Keys of forked columns that are already fetched from the remote node
+    /// or modified locally.
+    resolved: [Mutex<HashSet<Vec<u8>>>; Column::COUNT],
+}
+
+impl ForkedStore {
+    pub fn new(local: DataSource, remote: Arc<dyn RemoteState>) -> Self {
+        Self {
+            local,
+            remote,
+            resolved: Default::default(),
+        }
+    }
+
+    fn is_forked(column: Column) -> bool {
+        FORKED_COLUMNS.contains(&column)
+    }
+
+    /// Fetches the value from the remote node into the local storage
+    /// if the key is accessed for the first time.
+    fn resolve(&self, key: &[u8], column: Column) -> DatabaseResult<()> {
+        if !Self::is_forked(column) {
+            return Ok(())
+        }
+
+        // The lock is held during the fetch to not fetch the same key twice.
+        let mut resolved = self.resolved[column.as_usize()].lock().expect("poisoned");
+        if resolved.contains(key) {
+            return Ok(())
+        }
+
+        if !self.local.exists(key, column)? {
+            if let Some(value) = self.remote.get(key, column)? {
+                self.local.put(key, column, value)?;
+            }
+        }
+        resolved.insert(key.to_vec());
+        Ok(())
+    }
+
+    /// Marks the key as resolved, because the local modification overrides the remote state.
+    fn override_remote(&self, key: &[u8], column: Column) {
+        if Self::is_forked(column) {
+            self.resolved[column.as_usize()]
+                .lock()
+                .expect("poisoned")
+                .insert(key.to_vec());
+        }
+    }
+}
+
+impl KeyValueStore for ForkedStore {
+    fn put(
+        &self,
+        key: &[u8],
+        column: Column,
+        value: Value,
+    ) -> DatabaseResult<Option<Value>> {
+        self.override_remote(key, column);
+        self.local.put(key, column, value)
+    }
+
+    fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
+        self.override_remote(key, column);
+        self.local.write(key, column, buf)
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &[u8],
+    ) -> DatabaseResult<(usize, Option<Value>)> {
+        self.override_remote(key, column);
+        self.local.replace(key, column, buf)
+    }
+
+    fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.resolve(key, column)?;
+        self.local.take(key, column)
+    }
+
+    fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.resolve(key, column)?;
+        self.local.delete(key, column)
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        self.resolve(key, column)?;
+        self.local.exists(key, column)
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        self.resolve(key, column)?;
+        self.local.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.resolve(key, column)?;
+        self.local.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        self.resolve(key, column)?;
+        self.local.read(key, column, buf)
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.resolve(key, column)?;
+        self.local.read_alloc(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.local.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl BatchOperations for ForkedStore {}
+
+impl TransactableStorage for ForkedStore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::memory_store::MemoryStore;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Default)]
+    struct MockRemote {
+        values: HashMap<(Vec<u8>, usize), Value>,
+        requests: Mutex<usize>,
+    }
+
+    impl RemoteState for MockRemote {
+        fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+            *self.requests.lock().unwrap() += 1;
+            Ok(self.values.get(&(key.to_vec(), column.as_usize())).cloned())
+        }
+    }
+
+    fn forked(values: &[(&[u8], Column, &[u8])]) -> (ForkedStore, Arc<MockRemote>) {
+        let remote = Arc::new(MockRemote {
+            values: values
+                .iter()
+                .map(|(key, column, value)| {
+                    ((key.to_vec(), column.as_usize()), Arc::new(value.to_vec()))
+                })
+                .collect(),
+            ..Default::default()
+        });
+        let store = ForkedStore::new(Arc::new(MemoryStore::default()), remote.clone());
+        (store, remote)
+    }
+
+    #[test]
+    fn remote_value_is_fetched_once_and_cached() {
+        let (store, remote) = forked(&[(&[1], Column::Coins, &[42])]);
+
+        assert_eq!(
+            store.get(&[1], Column::Coins).unwrap(),
+            Some(Arc::new(vec![42]))
+        );
+        assert!(store.exists(&[1], Column::Coins).unwrap());
+        assert_eq!(*remote.requests.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn missing_remote_value_is_not_fetched_again() {
+        let (store, remote) = forked(&[]);
+
+        assert_eq!(store.get(&[1], Column::Coins).unwrap(), None);
+        assert_eq!(store.get(&[1], Column::Coins).unwrap(), None);
+        assert_eq!(*remote.requests.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn removed_value_is_not_fetched_again() {
+        let (store, _) = forked(&[(&[1], Column::Coins, &[42])]);
+
+        assert_eq!(
+            store.delete(&[1], Column::Coins).unwrap(),
+            Some(Arc::new(vec![42]))
+        );
+        assert_eq!(store.get(&[1], Column::Coins).unwrap(), None);
+    }
+
+    #[test]
+    fn local_value_overrides_remote_value() {
+        let (store, remote) = forked(&[(&[1], Column::ContractsState, &[42])]);
+
+        store
+            .put(&[1], Column::ContractsState, Arc::new(vec![7]))
+            .unwrap();
+
+        assert_eq!(
+            store.get(&[1], Column::ContractsState).unwrap(),
+            Some(Arc::new(vec![7]))
+        );
+        assert_eq!(*remote.requests.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn not_forked_columns_are_not_fetched() {
+        let (store, remote) = forked(&[(&[1], Column::Metadata, &[42])]);
+
+        assert_eq!(store.get(&[1], Column::Metadata).unwrap(), None);
+        assert_eq!(*remote.requests.lock().unwrap(), 0);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    database::{
+        Column,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        fork::RemoteState,
+        Value,
+    },
+};
+use anyhow::anyhow;
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    entities::coins::coin::CompressedCoin,
+    fuel_tx::{
+        Contract,
+        TxPointer,
+        UtxoId,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Salt,
+    },
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    sync::Arc,
+};
+use tokio::runtime::{
+    Handle,
+    Runtime,
+    RuntimeFlavor,
+};
+
+/// Fetches the state from the GraphQL API of the remote node.
+///
+/// The storage is synchronous, so the requests are executed on the separate runtime
+/// and the caller is blocked until the response is received.
+///
+/// The API serves only the latest state, so the state is fetched only while the remote
+/// node stays at the fork height. Once it moves past it, fetches fail instead of mixing
+/// the state of later blocks into the fork.
+pub struct GraphQLRemoteState {
+    client: FuelClient,
+    height: BlockHeight,
+    // `Option` to shut down the runtime on drop without blocking the async context.
+    runtime: Option<Runtime>,
+}
+
+impl Debug for GraphQLRemoteState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphQLRemoteState")
+            .field("client", &self.client)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+impl GraphQLRemoteState {
+    pub fn new(url: &str, height: BlockHeight) -> anyhow::Result<Self> {
+        let client = FuelClient::new(url)?;
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("fork-remote")
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            client,
+            height,
+            runtime: Some(runtime),
+        })
+    }
+
+    /// Returns the height of the latest block of the remote node at the `url`.
+    pub async fn latest_height_of(url: &str) -> anyhow::Result<BlockHeight> {
+        Self::latest_height(&FuelClient::new(url)?).await
+    }
+
+    async fn latest_height(client: &FuelClient) -> anyhow::Result<BlockHeight> {
+        let chain_info = client.chain_info().await?;
+        Ok(chain_info.latest_block.header.height.0.into())
+    }
+
+    async fn fetch(
+        client: FuelClient,
+        height: BlockHeight,
+        key: Vec<u8>,
+        column: Column,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let value = Self::fetch_latest(&client, key, column).await?;
+        // The height of the remote node never decreases, so the fetched value is the
+        // state at the fork height if the remote node is still at it.
+        let latest_height = Self::latest_height(&client).await?;
+        anyhow::ensure!(
+            latest_height == height,
+            "The remote node moved past the fork height {} to {}",
+            *height,
+            *latest_height
+        );
+        Ok(value)
+    }
+
+    async fn fetch_latest(
+        client: &FuelClient,
+        key: Vec<u8>,
+        column: Column,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let value = match column {
+            Column::Coins => {
+                let utxo_id = UtxoId::new(
+                    Bytes32::try_from(&key[..Bytes32::LEN])?,
+                    key[Bytes32::LEN],
+                );
+                client
+                    .coin(&format!("{utxo_id:#x}"))
+                    .await?
+                    .map(|coin| -> anyhow::Result<_> {
+                        let coin = CompressedCoin {
+                            owner: coin.owner.into(),
+                            amount: coin.amount.0,
+                            asset_id: coin.asset_id.into(),
+                            maturity: coin.maturity.0.into(),
+                            tx_pointer: TxPointer::new(
+                                coin.block_created.0.into(),
+                                coin.tx_created_idx.0.try_into()?,
+                            ),
+                        };
+                        Ok(postcard::to_stdvec(&coin)?)
+                    })
+                    .transpose()?
+            }
+            Column::ContractsRawCode => {
+                let contract_id = ContractId::try_from(key.as_slice())?;
+                client
+                    .contract(&format!("{contract_id:#x}"))
+                    .await?
+                    .map(|contract| contract.bytecode.0 .0)
+            }
+            Column::ContractsInfo => {
+                let contract_id = ContractId::try_from(key.as_slice())?;
+                client
+                    .contract(&format!("{contract_id:#x}"))
+                    .await?
+                    .map(|contract| {
+                        let salt: Salt = contract.salt.into();
+                        let root = Contract::from(contract.bytecode.0 .0).root();
+                        postcard::to_stdvec(&(salt, root))
+                    })
+                    .transpose()?
+            }
+            Column::ContractsAssets => {
+                let contract_id = ContractId::try_from(&key[..ContractId::LEN])?;
+                let asset_id = AssetId::try_from(&key[ContractId::LEN..])?;
+                let balance = client
+                    .contract_balance(
+                        &format!("{contract_id:#x}"),
+                        Some(&format!("{asset_id:#x}")),
+                    )
+                    .await?;
+                // The remote node returns zero for unknown balances.
+                (balance != 0)
+                    .then(|| postcard::to_stdvec(&balance))
+                    .transpose()?
+            }
+            Column::ContractsState => {
+                let contract_id = ContractId::try_from(&key[..ContractId::LEN])?;
+                let slot = Bytes32::try_from(&key[ContractId::LEN..])?;
+                client
+                    .contract_storage_slot(
+                        &format!("{contract_id:#x}"),
+                        &format!("{slot:#x}"),
+                    )
+                    .await?
+                    .map(|value| postcard::to_stdvec(&Bytes32::from(value)))
+                    .transpose()?
+            }
+            _ => None,
+        };
+        Ok(value)
+    }
+}
+
+impl RemoteState for GraphQLRemoteState {
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        let runtime = self
+            .runtime
+            .as_ref()
+            .expect("The runtime is dropped only on drop");
+        let task = runtime.spawn(Self::fetch(
+            self.client.clone(),
+            self.height,
+            key.to_vec(),
+            column,
+        ));
+        let wait = || futures::executor::block_on(task);
+        // Let the runtime of the caller move other tasks off the blocked worker.
+        let value = match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(wait)
+            }
+            _ => wait(),
+        }
+        .map_err(|e| anyhow!(e))?
+        .map_err(|e| {
+            DatabaseError::Other(e.context(format!(
+                "Failed to fetch the value of {column:?} from the remote node"
+            )))
+        })?;
+        Ok(value.map(Arc::new))
+    }
+}
+
+impl Drop for GraphQLRemoteState {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        prefix_upper_bound,
         BatchOperations,
         IterDirection,
         KVItem,
@@ -20,92 +21,128 @@ use fuel_core_storage::iter::{
 use std::{
     collections::BTreeMap,
     fmt::Debug,
+    ops::Bound,
     sync::{
         Arc,
         Mutex,
+        MutexGuard,
     },
 };
 
+/// The column is shared with iterators, so they see the column as it was at the start
+/// of the iteration. Writes copy the column only while some iterator holds it.
+type SharedColumn = Arc<BTreeMap<Vec<u8>, Value>>;
+
 #[derive(Default, Debug)]
 pub struct MemoryStore {
     // TODO: Remove `Mutex`.
-    inner: [Mutex<BTreeMap<Vec<u8>, Value>>; Column::COUNT],
+    inner: [Mutex<SharedColumn>; Column::COUNT],
+}
+
+/// The copy of all columns of the database. Values are reference counted, so cloning
+/// of the snapshot doesn't copy them.
+#[derive(Clone, Debug, Default)]
+pub struct MemorySnapshot {
+    pub(crate) inner: [BTreeMap<Vec<u8>, Value>; Column::COUNT],
 }
 
+/// The maximum number of entries cloned out of a column while holding its lock.
+const ITER_PAGE_SIZE: usize = 256;
+
 impl MemoryStore {
+    /// Iterates over the snapshot of the column page by page. Each page resumes after
+    /// the last returned key, so a partially consumed iterator costs at most one page
+    /// of clones instead of a copy of the whole range. Writes that happen during the
+    /// iteration are not visible to it.
     pub fn iter_all(
         &self,
         column: Column,
         prefix: Option<&[u8]>,
         start: Option<&[u8]>,
         direction: IterDirection,
-    ) -> impl Iterator<Item = KVItem> {
-        let lock = self.inner[column.as_usize()].lock().expect("poisoned");
-
-        fn clone<K: Clone, V: Clone>(kv: (&K, &V)) -> (K, V) {
-            (kv.0.clone(), kv.1.clone())
-        }
-
-        let collection: Vec<_> = match (prefix, start) {
-            (None, None) => {
+    ) -> impl Iterator<Item = KVItem> + '_ {
+        let snapshot = self.column(column).clone();
+        let prefix = prefix.map(|prefix| prefix.to_vec());
+        let mut cursor = match (start, prefix.as_ref()) {
+            (Some(start), _) => Bound::Included(start.to_vec()),
+            (None, Some(prefix)) => {
                 if direction == IterDirection::Forward {
-                    lock.iter().map(clone).collect()
+                    Bound::Included(prefix.clone())
                 } else {
-                    lock.iter().rev().map(clone).collect()
+                    prefix_upper_bound(prefix)
+                        .map(Bound::Excluded)
+                        .unwrap_or(Bound::Unbounded)
                 }
             }
-            (Some(prefix), None) => {
-                if direction == IterDirection::Forward {
-                    lock.range(prefix.to_vec()..)
-                        .take_while(|(key, _)| key.starts_with(prefix))
-                        .map(clone)
-                        .collect()
-                } else {
-                    let mut vec: Vec<_> = lock
-                        .range(prefix.to_vec()..)
-                        .into_boxed()
-                        .take_while(|(key, _)| key.starts_with(prefix))
-                        .map(clone)
-                        .collect();
-
-                    vec.reverse();
-                    vec
-                }
+            (None, None) => Bound::Unbounded,
+        };
+        let mut page = Vec::new().into_iter();
+        // The `start` outside of the `prefix` yields nothing, the same as in RocksDB.
+        let mut exhausted = matches!(
+            (prefix.as_ref(), start),
+            (Some(prefix), Some(start)) if !start.starts_with(prefix)
+        );
+
+        std::iter::from_fn(move || {
+            if let Some(entry) = page.next() {
+                return Some(Ok(entry))
             }
-            (None, Some(start)) => {
-                if direction == IterDirection::Forward {
-                    lock.range(start.to_vec()..).map(clone).collect()
-                } else {
-                    lock.range(..=start.to_vec()).rev().map(clone).collect()
-                }
+            if exhausted {
+                return None
             }
-            (Some(prefix), Some(start)) => {
-                if direction == IterDirection::Forward {
-                    lock.range(start.to_vec()..)
-                        .take_while(|(key, _)| key.starts_with(prefix))
-                        .map(clone)
-                        .collect()
-                } else {
-                    lock.range(..=start.to_vec())
-                        .rev()
-                        .take_while(|(key, _)| key.starts_with(prefix))
-                        .map(clone)
-                        .collect()
-                }
+
+            let next_page = read_page(&snapshot, &cursor, prefix.as_deref(), direction);
+
+            exhausted = next_page.len() < ITER_PAGE_SIZE;
+            if let Some((key, _)) = next_page.last() {
+                cursor = Bound::Excluded(key.clone());
             }
-        };
+            page = next_page.into_iter();
+            page.next().map(Ok)
+        })
+    }
+
+    fn column(&self, column: Column) -> MutexGuard<'_, SharedColumn> {
+        self.inner[column.as_usize()].lock().expect("poisoned")
+    }
 
-        collection.into_iter().map(Ok)
+    /// Modifies the column, copying it first if some iterator holds its snapshot.
+    fn modify<R>(
+        &self,
+        column: Column,
+        f: impl FnOnce(&mut BTreeMap<Vec<u8>, Value>) -> R,
+    ) -> R {
+        f(Arc::make_mut(&mut self.column(column)))
     }
 }
 
+/// Clones up to `ITER_PAGE_SIZE` entries following the `cursor` in the `direction`.
+fn read_page(
+    map: &BTreeMap<Vec<u8>, Value>,
+    cursor: &Bound<Vec<u8>>,
+    prefix: Option<&[u8]>,
+    direction: IterDirection,
+) -> Vec<(Vec<u8>, Value)> {
+    let range = if direction == IterDirection::Forward {
+        map.range((cursor.clone(), Bound::Unbounded)).into_boxed()
+    } else {
+        map.range((Bound::Unbounded, cursor.clone()))
+            .rev()
+            .into_boxed()
+    };
+
+    range
+        .take_while(|(key, _)| {
+            prefix.map(|prefix| key.starts_with(prefix)).unwrap_or(true)
+        })
+        .take(ITER_PAGE_SIZE)
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
 impl KeyValueStore for MemoryStore {
     fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .get(&key.to_vec())
-            .cloned())
+        Ok(self.column(column).get(&key.to_vec()).cloned())
     }
 
     fn put(
@@ -114,24 +151,15 @@ impl KeyValueStore for MemoryStore {
         column: Column,
         value: Value,
     ) -> DatabaseResult<Option<Value>> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .insert(key.to_vec(), value))
+        Ok(self.modify(column, |map| map.insert(key.to_vec(), value)))
     }
 
     fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .remove(&key.to_vec()))
+        Ok(self.modify(column, |map| map.remove(&key.to_vec())))
     }
 
     fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .contains_key(&key.to_vec()))
+        Ok(self.column(column).contains_key(&key.to_vec()))
     }
 
     fn iter_all(
@@ -145,11 +173,7 @@ impl KeyValueStore for MemoryStore {
     }
 
     fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .get(&key.to_vec())
-            .map(|v| v.len()))
+        Ok(self.column(column).get(&key.to_vec()).map(|v| v.len()))
     }
 
     fn read(
@@ -158,9 +182,7 @@ impl KeyValueStore for MemoryStore {
         column: Column,
         mut buf: &mut [u8],
     ) -> DatabaseResult<Option<usize>> {
-        self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
+        self.column(column)
             .get(&key.to_vec())
             .map(|value| {
                 let read = value.len();
@@ -172,19 +194,14 @@ impl KeyValueStore for MemoryStore {
     }
 
     fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .get(&key.to_vec())
-            .cloned())
+        Ok(self.column(column).get(&key.to_vec()).cloned())
     }
 
     fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
         let len = buf.len();
-        self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .insert(key.to_vec(), Arc::new(buf.to_vec()));
+        self.modify(column, |map| {
+            map.insert(key.to_vec(), Arc::new(buf.to_vec()))
+        });
         Ok(len)
     }
 
@@ -195,18 +212,22 @@ impl KeyValueStore for MemoryStore {
         buf: &[u8],
     ) -> DatabaseResult<(usize, Option<Value>)> {
         let len = buf.len();
-        let existing = self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .insert(key.to_vec(), Arc::new(buf.to_vec()));
+        let existing = self.modify(column, |map| {
+            map.insert(key.to_vec(), Arc::new(buf.to_vec()))
+        });
         Ok((len, existing))
     }
 
     fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
-        Ok(self.inner[column.as_usize()]
-            .lock()
-            .expect("poisoned")
-            .remove(&key.to_vec()))
+        Ok(self.modify(column, |map| map.remove(&key.to_vec())))
+    }
+}
+
+impl From<MemorySnapshot> for MemoryStore {
+    fn from(snapshot: MemorySnapshot) -> Self {
+        Self {
+            inner: snapshot.inner.map(|column| Mutex::new(Arc::new(column))),
+        }
     }
 }
 
@@ -300,4 +321,122 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn iter_all_matches_btree_order_across_pages() {
+        let db = MemoryStore::default();
+        let value = Arc::new(vec![]);
+        let keys: Vec<Vec<u8>> = (0..3u8)
+            .flat_map(|prefix| {
+                (0..ITER_PAGE_SIZE as u16 + 10).map(move |i| {
+                    let mut key = vec![prefix];
+                    key.extend_from_slice(&i.to_be_bytes());
+                    key
+                })
+            })
+            .collect();
+        for key in &keys {
+            db.put(key, Column::Metadata, value.clone()).unwrap();
+        }
+
+        let collect =
+            |prefix: Option<&[u8]>, start: Option<&[u8]>, direction: IterDirection| {
+                db.iter_all(Column::Metadata, prefix, start, direction)
+                    .map(|result| result.unwrap().0)
+                    .collect::<Vec<_>>()
+            };
+        let start = keys[ITER_PAGE_SIZE + 100].clone();
+        let with_prefix = |prefix: u8| {
+            keys.iter()
+                .filter(move |key| key[0] == prefix)
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(collect(None, None, IterDirection::Forward), keys);
+        assert_eq!(
+            collect(None, None, IterDirection::Reverse),
+            keys.iter().rev().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect(Some(&[1]), None, IterDirection::Forward),
+            with_prefix(1)
+        );
+        assert_eq!(
+            collect(Some(&[1]), None, IterDirection::Reverse),
+            with_prefix(1).into_iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect(None, Some(&start), IterDirection::Forward),
+            keys.iter()
+                .filter(|key| **key >= start)
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect(Some(&[1]), Some(&start), IterDirection::Reverse),
+            with_prefix(1)
+                .into_iter()
+                .filter(|key| *key <= start)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iter_all_clones_at_most_one_page_for_partial_reads() {
+        let db = MemoryStore::default();
+        let value = Arc::new(vec![1, 2, 3]);
+        let entries = 10 * ITER_PAGE_SIZE;
+        for i in 0..entries as u32 {
+            db.put(&i.to_be_bytes(), Column::Metadata, value.clone())
+                .unwrap();
+        }
+        let baseline = Arc::strong_count(&value);
+
+        let mut iter = db.iter_all(Column::Metadata, None, None, IterDirection::Forward);
+        let first: Vec<_> = iter.by_ref().take(10).collect();
+
+        // Only the first page is copied out of the store, not the whole column.
+        assert_eq!(first.len(), 10);
+        assert_eq!(Arc::strong_count(&value) - baseline, ITER_PAGE_SIZE);
+
+        drop(first);
+        let rest = iter.count();
+        assert_eq!(rest, entries - 10);
+        assert_eq!(Arc::strong_count(&value), baseline);
+    }
+
+    #[test]
+    fn iter_all_doesnt_see_writes_during_iteration() {
+        let db = MemoryStore::default();
+        let value = Arc::new(vec![]);
+        let entries = 3 * ITER_PAGE_SIZE as u32;
+        for i in 0..entries {
+            db.put(&i.to_be_bytes(), Column::Metadata, value.clone())
+                .unwrap();
+        }
+
+        let mut iter = db.iter_all(Column::Metadata, None, None, IterDirection::Forward);
+        let first = iter.next().unwrap().unwrap().0;
+        db.delete(&(entries - 1).to_be_bytes(), Column::Metadata)
+            .unwrap();
+        db.put(&entries.to_be_bytes(), Column::Metadata, value.clone())
+            .unwrap();
+        let keys: Vec<_> = std::iter::once(first)
+            .chain(iter.map(|result| result.unwrap().0))
+            .collect();
+
+        let expected: Vec<_> = (0..entries).map(|i| i.to_be_bytes().to_vec()).collect();
+        assert_eq!(keys, expected);
+        // The next iteration sees the writes.
+        assert_eq!(
+            db.iter_all(Column::Metadata, None, None, IterDirection::Forward)
+                .last()
+                .unwrap()
+                .unwrap()
+                .0,
+            entries.to_be_bytes().to_vec()
+        );
+    }
 }
@@ -66,6 +66,28 @@ impl MemoryTransactionView {
 
         self.data_source.batch_write(&mut iter)
     }
+
+    /// Returns the copy of the view with the same uncommitted changes on top of the
+    /// `source`.
+    pub fn rebase(&self, source: DataSource) -> DatabaseResult<Self> {
+        let view = Self::new(source);
+        let columns = self.changes.iter().zip(view.changes.iter());
+        for ((changes, rebased), column) in columns.zip(enum_iterator::all::<Column>()) {
+            let changes = changes.lock().expect("poisoned lock");
+            for (key, operation) in changes.iter() {
+                match operation {
+                    WriteOperation::Insert(value) => {
+                        view.view_layer.put(key, column, value.clone())?;
+                    }
+                    WriteOperation::Remove => {
+                        view.view_layer.delete(key, column)?;
+                    }
+                }
+            }
+            *rebased.lock().expect("poisoned lock") = changes.clone();
+        }
+        Ok(view)
+    }
 }
 
 impl KeyValueStore for MemoryTransactionView {
@@ -284,7 +306,12 @@ impl KeyValueStore for MemoryTransactionView {
 
 impl BatchOperations for MemoryTransactionView {}
 
-impl TransactableStorage for MemoryTransactionView {}
+impl TransactableStorage for MemoryTransactionView {
+    fn fork(&self) -> Option<DatabaseResult<DataSource>> {
+        let view = self.rebase(self.data_source.clone());
+        Some(view.map(|view| Arc::new(view) as DataSource))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -708,4 +735,36 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn rebase_moves_changes_on_top_of_another_source() {
+        let key = vec![0xA, 0xB, 0xC];
+        let removed = vec![0xD, 0xE, 0xF];
+        let value = Arc::new(vec![1, 2, 3]);
+        let view = MemoryTransactionView::new(Arc::new(MemoryStore::default()));
+        view.put(&key, Column::Metadata, value.clone()).unwrap();
+        view.delete(&removed, Column::Metadata).unwrap();
+
+        let store = Arc::new(MemoryStore::default());
+        store
+            .put(&removed, Column::Metadata, value.clone())
+            .unwrap();
+        let rebased = view.rebase(store.clone()).unwrap();
+
+        assert_eq!(
+            rebased.get(&key, Column::Metadata).unwrap(),
+            Some(value.clone())
+        );
+        assert_eq!(rebased.get(&removed, Column::Metadata).unwrap(), None);
+
+        rebased.commit().unwrap();
+
+        assert_eq!(
+            store.get(&key, Column::Metadata).unwrap(),
+            Some(value.clone())
+        );
+        assert!(!store.exists(&removed, Column::Metadata).unwrap());
+        // The original view keeps its changes.
+        assert_eq!(view.get(&key, Column::Metadata).unwrap(), Some(value));
+    }
 }
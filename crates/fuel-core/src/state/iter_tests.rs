@@ -0,0 +1,165 @@
+//! Conformance tests of `KeyValueStore::iter_all` shared by all backends. Every backend
+//! must yield the same keys in the same order as the reference computed over the
+//! sorted keys.
+
+use crate::{
+    database::Column,
+    state::{
+        in_memory::{
+            memory_store::MemoryStore,
+            transaction::MemoryTransactionView,
+        },
+        IterDirection,
+        KeyValueStore,
+    },
+};
+use std::sync::Arc;
+
+/// Keys around the edges of prefixes, including the prefix of `u8::MAX` bytes.
+fn short_keys() -> Vec<Vec<u8>> {
+    vec![
+        vec![0],
+        vec![1],
+        vec![1, 0],
+        vec![1, 1],
+        vec![1, 1, 0],
+        vec![1, 2],
+        vec![1, 255],
+        vec![2],
+        vec![255],
+        vec![255, 255],
+    ]
+}
+
+/// Keys of the column with the fixed 32-byte prefix extractor in RocksDB.
+fn owner_keys() -> Vec<Vec<u8>> {
+    [[0u8; 32], [1; 32], [255; 32]]
+        .into_iter()
+        .flat_map(|owner| {
+            [[0u8; 8], [1; 8], [255; 8]]
+                .into_iter()
+                .map(move |suffix| [owner.as_slice(), suffix.as_slice()].concat())
+        })
+        .collect()
+}
+
+fn short_queries() -> Vec<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    let prefixes = [None, Some(vec![]), Some(vec![1]), Some(vec![1, 1]), Some(vec![255])];
+    let starts = [
+        None,
+        Some(vec![0]),
+        Some(vec![1]),
+        Some(vec![1, 1]),
+        Some(vec![1, 1, 5]),
+        Some(vec![1, 3]),
+        Some(vec![2]),
+        Some(vec![255, 255]),
+    ];
+    prefixes
+        .iter()
+        .flat_map(|prefix| starts.iter().map(|start| (prefix.clone(), start.clone())))
+        .collect()
+}
+
+fn owner_queries() -> Vec<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    let prefixes = [None, Some(vec![0; 32]), Some(vec![1; 32]), Some(vec![255; 32])];
+    let starts = [
+        None,
+        Some([vec![1; 32], vec![1; 8]].concat()),
+        Some([vec![1; 32], vec![5; 8]].concat()),
+        Some([vec![255; 32], vec![0; 8]].concat()),
+    ];
+    prefixes
+        .iter()
+        .flat_map(|prefix| starts.iter().map(|start| (prefix.clone(), start.clone())))
+        .collect()
+}
+
+/// The expected result of `iter_all` over the `keys`.
+fn reference(
+    keys: &[Vec<u8>],
+    prefix: Option<&[u8]>,
+    start: Option<&[u8]>,
+    direction: IterDirection,
+) -> Vec<Vec<u8>> {
+    let prefix = prefix.unwrap_or_default();
+    if !start.map(|start| start.starts_with(prefix)).unwrap_or(true) {
+        return vec![]
+    }
+    let mut keys: Vec<_> = keys
+        .iter()
+        .filter(|key| key.starts_with(prefix))
+        .cloned()
+        .collect();
+    keys.sort();
+    if direction == IterDirection::Reverse {
+        keys.reverse();
+    }
+    keys.into_iter()
+        .filter(|key| match (start, direction) {
+            (Some(start), IterDirection::Forward) => key.as_slice() >= start,
+            (Some(start), IterDirection::Reverse) => key.as_slice() <= start,
+            (None, _) => true,
+        })
+        .collect()
+}
+
+fn check(
+    store: &dyn KeyValueStore,
+    column: Column,
+    keys: &[Vec<u8>],
+    queries: &[(Option<Vec<u8>>, Option<Vec<u8>>)],
+) {
+    for (prefix, start) in queries {
+        for direction in [IterDirection::Forward, IterDirection::Reverse] {
+            let actual: Vec<_> = store
+                .iter_all(column, prefix.as_deref(), start.as_deref(), direction)
+                .map(|item| item.unwrap().0)
+                .collect();
+            let expected =
+                reference(keys, prefix.as_deref(), start.as_deref(), direction);
+            assert_eq!(
+                actual, expected,
+                "prefix {prefix:?}, start {start:?}, direction {direction:?}"
+            );
+        }
+    }
+}
+
+/// Fills the `store` with test keys and checks the iteration over them. The `split`
+/// store receives every second key, so the iteration has to merge both.
+fn check_store(store: &dyn KeyValueStore, split: Option<&dyn KeyValueStore>) {
+    for (column, keys, queries) in [
+        (Column::Metadata, short_keys(), short_queries()),
+        (Column::TransactionsByOwnerBlockIdx, owner_keys(), owner_queries()),
+    ] {
+        for (i, key) in keys.iter().enumerate() {
+            let target = match split {
+                Some(split) if i % 2 == 1 => split,
+                _ => store,
+            };
+            target.put(key, column, Arc::new(vec![])).unwrap();
+        }
+        check(split.unwrap_or(store), column, &keys, &queries);
+    }
+}
+
+#[test]
+fn memory_store_iterates_in_both_directions_within_prefix() {
+    check_store(&MemoryStore::default(), None);
+}
+
+#[test]
+fn memory_transaction_view_iterates_in_both_directions_within_prefix() {
+    let source = Arc::new(MemoryStore::default());
+    let view = MemoryTransactionView::new(source.clone());
+    check_store(source.as_ref(), Some(&view));
+}
+
+#[cfg(feature = "rocksdb")]
+#[test]
+fn rocks_db_iterates_in_both_directions_within_prefix() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let db = crate::state::rocks_db::RocksDb::default_open(tmp_dir.path(), None).unwrap();
+    check_store(&db, None);
+}
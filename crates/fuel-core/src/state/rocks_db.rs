@@ -6,6 +6,7 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        prefix_upper_bound,
         BatchOperations,
         IterDirection,
         KVItem,
@@ -156,6 +157,20 @@ impl RocksDb {
     }
 }
 
+/// Returns read options that bound the iterator to keys starting with `prefix`.
+///
+/// The prefix seek of RocksDB doesn't support the reverse iteration, so the iterator
+/// uses the total order seek between explicit bounds instead.
+fn prefix_read_options(prefix: &[u8]) -> ReadOptions {
+    let mut opts = ReadOptions::default();
+    opts.set_total_order_seek(true);
+    opts.set_iterate_lower_bound(prefix.to_vec());
+    if let Some(upper_bound) = prefix_upper_bound(prefix) {
+        opts.set_iterate_upper_bound(upper_bound);
+    }
+    opts
+}
+
 impl KeyValueStore for RocksDb {
     fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
         #[cfg(feature = "metrics")]
@@ -232,13 +247,14 @@ impl KeyValueStore for RocksDb {
                     .into_boxed()
             }
             (Some(prefix), None) => {
-                // start iterating in a certain direction within the keyspace
-                let iter_mode =
-                    IteratorMode::From(prefix, convert_to_rocksdb_direction(direction));
-                let mut opts = ReadOptions::default();
-                opts.set_prefix_same_as_start(true);
-
-                self._iter_all(column, opts, iter_mode).into_boxed()
+                // iterate over the whole prefix, the bounds of the read options
+                // keep the iterator inside of it in both directions
+                let iter_mode = match direction {
+                    IterDirection::Forward => IteratorMode::Start,
+                    IterDirection::Reverse => IteratorMode::End,
+                };
+                self._iter_all(column, prefix_read_options(prefix), iter_mode)
+                    .into_boxed()
             }
             (None, Some(start)) => {
                 // start iterating in a certain direction from the start key
@@ -256,17 +272,9 @@ impl KeyValueStore for RocksDb {
 
                 // start iterating in a certain direction from the start key
                 // and end iterating when we've gone outside the prefix
-                let prefix = prefix.to_vec();
                 let iter_mode =
                     IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
-                    .take_while(move |item| {
-                        if let Ok((key, _)) = item {
-                            key.starts_with(prefix.as_slice())
-                        } else {
-                            true
-                        }
-                    })
+                self._iter_all(column, prefix_read_options(prefix), iter_mode)
                     .into_boxed()
             }
         }
@@ -0,0 +1,233 @@
+//! The storage that records slow commits into the [`SlowLog`] and counts rows read by
+//! GraphQL requests.
+
+use crate::{
+    database::{
+        Column,
+        Result as DatabaseResult,
+    },
+    service::slow_log::{
+        add_rows,
+        OperationKind,
+        SlowLog,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        TransactableStorage,
+        Value,
+        WriteOperation,
+    },
+};
+use fuel_core_storage::iter::{
+    BoxedIter,
+    IntoBoxedIter,
+};
+use std::time::Instant;
+
+#[derive(Debug)]
+pub struct SlowLogStore {
+    inner: DataSource,
+    slow_log: SlowLog,
+}
+
+impl SlowLogStore {
+    pub fn new(inner: DataSource, slow_log: SlowLog) -> Self {
+        Self { inner, slow_log }
+    }
+}
+
+impl KeyValueStore for SlowLogStore {
+    fn put(
+        &self,
+        key: &[u8],
+        column: Column,
+        value: Value,
+    ) -> DatabaseResult<Option<Value>> {
+        self.inner.put(key, column, value)
+    }
+
+    fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
+        self.inner.write(key, column, buf)
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &[u8],
+    ) -> DatabaseResult<(usize, Option<Value>)> {
+        self.inner.replace(key, column, buf)
+    }
+
+    fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.inner.take(key, column)
+    }
+
+    fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.inner.delete(key, column)
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        add_rows(1);
+        self.inner.exists(key, column)
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        add_rows(1);
+        self.inner.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        add_rows(1);
+        self.inner.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        add_rows(1);
+        self.inner.read(key, column, buf)
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        add_rows(1);
+        self.inner.read_alloc(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner
+            .iter_all(column, prefix, start, direction)
+            .map(|item| {
+                add_rows(1);
+                item
+            })
+            .into_boxed()
+    }
+}
+
+impl BatchOperations for SlowLogStore {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Column, WriteOperation)>,
+    ) -> DatabaseResult<()> {
+        let mut columns = vec![];
+        let mut rows = 0;
+        let mut entries = entries.inspect(|(_, column, _)| {
+            if !columns.contains(column) {
+                columns.push(*column);
+            }
+            rows += 1;
+        });
+
+        let start = Instant::now();
+        let result = self.inner.batch_write(&mut entries);
+        let duration = start.elapsed();
+
+        if duration >= self.slow_log.storage_threshold() {
+            let columns: Vec<_> =
+                columns.iter().map(|column| format!("{column:?}")).collect();
+            self.slow_log.record(
+                OperationKind::Storage,
+                columns.join(","),
+                None,
+                duration,
+                rows,
+            );
+        }
+        result
+    }
+}
+
+impl TransactableStorage for SlowLogStore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        service::slow_log::{
+            count_rows,
+            Config,
+        },
+        state::in_memory::memory_store::MemoryStore,
+    };
+    use std::{
+        sync::Arc,
+        time::Duration,
+    };
+
+    fn store(storage_threshold: Duration) -> (SlowLogStore, SlowLog) {
+        let slow_log = SlowLog::new(Config {
+            storage_threshold,
+            ..Default::default()
+        });
+        let store = SlowLogStore::new(Arc::new(MemoryStore::default()), slow_log.clone());
+        (store, slow_log)
+    }
+
+    #[test]
+    fn slow_commit_is_recorded_with_its_columns_and_rows() {
+        let (store, slow_log) = store(Duration::ZERO);
+        let mut entries = vec![
+            (
+                vec![1],
+                Column::Coins,
+                WriteOperation::Insert(Arc::new(vec![1])),
+            ),
+            (vec![2], Column::Coins, WriteOperation::Remove),
+            (vec![3], Column::Messages, WriteOperation::Remove),
+        ]
+        .into_iter();
+
+        store.batch_write(&mut entries).unwrap();
+
+        let operations = slow_log.operations();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].kind, OperationKind::Storage);
+        assert_eq!(operations[0].operation, "Coins,Messages");
+        assert_eq!(operations[0].rows, 3);
+    }
+
+    #[test]
+    fn fast_commit_is_not_recorded() {
+        let (store, slow_log) = store(Duration::from_secs(60));
+        let mut entries =
+            vec![(vec![1], Column::Coins, WriteOperation::Remove)].into_iter();
+
+        store.batch_write(&mut entries).unwrap();
+
+        assert!(slow_log.operations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reads_are_counted() {
+        let (store, _) = store(Duration::from_secs(60));
+        for key in 0..3u8 {
+            store
+                .put(&[key], Column::Coins, Arc::new(vec![key]))
+                .unwrap();
+        }
+
+        let (_, rows) = count_rows(async {
+            store.get(&[0], Column::Coins).unwrap();
+            store
+                .iter_all(Column::Coins, None, None, IterDirection::Forward)
+                .count()
+        })
+        .await;
+
+        assert_eq!(rows, 4);
+    }
+}
@@ -0,0 +1,347 @@
+//! # Deterministic network of nodes in one process
+//!
+//! The [`LocalNetwork`] runs one producer and several validators with in-memory databases.
+//! The nodes are connected via the in-memory p2p transport, so no sockets are opened for
+//! the peering. The producer creates blocks only on request with deterministic timestamps,
+//! and all keys and genesis coins are derived from the seed, so the same scenario always
+//! produces the same chain.
+
+use crate::{
+    chain_config::{
+        ChainConfig,
+        ConsensusConfig,
+    },
+    database::Database,
+    fuel_core_graphql_api::ports::ConsensusModulePort,
+    p2p::{
+        config::convert_to_libp2p_keypair,
+        Multiaddr,
+    },
+    p2p_test_helpers::Bootstrap,
+    service::{
+        Config,
+        FuelService,
+        ServiceTrait,
+    },
+};
+use anyhow::anyhow;
+use fuel_core_poa::{
+    service::ManualGasLimit,
+    Trigger,
+};
+use fuel_core_storage::{
+    tables::Transactions,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        ConsensusParameters,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+    },
+    secrecy::Secret,
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::Duration,
+};
+
+/// The amount of each genesis coin of the [`LocalNetwork`].
+pub const COIN_AMOUNT: u64 = 10_000;
+
+#[derive(Clone, Debug)]
+/// Setup for the [`LocalNetwork`].
+pub struct LocalNetworkConfig {
+    /// The seed used to derive the keys and the genesis coins.
+    pub seed: u64,
+    /// Number of validators that sync blocks from the producer.
+    pub validators: usize,
+    /// Number of genesis coins that can be spent by [`LocalNetwork::transfer_tx`].
+    pub coins: usize,
+}
+
+/// A node of the [`LocalNetwork`].
+pub struct NodeHandle {
+    pub node: FuelService,
+    pub db: Database,
+    pub config: Config,
+}
+
+/// The producer and validators running in one process.
+pub struct LocalNetwork {
+    producer: NodeHandle,
+    validators: Vec<NodeHandle>,
+    /// Unspent genesis coins with the key of the owner.
+    coins: VecDeque<(SecretKey, UtxoId)>,
+    _bootstrap: Bootstrap,
+}
+
+impl Default for LocalNetworkConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            validators: 1,
+            coins: 10,
+        }
+    }
+}
+
+impl LocalNetworkConfig {
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    pub fn with_validators(self, validators: usize) -> Self {
+        Self { validators, ..self }
+    }
+
+    pub fn with_coins(self, coins: usize) -> Self {
+        Self { coins, ..self }
+    }
+}
+
+impl LocalNetwork {
+    /// Starts the producer and the validators bootstrapped with the same node.
+    pub async fn new(setup: LocalNetworkConfig) -> anyhow::Result<Self> {
+        let mut rng = StdRng::seed_from_u64(setup.seed);
+
+        let producer_secret = SecretKey::random(&mut rng);
+        let mut chain_config = ChainConfig::local_testnet();
+        chain_config.consensus = ConsensusConfig::PoA {
+            signing_key: Input::owner(&producer_secret.public_key()),
+            key_rotations: vec![],
+        };
+        let state = chain_config
+            .initial_state
+            .get_or_insert_with(Default::default);
+        let genesis_coins = state.coins.get_or_insert_with(Default::default);
+        let coins: VecDeque<_> = (0..setup.coins)
+            .map(|_| {
+                let secret = SecretKey::random(&mut rng);
+                let utxo_id: UtxoId = rng.gen();
+                genesis_coins.push(ChainConfig::initial_coin(
+                    secret,
+                    COIN_AMOUNT,
+                    Some(utxo_id),
+                ));
+                (secret, utxo_id)
+            })
+            .collect();
+
+        let bootstrap_config = make_config("bootstrap", &chain_config, &mut rng)?;
+        let bootstrap = Bootstrap::new(&bootstrap_config).await;
+        let bootstrap_nodes = bootstrap.listeners();
+
+        let mut producer_config = make_config("producer", &chain_config, &mut rng)?;
+        producer_config.block_production = Trigger::Never;
+        producer_config.manual_blocks_enabled = true;
+        producer_config.consensus_key = Some(Secret::new(producer_secret.into()));
+        set_bootstrap_nodes(&mut producer_config, &bootstrap_nodes);
+        let producer = NodeHandle::new(producer_config).await?;
+
+        let mut validators = Vec::with_capacity(setup.validators);
+        for i in 0..setup.validators {
+            let mut validator_config =
+                make_config(&format!("validator:{i}"), &chain_config, &mut rng)?;
+            validator_config.block_production = Trigger::Never;
+            validator_config.consensus_key = None;
+            set_bootstrap_nodes(&mut validator_config, &bootstrap_nodes);
+            validators.push(NodeHandle::new(validator_config).await?);
+        }
+
+        Ok(Self {
+            producer,
+            validators,
+            coins,
+            _bootstrap: bootstrap,
+        })
+    }
+
+    pub fn producer(&self) -> &NodeHandle {
+        &self.producer
+    }
+
+    pub fn validators(&self) -> &[NodeHandle] {
+        &self.validators
+    }
+
+    pub fn validators_mut(&mut self) -> &mut [NodeHandle] {
+        &mut self.validators
+    }
+
+    /// Returns the producer followed by the validators.
+    pub fn nodes(&self) -> impl Iterator<Item = &NodeHandle> {
+        core::iter::once(&self.producer).chain(self.validators.iter())
+    }
+
+    /// Creates the transaction that transfers the next unspent genesis coin to its owner.
+    /// Returns `None` if all genesis coins are spent.
+    pub fn transfer_tx(&mut self) -> Option<Transaction> {
+        let (secret, utxo_id) = self.coins.pop_front()?;
+        let owner = Input::owner(&secret.public_key());
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_limit(100_000)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            COIN_AMOUNT,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+        Some(tx)
+    }
+
+    /// Inserts the transaction into the transaction pool of the producer.
+    pub fn insert_tx(&self, tx: Transaction) -> anyhow::Result<TxId> {
+        let id = tx.id(&ConsensusParameters::DEFAULT);
+        self.producer
+            .node
+            .shared
+            .txpool
+            .insert(vec![Arc::new(tx)])
+            .pop()
+            .ok_or(anyhow!("The transaction pool didn't return the result"))??;
+        Ok(id)
+    }
+
+    /// Produces `number_of_blocks` blocks on the producer with the transactions from its pool.
+    /// The timestamp of each block is the number of seconds since the UNIX epoch equal to
+    /// its height. Returns the height of the last produced block.
+    pub async fn produce_blocks(
+        &self,
+        number_of_blocks: u32,
+    ) -> anyhow::Result<BlockHeight> {
+        let poa = &self.producer.node.shared.poa_adapter;
+        for _ in 0..number_of_blocks {
+            let next_height = *self.producer.latest_height()? + 1;
+            let start_time = Some(Tai64::UNIX_EPOCH + next_height as u64);
+            poa.manually_produce_blocks(start_time, 1, ManualGasLimit::Config, None)
+                .await?;
+        }
+        self.producer.latest_height()
+    }
+
+    /// Waits until all validators import the latest block of the producer.
+    pub async fn await_synced(&self, timeout: Duration) -> anyhow::Result<()> {
+        let height = self.producer.latest_height()?;
+        for validator in &self.validators {
+            validator.await_height(height, timeout).await?;
+        }
+        Ok(())
+    }
+}
+
+impl NodeHandle {
+    async fn new(config: Config) -> anyhow::Result<Self> {
+        let db = Database::in_memory();
+        let node = FuelService::from_database(db.clone(), config).await?;
+        let config = node.shared.config.clone();
+        Ok(Self { node, db, config })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    pub fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.db.latest_height()?)
+    }
+
+    /// Returns `true` if the transaction is included in the block of the node.
+    pub fn contains_tx(&self, id: &TxId) -> anyhow::Result<bool> {
+        Ok(self.db.storage::<Transactions>().contains_key(id)?)
+    }
+
+    /// Waits until the node imports the block at the `height`.
+    pub async fn await_height(
+        &self,
+        height: BlockHeight,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
+        let mut blocks = self.node.shared.block_importer.block_importer.subscribe();
+        tokio::time::timeout(timeout, async {
+            while self.latest_height()? < height {
+                blocks.recv().await?;
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "The node {:?} didn't reach the height {} in {:?}",
+                self.name(),
+                *height,
+                timeout
+            )
+        })?
+    }
+
+    /// Starts the node that has been shut down.
+    pub async fn start(&mut self) -> anyhow::Result<()> {
+        self.node =
+            FuelService::from_database(self.db.clone(), self.config.clone()).await?;
+        Ok(())
+    }
+
+    /// Stops the node, the state of the node is preserved.
+    pub async fn shutdown(&mut self) -> anyhow::Result<()> {
+        self.node.stop_and_await().await?;
+        Ok(())
+    }
+}
+
+fn make_config(
+    name: &str,
+    chain_config: &ChainConfig,
+    rng: &mut StdRng,
+) -> anyhow::Result<Config> {
+    let mut config = Config::local_node();
+    config.name = name.to_string();
+    config.chain_conf = chain_config.clone();
+    config.txpool.chain_config = chain_config.clone();
+    config.utxo_validation = true;
+    config.txpool.utxo_validation = true;
+
+    let p2p = config
+        .p2p
+        .as_mut()
+        .expect("The local node has the p2p config");
+    p2p.memory_transport = true;
+    p2p.keypair = convert_to_libp2p_keypair(&mut SecretKey::random(rng).to_vec())?;
+    Ok(config)
+}
+
+fn set_bootstrap_nodes(config: &mut Config, bootstrap_nodes: &[Multiaddr]) {
+    config
+        .p2p
+        .as_mut()
+        .expect("The local node has the p2p config")
+        .bootstrap_nodes = bootstrap_nodes.to_vec();
+}
@@ -3,5 +3,9 @@
 pub mod core_metrics;
 pub mod graphql_metrics;
 pub mod p2p_metrics;
+pub mod poa_metrics;
+pub mod relayer_metrics;
 pub mod service;
+pub mod services_metrics;
 pub mod txpool_metrics;
+pub mod version_metrics;
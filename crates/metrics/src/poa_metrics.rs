@@ -0,0 +1,46 @@
+use lazy_static::lazy_static;
+use prometheus_client::{
+    metrics::counter::Counter,
+    registry::Registry,
+};
+
+pub struct PoAMetrics {
+    pub registry: Registry,
+    pub clock_jumps: Counter,
+    pub blocks_ahead_of_clock: Counter,
+    pub invalid_produced_blocks: Counter,
+}
+
+impl PoAMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let clock_jumps = Counter::default();
+        registry.register(
+            "poa_clock_jumps",
+            "The number of detected jumps of the system clock",
+            clock_jumps.clone(),
+        );
+        let blocks_ahead_of_clock = Counter::default();
+        registry.register(
+            "poa_blocks_ahead_of_clock",
+            "The number of verified blocks with the time ahead of the system clock",
+            blocks_ahead_of_clock.clone(),
+        );
+        let invalid_produced_blocks = Counter::default();
+        registry.register(
+            "poa_invalid_produced_blocks",
+            "The number of produced blocks refused to be sealed by the verification",
+            invalid_produced_blocks.clone(),
+        );
+        Self {
+            registry,
+            clock_jumps,
+            blocks_ahead_of_clock,
+            invalid_produced_blocks,
+        }
+    }
+}
+
+lazy_static! {
+    pub static ref POA_METRICS: PoAMetrics = PoAMetrics::new();
+}
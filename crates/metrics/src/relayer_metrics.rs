@@ -0,0 +1,30 @@
+use lazy_static::lazy_static;
+use prometheus_client::{
+    metrics::gauge::Gauge,
+    registry::Registry,
+};
+
+pub struct RelayerMetrics {
+    pub registry: Registry,
+    pub wallet_balance_gwei: Gauge,
+}
+
+impl RelayerMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let wallet_balance_gwei = Gauge::default();
+        registry.register(
+            "relayer_wallet_balance_gwei",
+            "The balance of the monitored L1 wallet in gwei",
+            wallet_balance_gwei.clone(),
+        );
+        Self {
+            registry,
+            wallet_balance_gwei,
+        }
+    }
+}
+
+lazy_static! {
+    pub static ref RELAYER_METRICS: RelayerMetrics = RelayerMetrics::new();
+}
@@ -1,7 +1,11 @@
 use crate::{
     graphql_metrics::GRAPHQL_METRICS,
     p2p_metrics::P2P_METRICS,
+    poa_metrics::POA_METRICS,
+    relayer_metrics::RELAYER_METRICS,
+    services_metrics::SERVICES_METRICS,
     txpool_metrics::TXPOOL_METRICS,
+    version_metrics::VERSION_METRICS,
 };
 use axum::{
     body::Body,
@@ -36,6 +40,23 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &RELAYER_METRICS.registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &POA_METRICS.registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &VERSION_METRICS.registry).is_err() {
+        return error_body()
+    }
+
+    SERVICES_METRICS.update_lifecycles();
+    if encode(&mut encoded, &SERVICES_METRICS.registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
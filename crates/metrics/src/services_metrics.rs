@@ -0,0 +1,337 @@
+use lazy_static::lazy_static;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
+    registry::Registry,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The name of the state in which the service is running.
+pub const STARTED: &str = "Started";
+/// The name of the state of the service before the start.
+pub const NOT_STARTED: &str = "NotStarted";
+/// The buckets of the `service_iteration_seconds` histogram.
+const ITERATION_BUCKETS: [f64; 10] =
+    [0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 60.0];
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ServiceLabel {
+    // the name of the sub-service of the node
+    service: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct InstanceLabel {
+    service: String,
+    // the number of the instance among instances of the same service
+    instance: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct StateLabel {
+    service: String,
+    instance: String,
+    // the lifecycle state of the service, like `Started` or `Stopped`
+    state: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ErrorLabel {
+    service: String,
+    instance: String,
+    // the kind of the error, like `panic`, from the fixed set
+    kind: String,
+}
+
+/// The instance of the service whose metrics are recorded. Instances of the same service
+/// are numbered in the order of creation, so their metrics don't collide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceInstance {
+    label: InstanceLabel,
+    /// Overrides the stall threshold of all services.
+    stall_threshold: Option<Duration>,
+}
+
+impl ServiceInstance {
+    fn error_label(&self, kind: &str) -> ErrorLabel {
+        ErrorLabel {
+            service: self.label.service.clone(),
+            instance: self.label.instance.clone(),
+            kind: kind.to_string(),
+        }
+    }
+}
+
+/// The current lifecycle state of the instance of the service.
+struct Lifecycle {
+    state: &'static str,
+    since: Instant,
+    started_at: Option<Instant>,
+    last_error: Option<ErrorLabel>,
+}
+
+pub struct ServicesMetrics {
+    pub registry: Registry,
+    health: Family<ServiceLabel, Gauge>,
+    restarts: Family<InstanceLabel, Counter>,
+    uptime: Family<InstanceLabel, Gauge<f64, AtomicU64>>,
+    state_duration: Family<StateLabel, Counter<f64, AtomicU64>>,
+    last_error: Family<ErrorLabel, Gauge>,
+    iteration_duration: Family<InstanceLabel, Histogram>,
+    stalled_iterations: Family<InstanceLabel, Counter>,
+    /// The duration of the iteration in nanoseconds above which it is stalled, 0 if the
+    /// detection of stalls is disabled.
+    stall_threshold: AtomicU64,
+    /// The number of created instances of every service.
+    instances: Mutex<HashMap<String, u64>>,
+    lifecycles: Mutex<HashMap<InstanceLabel, Lifecycle>>,
+}
+
+impl ServicesMetrics {
+    /// The value of the healthy service.
+    pub const HEALTHY: i64 = 2;
+    /// The value of the degraded service.
+    pub const DEGRADED: i64 = 1;
+    /// The value of the unhealthy service.
+    pub const UNHEALTHY: i64 = 0;
+
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let health = Family::<ServiceLabel, Gauge>::default();
+        registry.register(
+            "service_health",
+            "The health of the service: 2 is healthy, 1 is degraded, 0 is unhealthy",
+            health.clone(),
+        );
+        let restarts = Family::<InstanceLabel, Counter>::default();
+        registry.register(
+            "service_restarts",
+            "The number of times the task of the service was restarted after a failure",
+            restarts.clone(),
+        );
+        let uptime = Family::<InstanceLabel, Gauge<f64, AtomicU64>>::default();
+        registry.register(
+            "service_uptime_seconds",
+            "The time since the service started, 0 if the service is not started",
+            uptime.clone(),
+        );
+        let state_duration = Family::<StateLabel, Counter<f64, AtomicU64>>::default();
+        registry.register(
+            "service_state_seconds",
+            "The time the service spent in each lifecycle state",
+            state_duration.clone(),
+        );
+        let last_error = Family::<ErrorLabel, Gauge>::default();
+        registry.register(
+            "service_last_error",
+            "The kind of the last error of the service and how many times in a row it \
+            occurred",
+            last_error.clone(),
+        );
+        let iteration_duration =
+            Family::<InstanceLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(ITERATION_BUCKETS.into_iter())
+            });
+        registry.register(
+            "service_iteration_seconds",
+            "The time the task of the service worked in iterations, excluding waits for \
+            events, and the number of iterations",
+            iteration_duration.clone(),
+        );
+        let stalled_iterations = Family::<InstanceLabel, Counter>::default();
+        registry.register(
+            "service_stalled_iterations",
+            "The number of iterations of the task of the service that exceeded the stall \
+            threshold",
+            stalled_iterations.clone(),
+        );
+        Self {
+            registry,
+            health,
+            restarts,
+            uptime,
+            state_duration,
+            last_error,
+            iteration_duration,
+            stalled_iterations,
+            stall_threshold: AtomicU64::new(0),
+            instances: Mutex::new(HashMap::new()),
+            lifecycles: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn set_health(&self, service: &str, value: i64) {
+        self.health
+            .get_or_create(&ServiceLabel {
+                service: service.to_string(),
+            })
+            .set(value);
+    }
+
+    /// Creates the next instance of the `service`. The `stall_threshold` overrides the
+    /// stall threshold of all services for the instance.
+    pub fn new_instance(
+        &self,
+        service: &str,
+        stall_threshold: Option<Duration>,
+    ) -> ServiceInstance {
+        let mut instances = self.instances.lock().expect("The lock is poisoned");
+        let count = instances.entry(service.to_string()).or_default();
+        let instance = *count;
+        *count += 1;
+        ServiceInstance {
+            label: InstanceLabel {
+                service: service.to_string(),
+                instance: instance.to_string(),
+            },
+            stall_threshold,
+        }
+    }
+
+    /// Records the transition of the `instance` into the `state`.
+    pub fn record_state(&self, instance: &ServiceInstance, state: &'static str) {
+        let now = Instant::now();
+        let mut lifecycles = self.lifecycles.lock().expect("The lock is poisoned");
+        let lifecycle = lifecycle(&mut lifecycles, instance, now);
+        self.flush_state_duration(&instance.label, lifecycle, now);
+        lifecycle.state = state;
+        if state == STARTED {
+            lifecycle.started_at.get_or_insert(now);
+        } else {
+            lifecycle.started_at = None;
+        }
+    }
+
+    pub fn record_restart(&self, instance: &ServiceInstance) {
+        self.restarts.get_or_create(&instance.label).inc();
+    }
+
+    /// Returns the number of restarts of the task of the `instance`.
+    pub fn restarts(&self, instance: &ServiceInstance) -> u64 {
+        self.restarts.get_or_create(&instance.label).get()
+    }
+
+    /// Replaces the last error of the `instance` with the error of the `kind`. The kind
+    /// should come from a fixed set, e.g. `panic`, to keep the number of labels bounded.
+    pub fn record_error(&self, instance: &ServiceInstance, kind: &'static str) {
+        let label = instance.error_label(kind);
+        let mut lifecycles = self.lifecycles.lock().expect("The lock is poisoned");
+        let lifecycle = lifecycle(&mut lifecycles, instance, Instant::now());
+        if let Some(last_error) = lifecycle.last_error.take() {
+            if last_error != label {
+                self.last_error.remove(&last_error);
+            }
+        }
+        self.last_error.get_or_create(&label).inc();
+        lifecycle.last_error = Some(label);
+    }
+
+    /// Returns the kind of the last error of the `instance` and how many times in a row
+    /// it occurred.
+    pub fn last_error(&self, instance: &ServiceInstance) -> Option<(String, i64)> {
+        let lifecycles = self.lifecycles.lock().expect("The lock is poisoned");
+        let label = lifecycles.get(&instance.label)?.last_error.clone()?;
+        let count = self.last_error.get_or_create(&label).get();
+        Some((label.kind, count))
+    }
+
+    /// Sets the duration of the iteration of the task above which it is reported as
+    /// stalled. The detection of stalls is disabled if the `threshold` is not set.
+    pub fn set_stall_threshold(&self, threshold: Option<Duration>) {
+        let nanos = threshold.map_or(0, |threshold| threshold.as_nanos() as u64);
+        self.stall_threshold.store(nanos, Ordering::Relaxed);
+    }
+
+    /// Records the iteration of the task of the `instance` that worked for the
+    /// `duration`. Returns `true` if the iteration exceeded the stall threshold of the
+    /// instance, or the stall threshold of all services if it is not set.
+    pub fn record_iteration(
+        &self,
+        instance: &ServiceInstance,
+        duration: Duration,
+    ) -> bool {
+        self.iteration_duration
+            .get_or_create(&instance.label)
+            .observe(duration.as_secs_f64());
+        let threshold = match instance.stall_threshold {
+            Some(threshold) => threshold.as_nanos(),
+            None => self.stall_threshold.load(Ordering::Relaxed) as u128,
+        };
+        let stalled = threshold != 0 && duration.as_nanos() > threshold;
+        if stalled {
+            self.stalled_iterations.get_or_create(&instance.label).inc();
+        }
+        stalled
+    }
+
+    /// Returns the number of stalled iterations of the task of the `instance`.
+    pub fn stalled_iterations(&self, instance: &ServiceInstance) -> u64 {
+        self.stalled_iterations.get_or_create(&instance.label).get()
+    }
+
+    /// Updates time-based metrics of all services. Should be called before the encoding.
+    pub fn update_lifecycles(&self) {
+        let now = Instant::now();
+        let mut lifecycles = self.lifecycles.lock().expect("The lock is poisoned");
+        for (label, lifecycle) in lifecycles.iter_mut() {
+            self.flush_state_duration(label, lifecycle, now);
+            let uptime = lifecycle
+                .started_at
+                .map_or(0.0, |started_at| (now - started_at).as_secs_f64());
+            self.uptime.get_or_create(label).set(uptime);
+        }
+    }
+
+    fn flush_state_duration(
+        &self,
+        label: &InstanceLabel,
+        lifecycle: &mut Lifecycle,
+        now: Instant,
+    ) {
+        self.state_duration
+            .get_or_create(&StateLabel {
+                service: label.service.clone(),
+                instance: label.instance.clone(),
+                state: lifecycle.state.to_string(),
+            })
+            .inc_by((now - lifecycle.since).as_secs_f64());
+        lifecycle.since = now;
+    }
+}
+
+fn lifecycle<'a>(
+    lifecycles: &'a mut HashMap<InstanceLabel, Lifecycle>,
+    instance: &ServiceInstance,
+    now: Instant,
+) -> &'a mut Lifecycle {
+    lifecycles
+        .entry(instance.label.clone())
+        .or_insert_with(|| Lifecycle {
+            state: NOT_STARTED,
+            since: now,
+            started_at: None,
+            last_error: None,
+        })
+}
+
+lazy_static! {
+    pub static ref SERVICES_METRICS: ServicesMetrics = ServicesMetrics::new();
+}
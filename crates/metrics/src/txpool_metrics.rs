@@ -10,6 +10,8 @@ pub struct TxPoolMetrics {
     pub registry: Registry,
     pub gas_price_histogram: Histogram,
     pub tx_size_histogram: Histogram,
+    pub pool_divergence_histogram: Histogram,
+    pub inclusion_delay_histogram: Histogram,
 }
 
 impl Default for TxPoolMetrics {
@@ -24,10 +26,20 @@ impl Default for TxPoolMetrics {
 
         let tx_size_histogram = Histogram::new(tx_sizes.into_iter());
 
+        let divergences = (0..=10).map(|i| i as f64 / 10.0);
+
+        let pool_divergence_histogram = Histogram::new(divergences);
+
+        let delays = [0.5, 1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0];
+
+        let inclusion_delay_histogram = Histogram::new(delays.into_iter());
+
         let mut metrics = TxPoolMetrics {
             registry,
             gas_price_histogram,
             tx_size_histogram,
+            pool_divergence_histogram,
+            inclusion_delay_histogram,
         };
 
         metrics.registry.register(
@@ -42,6 +54,18 @@ impl Default for TxPoolMetrics {
             metrics.tx_size_histogram.clone(),
         );
 
+        metrics.registry.register(
+            "Tx_Pool_Divergence_Histogram",
+            "A Histogram keeping track of the share of transactions that differ between the local pool and pools of peers",
+            metrics.pool_divergence_histogram.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Inclusion_Delay_Histogram",
+            "A Histogram keeping track of seconds that tracked txs waited in the mempool before the inclusion into a block",
+            metrics.inclusion_delay_histogram.clone(),
+        );
+
         metrics
     }
 }
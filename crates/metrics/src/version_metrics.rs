@@ -0,0 +1,69 @@
+use lazy_static::lazy_static;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        family::Family,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct BuildLabels {
+    pub version: String,
+    pub git_sha: String,
+    pub rustc_version: String,
+    // the comma-separated cargo features
+    pub features: String,
+    // the hash of the consensus-relevant configuration of the node
+    pub config_hash: String,
+}
+
+pub struct VersionMetrics {
+    pub registry: Registry,
+    pub version_status: Gauge,
+    build_info: Family<BuildLabels, Gauge>,
+}
+
+impl VersionMetrics {
+    /// The running version is supported and recommended.
+    pub const UP_TO_DATE: i64 = 0;
+    /// The running version is supported, but the upgrade is recommended.
+    pub const UPGRADE_RECOMMENDED: i64 = 1;
+    /// The running version is below the minimum version of the network.
+    pub const UNSUPPORTED: i64 = 2;
+
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let version_status = Gauge::default();
+        registry.register(
+            "node_version_status",
+            "The status of the running version according to the version manifest of the \
+            network: 0 is up to date, 1 is behind the recommended version, 2 is below the \
+            minimum version",
+            version_status.clone(),
+        );
+        let build_info = Family::<BuildLabels, Gauge>::default();
+        registry.register(
+            "node_build_info",
+            "Always 1, the labels describe the build of the node and the hash of its \
+            consensus-relevant configuration",
+            build_info.clone(),
+        );
+        Self {
+            registry,
+            version_status,
+            build_info,
+        }
+    }
+
+    /// Replaces the build of the node described by the metric.
+    pub fn set_build_info(&self, labels: BuildLabels) {
+        self.build_info.clear();
+        self.build_info.get_or_create(&labels).set(1);
+    }
+}
+
+lazy_static! {
+    pub static ref VERSION_METRICS: VersionMetrics = VersionMetrics::new();
+}
@@ -0,0 +1,168 @@
+use fuel_core_metrics::poa_metrics::POA_METRICS;
+use fuel_core_types::tai64::Tai64;
+use tokio::time::{
+    Duration,
+    Instant,
+};
+
+/// The divergence of the system clock from the monotonic clock that is considered as a
+/// jump of the system clock. Block timestamps have a precision of seconds, so smaller
+/// divergences are expected.
+pub const CLOCK_JUMP_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// Detects jumps of the system clock, e.g. NTP steps or migrations of the virtual
+/// machine, by comparing its progress with the progress of the monotonic clock.
+pub struct SystemClock {
+    /// The source of the time of the system clock.
+    wall_clock: fn() -> Tai64,
+    /// The time expected at the `monotonic` instant. It follows the system clock, except
+    /// while catching up with the forward jump.
+    wall: Tai64,
+    monotonic: Instant,
+    /// Whether the time is still behind the system clock after the forward jump.
+    catching_up: bool,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self::with_wall_clock(Tai64::now)
+    }
+
+    pub(crate) fn with_wall_clock(wall_clock: fn() -> Tai64) -> Self {
+        Self {
+            wall_clock,
+            wall: wall_clock(),
+            monotonic: Instant::now(),
+            catching_up: false,
+        }
+    }
+
+    /// Returns the current time of the system clock. The jump of the system clock since
+    /// the previous call is logged and recorded into metrics. The forward jump is
+    /// clamped, so block timestamps catch up with the system clock gradually instead of
+    /// skipping the time.
+    pub fn now(&mut self) -> Tai64 {
+        let wall = (self.wall_clock)();
+        let (time, jump) = self.observe(wall, Instant::now());
+        if let Some(jump) = jump {
+            tracing::warn!(
+                "The system clock jumped by {jump}s, block timestamps are re-derived"
+            );
+            POA_METRICS.clock_jumps.inc();
+        }
+        time
+    }
+
+    /// Remembers the time of both clocks and returns the time for block timestamps. The
+    /// time is ahead of the time expected by the monotonic clock at most by the
+    /// threshold, and the expected time catches up with the forward jump by at most a
+    /// second per elapsed second. Also returns the jump of the system clock in seconds
+    /// if it diverged from the monotonic clock by more than the threshold, unless the
+    /// time is still catching up with the previous jump.
+    fn observe(&mut self, wall: Tai64, monotonic: Instant) -> (Tai64, Option<i128>) {
+        let threshold = CLOCK_JUMP_THRESHOLD.as_secs();
+        let elapsed = monotonic.saturating_duration_since(self.monotonic).as_secs();
+        // Only whole seconds are consumed, so frequent calls don't lose the remainder.
+        self.monotonic += Duration::from_secs(elapsed);
+        let expected = self.wall.0.saturating_add(elapsed);
+        let jump = wall.0 as i128 - expected as i128;
+        self.wall = if jump > threshold as i128 {
+            let behind = wall.0 - expected - threshold;
+            Tai64(expected.saturating_add(behind.min(elapsed)))
+        } else {
+            wall
+        };
+        let time = Tai64(wall.0.min(self.wall.0.saturating_add(threshold)));
+
+        let jumped = jump.unsigned_abs() > threshold as u128 && !self.catching_up;
+        self.catching_up = jump > threshold as i128;
+        (time, jumped.then_some(jump))
+    }
+}
+
+/// Returns the number of seconds the `time` is ahead of the system clock if it is ahead
+/// by more than the threshold.
+pub fn ahead_of_system_clock(time: Tai64) -> Option<u64> {
+    let ahead = time.0.saturating_sub(Tai64::now().0);
+    (ahead > CLOCK_JUMP_THRESHOLD.as_secs()).then_some(ahead)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_clock(start: Instant) -> SystemClock {
+        SystemClock {
+            wall_clock: Tai64::now,
+            wall: Tai64(100),
+            monotonic: start,
+            catching_up: false,
+        }
+    }
+
+    #[test]
+    fn clock_following_monotonic_clock_has_no_jumps() {
+        let start = Instant::now();
+        let mut clock = new_clock(start);
+
+        assert_eq!(
+            clock.observe(Tai64(160), start + Duration::from_secs(60)),
+            (Tai64(160), None)
+        );
+        assert_eq!(
+            clock.observe(Tai64(162), start + Duration::from_secs(61)),
+            (Tai64(162), None)
+        );
+    }
+
+    #[test]
+    fn detects_jumps_in_both_directions() {
+        let start = Instant::now();
+        let mut clock = new_clock(start);
+
+        assert_eq!(
+            clock.observe(Tai64(50), start + Duration::from_secs(10)),
+            (Tai64(50), Some(-60))
+        );
+        assert_eq!(
+            clock.observe(Tai64(3650), start + Duration::from_secs(10)),
+            (Tai64(55), Some(3600))
+        );
+    }
+
+    #[test]
+    fn time_catches_up_with_forward_jump_gradually() {
+        let start = Instant::now();
+        let mut clock = new_clock(start);
+
+        assert_eq!(clock.observe(Tai64(3700), start), (Tai64(105), Some(3600)));
+        // The jump is reported once, while the time catches up.
+        assert_eq!(
+            clock.observe(Tai64(3710), start + Duration::from_secs(10)),
+            (Tai64(125), None)
+        );
+        assert_eq!(
+            clock.observe(Tai64(3711), start + Duration::from_secs(3600)),
+            (Tai64(3711), None)
+        );
+    }
+
+    #[test]
+    fn frequent_calls_dont_speed_up_catching_up() {
+        let start = Instant::now();
+        let mut clock = new_clock(start);
+
+        assert_eq!(clock.observe(Tai64(3700), start), (Tai64(105), Some(3600)));
+        for millis in [300, 600, 900] {
+            assert_eq!(
+                clock.observe(Tai64(3700), start + Duration::from_millis(millis)),
+                (Tai64(105), None)
+            );
+        }
+        // The fractions of the second between calls add up to the whole second.
+        assert_eq!(
+            clock.observe(Tai64(3701), start + Duration::from_millis(1200)),
+            (Tai64(107), None)
+        );
+    }
+}
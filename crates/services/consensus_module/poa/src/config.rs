@@ -1,18 +1,191 @@
+use crate::{
+    ports::{
+        BlockSigner,
+        PeerToPeerPort,
+    },
+    verifier::Config as VerifierConfig,
+};
+use fuel_core_chain_config::{
+    BlockProduction,
+    KeyRotation,
+};
 use fuel_core_types::{
-    blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
-    secrecy::Secret,
+    fuel_types::Address,
 };
+use anyhow::anyhow;
+use std::sync::Arc;
 use tokio::time::Duration;
 
 #[derive(Default, Debug, Clone)]
 pub struct Config {
     pub trigger: Trigger,
     pub block_gas_limit: Word,
-    pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    /// The signer of produced blocks. The consensus key loaded into the memory of the
+    /// node, `Secret<SecretKeyWrapper>`, is the signer too.
+    pub signer: Option<Arc<dyn BlockSigner>>,
+    /// The round-robin of authorities of the chain. The node produces blocks only at
+    /// heights scheduled for its `signer` and follows blocks of other authorities.
+    /// Empty if the node is the only authority.
+    pub authorities: Vec<Address>,
+    /// The schedule of keys of the single authority from the chain config. The node
+    /// produces blocks only with the `signer` of the key scheduled for their height, and
+    /// accepts only rotations of the signer in the schedule.
+    pub key_rotations: Vec<KeyRotation>,
+    /// Emits signed pre-confirmations of transactions included into the block once the
+    /// block is sealed, before it is committed.
+    pub pre_confirmations: bool,
     pub metrics: bool,
     pub consensus_params: ConsensusParameters,
+    /// The runtime to produce blocks on instead of the current runtime. The execution of
+    /// blocks is CPU-heavy, so it may be isolated from other services.
+    pub runtime: Option<tokio::runtime::Handle>,
+    /// The time budget of the execution of the produced block. If the execution exceeds
+    /// it, the block is produced again with fewer transactions, or empty with the
+    /// `Interval` trigger, so a pathological transaction can't stall the chain.
+    pub execution_budget: Option<Duration>,
+    /// Adjusts the `block_gas_limit` to the demand. The `block_gas_limit` is the limit of
+    /// the first block then.
+    pub gas_limit_adjustment: Option<GasLimitAdjustment>,
+    /// Verifies the produced block before it is sealed with the same checks of fields as
+    /// followers use, and also the roots, the number of transactions and the gas. The
+    /// invalid block is refused and counted in metrics.
+    pub verify_before_seal: Option<VerifierConfig>,
+    /// Starts the execution of the next block while the produced block is imported. The
+    /// result is used only if the next block turns out to be the same, e.g. it has the
+    /// predicted time and transactions.
+    pub speculative_execution: bool,
+    /// Runs the node as the hot standby of the primary producer with the same key.
+    pub standby: Option<Standby>,
+    /// The view of the network that fences the standby producer. The standby never
+    /// produces without it.
+    pub p2p: Option<Arc<dyn PeerToPeerPort>>,
+    /// How the `Interval` trigger handles the block produced later than its slot.
+    pub late_block_policy: LateBlockPolicy,
+    /// The limits on the `trigger`, checked on start and on every switch of the trigger.
+    pub trigger_limits: TriggerLimits,
+}
+
+/// The limits of the chain and the node on the trigger of the block production.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerLimits {
+    /// Blocks are produced manually too. Only the `Never`, `Instant` and `Interval`
+    /// triggers support it.
+    pub manual_blocks_enabled: bool,
+    /// The cadence of the chain config. Validators reject blocks produced faster.
+    pub cadence: Option<BlockProduction>,
+}
+
+impl TriggerLimits {
+    /// Checks that the `trigger` is consistent and produces blocks that the node and
+    /// validators of the chain accept.
+    pub fn check(&self, trigger: &Trigger) -> anyhow::Result<()> {
+        trigger.validate()?;
+        anyhow::ensure!(
+            !self.manual_blocks_enabled
+                || matches!(
+                    trigger,
+                    Trigger::Never | Trigger::Instant | Trigger::Interval { .. }
+                ),
+            "Cannot use manual block production unless trigger mode is never, instant or \
+            interval."
+        );
+        // Manually produced blocks aren't verified against the cadence anyway.
+        if let (Some(cadence), Some(min_block_time), false) = (
+            self.cadence,
+            trigger.min_block_time(),
+            self.manual_blocks_enabled,
+        ) {
+            anyhow::ensure!(
+                min_block_time >= cadence.min_block_time(),
+                "The block production trigger {:?} produces blocks faster than the \
+                cadence {:?} of the chain config allows. Validators would reject such \
+                blocks.",
+                trigger,
+                cadence
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The policy of the `Interval` trigger for the block produced later than its slot. The
+/// slots follow each other every `block_time` after the last block, and the block is late
+/// if the next slot already started when it is produced, e.g. after the node was busy.
+/// All policies except `Drift` timestamp blocks with the time of their slots, so the
+/// timestamps of blocks stay on the grid of the `block_time`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LateBlockPolicy {
+    /// The block is produced with the current time, and the next slot starts from it.
+    /// The grid of slots drifts by the delay of each block.
+    #[default]
+    Drift,
+    /// The late block isn't produced. The next block is produced in the next slot that
+    /// didn't start yet.
+    Skip,
+    /// The late block is produced right away with the time of its slot. Other missed
+    /// slots are skipped.
+    Backdate,
+    /// The blocks of all missed slots are produced one after another right away with
+    /// times of their slots, up to [`LateBlockPolicy::MAX_CATCH_UP_BLOCKS`] at once.
+    CatchUp,
+}
+
+impl LateBlockPolicy {
+    /// The maximum number of blocks produced at once by the `CatchUp` policy. Older
+    /// missed slots are skipped, e.g. after the node was down for a long time.
+    pub const MAX_CATCH_UP_BLOCKS: u32 = 100;
+}
+
+/// The hot-standby mode of the producer. The standby follows blocks of the primary
+/// producer and takes over only if the primary produces no blocks for
+/// `missed_intervals` of the longest interval of the trigger. The standby steps down
+/// as soon as it imports the block it didn't produce, e.g. after the network partition
+/// heals, so both producers don't keep producing at once.
+///
+/// The standby is fenced by its view of the network, see [`Config::p2p`]. It neither
+/// takes over nor produces while fewer than `min_peers` peers are connected, so the
+/// standby cut off from the network doesn't mistake the partition for the silence of the
+/// primary. It steps down once peers announce the height it didn't produce yet, so the
+/// primary producing behind the partition stops the standby before the fork grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standby {
+    pub missed_intervals: u32,
+    pub min_peers: usize,
+}
+
+/// Adjusts the gas limit after each produced block toward the target utilization, like
+/// EIP-1559 adjusts the base fee. Fuller blocks increase the limit, emptier decrease it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasLimitAdjustment {
+    pub min_gas_limit: Word,
+    pub max_gas_limit: Word,
+    /// The percentage of the gas limit the blocks should use.
+    pub target_utilization: u8,
+}
+
+impl GasLimitAdjustment {
+    /// The gas limit changes at most by `1 / CHANGE_DENOMINATOR` per block.
+    const CHANGE_DENOMINATOR: u128 = 8;
+
+    /// Returns the gas limit of the next block after the block with the `gas_limit`
+    /// used the `gas_used`.
+    pub fn next_gas_limit(&self, gas_limit: Word, gas_used: Word) -> Word {
+        let limit = gas_limit as u128;
+        let target = limit * self.target_utilization as u128 / 100;
+        let next = if target == 0 {
+            limit
+        } else if gas_used as u128 > target {
+            let excess = gas_used as u128 - target;
+            let change = (limit * excess / target / Self::CHANGE_DENOMINATOR).max(1);
+            limit + change
+        } else {
+            let shortage = target - gas_used as u128;
+            limit - limit * shortage / target / Self::CHANGE_DENOMINATOR
+        };
+        next.clamp(self.min_gas_limit as u128, self.max_gas_limit as u128) as Word
+    }
 }
 
 /// Block production trigger for PoA operation
@@ -42,4 +215,104 @@ pub enum Trigger {
         /// Time after which a new block is produced, even if it's empty
         max_block_time: Duration,
     },
+    /// A new block is produced periodically, and the interval adapts to the load. The
+    /// interval is `max_block_time` when the txpool is empty and shrinks linearly with
+    /// the consumable gas of the txpool down to `min_block_time` when the txpool has a
+    /// full block of transactions. Bursts are included quickly, like with `Instant`,
+    /// while idle periods don't flood the chain with empty blocks.
+    /// Requires `min_block_time` <= `max_block_time`.
+    Adaptive {
+        /// The interval when the txpool has at least a full block of transactions
+        min_block_time: Duration,
+        /// The interval when the txpool is empty
+        max_block_time: Duration,
+    },
+}
+
+impl Trigger {
+    /// The minimum time between two blocks produced by the trigger.
+    /// Returns `None` if the trigger doesn't produce blocks.
+    pub fn min_block_time(&self) -> Option<Duration> {
+        match self {
+            Trigger::Instant => Some(Duration::ZERO),
+            Trigger::Never => None,
+            Trigger::Interval { block_time } => Some(*block_time),
+            Trigger::Hybrid { min_block_time, .. } => Some(*min_block_time),
+            Trigger::Adaptive { min_block_time, .. } => Some(*min_block_time),
+        }
+    }
+
+    /// The maximum time between two blocks produced by the trigger.
+    /// Returns `None` if the trigger doesn't produce blocks on a schedule.
+    pub fn max_block_time(&self) -> Option<Duration> {
+        match self {
+            Trigger::Instant | Trigger::Never => None,
+            Trigger::Interval { block_time } => Some(*block_time),
+            Trigger::Hybrid { max_block_time, .. } => Some(*max_block_time),
+            Trigger::Adaptive { max_block_time, .. } => Some(*max_block_time),
+        }
+    }
+
+    /// Checks that the timings of the trigger are consistent and the trigger doesn't
+    /// spin on the zero interval.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match *self {
+            Trigger::Instant | Trigger::Never => Ok(()),
+            Trigger::Interval { block_time } => {
+                if block_time.is_zero() {
+                    return Err(anyhow!(
+                        "The block time of the interval should be positive"
+                    ))
+                }
+                Ok(())
+            }
+            Trigger::Hybrid {
+                min_block_time,
+                max_tx_idle_time,
+                max_block_time,
+            } => {
+                if max_block_time.is_zero()
+                    || min_block_time > max_tx_idle_time
+                    || max_tx_idle_time > max_block_time
+                {
+                    return Err(anyhow!(
+                        "The hybrid trigger requires `min_block_time` <= \
+                        `max_tx_idle_time` <= `max_block_time` and the positive \
+                        `max_block_time`"
+                    ))
+                }
+                Ok(())
+            }
+            Trigger::Adaptive {
+                min_block_time,
+                max_block_time,
+            } => {
+                if max_block_time.is_zero() || min_block_time > max_block_time {
+                    return Err(anyhow!(
+                        "The adaptive trigger requires `min_block_time` <= \
+                        `max_block_time` and the positive `max_block_time`"
+                    ))
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<BlockProduction> for Trigger {
+    fn from(value: BlockProduction) -> Self {
+        match value {
+            BlockProduction::Instant => Trigger::Instant,
+            BlockProduction::Interval { block_time } => Trigger::Interval { block_time },
+            BlockProduction::Hybrid {
+                min_block_time,
+                max_tx_idle_time,
+                max_block_time,
+            } => Trigger::Hybrid {
+                min_block_time,
+                max_tx_idle_time,
+                max_block_time,
+            },
+        }
+    }
 }
@@ -1,7 +1,9 @@
 #![deny(unused_crate_dependencies)]
 #![deny(unused_must_use)]
 
+mod clock;
 mod deadline_clock;
+mod signer;
 
 #[cfg(test)]
 mod service_test;
@@ -13,9 +15,14 @@ pub mod verifier;
 
 pub use config::{
     Config,
+    GasLimitAdjustment,
+    LateBlockPolicy,
+    Standby,
     Trigger,
+    TriggerLimits,
 };
 pub use service::{
     new_service,
     Service,
 };
+pub use signer::ThresholdSigner;
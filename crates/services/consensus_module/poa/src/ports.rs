@@ -5,18 +5,33 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        consensus::{
+            poa::{
+                PoAConsensus,
+                PoASeal,
+            },
+            Consensus,
+        },
         header::BlockHeader,
         primitives::DaBlockHeight,
     },
     fuel_asm::Word,
-    fuel_tx::TxId,
+    fuel_crypto::Message,
+    fuel_tx::{
+        Receipt,
+        TxId,
+    },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
     },
     services::{
         block_importer::UncommittedResult as UncommittedImportResult,
-        executor::UncommittedResult as UncommittedExecutionResult,
+        executor::{
+            Error as ExecutorError,
+            UncommittedResult as UncommittedExecutionResult,
+        },
         txpool::{
             ArcPoolTx,
             TxStatus,
@@ -24,6 +39,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use std::{
+    fmt,
+    time::Duration,
+};
 
 #[cfg_attr(test, mockall::automock)]
 pub trait TransactionPool: Send + Sync {
@@ -32,7 +51,9 @@ pub trait TransactionPool: Send + Sync {
 
     fn total_consumable_gas(&self) -> u64;
 
-    fn remove_txs(&self, tx_ids: Vec<TxId>) -> Vec<ArcPoolTx>;
+    /// Removes transactions skipped by the block producer along with the errors that
+    /// made the producer skip them, so submitters learn why transactions are dropped.
+    fn remove_txs(&self, tx_ids: Vec<(TxId, ExecutorError)>) -> Vec<ArcPoolTx>;
 
     fn transaction_status_events(&self) -> BoxStream<TxStatus>;
 }
@@ -45,12 +66,41 @@ use fuel_core_storage::test_helpers::EmptyStorage;
 pub trait BlockProducer: Send + Sync {
     type Database;
 
+    /// Produces the block at the `height`. The block includes events of the DA layer up
+    /// to the `da_height`, or up to the latest finalized DA height if it is `None`.
     async fn produce_and_execute_block(
         &self,
         height: BlockHeight,
         block_time: Tai64,
         max_gas: Word,
+        da_height: Option<DaBlockHeight>,
     ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<Self::Database>>>;
+
+    /// Produces the block like [`Self::produce_and_execute_block`], but cancels its
+    /// execution once it takes longer than the `budget`. The wait for the DA layer isn't
+    /// measured. Returns `None` if the budget is exceeded.
+    async fn produce_and_execute_block_within(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+        da_height: Option<DaBlockHeight>,
+        budget: Duration,
+    ) -> anyhow::Result<
+        Option<UncommittedExecutionResult<StorageTransaction<Self::Database>>>,
+    >;
+
+    /// Starts the execution of the block at the `height` on top of the `parent` block
+    /// that is not imported yet. The next production of the block at the `height`
+    /// reuses the result if the block is the same. The execution runs in the background,
+    /// so the method returns without waiting for it.
+    fn speculate_next_block(
+        &self,
+        parent: &StorageTransaction<Self::Database>,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(test, mockall::automock(type Database=EmptyStorage;))]
@@ -61,6 +111,72 @@ pub trait BlockImporter: Send + Sync {
         &self,
         result: UncommittedImportResult<StorageTransaction<Self::Database>>,
     ) -> anyhow::Result<()>;
+
+    /// Records the gas limit of the next produced block in the database `transaction` of
+    /// the produced block, so the adjusted limit survives restarts.
+    fn record_gas_limit(
+        &self,
+        transaction: &mut StorageTransaction<Self::Database>,
+        gas_limit: Word,
+    ) -> anyhow::Result<()>;
+
+    /// Returns the stream of headers of committed blocks, including blocks received from
+    /// the network.
+    fn block_stream(&self) -> BoxStream<BlockHeader>;
+}
+
+/// Signs blocks and pre-confirmations produced by the node. The consensus key may live
+/// outside of the node, e.g. in the KMS or the remote signing service.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait BlockSigner: Send + Sync {
+    /// The address of the consensus key.
+    fn address(&self) -> Address;
+
+    /// Seals the `message` with the consensus key in the [`PoASealScheme`].
+    ///
+    /// [`PoASealScheme`]: fuel_core_types::blockchain::consensus::poa::PoASealScheme
+    async fn sign(&self, message: &Message) -> anyhow::Result<PoASeal>;
+
+    /// Signs the block with the `header` with the consensus key. Signers outside of the
+    /// node may receive the whole header, so they can check what they sign.
+    async fn sign_block(&self, header: &BlockHeader) -> anyhow::Result<PoASeal> {
+        let block_id = header.id();
+        self.sign(block_id.as_message()).await
+    }
+
+    /// Seals the block with the `header`. By default, the block is sealed by the
+    /// signature of the consensus key.
+    async fn seal(&self, header: &BlockHeader) -> anyhow::Result<Consensus> {
+        let signature = self.sign_block(header).await?;
+        Ok(Consensus::PoA(PoAConsensus::new(signature)))
+    }
+}
+
+impl fmt::Debug for dyn BlockSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockSigner")
+            .field("address", &self.address())
+            .finish()
+    }
+}
+
+/// The view of the network that fences the hot-standby producer.
+#[cfg_attr(test, mockall::automock)]
+pub trait PeerToPeerPort: Send + Sync {
+    /// The number of connected peers.
+    fn connected_peers(&self) -> usize;
+
+    /// The highest block height announced by peers, if any.
+    fn highest_peer_height(&self) -> Option<BlockHeight>;
+}
+
+impl fmt::Debug for dyn PeerToPeerPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeerToPeerPort")
+            .field("connected_peers", &self.connected_peers())
+            .finish()
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -71,6 +187,10 @@ pub trait Database {
 
     /// Gets the block header BMT MMR root at `height`.
     fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32>;
+
+    /// Gets the receipts of the executed transaction with the `tx_id`. Returns the empty
+    /// list if the transaction has no receipts.
+    fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -1,4 +1,5 @@
 use crate::{
+    clock::SystemClock,
     deadline_clock::{
         DeadlineClock,
         OnConflict,
@@ -6,44 +7,70 @@ use crate::{
     ports::{
         BlockImporter,
         BlockProducer,
+        BlockSigner,
+        Database,
+        PeerToPeerPort,
         TransactionPool,
     },
+    verifier::{
+        verify_produced_block,
+        Config as VerifierConfig,
+    },
     Config,
+    GasLimitAdjustment,
+    LateBlockPolicy,
     Trigger,
+    TriggerLimits,
 };
 use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_chain_config::{
+    round_robin_authority,
+    scheduled_key,
+    KeyRotation,
+};
+use fuel_core_metrics::poa_metrics::POA_METRICS;
 use fuel_core_services::{
-    stream::BoxStream,
+    stream::{
+        batch_within,
+        pending,
+        BoxStream,
+    },
+    ConfigUpdate,
     RunnableService,
     RunnableTask,
     ServiceRunner,
+    ServiceRunnerBuilder,
     StateWatcher,
 };
-use fuel_core_storage::transactional::StorageTransaction;
+use fuel_core_storage::{
+    transactional::StorageTransaction,
+    Result as StorageResult,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::{
-            poa::PoAConsensus,
+            poa::PreConfirmation,
             Consensus,
         },
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
+        primitives::DaBlockHeight,
         SealedBlock,
     },
     fuel_asm::Word,
-    fuel_crypto::Signature,
     fuel_tx::{
         ConsensusParameters,
+        Transaction,
+        TransactionFee,
+        TxId,
         UniqueIdentifier,
     },
-    fuel_types::BlockHeight,
-    secrecy::{
-        ExposeSecret,
-        Secret,
+    fuel_types::{
+        Address,
+        BlockHeight,
     },
     services::{
         block_importer::ImportResult,
@@ -57,32 +84,63 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use std::{
-    ops::Deref,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
     time::Duration,
 };
 use tokio::{
     sync::{
+        broadcast,
         mpsc,
         oneshot,
+        watch,
     },
     time::Instant,
 };
 use tokio_stream::StreamExt;
-use tracing::error;
+use tracing::{
+    error,
+    info,
+    warn,
+};
 
 pub type Service<T, B, I> = ServiceRunner<Task<T, B, I>>;
 
+/// The number of pre-confirmations buffered for slow subscribers.
+const PRE_CONFIRMATIONS_CAPACITY: usize = 1024;
+/// The maximum number of txpool events processed at once.
+const TXPOOL_EVENTS_BATCH_SIZE: usize = 256;
+
 #[derive(Clone)]
 pub struct SharedState {
     request_sender: mpsc::Sender<Request>,
+    pre_confirmation_sender: broadcast::Sender<PreConfirmation>,
+    /// The latest block time set at runtime. Only the latest value matters, so setting it
+    /// never fails, unlike sending a request.
+    block_time_sender: Arc<watch::Sender<Option<Duration>>>,
+    /// Whether the running trigger is the `Interval` trigger.
+    interval_trigger: Arc<AtomicBool>,
+    /// The limits on the trigger, applied to every change of the trigger or block time.
+    trigger_limits: TriggerLimits,
 }
 
 impl SharedState {
+    /// Produces `number_of_blocks` blocks with the `gas_limit` and returns them in the
+    /// order of production. If the `da_height` is pinned, blocks don't include events of
+    /// the DA layer above it, e.g. to hold back the DA advancement during the incident
+    /// with the relayer.
     pub async fn manually_produce_block(
         &self,
         start_time: Option<Tai64>,
         number_of_blocks: u32,
-    ) -> anyhow::Result<()> {
+        gas_limit: ManualGasLimit,
+        da_height: Option<DaBlockHeight>,
+    ) -> anyhow::Result<Vec<ProducedBlock>> {
         let (sender, receiver) = oneshot::channel();
 
         self.request_sender
@@ -90,24 +148,125 @@ impl SharedState {
                 ManualProduction {
                     start_time,
                     number_of_blocks,
+                    gas_limit,
+                    da_height,
                 },
                 sender,
             )))
             .await?;
         receiver.await?
     }
+
+    /// Changes the interval between blocks produced by the `Interval` trigger. Fails if
+    /// the running trigger is another one, or if the `block_time` is zero or below the
+    /// cadence of the chain config.
+    pub fn set_block_time(&self, block_time: Duration) -> anyhow::Result<()> {
+        self.validate_block_time(block_time)?;
+        self.block_time_sender.send_replace(Some(block_time));
+        Ok(())
+    }
+
+    fn validate_block_time(&self, block_time: Duration) -> anyhow::Result<()> {
+        if !self.interval_trigger.load(Ordering::Acquire) {
+            return Err(anyhow!(
+                "The block time can be changed only for the `Interval` trigger"
+            ))
+        }
+        self.trigger_limits.check(&Trigger::Interval { block_time })
+    }
+
+    /// Switches the running block production to the `trigger`. The next block is
+    /// scheduled by the new trigger from the moment of the switch.
+    pub async fn set_trigger(&self, trigger: Trigger) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+        self.request_sender
+            .send(Request::SetTrigger((trigger, sender)))
+            .await?;
+        receiver.await?
+    }
+
+    /// Pauses or resumes the block production. The paused task keeps following the
+    /// transaction pool and imported blocks, but doesn't seal blocks, neither by the
+    /// trigger nor manually. The resumed task schedules the next block from now.
+    pub async fn set_paused(&self, paused: bool) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+        self.request_sender
+            .send(Request::SetPaused((paused, sender)))
+            .await?;
+        receiver.await?
+    }
+
+    /// Rotates the signing key of produced blocks. Blocks below the `height` are still
+    /// signed with the current signer, blocks from the `height` onwards are signed with
+    /// the `signer`. The `height` should be above the latest block, and the rotation to
+    /// the key of the `signer` at the `height` should be in the key schedule of the
+    /// chain config, so followers accept blocks of the new key. The pending rotation is
+    /// replaced by the new one.
+    pub async fn rotate_signer(
+        &self,
+        signer: Arc<dyn BlockSigner>,
+        height: BlockHeight,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+        self.request_sender
+            .send(Request::RotateSigner((SignerRotation { signer, height }, sender)))
+            .await?;
+        receiver.await?
+    }
+
+    /// Subscribes to the pre-confirmations of transactions selected into the produced
+    /// blocks. Nothing is received if pre-confirmations are disabled.
+    pub fn pre_confirmations(&self) -> broadcast::Receiver<PreConfirmation> {
+        self.pre_confirmation_sender.subscribe()
+    }
+}
+
+/// The gas limit of manually produced blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ManualGasLimit {
+    /// The `block_gas_limit` of the config.
+    #[default]
+    Config,
+    /// The gas consumable by transactions of the pool at the start of the production of
+    /// each block, bounded by the `block_gas_limit`. The block is sized to fit the whole
+    /// pool, so tests don't depend on the static limit.
+    FromPool,
+}
+
+/// The summary of the manually produced block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProducedBlock {
+    pub height: BlockHeight,
+    /// The gas limit the block was produced with.
+    pub gas_limit: Word,
+    /// The gas actually used by transactions of the block during its execution.
+    pub gas_used: Word,
 }
 
 struct ManualProduction {
     pub start_time: Option<Tai64>,
     pub number_of_blocks: u32,
+    pub gas_limit: ManualGasLimit,
+    pub da_height: Option<DaBlockHeight>,
+}
+
+/// The signer that replaces the current one from the `height`.
+pub(crate) struct SignerRotation {
+    pub signer: Arc<dyn BlockSigner>,
+    pub height: BlockHeight,
 }
 
 /// Requests accepted by the task.
 enum Request {
     /// Manually produces the next blocks with `Tai64` block timestamp.
     /// The block timestamp should be higher than previous one.
-    ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<()>>)),
+    ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<Vec<ProducedBlock>>>)),
+    /// Switches to another trigger.
+    SetTrigger((Trigger, oneshot::Sender<anyhow::Result<()>>)),
+    /// Pauses or resumes the block production.
+    SetPaused((bool, oneshot::Sender<anyhow::Result<()>>)),
+    /// Schedules the rotation of the signing key.
+    RotateSigner((SignerRotation, oneshot::Sender<anyhow::Result<()>>)),
 }
 
 impl core::fmt::Debug for Request {
@@ -121,27 +280,66 @@ pub(crate) enum RequestType {
     Trigger,
 }
 
+/// The state of the hot-standby producer.
+struct StandbyState {
+    /// The number of block intervals the primary producer may miss.
+    missed_intervals: u32,
+    /// The number of connected peers required to take over and to produce.
+    min_peers: usize,
+    /// The silence of the primary producer after which the standby takes over.
+    failover_timeout: Duration,
+    /// Whether the standby produces blocks because the primary producer is silent.
+    active: bool,
+}
+
 pub struct Task<T, B, I> {
     block_gas_limit: Word,
-    signing_key: Option<Secret<SecretKeyWrapper>>,
+    signer: Option<Arc<dyn BlockSigner>>,
+    /// The signer that takes over at the boundary height.
+    signer_rotation: Option<SignerRotation>,
+    /// The round-robin of authorities, empty if the node is the only authority.
+    authorities: Vec<Address>,
+    /// The schedule of keys of the single authority.
+    key_rotations: Vec<KeyRotation>,
+    pre_confirmations: bool,
     block_producer: B,
     block_importer: I,
     txpool: T,
-    tx_status_update_stream: BoxStream<TxStatus>,
+    tx_status_update_stream: BoxStream<Vec<TxStatus>>,
+    /// Headers of committed blocks, including blocks of other authorities.
+    imported_blocks: BoxStream<BlockHeader>,
     request_receiver: mpsc::Receiver<Request>,
+    block_time_receiver: watch::Receiver<Option<Duration>>,
     shared_state: SharedState,
     last_height: BlockHeight,
     last_timestamp: Tai64,
     last_block_created: Instant,
+    /// The number of slots of the `Interval` trigger skipped since the last block.
+    skipped_slots: u32,
+    /// Whether the `CatchUp` policy has blocks of missed slots left to produce.
+    catching_up: bool,
+    late_block_policy: LateBlockPolicy,
+    /// Detects jumps of the system clock used for block timestamps
+    clock: SystemClock,
     trigger: Trigger,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     consensus_params: ConsensusParameters,
+    execution_budget: Option<Duration>,
+    gas_limit_adjustment: Option<GasLimitAdjustment>,
+    verify_before_seal: Option<VerifierConfig>,
+    speculative_execution: bool,
+    standby: Option<StandbyState>,
+    /// The view of the network that fences the standby.
+    p2p: Option<Arc<dyn PeerToPeerPort>>,
+    /// Whether the block production is paused by the operator.
+    paused: bool,
 }
 
 impl<T, B, I> Task<T, B, I>
 where
     T: TransactionPool,
+    I: BlockImporter,
 {
     pub fn new(
         last_block: &BlockHeader,
@@ -150,35 +348,204 @@ where
         block_producer: B,
         block_importer: I,
     ) -> Self {
-        let tx_status_update_stream = txpool.transaction_status_events();
+        // The burst of events is processed at once, without waiting for more events.
+        let tx_status_update_stream = batch_within(
+            txpool.transaction_status_events(),
+            TXPOOL_EVENTS_BATCH_SIZE,
+            Duration::ZERO,
+        );
+        // The standby mode requires the trigger with the interval to count missed blocks.
+        let standby = config.standby.and_then(|standby| {
+            let interval = config.trigger.max_block_time()?;
+            Some(StandbyState {
+                missed_intervals: standby.missed_intervals,
+                min_peers: standby.min_peers,
+                failover_timeout: interval.saturating_mul(standby.missed_intervals),
+                active: false,
+            })
+        });
+        // Only the round-robin and the standby have blocks of other producers to follow.
+        let imported_blocks = if config.authorities.len() > 1 || standby.is_some() {
+            block_importer.block_stream()
+        } else {
+            Box::pin(pending())
+        };
         let (request_sender, request_receiver) = mpsc::channel(100);
+        let (pre_confirmation_sender, _) = broadcast::channel(PRE_CONFIRMATIONS_CAPACITY);
+        let (block_time_sender, block_time_receiver) = watch::channel(None);
+        let interval_trigger = matches!(config.trigger, Trigger::Interval { .. });
         let last_timestamp = last_block.time();
         let duration =
             Duration::from_secs(Tai64::now().0.saturating_sub(last_timestamp.0));
-        let last_block_created = Instant::now() - duration;
+        // The monotonic clock may have started later than the last block was created.
+        let last_block_created =
+            Instant::now().checked_sub(duration).unwrap_or_else(Instant::now);
         Self {
             block_gas_limit: config.block_gas_limit,
-            signing_key: config.signing_key,
+            signer: config.signer,
+            signer_rotation: None,
+            authorities: config.authorities,
+            key_rotations: config.key_rotations,
+            pre_confirmations: config.pre_confirmations,
             txpool,
             block_producer,
             block_importer,
             tx_status_update_stream,
+            imported_blocks,
             request_receiver,
-            shared_state: SharedState { request_sender },
+            block_time_receiver,
+            shared_state: SharedState {
+                request_sender,
+                pre_confirmation_sender,
+                block_time_sender: Arc::new(block_time_sender),
+                interval_trigger: Arc::new(AtomicBool::new(interval_trigger)),
+                trigger_limits: config.trigger_limits,
+            },
             last_height: *last_block.height(),
             last_timestamp,
             last_block_created,
+            skipped_slots: 0,
+            catching_up: false,
+            late_block_policy: config.late_block_policy,
+            clock: SystemClock::new(),
             trigger: config.trigger,
             timer: DeadlineClock::new(),
             consensus_params: config.consensus_params,
+            execution_budget: config.execution_budget,
+            gas_limit_adjustment: config.gas_limit_adjustment,
+            verify_before_seal: config.verify_before_seal,
+            speculative_execution: config.speculative_execution,
+            standby,
+            p2p: config.p2p,
+            paused: false,
         }
     }
 
+    #[cfg(test)]
+    pub(crate) fn set_clock(&mut self, clock: SystemClock) {
+        self.clock = clock;
+    }
+
     fn next_height(&self) -> BlockHeight {
         self.last_height + 1u32.into()
     }
 
-    fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
+    /// Returns the signer of the block at the `height`, taking the pending rotation into
+    /// account.
+    fn signer_at(&self, height: BlockHeight) -> Option<Arc<dyn BlockSigner>> {
+        match &self.signer_rotation {
+            Some(rotation) if height >= rotation.height => Some(rotation.signer.clone()),
+            _ => self.signer.clone(),
+        }
+    }
+
+    /// Returns whether the `height` is the slot of the local authority.
+    fn is_scheduled(&self, height: BlockHeight) -> bool {
+        match round_robin_authority(&self.authorities, height) {
+            Some(authority) => {
+                let signer = self.signer_at(height);
+                signer.map(|signer| signer.address()) == Some(authority)
+            }
+            None => true,
+        }
+    }
+
+    /// Returns whether the node is the standby waiting for the primary producer to go
+    /// silent.
+    fn is_passive_standby(&self) -> bool {
+        matches!(&self.standby, Some(standby) if !standby.active)
+    }
+
+    /// Returns why the standby must not produce the next block, if it must not. The
+    /// standby that sees too few peers can't tell the silence of the primary producer
+    /// from the network partition. Peers that announce the next height follow another
+    /// producer, e.g. the primary producing behind the partition.
+    fn standby_fence(&self) -> Option<String> {
+        let standby = self.standby.as_ref()?;
+        let p2p = match &self.p2p {
+            Some(p2p) => p2p,
+            None => return Some("the node has no view of the network".to_string()),
+        };
+        let peers = p2p.connected_peers();
+        if peers < standby.min_peers {
+            return Some(format!(
+                "{peers} peers are connected, but {} are required",
+                standby.min_peers
+            ))
+        }
+        match p2p.highest_peer_height() {
+            Some(height) if height >= self.next_height() => Some(format!(
+                "peers announced the block {} the standby didn't produce",
+                *height
+            )),
+            _ => None,
+        }
+    }
+
+    /// Makes the active standby passive again and restarts the countdown to the takeover.
+    async fn step_down(&mut self, reason: &str) {
+        if let Some(standby) = &mut self.standby {
+            if standby.active {
+                warn!("The standby steps down, {reason}");
+                standby.active = false;
+            }
+        }
+        self.restart_failover_timer().await;
+    }
+
+    /// Restarts the countdown to the takeover of the standby from now.
+    async fn restart_failover_timer(&self) {
+        if let Some(standby) = &self.standby {
+            self.timer
+                .set_timeout(standby.failover_timeout, OnConflict::Overwrite)
+                .await;
+        }
+    }
+
+    /// Schedules the rotation of the signer at the boundary height.
+    pub(crate) fn rotate_signer(
+        &mut self,
+        rotation: SignerRotation,
+    ) -> anyhow::Result<()> {
+        if rotation.height <= self.last_height {
+            return Err(anyhow!(
+                "The signer can't be rotated at the height {} below the next block",
+                *rotation.height
+            ))
+        }
+        let address = rotation.signer.address();
+        if !self.authorities.is_empty() {
+            if !self.authorities.contains(&address) {
+                return Err(anyhow!(
+                    "The new signer {address} is not one of the authorities"
+                ))
+            }
+        } else if !self.key_rotations.iter().any(|scheduled| {
+            scheduled.height == rotation.height && scheduled.signing_key == address
+        }) {
+            return Err(anyhow!(
+                "The rotation to the signer {address} at the height {} is not in the key \
+                schedule of the chain config, followers would reject its blocks",
+                *rotation.height
+            ))
+        }
+        self.signer_rotation = Some(rotation);
+        self.complete_signer_rotation();
+        Ok(())
+    }
+
+    /// Replaces the signer with the rotated one once the next block is at the boundary.
+    fn complete_signer_rotation(&mut self) {
+        let next_height = self.next_height();
+        match self.signer_rotation.take() {
+            Some(rotation) if next_height >= rotation.height => {
+                self.signer = Some(rotation.signer);
+            }
+            rotation => self.signer_rotation = rotation,
+        }
+    }
+
+    fn next_time(&mut self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
                 Trigger::Never | Trigger::Instant => {
@@ -188,16 +555,22 @@ where
                 Trigger::Interval { block_time } => {
                     increase_time(self.last_timestamp, block_time)
                 }
-                Trigger::Hybrid { min_block_time, .. } => {
+                Trigger::Hybrid { min_block_time, .. }
+                | Trigger::Adaptive { min_block_time, .. } => {
                     increase_time(self.last_timestamp, min_block_time)
                 }
             },
             RequestType::Trigger => {
-                let now = Tai64::now();
+                let now = self.clock.now();
                 if now > self.last_timestamp {
                     Ok(now)
                 } else {
-                    self.next_time(RequestType::Manual)
+                    // The system clock is behind the last block, e.g. after it jumped
+                    // backwards. The time is re-derived from the monotonic clock, so
+                    // blocks don't pile up on the same timestamp.
+                    let elapsed = self.last_block_created.elapsed();
+                    let monotonic = increase_time(self.last_timestamp, elapsed)?;
+                    Ok(monotonic.max(self.next_time(RequestType::Manual)?))
                 }
             }
         }
@@ -209,52 +582,288 @@ where
     T: TransactionPool,
     B: BlockProducer<Database = D>,
     I: BlockImporter<Database = D>,
+    D: Database,
 {
     // Request the block producer to make a new block, and return it when ready
     async fn signal_produce_block(
         &self,
         height: BlockHeight,
         block_time: Tai64,
+        gas_limit: Word,
+        da_height: Option<DaBlockHeight>,
     ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<D>>> {
         self.block_producer
-            .produce_and_execute_block(height, block_time, self.block_gas_limit)
+            .produce_and_execute_block(height, block_time, gas_limit, da_height)
             .await
     }
 
-    pub(crate) async fn produce_next_block(&mut self) -> anyhow::Result<()> {
-        self.produce_block(
-            self.next_height(),
-            self.next_time(RequestType::Trigger)?,
-            RequestType::Trigger,
+    /// Requests the block within the `execution_budget`. If the execution exceeds the
+    /// budget, it is cancelled and the block is requested again with the halved gas
+    /// limit, so fewer transactions are executed, until the block is empty. The
+    /// `Interval` trigger falls back to the empty block right away to keep the cadence.
+    /// Returns the gas limit of the block.
+    async fn produce_within_budget(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        mut gas_limit: Word,
+        da_height: Option<DaBlockHeight>,
+    ) -> anyhow::Result<(UncommittedExecutionResult<StorageTransaction<D>>, Word)> {
+        let budget = match self.execution_budget {
+            Some(budget) => budget,
+            None => {
+                let result = self
+                    .signal_produce_block(height, block_time, gas_limit, da_height)
+                    .await?;
+                return Ok((result, gas_limit))
+            }
+        };
+        loop {
+            let result = self
+                .block_producer
+                .produce_and_execute_block_within(
+                    height, block_time, gas_limit, da_height, budget,
+                )
+                .await?;
+            match result {
+                Some(result) => return Ok((result, gas_limit)),
+                None if gas_limit == 0 => {
+                    return Err(anyhow!(
+                        "The empty block {} exceeded the execution budget {budget:?}",
+                        *height
+                    ))
+                }
+                None => {
+                    gas_limit = match self.trigger {
+                        Trigger::Interval { .. } => 0,
+                        _ => gas_limit / 2,
+                    };
+                    warn!(
+                        "The block {} exceeded the execution budget {budget:?}, retrying \
+                        with the gas limit {gas_limit}",
+                        *height
+                    );
+                }
+            }
+        }
+    }
+
+    /// Starts the execution of the block after the produced block at the `height` while
+    /// the produced block is imported. The time of the next block is predicted, so the
+    /// producer discards the speculation if the next block turns out to be different.
+    fn speculate_next_block(
+        &mut self,
+        parent: &StorageTransaction<D>,
+        height: BlockHeight,
+        block_time: Tai64,
+        gas_limit: Word,
+    ) -> anyhow::Result<()> {
+        let next_height = height + 1u32.into();
+        if !self.is_scheduled(next_height) || self.is_passive_standby() || self.paused {
+            return Ok(())
+        }
+        let next_block_time = match self.trigger {
+            Trigger::Never => return Ok(()),
+            Trigger::Interval {
+                block_time: interval,
+            } => increase_time(block_time, interval)?,
+            _ => self.clock.now().max(block_time),
+        };
+        self.block_producer.speculate_next_block(
+            parent,
+            next_height,
+            next_block_time,
+            gas_limit,
         )
-        .await
+    }
+
+    pub(crate) async fn produce_next_block(&mut self) -> anyhow::Result<()> {
+        let height = self.next_height();
+        if !self.is_scheduled(height) {
+            // The trigger restarts when the block of another authority is imported.
+            return Ok(())
+        }
+        if self.is_passive_standby() {
+            // The standby produces only after the primary producer goes silent.
+            return Ok(())
+        }
+        if let Some(reason) = self.standby_fence() {
+            // Another producer may be producing with the same key.
+            self.step_down(&reason).await;
+            return Ok(())
+        }
+        if self.paused {
+            // The next block is scheduled again when the production is resumed.
+            return Ok(())
+        }
+        match (self.trigger, self.late_block_policy) {
+            (Trigger::Interval { block_time }, policy)
+                if policy != LateBlockPolicy::Drift && !block_time.is_zero() =>
+            {
+                self.produce_slots(block_time).await?;
+            }
+            _ => {
+                let block_time = self.next_time(RequestType::Trigger)?;
+                self.produce_block(
+                    height,
+                    block_time,
+                    self.block_gas_limit,
+                    None,
+                    RequestType::Trigger,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Produces blocks of the `Interval` trigger with times of their slots. Slots follow
+    /// each other every `interval` after the last block, and the block produced after
+    /// the next slot started is handled by the `late_block_policy`.
+    ///
+    /// Only one block is produced per call. If the `CatchUp` policy has more missed
+    /// slots, the timer fires again right away, so the task handles the shutdown and
+    /// imported blocks between catch-up blocks.
+    async fn produce_slots(&mut self, interval: Duration) -> anyhow::Result<()> {
+        let base = self.last_block_created;
+        let base_time = self.last_timestamp;
+        let next_slot = self.skipped_slots.saturating_add(1);
+        let slot_start = base + interval.saturating_mul(next_slot);
+        let late_slots = Instant::now()
+            .saturating_duration_since(slot_start)
+            .as_nanos()
+            / interval.as_nanos();
+        let late_slots = u32::try_from(late_slots).unwrap_or(u32::MAX);
+
+        // The latest slot that already started.
+        let latest_slot = next_slot.saturating_add(late_slots);
+        let slots = match self.late_block_policy {
+            _ if late_slots == 0 => next_slot..latest_slot.saturating_add(1),
+            LateBlockPolicy::Drift | LateBlockPolicy::Backdate => {
+                next_slot..next_slot.saturating_add(1)
+            }
+            LateBlockPolicy::Skip => next_slot..next_slot,
+            LateBlockPolicy::CatchUp => {
+                let blocks = late_slots
+                    .saturating_add(1)
+                    .min(LateBlockPolicy::MAX_CATCH_UP_BLOCKS);
+                let end = latest_slot.saturating_add(1);
+                end - blocks..end
+            }
+        };
+        if late_slots > 0 && !self.catching_up {
+            warn!(
+                "The block {} is late by {} slots, producing {} blocks with the {:?} \
+                policy",
+                *self.next_height(),
+                late_slots,
+                slots.len(),
+                self.late_block_policy
+            );
+        }
+
+        let mut last_slot = 0;
+        if !slots.is_empty() {
+            let slot = slots.start;
+            let block_time = increase_time(base_time, interval.saturating_mul(slot))?;
+            self.catching_up = false;
+            self.produce_block(
+                self.next_height(),
+                block_time,
+                self.block_gas_limit,
+                None,
+                RequestType::Trigger,
+            )
+            .await?;
+            // The next slot follows the grid instead of the time of the production.
+            self.last_block_created = base + interval.saturating_mul(slot);
+            last_slot = slot;
+        }
+        if slots.len() > 1 {
+            // The next missed slot directly follows the produced block.
+            self.catching_up = true;
+            self.timer
+                .set_deadline(Instant::now(), OnConflict::Overwrite)
+                .await;
+            return Ok(())
+        }
+        // Slots that started without blocks are skipped, so the next block is produced
+        // in the first slot that didn't start yet.
+        self.skipped_slots = latest_slot - last_slot;
+        self.timer
+            .set_deadline(
+                self.last_block_created
+                    + interval.saturating_mul(self.skipped_slots.saturating_add(1)),
+                OnConflict::Overwrite,
+            )
+            .await;
+        Ok(())
     }
 
     async fn produce_manual_blocks(
         &mut self,
         block_production: ManualProduction,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<ProducedBlock>> {
+        if self.paused {
+            return Err(anyhow!("The block production is paused"))
+        }
         let mut block_time = block_production
             .start_time
             .unwrap_or(self.next_time(RequestType::Manual)?);
+        let mut blocks = Vec::with_capacity(block_production.number_of_blocks as usize);
         for _ in 0..block_production.number_of_blocks {
-            self.produce_block(self.next_height(), block_time, RequestType::Manual)
+            let gas_limit = match block_production.gas_limit {
+                ManualGasLimit::Config => self.block_gas_limit,
+                ManualGasLimit::FromPool => self
+                    .txpool
+                    .total_consumable_gas()
+                    .min(self.block_gas_limit),
+            };
+            let block = self
+                .produce_block(
+                    self.next_height(),
+                    block_time,
+                    gas_limit,
+                    block_production.da_height,
+                    RequestType::Manual,
+                )
                 .await?;
+            blocks.push(block);
             block_time = self.next_time(RequestType::Manual)?;
         }
-        Ok(())
+        Ok(blocks)
     }
 
     async fn produce_block(
         &mut self,
         height: BlockHeight,
         block_time: Tai64,
+        gas_limit: Word,
+        da_height: Option<DaBlockHeight>,
         request_type: RequestType,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<ProducedBlock> {
         let last_block_created = Instant::now();
         // verify signing key is set
-        if self.signing_key.is_none() {
-            return Err(anyhow!("unable to produce blocks without a consensus key"))
+        let signer = match self.signer_at(height) {
+            Some(signer) => signer,
+            None => {
+                return Err(anyhow!("unable to produce blocks without a consensus key"))
+            }
+        };
+
+        if !self.is_scheduled(height) {
+            return Err(anyhow!("The height {} is the slot of another authority", *height))
+        }
+
+        // Before the first rotation, the schedule can't differ from the signer.
+        let address = signer.address();
+        let scheduled = scheduled_key(address, &self.key_rotations, height);
+        if scheduled != address {
+            return Err(anyhow!(
+                "The block at the height {} should be signed by the key {scheduled} of \
+                the key schedule, not by {address}. Rotate the signer",
+                *height
+            ))
         }
 
         if self.last_timestamp > block_time {
@@ -262,14 +871,17 @@ where
         }
 
         // Ask the block producer to create the block
+        let (result, gas_limit) = self
+            .produce_within_budget(height, block_time, gas_limit, da_height)
+            .await?;
         let (
             ExecutionResult {
                 block,
                 skipped_transactions,
                 tx_status,
             },
-            db_transaction,
-        ) = self.signal_produce_block(height, block_time).await?.into();
+            mut db_transaction,
+        ) = result.into();
 
         let mut tx_ids_to_remove = Vec::with_capacity(skipped_transactions.len());
         for (tx, err) in skipped_transactions {
@@ -277,16 +889,85 @@ where
                 "During block production got invalid transaction {:?} with error {:?}",
                 tx, err
             );
-            tx_ids_to_remove.push(tx.id(&self.consensus_params));
+            tx_ids_to_remove.push((tx.id(&self.consensus_params), err));
         }
         self.txpool.remove_txs(tx_ids_to_remove);
 
+        let gas_used: Word = tx_status.iter().map(|status| status.gas_used).sum();
+        if let Some(verifier) = &self.verify_before_seal {
+            // The execution of the block stored receipts in its database transaction.
+            let database = db_transaction.as_ref();
+            tx_status
+                .iter()
+                .map(|status| database.receipts(&status.id))
+                .collect::<StorageResult<Vec<_>>>()
+                .map_err(anyhow::Error::from)
+                .and_then(|receipts| {
+                    verify_produced_block(
+                        verifier,
+                        database,
+                        &block,
+                        &receipts.concat(),
+                        height,
+                        block_time,
+                        gas_limit,
+                        self.max_gas(&block),
+                    )
+                })
+                .map_err(|e| {
+                    POA_METRICS.invalid_produced_blocks.inc();
+                    e.context("Refused to seal the invalid produced block")
+                })?;
+        }
+        let pre_confirmed = if self.pre_confirmations {
+            self.pre_confirmed_transactions(&block)
+        } else {
+            vec![]
+        };
+
         // Sign the block and seal it
-        let seal = seal_block(&self.signing_key, &block)?;
+        let seal = seal_block(&signer, &block).await?;
         let block = SealedBlock {
             entity: block,
             consensus: seal,
         };
+
+        // Subscribers learn about transactions of the sealed block before the commit.
+        // The failed pre-confirmation doesn't fail the block.
+        if !pre_confirmed.is_empty() {
+            if let Err(e) = self.pre_confirm(&signer, height, pre_confirmed).await {
+                warn!(
+                    "Failed to pre-confirm transactions of the block {}: {e}",
+                    *height
+                );
+            }
+        }
+
+        // Manual blocks don't follow the demand, so they don't affect the limit.
+        let next_gas_limit = match (self.gas_limit_adjustment, &request_type) {
+            (Some(adjustment), RequestType::Trigger) => {
+                // The demand is the gas transactions actually used, not their maximum.
+                let next_gas_limit =
+                    adjustment.next_gas_limit(self.block_gas_limit, gas_used);
+                self.block_importer
+                    .record_gas_limit(&mut db_transaction, next_gas_limit)?;
+                next_gas_limit
+            }
+            _ => self.block_gas_limit,
+        };
+        if self.speculative_execution && matches!(request_type, RequestType::Trigger) {
+            // The failed speculation only means the next block is executed as usual.
+            let speculation = self.speculate_next_block(
+                &db_transaction,
+                height,
+                block_time,
+                next_gas_limit,
+            );
+            if let Err(e) = speculation {
+                warn!("Failed to speculate the block after {}: {e}", *height);
+            }
+        }
+
         // Import the sealed block
         self.block_importer.commit_result(Uncommitted::new(
             ImportResult {
@@ -300,9 +981,16 @@ where
         self.last_height = height;
         self.last_timestamp = block_time;
         self.last_block_created = last_block_created;
+        self.skipped_slots = 0;
+        self.complete_signer_rotation();
+        self.block_gas_limit = next_gas_limit;
 
         // Set timer for the next block
         match (self.trigger, request_type) {
+            _ if self.is_passive_standby() => {
+                // Manually produced blocks don't make the standby active.
+                self.restart_failover_timer().await;
+            }
             (Trigger::Never, RequestType::Manual) => (),
             (Trigger::Never, RequestType::Trigger) => {
                 unreachable!("Trigger production will never produce blocks in never mode")
@@ -348,8 +1036,238 @@ where
             (Trigger::Hybrid { .. }, RequestType::Manual) => {
                 unreachable!("Trigger types hybrid cannot be used with manual. This is enforced during config validation")
             }
+            (Trigger::Adaptive { .. }, RequestType::Trigger) => {
+                let interval = self.adaptive_interval();
+                self.timer.set_timeout(interval, OnConflict::Max).await;
+            }
+            (Trigger::Adaptive { .. }, RequestType::Manual) => {
+                unreachable!("Trigger types adaptive cannot be used with manual. This is enforced during config validation")
+            }
+        }
+
+        Ok(ProducedBlock {
+            height,
+            gas_limit,
+            gas_used,
+        })
+    }
+
+    /// Returns the interval of the `Adaptive` trigger for the current consumable gas of
+    /// the txpool. Returns `Duration::ZERO` for other triggers.
+    fn adaptive_interval(&self) -> Duration {
+        match self.trigger {
+            Trigger::Adaptive {
+                min_block_time,
+                max_block_time,
+            } => {
+                let consumable_gas = self.txpool.total_consumable_gas();
+                let load = match (consumable_gas, self.block_gas_limit) {
+                    (0, _) => 0.0,
+                    (_, 0) => 1.0,
+                    (gas, limit) => (gas as f64 / limit as f64).min(1.0),
+                };
+                let range = max_block_time.saturating_sub(min_block_time);
+                max_block_time.saturating_sub(range.mul_f64(load))
+            }
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Returns the sum of the maximal gas of transactions of the `block`, the same
+    /// measure the block producer uses to fit transactions into the gas limit.
+    fn max_gas(&self, block: &Block) -> Word {
+        block
+            .transactions()
+            .iter()
+            .filter_map(|tx| match tx {
+                Transaction::Script(tx) => {
+                    TransactionFee::checked_from_tx(&self.consensus_params, tx)
+                }
+                Transaction::Create(tx) => {
+                    TransactionFee::checked_from_tx(&self.consensus_params, tx)
+                }
+                Transaction::Mint(_) => None,
+            })
+            .map(|fee| fee.max_gas())
+            .sum()
+    }
+
+    /// Follows blocks of other authorities. If the next height is the slot of the local
+    /// authority, the trigger starts counting from the imported block.
+    pub(crate) async fn on_imported_block(
+        &mut self,
+        header: BlockHeader,
+    ) -> anyhow::Result<()> {
+        let height = *header.height();
+        // Blocks of the local authority are already applied.
+        if height <= self.last_height {
+            return Ok(())
+        }
+        self.last_height = height;
+        self.last_timestamp = header.time();
+        self.last_block_created = Instant::now();
+        self.skipped_slots = 0;
+        self.catching_up = false;
+        self.complete_signer_rotation();
+        if self.standby.is_some() {
+            // Only the primary producer keeps producing once it is heard from again.
+            let reason = format!("the primary producer produced the block {}", *height);
+            self.step_down(&reason).await;
+            return Ok(())
+        }
+        if !self.is_scheduled(self.next_height()) {
+            return Ok(())
+        }
+        self.schedule_next_block().await
+    }
+
+    /// Sets the timer for the next block of the local authority according to the
+    /// trigger, or produces the block right away if the trigger requires it.
+    async fn schedule_next_block(&mut self) -> anyhow::Result<()> {
+        match self.trigger {
+            Trigger::Never => {}
+            Trigger::Instant => {
+                if self.txpool.pending_number() > 0 {
+                    self.produce_next_block().await?;
+                }
+            }
+            Trigger::Interval { block_time } => {
+                self.timer
+                    .set_timeout(block_time, OnConflict::Overwrite)
+                    .await;
+            }
+            Trigger::Hybrid {
+                max_tx_idle_time,
+                max_block_time,
+                ..
+            } => {
+                let timeout = if self.txpool.pending_number() > 0 {
+                    max_tx_idle_time
+                } else {
+                    max_block_time
+                };
+                self.timer.set_timeout(timeout, OnConflict::Overwrite).await;
+            }
+            Trigger::Adaptive { .. } => {
+                let interval = self.adaptive_interval();
+                self.timer.set_timeout(interval, OnConflict::Overwrite).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Discards the timer and schedules the next block from now, or restarts the
+    /// countdown to the takeover if the node is the passive standby.
+    async fn reschedule(&mut self) -> anyhow::Result<()> {
+        self.timer.clear().await;
+        // The lateness of the next block is counted from the last block again.
+        self.skipped_slots = 0;
+        self.catching_up = false;
+
+        if self.is_passive_standby() {
+            self.restart_failover_timer().await;
+            return Ok(())
+        }
+        if !self.is_scheduled(self.next_height()) {
+            return Ok(())
         }
+        self.schedule_next_block().await
+    }
 
+    /// Changes the interval of the `Interval` trigger and moves the deadline of the next
+    /// block accordingly.
+    async fn set_block_time(&mut self, new_block_time: Duration) {
+        match &mut self.trigger {
+            Trigger::Interval { block_time } => {
+                *block_time = new_block_time;
+                self.skipped_slots = 0;
+                self.catching_up = false;
+                self.timer
+                    .set_deadline(
+                        self.last_block_created + new_block_time,
+                        OnConflict::Overwrite,
+                    )
+                    .await;
+            }
+            trigger => warn!("The block time is ignored by the {trigger:?} trigger"),
+        }
+    }
+
+    /// Switches to the `trigger` at runtime. The timer of the previous trigger is
+    /// discarded, and the next block is scheduled by the new trigger from now on.
+    pub(crate) async fn set_trigger(&mut self, trigger: Trigger) -> anyhow::Result<()> {
+        self.shared_state.trigger_limits.check(&trigger)?;
+        if let Some(standby) = &mut self.standby {
+            let interval = trigger.max_block_time().ok_or_else(|| {
+                anyhow!(
+                    "The standby producer requires the trigger with the interval, not \
+                    {trigger:?}"
+                )
+            })?;
+            standby.failover_timeout =
+                interval.saturating_mul(standby.missed_intervals);
+        }
+        info!(
+            "Switching the block production from {:?} to {:?}",
+            self.trigger,
+            trigger
+        );
+        self.trigger = trigger;
+        self.shared_state.interval_trigger.store(
+            matches!(self.trigger, Trigger::Interval { .. }),
+            Ordering::Release,
+        );
+        self.reschedule().await
+    }
+
+    /// Pauses or resumes the block production. Resuming schedules the next block by the
+    /// trigger as if the previous block was just imported.
+    pub(crate) async fn set_paused(&mut self, paused: bool) -> anyhow::Result<()> {
+        if self.paused == paused {
+            return Ok(())
+        }
+        self.paused = paused;
+        if paused {
+            warn!("The block production is paused");
+            return Ok(())
+        }
+        info!("The block production is resumed");
+        self.reschedule().await
+    }
+
+    /// Returns ids of transactions of the block to pre-confirm.
+    fn pre_confirmed_transactions(&self, block: &Block) -> Vec<TxId> {
+        block
+            .transactions()
+            .iter()
+            // The coinbase transaction is created by the producer itself
+            .filter(|tx| !matches!(tx, Transaction::Mint(_)))
+            .map(|tx| tx.id(&self.consensus_params))
+            .collect()
+    }
+
+    /// Notifies subscribers that the transactions are included into the sealed block at
+    /// the `height`. The producer signs all transactions of the block at once rather than
+    /// each of them.
+    async fn pre_confirm(
+        &self,
+        signer: &Arc<dyn BlockSigner>,
+        height: BlockHeight,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<()> {
+        let chain_id = self.consensus_params.chain_id;
+        let message = PreConfirmation::message(chain_id, &tx_ids, height);
+        let signature = signer.sign(&message).await?;
+        let pre_confirmation = PreConfirmation {
+            tx_ids,
+            height,
+            signature,
+        };
+        // It is okay if nobody is subscribed
+        let _ = self
+            .shared_state
+            .pre_confirmation_sender
+            .send(pre_confirmation);
         Ok(())
     }
 
@@ -357,6 +1275,10 @@ where
         &mut self,
         txpool_event: TxStatus,
     ) -> anyhow::Result<()> {
+        if self.is_passive_standby() {
+            // The timer counts down to the takeover of the standby meanwhile.
+            return Ok(())
+        }
         match txpool_event {
             TxStatus::Submitted => match self.trigger {
                 Trigger::Instant => {
@@ -390,6 +1312,12 @@ where
 
                     Ok(())
                 }
+                Trigger::Adaptive { .. } => {
+                    // New transactions can only bring the next block closer.
+                    let deadline = self.last_block_created + self.adaptive_interval();
+                    self.timer.set_deadline(deadline, OnConflict::Min).await;
+                    Ok(())
+                }
             },
             TxStatus::Completed => Ok(()), // This has been processed already
             TxStatus::SqueezedOut { .. } => {
@@ -399,7 +1327,21 @@ where
         }
     }
 
-    async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
+    pub(crate) async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
+        if self.is_passive_standby() {
+            if let Some(reason) = self.standby_fence() {
+                warn!("The primary producer is silent, but the standby waits: {reason}");
+                self.restart_failover_timer().await;
+                return Ok(())
+            }
+            if let Some(standby) = &mut self.standby {
+                warn!(
+                    "The primary producer is silent for {:?}, the standby takes over",
+                    standby.failover_timeout
+                );
+                standby.active = true;
+            }
+        }
         match self.trigger {
             Trigger::Instant | Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
@@ -411,7 +1353,10 @@ where
             // 2. max_tx_idle_time expired after a tx has arrived
             // 3. max_block_time expired
             // => we produce a new block in any case
-            Trigger::Interval { .. } | Trigger::Hybrid { .. } => {
+            // In the Adaptive mode the timer expires after the interval for the load.
+            Trigger::Interval { .. }
+            | Trigger::Hybrid { .. }
+            | Trigger::Adaptive { .. } => {
                 self.produce_next_block().await?;
                 Ok(())
             }
@@ -434,11 +1379,37 @@ where
         self.shared_state.clone()
     }
 
+    fn validate_reload(
+        shared: &Self::SharedData,
+        update: &ConfigUpdate,
+    ) -> anyhow::Result<ConfigUpdate> {
+        if let Some(block_time) = update.block_time {
+            shared.validate_block_time(block_time)?;
+        }
+        Ok(ConfigUpdate {
+            block_time: update.block_time,
+            ..Default::default()
+        })
+    }
+
+    fn reload(shared: &Self::SharedData, update: &ConfigUpdate) {
+        if let Some(block_time) = update.block_time {
+            shared.block_time_sender.send_replace(Some(block_time));
+        }
+    }
+
     async fn into_task(
         self,
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        if let Some(standby) = &self.standby {
+            // The standby waits for the primary producer to go silent first.
+            self.timer
+                .set_timeout(standby.failover_timeout, OnConflict::Overwrite)
+                .await;
+            return Ok(self)
+        }
         match self.trigger {
             Trigger::Never | Trigger::Instant => {}
             Trigger::Interval { block_time } => {
@@ -446,7 +1417,8 @@ where
                     .set_timeout(block_time, OnConflict::Overwrite)
                     .await;
             }
-            Trigger::Hybrid { max_block_time, .. } => {
+            Trigger::Hybrid { max_block_time, .. }
+            | Trigger::Adaptive { max_block_time, .. } => {
                 self.timer
                     .set_timeout(max_block_time, OnConflict::Overwrite)
                     .await;
@@ -462,6 +1434,7 @@ where
     T: TransactionPool,
     B: BlockProducer<Database = D>,
     I: BlockImporter<Database = D>,
+    D: Database,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
@@ -476,21 +1449,49 @@ where
                             let result = self.produce_manual_blocks(block).await;
                             let _ = response.send(result);
                         }
+                        Request::SetTrigger((trigger, response)) => {
+                            let _ = response.send(self.set_trigger(trigger).await);
+                        }
+                        Request::SetPaused((paused, response)) => {
+                            let _ = response.send(self.set_paused(paused).await);
+                        }
+                        Request::RotateSigner((rotation, response)) => {
+                            let _ = response.send(self.rotate_signer(rotation));
+                        }
                     }
                     should_continue = true;
                 } else {
                     unreachable!("The task is the holder of the `Sender` too")
                 }
             }
-            // TODO: This should likely be refactored to use something like tokio::sync::Notify.
-            //       Otherwise, if a bunch of txs are submitted at once and all the txs are included
-            //       into the first block production trigger, we'll still call the event handler
-            //       for each tx after they've already been included into a block.
-            //       The poa service also doesn't care about events unrelated to new tx submissions,
+            changed = self.block_time_receiver.changed() => {
+                if changed.is_ok() {
+                    let block_time = *self.block_time_receiver.borrow_and_update();
+                    if let Some(block_time) = block_time {
+                        self.set_block_time(block_time).await;
+                    }
+                    should_continue = true;
+                } else {
+                    unreachable!("The task is the holder of the `Sender` too")
+                }
+            }
+            // Events arrive in batches, so if a bunch of txs are submitted at once, the event
+            // handler is called once for all of them instead of producing a block per tx.
+            // TODO: The poa service doesn't care about events unrelated to new tx submissions,
             //       and shouldn't be awoken when txs are completed or squeezed out of the pool.
-            txpool_event = self.tx_status_update_stream.next() => {
-                if let Some(txpool_event) = txpool_event {
-                    self.on_txpool_event(txpool_event).await.context("While processing txpool event")?;
+            txpool_events = self.tx_status_update_stream.next() => {
+                if let Some(txpool_events) = txpool_events {
+                    if txpool_events.iter().any(|event| matches!(event, TxStatus::Submitted)) {
+                        self.on_txpool_event(TxStatus::Submitted).await.context("While processing txpool event")?;
+                    }
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+            header = self.imported_blocks.next() => {
+                if let Some(header) = header {
+                    self.on_imported_block(header).await.context("While processing imported block")?;
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -522,33 +1523,22 @@ where
     T: TransactionPool + 'static,
     B: BlockProducer<Database = D> + 'static,
     I: BlockImporter<Database = D> + 'static,
+    D: Database,
 {
-    Service::new(Task::new(
-        last_block,
-        config,
-        txpool,
-        block_producer,
-        block_importer,
-    ))
+    let runtime = config.runtime.clone();
+    ServiceRunnerBuilder::new(
+        Task::new(last_block, config, txpool, block_producer, block_importer),
+        (),
+    )
+    .runtime(runtime)
+    .build()
 }
 
-fn seal_block(
-    signing_key: &Option<Secret<SecretKeyWrapper>>,
+async fn seal_block(
+    signer: &Arc<dyn BlockSigner>,
     block: &Block,
 ) -> anyhow::Result<Consensus> {
-    if let Some(key) = signing_key {
-        let block_hash = block.id();
-        let message = block_hash.into_message();
-
-        // The length of the secret is checked
-        let signing_key = key.expose_secret().deref();
-
-        let poa_signature = Signature::sign(signing_key, &message);
-        let seal = Consensus::PoA(PoAConsensus::new(poa_signature));
-        Ok(seal)
-    } else {
-        Err(anyhow!("no PoA signing key configured"))
-    }
+    signer.seal(block.header()).await
 }
 
 fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
@@ -1,28 +1,57 @@
 use crate::{
+    clock::{
+        SystemClock,
+        CLOCK_JUMP_THRESHOLD,
+    },
     new_service,
     ports::{
+        BlockProducer,
+        Database,
         MockBlockImporter,
         MockBlockProducer,
+        MockPeerToPeerPort,
         MockTransactionPool,
+        PeerToPeerPort,
+    },
+    service::{
+        ManualGasLimit,
+        ProducedBlock,
+        SignerRotation,
+        Task,
     },
-    service::Task,
+    verifier::Config as VerifierConfig,
     Config,
+    GasLimitAdjustment,
+    LateBlockPolicy,
     Service,
+    Standby,
     Trigger,
+    TriggerLimits,
+};
+use fuel_core_chain_config::{
+    BlockProduction,
+    KeyRotation,
 };
 use fuel_core_services::{
     stream::pending,
+    RunnableService,
     Service as StorageTrait,
     State,
 };
 use fuel_core_storage::{
     test_helpers::EmptyStorage,
     transactional::StorageTransaction,
+    Result as StorageResult,
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
+        consensus::Consensus,
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
+        primitives::{
+            DaBlockHeight,
+            SecretKeyWrapper,
+        },
         SealedBlock,
     },
     fuel_asm::*,
@@ -37,6 +66,8 @@ use fuel_core_types::{
         executor::{
             Error as ExecutorError,
             ExecutionResult,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
             UncommittedResult,
         },
         txpool::{
@@ -54,6 +85,12 @@ use rand::{
 use std::{
     collections::HashSet,
     sync::{
+        atomic::{
+            AtomicU32,
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
         Arc,
         Mutex as StdMutex,
         Mutex,
@@ -71,6 +108,22 @@ use tokio::{
 mod manually_produce_tests;
 mod trigger_tests;
 
+/// Every previous block has the default header and the zero root, and transactions have
+/// no receipts.
+impl Database for EmptyStorage {
+    fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
+        Ok(BlockHeader::new_block(*height, Tai64::UNIX_EPOCH))
+    }
+
+    fn block_header_merkle_root(&self, _: &BlockHeight) -> StorageResult<Bytes32> {
+        Ok(Bytes32::zeroed())
+    }
+
+    fn receipts(&self, _: &TxId) -> StorageResult<Vec<Receipt>> {
+        Ok(vec![])
+    }
+}
+
 struct TestContextBuilder {
     config: Option<Config>,
     txpool: Option<MockTransactionPool>,
@@ -114,7 +167,7 @@ impl TestContextBuilder {
             let mut producer = MockBlockProducer::default();
             producer
                 .expect_produce_and_execute_block()
-                .returning(|_, _, _| {
+                .returning(|_, _, _, _| {
                     Ok(UncommittedResult::new(
                         ExecutionResult {
                             block: Default::default(),
@@ -216,15 +269,15 @@ impl MockTransactionPool {
                 .sum()
         });
         let removed = txs.clone();
-        txpool
-            .expect_remove_txs()
-            .returning(move |tx_ids: Vec<TxId>| {
+        txpool.expect_remove_txs().returning(
+            move |tx_ids: Vec<(TxId, ExecutorError)>| {
                 let mut guard = removed.lock().unwrap();
-                for id in tx_ids {
+                for (id, _) in tx_ids {
                     guard.retain(|tx| tx.id(&ConsensusParameters::DEFAULT) == id);
                 }
                 vec![]
-            });
+            },
+        );
 
         TxPoolContext {
             txpool,
@@ -257,7 +310,7 @@ async fn remove_skipped_transactions() {
     block_producer
         .expect_produce_and_execute_block()
         .times(1)
-        .returning(move |_, _, _| {
+        .returning(move |_, _, _, _| {
             Ok(UncommittedResult::new(
                 ExecutionResult {
                     block: Default::default(),
@@ -281,7 +334,7 @@ async fn remove_skipped_transactions() {
 
     let mut txpool = MockTransactionPool::no_tx_updates();
     // Test created for only for this check.
-    txpool.expect_remove_txs().returning(move |skipped_ids| {
+    txpool.expect_remove_txs().returning(move |skipped| {
         // Transform transactions into ids.
         let skipped_transactions: Vec<_> = skipped_transactions
             .iter()
@@ -293,7 +346,12 @@ async fn remove_skipped_transactions() {
             skipped_transactions.clone().into_iter().collect();
         assert_eq!(expected_skipped_ids_set.len(), TX_NUM);
 
-        // Check that `TxPool::remove_txs` was called with the same ids in the same order.
+        // Check that `TxPool::remove_txs` was called with the same ids in the same order
+        // along with the reasons of the skipping.
+        assert!(skipped
+            .iter()
+            .all(|(_, err)| matches!(err, ExecutorError::OutputAlreadyExists)));
+        let skipped_ids: Vec<_> = skipped.into_iter().map(|(id, _)| id).collect();
         assert_eq!(skipped_ids.len(), TX_NUM);
         assert_eq!(skipped_transactions.len(), TX_NUM);
         assert_eq!(skipped_transactions, skipped_ids);
@@ -303,9 +361,21 @@ async fn remove_skipped_transactions() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     };
     let mut task = Task::new(
         &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
@@ -318,6 +388,1037 @@ async fn remove_skipped_transactions() {
     assert!(task.produce_next_block().await.is_ok());
 }
 
+#[tokio::test]
+async fn pre_confirms_transactions_of_produced_block() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let tx: Transaction = make_tx(&mut rng).into();
+    let tx_id = tx.id(&ConsensusParameters::DEFAULT);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(move |_, _, _, _| {
+            let mut block = Block::default();
+            block.transactions_mut().push(tx.clone());
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: true,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+    let mut pre_confirmations = task.shared_data().pre_confirmations();
+
+    task.produce_next_block().await.unwrap();
+
+    let pre_confirmation = pre_confirmations.try_recv().unwrap();
+    assert_eq!(pre_confirmation.tx_ids, vec![tx_id]);
+    assert_eq!(pre_confirmation.height, BlockHeight::from(2u32));
+    assert_eq!(
+        pre_confirmation
+            .producer(ConsensusParameters::DEFAULT.chain_id)
+            .unwrap(),
+        Input::owner(&secret_key.public_key())
+    );
+    assert!(pre_confirmations.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn transactions_are_pre_confirmed_before_commit() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let tx: Transaction = make_tx(&mut rng).into();
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(move |_, _, _, _| {
+            let mut block = Block::default();
+            block.transactions_mut().push(tx.clone());
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    // The subscription is created with the task, after the importer.
+    let subscription = Arc::new(Mutex::new(None));
+    let pre_confirmed_on_commit = Arc::new(Mutex::new(false));
+    let mut block_importer = MockBlockImporter::default();
+    {
+        let subscription = subscription.clone();
+        let pre_confirmed_on_commit = pre_confirmed_on_commit.clone();
+        block_importer
+            .expect_commit_result()
+            .times(1)
+            .returning(move |_| {
+                let mut subscription = subscription.lock().unwrap();
+                let pre_confirmations: &mut broadcast::Receiver<_> =
+                    subscription.as_mut().unwrap();
+                *pre_confirmed_on_commit.lock().unwrap() =
+                    pre_confirmations.try_recv().is_ok();
+                Ok(())
+            });
+    }
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: true,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+    *subscription.lock().unwrap() = Some(task.shared_data().pre_confirmations());
+
+    task.produce_next_block().await.unwrap();
+
+    assert!(*pre_confirmed_on_commit.lock().unwrap());
+}
+
+#[tokio::test]
+async fn round_robin_authority_produces_only_in_its_slots() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let other_secret_key = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .withf(|height, _, _, _| *height == BlockHeight::from(3u32))
+        .times(1)
+        .returning(|_, _, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_pending_number().returning(|| 1);
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![
+            Input::owner(&secret_key.public_key()),
+            Input::owner(&other_secret_key.public_key()),
+        ],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    // The height `2` is the slot of the other authority.
+    task.produce_next_block().await.unwrap();
+    // The block of the other authority makes the height `3` the next one.
+    task.on_imported_block(BlockHeader::new_block(
+        BlockHeight::from(2u32),
+        Tai64::now(),
+    ))
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn standby_produces_only_while_primary_is_silent() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .withf(|height, _, _, _| *height == BlockHeight::from(3u32))
+        .times(1)
+        .returning(|_, _, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::from_secs(10),
+        },
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: Some(Standby {
+            missed_intervals: 3,
+            min_peers: 1,
+        }),
+        p2p: Some(network(Arc::new(1.into()), Default::default())),
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    // The standby doesn't produce while the primary is producing.
+    task.produce_next_block().await.unwrap();
+    task.on_imported_block(BlockHeader::new_block(
+        BlockHeight::from(2u32),
+        Tai64::now(),
+    ))
+    .await
+    .unwrap();
+    task.produce_next_block().await.unwrap();
+    // The failover timer expires, so the standby takes over at the height `3`.
+    task.on_timer(time::Instant::now()).await.unwrap();
+    // The primary is back after the partition heals, so the standby steps down.
+    task.on_imported_block(BlockHeader::new_block(
+        BlockHeight::from(4u32),
+        Tai64::now(),
+    ))
+    .await
+    .unwrap();
+    task.produce_next_block().await.unwrap();
+}
+
+/// The view of the network controlled by the test: the number of connected peers and
+/// the highest height they announced.
+fn network(peers: Arc<AtomicUsize>, height: Arc<AtomicU32>) -> Arc<dyn PeerToPeerPort> {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_connected_peers()
+        .returning(move || peers.load(Ordering::Relaxed));
+    p2p.expect_highest_peer_height().returning(move || {
+        match height.load(Ordering::Relaxed) {
+            0 => None,
+            height => Some(height.into()),
+        }
+    });
+    Arc::new(p2p)
+}
+
+#[tokio::test]
+async fn standby_is_fenced_by_network_partition() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .withf(|height, _, _, _| *height == BlockHeight::from(2u32))
+        .times(1)
+        .returning(|_, _, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let peers = Arc::new(AtomicUsize::new(1));
+    let peer_height = Arc::new(AtomicU32::new(1));
+    let config = Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::from_secs(10),
+        },
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: Some(Standby {
+            missed_intervals: 3,
+            min_peers: 2,
+        }),
+        p2p: Some(network(peers.clone(), peer_height.clone())),
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    // The standby is cut off from the network, so the silence of the primary may be the
+    // partition, and the standby doesn't take over.
+    task.on_timer(time::Instant::now()).await.unwrap();
+    task.produce_next_block().await.unwrap();
+
+    // The standby sees enough peers and none of them has seen the next block, so it
+    // takes over at the height `2`.
+    peers.store(2, Ordering::Relaxed);
+    task.on_timer(time::Instant::now()).await.unwrap();
+
+    // The primary is producing behind the partition, and peers announce its block at the
+    // height `3`. The standby steps down without producing the conflicting block.
+    peer_height.store(3, Ordering::Relaxed);
+    task.produce_next_block().await.unwrap();
+    task.on_timer(time::Instant::now()).await.unwrap();
+}
+
+#[tokio::test]
+async fn rotated_signer_signs_blocks_from_boundary() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let new_secret_key = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(2)
+        .returning(|_, _, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let signers = Arc::new(Mutex::new(vec![]));
+    let committed = signers.clone();
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(2)
+        .returning(move |result| {
+            let block = result.into_result().sealed_block;
+            let signer = match block.consensus {
+                Consensus::PoA(poa) => poa.block_producer(&block.entity.id()).unwrap(),
+                _ => unreachable!("The block is sealed by the PoA"),
+            };
+            committed.lock().unwrap().push(signer);
+            Ok(())
+        });
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![KeyRotation {
+            height: BlockHeight::from(3u32),
+            signing_key: Input::owner(&new_secret_key.public_key()),
+        }],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    let new_signer = Arc::new(Secret::new(new_secret_key.into()));
+    // The height of the latest block can't be the boundary.
+    assert!(task
+        .rotate_signer(SignerRotation {
+            signer: new_signer.clone(),
+            height: BlockHeight::from(1u32),
+        })
+        .is_err());
+    // Followers would reject blocks of the rotation outside of the key schedule.
+    assert!(task
+        .rotate_signer(SignerRotation {
+            signer: new_signer.clone(),
+            height: BlockHeight::from(4u32),
+        })
+        .is_err());
+    task.rotate_signer(SignerRotation {
+        signer: new_signer,
+        height: BlockHeight::from(3u32),
+    })
+    .unwrap();
+    task.produce_next_block().await.unwrap();
+    task.produce_next_block().await.unwrap();
+
+    assert_eq!(
+        *signers.lock().unwrap(),
+        vec![
+            Input::owner(&secret_key.public_key()),
+            Input::owner(&new_secret_key.public_key()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn signer_outside_of_key_schedule_does_not_produce() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let new_secret_key = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer.expect_produce_and_execute_block().never();
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().never();
+    let txpool = MockTransactionPool::no_tx_updates();
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        // The restarted node is configured with the key replaced at the height `2`.
+        key_rotations: vec![KeyRotation {
+            height: BlockHeight::from(2u32),
+            signing_key: Input::owner(&new_secret_key.public_key()),
+        }],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    let error = task.produce_next_block().await.unwrap_err();
+    assert!(error.to_string().contains("key schedule"));
+}
+
+/// The producer whose non-empty blocks always exceed the execution budget.
+struct StuckBlockProducer {
+    gas_limits: Arc<Mutex<Vec<Word>>>,
+}
+
+#[async_trait::async_trait]
+impl BlockProducer for StuckBlockProducer {
+    type Database = EmptyStorage;
+
+    async fn produce_and_execute_block(
+        &self,
+        _: BlockHeight,
+        _: Tai64,
+        _: Word,
+        _: Option<DaBlockHeight>,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<EmptyStorage>>> {
+        unreachable!("The producer is used only with the execution budget")
+    }
+
+    async fn produce_and_execute_block_within(
+        &self,
+        _: BlockHeight,
+        _: Tai64,
+        max_gas: Word,
+        _: Option<DaBlockHeight>,
+        _: Duration,
+    ) -> anyhow::Result<Option<UncommittedResult<StorageTransaction<EmptyStorage>>>> {
+        self.gas_limits.lock().unwrap().push(max_gas);
+        if max_gas > 0 {
+            return Ok(None)
+        }
+        Ok(Some(UncommittedResult::new(
+            ExecutionResult {
+                block: Default::default(),
+                skipped_transactions: Default::default(),
+                tx_status: Default::default(),
+            },
+            StorageTransaction::new(EmptyStorage),
+        )))
+    }
+
+    fn speculate_next_block(
+        &self,
+        _: &StorageTransaction<EmptyStorage>,
+        _: BlockHeight,
+        _: Tai64,
+        _: Word,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn interval_trigger_produces_empty_block_when_execution_budget_exceeded() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let gas_limits = Arc::new(Mutex::new(vec![]));
+    let block_producer = StuckBlockProducer {
+        gas_limits: gas_limits.clone(),
+    };
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::from_secs(10),
+        },
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: Some(Duration::from_secs(1)),
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    task.produce_next_block().await.unwrap();
+
+    assert_eq!(*gas_limits.lock().unwrap(), vec![1000000, 0]);
+}
+
+/// The offset of the system clock used by the producer in the test of clock jumps.
+static CLOCK_OFFSET: AtomicU64 = AtomicU64::new(0);
+
+fn jumping_wall_clock() -> Tai64 {
+    Tai64(Tai64::now().0 + CLOCK_OFFSET.load(Ordering::SeqCst))
+}
+
+#[tokio::test]
+async fn forward_jump_of_system_clock_is_clamped_in_block_time() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let block_times = Arc::new(Mutex::new(vec![]));
+    let mut block_producer = MockBlockProducer::default();
+    let produced_times = block_times.clone();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(move |_, block_time, _, _| {
+            produced_times.lock().unwrap().push(block_time);
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_pending_number().returning(|| 1);
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+    task.set_clock(SystemClock::with_wall_clock(jumping_wall_clock));
+
+    // The system clock jumps an hour forward.
+    CLOCK_OFFSET.store(3600, Ordering::SeqCst);
+    task.produce_next_block().await.unwrap();
+
+    let block_time = block_times.lock().unwrap()[0];
+    let max_block_time = Tai64::now().0 + CLOCK_JUMP_THRESHOLD.as_secs() + 1;
+    assert!(block_time.0 <= max_block_time);
+}
+#[tokio::test]
+async fn gas_limit_decreases_toward_target_utilization_of_empty_blocks() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let gas_limits = Arc::new(Mutex::new(vec![]));
+    let requested = gas_limits.clone();
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(4)
+        .returning(move |_, _, gas_limit, _| {
+            requested.lock().unwrap().push(gas_limit);
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    let recorded_gas_limits = Arc::new(Mutex::new(vec![]));
+    let recorded = recorded_gas_limits.clone();
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(4)
+        .returning(|_| Ok(()));
+    block_importer
+        .expect_record_gas_limit()
+        .times(4)
+        .returning(move |_, gas_limit| {
+            recorded.lock().unwrap().push(gas_limit);
+            Ok(())
+        });
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 800_000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: Some(GasLimitAdjustment {
+            min_gas_limit: 600_000,
+            max_gas_limit: 1_000_000,
+            target_utilization: 50,
+        }),
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    for _ in 0..4 {
+        task.produce_next_block().await.unwrap();
+    }
+
+    // Each empty block decreases the limit by 1/8 until the minimum.
+    assert_eq!(*gas_limits.lock().unwrap(), vec![800_000, 700_000, 612_500, 600_000]);
+    // The limit of the next block is recorded with each block to survive restarts.
+    assert_eq!(
+        *recorded_gas_limits.lock().unwrap(),
+        vec![700_000, 612_500, 600_000, 600_000]
+    );
+}
+
+#[tokio::test]
+async fn gas_limit_follows_gas_used_by_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let gas_limits = Arc::new(Mutex::new(vec![]));
+    let requested = gas_limits.clone();
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(2)
+        .returning(move |_, _, gas_limit, _| {
+            requested.lock().unwrap().push(gas_limit);
+            // Transactions use 3/4 of the limit, above the target of 1/2.
+            let status = |gas_used| TransactionExecutionStatus {
+                id: Default::default(),
+                result: TransactionExecutionResult::Success { result: None },
+                gas_used,
+            };
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: vec![status(gas_limit / 4), status(gas_limit / 2)],
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .times(2)
+        .returning(|_| Ok(()));
+    block_importer
+        .expect_record_gas_limit()
+        .times(2)
+        .returning(|_, _| Ok(()));
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 800_000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: Some(GasLimitAdjustment {
+            min_gas_limit: 600_000,
+            max_gas_limit: 1_000_000,
+            target_utilization: 50,
+        }),
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    for _ in 0..2 {
+        task.produce_next_block().await.unwrap();
+    }
+
+    // The excess of 1/2 of the target increases the limit by 1/16.
+    assert_eq!(*gas_limits.lock().unwrap(), vec![800_000, 850_000]);
+}
+
+#[tokio::test]
+async fn invalid_produced_block_is_not_sealed() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let tx: Transaction = make_tx(&mut rng).into();
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(move |height, time, _, _| {
+            // The header doesn't account for the transaction.
+            let mut block = Block::default();
+            block.header_mut().consensus.height = height;
+            block.header_mut().consensus.time = time;
+            block.header_mut().recalculate_metadata();
+            block.transactions_mut().push(tx.clone());
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().never();
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: Some(VerifierConfig {
+            enabled_manual_blocks: false,
+            min_block_time: Duration::ZERO,
+        }),
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    assert!(task.produce_next_block().await.is_err());
+}
+
+#[tokio::test]
+async fn produced_block_not_following_previous_block_is_not_sealed() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(move |height, time, _, _| {
+            // Followers would reject the block with another previous root.
+            let mut block = Block::default();
+            block.header_mut().consensus.height = height;
+            block.header_mut().consensus.time = time;
+            block.header_mut().consensus.prev_root = Bytes32::from([1; 32]);
+            block.header_mut().recalculate_metadata();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().never();
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: Some(VerifierConfig {
+            enabled_manual_blocks: false,
+            min_block_time: Duration::ZERO,
+        }),
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    let err = task.produce_next_block().await.unwrap_err();
+    assert!(format!("{err:#}").contains("Previous root"), "{err:#}");
+}
+
 #[tokio::test]
 async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     // verify the PoA service doesn't trigger empty blocks to be produced when there are
@@ -329,7 +1430,7 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
 
     block_producer
         .expect_produce_and_execute_block()
-        .returning(|_, _, _| panic!("Block production should not be called"));
+        .returning(|_, _, _, _| panic!("Block production should not be called"));
 
     let mut block_importer = MockBlockImporter::default();
 
@@ -344,9 +1445,21 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     };
     let mut task = Task::new(
         &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
@@ -381,7 +1494,7 @@ async fn hybrid_production_doesnt_produce_empty_blocks_when_txpool_is_empty() {
 
     block_producer
         .expect_produce_and_execute_block()
-        .returning(|_, _, _| panic!("Block production should not be called"));
+        .returning(|_, _, _, _| panic!("Block production should not be called"));
 
     let mut block_importer = MockBlockImporter::default();
 
@@ -400,9 +1513,21 @@ async fn hybrid_production_doesnt_produce_empty_blocks_when_txpool_is_empty() {
             max_block_time: Duration::from_millis(1000),
         },
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: Some(Arc::new(Secret::new(secret_key.into()))),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     };
     let task = Task::new(
         &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
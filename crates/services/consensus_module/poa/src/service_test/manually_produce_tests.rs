@@ -43,9 +43,21 @@ async fn can_manually_produce_block(
     ctx_builder.with_config(Config {
         trigger,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // initialize txpool with some txs
@@ -68,7 +80,7 @@ async fn can_manually_produce_block(
     let mut producer = MockBlockProducer::default();
     producer
         .expect_produce_and_execute_block()
-        .returning(|_, time, _| {
+        .returning(|_, time, _, _| {
             let mut block = Block::default();
             block.header_mut().consensus.time = time;
             block.header_mut().recalculate_metadata();
@@ -87,7 +99,12 @@ async fn can_manually_produce_block(
 
     ctx.service
         .shared
-        .manually_produce_block(Some(start_time), number_of_blocks)
+        .manually_produce_block(
+            Some(start_time),
+            number_of_blocks,
+            ManualGasLimit::Config,
+            None,
+        )
         .await
         .unwrap();
     for _ in 0..num_txns {
@@ -102,3 +119,137 @@ async fn can_manually_produce_block(
     // Stop
     assert_eq!(ctx.stop().await, State::Stopped);
 }
+
+#[tokio::test]
+async fn manually_produced_block_is_sized_from_pool() {
+    let mut rng = StdRng::seed_from_u64(1234u64);
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: u64::MAX,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    });
+
+    let txs: Vec<_> = (0..5).map(|_| make_tx(&mut rng)).collect();
+    let pool_gas: Word = txs.iter().map(|tx| *tx.gas_limit()).sum();
+    // The gas used by transactions is below their gas limit
+    let tx_status: Vec<_> = (0..5)
+        .map(|i| TransactionExecutionStatus {
+            id: Default::default(),
+            result: TransactionExecutionResult::Success { result: None },
+            gas_used: i * 10,
+        })
+        .collect();
+    let TxPoolContext { txpool, .. } = MockTransactionPool::new_with_txs(txs.clone());
+    ctx_builder.with_txpool(txpool);
+
+    let mut importer = MockBlockImporter::default();
+    importer.expect_commit_result().returning(|_| Ok(()));
+    let mut producer = MockBlockProducer::default();
+    producer
+        .expect_produce_and_execute_block()
+        .withf(move |_, _, gas_limit, _| *gas_limit == pool_gas)
+        .returning(move |_, _, _, _| {
+            let mut block = Block::default();
+            *block.transactions_mut() = txs.iter().cloned().map(Into::into).collect();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: tx_status.clone(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    ctx_builder.with_importer(importer);
+    ctx_builder.with_producer(producer);
+    let ctx = ctx_builder.build();
+
+    let blocks = ctx
+        .service
+        .shared
+        .manually_produce_block(None, 1, ManualGasLimit::FromPool, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        blocks,
+        vec![ProducedBlock {
+            height: BlockHeight::from(2u32),
+            gas_limit: pool_gas,
+            gas_used: 100,
+        }]
+    );
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn manually_produced_blocks_use_pinned_da_height() {
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    });
+
+    let mut importer = MockBlockImporter::default();
+    importer.expect_commit_result().returning(|_| Ok(()));
+    let mut producer = MockBlockProducer::default();
+    producer
+        .expect_produce_and_execute_block()
+        .withf(|_, _, _, da_height| *da_height == Some(5u64.into()))
+        .times(2)
+        .returning(|_, _, _, da_height| {
+            let mut block = Block::default();
+            block.header_mut().application.da_height = da_height.unwrap();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    ctx_builder.with_importer(importer);
+    ctx_builder.with_producer(producer);
+    let ctx = ctx_builder.build();
+
+    let blocks = ctx
+        .service
+        .shared
+        .manually_produce_block(None, 2, ManualGasLimit::Config, Some(5u64.into()))
+        .await
+        .unwrap();
+
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
@@ -13,14 +13,30 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
             max_tx_idle_time: Duration::new(1, 0),
             max_block_time: Duration::new(1, 0),
         },
+        Trigger::Adaptive {
+            min_block_time: Duration::new(1, 0),
+            max_block_time: Duration::new(1, 0),
+        },
     ] {
         let mut ctx_builder = TestContextBuilder::new();
         ctx_builder.with_config(Config {
             trigger,
             block_gas_limit: 100_000,
-            signing_key: Some(test_signing_key()),
+            signer: Some(Arc::new(test_signing_key())),
+            authorities: vec![],
+            key_rotations: vec![],
+            pre_confirmations: false,
             metrics: false,
             consensus_params: Default::default(),
+            runtime: None,
+            execution_budget: None,
+            gas_limit_adjustment: None,
+            verify_before_seal: None,
+            speculative_execution: false,
+            standby: None,
+            p2p: None,
+            late_block_policy: LateBlockPolicy::Drift,
+            trigger_limits: Default::default(),
         });
         let ctx = ctx_builder.build();
 
@@ -38,9 +54,21 @@ async fn never_trigger_never_produces_blocks() {
     ctx_builder.with_config(Config {
         trigger: Trigger::Never,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // initialize txpool with some txs
@@ -119,9 +147,21 @@ async fn instant_trigger_produces_block_instantly() {
     let mut ctx = DefaultContext::new(Config {
         trigger: Trigger::Instant,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
     ctx.status_sender.send_replace(Some(TxStatus::Submitted));
 
@@ -139,9 +179,21 @@ async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
     ctx.status_sender.send_replace(Some(TxStatus::Submitted));
 
@@ -205,9 +257,21 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // Brackets to release the lock.
@@ -252,9 +316,21 @@ async fn hybrid_trigger_produces_blocks_correctly_max_block_time() -> anyhow::Re
             max_block_time: Duration::new(10, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // Make sure no blocks are produced yet
@@ -299,9 +375,21 @@ async fn hybrid_trigger_produces_blocks_correctly_max_block_time_not_overrides_m
         },
         // We want to test behaviour when the gas of all transactions < `block_gas_limit`
         block_gas_limit: Word::MAX,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // Make sure no blocks are produced when txpool is empty and `MAX_BLOCK_TIME` is not exceeded
@@ -342,9 +430,21 @@ async fn hybrid_trigger_produces_blocks_correctly_max_tx_idle_time() -> anyhow::
         },
         // We want to test behaviour when the gas of all transactions < `block_gas_limit`
         block_gas_limit: Word::MAX,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     assert!(matches!(
@@ -394,9 +494,21 @@ async fn hybrid_trigger_produces_blocks_correctly_min_block_time_min_block_gas_l
         },
         // We want to test behaviour when the gas of all transactions > `block_gas_limit`
         block_gas_limit: Word::MIN,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // Emulate tx status update to trigger the execution.
@@ -435,6 +547,72 @@ async fn hybrid_trigger_produces_blocks_correctly_min_block_time_min_block_gas_l
     Ok(())
 }
 
+#[tokio::test(start_paused = true)]
+async fn adaptive_trigger_shortens_interval_with_load() -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Adaptive {
+            min_block_time: Duration::new(2, 0),
+            max_block_time: Duration::new(10, 0),
+        },
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    });
+    ctx.txs.lock().unwrap().clear();
+
+    // The empty txpool waits for the `max_block_time`
+    time::sleep(Duration::new(9, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(matches!(ctx.block_import.try_recv(), Ok(_)));
+
+    // The full txpool brings the next block to the `min_block_time`
+    {
+        let mut guard = ctx.txs.lock().unwrap();
+        for _ in 0..1_000 {
+            guard.push(make_tx(&mut ctx.rng));
+        }
+        ctx.status_sender.send_replace(Some(TxStatus::Submitted));
+    }
+    time::sleep(Duration::from_millis(500)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(ctx.block_import.try_recv(), Ok(_)));
+
+    for _ in 0..3 {
+        time::sleep(Duration::new(2, 0)).await;
+        assert!(matches!(ctx.block_import.try_recv(), Ok(_)));
+        assert!(matches!(
+            ctx.block_import.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
 // TODO: We found a bug https://github.com/FuelLabs/fuel-core/issues/866 in the hybrid logic,
 //  we need to fix it=) Don't remove this test. Remove `ignore` when bug is resolved.
 #[ignore]
@@ -452,9 +630,21 @@ async fn hybrid_trigger_produces_blocks_correctly_min_block_time_max_block_gas_l
         },
         // We want to test behaviour when the gas of all transactions < `block_gas_limit`
         block_gas_limit: Word::MAX,
-        signing_key: Some(test_signing_key()),
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
         metrics: false,
         consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
     });
 
     // Emulate tx status update to trigger the execution.
@@ -490,3 +680,325 @@ async fn hybrid_trigger_produces_blocks_correctly_min_block_time_max_block_gas_l
 
     Ok(())
 }
+
+#[tokio::test(start_paused = true)]
+async fn trigger_is_switched_at_runtime() -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    });
+    time::sleep(Duration::new(5, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // The interval is counted from the switch
+    ctx.test_ctx
+        .service
+        .shared
+        .set_trigger(Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        })
+        .await?;
+    time::sleep(Duration::from_millis(1500)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(ctx.block_import.try_recv(), Ok(_)));
+
+    // Switching back to `Never` cancels the next block
+    ctx.test_ctx
+        .service
+        .shared
+        .set_trigger(Trigger::Never)
+        .await?;
+    time::sleep(Duration::new(5, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Inconsistent timings are rejected and keep the running trigger
+    let result = ctx
+        .test_ctx
+        .service
+        .shared
+        .set_trigger(Trigger::Hybrid {
+            min_block_time: Duration::new(3, 0),
+            max_tx_idle_time: Duration::new(1, 0),
+            max_block_time: Duration::new(2, 0),
+        })
+        .await;
+    assert!(result.is_err());
+
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_without_manual_blocks_is_rejected_at_runtime() -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: TriggerLimits {
+            manual_blocks_enabled: true,
+            cadence: None,
+        },
+    });
+    let shared = ctx.test_ctx.service.shared.clone();
+
+    // The `Hybrid` trigger doesn't support manual blocks
+    let result = shared
+        .set_trigger(Trigger::Hybrid {
+            min_block_time: Duration::new(1, 0),
+            max_tx_idle_time: Duration::new(1, 0),
+            max_block_time: Duration::new(2, 0),
+        })
+        .await;
+    assert!(result.is_err());
+
+    // The running trigger is kept and manual blocks are still produced
+    time::sleep(Duration::new(5, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    shared
+        .manually_produce_block(None, 1, ManualGasLimit::Config, None)
+        .await?;
+    assert!(matches!(ctx.block_import.try_recv(), Ok(_)));
+
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn block_time_below_cadence_is_rejected_at_runtime() -> anyhow::Result<()> {
+    let ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        },
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: TriggerLimits {
+            manual_blocks_enabled: false,
+            cadence: Some(BlockProduction::Interval {
+                block_time: Duration::new(2, 0),
+            }),
+        },
+    });
+    let shared = ctx.test_ctx.service.shared.clone();
+
+    assert!(shared.set_block_time(Duration::new(1, 0)).is_err());
+    shared.set_block_time(Duration::new(3, 0))?;
+
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn paused_production_resumes_with_trigger() -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        },
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy: LateBlockPolicy::Drift,
+        trigger_limits: Default::default(),
+    });
+    let shared = ctx.test_ctx.service.shared.clone();
+    shared.set_paused(true).await?;
+
+    // Neither the trigger nor the manual production seals blocks
+    time::sleep(Duration::new(5, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    let result = shared
+        .manually_produce_block(None, 1, ManualGasLimit::Config, None)
+        .await;
+    assert!(result.is_err());
+
+    // The interval is counted from the resumption
+    shared.set_paused(false).await?;
+    time::sleep(Duration::from_millis(1500)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(ctx.block_import.try_recv(), Ok(_)));
+
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+/// Produces blocks with the `Interval` trigger of 10 seconds when the production is
+/// delayed to 25 seconds after the last block, and again at 30 seconds. Returns times of
+/// produced blocks in seconds after the last block, including caught up blocks.
+async fn times_of_late_blocks(late_block_policy: LateBlockPolicy) -> Vec<u64> {
+    let last_block_time = Tai64::now();
+    let times = Arc::new(StdMutex::new(vec![]));
+
+    let mut block_producer = MockBlockProducer::default();
+    let produced_times = times.clone();
+    block_producer
+        .expect_produce_and_execute_block()
+        .returning(move |_, time, _, _| {
+            produced_times.lock().unwrap().push(time);
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().returning(|_| Ok(()));
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::from_secs(10),
+        },
+        block_gas_limit: 100_000,
+        signer: Some(Arc::new(test_signing_key())),
+        authorities: vec![],
+        key_rotations: vec![],
+        pre_confirmations: false,
+        metrics: false,
+        consensus_params: Default::default(),
+        runtime: None,
+        execution_budget: None,
+        gas_limit_adjustment: None,
+        verify_before_seal: None,
+        speculative_execution: false,
+        standby: None,
+        p2p: None,
+        late_block_policy,
+        trigger_limits: Default::default(),
+    };
+    let mut task = Task::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), last_block_time),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+    );
+
+    time::advance(Duration::from_secs(25)).await;
+    task.produce_next_block().await.unwrap();
+    // Each catch-up block is produced by its own call, the timer of the running task
+    // fires right away for the next one
+    while task.catching_up {
+        let produced = times.lock().unwrap().len();
+        task.produce_next_block().await.unwrap();
+        assert_eq!(times.lock().unwrap().len(), produced + 1);
+    }
+    time::advance(Duration::from_secs(5)).await;
+    task.produce_next_block().await.unwrap();
+    assert!(!task.catching_up);
+
+    let times = times.lock().unwrap();
+    times
+        .iter()
+        .map(|time| time.0 - last_block_time.0)
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn late_block_drifts_by_default() {
+    let times = times_of_late_blocks(LateBlockPolicy::Drift).await;
+
+    // Both blocks are produced with the current time
+    assert_eq!(times.len(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn late_block_is_skipped() {
+    let times = times_of_late_blocks(LateBlockPolicy::Skip).await;
+
+    // The slots at 10 and 20 seconds are skipped
+    assert_eq!(times, vec![30]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn late_block_is_backdated() {
+    let times = times_of_late_blocks(LateBlockPolicy::Backdate).await;
+
+    // The slot at 20 seconds is skipped
+    assert_eq!(times, vec![10, 30]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn late_blocks_are_caught_up() {
+    let times = times_of_late_blocks(LateBlockPolicy::CatchUp).await;
+
+    assert_eq!(times, vec![10, 20, 30]);
+}
@@ -0,0 +1,167 @@
+//! The signer with the consensus key loaded into the memory of the node and the signer
+//! of the committee of the threshold PoA.
+
+use crate::ports::BlockSigner;
+use anyhow::anyhow;
+use fuel_core_types::{
+    blockchain::{
+        consensus::{
+            poa::{
+                PoASealScheme,
+                PoAThresholdConsensus,
+            },
+            seal::{
+                Committee,
+                SealScheme,
+                ThresholdSeal,
+            },
+            Consensus,
+        },
+        header::BlockHeader,
+        primitives::SecretKeyWrapper,
+    },
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_types::Address,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use std::{
+    ops::Deref,
+    sync::Arc,
+};
+
+#[async_trait::async_trait]
+impl BlockSigner for Secret<SecretKeyWrapper> {
+    fn address(&self) -> Address {
+        PoASealScheme::authority(self.expose_secret().deref())
+    }
+
+    async fn sign(&self, message: &Message) -> anyhow::Result<Signature> {
+        // The length of the secret is checked
+        let signing_key = self.expose_secret().deref();
+        Ok(PoASealScheme::seal(signing_key, message))
+    }
+}
+
+/// Seals blocks on behalf of the committee. Members are asked for signatures in order
+/// until the threshold of the committee is reached, so unavailable members don't stop
+/// the block production. Members receive the whole header of the block, so remote
+/// cosigners can check the block before they sign it.
+pub struct ThresholdSigner {
+    committee: Committee,
+    members: Vec<Arc<dyn BlockSigner>>,
+}
+
+impl ThresholdSigner {
+    pub fn new(committee: Committee, members: Vec<Arc<dyn BlockSigner>>) -> Self {
+        Self { committee, members }
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockSigner for ThresholdSigner {
+    fn address(&self) -> Address {
+        self.committee.address()
+    }
+
+    async fn sign(&self, _: &Message) -> anyhow::Result<Signature> {
+        Err(anyhow!(
+            "The committee seals blocks with the threshold seal, not with the signature"
+        ))
+    }
+
+    async fn seal(&self, header: &BlockHeader) -> anyhow::Result<Consensus> {
+        let block_id = header.id();
+        let mut signatures = vec![];
+        for member in &self.members {
+            if signatures.len() >= self.committee.threshold as usize {
+                break
+            }
+            match member.sign_block(header).await {
+                Ok(signature) => signatures.push(signature),
+                Err(err) => tracing::warn!(
+                    "The member {} of the committee failed to sign the block {}: {}",
+                    member.address(),
+                    block_id,
+                    err
+                ),
+            }
+        }
+        // The committee is fixed by the chain config, the seal only refers to it.
+        let seal = ThresholdSeal {
+            committee: self.committee.address(),
+            signatures,
+        };
+        // Fails if members signed with unexpected keys or the threshold isn't reached
+        self.committee.verify(&seal, block_id.as_message())?;
+        Ok(Consensus::PoAThreshold(PoAThresholdConsensus::new(seal)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_crypto::SecretKey;
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    struct Unavailable;
+
+    #[async_trait::async_trait]
+    impl BlockSigner for Unavailable {
+        fn address(&self) -> Address {
+            Address::default()
+        }
+
+        async fn sign(&self, _: &Message) -> anyhow::Result<Signature> {
+            Err(anyhow!("The signing service is unavailable"))
+        }
+    }
+
+    fn member(rng: &mut StdRng) -> Arc<dyn BlockSigner> {
+        let secret: Secret<SecretKeyWrapper> = Secret::new(SecretKey::random(rng).into());
+        Arc::new(secret)
+    }
+
+    #[tokio::test]
+    async fn committee_seals_block_once_threshold_is_reached() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let members = vec![member(&mut rng), Arc::new(Unavailable), member(&mut rng)];
+        let committee = Committee {
+            threshold: 2,
+            members: members.iter().map(|member| member.address()).collect(),
+        };
+        let signer = ThresholdSigner::new(committee.clone(), members);
+        let header = BlockHeader::default();
+
+        let consensus = signer.seal(&header).await.unwrap();
+
+        match consensus {
+            Consensus::PoAThreshold(consensus) => {
+                assert_eq!(consensus.seal.committee, committee.address());
+                assert!(consensus.is_sealed_by(&header.id(), &committee));
+            }
+            _ => panic!("The block should be sealed by the committee"),
+        }
+    }
+
+    #[tokio::test]
+    async fn committee_fails_to_seal_block_below_threshold() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let members = vec![member(&mut rng), Arc::new(Unavailable)];
+        let committee = Committee {
+            threshold: 2,
+            members: members.iter().map(|member| member.address()).collect(),
+        };
+        let signer = ThresholdSigner::new(committee, members);
+
+        assert!(signer.seal(&BlockHeader::default()).await.is_err());
+    }
+}
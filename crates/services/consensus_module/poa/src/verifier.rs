@@ -1,22 +1,54 @@
-use crate::ports::Database;
+use crate::{
+    clock::ahead_of_system_clock,
+    ports::Database,
+};
 use anyhow::ensure;
-use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_chain_config::{
+    ChainConfig,
+    ConsensusConfig,
+};
+use fuel_core_metrics::poa_metrics::POA_METRICS;
 use fuel_core_types::{
     blockchain::{
         block::Block,
-        consensus::poa::PoAConsensus,
+        consensus::poa::{
+            PoAConsensus,
+            PoAThresholdConsensus,
+        },
         header::BlockHeader,
     },
-    fuel_tx::Input,
+    fuel_asm::Word,
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+    tai64::Tai64,
 };
+use std::time::Duration;
 
 #[cfg(test)]
 mod tests;
 
 /// The config of the block verifier.
+#[derive(Clone, Debug)]
 pub struct Config {
     /// If the manual block is enabled, skip verification of some fields.
     pub enabled_manual_blocks: bool,
+    /// The minimum time between timestamps of two blocks defined by the cadence
+    /// of the chain config. Block timestamps have a precision of seconds.
+    pub min_block_time: Duration,
+}
+
+impl Config {
+    /// Creates the config that verifies blocks of the chain with the `chain_config`.
+    pub fn new(chain_config: &ChainConfig, enabled_manual_blocks: bool) -> Self {
+        let min_block_time = chain_config
+            .block_production
+            .map(|block_production| block_production.min_block_time())
+            .unwrap_or_default();
+        Self {
+            enabled_manual_blocks,
+            min_block_time,
+        }
+    }
 }
 
 // TODO: Make this function `async` and await the synchronization with the relayer.
@@ -25,16 +57,27 @@ pub fn verify_consensus(
     header: &BlockHeader,
     consensus: &PoAConsensus,
 ) -> bool {
-    match consensus_config {
-        ConsensusConfig::PoA { signing_key } => {
-            let id = header.id();
-            let m = id.as_message();
-            consensus
-                .signature
-                .recover(m)
-                .map_or(false, |k| Input::owner(&k) == *signing_key)
-        }
-    }
+    // The block at each height can be signed only by the authority scheduled for it.
+    let authority = match consensus_config.authority_at(*header.height()) {
+        Some(authority) => authority,
+        None => return false,
+    };
+    consensus.is_sealed_by(&header.id(), &authority)
+}
+
+/// Verifies the block sealed by the committee. The seal only refers to the committee,
+/// so it is accepted only if it refers to the committee of the chain config and is
+/// signed by the threshold of its members.
+pub fn verify_threshold_consensus(
+    consensus_config: &ConsensusConfig,
+    header: &BlockHeader,
+    consensus: &PoAThresholdConsensus,
+) -> bool {
+    let committee = match consensus_config.committee() {
+        Some(committee) => committee,
+        None => return false,
+    };
+    consensus.is_sealed_by(&header.id(), &committee)
 }
 
 pub fn verify_block_fields<D: Database>(
@@ -69,6 +112,26 @@ pub fn verify_block_fields<D: Database>(
             header.time() >= prev_header.time(),
             "The `time` of the next block can't be lower"
         );
+        let min_block_time = config.min_block_time.as_secs();
+        ensure!(
+            header.time().0.saturating_sub(prev_header.time().0) >= min_block_time,
+            "The `time` of the next block is {}s after the previous block, but the chain \
+            config expects at least {}s between blocks. Check that the block production trigger \
+            of the producer matches the `block_production` of the chain config",
+            header.time().0.saturating_sub(prev_header.time().0),
+            min_block_time
+        );
+        // The block isn't rejected because the local system clock may be the one that
+        // jumped, but the event is worth the attention of the operator.
+        if let Some(ahead) = ahead_of_system_clock(header.time()) {
+            tracing::warn!(
+                "The `time` of the block at height {} is {}s ahead of the system clock. \
+                Either the producer or the local system clock jumped",
+                *height,
+                ahead
+            );
+            POA_METRICS.blocks_ahead_of_clock.inc();
+        }
     }
 
     ensure!(
@@ -85,3 +148,60 @@ pub fn verify_block_fields<D: Database>(
 
     Ok(())
 }
+
+/// Verifies the block produced by the local node before it is sealed, so the invalid block
+/// is never signed instead of being rejected by validators during the import. The block
+/// passes the same [`verify_block_fields`] as on followers, and is also checked against
+/// the parameters of the production and the `receipts` of its execution.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_produced_block<D: Database>(
+    config: &Config,
+    database: &D,
+    block: &Block,
+    receipts: &[Receipt],
+    height: BlockHeight,
+    time: Tai64,
+    gas_limit: Word,
+    max_gas: Word,
+) -> anyhow::Result<()> {
+    verify_block_fields(config, database, block)?;
+
+    let header = block.header();
+    ensure!(
+        *header.height() == height,
+        "The produced block has the height {} instead of {}",
+        **header.height(),
+        *height
+    );
+    ensure!(
+        header.time() == time,
+        "The produced block has the time {} instead of {}",
+        header.time().0,
+        time.0
+    );
+    ensure!(
+        header.transactions_count == block.transactions().len() as u64,
+        "The produced block has {} transactions, but the header counts {}",
+        block.transactions().len(),
+        header.transactions_count
+    );
+    ensure!(
+        header.validate_transactions(block.transactions()),
+        "The transactions root of the produced block mismatch"
+    );
+    let message_receipt_count = receipts
+        .iter()
+        .filter(|receipt| receipt.message_id().is_some())
+        .count() as u64;
+    ensure!(
+        header.message_receipt_count == message_receipt_count
+            && header.message_receipt_root == Block::recompute_receipts_root(receipts),
+        "The message receipt root of the produced block mismatch"
+    );
+    ensure!(
+        max_gas <= gas_limit,
+        "The transactions of the produced block have {max_gas} maximal gas above the gas \
+        limit {gas_limit}"
+    );
+    Ok(())
+}
@@ -1,15 +1,33 @@
 use super::*;
 use crate::ports::MockDatabase;
 use fuel_core_types::{
-    blockchain::header::{
-        ApplicationHeader,
-        ConsensusHeader,
-        GeneratedApplicationFields,
-        GeneratedConsensusFields,
+    blockchain::{
+        consensus::{
+            poa::PoASealScheme,
+            seal::{
+                SealScheme,
+                ThresholdSeal,
+            },
+        },
+        header::{
+            ApplicationHeader,
+            ConsensusHeader,
+            GeneratedApplicationFields,
+            GeneratedConsensusFields,
+        },
     },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx,
     fuel_types::Bytes32,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -33,6 +51,7 @@ fn correct() -> Input {
     Input {
         c: Config {
             enabled_manual_blocks: false,
+            min_block_time: Duration::ZERO,
         },
         block_header_merkle_root: [2u8; 32],
         prev_header_time: Tai64(2),
@@ -88,6 +107,31 @@ fn correct() -> Input {
         i
     } => matches Err(_) ; "time before prev header"
 )]
+#[test_case(
+    {
+        let mut i = correct();
+        i.c.min_block_time = Duration::from_secs(2);
+        i.ch.time = Tai64(3);
+        i
+    } => matches Err(_) ; "time closer than min block time"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        i.c.min_block_time = Duration::from_secs(2);
+        i.ch.time = Tai64(4);
+        i
+    } => matches Ok(_) ; "time after min block time"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        i.c.enabled_manual_blocks = true;
+        i.c.min_block_time = Duration::from_secs(2);
+        i.ch.time = Tai64(3);
+        i
+    } => matches Ok(_) ; "min block time ignored with manual blocks"
+)]
 fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     let Input {
         c,
@@ -111,3 +155,119 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     b.header_mut().application = ah;
     verify_block_fields(&c, &d, &b)
 }
+
+#[test]
+fn block_sealed_through_scheme_is_verified() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let other = SecretKey::random(&mut rng);
+    let config = ConsensusConfig::PoA {
+        signing_key: PoASealScheme::authority(&secret),
+        key_rotations: vec![],
+    };
+    let mut header = BlockHeader::default();
+    header.consensus.height = 1u32.into();
+    header.recalculate_metadata();
+
+    let consensus = PoAConsensus::<PoASealScheme>::seal(&header.id(), &secret);
+    assert!(verify_consensus(&config, &header, &consensus));
+    let consensus = PoAConsensus::<PoASealScheme>::seal(&header.id(), &other);
+    assert!(!verify_consensus(&config, &header, &consensus));
+}
+
+#[test]
+fn round_robin_block_is_signed_by_scheduled_authority() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secrets = [SecretKey::random(&mut rng), SecretKey::random(&mut rng)];
+    let config = ConsensusConfig::PoARoundRobin {
+        authorities: secrets
+            .iter()
+            .map(|secret| fuel_tx::Input::owner(&secret.public_key()))
+            .collect(),
+    };
+    let sign = |height: u32, secret: &SecretKey| {
+        let mut header = BlockHeader::default();
+        header.consensus.height = height.into();
+        header.recalculate_metadata();
+        let signature = Signature::sign(secret, &header.id().into_message());
+        (header, PoAConsensus::new(signature))
+    };
+
+    let (header, consensus) = sign(1, &secrets[0]);
+    assert!(verify_consensus(&config, &header, &consensus));
+    let (header, consensus) = sign(2, &secrets[1]);
+    assert!(verify_consensus(&config, &header, &consensus));
+    let (header, consensus) = sign(3, &secrets[1]);
+    assert!(!verify_consensus(&config, &header, &consensus));
+}
+
+#[test]
+fn block_is_signed_by_key_scheduled_for_its_height() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secrets = [SecretKey::random(&mut rng), SecretKey::random(&mut rng)];
+    let config = ConsensusConfig::PoA {
+        signing_key: fuel_tx::Input::owner(&secrets[0].public_key()),
+        key_rotations: vec![fuel_core_chain_config::KeyRotation {
+            height: 2u32.into(),
+            signing_key: fuel_tx::Input::owner(&secrets[1].public_key()),
+        }],
+    };
+    let sign = |height: u32, secret: &SecretKey| {
+        let mut header = BlockHeader::default();
+        header.consensus.height = height.into();
+        header.recalculate_metadata();
+        let signature = Signature::sign(secret, &header.id().into_message());
+        (header, PoAConsensus::new(signature))
+    };
+
+    let (header, consensus) = sign(1, &secrets[0]);
+    assert!(verify_consensus(&config, &header, &consensus));
+    let (header, consensus) = sign(2, &secrets[1]);
+    assert!(verify_consensus(&config, &header, &consensus));
+    let (header, consensus) = sign(2, &secrets[0]);
+    assert!(!verify_consensus(&config, &header, &consensus));
+}
+
+#[test]
+fn threshold_block_is_sealed_by_enough_members_of_committee() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secrets: Vec<_> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+    let config = ConsensusConfig::PoAThreshold {
+        threshold: 2,
+        members: secrets
+            .iter()
+            .map(|secret| fuel_tx::Input::owner(&secret.public_key()))
+            .collect(),
+    };
+    let mut header = BlockHeader::default();
+    header.consensus.height = 1u32.into();
+    header.recalculate_metadata();
+    let seal = |signers: &[&SecretKey]| {
+        PoAThresholdConsensus::new(ThresholdSeal {
+            committee: config.committee().unwrap().address(),
+            signatures: signers
+                .iter()
+                .map(|secret| Signature::sign(secret, &header.id().into_message()))
+                .collect(),
+        })
+    };
+    let outsider = SecretKey::random(&mut rng);
+
+    let consensus = seal(&[&secrets[0], &secrets[2]]);
+    assert!(verify_threshold_consensus(&config, &header, &consensus));
+    let consensus = seal(&[&secrets[1], &secrets[1]]);
+    assert!(!verify_threshold_consensus(&config, &header, &consensus));
+    let consensus = seal(&[&secrets[0], &outsider]);
+    assert!(!verify_threshold_consensus(&config, &header, &consensus));
+
+    let single_authority = ConsensusConfig::PoA {
+        signing_key: config.committee().unwrap().address(),
+        key_rotations: vec![],
+    };
+    let consensus = seal(&[&secrets[0], &secrets[1]]);
+    assert!(!verify_threshold_consensus(
+        &single_authority,
+        &header,
+        &consensus
+    ));
+}
@@ -8,6 +8,7 @@ mod tests;
 
 use crate::block_verifier::config::Config;
 use anyhow::ensure;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::ports::{
     Database as PoAVerifierDatabase,
     RelayerPort,
@@ -69,7 +70,29 @@ where
                     .unwrap_or_else(|| 0u32.into());
                 verify_genesis_block_fields(expected_genesis_height, block.header())
             }
-            Consensus::PoA(_) => fuel_core_poa::verifier::verify_block_fields(
+            Consensus::PoA(consensus) => {
+                // Headers from the network are checked by the synchronizer, but with
+                // several authorities the importer also rejects blocks signed out of
+                // turn, e.g. produced by an authority that didn't follow the chain.
+                let consensus_config = &self.config.chain_config.consensus;
+                if let ConsensusConfig::PoARoundRobin { .. } = consensus_config {
+                    ensure!(
+                        fuel_core_poa::verifier::verify_consensus(
+                            consensus_config,
+                            block.header(),
+                            consensus,
+                        ),
+                        "The block at height {} is not signed by the scheduled authority",
+                        **block.header().height()
+                    );
+                }
+                fuel_core_poa::verifier::verify_block_fields(
+                    &self.config.poa,
+                    &self.database,
+                    block,
+                )
+            }
+            Consensus::PoAThreshold(_) => fuel_core_poa::verifier::verify_block_fields(
                 &self.config.poa,
                 &self.database,
                 block,
@@ -90,6 +113,13 @@ where
                 header,
                 consensus,
             ),
+            Consensus::PoAThreshold(consensus) => {
+                fuel_core_poa::verifier::verify_threshold_consensus(
+                    &self.config.chain_config.consensus,
+                    header,
+                    consensus,
+                )
+            }
         }
     }
 
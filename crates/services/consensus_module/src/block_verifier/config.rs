@@ -43,10 +43,8 @@ impl Config {
         relayer: RelayerVerifierConfig,
     ) -> Self {
         Self {
+            poa: PoAVerifierConfig::new(&chain_config, enabled_manual_blocks),
             chain_config,
-            poa: PoAVerifierConfig {
-                enabled_manual_blocks,
-            },
             relayer,
         }
     }
@@ -4,4 +4,9 @@ pub struct Config {
     pub backtrace: bool,
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
+    /// The number of threads executing partitions of independent transactions of the
+    /// produced block. The block is executed sequentially if it is less than 2. The
+    /// signatures and predicates of validated blocks are verified on the same number
+    /// of threads.
+    pub parallel_threads: usize,
 }
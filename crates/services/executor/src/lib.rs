@@ -2,7 +2,9 @@
 
 mod config;
 
+pub mod partition;
 pub mod refs;
+pub mod signatures;
 
 pub struct BlockExecutor {}
 
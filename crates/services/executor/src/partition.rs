@@ -0,0 +1,239 @@
+//! The dependency analysis of transactions of the block. Transactions that don't touch
+//! the same UTXOs, contracts or messages can't observe changes of each other, so
+//! partitions of such transactions can be executed concurrently.
+
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::message::{
+            MessageCoinPredicate,
+            MessageCoinSigned,
+            MessageDataPredicate,
+            MessageDataSigned,
+        },
+        ConsensusParameters,
+        ContractId,
+        Input,
+        Output,
+        Transaction,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::Nonce,
+};
+use std::collections::HashMap;
+
+/// The state that the transaction reads or writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Resource {
+    Utxo(UtxoId),
+    Contract(ContractId),
+    Message(Nonce),
+}
+
+/// Splits `transactions` into at most `max_partitions` partitions, so transactions of
+/// different partitions don't touch the same resources. Returns indexes of
+/// transactions of every partition in ascending order. The result depends only on the
+/// arguments, so every node partitions the block in the same way.
+pub fn partition(
+    transactions: &[Transaction],
+    params: &ConsensusParameters,
+    max_partitions: usize,
+) -> Vec<Vec<usize>> {
+    let mut sets = DisjointSets::new(transactions.len());
+    let mut owners = HashMap::new();
+    for (index, tx) in transactions.iter().enumerate() {
+        for resource in resources(tx, params) {
+            let owner = *owners.entry(resource).or_insert(index);
+            sets.union(owner, index);
+        }
+    }
+
+    // Independent groups are ordered by their first transaction.
+    let mut groups: Vec<Vec<usize>> = vec![];
+    let mut group_of_root = HashMap::new();
+    for index in 0..transactions.len() {
+        let root = sets.find(index);
+        let group = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        groups[group].push(index);
+    }
+
+    // The largest groups are distributed first to balance the number of transactions
+    // between partitions. The sort is stable, so ties keep the order of groups.
+    groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+    let mut partitions = vec![vec![]; max_partitions.max(1)];
+    for group in groups {
+        let smallest = partitions
+            .iter_mut()
+            .min_by_key(|partition| partition.len())
+            .expect("There is at least one partition");
+        smallest.extend(group);
+    }
+
+    partitions.retain(|partition| !partition.is_empty());
+    for partition in partitions.iter_mut() {
+        partition.sort_unstable();
+    }
+    partitions
+}
+
+/// Returns resources touched by the `tx`: spent and created UTXOs, used and created
+/// contracts, and spent messages.
+fn resources(tx: &Transaction, params: &ConsensusParameters) -> Vec<Resource> {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(script) => (script.inputs(), script.outputs()),
+        Transaction::Create(create) => (create.inputs(), create.outputs()),
+        Transaction::Mint(mint) => (&[][..], mint.outputs()),
+    };
+
+    let mut resources = vec![];
+    for input in inputs {
+        match input {
+            Input::CoinSigned(_) | Input::CoinPredicate(_) => {
+                resources.extend(input.utxo_id().copied().map(Resource::Utxo));
+            }
+            Input::Contract(contract) => {
+                resources.push(Resource::Contract(contract.contract_id));
+            }
+            Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+            | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                resources.push(Resource::Message(*nonce));
+            }
+        }
+    }
+
+    let tx_id = tx.id(params);
+    for (index, output) in outputs.iter().enumerate() {
+        if let Output::ContractCreated { contract_id, .. } = output {
+            resources.push(Resource::Contract(*contract_id));
+        }
+        let index = u8::try_from(index).expect("The number of outputs fits into `u8`");
+        resources.push(Resource::Utxo(UtxoId::new(tx_id, index)));
+    }
+    resources
+}
+
+/// The disjoint-set forest of transactions.
+struct DisjointSets {
+    parents: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let a = self.find(a);
+        let b = self.find(b);
+        // The smaller index becomes the root, so the result doesn't depend on the order
+        // of unions.
+        if a < b {
+            self.parents[b] = a;
+        } else {
+            self.parents[a] = b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::Bytes32;
+
+    fn script(inputs: Vec<Input>, outputs: Vec<Output>) -> Transaction {
+        Transaction::script(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            vec![],
+            vec![],
+            inputs,
+            outputs,
+            vec![],
+        )
+        .into()
+    }
+
+    fn coin(utxo_id: UtxoId) -> Input {
+        Input::coin_signed(
+            utxo_id,
+            Default::default(),
+            10,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    fn contract(contract_id: ContractId) -> Input {
+        Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )
+    }
+
+    fn utxo(byte: u8) -> UtxoId {
+        UtxoId::new(Bytes32::from([byte; 32]), 0)
+    }
+
+    #[test]
+    fn independent_transactions_are_spread_between_partitions() {
+        let params = ConsensusParameters::DEFAULT;
+        let txs: Vec<_> = (1..=4)
+            .map(|i| script(vec![coin(utxo(i))], vec![]))
+            .collect();
+
+        assert_eq!(partition(&txs, &params, 2), vec![vec![0, 2], vec![1, 3]]);
+        assert_eq!(partition(&txs, &params, 1), vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn transactions_of_the_same_contract_share_the_partition() {
+        let params = ConsensusParameters::DEFAULT;
+        let contract_id = ContractId::from([1; 32]);
+        let txs = vec![
+            script(vec![coin(utxo(1)), contract(contract_id)], vec![]),
+            script(vec![coin(utxo(2))], vec![]),
+            script(vec![coin(utxo(3)), contract(contract_id)], vec![]),
+        ];
+
+        assert_eq!(partition(&txs, &params, 4), vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn child_shares_the_partition_with_its_parent() {
+        let params = ConsensusParameters::DEFAULT;
+        let parent = script(
+            vec![coin(utxo(1))],
+            vec![Output::coin(Default::default(), 10, Default::default())],
+        );
+        let child = script(vec![coin(UtxoId::new(parent.id(&params), 0))], vec![]);
+        let other = script(vec![coin(utxo(2))], vec![]);
+        let txs = vec![parent, other, child];
+
+        assert_eq!(partition(&txs, &params, 4), vec![vec![0, 2], vec![1]]);
+    }
+}
@@ -0,0 +1,137 @@
+//! The batched verification of signatures of transactions of the validated block. The
+//! recovery of signatures is the most expensive part of the validation, so signatures
+//! of all transactions of the block are recovered at once on several threads. The batch
+//! only tells whether all signatures are valid, so the caller verifies transactions one
+//! by one to find the invalid one if it isn't.
+
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Witnesses,
+        },
+        input::{
+            coin::CoinSigned,
+            message::{
+                MessageCoinSigned,
+                MessageDataSigned,
+            },
+        },
+        Address,
+        ConsensusParameters,
+        Input,
+        Transaction,
+        UniqueIdentifier,
+        Witness,
+    },
+};
+use std::collections::BTreeMap;
+
+/// The witness of the transaction that should be the signature of its id by owners of
+/// all inputs referring to it.
+struct SignedWitness<'a> {
+    message: Message,
+    witness: Option<&'a Witness>,
+    owners: Vec<Address>,
+}
+
+/// Returns whether signatures of all signed inputs of `transactions` are valid, using up
+/// to `threads` threads. Each witness is recovered once, even if several inputs of the
+/// transaction refer to it.
+pub fn verify_batch(
+    transactions: &[Transaction],
+    params: &ConsensusParameters,
+    threads: usize,
+) -> bool {
+    let witnesses: Vec<_> = transactions
+        .iter()
+        .flat_map(|tx| signed_witnesses(tx, params))
+        .collect();
+    let threads = threads.max(1);
+    if threads == 1 || witnesses.len() < 2 {
+        return witnesses.iter().all(verify)
+    }
+
+    let chunk_size = (witnesses.len() + threads - 1) / threads;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = witnesses
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().all(verify)))
+            .collect();
+        handles.into_iter().fold(true, |valid, handle| {
+            let chunk_valid = handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            valid && chunk_valid
+        })
+    })
+}
+
+/// Returns witnesses of the `tx` referred by signed inputs, along with their owners.
+fn signed_witnesses<'a>(
+    tx: &'a Transaction,
+    params: &ConsensusParameters,
+) -> Vec<SignedWitness<'a>> {
+    let (inputs, witnesses) = match tx {
+        Transaction::Script(script) => (script.inputs(), script.witnesses()),
+        Transaction::Create(create) => (create.inputs(), create.witnesses()),
+        Transaction::Mint(_) => return vec![],
+    };
+
+    let mut owners: BTreeMap<u8, Vec<Address>> = BTreeMap::new();
+    for input in inputs {
+        let (owner, witness_index) = match input {
+            Input::CoinSigned(CoinSigned {
+                owner,
+                witness_index,
+                ..
+            }) => (owner, witness_index),
+            Input::MessageCoinSigned(MessageCoinSigned {
+                recipient,
+                witness_index,
+                ..
+            })
+            | Input::MessageDataSigned(MessageDataSigned {
+                recipient,
+                witness_index,
+                ..
+            }) => (recipient, witness_index),
+            _ => continue,
+        };
+        owners.entry(*witness_index).or_default().push(*owner);
+    }
+    if owners.is_empty() {
+        return vec![]
+    }
+
+    let message = Message::from_bytes(*tx.id(params));
+    owners
+        .into_iter()
+        .map(|(witness_index, owners)| SignedWitness {
+            message,
+            witness: witnesses.get(witness_index as usize),
+            owners,
+        })
+        .collect()
+}
+
+fn verify(witness: &SignedWitness) -> bool {
+    let bytes = match witness.witness {
+        Some(bytes) => bytes.as_vec().as_slice(),
+        None => return false,
+    };
+    let signature = match <[u8; Signature::LEN]>::try_from(bytes) {
+        Ok(bytes) => Signature::from_bytes(bytes),
+        Err(_) => return false,
+    };
+    let public_key = match signature.recover(&witness.message) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signer = Input::owner(&public_key);
+    witness.owners.iter().all(|owner| *owner == signer)
+}
@@ -79,6 +79,35 @@ pub enum Error {
     StorageError(#[from] StorageError),
 }
 
+impl Error {
+    /// Returns whether the block itself is invalid. Other errors are caused by the state
+    /// of the node, e.g. by the failure of the database, and the same block may be
+    /// imported later.
+    pub fn is_invalid_block(&self) -> bool {
+        match self {
+            Error::Overflow
+            | Error::ZeroNonGenericHeight
+            | Error::BlockIdMismatch(_, _)
+            | Error::FailedVerification(_)
+            | Error::SkippedTransactionsNotEmpty
+            | Error::ExecuteGenesis => true,
+            Error::FailedExecution(error) => !matches!(
+                error,
+                executor::Error::StorageError(_)
+                    | executor::Error::RelayerError(_)
+                    | executor::Error::Backtrace(_)
+                    | executor::Error::Cancelled
+            ),
+            Error::SemaphoreError(_)
+            | Error::InvalidUnderlyingDatabaseGenesisState
+            | Error::InvalidDatabaseStateAfterExecution(_, _)
+            | Error::IncorrectBlockHeight(_, _)
+            | Error::NotUnique(_)
+            | Error::StorageError(_) => false,
+        }
+    }
+}
+
 #[cfg(test)]
 impl PartialEq for Error {
     fn eq(&self, other: &Self) -> bool {
@@ -190,7 +219,7 @@ where
                 }
                 actual_next_height
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::PoAThreshold(_) => {
                 if actual_next_height == BlockHeight::from(0u32) {
                     return Err(Error::ZeroNonGenericHeight)
                 }
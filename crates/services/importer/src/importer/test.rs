@@ -537,3 +537,15 @@ fn verify_and_execute_allowed_when_locked() {
     let _guard = importer.lock();
     assert!(importer.verify_and_execute_block(poa_block(13)).is_ok());
 }
+
+#[test_case(Error::FailedVerification(anyhow!("Wrong time")) => true)]
+#[test_case(Error::FailedExecution(ExecutorError::InvalidBlockId) => true)]
+#[test_case(Error::FailedExecution(ExecutorError::StorageError(Box::new(
+    StorageError::NotFound("", "")
+))) => false)]
+#[test_case(Error::FailedExecution(ExecutorError::Cancelled) => false)]
+#[test_case(Error::StorageError(StorageError::NotFound("", "")) => false)]
+#[test_case(Error::NotUnique(13u32.into()) => false)]
+fn only_errors_of_block_make_it_invalid(error: Error) -> bool {
+    error.is_invalid_block()
+}
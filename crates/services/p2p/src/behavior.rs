@@ -10,6 +10,7 @@ use crate::{
         config::build_gossipsub_behaviour,
         topics::GossipTopic,
     },
+    node_record::NodeRecord,
     peer_report::{
         PeerReportBehaviour,
         PeerReportEvent,
@@ -131,6 +132,14 @@ impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
         }
     }
 
+    pub fn add_node_record(&mut self, record: NodeRecord) -> bool {
+        self.discovery.add_node_record(record)
+    }
+
+    pub fn has_node_record(&mut self, peer_id: &PeerId) -> bool {
+        self.discovery.has_node_record(peer_id)
+    }
+
     pub fn publish_message(
         &mut self,
         topic: GossipTopic,
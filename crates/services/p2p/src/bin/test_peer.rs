@@ -0,0 +1,87 @@
+//! Runs the p2p test peer with the scripted behavior, to check running nodes against
+//! misbehaving peers.
+
+use clap::Parser;
+use fuel_core_p2p::{
+    config::Config,
+    test_peer::{
+        Behavior,
+        TestPeer,
+    },
+    Multiaddr,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        header::PartialBlockHeader,
+        SealedBlock,
+    },
+    fuel_tx::Transaction,
+};
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser, Debug)]
+#[clap(
+    name = "fuel-core-p2p-test-peer",
+    about = "The p2p peer with scripted misbehavior",
+    version
+)]
+struct Args {
+    /// The name of the network to join.
+    #[clap(long = "network", default_value = "test_network")]
+    network_name: String,
+
+    /// The TCP port to listen on.
+    #[clap(long = "peering-port", default_value = "30333")]
+    peering_port: u16,
+
+    /// The addresses of nodes to connect to.
+    #[clap(long = "bootstrap-nodes", value_delimiter = ',')]
+    bootstrap_nodes: Vec<Multiaddr>,
+
+    /// The behavior of the peer: `honest`, `slowloris:<delay in millis>`, `malformed`
+    /// or `stale:<height>`.
+    #[clap(long = "behavior", default_value = "honest")]
+    behavior: Behavior,
+
+    /// Serves empty blocks from the height 1 up to the `height`.
+    #[clap(long = "height", default_value = "0")]
+    height: u32,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+    let args = Args::parse();
+
+    let mut config = Config::default_initialized(&args.network_name);
+    config.tcp_port = args.peering_port;
+    config.bootstrap_nodes = args.bootstrap_nodes;
+
+    let mut peer = TestPeer::new(config, args.behavior)?;
+    for height in 1..=args.height {
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+        peer.add_block(SealedBlock {
+            entity: Block::new(header, Vec::<Transaction>::new(), &[]),
+            consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+        });
+    }
+    tracing::info!(
+        "Test peer {} started with the behavior {:?}",
+        peer.local_peer_id(),
+        peer.behavior()
+    );
+
+    loop {
+        if let Some(event) = peer.next_event().await {
+            tracing::info!("Event: {:?}", event);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Capabilities of the node advertised to peers in the agent version of the identify
+//! protocol, e.g. `fuel-core/0.18.1 (pruned=1000)`. Nodes of older versions don't
+//! advertise capabilities and don't prune blocks, so they are handled as archive nodes.
+
+use fuel_core_types::fuel_types::BlockHeight;
+use std::fmt;
+
+/// Which blocks the node keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockRetention {
+    /// The node keeps all blocks.
+    Archive,
+    /// The node keeps bodies of the `blocks` latest blocks and headers of all blocks.
+    Pruned { blocks: u32 },
+}
+
+/// The data that the node serves to peers. It follows the storage of the node, so it
+/// isn't configured separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NodeCapabilities {
+    pub retention: BlockRetention,
+}
+
+impl Default for NodeCapabilities {
+    fn default() -> Self {
+        Self {
+            retention: BlockRetention::Archive,
+        }
+    }
+}
+
+impl NodeCapabilities {
+    /// Returns whether the node at the `latest` height serves the header and the
+    /// transactions of the block at the `height`.
+    pub fn serves_block(&self, height: BlockHeight, latest: BlockHeight) -> bool {
+        if height > latest {
+            return false
+        }
+        match self.retention {
+            BlockRetention::Archive => true,
+            BlockRetention::Pruned { blocks } => *latest - *height < blocks,
+        }
+    }
+
+    /// Returns the agent version of the node with these capabilities.
+    pub fn agent_version(&self) -> String {
+        format!("fuel-core/{} ({})", env!("CARGO_PKG_VERSION"), self)
+    }
+
+    /// Returns capabilities advertised in the `agent_version` of the peer. Unknown
+    /// capabilities of other versions are ignored.
+    pub fn from_agent_version(agent_version: &str) -> Self {
+        let mut capabilities = Self::default();
+        let advertised = agent_version
+            .rsplit_once('(')
+            .and_then(|(_, advertised)| advertised.strip_suffix(')'));
+        for capability in advertised.into_iter().flat_map(|s| s.split(',')) {
+            let _ = capabilities.apply(capability);
+        }
+        capabilities
+    }
+
+    fn apply(&mut self, capability: &str) -> anyhow::Result<()> {
+        match capability.trim() {
+            "archive" => self.retention = BlockRetention::Archive,
+            capability => {
+                let blocks = capability
+                    .strip_prefix("pruned=")
+                    .ok_or_else(|| anyhow::anyhow!("Unknown capability `{capability}`"))?
+                    .parse()?;
+                self.retention = BlockRetention::Pruned { blocks };
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NodeCapabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.retention {
+            BlockRetention::Archive => write!(f, "archive"),
+            BlockRetention::Pruned { blocks } => write!(f, "pruned={blocks}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capabilities_are_advertised_in_agent_version() {
+        let capabilities = NodeCapabilities {
+            retention: BlockRetention::Pruned { blocks: 1000 },
+        };
+        let agent_version = capabilities.agent_version();
+
+        assert!(agent_version.ends_with("(pruned=1000)"));
+        assert_eq!(
+            NodeCapabilities::from_agent_version(&agent_version),
+            capabilities
+        );
+    }
+
+    #[test]
+    fn peers_without_capabilities_are_archive_nodes() {
+        assert_eq!(
+            NodeCapabilities::from_agent_version("rust-libp2p/0.41.0"),
+            NodeCapabilities::default()
+        );
+        assert_eq!(
+            NodeCapabilities::from_agent_version("fuel-core/1.0.0 (pruned=,unknown)"),
+            NodeCapabilities::default()
+        );
+    }
+
+    #[test]
+    fn pruned_node_serves_only_latest_blocks() {
+        let pruned = NodeCapabilities {
+            retention: BlockRetention::Pruned { blocks: 10 },
+        };
+        let latest = BlockHeight::from(100);
+
+        assert!(pruned.serves_block(91.into(), latest));
+        assert!(!pruned.serves_block(90.into(), latest));
+        assert!(!pruned.serves_block(101.into(), latest));
+        assert!(NodeCapabilities::default().serves_block(0.into(), latest));
+    }
+}
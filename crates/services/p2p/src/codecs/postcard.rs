@@ -222,6 +222,24 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(ResponseMessage::Transactions(response))
             }
+            NetworkResponse::PoolSketch(sketch_bytes) => {
+                let response = if let Some(sketch_bytes) = sketch_bytes {
+                    Some(self.deserialize(sketch_bytes)?)
+                } else {
+                    None
+                };
+
+                Ok(ResponseMessage::PoolSketch(response))
+            }
+            NetworkResponse::NodeRecord(record_bytes) => {
+                let response = if let Some(record_bytes) = record_bytes {
+                    Some(self.deserialize(record_bytes)?)
+                } else {
+                    None
+                };
+
+                Ok(ResponseMessage::NodeRecord(response))
+            }
         }
     }
 
@@ -257,6 +275,24 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(NetworkResponse::Transactions(response))
             }
+            OutboundResponse::PoolSketch(sketch) => {
+                let response = if let Some(sketch) = sketch {
+                    Some(self.serialize(sketch.as_ref())?)
+                } else {
+                    None
+                };
+
+                Ok(NetworkResponse::PoolSketch(response))
+            }
+            OutboundResponse::NodeRecord(record) => {
+                let response = if let Some(record) = record {
+                    Some(self.serialize(record.as_ref())?)
+                } else {
+                    None
+                };
+
+                Ok(NetworkResponse::NodeRecord(response))
+            }
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    capabilities::NodeCapabilities,
     gossipsub::{
         config::default_gossipsub_config,
         topics::{
@@ -15,7 +16,11 @@ use fuel_core_types::blockchain::consensus::Genesis;
 use libp2p::{
     core::{
         muxing::StreamMuxerBox,
-        transport::Boxed,
+        transport::{
+            Boxed,
+            MemoryTransport,
+            OptionalTransport,
+        },
     },
     gossipsub::GossipsubConfig,
     identity::{
@@ -23,7 +28,9 @@ use libp2p::{
         Keypair,
     },
     mplex,
-    noise::{self,},
+    noise::{
+        self,
+    },
     tcp::{
         tokio::Transport as TokioTcpTransport,
         Config as TcpConfig,
@@ -86,12 +93,21 @@ pub struct Config<State = Initialized> {
     /// IP address for Swarm to listen on
     pub address: IpAddr,
 
-    /// Optional address of your local node made reachable for other nodes in the network.
-    pub public_address: Option<Multiaddr>,
+    /// Additional IP addresses for Swarm to listen on with the same `tcp_port`, e.g. the
+    /// IPv6 address of the dual-stack node.
+    pub additional_addresses: Vec<IpAddr>,
+
+    /// Addresses of your local node made reachable for other nodes in the network.
+    pub public_addresses: Vec<Multiaddr>,
 
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// Use the in-process memory transport instead of TCP, the `tcp_port` is used as the port
+    /// of the memory address. Allows running several nodes in one process without opening
+    /// sockets. Should be used only for tests.
+    pub memory_transport: bool,
+
     /// Max Size of a Block in bytes
     pub max_block_size: usize,
 
@@ -122,6 +138,9 @@ pub struct Config<State = Initialized> {
     /// The duration between the last successful outbound or inbound ping
     /// and the next outbound ping
     pub info_interval: Option<Duration>,
+    /// The data that the node serves to peers, advertised via the identify protocol.
+    /// The node derives it from its block retention on start.
+    pub capabilities: NodeCapabilities,
 
     // `Gossipsub` config and topics
     pub gossipsub_config: GossipsubConfig,
@@ -135,6 +154,10 @@ pub struct Config<State = Initialized> {
     /// Sets the keep-alive timeout of idle connections.
     pub set_connection_keep_alive: Duration,
 
+    /// If set, an iteration of the p2p task that runs longer is cancelled and handled
+    /// as a failure. The task owns the swarm, so it can't be restarted.
+    pub watchdog: Option<Duration>,
+
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
@@ -161,8 +184,10 @@ impl Config<NotInitialized> {
             network_name: self.network_name,
             checksum: genesis.root()?.into(),
             address: self.address,
-            public_address: self.public_address,
+            additional_addresses: self.additional_addresses,
+            public_addresses: self.public_addresses,
             tcp_port: self.tcp_port,
+            memory_transport: self.memory_transport,
             max_block_size: self.max_block_size,
             bootstrap_nodes: self.bootstrap_nodes,
             enable_mdns: self.enable_mdns,
@@ -175,11 +200,13 @@ impl Config<NotInitialized> {
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
+            capabilities: self.capabilities,
             gossipsub_config: self.gossipsub_config,
             topics: self.topics,
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
             set_connection_keep_alive: self.set_connection_keep_alive,
+            watchdog: self.watchdog,
             metrics: self.metrics,
             state: Initialized(()),
         })
@@ -205,8 +232,10 @@ impl Config<NotInitialized> {
             network_name: network_name.into(),
             checksum: Default::default(),
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
-            public_address: None,
+            additional_addresses: vec![],
+            public_addresses: vec![],
             tcp_port: 0,
+            memory_transport: false,
             max_block_size: MAX_RESPONSE_SIZE,
             bootstrap_nodes: vec![],
             enable_mdns: false,
@@ -228,6 +257,8 @@ impl Config<NotInitialized> {
             set_connection_keep_alive: REQ_RES_TIMEOUT,
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            capabilities: NodeCapabilities::default(),
+            watchdog: None,
             metrics: false,
             state: NotInitialized,
         }
@@ -244,7 +275,7 @@ impl Config<Initialized> {
 }
 
 /// Transport for libp2p communication:
-/// TCP/IP, Websocket
+/// TCP/IP, Websocket, or Memory if `memory_transport` is enabled
 /// Noise as encryption layer
 /// mplex or yamux for multiplexing
 pub(crate) fn build_transport(
@@ -262,7 +293,13 @@ pub(crate) fn build_transport(
         let ws_tcp =
             libp2p::websocket::WsConfig::new(generate_tcp_transport()).or_transport(tcp);
 
-        libp2p::dns::TokioDnsConfig::system(ws_tcp).unwrap()
+        let memory = if p2p_config.memory_transport {
+            OptionalTransport::some(MemoryTransport::new())
+        } else {
+            OptionalTransport::none()
+        };
+
+        memory.or_transport(libp2p::dns::TokioDnsConfig::system(ws_tcp).unwrap())
     }
     .upgrade(libp2p::core::upgrade::Version::V1);
 
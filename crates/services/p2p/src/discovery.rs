@@ -1,4 +1,9 @@
 use self::mdns::MdnsWrapper;
+use crate::node_record::{
+    unix_now,
+    NodeRecord,
+    NodeRecords,
+};
 use futures::FutureExt;
 use ip_network::IpNetwork;
 use libp2p::{
@@ -92,6 +97,10 @@ pub struct DiscoveryBehaviour {
     /// If false, `addresses_of_peer` won't return any private IPv4/IPv6 address,
     /// except for the ones stored in `bootstrap_nodes` and `reserved_peers`.
     allow_private_addresses: bool,
+
+    /// Addresses of peers verified with their signatures.
+    /// They are dialed before the addresses learned from other sources.
+    node_records: NodeRecords,
 }
 
 impl DiscoveryBehaviour {
@@ -99,6 +108,25 @@ impl DiscoveryBehaviour {
     pub fn add_address(&mut self, peer_id: &PeerId, address: Multiaddr) {
         self.kademlia.add_address(peer_id, address);
     }
+
+    /// Adds the verified record of the peer. Returns `false` if the record is older
+    /// than the known one.
+    pub fn add_node_record(&mut self, record: NodeRecord) -> bool {
+        let peer_id = record.peer_id;
+        let addresses = record.addresses.clone();
+        if !self.node_records.insert(record) {
+            return false
+        }
+        for address in addresses {
+            self.kademlia.add_address(&peer_id, address);
+        }
+        true
+    }
+
+    /// Returns `true` if the peer has a valid signed record.
+    pub fn has_node_record(&mut self, peer_id: &PeerId) -> bool {
+        !self.node_records.addresses(peer_id, unix_now()).is_empty()
+    }
 }
 
 impl NetworkBehaviour for DiscoveryBehaviour {
@@ -279,8 +307,20 @@ impl NetworkBehaviour for DiscoveryBehaviour {
         {
             let mut list_to_filter = Vec::new();
 
-            list_to_filter.extend(self.kademlia.addresses_of_peer(peer_id));
-            list_to_filter.extend(self.mdns.addresses_of_peer(peer_id));
+            // signed addresses go first, so they are dialed first
+            list_to_filter.extend_from_slice(
+                self.node_records.addresses(peer_id, unix_now()),
+            );
+            for address in self
+                .kademlia
+                .addresses_of_peer(peer_id)
+                .into_iter()
+                .chain(self.mdns.addresses_of_peer(peer_id))
+            {
+                if !list_to_filter.contains(&address) {
+                    list_to_filter.push(address);
+                }
+            }
 
             // filter private addresses
             // nodes could potentially report addresses in the private network
@@ -190,6 +190,7 @@ impl DiscoveryConfig {
             max_peers_connected,
             mdns,
             allow_private_addresses,
+            node_records: Default::default(),
         }
     }
 }
@@ -1,9 +1,11 @@
 mod behavior;
+pub mod capabilities;
 pub mod codecs;
 pub mod config;
 mod discovery;
 mod gossipsub;
 mod heartbeat;
+mod node_record;
 mod p2p_service;
 mod peer_manager;
 mod peer_report;
@@ -25,5 +27,18 @@ pub mod network_service {
     pub use crate::p2p_service::*;
 }
 
+#[cfg(any(feature = "test-helpers", test))]
+pub mod test_peer;
+
+/// Messages decoded from the untrusted network input, used by the fuzz targets.
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing {
+    pub use crate::{
+        gossipsub::messages::*,
+        node_record::SignedNodeRecord,
+        request_response::messages::*,
+    };
+}
+
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
@@ -0,0 +1,266 @@
+use libp2p::{
+    identity::{
+        Keypair,
+        PublicKey,
+    },
+    Multiaddr,
+    PeerId,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+/// For how long the signed record of the local node is valid.
+pub const NODE_RECORD_TTL: Duration = Duration::from_secs(60 * 60);
+/// Maximum amount of addresses that we are ready to accept in the peer's record.
+pub const MAX_NODE_RECORD_ADDRESSES: usize = 10;
+/// Separates signatures of the node records from signatures of other messages
+/// produced with the same key.
+const NODE_RECORD_DOMAIN: &[u8] = b"fuel-node-record";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeRecordError {
+    SigningFailed,
+    InvalidEncoding,
+    InvalidSignature,
+    /// The record is signed by another peer than the one that sent it.
+    PeerMismatch,
+    Expired,
+    TooManyAddresses,
+}
+
+/// The addresses of the peer verified with its signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeRecord {
+    pub peer_id: PeerId,
+    /// Increases with every new record of the peer, so older records can't be replayed.
+    pub seq: u64,
+    /// The UNIX timestamp in seconds after which the record is not valid.
+    pub expires_at: u64,
+    pub addresses: Vec<Multiaddr>,
+}
+
+/// The node record in the form that is sent over the wire.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignedNodeRecord {
+    /// Protobuf encoding of the public key of the peer.
+    public_key: Vec<u8>,
+    /// Postcard encoding of the `Payload`.
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Payload {
+    seq: u64,
+    expires_at: u64,
+    addresses: Vec<Vec<u8>>,
+}
+
+impl SignedNodeRecord {
+    pub fn new(
+        keypair: &Keypair,
+        seq: u64,
+        expires_at: u64,
+        addresses: &[Multiaddr],
+    ) -> Result<Self, NodeRecordError> {
+        let payload = Payload {
+            seq,
+            expires_at,
+            addresses: addresses.iter().map(|address| address.to_vec()).collect(),
+        };
+        let payload = postcard::to_stdvec(&payload)
+            .map_err(|_| NodeRecordError::InvalidEncoding)?;
+        let signature = keypair
+            .sign(&signing_message(&payload))
+            .map_err(|_| NodeRecordError::SigningFailed)?;
+
+        Ok(Self {
+            public_key: keypair.public().to_protobuf_encoding(),
+            payload,
+            signature,
+        })
+    }
+
+    /// Verifies that the record is signed by the `peer_id` and isn't expired at `now`.
+    pub fn verify(
+        &self,
+        peer_id: &PeerId,
+        now: u64,
+    ) -> Result<NodeRecord, NodeRecordError> {
+        let public_key = PublicKey::from_protobuf_encoding(&self.public_key)
+            .map_err(|_| NodeRecordError::InvalidEncoding)?;
+        if PeerId::from_public_key(&public_key) != *peer_id {
+            return Err(NodeRecordError::PeerMismatch)
+        }
+        if !public_key.verify(&signing_message(&self.payload), &self.signature) {
+            return Err(NodeRecordError::InvalidSignature)
+        }
+
+        let payload: Payload = postcard::from_bytes(&self.payload)
+            .map_err(|_| NodeRecordError::InvalidEncoding)?;
+        if payload.expires_at <= now {
+            return Err(NodeRecordError::Expired)
+        }
+        if payload.addresses.len() > MAX_NODE_RECORD_ADDRESSES {
+            return Err(NodeRecordError::TooManyAddresses)
+        }
+        let addresses = payload
+            .addresses
+            .into_iter()
+            .map(Multiaddr::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|_| NodeRecordError::InvalidEncoding)?;
+
+        Ok(NodeRecord {
+            peer_id: *peer_id,
+            seq: payload.seq,
+            expires_at: payload.expires_at,
+            addresses,
+        })
+    }
+}
+
+fn signing_message(payload: &[u8]) -> Vec<u8> {
+    [NODE_RECORD_DOMAIN, payload].concat()
+}
+
+/// Returns the current UNIX timestamp in seconds.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("The system time is after the UNIX epoch")
+        .as_secs()
+}
+
+/// Keeps the latest verified record of every peer.
+#[derive(Debug, Default)]
+pub struct NodeRecords {
+    records: HashMap<PeerId, NodeRecord>,
+}
+
+impl NodeRecords {
+    /// Stores the record if it is newer than the known record of the peer.
+    /// Returns `false` if the record is stale.
+    pub fn insert(&mut self, record: NodeRecord) -> bool {
+        match self.records.get(&record.peer_id) {
+            Some(known) if known.seq >= record.seq => false,
+            _ => {
+                self.records.insert(record.peer_id, record);
+                true
+            }
+        }
+    }
+
+    /// Returns the signed addresses of the peer if its record isn't expired at `now`.
+    pub fn addresses(&mut self, peer_id: &PeerId, now: u64) -> &[Multiaddr] {
+        if self
+            .records
+            .get(peer_id)
+            .map_or(false, |record| record.expires_at <= now)
+        {
+            self.records.remove(peer_id);
+        }
+        self.records
+            .get(peer_id)
+            .map_or(&[], |record| record.addresses.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addresses() -> Vec<Multiaddr> {
+        vec!["/ip4/1.2.3.4/tcp/30333".parse().unwrap()]
+    }
+
+    #[test]
+    fn verifies_signed_record() {
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        let signed = SignedNodeRecord::new(&keypair, 1, 100, &addresses()).unwrap();
+
+        let record = signed.verify(&peer_id, 10).unwrap();
+
+        assert_eq!(
+            record,
+            NodeRecord {
+                peer_id,
+                seq: 1,
+                expires_at: 100,
+                addresses: addresses(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_addresses() {
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        let mut signed = SignedNodeRecord::new(&keypair, 1, 100, &addresses()).unwrap();
+        let spoofed: Multiaddr = "/ip4/6.6.6.6/tcp/30333".parse().unwrap();
+        signed.payload = postcard::to_stdvec(&Payload {
+            seq: 1,
+            expires_at: 100,
+            addresses: vec![spoofed.to_vec()],
+        })
+        .unwrap();
+
+        assert_eq!(
+            signed.verify(&peer_id, 10),
+            Err(NodeRecordError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn rejects_record_of_another_peer() {
+        let keypair = Keypair::generate_secp256k1();
+        let other = Keypair::generate_secp256k1().public().to_peer_id();
+        let signed = SignedNodeRecord::new(&keypair, 1, 100, &addresses()).unwrap();
+
+        assert_eq!(signed.verify(&other, 10), Err(NodeRecordError::PeerMismatch));
+    }
+
+    #[test]
+    fn rejects_expired_record() {
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        let signed = SignedNodeRecord::new(&keypair, 1, 100, &addresses()).unwrap();
+
+        assert_eq!(signed.verify(&peer_id, 100), Err(NodeRecordError::Expired));
+    }
+
+    #[test]
+    fn keeps_latest_record() {
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        let newer: Multiaddr = "/ip4/4.3.2.1/tcp/30333".parse().unwrap();
+        let mut records = NodeRecords::default();
+
+        assert!(records.insert(NodeRecord {
+            peer_id,
+            seq: 2,
+            expires_at: 100,
+            addresses: vec![newer.clone()],
+        }));
+        assert!(!records.insert(NodeRecord {
+            peer_id,
+            seq: 1,
+            expires_at: 100,
+            addresses: addresses(),
+        }));
+
+        assert_eq!(records.addresses(&peer_id, 10), &[newer]);
+        assert!(records.addresses(&peer_id, 100).is_empty());
+    }
+}
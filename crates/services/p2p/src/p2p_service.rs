@@ -15,6 +15,12 @@ use crate::{
         },
         topics::GossipsubTopics,
     },
+    node_record::{
+        unix_now,
+        SignedNodeRecord,
+        MAX_NODE_RECORD_ADDRESSES,
+        NODE_RECORD_TTL,
+    },
     peer_manager::{
         PeerManager,
         Punisher,
@@ -44,6 +50,7 @@ use libp2p::{
         MessageId,
         TopicHash,
     },
+    identity::Keypair,
     multiaddr::Protocol,
     request_response::{
         RequestId,
@@ -62,7 +69,13 @@ use libp2p::{
     Swarm,
 };
 use rand::seq::IteratorRandom;
-use std::collections::HashMap;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
+};
 use tracing::{
     debug,
     warn,
@@ -83,9 +96,15 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
     /// IP address for Swarm to listen on
     local_address: std::net::IpAddr,
 
+    /// Additional IP addresses for Swarm to listen on
+    additional_addresses: Vec<std::net::IpAddr>,
+
     /// The TCP port that Swarm listens on
     tcp_port: u16,
 
+    /// Listen on the memory address instead of the TCP
+    memory_transport: bool,
+
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour<Codec>>,
 
@@ -110,6 +129,22 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
 
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
+
+    /// Signs the record with the addresses of the local node
+    keypair: Keypair,
+
+    /// The latest signed record of the local node
+    local_node_record: Option<LocalNodeRecord>,
+
+    /// Requests for the signed records of the peers sent by the service itself
+    node_record_requests: HashSet<RequestId>,
+}
+
+struct LocalNodeRecord {
+    addresses: Vec<Multiaddr>,
+    seq: u64,
+    expires_at: u64,
+    signed: Arc<SignedNodeRecord>,
 }
 
 #[derive(Debug)]
@@ -148,6 +183,8 @@ pub enum FuelP2PEvent {
         peer_id: PeerId,
         block_height: BlockHeight,
     },
+    /// The node started listening for P2P traffic on the address.
+    ListenerBound(Multiaddr),
 }
 
 impl<Codec: NetworkCodec> FuelP2PService<Codec> {
@@ -197,7 +234,7 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
 
         let metrics = config.metrics;
 
-        if let Some(public_address) = config.public_address {
+        for public_address in config.public_addresses {
             let _ = swarm.add_external_address(public_address, AddressScore::Infinite);
         }
 
@@ -210,7 +247,9 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         Self {
             local_peer_id,
             local_address: config.address,
+            additional_addresses: config.additional_addresses,
             tcp_port: config.tcp_port,
+            memory_transport: config.memory_transport,
             swarm,
             network_codec: codec,
             outbound_requests_table: HashMap::default(),
@@ -222,28 +261,40 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                 connection_state,
                 config.max_peers_connected as usize,
             ),
+            keypair: config.keypair,
+            local_node_record: None,
+            node_record_requests: HashSet::default(),
         }
     }
 
     pub fn start(&mut self) -> anyhow::Result<()> {
-        // set up node's address to listen on
-        let listen_multiaddr = {
-            let mut m = Multiaddr::from(self.local_address);
-            m.push(Protocol::Tcp(self.tcp_port));
-            m
+        // set up node's addresses to listen on
+        let listen_multiaddrs: Vec<_> = if self.memory_transport {
+            vec![Multiaddr::from(Protocol::Memory(self.tcp_port as u64))]
+        } else {
+            std::iter::once(self.local_address)
+                .chain(self.additional_addresses.iter().copied())
+                .map(|address| {
+                    let mut m = Multiaddr::from(address);
+                    m.push(Protocol::Tcp(self.tcp_port));
+                    m
+                })
+                .collect()
         };
         let peer_id = self.local_peer_id;
 
-        tracing::info!(
-            "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
-        );
+        for listen_multiaddr in listen_multiaddrs {
+            tracing::info!(
+                "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
+            );
 
-        // start listening at the given address
-        self.swarm.listen_on(listen_multiaddr)?;
+            // start listening at the given address
+            self.swarm.listen_on(listen_multiaddr)?;
+        }
         Ok(())
     }
 
-    #[cfg(feature = "test-helpers")]
+    #[cfg(any(feature = "test-helpers", test))]
     pub fn listeners(&self) -> impl Iterator<Item = &Multiaddr> {
         self.swarm.listeners()
     }
@@ -339,6 +390,42 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         Ok(())
     }
 
+    /// Publishes the `data` to the topic of the `message` as is, bypassing the codec.
+    /// Used to check how peers handle gossip that doesn't decode.
+    #[cfg(any(feature = "test-helpers", test))]
+    pub fn publish_raw_message(
+        &mut self,
+        message: &GossipsubBroadcastRequest,
+        data: Vec<u8>,
+    ) -> Result<MessageId, PublishError> {
+        let topic = self
+            .network_metadata
+            .gossipsub_data
+            .topics
+            .get_gossipsub_topic(message);
+
+        self.swarm.behaviour_mut().publish_message(topic, data)
+    }
+
+    /// Sends the `message` to the peer that requested the data as is, bypassing the
+    /// conversion of the codec. Used to check how peers handle malformed responses.
+    #[cfg(any(feature = "test-helpers", test))]
+    pub fn send_raw_response_msg(
+        &mut self,
+        request_id: RequestId,
+        message: NetworkResponse,
+    ) -> Result<(), ResponseError> {
+        let channel = self
+            .inbound_requests_table
+            .remove(&request_id)
+            .ok_or(ResponseError::ResponseChannelDoesNotExist)?;
+
+        self.swarm
+            .behaviour_mut()
+            .send_response_msg(channel, message)
+            .map_err(|_| ResponseError::SendingResponseFailed)
+    }
+
     pub fn update_block_height(&mut self, block_height: BlockHeight) {
         self.swarm.behaviour_mut().update_block_height(block_height)
     }
@@ -382,6 +469,15 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         );
     }
 
+    /// Changes the limit of connected non-reserved peers and disconnects surplus peers.
+    /// The connection limits of the swarm are built from the initial limit, so raising
+    /// the limit above it doesn't allow more connections.
+    pub fn set_max_peers(&mut self, max_peers: u32) {
+        for peer_id in self.peer_manager.set_max_non_reserved_peers(max_peers as usize) {
+            let _ = self.swarm.disconnect_peer_id(peer_id);
+        }
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -403,7 +499,7 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             }
             SwarmEvent::NewListenAddr { address, .. } => {
                 tracing::info!("Listening for p2p traffic on `{address}`");
-                None
+                Some(FuelP2PEvent::ListenerBound(address))
             }
             SwarmEvent::ListenerClosed {
                 addresses, reason, ..
@@ -421,6 +517,83 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         &self.peer_manager
     }
 
+    /// Returns the signed record with the addresses of the local node.
+    /// The record is signed again when the addresses change or half of its TTL passes.
+    fn local_node_record(&mut self) -> Option<Arc<SignedNodeRecord>> {
+        let mut addresses: Vec<Multiaddr> = vec![];
+        for address in self
+            .swarm
+            .external_addresses()
+            .map(|record| &record.addr)
+            .chain(self.swarm.listeners())
+        {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        addresses.truncate(MAX_NODE_RECORD_ADDRESSES);
+
+        let now = unix_now();
+        let ttl = NODE_RECORD_TTL.as_secs();
+        if let Some(local) = &self.local_node_record {
+            if local.addresses == addresses && local.expires_at > now + ttl / 2 {
+                return Some(local.signed.clone())
+            }
+        }
+
+        // The timestamp keeps the sequence number increasing across restarts
+        let seq = self
+            .local_node_record
+            .as_ref()
+            .map_or(now, |local| now.max(local.seq + 1));
+        let expires_at = now + ttl;
+        match SignedNodeRecord::new(&self.keypair, seq, expires_at, &addresses) {
+            Ok(signed) => {
+                let signed = Arc::new(signed);
+                self.local_node_record = Some(LocalNodeRecord {
+                    addresses,
+                    seq,
+                    expires_at,
+                    signed: signed.clone(),
+                });
+                Some(signed)
+            }
+            Err(e) => {
+                warn!(target: "fuel-p2p", "Failed to sign the node record: {:?}", e);
+                None
+            }
+        }
+    }
+
+    fn handle_node_record_response(
+        &mut self,
+        peer_id: PeerId,
+        response: &NetworkResponse,
+    ) {
+        let record = match self.network_codec.convert_to_response(response) {
+            Ok(ResponseMessage::NodeRecord(Some(record))) => record,
+            Ok(_) => {
+                debug!(target: "fuel-p2p", "Peer {:?} did not send its node record", peer_id);
+                return
+            }
+            Err(e) => {
+                debug!(target: "fuel-p2p", "Failed to decode the node record of {:?} with {:?}", peer_id, e);
+                return
+            }
+        };
+
+        match record.verify(&peer_id, unix_now()) {
+            Ok(record) => {
+                if !self.swarm.behaviour_mut().add_node_record(record) {
+                    debug!(target: "fuel-p2p", "Peer {:?} sent a stale node record", peer_id);
+                }
+            }
+            Err(e) => {
+                warn!(target: "fuel-p2p", "Peer {:?} sent an invalid node record: {:?}", peer_id, e);
+            }
+        }
+    }
+
     fn handle_behaviour_event(
         &mut self,
         event: FuelBehaviourEvent,
@@ -478,9 +651,19 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                             agent_version,
                         );
 
-                        self.swarm
+                        // Unsigned addresses are used only until the peer sends
+                        // its signed record
+                        if !self.swarm.behaviour_mut().has_node_record(&peer_id) {
+                            self.swarm
+                                .behaviour_mut()
+                                .add_addresses_to_discovery(&peer_id, addresses);
+                        }
+
+                        let request_id = self
+                            .swarm
                             .behaviour_mut()
-                            .add_addresses_to_discovery(&peer_id, addresses);
+                            .send_request_msg(RequestMessage::NodeRecord, &peer_id);
+                        self.node_record_requests.insert(request_id);
                     }
                     PeerReportEvent::PerformDecay => {
                         self.peer_manager.batch_update_score_with_decay()
@@ -535,6 +718,33 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             }
             FuelBehaviourEvent::RequestResponse(req_res_event) => match req_res_event {
                 RequestResponseEvent::Message { peer, message } => match message {
+                    RequestResponseMessage::Request {
+                        request: RequestMessage::NodeRecord,
+                        channel,
+                        request_id,
+                    } => {
+                        let response =
+                            OutboundResponse::NodeRecord(self.local_node_record());
+                        match self.network_codec.convert_to_network_response(&response)
+                        {
+                            Ok(response) => {
+                                if self
+                                    .swarm
+                                    .behaviour_mut()
+                                    .send_response_msg(channel, response)
+                                    .is_err()
+                                {
+                                    debug!(
+                                        "Failed to send the node record for {:?}",
+                                        request_id
+                                    );
+                                }
+                            }
+                            Err(e) => {
+                                debug!("Failed to encode the node record with {:?}", e);
+                            }
+                        }
+                    }
                     RequestResponseMessage::Request {
                         request,
                         channel,
@@ -547,6 +757,13 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                             request_message: request,
                         })
                     }
+                    RequestResponseMessage::Response {
+                        request_id,
+                        response,
+                    } if self.node_record_requests.contains(&request_id) => {
+                        self.node_record_requests.remove(&request_id);
+                        self.handle_node_record_response(peer, &response);
+                    }
                     RequestResponseMessage::Response {
                         request_id,
                         response,
@@ -588,6 +805,17 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                                     );
                                 }
                             }
+                            (
+                                Some(ResponseChannelItem::PoolSketch(channel)),
+                                Ok(ResponseMessage::PoolSketch(sketch)),
+                            ) => {
+                                if channel.send(sketch).is_err() {
+                                    debug!(
+                                        "Failed to send through the channel for {:?}",
+                                        request_id
+                                    );
+                                }
+                            }
 
                             (Some(_), Err(e)) => {
                                 debug!("Failed to convert IntermediateResponse into a ResponseMessage {:?} with {:?}", response, e);
@@ -614,6 +842,7 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                     debug!("RequestResponse outbound error for peer: {:?} with id: {:?} and error: {:?}", peer, request_id, error);
 
                     let _ = self.outbound_requests_table.remove(&request_id);
+                    self.node_record_requests.remove(&request_id);
                 }
                 _ => {}
             },
@@ -666,7 +895,10 @@ mod tests {
             SealedBlockHeader,
         },
         fuel_tx::Transaction,
-        services::p2p::GossipsubMessageAcceptance,
+        services::p2p::{
+            GossipsubMessageAcceptance,
+            PoolSketch,
+        },
     };
     use futures::StreamExt;
     use libp2p::{
@@ -1204,6 +1436,81 @@ mod tests {
         }
     }
 
+    // After the identification Node B should receive and verify the signed record of Node A
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_exchange_signed_node_records() {
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_exchange_signed_node_records");
+
+        let node_a_data = NodeData::random();
+        let mut node_a = node_a_data.create_service(p2p_config.clone());
+
+        // Node B
+        p2p_config.bootstrap_nodes = vec![node_a_data.multiaddr];
+        let mut node_b = build_service_from_config(p2p_config);
+
+        let node_a_peer_id = node_a.local_peer_id;
+        while !node_b.swarm.behaviour_mut().has_node_record(&node_a_peer_id) {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
+    // Simulates 2 p2p nodes in the same process, Node B is bootstrapped with Node A
+    // via the memory transport without opening any sockets
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_memory_transport() {
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_connected_via_memory_transport");
+        p2p_config.memory_transport = true;
+        let mut node_a = build_service_from_config(p2p_config.clone());
+
+        let node_a_address = loop {
+            if let Some(address) = node_a.listeners().next() {
+                break address.clone()
+            }
+            node_a.next_event().await;
+        };
+        assert!(matches!(
+            node_a_address.iter().next(),
+            Some(Protocol::Memory(_))
+        ));
+
+        // Node B
+        p2p_config.bootstrap_nodes =
+            vec![format!("{node_a_address}/p2p/{}", node_a.local_peer_id)
+                .parse()
+                .unwrap()];
+        let mut node_b = build_service_from_config(p2p_config);
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_b_event {
+                        if peer_id == node_a.local_peer_id {
+                            break
+                        }
+                    }
+
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                }
+            };
+        }
+    }
+
     // Simulates 2 p2p nodes that connect to each other and consequently exchange Peer Info
     // On sucessful connection, node B updates its latest BlockHeight
     // and shares it with Peer A via Heartbeat protocol
@@ -1513,6 +1820,22 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::PoolSketch => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::PoolSketch(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok(Some(sketch)) = response_message {
+                                                let _ = tx_test_end.send(sketch.short_ids == vec![1, 2, 3]).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1548,6 +1871,10 @@ mod tests {
                                 let transactions = vec![Transaction::default(), Transaction::default(), Transaction::default(), Transaction::default(), Transaction::default()];
                                 let _ = node_b.send_response_msg(request_id, OutboundResponse::Transactions(Some(Arc::new(transactions))));
                             }
+                            RequestMessage::PoolSketch => {
+                                let sketch = PoolSketch { short_ids: vec![1, 2, 3] };
+                                let _ = node_b.send_response_msg(request_id, OutboundResponse::PoolSketch(Some(Arc::new(sketch))));
+                            }
                         }
 
                     }
@@ -1577,6 +1904,12 @@ mod tests {
         request_response_works_with(RequestMessage::SealedHeader(0.into())).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_pool_sketch() {
+        request_response_works_with(RequestMessage::PoolSketch).await
+    }
+
     #[tokio::test]
     #[instrument]
     async fn req_res_outbound_timeout_works() {
@@ -1,3 +1,4 @@
+use crate::capabilities::NodeCapabilities;
 use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::peer_reputation::{
@@ -35,15 +36,29 @@ use tracing::{
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
+    /// Capabilities advertised by the peer, the archive node until it is identified.
+    pub capabilities: NodeCapabilities,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
 }
 
+impl PeerInfo {
+    /// Returns whether the peer serves the header and the transactions of the block at
+    /// the `height`.
+    pub fn serves_block(&self, height: &BlockHeight) -> bool {
+        match self.heartbeat_data.block_height {
+            Some(latest) => self.capabilities.serves_block(*height, latest),
+            None => false,
+        }
+    }
+}
+
 impl Default for PeerInfo {
     fn default() -> Self {
         Self {
             score: DEFAULT_APP_SCORE,
             client_version: Default::default(),
+            capabilities: Default::default(),
             heartbeat_data: Default::default(),
             peer_addresses: Default::default(),
         }
@@ -216,7 +231,40 @@ impl PeerManager {
         }
     }
 
-    /// Find a peer that is holding the given block height.
+    /// Changes the limit of connected non-reserved peers. Returns surplus peers with the
+    /// lowest scores that should be disconnected to fit into the new limit.
+    pub fn set_max_non_reserved_peers(
+        &mut self,
+        max_non_reserved_peers: usize,
+    ) -> Vec<PeerId> {
+        self.max_non_reserved_peers = max_non_reserved_peers;
+        if let Ok(mut connection_state) = self.connection_state.write() {
+            if self.non_reserved_connected_peers.len() < max_non_reserved_peers {
+                connection_state.allow_new_peers();
+            } else {
+                connection_state.deny_new_peers();
+            }
+        }
+
+        let surplus = self
+            .non_reserved_connected_peers
+            .len()
+            .saturating_sub(max_non_reserved_peers);
+        let mut peers = self
+            .non_reserved_connected_peers
+            .iter()
+            .map(|(peer_id, info)| (*peer_id, info.score))
+            .collect::<Vec<_>>();
+        peers.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        peers
+            .into_iter()
+            .take(surplus)
+            .map(|(peer_id, _)| peer_id)
+            .collect()
+    }
+
+    /// Find a peer that serves the whole block at the given height. Pruned peers are
+    /// selected only for their latest blocks.
     pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
         let mut range = rand::thread_rng();
         // TODO: Optimize the selection of the peer.
@@ -225,9 +273,7 @@ impl PeerManager {
         self.non_reserved_connected_peers
             .iter()
             .chain(self.reserved_connected_peers.iter())
-            .filter(|(_, peer_info)| {
-                peer_info.heartbeat_data.block_height >= Some(*height)
-            })
+            .filter(|(_, peer_info)| peer_info.serves_block(height))
             .map(|(peer_id, _)| *peer_id)
             .choose(&mut range)
     }
@@ -362,6 +408,7 @@ fn insert_client_version(
     client_version: String,
 ) {
     if let Some(peer) = peers.get_mut(peer_id) {
+        peer.capabilities = NodeCapabilities::from_agent_version(&client_version);
         peer.client_version = Some(client_version);
     } else {
         log_missing_peer(peer_id);
@@ -435,6 +482,53 @@ mod tests {
         assert_eq!(peer_manager.total_peers_connected(), max_non_reserved_peers);
     }
 
+    #[test]
+    fn lowering_the_limit_returns_surplus_peers_with_lowest_scores() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let random_peers = get_random_peers(5);
+        for (i, peer_id) in random_peers.iter().enumerate() {
+            peer_manager.handle_initial_connection(peer_id, vec![]);
+            peer_manager
+                .non_reserved_connected_peers
+                .get_mut(peer_id)
+                .unwrap()
+                .score = i as AppScore;
+        }
+
+        let surplus = peer_manager.set_max_non_reserved_peers(3);
+
+        assert_eq!(surplus.into_iter().collect::<HashSet<_>>(), {
+            random_peers[..2].iter().copied().collect()
+        });
+        assert!(!peer_manager.connection_state.read().unwrap().available_slot());
+        assert!(peer_manager.set_max_non_reserved_peers(10).is_empty());
+        assert!(peer_manager.connection_state.read().unwrap().available_slot());
+    }
+
+    #[test]
+    fn only_peers_serving_the_block_are_selected() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let [pruned, archive]: [PeerId; 2] = get_random_peers(2).try_into().unwrap();
+        for (peer_id, agent_version) in [
+            (pruned, "fuel-core/1.0.0 (pruned=10)"),
+            (archive, "fuel-core/1.0.0 (archive)"),
+        ] {
+            peer_manager.handle_initial_connection(&peer_id, vec![]);
+            peer_manager.handle_peer_identified(&peer_id, vec![], agent_version.into());
+            peer_manager.handle_peer_info_updated(&peer_id, 100.into());
+        }
+
+        for _ in 0..10 {
+            let peer = peer_manager.get_peer_id_with_height(&95.into());
+            assert!(peer == Some(pruned) || peer == Some(archive));
+        }
+        assert_eq!(
+            peer_manager.get_peer_id_with_height(&50.into()),
+            Some(archive)
+        );
+        assert_eq!(peer_manager.get_peer_id_with_height(&101.into()), None);
+    }
+
     #[test]
     fn only_reserved_peers_are_connected() {
         let max_non_reserved_peers = 0;
@@ -95,7 +95,8 @@ impl PeerReportBehaviour {
     pub(crate) fn new(config: &Config) -> Self {
         let identify = {
             let identify_config =
-                IdentifyConfig::new("/fuel/1.0".to_string(), config.keypair.public());
+                IdentifyConfig::new("/fuel/1.0".to_string(), config.keypair.public())
+                    .with_agent_version(config.capabilities.agent_version());
             if let Some(interval) = config.identify_interval {
                 Identify::new(identify_config.with_interval(interval))
             } else {
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::node_record::SignedNodeRecord;
 use fuel_core_types::{
     blockchain::{
         primitives::BlockId,
@@ -8,6 +9,7 @@ use fuel_core_types::{
     },
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
+    services::p2p::PoolSketch,
 };
 use libp2p::PeerId;
 use serde::{
@@ -43,6 +45,9 @@ pub enum RequestMessage {
     Block(BlockHeight),
     SealedHeader(BlockHeight),
     Transactions(#[serde_as(as = "FromInto<[u8; 32]>")] BlockId),
+    PoolSketch,
+    /// Requests the signed record with the addresses of the peer.
+    NodeRecord,
 }
 
 /// Final Response Message that p2p service sends to the Orchestrator
@@ -51,6 +56,8 @@ pub enum ResponseMessage {
     SealedBlock(Option<SealedBlock>),
     SealedHeader(Option<SealedBlockHeader>),
     Transactions(Option<Vec<Transaction>>),
+    PoolSketch(Option<PoolSketch>),
+    NodeRecord(Option<SignedNodeRecord>),
 }
 
 /// Holds oneshot channels for specific responses
@@ -59,6 +66,7 @@ pub enum ResponseChannelItem {
     Block(ChannelItem<SealedBlock>),
     SealedHeader(ChannelItem<(PeerId, SealedBlockHeader)>),
     Transactions(ChannelItem<Vec<Transaction>>),
+    PoolSketch(ChannelItem<PoolSketch>),
 }
 
 /// Response that is sent over the wire
@@ -68,6 +76,8 @@ pub enum NetworkResponse {
     Block(Option<Vec<u8>>),
     Header(Option<Vec<u8>>),
     Transactions(Option<Vec<u8>>),
+    PoolSketch(Option<Vec<u8>>),
+    NodeRecord(Option<Vec<u8>>),
 }
 
 /// Initial state of the `ResponseMessage` prior to having its inner value serialized
@@ -77,6 +87,8 @@ pub enum OutboundResponse {
     Block(Option<Arc<SealedBlock>>),
     SealedHeader(Option<Arc<SealedBlockHeader>>),
     Transactions(Option<Arc<Vec<Transaction>>>),
+    PoolSketch(Option<Arc<PoolSketch>>),
+    NodeRecord(Option<Arc<SignedNodeRecord>>),
 }
 
 #[derive(Debug)]
@@ -25,9 +25,13 @@ use crate::{
 use anyhow::anyhow;
 use fuel_core_services::{
     stream::BoxStream,
+    ConfigUpdate,
+    Health,
+    ReadySignal,
     RunnableService,
     RunnableTask,
     ServiceRunner,
+    ServiceRunnerBuilder,
     StateWatcher,
 };
 use fuel_core_types::{
@@ -50,6 +54,7 @@ use fuel_core_types::{
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
         PeerId as FuelPeerId,
+        PoolSketch,
         TransactionGossipData,
     },
 };
@@ -60,12 +65,21 @@ use libp2p::{
 };
 use std::{
     fmt::Debug,
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicU32,
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
 };
 use tokio::sync::{
     broadcast,
     mpsc,
     oneshot,
+    watch,
 };
 use tracing::warn;
 
@@ -82,8 +96,13 @@ enum TaskRequest {
         height: BlockHeight,
         channel: oneshot::Sender<Option<SealedBlock>>,
     },
+    GetPeerWithBlock {
+        height: BlockHeight,
+        channel: oneshot::Sender<Option<PeerId>>,
+    },
     GetSealedHeader {
         height: BlockHeight,
+        from_peer: PeerId,
         channel: oneshot::Sender<Option<(PeerId, SealedBlockHeader)>>,
     },
     GetTransactions {
@@ -91,6 +110,10 @@ enum TaskRequest {
         from_peer: PeerId,
         channel: oneshot::Sender<Option<Vec<Transaction>>>,
     },
+    GetPoolSketch {
+        from_peer: PeerId,
+        channel: oneshot::Sender<Option<PoolSketch>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -114,7 +137,10 @@ pub struct Task<D> {
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
+    max_peers_receiver: watch::Receiver<Option<u32>>,
     shared: SharedState,
+    /// Reports readiness after the first listener is bound.
+    ready: ReadySignal,
 }
 
 impl<D> Task<D> {
@@ -128,18 +154,27 @@ impl<D> Task<D> {
         let (block_height_broadcast, _) = broadcast::channel(100);
         let next_block_height = block_importer.next_block_height();
         let max_block_size = config.max_block_size;
+        let max_peers_limit = config.max_peers_connected;
+        let (max_peers_sender, max_peers_receiver) = watch::channel(None);
         let p2p_service = FuelP2PService::new(config, PostcardCodec::new(max_block_size));
 
         Self {
             p2p_service,
             db,
             request_receiver,
+            max_peers_receiver,
             next_block_height,
             shared: SharedState {
                 request_sender,
                 tx_broadcast,
                 block_height_broadcast,
+                pool_sketch: Default::default(),
+                connected_peers: Default::default(),
+                highest_peer_height: Default::default(),
+                max_peers_sender: Arc::new(max_peers_sender),
+                max_peers_limit,
             },
+            ready: Default::default(),
         }
     }
 }
@@ -150,6 +185,7 @@ where
     Self: RunnableTask,
 {
     const NAME: &'static str = "P2P";
+    const READY_ON_START: bool = false;
 
     type SharedData = SharedState;
     type Task = Task<D>;
@@ -159,12 +195,40 @@ where
         self.shared.clone()
     }
 
+    fn health(shared: &Self::SharedData) -> Health {
+        if shared.connected_peers_count() == 0 {
+            Health::Degraded("No connected peers".to_string())
+        } else {
+            Health::Healthy
+        }
+    }
+
+    fn validate_reload(
+        shared: &Self::SharedData,
+        update: &ConfigUpdate,
+    ) -> anyhow::Result<ConfigUpdate> {
+        if let Some(max_peers) = update.max_peers {
+            shared.validate_max_peers(max_peers)?;
+        }
+        Ok(ConfigUpdate {
+            max_peers: update.max_peers,
+            ..Default::default()
+        })
+    }
+
+    fn reload(shared: &Self::SharedData, update: &ConfigUpdate) {
+        if let Some(max_peers) = update.max_peers {
+            shared.max_peers_sender.send_replace(Some(max_peers));
+        }
+    }
+
     async fn into_task(
         mut self,
-        _: &StateWatcher,
+        watcher: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.p2p_service.start()?;
+        self.ready = watcher.ready_signal();
         Ok(self)
     }
 }
@@ -217,17 +281,25 @@ where
                         let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&height);
                         let _ = self.p2p_service.send_request_msg(peer, request_msg, channel_item);
                     }
-                    Some(TaskRequest::GetSealedHeader{ height, channel: response }) => {
+                    Some(TaskRequest::GetPeerWithBlock { height, channel }) => {
+                        let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&height);
+                        let _ = channel.send(peer);
+                    }
+                    Some(TaskRequest::GetSealedHeader{ height, from_peer, channel: response }) => {
                         let request_msg = RequestMessage::SealedHeader(height);
                         let channel_item = ResponseChannelItem::SealedHeader(response);
-                        let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&height);
-                        let _ = self.p2p_service.send_request_msg(peer, request_msg, channel_item);
+                        let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
                     }
                     Some(TaskRequest::GetTransactions { block_id, from_peer, channel }) => {
                         let request_msg = RequestMessage::Transactions(block_id);
                         let channel_item = ResponseChannelItem::Transactions(channel);
                         let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
                     }
+                    Some(TaskRequest::GetPoolSketch { from_peer, channel }) => {
+                        let request_msg = RequestMessage::PoolSketch;
+                        let channel_item = ResponseChannelItem::PoolSketch(channel);
+                        let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         report_message(&mut self.p2p_service, message, acceptance);
                     }
@@ -239,10 +311,30 @@ where
                     }
                 }
             }
+            changed = self.max_peers_receiver.changed() => {
+                should_continue = true;
+                if changed.is_ok() {
+                    let max_peers = *self.max_peers_receiver.borrow_and_update();
+                    if let Some(max_peers) = max_peers {
+                        self.p2p_service.set_max_peers(max_peers);
+                    }
+                } else {
+                    unreachable!("The `Task` is holder of the `Sender` too");
+                }
+            }
             p2p_event = self.p2p_service.next_event() => {
                 should_continue = true;
                 match p2p_event {
+                    Some(FuelP2PEvent::ListenerBound(_)) => {
+                        self.ready.ready();
+                    }
+                    Some(FuelP2PEvent::PeerConnected(_) | FuelP2PEvent::PeerDisconnected(_)) => {
+                        let connected_peers = self.p2p_service.get_peers_ids().count();
+                        self.shared.connected_peers.store(connected_peers, Ordering::Relaxed);
+                    }
                     Some(FuelP2PEvent::PeerInfoUpdated { peer_id, block_height }) => {
+                        let highest_peer_height = &self.shared.highest_peer_height;
+                        highest_peer_height.fetch_max(*block_height, Ordering::Relaxed);
                         let peer_id: Vec<u8> = peer_id.into();
                         let block_height_data = BlockHeightHeartbeatData {
                             peer_id: peer_id.into(),
@@ -289,6 +381,14 @@ where
 
                                 let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeader(response));
                             }
+                            RequestMessage::PoolSketch => {
+                                let response = self.shared.pool_sketch.lock().expect("poisoned").clone();
+
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::PoolSketch(response));
+                            }
+                            RequestMessage::NodeRecord => {
+                                // The network answers with the local node record itself
+                            }
                         }
                     },
                     _ => (),
@@ -327,6 +427,17 @@ pub struct SharedState {
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// The latest sketch of the local transaction pool served to peers.
+    pool_sketch: Arc<Mutex<Option<Arc<PoolSketch>>>>,
+    /// The number of connected peers.
+    connected_peers: Arc<AtomicUsize>,
+    /// The highest block height announced by peers since the start.
+    highest_peer_height: Arc<AtomicU32>,
+    /// The limit of connected peers set at runtime. Only the latest value matters, so
+    /// setting it never fails, unlike sending a request.
+    max_peers_sender: Arc<watch::Sender<Option<u32>>>,
+    /// The limit of connected peers the service started with.
+    max_peers_limit: u32,
 }
 
 impl SharedState {
@@ -359,15 +470,26 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Requests the header of the block at the `height` from the peer that serves the
+    /// whole block, so its transactions can be requested from the same peer. Fails if
+    /// no connected peer serves the block.
     pub async fn get_sealed_block_header(
         &self,
         height: BlockHeight,
     ) -> anyhow::Result<Option<(Vec<u8>, SealedBlockHeader)>> {
+        let from_peer = self.get_peer_with_block(height).await?.ok_or_else(|| {
+            anyhow!(
+                "No connected peer serves the block at height {}: peers are behind, \
+                pruned the block or serve only headers",
+                *height
+            )
+        })?;
         let (sender, receiver) = oneshot::channel();
 
         self.request_sender
             .send(TaskRequest::GetSealedHeader {
                 height,
+                from_peer,
                 channel: sender,
             })
             .await?;
@@ -378,6 +500,23 @@ impl SharedState {
             .map_err(|e| anyhow!("{}", e))
     }
 
+    /// Returns the random connected peer that serves the whole block at the `height`.
+    pub async fn get_peer_with_block(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<Option<PeerId>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetPeerWithBlock {
+                height,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
     pub async fn get_transactions_from_peer(
         &self,
         peer_id: Vec<u8>,
@@ -397,6 +536,28 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn get_pool_sketch_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Option<PoolSketch>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        self.request_sender
+            .send(TaskRequest::GetPoolSketch {
+                from_peer,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Updates the sketch of the local transaction pool that is served to peers.
+    pub fn update_pool_sketch(&self, sketch: PoolSketch) {
+        *self.pool_sketch.lock().expect("poisoned") = Some(Arc::new(sketch));
+    }
+
     pub fn broadcast_vote(&self, vote: Arc<ConsensusVote>) -> anyhow::Result<()> {
         self.request_sender
             .try_send(TaskRequest::BroadcastVote(vote))?;
@@ -430,6 +591,37 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Changes the limit of connected non-reserved peers. The limit can be lowered and
+    /// raised back, but not above the limit the service started with.
+    pub fn set_max_peers(&self, max_peers: u32) -> anyhow::Result<()> {
+        self.validate_max_peers(max_peers)?;
+        self.max_peers_sender.send_replace(Some(max_peers));
+        Ok(())
+    }
+
+    fn validate_max_peers(&self, max_peers: u32) -> anyhow::Result<()> {
+        if max_peers > self.max_peers_limit {
+            return Err(anyhow!(
+                "The limit of peers can't exceed {} the service started with",
+                self.max_peers_limit
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns the number of connected peers without a round trip to the `Task`.
+    pub fn connected_peers_count(&self) -> usize {
+        self.connected_peers.load(Ordering::Relaxed)
+    }
+
+    /// Returns the highest block height announced by peers since the start, if any.
+    pub fn highest_peer_height(&self) -> Option<BlockHeight> {
+        match self.highest_peer_height.load(Ordering::Relaxed) {
+            0 => None,
+            height => Some(height.into()),
+        }
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
@@ -472,11 +664,13 @@ where
     D: P2pDb + 'static,
     B: BlockHeightImporter,
 {
-    Service::new(Task::new(
-        p2p_config,
-        Arc::new(db),
-        Arc::new(block_importer),
-    ))
+    let watchdog = p2p_config.watchdog;
+    ServiceRunnerBuilder::new(
+        Task::new(p2p_config, Arc::new(db), Arc::new(block_importer)),
+        (),
+    )
+    .watchdog(watchdog)
+    .build()
 }
 
 pub(crate) fn to_message_acceptance(
@@ -528,6 +722,7 @@ pub mod tests {
         },
         fuel_types::BlockHeight,
     };
+    use std::time::Duration;
 
     #[derive(Clone, Debug)]
     struct FakeDb;
@@ -584,4 +779,18 @@ pub mod tests {
         // Node with p2p service stopped
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
+
+    #[tokio::test]
+    async fn service_is_ready_after_binding_listener() {
+        let p2p_config = Config::default_initialized("ready_after_listener");
+        let service = new_service(p2p_config, FakeDb, FakeBlockImporter);
+
+        service.start_and_await().await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), service.await_ready())
+            .await
+            .expect("The listener should be bound")
+            .unwrap();
+
+        assert!(service.is_ready());
+    }
 }
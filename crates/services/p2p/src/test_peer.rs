@@ -0,0 +1,278 @@
+//! The peer that speaks the request-response and gossip protocols of the node, but
+//! follows the scripted [`Behavior`] instead of the honest one. It allows checking
+//! changes of the protocols against misbehaving peers.
+
+use crate::{
+    codecs::postcard::PostcardCodec,
+    config::Config,
+    p2p_service::{
+        FuelP2PEvent,
+        FuelP2PService,
+    },
+    request_response::messages::{
+        NetworkResponse,
+        OutboundResponse,
+        RequestMessage,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_types::BlockHeight,
+};
+use libp2p::{
+    gossipsub::{
+        error::PublishError,
+        MessageId,
+    },
+    request_response::RequestId,
+    Multiaddr,
+    PeerId,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::Instant;
+
+pub use crate::gossipsub::messages::GossipsubBroadcastRequest;
+
+#[cfg(test)]
+mod conformance_tests;
+
+/// The payload of malformed frames. It doesn't decode as any message of the protocols.
+const MALFORMED_PAYLOAD: [u8; 8] = [u8::MAX; 8];
+
+/// The scripted behavior of the [`TestPeer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Behavior {
+    /// Serves its blocks and advertises its latest height.
+    Honest,
+    /// Holds every response for the `delay` before sending it, keeping requests of
+    /// other peers open.
+    Slowloris { delay: Duration },
+    /// Responds and publishes gossip with the payload that doesn't decode.
+    MalformedFrames,
+    /// Advertises the `height` instead of its latest one and doesn't serve blocks
+    /// above it, like the peer stuck on the old part of the chain.
+    StaleHeight { height: BlockHeight },
+}
+
+impl FromStr for Behavior {
+    type Err = anyhow::Error;
+
+    /// Parses `honest`, `slowloris:<delay in millis>`, `malformed` or
+    /// `stale:<height>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, argument) = match s.split_once(':') {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (s, None),
+        };
+        match (name, argument) {
+            ("honest", None) => Ok(Behavior::Honest),
+            ("slowloris", Some(delay)) => Ok(Behavior::Slowloris {
+                delay: Duration::from_millis(delay.parse()?),
+            }),
+            ("malformed", None) => Ok(Behavior::MalformedFrames),
+            ("stale", Some(height)) => Ok(Behavior::StaleHeight {
+                height: height.parse::<u32>()?.into(),
+            }),
+            _ => Err(anyhow::anyhow!(
+                "Unknown behavior `{s}`, expected `honest`, `slowloris:<millis>`, \
+                `malformed` or `stale:<height>`"
+            )),
+        }
+    }
+}
+
+/// The peer of the p2p network with the scripted [`Behavior`]. It answers requests
+/// while it is polled with [`TestPeer::next_event`].
+pub struct TestPeer {
+    service: FuelP2PService<PostcardCodec>,
+    behavior: Behavior,
+    blocks: BTreeMap<BlockHeight, Arc<SealedBlock>>,
+    /// Responses held back by the [`Behavior::Slowloris`] until their deadlines.
+    delayed: VecDeque<(Instant, RequestId, OutboundResponse)>,
+}
+
+impl TestPeer {
+    /// Starts the peer with the `config`.
+    pub fn new(config: Config, behavior: Behavior) -> anyhow::Result<Self> {
+        let codec = PostcardCodec::new(config.max_block_size);
+        let mut service = FuelP2PService::new(config, codec);
+        service.start()?;
+
+        let mut peer = Self {
+            service,
+            behavior,
+            blocks: Default::default(),
+            delayed: Default::default(),
+        };
+        peer.advertise_height();
+        Ok(peer)
+    }
+
+    pub fn local_peer_id(&self) -> PeerId {
+        self.service.local_peer_id
+    }
+
+    pub fn listeners(&self) -> impl Iterator<Item = &Multiaddr> {
+        self.service.listeners()
+    }
+
+    pub fn behavior(&self) -> Behavior {
+        self.behavior
+    }
+
+    /// Switches the peer to the `behavior`. Responses that are already held back are
+    /// still sent at their deadlines.
+    pub fn set_behavior(&mut self, behavior: Behavior) {
+        self.behavior = behavior;
+        self.advertise_height();
+    }
+
+    /// Adds the `block` to the blocks served by the peer.
+    pub fn add_block(&mut self, block: SealedBlock) {
+        let height = *block.entity.header().height();
+        self.blocks.insert(height, Arc::new(block));
+        self.advertise_height();
+    }
+
+    /// Publishes the `message`, or the malformed payload on its topic if the peer sends
+    /// malformed frames.
+    pub fn publish(
+        &mut self,
+        message: GossipsubBroadcastRequest,
+    ) -> Result<MessageId, PublishError> {
+        match self.behavior {
+            Behavior::MalformedFrames => self
+                .service
+                .publish_raw_message(&message, MALFORMED_PAYLOAD.to_vec()),
+            _ => self.service.publish_message(message),
+        }
+    }
+
+    /// Handles the next event of the network and answers requests according to the
+    /// behavior. Returns the event to the caller to script further actions.
+    pub async fn next_event(&mut self) -> Option<FuelP2PEvent> {
+        let event = match self.delayed.front().map(|(deadline, ..)| *deadline) {
+            Some(deadline) => tokio::select! {
+                event = self.service.next_event() => event,
+                _ = tokio::time::sleep_until(deadline) => {
+                    self.send_delayed_responses();
+                    None
+                }
+            },
+            None => self.service.next_event().await,
+        };
+
+        if let Some(FuelP2PEvent::RequestMessage {
+            request_id,
+            request_message,
+        }) = &event
+        {
+            self.respond(*request_id, *request_message);
+        }
+        event
+    }
+
+    fn respond(&mut self, request_id: RequestId, request: RequestMessage) {
+        let response = self.response(request);
+        let result = match self.behavior {
+            Behavior::Honest | Behavior::StaleHeight { .. } => {
+                self.service.send_response_msg(request_id, response)
+            }
+            Behavior::Slowloris { delay } => {
+                self.delayed
+                    .push_back((Instant::now() + delay, request_id, response));
+                Ok(())
+            }
+            Behavior::MalformedFrames => self
+                .service
+                .send_raw_response_msg(request_id, malformed_response(&response)),
+        };
+        if let Err(e) = result {
+            tracing::debug!("Test peer failed to respond to {:?}: {:?}", request, e);
+        }
+    }
+
+    fn send_delayed_responses(&mut self) {
+        let now = Instant::now();
+        while let Some((deadline, ..)) = self.delayed.front() {
+            if *deadline > now {
+                break
+            }
+            let (_, request_id, response) =
+                self.delayed.pop_front().expect("Checked above; qed");
+            if let Err(e) = self.service.send_response_msg(request_id, response) {
+                tracing::debug!("Test peer failed to respond to {request_id:?}: {e:?}");
+            }
+        }
+    }
+
+    fn response(&self, request: RequestMessage) -> OutboundResponse {
+        match request {
+            RequestMessage::Block(height) => OutboundResponse::Block(self.block(&height)),
+            RequestMessage::SealedHeader(height) => {
+                OutboundResponse::SealedHeader(self.block(&height).map(|block| {
+                    Arc::new(SealedBlockHeader {
+                        entity: block.entity.header().clone(),
+                        consensus: block.consensus.clone(),
+                    })
+                }))
+            }
+            RequestMessage::Transactions(block_id) => OutboundResponse::Transactions(
+                self.served_blocks()
+                    .find(|block| block.entity.id() == block_id)
+                    .map(|block| Arc::new(block.entity.transactions().to_vec())),
+            ),
+            RequestMessage::PoolSketch => OutboundResponse::PoolSketch(None),
+            // The service answers requests for the node record itself
+            RequestMessage::NodeRecord => OutboundResponse::NodeRecord(None),
+        }
+    }
+
+    fn block(&self, height: &BlockHeight) -> Option<Arc<SealedBlock>> {
+        self.served_blocks()
+            .find(|block| block.entity.header().height() == height)
+            .cloned()
+    }
+
+    fn served_blocks(&self) -> impl Iterator<Item = &Arc<SealedBlock>> {
+        let max_height = match self.behavior {
+            Behavior::StaleHeight { height } => height,
+            _ => u32::MAX.into(),
+        };
+        self.blocks.range(..=max_height).map(|(_, block)| block)
+    }
+
+    fn advertise_height(&mut self) {
+        let height = match self.behavior {
+            Behavior::StaleHeight { height } => Some(height),
+            _ => self.blocks.keys().next_back().copied(),
+        };
+        if let Some(height) = height {
+            self.service.update_block_height(height);
+        }
+    }
+}
+
+/// Returns the response of the same kind as the `response`, but with the malformed
+/// payload.
+fn malformed_response(response: &OutboundResponse) -> NetworkResponse {
+    let payload = Some(MALFORMED_PAYLOAD.to_vec());
+    match response {
+        OutboundResponse::Block(_) => NetworkResponse::Block(payload),
+        OutboundResponse::SealedHeader(_) => NetworkResponse::Header(payload),
+        OutboundResponse::Transactions(_) => NetworkResponse::Transactions(payload),
+        OutboundResponse::PoolSketch(_) => NetworkResponse::PoolSketch(payload),
+        OutboundResponse::NodeRecord(_) => NetworkResponse::NodeRecord(payload),
+    }
+}
@@ -0,0 +1,324 @@
+//! The conformance suite of the `FuelP2PService` against peers with scripted behaviors.
+//! The node under test is driven by the test, while the peers run in the background
+//! unless the test scripts them.
+
+use super::{
+    Behavior,
+    GossipsubBroadcastRequest,
+    TestPeer,
+};
+use crate::{
+    codecs::postcard::PostcardCodec,
+    config::Config,
+    gossipsub::messages::GossipsubMessage,
+    p2p_service::{
+        FuelP2PEvent,
+        FuelP2PService,
+    },
+    request_response::messages::{
+        RequestMessage,
+        ResponseChannelItem,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        header::PartialBlockHeader,
+        SealedBlock,
+    },
+    fuel_tx::Transaction,
+    fuel_types::BlockHeight,
+};
+use futures::Future;
+use libp2p::{
+    Multiaddr,
+    PeerId,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::oneshot;
+use tracing_attributes::instrument;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+fn sealed_block(height: u32) -> SealedBlock {
+    let mut header = PartialBlockHeader::default();
+    header.consensus.height = height.into();
+
+    SealedBlock {
+        entity: Block::new(header, vec![Transaction::default()], &[]),
+        consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+    }
+}
+
+/// Starts the node under test on the memory transport and returns it with its address.
+async fn start_node(
+    network_name: &str,
+    request_timeout: Duration,
+) -> (FuelP2PService<PostcardCodec>, Multiaddr) {
+    let mut config = Config::default_initialized(network_name);
+    config.memory_transport = true;
+    config.set_request_timeout = request_timeout;
+    let max_block_size = config.max_block_size;
+
+    let mut node = FuelP2PService::new(config, PostcardCodec::new(max_block_size));
+    node.start().unwrap();
+
+    let address = loop {
+        if let Some(address) = node.listeners().next() {
+            break format!("{address}/p2p/{}", node.local_peer_id)
+                .parse()
+                .unwrap()
+        }
+        node.next_event().await;
+    };
+    (node, address)
+}
+
+/// Starts the test peer that connects to the node at the `address`.
+fn test_peer(
+    network_name: &str,
+    address: &Multiaddr,
+    behavior: Behavior,
+    heights: impl IntoIterator<Item = u32>,
+) -> TestPeer {
+    let mut config = Config::default_initialized(network_name);
+    config.memory_transport = true;
+    config.bootstrap_nodes = vec![address.clone()];
+
+    let mut peer = TestPeer::new(config, behavior).unwrap();
+    for height in heights {
+        peer.add_block(sealed_block(height));
+    }
+    peer
+}
+
+/// Runs the `peer` in the background and returns its id.
+fn spawn(mut peer: TestPeer) -> PeerId {
+    let peer_id = peer.local_peer_id();
+    tokio::spawn(async move {
+        loop {
+            peer.next_event().await;
+        }
+    });
+    peer_id
+}
+
+/// Handles events of the `node` until the `future` completes.
+async fn drive<T>(
+    node: &mut FuelP2PService<PostcardCodec>,
+    future: impl Future<Output = T>,
+) -> T {
+    tokio::pin!(future);
+    loop {
+        tokio::select! {
+            output = &mut future => return output,
+            node_event = node.next_event() => {
+                tracing::info!("Node Event: {:?}", node_event);
+            }
+        }
+    }
+}
+
+/// Handles events of the `node` until the `peer_id` reports its block height.
+async fn wait_for_height(
+    node: &mut FuelP2PService<PostcardCodec>,
+    peer_id: PeerId,
+) -> BlockHeight {
+    loop {
+        if let Some(FuelP2PEvent::PeerInfoUpdated {
+            peer_id: updated_peer_id,
+            block_height,
+        }) = node.next_event().await
+        {
+            if updated_peer_id == peer_id {
+                return block_height
+            }
+        }
+    }
+}
+
+fn request_block(
+    node: &mut FuelP2PService<PostcardCodec>,
+    peer_id: PeerId,
+    height: u32,
+) -> oneshot::Receiver<Option<SealedBlock>> {
+    let (sender, receiver) = oneshot::channel();
+    node.send_request_msg(
+        Some(peer_id),
+        RequestMessage::Block(height.into()),
+        ResponseChannelItem::Block(sender),
+    )
+    .unwrap();
+    receiver
+}
+
+fn height_of(block: &SealedBlock) -> BlockHeight {
+    *block.entity.header().height()
+}
+
+#[tokio::test]
+#[instrument]
+async fn honest_peer_serves_blocks() {
+    let network_name = "honest_peer_serves_blocks";
+    let (mut node, address) = start_node(network_name, REQUEST_TIMEOUT).await;
+    let peer_id = spawn(test_peer(network_name, &address, Behavior::Honest, 1..=3));
+
+    assert_eq!(wait_for_height(&mut node, peer_id).await, 3.into());
+    let response = request_block(&mut node, peer_id, 2);
+    let block = drive(&mut node, response).await.unwrap();
+    assert_eq!(block.as_ref().map(height_of), Some(2.into()));
+}
+
+#[tokio::test]
+#[instrument]
+async fn malformed_responses_fail_only_their_requests() {
+    let network_name = "malformed_responses_fail_only_their_requests";
+    let (mut node, address) = start_node(network_name, REQUEST_TIMEOUT).await;
+    let malformed = spawn(test_peer(
+        network_name,
+        &address,
+        Behavior::MalformedFrames,
+        1..=3,
+    ));
+    let honest = spawn(test_peer(network_name, &address, Behavior::Honest, 1..=3));
+    wait_for_height(&mut node, malformed).await;
+    wait_for_height(&mut node, honest).await;
+
+    // The response that doesn't decode drops the request instead of hanging it
+    let response = request_block(&mut node, malformed, 1);
+    assert!(drive(&mut node, response).await.is_err());
+
+    let response = request_block(&mut node, honest, 1);
+    let block = drive(&mut node, response).await.unwrap();
+    assert_eq!(block.as_ref().map(height_of), Some(1.into()));
+}
+
+#[tokio::test]
+#[instrument]
+async fn malformed_gossip_is_not_delivered() {
+    let network_name = "malformed_gossip_is_not_delivered";
+    let (mut node, address) = start_node(network_name, REQUEST_TIMEOUT).await;
+    let mut peer = test_peer(network_name, &address, Behavior::MalformedFrames, []);
+    let peer_id = peer.local_peer_id();
+    let tx = Arc::new(Transaction::default());
+    let mut published = false;
+
+    loop {
+        tokio::select! {
+            node_event = node.next_event() => match node_event {
+                Some(FuelP2PEvent::PeerInfoUpdated { peer_id: updated_peer_id, .. })
+                    if updated_peer_id == peer_id && !published =>
+                {
+                    published = true;
+                    // The malformed message is sent first, but only the valid one
+                    // reaches the node
+                    peer.publish(GossipsubBroadcastRequest::NewTx(tx.clone())).unwrap();
+                    peer.set_behavior(Behavior::Honest);
+                    peer.publish(GossipsubBroadcastRequest::NewTx(tx.clone())).unwrap();
+                }
+                Some(FuelP2PEvent::GossipsubMessage { peer_id: source, message, .. }) => {
+                    assert_eq!(source, peer_id);
+                    let received = match message {
+                        GossipsubMessage::NewTx(received) => received,
+                        other => panic!("Unexpected message {other:?}"),
+                    };
+                    assert_eq!(received, *tx);
+                    break
+                }
+                _ => {}
+            },
+            _ = peer.next_event() => {}
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[instrument]
+async fn slowloris_peer_does_not_block_other_requests() {
+    let network_name = "slowloris_peer_does_not_block_other_requests";
+    let request_timeout = Duration::from_secs(2);
+    let (mut node, address) = start_node(network_name, request_timeout).await;
+    let slowloris = spawn(test_peer(
+        network_name,
+        &address,
+        Behavior::Slowloris {
+            delay: Duration::from_secs(60),
+        },
+        1..=3,
+    ));
+    let honest = spawn(test_peer(network_name, &address, Behavior::Honest, 1..=3));
+    wait_for_height(&mut node, slowloris).await;
+    wait_for_height(&mut node, honest).await;
+
+    let slow_response = request_block(&mut node, slowloris, 1);
+    let response = request_block(&mut node, honest, 1);
+    let block = drive(&mut node, response).await.unwrap();
+    assert_eq!(block.as_ref().map(height_of), Some(1.into()));
+
+    // The held back response times out instead of waiting for the peer
+    let slow_response = tokio::time::timeout(
+        request_timeout * 5,
+        drive(&mut node, slow_response),
+    )
+    .await
+    .expect("The request should time out");
+    assert!(slow_response.is_err());
+}
+
+#[tokio::test]
+#[instrument]
+async fn stale_peer_reports_its_height_and_misses_newer_blocks() {
+    let network_name = "stale_peer_reports_its_height_and_misses_newer_blocks";
+    let (mut node, address) = start_node(network_name, REQUEST_TIMEOUT).await;
+    let stale_height = 2;
+    let peer_id = spawn(test_peer(
+        network_name,
+        &address,
+        Behavior::StaleHeight {
+            height: stale_height.into(),
+        },
+        1..=5,
+    ));
+
+    assert_eq!(wait_for_height(&mut node, peer_id).await, stale_height.into());
+    assert_eq!(
+        node.peer_manager()
+            .get_peer_info(&peer_id)
+            .and_then(|info| info.heartbeat_data.block_height),
+        Some(stale_height.into())
+    );
+
+    let response = request_block(&mut node, peer_id, 4);
+    assert!(drive(&mut node, response).await.unwrap().is_none());
+    let response = request_block(&mut node, peer_id, stale_height);
+    let block = drive(&mut node, response).await.unwrap();
+    assert_eq!(block.as_ref().map(height_of), Some(stale_height.into()));
+}
+
+#[test]
+fn behavior_is_parsed_from_str() {
+    assert_eq!("honest".parse::<Behavior>().unwrap(), Behavior::Honest);
+    assert_eq!(
+        "slowloris:1500".parse::<Behavior>().unwrap(),
+        Behavior::Slowloris {
+            delay: Duration::from_millis(1500)
+        }
+    );
+    assert_eq!(
+        "malformed".parse::<Behavior>().unwrap(),
+        Behavior::MalformedFrames
+    );
+    assert_eq!(
+        "stale:7".parse::<Behavior>().unwrap(),
+        Behavior::StaleHeight { height: 7.into() }
+    );
+    assert!("stale".parse::<Behavior>().is_err());
+    assert!("honest:1".parse::<Behavior>().is_err());
+}
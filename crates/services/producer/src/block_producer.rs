@@ -1,6 +1,12 @@
 use crate::{
     ports,
     ports::BlockProducerDatabase,
+    tx_selector::{
+        BlockLimits,
+        Excluding,
+        ReservedLane,
+        TxSelector,
+    },
     Config,
 };
 use anyhow::{
@@ -10,7 +16,10 @@ use anyhow::{
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
     blockchain::{
-        block::PartialFuelBlock,
+        block::{
+            Block,
+            PartialFuelBlock,
+        },
         header::{
             ApplicationHeader,
             ConsensusHeader,
@@ -20,27 +29,51 @@ use fuel_core_types::{
     },
     fuel_asm::Word,
     fuel_tx::{
+        AssetId,
+        Output,
         Receipt,
         Transaction,
+        TxId,
+        TxPointer,
     },
     fuel_types::{
+        bytes::SerializableVec,
         BlockHeight,
         Bytes32,
     },
     services::executor::{
         ExecutionBlock,
+        ExecutionResult,
+        Result as ExecutorResult,
         UncommittedResult,
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::{
+    sync::Mutex,
+    task::JoinHandle,
+};
 use tracing::debug;
 
 #[cfg(test)]
 mod tests;
 
+/// The upper bound of the size of the header and the consensus seal of the block in
+/// bytes, which is subtracted from the `max_block_size` along with the coinbase.
+pub const MAX_HEADER_SIZE: usize = 1024;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(
@@ -54,44 +87,175 @@ pub enum Error {
         best: DaBlockHeight,
         previous_block: DaBlockHeight,
     },
+    #[error("Pinned da_height {pinned} is behind previous da_height {previous_block}")]
+    PinnedDaHeightBelowPrevious {
+        pinned: DaBlockHeight,
+        previous_block: DaBlockHeight,
+    },
+    #[error("Pinned da_height {pinned} is above best finalized da_height {best}")]
+    PinnedDaHeightNotFinalized {
+        pinned: DaBlockHeight,
+        best: DaBlockHeight,
+    },
+}
+
+/// The execution of the next block that started before the previous block is imported.
+/// It is used only if the producer selects the same block when its turn comes. The
+/// dropped speculation cancels the execution.
+pub struct Speculation<Database> {
+    cancel: Arc<AtomicBool>,
+    task: JoinHandle<anyhow::Result<SpeculativeBlock<Database>>>,
+}
+
+impl<Database> Drop for Speculation<Database> {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+        self.task.abort();
+    }
+}
+
+/// The block selected by the speculation and its running execution.
+struct SpeculativeBlock<Database> {
+    header: PartialBlockHeader,
+    max_gas: Word,
+    transactions: Vec<TxId>,
+    execution:
+        JoinHandle<ExecutorResult<UncommittedResult<StorageTransaction<Database>>>>,
+}
+
+/// The block that the producer would produce right now, executed but not sealed.
+/// External builders use it as the base of their blocks.
+#[derive(Debug, Clone)]
+pub struct BlockTemplate {
+    /// The executed block with the coinbase and the complete header.
+    pub block: Block,
+    /// The sum of the maximal gas of transactions of the block, the measure the gas
+    /// limit of the block applies to.
+    pub gas_used: Word,
 }
 
 pub struct Producer<Database> {
     pub config: Config,
     pub db: Database,
     pub txpool: Box<dyn ports::TxPool>,
+    pub tx_selector: Box<dyn TxSelector>,
     pub executor: Arc<dyn ports::Executor<Database>>,
     pub relayer: Box<dyn ports::Relayer>,
     // use a tokio lock since we want callers to yield until the previous block
     // execution has completed (which may take a while).
     pub lock: Mutex<()>,
+    pub speculation: std::sync::Mutex<Option<Speculation<Database>>>,
 }
 
 impl<Database> Producer<Database>
 where
     Database: BlockProducerDatabase + 'static,
 {
-    /// Produces and execute block for the specified height
+    /// Produces and execute block for the specified height. The block includes events of
+    /// the DA layer up to the pinned `da_height`, or up to the best finalized da_height
+    /// of the relayer if it is `None`.
     pub async fn produce_and_execute_block(
         &self,
         height: BlockHeight,
         block_time: Tai64,
         max_gas: Word,
+        da_height: Option<DaBlockHeight>,
     ) -> anyhow::Result<UncommittedResult<StorageTransaction<Database>>> {
+        self.produce(height, block_time, max_gas, da_height, None)
+            .await?
+            .ok_or_else(|| anyhow!("The execution without the budget was cancelled"))
+    }
+
+    /// Produces and executes the block like [`Self::produce_and_execute_block`], but
+    /// cancels the execution once it takes longer than the `budget`. Only the execution
+    /// is measured, not the wait for the relayer. Returns `None` if the budget is
+    /// exceeded.
+    pub async fn produce_and_execute_block_within(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+        da_height: Option<DaBlockHeight>,
+        budget: Duration,
+    ) -> anyhow::Result<Option<UncommittedResult<StorageTransaction<Database>>>> {
+        self.produce(height, block_time, max_gas, da_height, Some(budget))
+            .await
+    }
+
+    async fn produce(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+        da_height: Option<DaBlockHeight>,
+        budget: Option<Duration>,
+    ) -> anyhow::Result<Option<UncommittedResult<StorageTransaction<Database>>>> {
         //  - get previous block info (hash, root, etc)
         //  - select best da_height from relayer
-        //  - get available txs from txpool
-        //  - select best txs based on factors like:
-        //      1. fees
-        //      2. parallel throughput
+        //  - select txs from txpool with the `tx_selector`
         //  - Execute block with production mode to correctly malleate txs outputs and block headers
 
         // prevent simultaneous block production calls, the guard will drop at the end of this fn.
         let _production_guard = self.lock.lock().await;
 
-        let best_transactions = self.txpool.get_includable_txs(height, max_gas);
+        let header = self.new_header(height, block_time, da_height).await?;
+        let best_transactions = self.with_limits(
+            self.tx_selector.as_ref(),
+            height,
+            max_gas,
+            |tx_selector, limits| {
+                self.txpool.get_includable_txs(height, limits, tx_selector)
+            },
+        );
+
+        let speculation = self.speculation.lock().expect("poisoned").take();
+        if let Some(mut speculation) = speculation {
+            let transactions: Vec<TxId> =
+                best_transactions.iter().map(|tx| tx.id()).collect();
+            // The failed speculation is not an error of the production, the block is
+            // executed again below.
+            let speculated = match (&mut speculation.task).await {
+                Ok(speculated) => speculated,
+                Err(e) => Err(e.into()),
+            };
+            match speculated {
+                Ok(speculated)
+                    if speculated.header == header
+                        && speculated.max_gas == max_gas
+                        && speculated.transactions == transactions =>
+                {
+                    let execution = speculated.execution;
+                    let result = match wait_within(execution, &speculation.cancel, budget)
+                        .await
+                    {
+                        Ok(Some(result)) => result.map_err(anyhow::Error::from),
+                        Ok(None) => {
+                            debug!("The speculative block {height} exceeded the budget");
+                            return Ok(None)
+                        }
+                        Err(e) => Err(e),
+                    };
+                    match result {
+                        Ok(result) => {
+                            debug!(
+                                "Used the speculative execution of the block {height}"
+                            );
+                            return Ok(Some(result))
+                        }
+                        Err(e) => debug!(
+                            "The speculative execution of the block {height} failed: {e}"
+                        ),
+                    }
+                }
+                Ok(_) => {
+                    debug!("Discarded the speculative execution of the block {height}")
+                }
+                Err(e) => debug!("The speculation of the block {height} failed: {e}"),
+            }
+            // The stale execution stops before its next transaction.
+            drop(speculation);
+        }
 
-        let header = self.new_header(height, block_time).await?;
         let block = PartialFuelBlock::new(
             header,
             best_transactions
@@ -103,13 +267,160 @@ where
         // Store the context string incase we error.
         let context_string =
             format!("Failed to produce block {block:?} due to execution failure");
-        let result = self
-            .executor
-            .execute_without_commit(ExecutionBlock::Production(block))
-            .context(context_string)?;
+        // The execution runs on the blocking thread, so the runtime isn't blocked while
+        // it is measured against the budget.
+        let cancel = Arc::new(AtomicBool::new(false));
+        let executor = self.executor.clone();
+        let execution = tokio::task::spawn_blocking({
+            let cancel = cancel.clone();
+            move || {
+                executor
+                    .execute_without_commit(ExecutionBlock::Production(block), &cancel)
+            }
+        });
+        let result = match wait_within(execution, &cancel, budget).await? {
+            Some(result) => result.context(context_string)?,
+            None => {
+                debug!("The execution of the block {height} exceeded the budget");
+                return Ok(None)
+            }
+        };
 
         debug!("Produced block with result: {:?}", result.result());
-        Ok(result)
+        Ok(Some(result))
+    }
+
+    /// Starts the execution of the block at the `height` on top of the `parent` state,
+    /// i.e. before the previous block is imported. The next call of
+    /// [`Self::produce_and_execute_block`] uses the result if it selects the same block.
+    ///
+    /// Only the `parent` is forked before the return, the block is selected and executed
+    /// in the background, so the speculation doesn't delay the import of the `parent`.
+    pub fn speculate_next_block(
+        self: &Arc<Self>,
+        parent: &Database,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<()> {
+        // The commit of the `parent` doesn't affect the fork, so it can be executed
+        // concurrently with the import.
+        let fork = parent.fork()?;
+        let cancel = Arc::new(AtomicBool::new(false));
+        let producer = self.clone();
+        let task = tokio::spawn({
+            let cancel = cancel.clone();
+            async move {
+                producer
+                    .speculative_block(fork, height, block_time, max_gas, cancel)
+                    .await
+            }
+        });
+
+        // The previous speculation is cancelled if it wasn't used.
+        *self.speculation.lock().expect("poisoned") = Some(Speculation { cancel, task });
+        Ok(())
+    }
+
+    /// Selects the block at the `height` on top of the `fork` and starts its execution.
+    async fn speculative_block(
+        &self,
+        fork: Database,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+        cancel: Arc<AtomicBool>,
+    ) -> anyhow::Result<SpeculativeBlock<Database>> {
+        let mut header = self._new_header(&fork, height, block_time)?;
+        header.application.da_height =
+            self.select_new_da_height(header.da_height, None).await?;
+
+        // Transactions of the previous block stay in the pool until it is imported.
+        let previous_height = height - 1u32.into();
+        let excluded = fork
+            .get_block(&previous_height)?
+            .transactions()
+            .iter()
+            .copied()
+            .collect();
+        let excluding = Excluding::new(self.tx_selector.as_ref(), excluded);
+        let best_transactions =
+            self.with_limits(&excluding, height, max_gas, |tx_selector, limits| {
+                self.txpool.get_includable_txs(height, limits, tx_selector)
+            });
+        let transactions = best_transactions.iter().map(|tx| tx.id()).collect();
+
+        let block = PartialFuelBlock::new(
+            header.clone(),
+            best_transactions
+                .into_iter()
+                .map(|tx| tx.as_ref().into())
+                .collect(),
+        );
+        let executor = self.executor.clone();
+        let execution = tokio::task::spawn_blocking(move || {
+            executor.execute_speculatively(
+                ExecutionBlock::Production(block),
+                &fork,
+                &cancel,
+            )
+        });
+
+        Ok(SpeculativeBlock {
+            header,
+            max_gas,
+            transactions,
+            execution,
+        })
+    }
+
+    /// Returns the template of the block at the `height` without sealing it. Unlike
+    /// [`Self::produce_and_execute_block`], it doesn't select transactions, so they stay
+    /// includable into blocks produced by the node.
+    pub async fn block_template(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<BlockTemplate> {
+        let header = self.new_header(height, block_time, None).await?;
+        let transactions = self.with_limits(
+            self.tx_selector.as_ref(),
+            height,
+            max_gas,
+            |tx_selector, limits| {
+                self.txpool.peek_includable_txs(height, limits, tx_selector)
+            },
+        );
+        let max_gas_of: HashMap<TxId, Word> = transactions
+            .iter()
+            .map(|tx| (tx.id(), tx.max_gas()))
+            .collect();
+
+        let block = PartialFuelBlock::new(
+            header,
+            transactions
+                .into_iter()
+                .map(|tx| tx.as_ref().into())
+                .collect(),
+        );
+        let executor = self.executor.clone();
+        // The changes of the execution are dropped without the commit.
+        let result = tokio::task::spawn_blocking(move || {
+            let cancel = AtomicBool::new(false);
+            executor.execute_without_commit(ExecutionBlock::Production(block), &cancel)
+        })
+        .await?
+        .context("Failed to execute the block template")?;
+        let ExecutionResult {
+            block, tx_status, ..
+        } = result.into_result();
+        let gas_used = tx_status
+            .iter()
+            .filter_map(|status| max_gas_of.get(&status.id))
+            .sum();
+
+        Ok(BlockTemplate { block, gas_used })
     }
 
     // TODO: Support custom `block_time` for `dry_run`.
@@ -133,7 +444,7 @@ where
         // use the same configuration as the last block -> the same DA height.
         // It is deterministic from the result perspective, plus it is more performant
         // because we don't need to wait for the relayer to sync.
-        let header = self._new_header(height, Tai64::now())?;
+        let header = self._new_header(&self.db, height, Tai64::now())?;
         let block =
             PartialFuelBlock::new(header, vec![transaction].into_iter().collect());
 
@@ -159,14 +470,59 @@ impl<Database> Producer<Database>
 where
     Database: BlockProducerDatabase,
 {
+    /// Calls the `select` with the `inner` selector restricted by the priority lane of
+    /// the config and with the limits of transactions of the block at the `height`.
+    fn with_limits<R>(
+        &self,
+        inner: &dyn TxSelector,
+        height: BlockHeight,
+        max_gas: Word,
+        select: impl FnOnce(&dyn TxSelector, BlockLimits) -> R,
+    ) -> R {
+        let reserved_lane;
+        let tx_selector: &dyn TxSelector = match self.config.priority_lane {
+            Some(priority_lane) => {
+                reserved_lane = ReservedLane::new(inner, priority_lane);
+                &reserved_lane
+            }
+            None => inner,
+        };
+        let limits = match self.config.max_block_size {
+            Some(max_block_size) => BlockLimits {
+                max_gas,
+                max_size: max_block_size
+                    .saturating_sub(MAX_HEADER_SIZE)
+                    .saturating_sub(self.coinbase_size(height)),
+            },
+            None => BlockLimits::gas(max_gas),
+        };
+        select(tx_selector, limits)
+    }
+
+    /// Returns the upper bound of the size of the coinbase of the block at the `height`.
+    fn coinbase_size(&self, height: BlockHeight) -> usize {
+        let mut coinbase = Transaction::mint(
+            TxPointer::new(height, Default::default()),
+            vec![Output::coin(
+                self.config.coinbase_recipient_at(height),
+                Word::MAX,
+                AssetId::BASE,
+            )],
+        );
+        coinbase.to_bytes().len()
+    }
+
     /// Create the header for a new block at the provided height
     async fn new_header(
         &self,
         height: BlockHeight,
         block_time: Tai64,
+        pinned_da_height: Option<DaBlockHeight>,
     ) -> anyhow::Result<PartialBlockHeader> {
-        let mut block_header = self._new_header(height, block_time)?;
-        let new_da_height = self.select_new_da_height(block_header.da_height).await?;
+        let mut block_header = self._new_header(&self.db, height, block_time)?;
+        let new_da_height = self
+            .select_new_da_height(block_header.da_height, pinned_da_height)
+            .await?;
 
         block_header.application.da_height = new_da_height;
 
@@ -176,6 +532,7 @@ where
     async fn select_new_da_height(
         &self,
         previous_da_height: DaBlockHeight,
+        pinned_da_height: Option<DaBlockHeight>,
     ) -> anyhow::Result<DaBlockHeight> {
         let best_height = self.relayer.wait_for_at_least(&previous_da_height).await?;
         if best_height < previous_da_height {
@@ -187,15 +544,35 @@ where
             }
             .into())
         }
-        Ok(best_height)
+        match pinned_da_height {
+            // The da_height of blocks never decreases
+            Some(pinned) if pinned < previous_da_height => {
+                Err(Error::PinnedDaHeightBelowPrevious {
+                    pinned,
+                    previous_block: previous_da_height,
+                }
+                .into())
+            }
+            // Events above the best finalized da_height are not known to the relayer
+            Some(pinned) if pinned > best_height => {
+                Err(Error::PinnedDaHeightNotFinalized {
+                    pinned,
+                    best: best_height,
+                }
+                .into())
+            }
+            Some(pinned) => Ok(pinned),
+            None => Ok(best_height),
+        }
     }
 
     fn _new_header(
         &self,
+        db: &Database,
         height: BlockHeight,
         block_time: Tai64,
     ) -> anyhow::Result<PartialBlockHeader> {
-        let previous_block_info = self.previous_block_info(height)?;
+        let previous_block_info = self.previous_block_info(db, height)?;
 
         Ok(PartialBlockHeader {
             application: ApplicationHeader {
@@ -213,6 +590,7 @@ where
 
     fn previous_block_info(
         &self,
+        db: &Database,
         height: BlockHeight,
     ) -> anyhow::Result<PreviousBlockInfo> {
         // TODO: It is not guaranteed that the genesis height is `0` height. Update the code to
@@ -224,8 +602,8 @@ where
         } else {
             // get info from previous block height
             let prev_height = height - 1u32.into();
-            let previous_block = self.db.get_block(&prev_height)?;
-            let prev_root = self.db.block_header_merkle_root(&prev_height)?;
+            let previous_block = db.get_block(&prev_height)?;
+            let prev_root = db.block_header_merkle_root(&prev_height)?;
 
             Ok(PreviousBlockInfo {
                 prev_root,
@@ -239,3 +617,23 @@ struct PreviousBlockInfo {
     prev_root: Bytes32,
     da_height: DaBlockHeight,
 }
+
+/// Waits for the `execution` for at most the `budget`. Once the budget is exceeded, the
+/// `cancel` is set to stop the execution and `None` is returned.
+async fn wait_within<T>(
+    execution: JoinHandle<T>,
+    cancel: &AtomicBool,
+    budget: Option<Duration>,
+) -> anyhow::Result<Option<T>> {
+    let budget = match budget {
+        Some(budget) => budget,
+        None => return Ok(Some(execution.await?)),
+    };
+    match tokio::time::timeout(budget, execution).await {
+        Ok(result) => Ok(Some(result?)),
+        Err(_) => {
+            cancel.store(true, Ordering::Relaxed);
+            Ok(None)
+        }
+    }
+}
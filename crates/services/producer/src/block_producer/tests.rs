@@ -1,13 +1,16 @@
 use crate::{
     block_producer::Error,
     mocks::{
+        CountingMockExecutor,
         FailingMockExecutor,
         MockDb,
         MockExecutor,
         MockRelayer,
         MockTxPool,
+        StuckMockExecutor,
     },
     ports::Executor,
+    tx_selector::FeePriority,
     Config,
     Producer,
 };
@@ -33,9 +36,13 @@ use rand::{
     Rng,
     SeedableRng,
 };
-use std::sync::{
-    Arc,
-    Mutex,
+use std::{
+    sync::{
+        atomic::Ordering,
+        Arc,
+        Mutex,
+    },
+    time::Duration,
 };
 
 #[tokio::test]
@@ -44,7 +51,7 @@ async fn cant_produce_at_genesis_height() {
     let producer = ctx.producer();
 
     let err = producer
-        .produce_and_execute_block(0u32.into(), Tai64::now(), 1_000_000_000)
+        .produce_and_execute_block(0u32.into(), Tai64::now(), 1_000_000_000, None)
         .await
         .expect_err("expected failure");
 
@@ -60,7 +67,7 @@ async fn can_produce_initial_block() {
     let producer = ctx.producer();
 
     let result = producer
-        .produce_and_execute_block(1u32.into(), Tai64::now(), 1_000_000_000)
+        .produce_and_execute_block(1u32.into(), Tai64::now(), 1_000_000_000, None)
         .await;
 
     assert!(result.is_ok());
@@ -95,7 +102,12 @@ async fn can_produce_next_block() {
     let ctx = TestContext::default_from_db(db);
     let producer = ctx.producer();
     let result = producer
-        .produce_and_execute_block(prev_height + 1u32.into(), Tai64::now(), 1_000_000_000)
+        .produce_and_execute_block(
+            prev_height + 1u32.into(),
+            Tai64::now(),
+            1_000_000_000,
+            None,
+        )
         .await;
 
     assert!(result.is_ok());
@@ -108,7 +120,7 @@ async fn cant_produce_if_no_previous_block() {
     let producer = ctx.producer();
 
     let err = producer
-        .produce_and_execute_block(100u32.into(), Tai64::now(), 1_000_000_000)
+        .produce_and_execute_block(100u32.into(), Tai64::now(), 1_000_000_000, None)
         .await
         .expect_err("expected failure");
 
@@ -152,7 +164,12 @@ async fn cant_produce_if_previous_block_da_height_too_high() {
     let producer = ctx.producer();
 
     let err = producer
-        .produce_and_execute_block(prev_height + 1u32.into(), Tai64::now(), 1_000_000_000)
+        .produce_and_execute_block(
+            prev_height + 1u32.into(),
+            Tai64::now(),
+            1_000_000_000,
+            None,
+        )
         .await
         .expect_err("expected failure");
 
@@ -168,6 +185,69 @@ async fn cant_produce_if_previous_block_da_height_too_high() {
     );
 }
 
+#[tokio::test]
+async fn block_is_produced_at_pinned_da_height() {
+    let prev_da_height = 10u64.into();
+    let prev_height = 1u32.into();
+    let previous_block = PartialFuelBlock {
+        header: PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: prev_da_height,
+                ..Default::default()
+            },
+            consensus: ConsensusHeader {
+                height: prev_height,
+                ..Default::default()
+            },
+        },
+        transactions: vec![],
+    }
+    .generate(&[])
+    .compress(&ConsensusParameters::DEFAULT);
+
+    let db = MockDb {
+        blocks: Arc::new(Mutex::new(
+            vec![(prev_height, previous_block)].into_iter().collect(),
+        )),
+    };
+    let ctx = TestContext {
+        relayer: MockRelayer {
+            best_finalized_height: 20u64.into(),
+            ..Default::default()
+        },
+        ..TestContext::default_from_db(db)
+    };
+    let producer = ctx.producer();
+    let produce = |da_height: u64| {
+        producer.produce_and_execute_block(
+            prev_height + 1u32.into(),
+            Tai64::now(),
+            1_000_000_000,
+            Some(da_height.into()),
+        )
+    };
+
+    let result = produce(15).await.unwrap();
+    assert_eq!(result.result().block.header().da_height, 15u64.into());
+
+    let err = produce(5).await.expect_err("expected failure");
+    assert!(
+        matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::PinnedDaHeightBelowPrevious { .. })
+        ),
+        "unexpected err {err:?}"
+    );
+    let err = produce(25).await.expect_err("expected failure");
+    assert!(
+        matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::PinnedDaHeightNotFinalized { .. })
+        ),
+        "unexpected err {err:?}"
+    );
+}
+
 #[tokio::test]
 async fn production_fails_on_execution_error() {
     let ctx = TestContext {
@@ -180,7 +260,7 @@ async fn production_fails_on_execution_error() {
     let producer = ctx.producer();
 
     let err = producer
-        .produce_and_execute_block(1u32.into(), Tai64::now(), 1_000_000_000)
+        .produce_and_execute_block(1u32.into(), Tai64::now(), 1_000_000_000, None)
         .await
         .expect_err("expected failure");
 
@@ -193,6 +273,83 @@ async fn production_fails_on_execution_error() {
     );
 }
 
+#[tokio::test]
+async fn block_template_has_header_of_the_next_block() {
+    let ctx = TestContext::default();
+    let producer = ctx.producer();
+
+    let template = producer
+        .block_template(1u32.into(), Tai64(1), 1_000_000_000)
+        .await
+        .unwrap();
+
+    assert_eq!(*template.block.header().height(), 1u32.into());
+    assert_eq!(template.block.header().time(), Tai64(1));
+    assert_eq!(template.gas_used, 0);
+}
+
+#[tokio::test]
+async fn speculation_is_used_only_for_the_same_block() {
+    let mut ctx = TestContext::default();
+    let executor = Arc::new(CountingMockExecutor::new(MockExecutor(ctx.db.clone())));
+    ctx.executor = executor.clone();
+    let producer = Arc::new(ctx.producer());
+    let height = 1u32.into();
+    let parent = producer.db.clone();
+
+    producer
+        .speculate_next_block(&parent, height, Tai64(1), 1_000_000_000)
+        .unwrap();
+    let result = producer
+        .produce_and_execute_block(height, Tai64(1), 1_000_000_000, None)
+        .await
+        .unwrap();
+    assert_eq!(result.result().block.header().time(), Tai64(1));
+    assert!(producer.speculation.lock().unwrap().is_none());
+    assert_eq!(executor.speculations.load(Ordering::SeqCst), 1);
+    assert_eq!(executor.executions.load(Ordering::SeqCst), 0);
+
+    producer
+        .speculate_next_block(&parent, height, Tai64(1), 1_000_000_000)
+        .unwrap();
+    let result = producer
+        .produce_and_execute_block(height, Tai64(2), 1_000_000_000, None)
+        .await
+        .unwrap();
+    assert_eq!(result.result().block.header().time(), Tai64(2));
+    assert!(producer.speculation.lock().unwrap().is_none());
+    assert_eq!(executor.executions.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn execution_exceeding_budget_is_cancelled() {
+    let mut ctx = TestContext::default();
+    let executor = Arc::new(StuckMockExecutor::default());
+    ctx.executor = executor.clone();
+    let producer = ctx.producer();
+
+    let result = producer
+        .produce_and_execute_block_within(
+            1u32.into(),
+            Tai64::now(),
+            1_000_000_000,
+            None,
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.is_none());
+    let cancelled = async {
+        while !executor.cancelled.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), cancelled)
+        .await
+        .expect("The execution wasn't cancelled");
+}
+
 struct TestContext {
     config: Config,
     db: MockDb,
@@ -233,9 +390,11 @@ impl TestContext {
             config: self.config,
             db: self.db,
             txpool: Box::new(self.txpool),
+            tx_selector: Box::new(FeePriority),
             executor: self.executor,
             relayer: Box::new(self.relayer),
             lock: Default::default(),
+            speculation: Default::default(),
         }
     }
 }
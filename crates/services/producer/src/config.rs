@@ -1,8 +1,36 @@
-use fuel_core_types::fuel_types::Address;
+use crate::tx_selector::{
+    PriorityLane,
+    TxOrdering,
+};
+use fuel_core_types::fuel_types::{
+    Address,
+    BlockHeight,
+};
+use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
     pub utxo_validation: bool,
     pub coinbase_recipient: Address,
+    /// The recipients of fees that replace the `coinbase_recipient` from the height.
+    pub coinbase_rotations: BTreeMap<BlockHeight, Address>,
     pub metrics: bool,
+    /// Reserves the share of the gas of produced blocks for priority transactions.
+    pub priority_lane: Option<PriorityLane>,
+    /// The order of transactions of produced blocks.
+    pub tx_ordering: TxOrdering,
+    /// The limit of the size of produced blocks in bytes, including the header and the
+    /// coinbase, e.g. to fit blocks into the `max_block_size` of the p2p network.
+    pub max_block_size: Option<usize>,
+}
+
+impl Config {
+    /// Returns the recipient of fees of the block at the `height`.
+    pub fn coinbase_recipient_at(&self, height: BlockHeight) -> Address {
+        self.coinbase_rotations
+            .range(..=height)
+            .next_back()
+            .map(|(_, recipient)| *recipient)
+            .unwrap_or(self.coinbase_recipient)
+    }
 }
@@ -4,6 +4,7 @@
 pub mod block_producer;
 pub mod config;
 pub mod ports;
+pub mod tx_selector;
 
 pub use block_producer::Producer;
 pub use config::Config;
@@ -1,8 +1,14 @@
-use crate::ports::{
-    BlockProducerDatabase,
-    Executor,
-    Relayer,
-    TxPool,
+use crate::{
+    ports::{
+        BlockProducerDatabase,
+        Executor,
+        Relayer,
+        TxPool,
+    },
+    tx_selector::{
+        BlockLimits,
+        TxSelector,
+    },
 };
 use fuel_core_storage::{
     not_found,
@@ -41,9 +47,15 @@ use std::{
     borrow::Cow,
     collections::HashMap,
     sync::{
+        atomic::{
+            AtomicBool,
+            AtomicUsize,
+            Ordering,
+        },
         Arc,
         Mutex,
     },
+    time::Duration,
 };
 
 // TODO: Replace mocks with `mockall`.
@@ -73,9 +85,19 @@ impl TxPool for MockTxPool {
     fn get_includable_txs(
         &self,
         _block_height: BlockHeight,
-        _max_gas: u64,
+        limits: BlockLimits,
+        selector: &dyn TxSelector,
     ) -> Vec<ArcPoolTx> {
-        self.0.clone().into_iter().collect()
+        selector.select(self.0.clone(), limits)
+    }
+
+    fn peek_includable_txs(
+        &self,
+        _block_height: BlockHeight,
+        limits: BlockLimits,
+        selector: &dyn TxSelector,
+    ) -> Vec<ArcPoolTx> {
+        selector.select(self.0.clone(), limits)
     }
 }
 
@@ -127,6 +149,7 @@ impl Executor<MockDb> for MockExecutor {
     fn execute_without_commit(
         &self,
         block: ExecutionBlock,
+        _cancel: &AtomicBool,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
         let block = match block {
             ExecutionBlock::DryRun(block) => block.generate(&[]),
@@ -149,6 +172,98 @@ impl Executor<MockDb> for MockExecutor {
         ))
     }
 
+    fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        _parent: &MockDb,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.execute_without_commit(block, cancel)
+    }
+
+    fn dry_run(
+        &self,
+        _block: ExecutionBlock,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        Ok(Default::default())
+    }
+}
+
+/// Counts calls of the wrapped [`MockExecutor`].
+pub struct CountingMockExecutor {
+    pub executor: MockExecutor,
+    pub executions: AtomicUsize,
+    pub speculations: AtomicUsize,
+}
+
+impl CountingMockExecutor {
+    pub fn new(executor: MockExecutor) -> Self {
+        Self {
+            executor,
+            executions: AtomicUsize::new(0),
+            speculations: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl Executor<MockDb> for CountingMockExecutor {
+    fn execute_without_commit(
+        &self,
+        block: ExecutionBlock,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.executions.fetch_add(1, Ordering::SeqCst);
+        self.executor.execute_without_commit(block, cancel)
+    }
+
+    fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        parent: &MockDb,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.speculations.fetch_add(1, Ordering::SeqCst);
+        self.executor.execute_speculatively(block, parent, cancel)
+    }
+
+    fn dry_run(
+        &self,
+        block: ExecutionBlock,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        self.executor.dry_run(block, utxo_validation)
+    }
+}
+
+/// Executes blocks until the execution is cancelled.
+#[derive(Default)]
+pub struct StuckMockExecutor {
+    pub cancelled: AtomicBool,
+}
+
+impl Executor<MockDb> for StuckMockExecutor {
+    fn execute_without_commit(
+        &self,
+        _block: ExecutionBlock,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        while !cancel.load(Ordering::Relaxed) {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        self.cancelled.store(true, Ordering::SeqCst);
+        Err(ExecutorError::Cancelled)
+    }
+
+    fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        _parent: &MockDb,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.execute_without_commit(block, cancel)
+    }
+
     fn dry_run(
         &self,
         _block: ExecutionBlock,
@@ -164,6 +279,7 @@ impl Executor<MockDb> for FailingMockExecutor {
     fn execute_without_commit(
         &self,
         block: ExecutionBlock,
+        _cancel: &AtomicBool,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
         // simulate an execution failure
         let mut err = self.0.lock().unwrap();
@@ -186,6 +302,15 @@ impl Executor<MockDb> for FailingMockExecutor {
         }
     }
 
+    fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        _parent: &MockDb,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.execute_without_commit(block, cancel)
+    }
+
     fn dry_run(
         &self,
         _block: ExecutionBlock,
@@ -224,4 +349,8 @@ impl BlockProducerDatabase for MockDb {
 
         Ok(blocks.keys().max().cloned().unwrap_or_default())
     }
+
+    fn fork(&self) -> StorageResult<Self> {
+        Ok(self.clone())
+    }
 }
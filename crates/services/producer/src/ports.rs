@@ -1,3 +1,7 @@
+use crate::tx_selector::{
+    BlockLimits,
+    TxSelector,
+};
 use async_trait::async_trait;
 use fuel_core_storage::{
     transactional::StorageTransaction,
@@ -22,7 +26,10 @@ use fuel_core_types::{
         txpool::ArcPoolTx,
     },
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    sync::atomic::AtomicBool,
+};
 
 pub trait BlockProducerDatabase: Send + Sync {
     /// Gets the committed block at the `height`.
@@ -33,16 +40,34 @@ pub trait BlockProducerDatabase: Send + Sync {
 
     /// Fetch the current block height.
     fn current_block_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Returns the copy of the uncommitted state of the database transaction that isn't
+    /// affected by the commit of the transaction.
+    fn fork(&self) -> StorageResult<Self>
+    where
+        Self: Sized;
 }
 
 #[async_trait]
 pub trait TxPool: Send + Sync {
+    /// Returns transactions of the block chosen by the `selector` among the includable
+    /// transactions of the pool.
     fn get_includable_txs(
         &self,
         // could be used by the txpool to filter txs based on maturity
         block_height: BlockHeight,
-        // The upper limit for the total amount of gas of these txs
-        max_gas: u64,
+        // The upper limits for the total amount of gas and the size of these txs
+        limits: BlockLimits,
+        selector: &dyn TxSelector,
+    ) -> Vec<ArcPoolTx>;
+
+    /// Returns transactions chosen by the `selector` like [`Self::get_includable_txs`],
+    /// but doesn't select them, so they stay includable into other blocks.
+    fn peek_includable_txs(
+        &self,
+        block_height: BlockHeight,
+        limits: BlockLimits,
+        selector: &dyn TxSelector,
     ) -> Vec<ArcPoolTx>;
 }
 
@@ -58,10 +83,23 @@ pub trait Relayer: Send + Sync {
 
 pub trait Executor<Database>: Send + Sync {
     /// Executes the block and returns the result of execution with uncommitted database
-    /// transaction.
+    /// transaction. The execution stops before the next transaction once the `cancel` is
+    /// set.
     fn execute_without_commit(
         &self,
         block: ExecutionBlock,
+        cancel: &AtomicBool,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>>;
+
+    /// Executes the block on top of the uncommitted state of the `parent`, e.g. of the
+    /// previous block that is being imported. The result can be committed only after the
+    /// `parent` is committed. The execution stops before the next transaction once the
+    /// `cancel` is set.
+    fn execute_speculatively(
+        &self,
+        block: ExecutionBlock,
+        parent: &Database,
+        cancel: &AtomicBool,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>>;
 
     /// Executes the block without committing it to the database. During execution collects the
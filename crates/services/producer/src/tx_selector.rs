@@ -0,0 +1,805 @@
+//! The selection of transactions for the block among the includable transactions of
+//! the pool.
+
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            contract::Contract,
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Address,
+        ContractId,
+        Input,
+        Output,
+        TxId,
+    },
+    services::txpool::ArcPoolTx,
+};
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeSet,
+        BinaryHeap,
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+};
+
+/// The encoding overhead of each transaction of the block, on top of its metered size.
+pub const TX_ENCODING_OVERHEAD: usize = 16;
+
+/// The limits of the block that selected transactions must fit into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockLimits {
+    /// The limit of the total max gas of transactions.
+    pub max_gas: Word,
+    /// The limit of the total size of transactions in bytes, see [`encoded_size`].
+    pub max_size: usize,
+}
+
+impl BlockLimits {
+    /// Limits only the gas of transactions.
+    pub fn gas(max_gas: Word) -> Self {
+        Self {
+            max_gas,
+            max_size: usize::MAX,
+        }
+    }
+}
+
+/// The size of the transaction in the block, including the encoding overhead.
+pub fn encoded_size(tx: &ArcPoolTx) -> usize {
+    tx.metered_bytes_size().saturating_add(TX_ENCODING_OVERHEAD)
+}
+
+/// The gas and the size used by selected transactions.
+#[derive(Default, Debug, Clone, Copy)]
+struct Usage {
+    gas: Word,
+    size: usize,
+}
+
+impl Usage {
+    fn of(txs: &[ArcPoolTx]) -> Self {
+        txs.iter().fold(Self::default(), |usage, tx| Self {
+            gas: usage.gas.saturating_add(tx.max_gas()),
+            size: usage.size.saturating_add(encoded_size(tx)),
+        })
+    }
+
+    /// Adds the `tx` if it fits into the `limits` along with transactions used before.
+    fn try_add(&mut self, tx: &ArcPoolTx, limits: &BlockLimits) -> bool {
+        let gas = self.gas.checked_add(tx.max_gas());
+        let size = self.size.checked_add(encoded_size(tx));
+        match (gas, size) {
+            (Some(gas), Some(size))
+                if gas <= limits.max_gas && size <= limits.max_size =>
+            {
+                self.gas = gas;
+                self.size = size;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The policy of the block producer to choose transactions of the block.
+pub trait TxSelector: Send + Sync {
+    /// Returns transactions from the `candidates` that fit into the `limits`, in the
+    /// order of their inclusion into the block. The `candidates` are in the order of
+    /// their arrival to the pool.
+    fn select(&self, candidates: Vec<ArcPoolTx>, limits: BlockLimits) -> Vec<ArcPoolTx>;
+}
+
+/// The built-in ordering of transactions of produced blocks. Chains with other
+/// requirements can provide their own [`TxSelector`] to the producer.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxOrdering {
+    /// Transactions are included in the order of their arrival, see [`Fifo`].
+    Fifo,
+    /// Transactions paying more are included first, see [`FeePriority`].
+    #[default]
+    FeePriority,
+    /// Senders take turns to include their transactions, see [`SenderRoundRobin`].
+    SenderRoundRobin,
+}
+
+impl TxOrdering {
+    /// Returns the selector implementing the ordering.
+    pub fn selector(&self) -> Box<dyn TxSelector> {
+        match self {
+            TxOrdering::Fifo => Box::new(Fifo),
+            TxOrdering::FeePriority => Box::new(FeePriority),
+            TxOrdering::SenderRoundRobin => Box::new(SenderRoundRobin),
+        }
+    }
+}
+
+/// Selects transactions with the highest effective gas price first. The transaction
+/// spending outputs of other candidates or using contracts they create is selected only
+/// after all of them, so the block never spends the UTXO or calls the contract before
+/// it is created.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct FeePriority;
+
+impl TxSelector for FeePriority {
+    fn select(&self, candidates: Vec<ArcPoolTx>, limits: BlockLimits) -> Vec<ArcPoolTx> {
+        let mut children = vec![vec![]; candidates.len()];
+        let mut pending_parents = vec![0usize; candidates.len()];
+        for (index, parents) in parents(&candidates).into_iter().enumerate() {
+            pending_parents[index] = parents.len();
+            for parent in parents {
+                children[parent].push(index);
+            }
+        }
+
+        // Ties are broken by the order of candidates
+        let priority =
+            |index: usize| (effective_gas_price(&candidates[index]), Reverse(index));
+        let mut ready: BinaryHeap<_> = (0..candidates.len())
+            .filter(|index| pending_parents[*index] == 0)
+            .map(priority)
+            .collect();
+
+        let mut usage = Usage::default();
+        let mut selected = vec![];
+        while let Some((_, Reverse(index))) = ready.pop() {
+            let tx = &candidates[index];
+            // Children of the skipped transaction stay pending, because they can't be
+            // included without it.
+            if !usage.try_add(tx, &limits) {
+                continue
+            }
+            selected.push(tx.clone());
+            for child in &children[index] {
+                pending_parents[*child] -= 1;
+                if pending_parents[*child] == 0 {
+                    ready.push(priority(*child));
+                }
+            }
+        }
+        selected
+    }
+}
+
+/// Selects transactions in the order of their arrival to the pool. The transaction that
+/// doesn't fit into the block is skipped along with its children, and later
+/// transactions may still be selected.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Fifo;
+
+impl TxSelector for Fifo {
+    fn select(&self, candidates: Vec<ArcPoolTx>, limits: BlockLimits) -> Vec<ArcPoolTx> {
+        let parents = parents(&candidates);
+        let mut included = vec![false; candidates.len()];
+        let mut usage = Usage::default();
+        let mut selected = vec![];
+        for (index, tx) in candidates.iter().enumerate() {
+            // Parents arrive to the pool before their children.
+            if !parents[index].iter().all(|parent| included[*parent]) {
+                continue
+            }
+            if !usage.try_add(tx, &limits) {
+                continue
+            }
+            included[index] = true;
+            selected.push(tx.clone());
+        }
+        selected
+    }
+}
+
+/// Selects transactions of senders in turns, one transaction of each sender per turn,
+/// so the sender with many transactions can't crowd out others. Senders take turns in
+/// the order of arrival of their first transactions, and transactions of each sender
+/// keep the order of their arrival. The sender is the owner of the first coin or the
+/// recipient of the first message spent by the transaction.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct SenderRoundRobin;
+
+impl TxSelector for SenderRoundRobin {
+    fn select(&self, candidates: Vec<ArcPoolTx>, limits: BlockLimits) -> Vec<ArcPoolTx> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Decision {
+            Pending,
+            Included,
+            Skipped,
+        }
+
+        let parents = parents(&candidates);
+        let mut queues: Vec<VecDeque<usize>> = vec![];
+        let mut queue_of_sender = HashMap::new();
+        for (index, tx) in candidates.iter().enumerate() {
+            let queue = *queue_of_sender.entry(sender(tx)).or_insert_with(|| {
+                queues.push(VecDeque::new());
+                queues.len() - 1
+            });
+            queues[queue].push_back(index);
+        }
+
+        let mut decisions = vec![Decision::Pending; candidates.len()];
+        let mut usage = Usage::default();
+        let mut selected = vec![];
+        let mut progress = true;
+        while progress {
+            progress = false;
+            for queue in queues.iter_mut() {
+                while let Some(&index) = queue.front() {
+                    let decided = |decision| {
+                        parents[index]
+                            .iter()
+                            .any(|parent| decisions[*parent] == decision)
+                    };
+                    // The sender waits for the parent of another sender to be included.
+                    if !decided(Decision::Skipped) && decided(Decision::Pending) {
+                        break
+                    }
+                    queue.pop_front();
+                    progress = true;
+
+                    let fits = !decided(Decision::Skipped)
+                        && usage.try_add(&candidates[index], &limits);
+                    if fits {
+                        decisions[index] = Decision::Included;
+                        selected.push(candidates[index].clone());
+                        // The turn passes to the next sender.
+                        break
+                    }
+                    decisions[index] = Decision::Skipped;
+                }
+            }
+        }
+        selected
+    }
+}
+
+/// Selects transactions with the `inner` selector as if the `excluded` transactions
+/// were not in the pool, e.g. because the previous block that is not imported yet
+/// includes them.
+pub struct Excluding<'a> {
+    inner: &'a dyn TxSelector,
+    excluded: HashSet<TxId>,
+}
+
+impl<'a> Excluding<'a> {
+    pub fn new(inner: &'a dyn TxSelector, excluded: HashSet<TxId>) -> Self {
+        Self { inner, excluded }
+    }
+}
+
+impl TxSelector for Excluding<'_> {
+    fn select(
+        &self,
+        mut candidates: Vec<ArcPoolTx>,
+        limits: BlockLimits,
+    ) -> Vec<ArcPoolTx> {
+        candidates.retain(|tx| !self.excluded.contains(&tx.id()));
+        self.inner.select(candidates, limits)
+    }
+}
+
+/// Reserves the share of the gas of the block for priority transactions, so large
+/// batches of cheap transactions can't crowd out urgent ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityLane {
+    /// The percentage of the gas of the block that only priority transactions can use.
+    pub reserved_gas_percentage: u8,
+    /// Transactions paying at least this gas price are priority transactions.
+    pub min_gas_price: Word,
+}
+
+/// Applies the [`PriorityLane`] to the selection of the `inner` selector. Priority
+/// transactions are selected first against the whole limits of the block. Then regular
+/// transactions fill the gas and the size left by priority transactions, except the
+/// reserved gas.
+/// The priority transaction spending outputs of regular candidates is selected along
+/// with regular transactions.
+pub struct ReservedLane<'a> {
+    inner: &'a dyn TxSelector,
+    lane: PriorityLane,
+}
+
+impl<'a> ReservedLane<'a> {
+    pub fn new(inner: &'a dyn TxSelector, lane: PriorityLane) -> Self {
+        Self { inner, lane }
+    }
+}
+
+impl TxSelector for ReservedLane<'_> {
+    fn select(&self, candidates: Vec<ArcPoolTx>, limits: BlockLimits) -> Vec<ArcPoolTx> {
+        let percentage = self.lane.reserved_gas_percentage.min(100) as u128;
+        let reserved_gas = (limits.max_gas as u128 * percentage / 100) as Word;
+
+        // Parents arrive to the pool before their children.
+        let parents = parents(&candidates);
+        let mut is_priority = vec![false; candidates.len()];
+        for (index, tx) in candidates.iter().enumerate() {
+            is_priority[index] = effective_gas_price(tx) >= self.lane.min_gas_price
+                && parents[index].iter().all(|parent| is_priority[*parent]);
+        }
+        let priority = candidates
+            .iter()
+            .zip(is_priority)
+            .filter(|(_, is_priority)| *is_priority)
+            .map(|(tx, _)| tx.clone())
+            .collect();
+        let mut selected = self.inner.select(priority, limits);
+
+        let priority_usage = Usage::of(&selected);
+        let regular_limits = BlockLimits {
+            max_gas: limits
+                .max_gas
+                .saturating_sub(reserved_gas.max(priority_usage.gas)),
+            max_size: limits.max_size.saturating_sub(priority_usage.size),
+        };
+        let selected_ids: HashSet<_> = selected.iter().map(|tx| tx.id()).collect();
+        let regular = candidates
+            .into_iter()
+            .filter(|tx| !selected_ids.contains(&tx.id()))
+            .collect();
+        selected.extend(self.inner.select(regular, regular_limits));
+        selected
+    }
+}
+
+/// Returns indexes of parents of every candidate, i.e. of candidates creating UTXOs that
+/// the candidate spends or contracts that the candidate uses.
+fn parents(candidates: &[ArcPoolTx]) -> Vec<BTreeSet<usize>> {
+    let indexes: HashMap<TxId, usize> = candidates
+        .iter()
+        .enumerate()
+        .map(|(index, tx)| (tx.id(), index))
+        .collect();
+    let creators: HashMap<ContractId, usize> = candidates
+        .iter()
+        .enumerate()
+        .flat_map(|(index, tx)| {
+            created_contracts(tx).map(move |contract_id| (*contract_id, index))
+        })
+        .collect();
+    candidates
+        .iter()
+        .map(|tx| {
+            let utxo_parents = tx
+                .inputs()
+                .iter()
+                .filter_map(|input| input.utxo_id())
+                .filter_map(|utxo_id| indexes.get(utxo_id.tx_id()).copied());
+            let contract_parents = used_contracts(tx)
+                .filter_map(|contract_id| creators.get(contract_id).copied());
+            utxo_parents.chain(contract_parents).collect()
+        })
+        .collect()
+}
+
+/// The contracts created by the `tx`.
+fn created_contracts(tx: &ArcPoolTx) -> impl Iterator<Item = &ContractId> {
+    tx.outputs().iter().filter_map(|output| match output {
+        Output::ContractCreated { contract_id, .. } => Some(contract_id),
+        _ => None,
+    })
+}
+
+/// The contracts used by the `tx`.
+fn used_contracts(tx: &ArcPoolTx) -> impl Iterator<Item = &ContractId> {
+    tx.inputs().iter().filter_map(|input| match input {
+        Input::Contract(Contract { contract_id, .. }) => Some(contract_id),
+        _ => None,
+    })
+}
+
+/// The owner of the first coin or the recipient of the first message spent by the `tx`.
+fn sender(tx: &ArcPoolTx) -> Option<Address> {
+    tx.inputs().iter().find_map(|input| match input {
+        Input::CoinSigned(CoinSigned { owner, .. })
+        | Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(*owner),
+        Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+        | Input::MessageCoinPredicate(MessageCoinPredicate { recipient, .. })
+        | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+        | Input::MessageDataPredicate(MessageDataPredicate { recipient, .. }) => {
+            Some(*recipient)
+        }
+        Input::Contract(_) => None,
+    })
+}
+
+/// The price per unit of gas that the transaction pays to the block producer.
+fn effective_gas_price(tx: &ArcPoolTx) -> Word {
+    tx.price()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_crypto::SecretKey,
+        fuel_tx,
+        fuel_tx::{
+            ConsensusParameters,
+            TransactionBuilder,
+            UtxoId,
+        },
+        fuel_vm::checked_transaction::builder::TransactionBuilderExt,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::sync::Arc;
+
+    fn tx(price: Word, limit: Word, utxo_id: UtxoId) -> ArcPoolTx {
+        tx_of(Default::default(), price, limit, utxo_id)
+    }
+
+    fn tx_of(secret: SecretKey, price: Word, limit: Word, utxo_id: UtxoId) -> ArcPoolTx {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(price)
+        .gas_limit(limit)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .with_params(ConsensusParameters {
+            gas_price_factor: 1,
+            ..ConsensusParameters::default()
+        })
+        .finalize_checked_basic(Default::default());
+        Arc::new(tx.into())
+    }
+
+    fn large_tx(price: Word, utxo_id: UtxoId) -> ArcPoolTx {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![0; 1024],
+        )
+        .gas_price(price)
+        .gas_limit(1000)
+        .add_unsigned_coin_input(
+            Default::default(),
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .with_params(ConsensusParameters {
+            gas_price_factor: 1,
+            ..ConsensusParameters::default()
+        })
+        .finalize_checked_basic(Default::default());
+        Arc::new(tx.into())
+    }
+
+    fn create_tx(price: Word, utxo_id: UtxoId, contract_id: ContractId) -> ArcPoolTx {
+        let tx = TransactionBuilder::create(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .gas_price(price)
+        .gas_limit(1000)
+        .add_unsigned_coin_input(
+            Default::default(),
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::contract_created(
+            contract_id,
+            fuel_tx::Contract::default_state_root(),
+        ))
+        .with_params(ConsensusParameters {
+            gas_price_factor: 1,
+            ..ConsensusParameters::default()
+        })
+        .finalize_checked_basic(Default::default());
+        Arc::new(tx.into())
+    }
+
+    fn call_tx(price: Word, utxo_id: UtxoId, contract_id: ContractId) -> ArcPoolTx {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(price)
+        .gas_limit(1000)
+        .add_unsigned_coin_input(
+            Default::default(),
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_input(Input::contract(
+            UtxoId::new([9; 32].into(), 0),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .with_params(ConsensusParameters {
+            gas_price_factor: 1,
+            ..ConsensusParameters::default()
+        })
+        .finalize_checked_basic(Default::default());
+        Arc::new(tx.into())
+    }
+
+    fn prices(txs: &[ArcPoolTx]) -> Vec<Word> {
+        txs.iter().map(|tx| tx.price()).collect()
+    }
+
+    #[test]
+    fn selects_transactions_by_gas_price() {
+        let candidates = vec![
+            tx(1, 1000, UtxoId::new([1; 32].into(), 0)),
+            tx(3, 1000, UtxoId::new([2; 32].into(), 0)),
+            tx(2, 1000, UtxoId::new([3; 32].into(), 0)),
+        ];
+        let limits = BlockLimits::gas(candidates[0].max_gas() * 2);
+
+        let selected = FeePriority.select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![3, 2]);
+    }
+
+    #[test]
+    fn selects_child_after_its_parent() {
+        let parent = tx(1, 1000, UtxoId::new([1; 32].into(), 0));
+        let child = tx(5, 1000, UtxoId::new(parent.id(), 0));
+        let other = tx(3, 1000, UtxoId::new([2; 32].into(), 0));
+
+        let selected =
+            FeePriority.select(vec![child, other, parent], BlockLimits::gas(u64::MAX));
+
+        assert_eq!(prices(&selected), vec![3, 1, 5]);
+    }
+
+    #[test]
+    fn skips_child_of_skipped_parent() {
+        let parent = tx(1, 1000, UtxoId::new([1; 32].into(), 0));
+        let child = tx(5, 1000, UtxoId::new(parent.id(), 0));
+        let other = tx(3, 1000, UtxoId::new([2; 32].into(), 0));
+        let limits = BlockLimits::gas(other.max_gas());
+
+        let selected = FeePriority.select(vec![child, other, parent], limits);
+
+        assert_eq!(prices(&selected), vec![3]);
+    }
+
+    #[test]
+    fn selects_contract_call_after_contract_creation() {
+        let contract_id = ContractId::from([7; 32]);
+        let create = create_tx(1, UtxoId::new([1; 32].into(), 0), contract_id);
+        let call = call_tx(5, UtxoId::new([2; 32].into(), 0), contract_id);
+
+        let selected = FeePriority.select(
+            vec![call.clone(), create.clone()],
+            BlockLimits::gas(u64::MAX),
+        );
+
+        assert_eq!(prices(&selected), vec![1, 5]);
+
+        // The call is not selected without the creation that doesn't fit.
+        let limits = BlockLimits {
+            max_gas: u64::MAX,
+            max_size: encoded_size(&create) - 1,
+        };
+        let selected = FeePriority.select(vec![call, create], limits);
+
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn fifo_selects_transactions_by_arrival() {
+        let candidates = vec![
+            tx(1, 1000, UtxoId::new([1; 32].into(), 0)),
+            tx(3, 1000, UtxoId::new([2; 32].into(), 0)),
+            tx(2, 1000, UtxoId::new([3; 32].into(), 0)),
+        ];
+        let limits = BlockLimits::gas(candidates[0].max_gas() * 2);
+
+        let selected = Fifo.select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![1, 3]);
+    }
+
+    #[test]
+    fn fifo_skips_child_of_skipped_parent() {
+        let parent = tx(1, 2000, UtxoId::new([1; 32].into(), 0));
+        let child = tx(5, 1000, UtxoId::new(parent.id(), 0));
+        let other = tx(3, 1000, UtxoId::new([2; 32].into(), 0));
+        let limits = BlockLimits::gas(other.max_gas());
+
+        let selected = Fifo.select(vec![parent, child, other], limits);
+
+        assert_eq!(prices(&selected), vec![3]);
+    }
+
+    #[test]
+    fn senders_take_turns() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let alice = SecretKey::random(&mut rng);
+        let bob = SecretKey::random(&mut rng);
+        let candidates = vec![
+            tx_of(alice, 1, 1000, UtxoId::new([1; 32].into(), 0)),
+            tx_of(alice, 2, 1000, UtxoId::new([2; 32].into(), 0)),
+            tx_of(alice, 3, 1000, UtxoId::new([3; 32].into(), 0)),
+            tx_of(bob, 10, 1000, UtxoId::new([4; 32].into(), 0)),
+        ];
+        let limits = BlockLimits::gas(candidates[0].max_gas() * 3);
+
+        let selected = SenderRoundRobin.select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![1, 10, 2]);
+    }
+
+    #[test]
+    fn excluded_transactions_are_not_selected() {
+        let included = tx(3, 1000, UtxoId::new([1; 32].into(), 0));
+        let other = tx(2, 1000, UtxoId::new([2; 32].into(), 0));
+        let excluded = [included.id()].into_iter().collect();
+
+        let selected = Excluding::new(&FeePriority, excluded)
+            .select(vec![included, other], BlockLimits::gas(u64::MAX));
+
+        assert_eq!(prices(&selected), vec![2]);
+    }
+
+    #[test]
+    fn regular_transactions_do_not_use_reserved_gas() {
+        let candidates: Vec<_> = (1..=4)
+            .map(|i| tx(1, 1000, UtxoId::new([i; 32].into(), 0)))
+            .collect();
+        let limits = BlockLimits::gas(candidates[0].max_gas() * 4);
+        let lane = PriorityLane {
+            reserved_gas_percentage: 50,
+            min_gas_price: 10,
+        };
+
+        let selected = ReservedLane::new(&FeePriority, lane).select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![1, 1]);
+    }
+
+    #[test]
+    fn priority_transactions_use_reserved_gas() {
+        let candidates = vec![
+            tx(1, 1000, UtxoId::new([1; 32].into(), 0)),
+            tx(1, 1000, UtxoId::new([2; 32].into(), 0)),
+            tx(10, 1000, UtxoId::new([3; 32].into(), 0)),
+            tx(10, 1000, UtxoId::new([4; 32].into(), 0)),
+        ];
+        let limits = BlockLimits::gas(candidates[0].max_gas() * 3);
+        let lane = PriorityLane {
+            reserved_gas_percentage: 50,
+            min_gas_price: 10,
+        };
+
+        let selected = ReservedLane::new(&FeePriority, lane).select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![10, 10, 1]);
+    }
+
+    #[test]
+    fn priority_transactions_are_selected_before_earlier_regular_ones() {
+        let candidates = vec![
+            tx(1, 1000, UtxoId::new([1; 32].into(), 0)),
+            tx(1, 1000, UtxoId::new([2; 32].into(), 0)),
+            tx(1, 1000, UtxoId::new([3; 32].into(), 0)),
+            tx(10, 1000, UtxoId::new([4; 32].into(), 0)),
+        ];
+        let limits = BlockLimits::gas(candidates[0].max_gas() * 3);
+        let lane = PriorityLane {
+            reserved_gas_percentage: 33,
+            min_gas_price: 10,
+        };
+
+        let selected = ReservedLane::new(&Fifo, lane).select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![10, 1, 1]);
+    }
+
+    #[test]
+    fn priority_child_of_regular_parent_is_selected_after_it() {
+        let parent = tx(1, 1000, UtxoId::new([1; 32].into(), 0));
+        let child = tx(10, 1000, UtxoId::new(parent.id(), 0));
+        let limits = BlockLimits::gas(parent.max_gas() * 3);
+        let lane = PriorityLane {
+            reserved_gas_percentage: 33,
+            min_gas_price: 10,
+        };
+
+        let selected =
+            ReservedLane::new(&FeePriority, lane).select(vec![parent, child], limits);
+
+        assert_eq!(prices(&selected), vec![1, 10]);
+    }
+
+    #[test]
+    fn transactions_over_max_size_are_skipped_for_smaller_ones() {
+        let large = large_tx(10, UtxoId::new([1; 32].into(), 0));
+        let small = tx(3, 1000, UtxoId::new([2; 32].into(), 0));
+        let other = tx(2, 1000, UtxoId::new([3; 32].into(), 0));
+        let limits = BlockLimits {
+            max_gas: small.max_gas() + other.max_gas(),
+            max_size: encoded_size(&small) + encoded_size(&other),
+        };
+
+        let selected = FeePriority.select(vec![large, small, other], limits);
+
+        assert_eq!(prices(&selected), vec![3, 2]);
+    }
+
+    #[test]
+    fn children_of_transactions_over_max_size_are_not_selected() {
+        let parent = tx(3, 1000, UtxoId::new([1; 32].into(), 0));
+        let child = tx(5, 1000, UtxoId::new(parent.id(), 0));
+        let limits = BlockLimits {
+            max_gas: u64::MAX,
+            max_size: encoded_size(&parent) - 1,
+        };
+
+        let selected = FeePriority.select(vec![parent, child], limits);
+
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn regular_transactions_fit_into_size_left_by_priority_ones() {
+        let candidates = vec![
+            tx(1, 1000, UtxoId::new([1; 32].into(), 0)),
+            tx(1, 1000, UtxoId::new([2; 32].into(), 0)),
+            tx(10, 1000, UtxoId::new([3; 32].into(), 0)),
+        ];
+        let limits = BlockLimits {
+            max_gas: u64::MAX,
+            max_size: encoded_size(&candidates[0]) * 2,
+        };
+        let lane = PriorityLane {
+            reserved_gas_percentage: 0,
+            min_gas_price: 10,
+        };
+
+        let selected = ReservedLane::new(&FeePriority, lane).select(candidates, limits);
+
+        assert_eq!(prices(&selected), vec![10, 1]);
+    }
+}
@@ -3,6 +3,7 @@ use ethers_core::types::{
     H160,
     H256,
 };
+use fuel_core_services::RestartPolicy;
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 use once_cell::sync::Lazy;
 use std::{
@@ -38,6 +39,14 @@ pub struct Config {
     /// How often progress logs are printed when the DA node is
     /// syncing.
     pub syncing_log_frequency: Duration,
+    /// The L1 wallet that pays for commitments to the DA layer.
+    /// If set, the relayer monitors its balance.
+    pub wallet_address: Option<H160>,
+    /// How the relayer restarts after failures, e.g. when the DA node is unreachable.
+    pub restart_policy: RestartPolicy,
+    /// If set, an iteration of the relayer task that runs longer is cancelled and
+    /// handled as a failure.
+    pub watchdog: Option<Duration>,
 
     /// Enables metrics on this fuel service
     pub metrics: bool,
@@ -67,6 +76,9 @@ impl Default for Config {
             sync_minimum_duration: Self::DEFAULT_SYNC_MINIMUM_DURATION,
             syncing_call_frequency: Self::DEFAULT_SYNCING_CALL_FREQ,
             syncing_log_frequency: Self::DEFAULT_SYNCING_LOG_FREQ,
+            wallet_address: None,
+            restart_policy: RestartPolicy::default(),
+            watchdog: None,
             metrics: false,
         }
     }
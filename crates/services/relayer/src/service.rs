@@ -14,6 +14,7 @@ use ethers_core::types::{
     SyncingStatus,
     ValueOrArray,
     H160,
+    U256,
 };
 use ethers_providers::{
     Http,
@@ -21,10 +22,13 @@ use ethers_providers::{
     Provider,
     ProviderError,
 };
+use fuel_core_metrics::relayer_metrics::RELAYER_METRICS;
 use fuel_core_services::{
+    RestartableService,
     RunnableService,
     RunnableTask,
     ServiceRunner,
+    ServiceRunnerBuilder,
     StateWatcher,
 };
 use fuel_core_storage::{
@@ -42,6 +46,7 @@ use std::{
     borrow::Cow,
     convert::TryInto,
     ops::Deref,
+    sync::Arc,
 };
 use synced::update_synced;
 use tokio::sync::watch;
@@ -61,7 +66,9 @@ mod syncing;
 mod test;
 
 type Synced = watch::Receiver<Option<DaBlockHeight>>;
-type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
+type NotifySynced = Arc<watch::Sender<Option<DaBlockHeight>>>;
+type WalletBalance = watch::Receiver<Option<u64>>;
+type NotifyWalletBalance = Arc<watch::Sender<Option<u64>>>;
 
 /// The alias of runnable relayer service.
 pub type Service<D> = CustomizableService<Provider<Http>, D>;
@@ -72,6 +79,10 @@ type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Receives the finalized height of the DA layer seen during the last sync.
+    remote_finalized: Synced,
+    /// Receives the balance of the monitored wallet in gwei.
+    wallet_balance: WalletBalance,
     database: D,
 }
 
@@ -79,6 +90,10 @@ pub struct SharedState<D> {
 pub struct NotInitializedTask<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the finalized height of the DA layer.
+    remote_finalized: NotifySynced,
+    /// Sends the balance of the monitored wallet in gwei.
+    wallet_balance: NotifyWalletBalance,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -91,6 +106,10 @@ pub struct NotInitializedTask<P, D> {
 pub struct Task<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the finalized height of the DA layer.
+    remote_finalized: NotifySynced,
+    /// Sends the balance of the monitored wallet in gwei.
+    wallet_balance: NotifyWalletBalance,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -106,8 +125,12 @@ impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
     fn new(eth_node: P, database: D, config: Config) -> Self {
         let (synced, _) = watch::channel(None);
+        let (remote_finalized, _) = watch::channel(None);
+        let (wallet_balance, _) = watch::channel(None);
         Self {
-            synced,
+            synced: Arc::new(synced),
+            remote_finalized: Arc::new(remote_finalized),
+            wallet_balance: Arc::new(wallet_balance),
             eth_node,
             database,
             config,
@@ -126,6 +149,41 @@ where
     }
 }
 
+impl<P, D> Task<P, D>
+where
+    P: Middleware<Error = ProviderError>,
+{
+    /// Fetches the balance of the monitored wallet, if any, and publishes it.
+    /// Failures are logged because they shouldn't stop the sync with the DA layer.
+    async fn update_wallet_balance(&self) {
+        if let Some(address) = self.config.wallet_address {
+            match self.eth_node.get_balance(address, None).await {
+                Ok(balance) => {
+                    let gwei = balance / U256::exp10(9);
+                    let gwei = if gwei > U256::from(u64::MAX) {
+                        u64::MAX
+                    } else {
+                        gwei.as_u64()
+                    };
+                    if self.config.metrics {
+                        RELAYER_METRICS
+                            .wallet_balance_gwei
+                            .set(i64::try_from(gwei).unwrap_or(i64::MAX));
+                    }
+                    self.wallet_balance.send_replace(Some(gwei));
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to get the balance of the wallet {:?}: {}",
+                        address,
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl<P, D> RelayerData for Task<P, D>
 where
@@ -164,7 +222,13 @@ where
     }
 
     fn update_synced(&self, state: &state::EthState) {
-        update_synced(&self.synced, state)
+        self.remote_finalized
+            .send_replace(Some(state.remote_finalized().into()));
+        update_synced(&self.synced, state);
+        // The relayer is ready after the first sync with the DA layer.
+        if self.synced.borrow().is_some() {
+            self.shutdown.ready_signal().ready();
+        }
     }
 }
 
@@ -175,6 +239,7 @@ where
     D: RelayerDb + Clone + 'static,
 {
     const NAME: &'static str = "Relayer";
+    const READY_ON_START: bool = false;
 
     type SharedData = SharedState<D>;
     type Task = Task<P, D>;
@@ -182,9 +247,13 @@ where
 
     fn shared_data(&self) -> Self::SharedData {
         let synced = self.synced.subscribe();
+        let remote_finalized = self.remote_finalized.subscribe();
+        let wallet_balance = self.wallet_balance.subscribe();
 
         SharedState {
             synced,
+            remote_finalized,
+            wallet_balance,
             database: self.database.clone(),
         }
     }
@@ -197,12 +266,16 @@ where
         let shutdown = watcher.clone();
         let NotInitializedTask {
             synced,
+            remote_finalized,
+            wallet_balance,
             eth_node,
             database,
             config,
         } = self;
         let mut task = Task {
             synced,
+            remote_finalized,
+            wallet_balance,
             eth_node,
             database,
             config,
@@ -214,6 +287,24 @@ where
     }
 }
 
+/// The restarted relayer keeps notifying the subscribers of the failed one.
+impl<P, D> RestartableService for NotInitializedTask<P, D>
+where
+    P: Middleware<Error = ProviderError> + Clone + 'static,
+    D: RelayerDb + Clone + 'static,
+{
+    fn rebuild(&self) -> Self {
+        Self {
+            synced: self.synced.clone(),
+            remote_finalized: self.remote_finalized.clone(),
+            wallet_balance: self.wallet_balance.clone(),
+            eth_node: self.eth_node.clone(),
+            database: self.database.clone(),
+            config: self.config.clone(),
+        }
+    }
+}
+
 #[async_trait]
 impl<P, D> RunnableTask for Task<P, D>
 where
@@ -225,6 +316,7 @@ where
         let should_continue = true;
 
         let result = run::run(self).await;
+        self.update_wallet_balance().await;
 
         if self.shutdown.borrow_and_update().started() && self.synced.borrow().is_some() {
             // Sleep the loop so the da node is not spammed.
@@ -304,6 +396,28 @@ impl<D> SharedState<D> {
     {
         Ok(self.database.get_finalized_da_height()?)
     }
+
+    /// Get the number of finalized blocks of the da layer that are not synced yet.
+    /// Returns `None` if the relayer didn't reach the da layer yet.
+    pub fn da_lag(&self) -> anyhow::Result<Option<u64>>
+    where
+        D: RelayerDb + 'static,
+    {
+        let remote = *self.remote_finalized.borrow();
+        match remote {
+            Some(remote) => {
+                let local = self.database.get_finalized_da_height()?;
+                Ok(Some((*remote).saturating_sub(*local)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Get the balance of the monitored wallet in gwei.
+    /// Returns `None` if no wallet is monitored or the balance is not known yet.
+    pub fn wallet_balance(&self) -> Option<u64> {
+        *self.wallet_balance.borrow()
+    }
 }
 
 #[async_trait]
@@ -365,7 +479,7 @@ pub fn new_service_test<P, D>(
     config: Config,
 ) -> CustomizableService<P, D>
 where
-    P: Middleware<Error = ProviderError> + 'static,
+    P: Middleware<Error = ProviderError> + Clone + 'static,
     D: RelayerDb + Clone + 'static,
 {
     new_service_internal(eth_node, database, config)
@@ -377,10 +491,15 @@ fn new_service_internal<P, D>(
     config: Config,
 ) -> CustomizableService<P, D>
 where
-    P: Middleware<Error = ProviderError> + 'static,
+    P: Middleware<Error = ProviderError> + Clone + 'static,
     D: RelayerDb + Clone + 'static,
 {
+    let restart_policy = config.restart_policy;
+    let watchdog = config.watchdog;
     let task = NotInitializedTask::new(eth_node, database, config);
 
-    CustomizableService::new(task)
+    ServiceRunnerBuilder::new(task, ())
+        .restart_policy(restart_policy)
+        .watchdog(watchdog)
+        .build()
 }
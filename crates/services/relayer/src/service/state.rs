@@ -56,6 +56,11 @@ impl EthState {
         self.local.filter(|local| *local >= self.remote.finalized())
     }
 
+    /// Get the finalized block height of the Ethereum node.
+    pub fn remote_finalized(&self) -> u64 {
+        self.remote.finalized()
+    }
+
     /// Get the gap between the relayer and the Ethereum node if
     /// a sync is required.
     pub fn needs_to_sync_eth(&self) -> Option<EthSyncGap> {
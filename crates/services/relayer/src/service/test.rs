@@ -76,3 +76,22 @@ async fn deploy_height_does_override() {
 
     assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 52);
 }
+
+#[tokio::test]
+async fn wallet_balance_is_published_in_gwei() {
+    let mock_db = crate::mock_db::MockDb::default();
+    let config = Config {
+        wallet_address: Some(H160::zero()),
+        ..Default::default()
+    };
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.balance = U256::exp10(18));
+    let relayer = NotInitializedTask::new(eth_node, mock_db, config);
+    let shared = relayer.shared_data();
+    let task = relayer.into_task(&Default::default(), ()).await.unwrap();
+    assert_eq!(shared.wallet_balance(), None);
+
+    task.update_wallet_balance().await;
+
+    assert_eq!(shared.wallet_balance(), Some(1_000_000_000));
+}
@@ -3,12 +3,14 @@ use ethers_core::types::{
     Block,
     BlockId,
     Filter,
+    NameOrAddress,
     Log,
     SyncingStatus,
     Transaction,
     TransactionReceipt,
     TxHash,
     H256,
+    U256,
     U64,
 };
 use ethers_providers::{
@@ -53,6 +55,7 @@ pub struct MockData {
     pub best_block: Block<TxHash>,
     pub logs_batch: Vec<Vec<Log>>,
     pub logs_batch_index: usize,
+    pub balance: U256,
 }
 
 impl MockMiddleware {
@@ -143,6 +146,7 @@ impl Default for MockData {
             is_syncing: SyncingStatus::IsFalse,
             logs_batch: Vec::new(),
             logs_batch_index: 0,
+            balance: U256::zero(),
         }
     }
 }
@@ -178,6 +182,7 @@ pub enum TriggerType<'a> {
     GetBlockNumber,
     GetLogs(&'a Filter),
     GetBlock(BlockId),
+    GetBalance,
     GetLogFilterChanges,
     GetBlockFilterChanges,
     Send,
@@ -302,4 +307,17 @@ impl Middleware for MockMiddleware {
         self.after_event(TriggerType::GetBlock(block_id));
         r
     }
+
+    /// Used to monitor the balance of the wallet.
+    async fn get_balance<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        _: T,
+        _: Option<BlockId>,
+    ) -> Result<U256, Self::Error> {
+        tokio::task::yield_now().await;
+        self.before_event(TriggerType::GetBalance);
+        let r = Ok(self.update_data(|data| data.balance));
+        self.after_event(TriggerType::GetBalance);
+        r
+    }
 }
@@ -2,7 +2,9 @@
 #![deny(unused_crate_dependencies)]
 #![deny(missing_docs)]
 
+mod orchestrator;
 mod service;
+mod shared_state;
 mod state;
 
 /// Re-exports for streaming utilities
@@ -30,18 +32,226 @@ pub mod stream {
     }
 
     impl<S> IntoBoxStream for S where S: Stream + Send + Sync + 'static {}
+
+    /// Groups items of the `stream` into batches of at most `max_items` items. The batch
+    /// is started by the first available item and is yielded when it is full, when the
+    /// `max_delay` since its first item is elapsed or when the `stream` ends. Items that
+    /// are ready right away are added to the batch even with the zero `max_delay`.
+    ///
+    /// The batch is collected only when the consumer polls the next batch, so a slow
+    /// consumer doesn't pull more than `max_items` items from the `stream` at a time.
+    pub fn batch_within<T>(
+        stream: BoxStream<T>,
+        max_items: usize,
+        max_delay: std::time::Duration,
+    ) -> BoxStream<Vec<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        use futures::StreamExt;
+
+        let max_items = max_items.max(1);
+        unfold(Some(stream), move |stream| async move {
+            let mut stream = stream?;
+            let first = stream.next().await?;
+            let mut batch = Vec::with_capacity(max_items);
+            batch.push(first);
+
+            let deadline = tokio::time::sleep(max_delay);
+            tokio::pin!(deadline);
+            let mut ended = false;
+            while batch.len() < max_items {
+                tokio::select! {
+                    biased;
+
+                    item = stream.next() => match item {
+                        Some(item) => batch.push(item),
+                        None => {
+                            ended = true;
+                            break
+                        }
+                    },
+                    _ = &mut deadline => break,
+                }
+            }
+
+            let stream = (!ended).then_some(stream);
+            Some((batch, stream))
+        })
+        .into_boxed()
+    }
+
+    /// Limits the `stream` to at most one item per `period`. The first item is yielded
+    /// right away, while items that arrive before the end of the `period` replace each
+    /// other, and only the latest of them is yielded when the `period` is elapsed.
+    pub fn throttle<T>(stream: BoxStream<T>, period: std::time::Duration) -> BoxStream<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        use futures::StreamExt;
+        use tokio::time::Instant;
+
+        // The stream and the earliest time when the next item can be yielded.
+        unfold(Some((stream, Instant::now())), move |state| async move {
+            let (mut stream, not_before) = state?;
+            let mut latest = stream.next().await?;
+
+            let deadline = tokio::time::sleep_until(not_before);
+            tokio::pin!(deadline);
+            let mut ended = false;
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut deadline => break,
+                    item = stream.next(), if !ended => match item {
+                        Some(item) => latest = item,
+                        None => ended = true,
+                    },
+                }
+            }
+
+            let state = (!ended).then(|| (stream, Instant::now() + period));
+            Some((latest, state))
+        })
+        .into_boxed()
+    }
+
+    /// Yields the latest item of the `stream` once no new items arrive for the `quiet`
+    /// period. The item is yielded right away when the `stream` ends. The continuous
+    /// flood of items postpones the item until the flood stops, so use [`throttle`] if
+    /// consumers need regular updates.
+    pub fn debounce<T>(stream: BoxStream<T>, quiet: std::time::Duration) -> BoxStream<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        use futures::StreamExt;
+
+        unfold(Some(stream), move |stream| async move {
+            let mut stream = stream?;
+            let mut latest = stream.next().await?;
+
+            let mut ended = false;
+            loop {
+                tokio::select! {
+                    biased;
+
+                    item = stream.next() => match item {
+                        Some(item) => latest = item,
+                        None => {
+                            ended = true;
+                            break
+                        }
+                    },
+                    _ = tokio::time::sleep(quiet) => break,
+                }
+            }
+
+            let stream = (!ended).then_some(stream);
+            Some((latest, stream))
+        })
+        .into_boxed()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use futures::StreamExt;
+        use std::time::Duration;
+
+        #[tokio::test]
+        async fn batch_within_splits_by_max_items() {
+            let stream = futures::stream::iter(0..5).into_boxed();
+
+            let batches: Vec<_> =
+                batch_within(stream, 2, Duration::from_secs(1)).collect().await;
+
+            assert_eq!(batches, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        }
+
+        #[tokio::test]
+        async fn batch_within_yields_after_max_delay() {
+            let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+            let stream = unfold(receiver, |mut receiver| async move {
+                let item = receiver.recv().await?;
+                Some((item, receiver))
+            })
+            .into_boxed();
+            let mut batches = batch_within(stream, 10, Duration::from_millis(10));
+
+            sender.send(1).unwrap();
+            sender.send(2).unwrap();
+            assert_eq!(batches.next().await, Some(vec![1, 2]));
+
+            sender.send(3).unwrap();
+            drop(sender);
+            assert_eq!(batches.next().await, Some(vec![3]));
+            assert_eq!(batches.next().await, None);
+        }
+
+        #[tokio::test]
+        async fn throttle_yields_latest_item_per_period() {
+            let stream = futures::stream::iter(0..5).into_boxed();
+
+            let items: Vec<_> = throttle(stream, Duration::from_millis(10)).collect().await;
+
+            assert_eq!(items, vec![0, 4]);
+        }
+
+        #[tokio::test]
+        async fn debounce_yields_latest_item_after_quiet_period() {
+            let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+            let stream = unfold(receiver, |mut receiver| async move {
+                let item = receiver.recv().await?;
+                Some((item, receiver))
+            })
+            .into_boxed();
+            let mut items = debounce(stream, Duration::from_millis(10));
+
+            sender.send(1).unwrap();
+            sender.send(2).unwrap();
+            assert_eq!(items.next().await, Some(2));
+
+            sender.send(3).unwrap();
+            drop(sender);
+            assert_eq!(items.next().await, Some(3));
+            assert_eq!(items.next().await, None);
+        }
+    }
 }
 
+pub use orchestrator::{
+    BoxedService,
+    ServiceOrchestrator,
+    ServicesHealth,
+};
 pub use service::{
+    Backoff,
+    ConfigUpdate,
     EmptyShared,
+    HealthProbe,
+    RestartPolicy,
+    RestartableService,
     RunnableService,
     RunnableTask,
     Service,
     ServiceRunner,
+    ServiceRunnerBuilder,
     Shared,
     SharedMutex,
 };
+pub use shared_state::{
+    SharedSnapshot,
+    SharedState,
+    Snapshot,
+    SnapshotReceiver,
+};
 pub use state::{
+    Health,
+    ReadySignal,
+    ServiceError,
     State,
+    StateTransition,
     StateWatcher,
+    STATE_HISTORY_CAPACITY,
 };
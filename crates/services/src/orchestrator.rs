@@ -0,0 +1,405 @@
+//! The module manages the startup and shutdown of services that depend on each other.
+
+use crate::{
+    service::{
+        HealthProbe,
+        Service,
+    },
+    shared_state::{
+        SharedSnapshot,
+        SharedState,
+    },
+    state::{
+        Health,
+        State,
+    },
+};
+use anyhow::anyhow;
+
+/// The type-erased service managed by the [`ServiceOrchestrator`].
+pub type BoxedService = Box<dyn Service + Send + Sync + 'static>;
+
+/// Reports the health of every service added to the [`ServiceOrchestrator`]. It can be
+/// cloned and shared with the services, including the ones added later.
+#[derive(Clone)]
+pub struct ServicesHealth(SharedSnapshot<Vec<(&'static str, HealthProbe)>>);
+
+impl Default for ServicesHealth {
+    fn default() -> Self {
+        Self(SharedSnapshot::new(vec![]))
+    }
+}
+
+impl ServicesHealth {
+    /// Returns the name and the current health of every service.
+    pub fn report(&self) -> Vec<(&'static str, Health)> {
+        self.0
+            .snapshot()
+            .iter()
+            .map(|(name, probe)| (*name, probe()))
+            .collect()
+    }
+}
+
+struct Node {
+    name: &'static str,
+    dependencies: Vec<&'static str>,
+}
+
+/// Starts services after their dependencies and stops them in the reverse order.
+/// If a service stops, the services that depend on it are stopped too.
+#[derive(Default)]
+pub struct ServiceOrchestrator {
+    nodes: Vec<Node>,
+    /// Services in the same order as `nodes`.
+    services: Vec<BoxedService>,
+    health: ServicesHealth,
+}
+
+impl ServiceOrchestrator {
+    /// Creates an orchestrator without services.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `service` that depends on the services with the `dependencies` names.
+    /// Services can be added in any order, dependencies are resolved at the startup.
+    pub fn add(
+        &mut self,
+        name: &'static str,
+        service: BoxedService,
+        dependencies: &[&'static str],
+    ) -> anyhow::Result<()> {
+        if self.position(name).is_some() {
+            return Err(anyhow!("The service `{name}` is already added"))
+        }
+        let probe = service.health_probe();
+        self.health.0.update(|probes| probes.push((name, probe)));
+        self.nodes.push(Node {
+            name,
+            dependencies: dependencies.to_vec(),
+        });
+        self.services.push(service);
+        Ok(())
+    }
+
+    /// Returns the health report of the services, including the ones added later.
+    pub fn health(&self) -> ServicesHealth {
+        self.health.clone()
+    }
+
+    /// Returns services in the order of the startup, or in the order of the insertion
+    /// if the orchestrator is not started yet.
+    pub fn services(&self) -> &[BoxedService] {
+        &self.services
+    }
+
+    /// Starts services after their dependencies. If any service fails to start, stops
+    /// already started services in the reverse order.
+    pub async fn start_and_await(&mut self) -> anyhow::Result<()> {
+        self.sort()?;
+        for (i, service) in self.services.iter().enumerate() {
+            let error = match service.start_and_await().await {
+                Ok(State::StoppedWithError(error)) => anyhow!(error),
+                Ok(_) => continue,
+                Err(error) => error,
+            };
+            Self::stop_all(&self.nodes[..i], &self.services[..i]).await;
+            return Err(error.context(format!(
+                "The service `{}` failed to start",
+                self.nodes[i].name
+            )))
+        }
+        Ok(())
+    }
+
+    /// Stops services in the reverse order of the startup. Every service receives the
+    /// stop signal even if some of them fail to stop.
+    pub async fn stop_and_await(&self) {
+        Self::stop_all(&self.nodes, &self.services).await
+    }
+
+    /// Waits until any of the services stops and stops the services that depend on it,
+    /// directly or transitively. Returns the name and the state of the stopped service.
+    /// Should be called after [`Self::start_and_await`].
+    pub async fn await_any_stop(&self) -> anyhow::Result<(&'static str, State)> {
+        if self.services.is_empty() {
+            return futures::future::pending().await
+        }
+        let stop_signals = self.services.iter().map(|service| service.await_stop());
+        let (result, index, _) = futures::future::select_all(stop_signals).await;
+        let state = result?;
+        self.stop_dependents(index).await;
+        Ok((self.nodes[index].name, state))
+    }
+
+    async fn stop_dependents(&self, index: usize) {
+        // Services are sorted, so all dependents follow the service.
+        let mut stopped = vec![self.nodes[index].name];
+        let mut dependents = vec![];
+        for (i, node) in self.nodes.iter().enumerate().skip(index + 1) {
+            if node.dependencies.iter().any(|d| stopped.contains(d)) {
+                stopped.push(node.name);
+                dependents.push(i);
+            }
+        }
+        for i in dependents.into_iter().rev() {
+            tracing::warn!(
+                "Stopping the service `{}` because its dependency `{}` stopped",
+                self.nodes[i].name,
+                self.nodes[index].name
+            );
+            Self::stop_one(&self.nodes[i], &self.services[i]).await;
+        }
+    }
+
+    async fn stop_all(nodes: &[Node], services: &[BoxedService]) {
+        for (node, service) in nodes.iter().zip(services).rev() {
+            Self::stop_one(node, service).await;
+        }
+    }
+
+    async fn stop_one(node: &Node, service: &BoxedService) {
+        if let Err(err) = service.stop_and_await().await {
+            tracing::error!(
+                "Got an error during awaiting for stop of the service `{}`: {}",
+                node.name,
+                err
+            );
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.name == name)
+    }
+
+    /// Sorts services so that every service follows its dependencies. Services without
+    /// dependencies between them keep the order of the insertion.
+    fn sort(&mut self) -> anyhow::Result<()> {
+        for node in &self.nodes {
+            for dependency in &node.dependencies {
+                if self.position(dependency).is_none() {
+                    return Err(anyhow!(
+                        "The service `{}` depends on the unknown service `{}`",
+                        node.name,
+                        dependency
+                    ))
+                }
+            }
+        }
+
+        let mut sorted = vec![false; self.nodes.len()];
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while order.len() < self.nodes.len() {
+            let next = (0..self.nodes.len()).find(|&i| {
+                !sorted[i]
+                    && self.nodes[i].dependencies.iter().all(|dependency| {
+                        self.position(dependency).map_or(false, |j| sorted[j])
+                    })
+            });
+            match next {
+                Some(i) => {
+                    sorted[i] = true;
+                    order.push(i);
+                }
+                None => {
+                    return Err(anyhow!("The dependencies of services form a cycle"))
+                }
+            }
+        }
+
+        let mut entries: Vec<_> = self
+            .nodes
+            .drain(..)
+            .zip(self.services.drain(..))
+            .map(Some)
+            .collect();
+        for i in order {
+            let (node, service) =
+                entries[i].take().expect("Every service is sorted once");
+            self.nodes.push(node);
+            self.services.push(service);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        EmptyShared,
+        RunnableService,
+        RunnableTask,
+        ServiceRunner,
+        SharedMutex,
+        StateWatcher,
+    };
+
+    type Events = SharedMutex<Vec<String>>;
+
+    struct Recorder {
+        name: &'static str,
+        events: Events,
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableService for Recorder {
+        const NAME: &'static str = "Recorder";
+        type SharedData = EmptyShared;
+        type Task = Self;
+        type TaskParams = ();
+
+        fn shared_data(&self) -> Self::SharedData {
+            EmptyShared
+        }
+
+        async fn into_task(
+            self,
+            _: &StateWatcher,
+            _: Self::TaskParams,
+        ) -> anyhow::Result<Self::Task> {
+            self.events.apply(|events| events.push(format!("start {}", self.name)));
+            Ok(self)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableTask for Recorder {
+        async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+            watcher.while_started().await?;
+            Ok(false)
+        }
+
+        async fn shutdown(self) -> anyhow::Result<()> {
+            self.events.apply(|events| events.push(format!("stop {}", self.name)));
+            Ok(())
+        }
+    }
+
+    fn recorder(name: &'static str, events: &Events) -> BoxedService {
+        Box::new(ServiceRunner::new(Recorder {
+            name,
+            events: events.clone(),
+        }))
+    }
+
+    fn events(events: &Events) -> Vec<String> {
+        events.apply(|events| events.clone())
+    }
+
+    #[tokio::test]
+    async fn starts_after_dependencies_and_stops_in_reverse_order() {
+        let events = Events::new(vec![]);
+        let mut orchestrator = ServiceOrchestrator::new();
+        orchestrator
+            .add("poa", recorder("poa", &events), &["txpool"])
+            .unwrap();
+        orchestrator
+            .add("txpool", recorder("txpool", &events), &["p2p"])
+            .unwrap();
+        orchestrator.add("p2p", recorder("p2p", &events), &[]).unwrap();
+
+        orchestrator.start_and_await().await.unwrap();
+        orchestrator.stop_and_await().await;
+
+        assert_eq!(
+            events(&events),
+            vec![
+                "start p2p",
+                "start txpool",
+                "start poa",
+                "stop poa",
+                "stop txpool",
+                "stop p2p"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stop_of_dependency_stops_dependents() {
+        let events = Events::new(vec![]);
+        let mut orchestrator = ServiceOrchestrator::new();
+        orchestrator.add("p2p", recorder("p2p", &events), &[]).unwrap();
+        orchestrator
+            .add("relayer", recorder("relayer", &events), &[])
+            .unwrap();
+        orchestrator
+            .add("txpool", recorder("txpool", &events), &["p2p"])
+            .unwrap();
+        orchestrator
+            .add("poa", recorder("poa", &events), &["txpool", "relayer"])
+            .unwrap();
+        orchestrator.start_and_await().await.unwrap();
+
+        orchestrator.services()[0].stop_and_await().await.unwrap();
+        let (name, state) = orchestrator.await_any_stop().await.unwrap();
+
+        assert_eq!(name, "p2p");
+        assert_eq!(state, State::Stopped);
+        assert_eq!(orchestrator.services()[1].state(), State::Started);
+        assert_eq!(orchestrator.services()[2].state(), State::Stopped);
+        assert_eq!(orchestrator.services()[3].state(), State::Stopped);
+        assert!(events(&events).ends_with(&[
+            "stop p2p".to_string(),
+            "stop poa".to_string(),
+            "stop txpool".to_string()
+        ]));
+    }
+
+    #[tokio::test]
+    async fn health_reports_stopped_services() {
+        let events = Events::new(vec![]);
+        let mut orchestrator = ServiceOrchestrator::new();
+        let health = orchestrator.health();
+        orchestrator.add("p2p", recorder("p2p", &events), &[]).unwrap();
+        orchestrator
+            .add("txpool", recorder("txpool", &events), &[])
+            .unwrap();
+        orchestrator.start_and_await().await.unwrap();
+
+        orchestrator.services()[0].stop_and_await().await.unwrap();
+
+        let report = health.report();
+        assert_eq!(report[0].0, "p2p");
+        assert!(matches!(report[0].1, Health::Unhealthy(_)));
+        assert_eq!(report[1], ("txpool", Health::Healthy));
+    }
+
+    #[tokio::test]
+    async fn start_fails_on_cycle() {
+        let events = Events::new(vec![]);
+        let mut orchestrator = ServiceOrchestrator::new();
+        orchestrator
+            .add("txpool", recorder("txpool", &events), &["poa"])
+            .unwrap();
+        orchestrator
+            .add("poa", recorder("poa", &events), &["txpool"])
+            .unwrap();
+
+        assert!(orchestrator.start_and_await().await.is_err());
+        assert!(events(&events).is_empty());
+    }
+
+    #[tokio::test]
+    async fn start_fails_on_unknown_dependency() {
+        let events = Events::new(vec![]);
+        let mut orchestrator = ServiceOrchestrator::new();
+        orchestrator
+            .add("txpool", recorder("txpool", &events), &["p2p"])
+            .unwrap();
+
+        assert!(orchestrator.start_and_await().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn add_fails_on_duplicate_name() {
+        let events = Events::new(vec![]);
+        let mut orchestrator = ServiceOrchestrator::new();
+        orchestrator.add("p2p", recorder("p2p", &events), &[]).unwrap();
+
+        assert!(orchestrator
+            .add("p2p", recorder("p2p", &events), &[])
+            .is_err());
+    }
+}
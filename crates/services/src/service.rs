@@ -1,10 +1,20 @@
 use crate::state::{
+    Health,
+    ServiceError,
     State,
+    StateSender,
     StateWatcher,
 };
 use anyhow::anyhow;
+#[cfg(feature = "metrics")]
+use fuel_core_metrics::services_metrics::{
+    ServiceInstance,
+    NOT_STARTED,
+    SERVICES_METRICS,
+    STARTED,
+};
 use futures::FutureExt;
-use tokio::sync::watch;
+use std::time::Duration;
 use tracing::Instrument;
 
 /// Alias for Arc<T>
@@ -20,10 +30,105 @@ impl<T> Clone for SharedMutex<T> {
     }
 }
 
+/// Returns the current health of the service. It can be called from any thread and
+/// outlives the service.
+pub type HealthProbe = Shared<dyn Fn() -> Health + Send + Sync>;
+
 /// Used if services have no asynchronously shared data
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EmptyShared;
 
+/// Defines how the `ServiceRunner` reacts to failures of the `RunnableTask::run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RestartPolicy {
+    /// Errors are logged and the task continues; a panic stops the service.
+    #[default]
+    Never,
+    /// Errors are logged and the task continues; after a panic, a fresh task is built
+    /// with the exponential backoff. `Backoff::max_attempts` is ignored.
+    Always(Backoff),
+    /// After an error or a panic, a fresh task is built with the exponential backoff.
+    /// The service stops with an error after `Backoff::max_attempts` failures in a row.
+    OnError(Backoff),
+}
+
+/// The exponential backoff between restarts of the task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    /// The delay after the first failure. It doubles with each following failure.
+    pub initial: Duration,
+    /// The upper bound of the delay.
+    pub max: Duration,
+    /// The number of failures in a row after which the service stops.
+    pub max_attempts: u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            max_attempts: 10,
+        }
+    }
+}
+
+impl Backoff {
+    /// Returns the delay before the restart after `failures` failures in a row.
+    pub fn delay(&self, failures: u32) -> Duration {
+        let factor = 1u32.checked_shl(failures.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// The partial update of the configuration of started services. Fields that are not set
+/// keep their current values, and every service applies only the fields it supports.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigUpdate {
+    /// The minimal gas price of transactions accepted by the transaction pool.
+    pub min_gas_price: Option<u64>,
+    /// The maximum number of connected non-reserved peers.
+    pub max_peers: Option<u32>,
+    /// The interval between blocks produced by the `Interval` trigger.
+    pub block_time: Option<Duration>,
+}
+
+impl ConfigUpdate {
+    /// Returns the names of the set fields.
+    pub fn fields(&self) -> Vec<&'static str> {
+        let Self {
+            min_gas_price,
+            max_peers,
+            block_time,
+        } = self;
+        let mut fields = vec![];
+        if min_gas_price.is_some() {
+            fields.push("min_gas_price");
+        }
+        if max_peers.is_some() {
+            fields.push("max_peers");
+        }
+        if block_time.is_some() {
+            fields.push("block_time");
+        }
+        fields
+    }
+
+    /// Unsets the fields that are set in the `other` update.
+    pub fn without(mut self, other: &ConfigUpdate) -> Self {
+        if other.min_gas_price.is_some() {
+            self.min_gas_price = None;
+        }
+        if other.max_peers.is_some() {
+            self.max_peers = None;
+        }
+        if other.block_time.is_some() {
+            self.block_time = None;
+        }
+        self
+    }
+}
+
 /// Trait for service runners, providing a minimal interface for managing
 /// the lifecycle of services such as start/stop and health status.
 #[async_trait::async_trait]
@@ -49,11 +154,143 @@ pub trait Service {
     /// Wait for service to stop (without sending a stop signal).
     async fn await_stop(&self) -> anyhow::Result<State>;
 
+    /// Send a pause signal to the service. The service finishes the current iteration of
+    /// the task and waits for the resume signal, keeping its shared state.
+    /// Returns an error if the service is not started.
+    fn pause(&self) -> anyhow::Result<()>;
+
+    /// Send a resume signal to the paused service.
+    /// Returns an error if the service is not paused.
+    fn resume(&self) -> anyhow::Result<()>;
+
+    /// Send a restart signal to the started or paused service. The service shuts its
+    /// task down and continues with a fresh one, keeping its shared state.
+    /// Returns an error if the service is not started or can't be restarted, which is
+    /// the default.
+    fn restart(&self) -> anyhow::Result<()> {
+        Err(anyhow!("The service can't be restarted."))
+    }
+
     /// The current state of the service (i.e. `Started`, `Stopped`, etc..)
     fn state(&self) -> State;
 
     /// Returns the state watcher of the service.
     fn state_watcher(&self) -> StateWatcher;
+
+    /// Returns the probe of the service health. By default, the service is healthy while
+    /// it is started.
+    fn health_probe(&self) -> HealthProbe {
+        let state = self.state_watcher();
+        Shared::new(move || Health::of_state(&state.borrow()))
+    }
+
+    /// The current health of the service.
+    fn health(&self) -> Health {
+        (self.health_probe())()
+    }
+
+    /// Returns the error that stopped the service, e.g. the panic of its task.
+    fn error(&self) -> Option<ServiceError> {
+        self.state_watcher().error()
+    }
+
+    /// Returns `true` if the service is started and ready to serve. Some services
+    /// become ready later than they start, see [`RunnableService::READY_ON_START`].
+    fn is_ready(&self) -> bool {
+        self.state_watcher().is_ready()
+    }
+
+    /// Waits until the service is started and ready to serve (without sending any
+    /// signal). Returns an error if the service stops before it becomes ready.
+    async fn await_ready(&self) -> anyhow::Result<()> {
+        self.state_watcher().await_ready().await
+    }
+
+    /// Checks that the service can apply the `update` of the configuration without the
+    /// restart, and returns the part of the `update` it applies. The service applies
+    /// nothing by default.
+    fn validate_reload(&self, _update: &ConfigUpdate) -> anyhow::Result<ConfigUpdate> {
+        Ok(ConfigUpdate::default())
+    }
+
+    /// Applies the `update` of the configuration accepted by [`Self::validate_reload`].
+    /// Does nothing by default.
+    fn reload(&self, _update: &ConfigUpdate) {}
+}
+
+/// The service shared between owners, e.g. the orchestrator and the registry of services.
+#[async_trait::async_trait]
+impl<S> Service for Shared<S>
+where
+    S: Service + Send + Sync + ?Sized,
+{
+    fn start(&self) -> anyhow::Result<()> {
+        self.as_ref().start()
+    }
+
+    async fn start_and_await(&self) -> anyhow::Result<State> {
+        self.as_ref().start_and_await().await
+    }
+
+    async fn await_start_or_stop(&self) -> anyhow::Result<State> {
+        self.as_ref().await_start_or_stop().await
+    }
+
+    fn stop(&self) -> bool {
+        self.as_ref().stop()
+    }
+
+    async fn stop_and_await(&self) -> anyhow::Result<State> {
+        self.as_ref().stop_and_await().await
+    }
+
+    async fn await_stop(&self) -> anyhow::Result<State> {
+        self.as_ref().await_stop().await
+    }
+
+    fn pause(&self) -> anyhow::Result<()> {
+        self.as_ref().pause()
+    }
+
+    fn resume(&self) -> anyhow::Result<()> {
+        self.as_ref().resume()
+    }
+
+    fn restart(&self) -> anyhow::Result<()> {
+        self.as_ref().restart()
+    }
+
+    fn state(&self) -> State {
+        self.as_ref().state()
+    }
+
+    fn state_watcher(&self) -> StateWatcher {
+        self.as_ref().state_watcher()
+    }
+
+    fn health_probe(&self) -> HealthProbe {
+        self.as_ref().health_probe()
+    }
+
+    fn error(&self) -> Option<ServiceError> {
+        self.as_ref().error()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.as_ref().is_ready()
+    }
+
+    async fn await_ready(&self) -> anyhow::Result<()> {
+        self.as_ref().await_ready().await
+    }
+
+    fn validate_reload(&self, update: &ConfigUpdate) -> anyhow::Result<ConfigUpdate> {
+        self.as_ref().validate_reload(update)
+    }
+
+    fn reload(&self, update: &ConfigUpdate) {
+        self.as_ref().reload(update)
+    }
 }
 
 /// Trait used by `ServiceRunner` to encapsulate the business logic tasks for a service.
@@ -62,11 +299,17 @@ pub trait RunnableService: Send {
     /// The name of the runnable service, used for namespacing error messages.
     const NAME: &'static str;
 
+    /// Whether the service is ready to serve as soon as it is started. Services that
+    /// need more time, e.g. to bind listeners or to sync with the remote source, set it
+    /// to `false` and report readiness via the [`StateWatcher::ready_signal`] of the
+    /// watcher passed to [`Self::into_task`].
+    const READY_ON_START: bool = true;
+
     /// Service specific shared data. This is used when you have data that needs to be shared by
     /// one or more tasks. It is the implementors responsibility to ensure cloning this
     /// type is shallow and doesn't provide a full duplication of data that is meant
     /// to be shared between asynchronous processes.
-    type SharedData: Clone + Send + Sync;
+    type SharedData: Clone + Send + Sync + 'static;
 
     /// The initialized runnable task type.
     type Task: RunnableTask;
@@ -77,6 +320,26 @@ pub trait RunnableService: Send {
     /// A cloned instance of the shared data
     fn shared_data(&self) -> Self::SharedData;
 
+    /// Reports the health of the started service based on its shared data. The
+    /// service is healthy by default.
+    fn health(_: &Self::SharedData) -> Health {
+        Health::Healthy
+    }
+
+    /// Checks the `update` of the configuration against the shared data and returns the
+    /// part of the `update` the service applies. The update is ignored by default.
+    fn validate_reload(
+        _: &Self::SharedData,
+        _update: &ConfigUpdate,
+    ) -> anyhow::Result<ConfigUpdate> {
+        Ok(ConfigUpdate::default())
+    }
+
+    /// Applies the validated `update` of the configuration via the shared data, so it
+    /// reaches the running task without the restart. Applying can't fail, so everything
+    /// that may reject the update belongs to [`Self::validate_reload`].
+    fn reload(_: &Self::SharedData, _update: &ConfigUpdate) {}
+
     /// Converts the service into a runnable task before the main run loop.
     ///
     /// The `state` is a `State` watcher of the service. Some tasks may handle state changes
@@ -88,6 +351,15 @@ pub trait RunnableService: Send {
     ) -> anyhow::Result<Self::Task>;
 }
 
+/// The service that can build a not initialized copy of itself. The `ServiceRunner` uses
+/// it to replace the failed task with a fresh one according to the [`RestartPolicy`],
+/// instead of running the failed task again.
+pub trait RestartableService: RunnableService {
+    /// Returns a copy of the service that shares [`RunnableService::SharedData`]
+    /// with `self`, so the restart is not visible to the owners of the shared data.
+    fn rebuild(&self) -> Self;
+}
+
 /// The trait is implemented by the service task and contains a single iteration of the infinity
 /// loop.
 #[async_trait::async_trait]
@@ -95,12 +367,18 @@ pub trait RunnableTask: Send {
     /// This function should contain the main business logic of the service task. It will run until
     /// the service either returns false, panics or a stop signal is received.
     /// If the service returns an error, it will be logged and execution will resume.
+    /// The reaction to errors and panics can be configured with [`RestartPolicy`].
+    /// If the `ServiceRunner` has a watchdog, the iteration that exceeds its deadline is
+    /// cancelled and handled as an error. With the `metrics` feature, the time the task
+    /// works in each iteration is recorded, excluding waits for events, and the iteration
+    /// above the stall threshold is reported.
     /// This is intended to be called only by the `ServiceRunner`.
     ///
     /// The `ServiceRunner` continue to call the `run` method in the loop while the state is
     /// `State::Started`. So first, the `run` method should return a value, and after, the service
     /// will stop. If the service should react to the state change earlier, it should handle it in
     /// the `run` loop on its own. See [`StateWatcher::while_started`].
+    /// While the state is `State::Paused`, the `run` method is not called.
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool>;
 
     /// Gracefully shutdowns the task after the end of the execution cycle.
@@ -116,7 +394,11 @@ where
 {
     /// The shared state of the service
     pub shared: S::SharedData,
-    state: Shared<watch::Sender<State>>,
+    state: Shared<StateSender>,
+    /// Whether the task can be replaced with a fresh one on demand.
+    restartable: bool,
+    #[cfg(feature = "metrics")]
+    metrics: ServiceInstance,
 }
 
 impl<S> Drop for ServiceRunner<S>
@@ -135,9 +417,7 @@ where
 {
     /// Initializes a new `ServiceRunner` containing a `RunnableService`
     pub fn new(service: S) -> Self {
-        let shared = service.shared_data();
-        let state = initialize_loop(service, S::TaskParams::default());
-        Self { shared, state }
+        Self::new_with_params(service, S::TaskParams::default())
     }
 }
 
@@ -147,9 +427,13 @@ where
 {
     /// Initializes a new `ServiceRunner` containing a `RunnableService` with parameters for underlying `Task`
     pub fn new_with_params(service: S, params: S::TaskParams) -> Self {
-        let shared = service.shared_data();
-        let state = initialize_loop(service, params);
-        Self { shared, state }
+        ServiceRunnerBuilder::new(service, params).build()
+    }
+
+    /// The instance of the service whose metrics are recorded.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> &ServiceInstance {
+        &self.metrics
     }
 
     async fn _await_start_or_stop(
@@ -176,6 +460,134 @@ where
     }
 }
 
+/// Configures how the [`ServiceRunner`] runs the `Task` of the service.
+pub struct ServiceRunnerBuilder<S>
+where
+    S: RunnableService + 'static,
+{
+    service: S,
+    params: S::TaskParams,
+    restart: Option<Restart<S>>,
+    watchdog: Option<Duration>,
+    #[cfg_attr(not(feature = "metrics"), allow(dead_code))]
+    stall_threshold: Option<Duration>,
+    runtime: Option<tokio::runtime::Handle>,
+}
+
+/// How the main loop supervises the `Task` of the service.
+struct Supervision<S>
+where
+    S: RunnableService,
+{
+    restart: Option<Restart<S>>,
+    watchdog: Option<Duration>,
+    /// The instance of the service whose metrics are recorded.
+    #[cfg(feature = "metrics")]
+    metrics: ServiceInstance,
+}
+
+/// The restart policy of the service and the way to build a fresh service for the
+/// restart.
+struct Restart<S>
+where
+    S: RunnableService,
+{
+    policy: RestartPolicy,
+    rebuild: fn(&S, &S::TaskParams) -> (S, S::TaskParams),
+}
+
+fn rebuild<S>(service: &S, params: &S::TaskParams) -> (S, S::TaskParams)
+where
+    S: RestartableService,
+    S::TaskParams: Clone,
+{
+    (service.rebuild(), params.clone())
+}
+
+impl<S> ServiceRunnerBuilder<S>
+where
+    S: RunnableService + 'static,
+{
+    /// Creates the builder of the runner that never restarts the `Task`, has no watchdog
+    /// and runs the `Task` on the current runtime.
+    pub fn new(service: S, params: S::TaskParams) -> Self {
+        Self {
+            service,
+            params,
+            restart: None,
+            watchdog: None,
+            stall_threshold: None,
+            runtime: None,
+        }
+    }
+
+    /// A single iteration of the `Task` that runs longer than the `watchdog` deadline is
+    /// cancelled and handled as an error, so a stalled task doesn't silently stop making
+    /// progress.
+    ///
+    /// The deadline should exceed the longest time the `Task` may legitimately wait
+    /// for events. The watchdog can't interrupt the task that blocks the thread.
+    pub fn watchdog(mut self, watchdog: Option<Duration>) -> Self {
+        self.watchdog = watchdog;
+        self
+    }
+
+    /// With the `metrics` feature, the iteration of the `Task` that works longer than the
+    /// `stall_threshold` is reported as stalled. It overrides the stall threshold of
+    /// all services for services that legitimately work longer or must react faster.
+    pub fn stall_threshold(mut self, stall_threshold: Option<Duration>) -> Self {
+        self.stall_threshold = stall_threshold;
+        self
+    }
+
+    /// Runs the `Task` on the `runtime` instead of the current runtime. CPU-heavy
+    /// services can be isolated on the dedicated runtime, so they don't starve
+    /// latency-sensitive services of worker threads.
+    pub fn runtime(mut self, runtime: Option<tokio::runtime::Handle>) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Creates the runner. The `Task` is initialized when the runner is started.
+    pub fn build(self) -> ServiceRunner<S> {
+        let shared = self.service.shared_data();
+        let restartable = self.restart.is_some();
+        #[cfg(feature = "metrics")]
+        let metrics = SERVICES_METRICS.new_instance(S::NAME, self.stall_threshold);
+        let supervision = Supervision {
+            restart: self.restart,
+            watchdog: self.watchdog,
+            #[cfg(feature = "metrics")]
+            metrics: metrics.clone(),
+        };
+        let state = initialize_loop(self.service, self.params, supervision, self.runtime);
+        ServiceRunner {
+            shared,
+            state,
+            restartable,
+            #[cfg(feature = "metrics")]
+            metrics,
+        }
+    }
+}
+
+impl<S> ServiceRunnerBuilder<S>
+where
+    S: RestartableService + 'static,
+    S::TaskParams: Clone,
+{
+    /// Restarts the `Task` according to the `restart_policy`. The failed `Task` is shut
+    /// down, and a fresh one is built from the [`RestartableService::rebuild`] copy of
+    /// the service. The runner also accepts restart signals, see [`Service::restart`].
+    pub fn restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.restart = Some(Restart {
+            policy: restart_policy,
+            rebuild: rebuild::<S>,
+        });
+        self
+    }
+}
+
 #[async_trait::async_trait]
 impl<S> Service for ServiceRunner<S>
 where
@@ -202,19 +614,24 @@ where
     }
 
     async fn start_and_await(&self) -> anyhow::Result<State> {
-        let start = self.state.subscribe().into();
+        let start = self.state.subscribe();
         self.start()?;
         self._await_start_or_stop(start).await
     }
 
     async fn await_start_or_stop(&self) -> anyhow::Result<State> {
-        let start = self.state.subscribe().into();
+        let start = self.state.subscribe();
         self._await_start_or_stop(start).await
     }
 
     fn stop(&self) -> bool {
         self.state.send_if_modified(|state| {
-            if state.not_started() || state.starting() || state.started() {
+            if state.not_started()
+                || state.starting()
+                || state.started()
+                || state.paused()
+                || state.restarting()
+            {
                 *state = State::Stopping;
                 true
             } else {
@@ -224,65 +641,160 @@ where
     }
 
     async fn stop_and_await(&self) -> anyhow::Result<State> {
-        let stop = self.state.subscribe().into();
+        let stop = self.state.subscribe();
         self.stop();
         self._await_stop(stop).await
     }
 
     async fn await_stop(&self) -> anyhow::Result<State> {
-        let stop = self.state.subscribe().into();
+        let stop = self.state.subscribe();
         self._await_stop(stop).await
     }
 
+    fn pause(&self) -> anyhow::Result<()> {
+        let paused = self.state.send_if_modified(|state| {
+            if state.started() {
+                *state = State::Paused;
+                true
+            } else {
+                false
+            }
+        });
+
+        if paused {
+            Ok(())
+        } else {
+            Err(anyhow!("The service `{}` is not started.", S::NAME))
+        }
+    }
+
+    fn resume(&self) -> anyhow::Result<()> {
+        let resumed = self.state.send_if_modified(|state| {
+            if state.paused() {
+                *state = State::Started;
+                true
+            } else {
+                false
+            }
+        });
+
+        if resumed {
+            Ok(())
+        } else {
+            Err(anyhow!("The service `{}` is not paused.", S::NAME))
+        }
+    }
+
+    fn restart(&self) -> anyhow::Result<()> {
+        if !self.restartable {
+            return Err(anyhow!("The service `{}` can't be restarted.", S::NAME))
+        }
+        let restarting = self.state.send_if_modified(|state| {
+            if state.started() || state.paused() {
+                *state = State::Restarting;
+                true
+            } else {
+                false
+            }
+        });
+
+        if restarting {
+            Ok(())
+        } else {
+            Err(anyhow!("The service `{}` is not started.", S::NAME))
+        }
+    }
+
     fn state(&self) -> State {
         self.state.borrow().clone()
     }
 
     fn state_watcher(&self) -> StateWatcher {
-        self.state.subscribe().into()
+        self.state.subscribe()
+    }
+
+    fn health_probe(&self) -> HealthProbe {
+        let state = self.state_watcher();
+        let shared = self.shared.clone();
+        Shared::new(move || match Health::of_state(&state.borrow()) {
+            Health::Healthy => S::health(&shared),
+            health => health,
+        })
+    }
+
+    fn validate_reload(&self, update: &ConfigUpdate) -> anyhow::Result<ConfigUpdate> {
+        if self.state.borrow().stopped() {
+            return Err(anyhow!("The service `{}` is stopped.", S::NAME))
+        }
+        S::validate_reload(&self.shared, update)
+    }
+
+    fn reload(&self, update: &ConfigUpdate) {
+        if !self.state.borrow().stopped() {
+            S::reload(&self.shared, update)
+        }
     }
 }
 
 #[tracing::instrument(skip_all, fields(service = S::NAME))]
 /// Initialize the background loop as a spawned task.
-fn initialize_loop<S>(service: S, params: S::TaskParams) -> Shared<watch::Sender<State>>
+fn initialize_loop<S>(
+    service: S,
+    params: S::TaskParams,
+    supervision: Supervision<S>,
+    runtime: Option<tokio::runtime::Handle>,
+) -> Shared<StateSender>
 where
     S: RunnableService + 'static,
 {
-    let (sender, _) = watch::channel(State::NotStarted);
-    let state = Shared::new(sender);
+    let state = Shared::new(StateSender::new(State::NotStarted));
     let stop_sender = state.clone();
+    #[cfg(feature = "metrics")]
+    tokio::task::spawn(record_lifecycle(
+        state.subscribe(),
+        supervision.metrics.clone(),
+    ));
+    let runtime = runtime.unwrap_or_else(tokio::runtime::Handle::current);
     // Spawned as a task to check if the service is already running and to capture any panics.
-    tokio::task::spawn(
+    runtime.spawn(
         async move {
             tracing::debug!("running");
-            let run =
-                std::panic::AssertUnwindSafe(run(service, stop_sender.clone(), params));
+            let run = std::panic::AssertUnwindSafe(run(
+                service,
+                stop_sender.clone(),
+                params,
+                supervision,
+            ));
             tracing::debug!("awaiting run");
-            let result = run.catch_unwind().await;
-
-            let stopped_state = if let Err(e) = result {
-                let panic_information = panic_to_string(e);
-                State::StoppedWithError(panic_information)
-            } else {
-                State::Stopped
+            let error = match run.catch_unwind().await {
+                Ok(result) => result.err(),
+                Err(panic) => Some(ServiceError::Panicked {
+                    service: S::NAME,
+                    message: panic_to_string(panic),
+                }),
             };
 
-            tracing::debug!("shutting down {:?}", stopped_state);
-
-            let _ = stop_sender.send_if_modified(|state| {
-                if !state.stopped() {
-                    *state = stopped_state.clone();
-                    tracing::debug!("Wasn't stopped, so sent stop.");
-                    true
-                } else {
-                    tracing::debug!("Was already stopped.");
-                    false
-                }
-            });
+            tracing::debug!("shutting down with error {:?}", error);
+
+            let stopped = match &error {
+                Some(error) => stop_sender.stop_with_error(error.clone()),
+                None => stop_sender.send_if_modified(|state| {
+                    if !state.stopped() {
+                        *state = State::Stopped;
+                        true
+                    } else {
+                        false
+                    }
+                }),
+            };
+            if stopped {
+                tracing::debug!("Wasn't stopped, so sent stop.");
+            } else {
+                tracing::debug!("Was already stopped.");
+            }
 
-            if let State::StoppedWithError(err) = stopped_state {
-                std::panic::resume_unwind(Box::new(err));
+            if let Some(error) = error {
+                std::panic::resume_unwind(Box::new(error.to_string()));
             }
         }
         .in_current_span(),
@@ -290,12 +802,46 @@ where
     state
 }
 
-/// Runs the main loop.
-async fn run<S>(service: S, sender: Shared<watch::Sender<State>>, params: S::TaskParams)
+/// Records the transitions between states of the service into metrics.
+#[cfg(feature = "metrics")]
+async fn record_lifecycle(mut state: StateWatcher, instance: ServiceInstance) {
+    loop {
+        let current = state.borrow_and_update().clone();
+        SERVICES_METRICS.record_state(&instance, state_name(&current));
+        if let State::StoppedWithError(error) = &current {
+            SERVICES_METRICS.record_error(&instance, error.kind());
+        }
+        if state.changed().await.is_err() {
+            break
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn state_name(state: &State) -> &'static str {
+    match state {
+        State::NotStarted => NOT_STARTED,
+        State::Starting => "Starting",
+        State::Started => STARTED,
+        State::Paused => "Paused",
+        State::Restarting => "Restarting",
+        State::Stopping => "Stopping",
+        State::Stopped => "Stopped",
+        State::StoppedWithError(_) => "StoppedWithError",
+    }
+}
+
+/// Runs the main loop. Returns the error that stopped the task.
+async fn run<S>(
+    service: S,
+    sender: Shared<StateSender>,
+    params: S::TaskParams,
+    supervision: Supervision<S>,
+) -> Result<(), ServiceError>
 where
     S: RunnableService + 'static,
 {
-    let mut state: StateWatcher = sender.subscribe().into();
+    let mut state: StateWatcher = sender.subscribe();
     if state.borrow_and_update().not_started() {
         // We can panic here, because it is inside of the task.
         state.changed().await.expect("The service is destroyed");
@@ -303,15 +849,33 @@ where
 
     // If the state after update is not `Starting` then return to stop the service.
     if !state.borrow().starting() {
-        return
+        return Ok(())
     }
 
+    let Supervision {
+        restart,
+        watchdog,
+        #[cfg(feature = "metrics")]
+        metrics,
+    } = supervision;
+    let restart_policy = restart
+        .as_ref()
+        .map_or(RestartPolicy::Never, |restart| restart.policy);
+    // `into_task` consumes the service, so fresh tasks are built from its copy.
+    let template =
+        restart.map(|restart| ((restart.rebuild)(&service, &params), restart.rebuild));
+
     // We can panic here, because it is inside of the task.
-    let mut task = service
+    let task = service
         .into_task(&state, params)
         .await
         .expect("The initialization of the service failed.");
+    let mut task = Some(task);
 
+    // Reported before the start, so the started service is ready right away.
+    if S::READY_ON_START {
+        state.ready_signal().ready();
+    }
     sender.send_if_modified(|s| {
         if s.starting() {
             *s = State::Started;
@@ -321,53 +885,260 @@ where
         }
     });
 
-    let mut got_panic = None;
+    let mut error: Option<ServiceError> = None;
+    let mut failures = 0;
 
-    while state.borrow_and_update().started() {
-        let task = std::panic::AssertUnwindSafe(task.run(&mut state));
-        let panic_result = task.catch_unwind().await;
-
-        match panic_result {
+    loop {
+        let current = state.borrow_and_update().clone();
+        if current.paused() {
+            tracing::debug!("paused");
+            // We can panic here, because it is inside of the task.
+            state.changed().await.expect("The service is destroyed");
+            continue
+        }
+        if current.restarting() {
+            tracing::info!("restarting");
+            if let Some(replaced) = task.take() {
+                if let Err(e) = shutdown_task(replaced).await {
+                    tracing::error!("The replaced task panicked during shutdown: {e}");
+                }
+            }
+            let (service, params) = match &template {
+                Some(((service, params), rebuild)) => rebuild(service, params),
+                None => break,
+            };
+            match into_fresh_task(service, params, &state).await {
+                Ok(fresh) => task = Some(fresh),
+                Err(e) => {
+                    error = Some(e);
+                    break
+                }
+            }
+            failures = 0;
+            #[cfg(feature = "metrics")]
+            SERVICES_METRICS.record_restart(&metrics);
+            sender.send_if_modified(|s| {
+                if s.restarting() {
+                    *s = State::Started;
+                    true
+                } else {
+                    false
+                }
+            });
+            continue
+        }
+        if !current.started() {
+            break
+        }
+        let running = match task.as_mut() {
+            Some(running) => running,
+            None => break,
+        };
+
+        let iteration = run_iteration(running.run(&mut state), watchdog);
+        let iteration = std::panic::AssertUnwindSafe(iteration).catch_unwind();
+        #[cfg(feature = "metrics")]
+        let (panic_result, busy) = measure_busy(iteration).await;
+        #[cfg(feature = "metrics")]
+        record_iteration(&metrics, busy);
+        #[cfg(not(feature = "metrics"))]
+        let panic_result = iteration.await;
+
+        let should_restart = match panic_result {
             Ok(Ok(should_continue)) => {
-                if !should_continue {
+                // The task may return `false` because it noticed the pause or the
+                // restart signal.
+                let current = state.borrow().clone();
+                if !should_continue && !current.paused() && !current.restarting() {
                     tracing::debug!("stopping");
                     break
                 }
                 tracing::debug!("run loop");
+                failures = 0;
+                continue
             }
             Ok(Err(e)) => {
+                #[cfg(feature = "metrics")]
+                SERVICES_METRICS.record_error(&metrics, "error");
                 let e: &dyn std::error::Error = &*e;
                 tracing::error!(e);
+                matches!(restart_policy, RestartPolicy::OnError(_))
             }
             Err(panic) => {
                 tracing::debug!("got a panic");
-                got_panic = Some(panic);
+                if restart_policy == RestartPolicy::Never {
+                    error = Some(ServiceError::Panicked {
+                        service: S::NAME,
+                        message: panic_to_string(panic),
+                    });
+                    break
+                }
+                let panic_information = panic_to_string(panic);
+                #[cfg(feature = "metrics")]
+                SERVICES_METRICS.record_error(&metrics, "panic");
+                tracing::error!("The task panicked: {panic_information}");
+                true
+            }
+        };
+
+        let backoff = match restart_policy {
+            RestartPolicy::Always(backoff) | RestartPolicy::OnError(backoff)
+                if should_restart =>
+            {
+                backoff
+            }
+            _ => continue,
+        };
+
+        failures += 1;
+        if matches!(restart_policy, RestartPolicy::OnError(_))
+            && failures >= backoff.max_attempts
+        {
+            error = Some(ServiceError::TooManyFailures {
+                service: S::NAME,
+                failures,
+            });
+            break
+        }
+
+        if let Some(failed) = task.take() {
+            if let Err(e) = shutdown_task(failed).await {
+                tracing::error!("The failed task panicked during shutdown: {e}");
+            }
+        }
+
+        let delay = backoff.delay(failures);
+        tracing::warn!("Restarting the task in {delay:?} after {failures} failures");
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = state.while_started() => {}
+        }
+        let current = state.borrow().clone();
+        if !current.started() && !current.paused() && !current.restarting() {
+            break
+        }
+
+        let (service, params) = match &template {
+            Some(((service, params), rebuild)) => rebuild(service, params),
+            None => break,
+        };
+        match into_fresh_task(service, params, &state).await {
+            Ok(fresh) => task = Some(fresh),
+            Err(e) => {
+                error = Some(e);
                 break
             }
         }
+        #[cfg(feature = "metrics")]
+        SERVICES_METRICS.record_restart(&metrics);
     }
 
-    let shutdown = std::panic::AssertUnwindSafe(task.shutdown());
-    match shutdown.catch_unwind().await {
-        Ok(Ok(_)) => {}
-        Ok(Err(e)) => {
-            tracing::error!("Go an error during shutdown of the task: {e}");
-        }
-        Err(e) => {
-            if got_panic.is_some() {
-                let panic_information = panic_to_string(e);
+    if let Some(task) = task {
+        if let Err(e) = shutdown_task(task).await {
+            if error.is_some() {
                 tracing::error!(
                     "Go a panic during execution and shutdown of the task. \
-                    The error during shutdown: {panic_information}"
+                    The error during shutdown: {e}"
                 );
             } else {
-                got_panic = Some(e);
+                error = Some(ServiceError::Panicked {
+                    service: S::NAME,
+                    message: e,
+                });
             }
         }
     }
 
-    if let Some(panic) = got_panic {
-        std::panic::resume_unwind(panic)
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Builds the fresh task for the restart of the service.
+async fn into_fresh_task<S>(
+    service: S,
+    params: S::TaskParams,
+    state: &StateWatcher,
+) -> Result<S::Task, ServiceError>
+where
+    S: RunnableService + 'static,
+{
+    let fresh = std::panic::AssertUnwindSafe(service.into_task(state, params));
+    let message = match fresh.catch_unwind().await {
+        Ok(Ok(fresh)) => return Ok(fresh),
+        Ok(Err(e)) => e.to_string(),
+        Err(panic) => panic_to_string(panic),
+    };
+    Err(ServiceError::RestartFailed {
+        service: S::NAME,
+        message,
+    })
+}
+
+/// Gracefully shutdowns the task. Returns the information about the panic during the
+/// shutdown, while errors are only logged.
+async fn shutdown_task<T>(task: T) -> Result<(), String>
+where
+    T: RunnableTask,
+{
+    let shutdown = std::panic::AssertUnwindSafe(task.shutdown());
+    match shutdown.catch_unwind().await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => {
+            tracing::error!("Go an error during shutdown of the task: {e}");
+            Ok(())
+        }
+        Err(e) => Err(panic_to_string(e)),
+    }
+}
+
+/// Runs the `iteration` and returns its output with the time spent in its polls. Waits
+/// for events between polls are not counted, so idle tasks are not reported as stalled.
+#[cfg(feature = "metrics")]
+async fn measure_busy<F>(iteration: F) -> (F::Output, Duration)
+where
+    F: core::future::Future,
+{
+    futures::pin_mut!(iteration);
+    let mut busy = Duration::ZERO;
+    let output = futures::future::poll_fn(|cx| {
+        let started_at = std::time::Instant::now();
+        let poll = core::future::Future::poll(iteration.as_mut(), cx);
+        busy += started_at.elapsed();
+        poll
+    })
+    .await;
+    (output, busy)
+}
+
+/// Records the `busy` time of the iteration of the task of the `instance` into metrics
+/// and warns about the iteration that exceeded its stall threshold.
+#[cfg(feature = "metrics")]
+fn record_iteration(instance: &ServiceInstance, busy: Duration) {
+    if SERVICES_METRICS.record_iteration(instance, busy) {
+        tracing::warn!("The iteration of the task stalled for {busy:?}");
+    }
+}
+
+/// Runs the iteration of the task. If the `watchdog` deadline is set, the iteration that
+/// exceeds it is cancelled and returns an error.
+async fn run_iteration<F>(
+    iteration: F,
+    watchdog: Option<Duration>,
+) -> anyhow::Result<bool>
+where
+    F: core::future::Future<Output = anyhow::Result<bool>>,
+{
+    if let Some(deadline) = watchdog {
+        match tokio::time::timeout(deadline, iteration).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow!(
+                "The iteration of the task exceeded the deadline {deadline:?}"
+            )),
+        }
+    } else {
+        iteration.await
     }
 }
 
@@ -398,6 +1169,7 @@ fn panic_to_string(e: Box<dyn core::any::Any + Send>) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::StateTransition;
     use futures::future::BoxFuture;
 
     mockall::mock! {
@@ -456,13 +1228,151 @@ mod tests {
         }
     }
 
+    /// Reports readiness when the receiver gets the signal.
+    struct SlowStartService(tokio::sync::oneshot::Receiver<()>);
+
+    #[async_trait::async_trait]
+    impl RunnableService for SlowStartService {
+        const NAME: &'static str = "SlowStartService";
+        const READY_ON_START: bool = false;
+
+        type SharedData = EmptyShared;
+        type Task = MockTask;
+        type TaskParams = ();
+
+        fn shared_data(&self) -> EmptyShared {
+            EmptyShared
+        }
+
+        async fn into_task(
+            self,
+            state: &StateWatcher,
+            _: (),
+        ) -> anyhow::Result<MockTask> {
+            let ready = state.ready_signal();
+            let receiver = self.0;
+            tokio::spawn(async move {
+                if receiver.await.is_ok() {
+                    ready.ready();
+                }
+            });
+            let mut mock = MockTask::default();
+            mock.expect_run().returning(|watcher| {
+                let mut watcher = watcher.clone();
+                Box::pin(async move {
+                    watcher.while_started().await.unwrap();
+                    let should_continue = false;
+                    Ok(should_continue)
+                })
+            });
+            mock.expect_shutdown().returning(|| Ok(()));
+            Ok(mock)
+        }
+    }
+
+    /// Builds the task of the restart with the number `build` via the `task` function and
+    /// counts the builds, so tests can check that restarts use fresh tasks.
+    struct RebuiltService {
+        builds: Shared<std::sync::atomic::AtomicUsize>,
+        task: fn(usize) -> anyhow::Result<MockTask>,
+    }
+
+    impl RebuiltService {
+        fn new(task: fn(usize) -> anyhow::Result<MockTask>) -> Self {
+            Self {
+                builds: Default::default(),
+                task,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableService for RebuiltService {
+        const NAME: &'static str = "RebuiltService";
+
+        type SharedData = Shared<std::sync::atomic::AtomicUsize>;
+        type Task = MockTask;
+        type TaskParams = ();
+
+        fn shared_data(&self) -> Self::SharedData {
+            self.builds.clone()
+        }
+
+        async fn into_task(self, _: &StateWatcher, _: ()) -> anyhow::Result<MockTask> {
+            let build = self
+                .builds
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            (self.task)(build)
+        }
+    }
+
+    impl RestartableService for RebuiltService {
+        fn rebuild(&self) -> Self {
+            Self {
+                builds: self.builds.clone(),
+                task: self.task,
+            }
+        }
+    }
+
     #[tokio::test]
     async fn start_and_await_stop_and_await_works() {
         let service = ServiceRunner::new(MockService::new_empty());
         let state = service.start_and_await().await.unwrap();
         assert!(state.started());
+        assert!(service.is_ready());
         let state = service.stop_and_await().await.unwrap();
         assert!(matches!(state, State::Stopped));
+        assert!(!service.is_ready());
+    }
+
+    #[tokio::test]
+    async fn service_is_ready_after_reporting_readiness() {
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        let service = ServiceRunner::new(SlowStartService(receiver));
+        service.start_and_await().await.unwrap();
+        assert!(!service.is_ready());
+
+        sender.send(()).unwrap();
+        service.await_ready().await.unwrap();
+        assert!(service.is_ready());
+    }
+
+    #[tokio::test]
+    async fn await_ready_fails_if_service_stops_before() {
+        let (_sender, receiver) = tokio::sync::oneshot::channel();
+        let service = ServiceRunner::new(SlowStartService(receiver));
+        service.start_and_await().await.unwrap();
+
+        let ready = service.await_ready();
+        service.stop();
+        assert!(ready.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reload_is_rejected_after_stop() {
+        let service = ServiceRunner::new(MockService::new_empty());
+        service.start_and_await().await.unwrap();
+        assert!(service.validate_reload(&ConfigUpdate::default()).is_ok());
+
+        service.stop_and_await().await.unwrap();
+        assert!(service.validate_reload(&ConfigUpdate::default()).is_err());
+    }
+
+    #[test]
+    fn config_update_without_other_keeps_only_its_own_fields() {
+        let update = ConfigUpdate {
+            min_gas_price: Some(1),
+            max_peers: Some(2),
+            block_time: None,
+        };
+        let handled = ConfigUpdate {
+            min_gas_price: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(update.clone().without(&handled).fields(), vec!["max_peers"]);
+        assert!(update.clone().without(&update).fields().is_empty());
     }
 
     #[tokio::test]
@@ -502,6 +1412,353 @@ mod tests {
 
         let state = service.await_stop().await.unwrap();
         assert!(matches!(state, State::StoppedWithError(s) if s.contains("Should fail")));
+        assert_eq!(
+            service.error(),
+            Some(ServiceError::Panicked {
+                service: "MockService",
+                message: "Should fail".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn always_restart_policy_rebuilds_task_after_panic() {
+        let service = RebuiltService::new(|build| {
+            let mut mock = MockTask::default();
+            mock.expect_run().times(1).returning(move |_| {
+                Box::pin(async move {
+                    if build < 2 {
+                        panic!("Should recover")
+                    }
+                    let should_continue = false;
+                    Ok(should_continue)
+                })
+            });
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let backoff = Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+            max_attempts: 1,
+        };
+        let service = ServiceRunnerBuilder::new(service, ())
+            .restart_policy(RestartPolicy::Always(backoff))
+            .build();
+        service.start().unwrap();
+
+        let state = service.await_stop().await.unwrap();
+        assert!(matches!(state, State::Stopped));
+        assert_eq!(service.shared.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn always_restart_policy_backs_off_between_restarts() {
+        let service = RebuiltService::new(|build| {
+            let mut mock = MockTask::default();
+            mock.expect_run().returning(move |_| {
+                Box::pin(async move {
+                    if build < 2 {
+                        panic!("Should recover")
+                    }
+                    let should_continue = false;
+                    Ok(should_continue)
+                })
+            });
+            mock.expect_shutdown().returning(|| Ok(()));
+            Ok(mock)
+        });
+        let backoff = Backoff {
+            initial: Duration::from_millis(50),
+            max: Duration::from_secs(1),
+            max_attempts: 1,
+        };
+        let service = ServiceRunnerBuilder::new(service, ())
+            .restart_policy(RestartPolicy::Always(backoff))
+            .build();
+        let started_at = std::time::Instant::now();
+        service.start().unwrap();
+
+        service.await_stop().await.unwrap();
+        // 50ms after the first panic and 100ms after the second one.
+        assert!(started_at.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[tokio::test]
+    async fn on_error_restart_policy_stops_after_max_attempts() {
+        let service = RebuiltService::new(|_| {
+            let mut mock = MockTask::default();
+            mock.expect_run()
+                .times(1)
+                .returning(|_| Box::pin(async move { Err(anyhow!("Should fail")) }));
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let backoff = Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+            max_attempts: 3,
+        };
+        let service = ServiceRunnerBuilder::new(service, ())
+            .restart_policy(RestartPolicy::OnError(backoff))
+            .build();
+        service.start().unwrap();
+
+        let state = service.await_stop().await.unwrap();
+        assert!(
+            matches!(state, State::StoppedWithError(s) if s.contains("3 times in a row"))
+        );
+        assert_eq!(
+            service.error(),
+            Some(ServiceError::TooManyFailures {
+                service: "RebuiltService",
+                failures: 3,
+            })
+        );
+        assert_eq!(service.shared.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn failed_rebuild_stops_service() {
+        let service = RebuiltService::new(|build| {
+            if build > 0 {
+                return Err(anyhow!("Should fail"))
+            }
+            let mut mock = MockTask::default();
+            mock.expect_run()
+                .returning(|_| Box::pin(async move { Err(anyhow!("Should restart")) }));
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let backoff = Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+            max_attempts: 3,
+        };
+        let service = ServiceRunnerBuilder::new(service, ())
+            .restart_policy(RestartPolicy::OnError(backoff))
+            .build();
+        service.start().unwrap();
+
+        service.await_stop().await.unwrap();
+        assert_eq!(
+            service.error(),
+            Some(ServiceError::RestartFailed {
+                service: "RebuiltService",
+                message: "Should fail".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn watchdog_cancels_stalled_iteration() {
+        let service = RebuiltService::new(|_| {
+            let mut mock = MockTask::default();
+            mock.expect_run()
+                .times(1)
+                .returning(|_| Box::pin(futures::future::pending()));
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let backoff = Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+            max_attempts: 2,
+        };
+        let service = ServiceRunnerBuilder::new(service, ())
+            .restart_policy(RestartPolicy::OnError(backoff))
+            .watchdog(Some(Duration::from_millis(10)))
+            .build();
+        service.start().unwrap();
+
+        let state = service.await_stop().await.unwrap();
+        assert!(
+            matches!(state, State::StoppedWithError(s) if s.contains("2 times in a row"))
+        );
+    }
+
+    #[tokio::test]
+    async fn restart_replaces_task_with_fresh_one() {
+        let service = RebuiltService::new(|_| {
+            let mut mock = MockTask::default();
+            mock.expect_run().returning(|watcher| {
+                let mut watcher = watcher.clone();
+                Box::pin(async move {
+                    watcher.while_started().await.unwrap();
+                    let should_continue = false;
+                    Ok(should_continue)
+                })
+            });
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let service = ServiceRunnerBuilder::new(service, ())
+            .restart_policy(RestartPolicy::Never)
+            .build();
+        service.start_and_await().await.unwrap();
+
+        let mut watcher = service.state_watcher();
+        service.restart().unwrap();
+        while !watcher.borrow_and_update().started() {
+            watcher.changed().await.unwrap();
+        }
+
+        assert_eq!(service.shared.load(std::sync::atomic::Ordering::SeqCst), 2);
+        let state = service.stop_and_await().await.unwrap();
+        assert!(matches!(state, State::Stopped));
+    }
+
+    #[tokio::test]
+    async fn restart_requires_restartable_service() {
+        let service = ServiceRunner::new(MockService::new_empty());
+        service.start_and_await().await.unwrap();
+
+        assert!(service.restart().is_err());
+        assert_eq!(service.state(), State::Started);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn stalled_iteration_is_recorded() {
+        let mut mock = MockService::default();
+        mock.expect_shared_data().returning(|| EmptyShared);
+        mock.expect_into_task().returning(|_, _| {
+            let mut mock = MockTask::default();
+            mock.expect_run().returning(|_| {
+                Box::pin(async move {
+                    // Blocks the thread like the heavy computation.
+                    std::thread::sleep(Duration::from_millis(20));
+                    Ok(false)
+                })
+            });
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let service = ServiceRunnerBuilder::new(mock, ())
+            .stall_threshold(Some(Duration::from_millis(10)))
+            .build();
+
+        service.start_and_await().await.unwrap();
+        service.await_stop().await.unwrap();
+
+        assert_eq!(SERVICES_METRICS.stalled_iterations(service.metrics()), 1);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn waiting_for_events_is_not_stalled() {
+        let service = RebuiltService::new(|_| {
+            let mut mock = MockTask::default();
+            mock.expect_run().returning(|_| {
+                Box::pin(async move {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    Ok(false)
+                })
+            });
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let service = ServiceRunnerBuilder::new(service, ())
+            .stall_threshold(Some(Duration::from_millis(10)))
+            .build();
+
+        service.start_and_await().await.unwrap();
+        service.await_stop().await.unwrap();
+
+        assert_eq!(SERVICES_METRICS.stalled_iterations(service.metrics()), 0);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn restarts_and_errors_are_recorded_per_instance() {
+        let new_service = || {
+            RebuiltService::new(|build| {
+                let mut mock = MockTask::default();
+                mock.expect_run().returning(move |_| {
+                    Box::pin(async move {
+                        if build < 2 {
+                            panic!("Should recover")
+                        }
+                        Ok(false)
+                    })
+                });
+                mock.expect_shutdown().returning(|| Ok(()));
+                Ok(mock)
+            })
+        };
+        let backoff = Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+            max_attempts: 1,
+        };
+        let restarted = ServiceRunnerBuilder::new(new_service(), ())
+            .restart_policy(RestartPolicy::Always(backoff))
+            .build();
+        let idle = ServiceRunner::new(new_service());
+        assert_ne!(restarted.metrics(), idle.metrics());
+
+        restarted.start().unwrap();
+        restarted.await_stop().await.unwrap();
+
+        assert_eq!(SERVICES_METRICS.restarts(restarted.metrics()), 2);
+        assert_eq!(
+            SERVICES_METRICS.last_error(restarted.metrics()),
+            Some(("panic".to_string(), 2))
+        );
+        assert_eq!(SERVICES_METRICS.restarts(idle.metrics()), 0);
+        assert_eq!(SERVICES_METRICS.last_error(idle.metrics()), None);
+    }
+
+    #[tokio::test]
+    async fn task_runs_on_given_runtime() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("dedicated")
+            .enable_all()
+            .build()
+            .unwrap();
+        let thread = SharedMutex::new(None);
+        let task_thread = thread.clone();
+        let mut mock = MockService::default();
+        mock.expect_shared_data().returning(|| EmptyShared);
+        mock.expect_into_task().returning(move |_, _| {
+            let thread = task_thread.clone();
+            let mut mock = MockTask::default();
+            mock.expect_run().returning(move |_| {
+                let name = std::thread::current().name().map(str::to_string);
+                thread.apply(|thread| *thread = name);
+                Box::pin(async move { Ok(false) })
+            });
+            mock.expect_shutdown().times(1).returning(|| Ok(()));
+            Ok(mock)
+        });
+        let service = ServiceRunnerBuilder::new(mock, ())
+            .runtime(Some(runtime.handle().clone()))
+            .build();
+
+        service.start_and_await().await.unwrap();
+        service.await_stop().await.unwrap();
+
+        assert_eq!(
+            thread.apply(|thread| thread.clone()),
+            Some("dedicated".to_string())
+        );
+        runtime.shutdown_background();
+    }
+
+    #[test]
+    fn backoff_delay_doubles_up_to_max() {
+        let backoff = Backoff {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(5),
+            max_attempts: 10,
+        };
+
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2), Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(4));
+        assert_eq!(backoff.delay(4), Duration::from_secs(5));
+        assert_eq!(backoff.delay(100), Duration::from_secs(5));
     }
 
     #[tokio::test]
@@ -556,6 +1813,44 @@ mod tests {
         assert!(matches!(state, State::Stopped));
     }
 
+    #[tokio::test]
+    async fn pause_and_resume_keeps_task() {
+        let service = ServiceRunner::new(MockService::new_empty());
+        service.start_and_await().await.unwrap();
+
+        service.pause().unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(service.state(), State::Paused);
+        assert!(!service.health().is_healthy());
+
+        service.resume().unwrap();
+        assert_eq!(service.state(), State::Started);
+        let state = service.stop_and_await().await.unwrap();
+        assert!(matches!(state, State::Stopped));
+    }
+
+    #[tokio::test]
+    async fn pause_requires_started_service() {
+        let service = ServiceRunner::new(MockService::new_empty());
+        assert!(service.pause().is_err());
+        assert!(service.resume().is_err());
+
+        service.start_and_await().await.unwrap();
+        assert!(service.resume().is_err());
+        service.pause().unwrap();
+        assert!(service.pause().is_err());
+    }
+
+    #[tokio::test]
+    async fn stop_paused_service() {
+        let service = ServiceRunner::new(MockService::new_empty());
+        service.start_and_await().await.unwrap();
+        service.pause().unwrap();
+
+        let state = service.stop_and_await().await.unwrap();
+        assert!(matches!(state, State::Stopped));
+    }
+
     #[tokio::test]
     async fn stop_unused_service() {
         let mut receiver;
@@ -570,4 +1865,32 @@ mod tests {
         receiver.changed().await.unwrap();
         assert!(matches!(receiver.borrow().clone(), State::Stopped));
     }
+
+    #[tokio::test]
+    async fn transitions_cover_full_lifecycle() {
+        use futures::StreamExt;
+
+        let service = ServiceRunner::new(MockService::new_empty());
+        let transitions = service.state_watcher().transitions();
+
+        service.start_and_await().await.unwrap();
+        service.pause().unwrap();
+        service.resume().unwrap();
+        service.stop_and_await().await.unwrap();
+
+        let expected = vec![
+            (State::NotStarted, State::Starting),
+            (State::Starting, State::Started),
+            (State::Started, State::Paused),
+            (State::Paused, State::Started),
+            (State::Started, State::Stopping),
+            (State::Stopping, State::Stopped),
+        ]
+        .into_iter()
+        .map(|(previous, new)| StateTransition { previous, new })
+        .collect::<Vec<_>>();
+        let transitions: Vec<_> = transitions.collect().await;
+        assert_eq!(transitions, expected);
+        assert_eq!(service.state_watcher().history(), expected);
+    }
 }
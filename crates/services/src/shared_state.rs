@@ -0,0 +1,126 @@
+//! The data shared between the task of the service and its readers, like the API.
+
+use crate::service::Shared;
+use core::ops::Deref;
+use tokio::sync::watch;
+
+/// The state shared by the service with other services. Readers get immutable snapshots
+/// of the state, so they never hold the lock while the service updates the state.
+pub trait SharedState {
+    /// The type of the shared data.
+    type Data;
+
+    /// Returns the snapshot of the latest version of the data.
+    fn snapshot(&self) -> Snapshot<Self::Data>;
+
+    /// Subscribes to new versions of the data. The receiver observes only the latest
+    /// version, intermediate versions may be skipped.
+    fn subscribe(&self) -> SnapshotReceiver<Self::Data>;
+}
+
+/// The immutable version of the shared data. It is cheap to clone.
+#[derive(Debug)]
+pub struct Snapshot<T> {
+    /// The version of the data. It is incremented by each update.
+    pub version: u64,
+    data: Shared<T>,
+}
+
+impl<T> Clone for Snapshot<T> {
+    fn clone(&self) -> Self {
+        Self {
+            version: self.version,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T> Deref for Snapshot<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+/// The receiver of new versions of the shared data.
+pub type SnapshotReceiver<T> = watch::Receiver<Snapshot<T>>;
+
+/// The copy-on-write implementation of the [`SharedState`]. The update copies the data
+/// only if some reader still holds the snapshot of the previous version.
+#[derive(Debug)]
+pub struct SharedSnapshot<T>(Shared<watch::Sender<Snapshot<T>>>);
+
+impl<T> Clone for SharedSnapshot<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> SharedSnapshot<T> {
+    /// Creates a new `SharedSnapshot` with the initial version of the data.
+    pub fn new(data: T) -> Self {
+        let snapshot = Snapshot {
+            version: 0,
+            data: Shared::new(data),
+        };
+        Self(Shared::new(watch::channel(snapshot).0))
+    }
+
+    /// Replaces the data with the new version and notifies subscribers.
+    pub fn replace(&self, data: T) {
+        self.0.send_modify(|snapshot| {
+            snapshot.version += 1;
+            snapshot.data = Shared::new(data);
+        });
+    }
+}
+
+impl<T> SharedSnapshot<T>
+where
+    T: Clone,
+{
+    /// Applies the function to the new version of the data and notifies subscribers.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut result = None;
+        self.0.send_modify(|snapshot| {
+            snapshot.version += 1;
+            result = Some(f(Shared::make_mut(&mut snapshot.data)));
+        });
+        result.expect("The function is called by `send_modify`")
+    }
+}
+
+impl<T> SharedState for SharedSnapshot<T> {
+    type Data = T;
+
+    fn snapshot(&self) -> Snapshot<T> {
+        self.0.borrow().clone()
+    }
+
+    fn subscribe(&self) -> SnapshotReceiver<T> {
+        self.0.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn snapshot_is_not_affected_by_update() {
+        let shared = SharedSnapshot::new(vec![1]);
+        let mut receiver = shared.subscribe();
+        let snapshot = shared.snapshot();
+
+        shared.update(|data| data.push(2));
+        receiver.changed().await.unwrap();
+
+        assert_eq!(*snapshot, vec![1]);
+        assert_eq!(snapshot.version, 0);
+        let latest = receiver.borrow().clone();
+        assert_eq!(*latest, vec![1, 2]);
+        assert_eq!(latest.version, 1);
+        assert_eq!(shared.snapshot().version, 1);
+    }
+}
@@ -1,6 +1,21 @@
 //! The module related to state of the service.
 
-use tokio::sync::watch;
+use crate::{
+    stream::{
+        unfold,
+        BoxStream,
+        IntoBoxStream,
+    },
+    Shared,
+};
+use std::collections::VecDeque;
+use tokio::sync::{
+    broadcast,
+    watch,
+};
+
+/// The number of the latest transitions retained in the history of the service.
+pub const STATE_HISTORY_CAPACITY: usize = 32;
 
 /// The lifecycle state of the service
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,6 +26,10 @@ pub enum State {
     Starting,
     /// Service is running as normal
     Started,
+    /// Service is temporarily suspended, but keeps its shared state
+    Paused,
+    /// Service replaces its task with a fresh one, but keeps its shared state
+    Restarting,
     /// Service is shutting down
     Stopping,
     /// Service is stopped
@@ -35,49 +54,276 @@ impl State {
         self == &State::Started
     }
 
+    /// is paused
+    pub fn paused(&self) -> bool {
+        self == &State::Paused
+    }
+
+    /// is restarting
+    pub fn restarting(&self) -> bool {
+        self == &State::Restarting
+    }
+
     /// is stopped
     pub fn stopped(&self) -> bool {
         matches!(self, State::Stopped | State::StoppedWithError(_))
     }
 }
 
+/// The error that stopped the service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceError {
+    /// The task of the service panicked.
+    Panicked {
+        /// The name of the service
+        service: &'static str,
+        /// The message of the panic
+        message: String,
+    },
+    /// The task failed more times in a row than the restart policy allows.
+    TooManyFailures {
+        /// The name of the service
+        service: &'static str,
+        /// The number of failures in a row
+        failures: u32,
+    },
+    /// The fresh task of the service couldn't be built for the restart.
+    RestartFailed {
+        /// The name of the service
+        service: &'static str,
+        /// The message of the error
+        message: String,
+    },
+}
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServiceError::Panicked { service, message } => {
+                write!(f, "The service `{service}` panicked: {message}")
+            }
+            ServiceError::TooManyFailures { service, failures } => write!(
+                f,
+                "The task of the service `{service}` failed {failures} times in a row"
+            ),
+            ServiceError::RestartFailed { service, message } => {
+                write!(f, "The service `{service}` failed to restart: {message}")
+            }
+        }
+    }
+}
+
+impl ServiceError {
+    /// The kind of the error from the fixed set, used to label it in metrics.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ServiceError::Panicked { .. } => "panicked",
+            ServiceError::TooManyFailures { .. } => "too_many_failures",
+            ServiceError::RestartFailed { .. } => "restart_failed",
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+/// The health of the service reported by the health probe.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Health {
+    /// Service works as expected
+    #[default]
+    Healthy,
+    /// Service is running, but can't fully perform its duties, e.g. doesn't have peers
+    Degraded(String),
+    /// Service is not running
+    Unhealthy(String),
+}
+
+impl Health {
+    /// is healthy
+    pub fn is_healthy(&self) -> bool {
+        self == &Health::Healthy
+    }
+
+    /// The health of the service in the `state`, without the service specific checks.
+    pub fn of_state(state: &State) -> Self {
+        match state {
+            State::Started => Health::Healthy,
+            State::Paused => Health::Degraded("The service is paused".to_string()),
+            State::Restarting => {
+                Health::Degraded("The service is restarting".to_string())
+            }
+            State::StoppedWithError(error) => {
+                Health::Unhealthy(format!("The service stopped with an error: {error}"))
+            }
+            state => Health::Unhealthy(format!("The service is {state:?}")),
+        }
+    }
+}
+
+/// The transition of the service from the `previous` state to the `new` state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateTransition {
+    /// The state before the transition
+    pub previous: State,
+    /// The state after the transition
+    pub new: State,
+}
+
+/// The journal of the state transitions. Unlike the `watch` channel, it doesn't skip
+/// intermediate states that change faster than watchers observe them. It also keeps
+/// the error that stopped the service and whether the service reported readiness.
+#[derive(Debug)]
+struct StateJournal {
+    history: parking_lot::Mutex<VecDeque<StateTransition>>,
+    transitions: broadcast::Sender<StateTransition>,
+    error: parking_lot::Mutex<Option<ServiceError>>,
+    ready: watch::Sender<bool>,
+}
+
+impl StateJournal {
+    fn new() -> Self {
+        let (transitions, _) = broadcast::channel(STATE_HISTORY_CAPACITY);
+        let (ready, _) = watch::channel(false);
+        Self {
+            history: parking_lot::Mutex::new(VecDeque::with_capacity(
+                STATE_HISTORY_CAPACITY,
+            )),
+            transitions,
+            error: parking_lot::Mutex::new(None),
+            ready,
+        }
+    }
+
+    fn record(&self, transition: StateTransition) {
+        let mut history = self.history.lock();
+        if history.len() == STATE_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(transition.clone());
+        // It is okay if nobody is subscribed to transitions.
+        let _ = self.transitions.send(transition);
+    }
+}
+
+/// Reports that the service is ready to serve, e.g. after it bound its listeners or
+/// caught up with the remote source. The service is ready while it is started after
+/// the first report. It can be cloned and moved into the task of the service.
+#[derive(Debug, Clone, Default)]
+pub struct ReadySignal(Option<Shared<StateJournal>>);
+
+impl ReadySignal {
+    /// Marks the service as ready. Repeated calls have no effect.
+    pub fn ready(&self) {
+        if let Some(journal) = &self.0 {
+            journal.ready.send_if_modified(|ready| !std::mem::replace(ready, true));
+        }
+    }
+}
+
+/// The wrapper around the `watch::Sender<State>` that records transitions of the state
+/// into the journal shared with watchers.
+#[derive(Debug)]
+pub(crate) struct StateSender {
+    sender: watch::Sender<State>,
+    journal: Shared<StateJournal>,
+}
+
+impl StateSender {
+    pub(crate) fn new(state: State) -> Self {
+        let (sender, _) = watch::channel(state);
+        Self {
+            sender,
+            journal: Shared::new(StateJournal::new()),
+        }
+    }
+
+    /// See [`watch::Sender::send_if_modified`].
+    pub(crate) fn send_if_modified<F>(&self, modify: F) -> bool
+    where
+        F: FnOnce(&mut State) -> bool,
+    {
+        self.sender.send_if_modified(|state| {
+            let previous = state.clone();
+            let modified = modify(state);
+            if modified {
+                // Recorded before watchers are notified, so they see the transition in
+                // the history.
+                self.journal.record(StateTransition {
+                    previous,
+                    new: state.clone(),
+                });
+            }
+            modified
+        })
+    }
+
+    /// Stops the service with the `error` unless it is already stopped. The error is
+    /// recorded before watchers are notified about the stopped state.
+    pub(crate) fn stop_with_error(&self, error: ServiceError) -> bool {
+        self.send_if_modified(|state| {
+            if state.stopped() {
+                return false
+            }
+            *self.journal.error.lock() = Some(error.clone());
+            *state = State::StoppedWithError(error.to_string());
+            true
+        })
+    }
+
+    /// See [`watch::Sender::borrow`].
+    pub(crate) fn borrow(&self) -> watch::Ref<'_, State> {
+        self.sender.borrow()
+    }
+
+    /// Creates a new watcher of the state.
+    pub(crate) fn subscribe(&self) -> StateWatcher {
+        StateWatcher {
+            receiver: self.sender.subscribe(),
+            journal: Some(self.journal.clone()),
+        }
+    }
+}
+
 /// The wrapper around the `watch::Receiver<State>`. It repeats the `Receiver` functionality +
 /// a new one.
 #[derive(Clone)]
-pub struct StateWatcher(watch::Receiver<State>);
+pub struct StateWatcher {
+    receiver: watch::Receiver<State>,
+    journal: Option<Shared<StateJournal>>,
+}
 
 #[cfg(feature = "test-helpers")]
 impl Default for StateWatcher {
     fn default() -> Self {
         let (_, receiver) = watch::channel(State::NotStarted);
-        Self(receiver)
+        receiver.into()
     }
 }
 
 impl StateWatcher {
     /// See [`watch::Receiver::borrow`].
     pub fn borrow(&self) -> watch::Ref<'_, State> {
-        self.0.borrow()
+        self.receiver.borrow()
     }
 
     /// See [`watch::Receiver::borrow_and_update`].
     pub fn borrow_and_update(&mut self) -> watch::Ref<'_, State> {
-        self.0.borrow_and_update()
+        self.receiver.borrow_and_update()
     }
 
     /// See [`watch::Receiver::has_changed`].
     pub fn has_changed(&self) -> Result<bool, watch::error::RecvError> {
-        self.0.has_changed()
+        self.receiver.has_changed()
     }
 
     /// See [`watch::Receiver::changed`].
     pub async fn changed(&mut self) -> Result<(), watch::error::RecvError> {
-        self.0.changed().await
+        self.receiver.changed().await
     }
 
     /// See [`watch::Receiver::same_channel`].
     pub fn same_channel(&self, other: &Self) -> bool {
-        self.0.same_channel(&other.0)
+        self.receiver.same_channel(&other.receiver)
     }
 }
 
@@ -95,10 +341,136 @@ impl StateWatcher {
             self.changed().await?;
         }
     }
+
+    /// Returns the signal that reports the readiness of the service. See
+    /// [`crate::RunnableService::READY_ON_START`].
+    ///
+    /// The signal of the watcher created from a bare `watch::Receiver` has no effect.
+    pub fn ready_signal(&self) -> ReadySignal {
+        ReadySignal(self.journal.clone())
+    }
+
+    /// Returns `true` if the service is started and reported readiness.
+    ///
+    /// The watcher created from a bare `watch::Receiver` is ready while it is started.
+    pub fn is_ready(&self) -> bool {
+        self.borrow().started()
+            && self
+                .journal
+                .as_ref()
+                .map_or(true, |journal| *journal.ready.borrow())
+    }
+
+    /// Waits until the service is started and ready. Returns an error if the service
+    /// stops before it becomes ready.
+    pub async fn await_ready(&self) -> anyhow::Result<()> {
+        let mut state = self.receiver.clone();
+        let mut ready = self.journal.as_ref().map(|journal| journal.ready.subscribe());
+        loop {
+            let current = state.borrow_and_update().clone();
+            if current.stopped() {
+                return Err(anyhow::anyhow!(
+                    "The service stopped before it became ready: {current:?}"
+                ))
+            }
+            let reported = ready
+                .as_mut()
+                .map_or(true, |ready| *ready.borrow_and_update());
+            if current.started() && reported {
+                return Ok(())
+            }
+            match ready.as_mut() {
+                Some(ready) => {
+                    tokio::select! {
+                        changed = state.changed() => changed?,
+                        changed = ready.changed() => changed?,
+                    }
+                }
+                None => state.changed().await?,
+            }
+        }
+    }
+
+    /// Returns the error that stopped the service, if it stopped with an error.
+    ///
+    /// The watcher created from a bare `watch::Receiver` has no error.
+    pub fn error(&self) -> Option<ServiceError> {
+        self.journal
+            .as_ref()
+            .and_then(|journal| journal.error.lock().clone())
+    }
+
+    /// Returns up to [`STATE_HISTORY_CAPACITY`] latest transitions of the state, from the
+    /// oldest to the newest.
+    ///
+    /// The watcher created from a bare `watch::Receiver` has no history.
+    pub fn history(&self) -> Vec<StateTransition> {
+        self.journal
+            .as_ref()
+            .map(|journal| journal.history.lock().iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the stream of the following transitions of the state. The stream ends
+    /// after the transition into the stopped state. Transitions are not skipped unless
+    /// the consumer lags behind by more than [`STATE_HISTORY_CAPACITY`] transitions.
+    ///
+    /// The watcher created from a bare `watch::Receiver` observes only the latest state,
+    /// so it may skip intermediate states.
+    pub fn transitions(&self) -> BoxStream<StateTransition> {
+        match &self.journal {
+            Some(journal) => {
+                let receiver = journal.transitions.subscribe();
+                // The transition into the stopped state is sent under the lock of the
+                // `watch` channel, so it is either observed here or received later.
+                let receiver = (!self.borrow().stopped()).then_some(receiver);
+                unfold(receiver, |receiver| async move {
+                    let mut receiver = receiver?;
+                    loop {
+                        match receiver.recv().await {
+                            Ok(transition) => {
+                                let receiver =
+                                    (!transition.new.stopped()).then_some(receiver);
+                                return Some((transition, receiver))
+                            }
+                            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                                tracing::warn!(
+                                    "Skipped {} transitions of the state",
+                                    skipped
+                                );
+                            }
+                            Err(broadcast::error::RecvError::Closed) => return None,
+                        }
+                    }
+                })
+                .into_boxed()
+            }
+            None => {
+                let mut receiver = self.receiver.clone();
+                let previous = receiver.borrow_and_update().clone();
+                let state = (!previous.stopped()).then_some((receiver, previous));
+                unfold(state, |state| async move {
+                    let (mut receiver, previous) = state?;
+                    receiver.changed().await.ok()?;
+                    let new = receiver.borrow_and_update().clone();
+                    let transition = StateTransition {
+                        previous,
+                        new: new.clone(),
+                    };
+                    let state = (!new.stopped()).then_some((receiver, new));
+                    Some((transition, state))
+                })
+                .into_boxed()
+            }
+        }
+    }
 }
 
 impl From<watch::Receiver<State>> for StateWatcher {
     fn from(receiver: watch::Receiver<State>) -> Self {
-        Self(receiver)
+        Self {
+            receiver,
+            journal: None,
+        }
     }
 }
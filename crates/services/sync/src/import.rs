@@ -3,6 +3,10 @@
 //! importing blocks from the network into the local blockchain.
 
 use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
     ops::RangeInclusive,
     sync::Arc,
 };
@@ -20,7 +24,10 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
-    services::p2p::SourcePeer,
+    services::p2p::{
+        PeerId,
+        SourcePeer,
+    },
 };
 use futures::{
     stream::{
@@ -37,6 +44,8 @@ use crate::{
     ports::{
         BlockImporterPort,
         ConsensusPort,
+        ImportError,
+        PeerReportReason,
         PeerToPeerPort,
     },
     state::State,
@@ -62,6 +71,10 @@ pub struct Config {
     pub max_get_header_requests: usize,
     /// The maximum number of get transaction requests to make in a single batch.
     pub max_get_txns_requests: usize,
+    /// The number of distinct peers that must serve the same invalid block
+    /// before the import halts. At this point the local node is more likely
+    /// at fault than the peers.
+    pub max_invalid_block_peers: usize,
 }
 
 impl Default for Config {
@@ -69,7 +82,35 @@ impl Default for Config {
         Self {
             max_get_header_requests: 10,
             max_get_txns_requests: 10,
+            max_invalid_block_peers: 3,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+/// The invalid blocks served by peers at the height that failed to import.
+pub(crate) struct InvalidBlocks {
+    height: Option<BlockHeight>,
+    /// The peers that served each invalid block at the height.
+    peers: HashMap<BlockId, HashSet<PeerId>>,
+}
+
+impl InvalidBlocks {
+    /// Record that the `peer` served the invalid block.
+    /// Returns the number of distinct peers that served this block.
+    fn insert(&mut self, height: BlockHeight, block_id: BlockId, peer: PeerId) -> usize {
+        if self.height != Some(height) {
+            self.height = Some(height);
+            self.peers.clear();
         }
+        let peers = self.peers.entry(block_id).or_default();
+        peers.insert(peer);
+        peers.len()
+    }
+
+    /// Did at least `count` distinct peers serve the same invalid block?
+    fn is_served_by(&self, count: usize) -> bool {
+        self.peers.values().any(|peers| peers.len() >= count)
     }
 }
 
@@ -86,6 +127,8 @@ pub(crate) struct Import<P, E, C> {
     executor: Arc<E>,
     /// Consensus port.
     consensus: Arc<C>,
+    /// Invalid blocks served by peers.
+    invalid_blocks: SharedMutex<InvalidBlocks>,
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -104,6 +147,7 @@ impl<P, E, C> Import<P, E, C> {
             p2p,
             executor,
             consensus,
+            invalid_blocks: SharedMutex::new(InvalidBlocks::default()),
         }
     }
 }
@@ -118,7 +162,22 @@ where
         &self,
         shutdown: &mut StateWatcher,
     ) -> anyhow::Result<bool> {
-        self.import_inner(shutdown).await?;
+        let result = self.import_inner(shutdown).await;
+
+        // If enough peers serve the same invalid block, then retrying other peers
+        // will not help, so the import stops until the operator intervenes.
+        let max_peers = self.params.max_invalid_block_peers;
+        if self
+            .invalid_blocks
+            .apply(|blocks| blocks.is_served_by(max_peers))
+        {
+            tracing::error!(
+                "{} peers served the same invalid block, halting the import",
+                max_peers
+            );
+            return Ok(false)
+        }
+        result?;
 
         Ok(wait_for_notify_or_shutdown(&self.notify, shutdown).await)
     }
@@ -161,6 +220,7 @@ where
             p2p,
             executor,
             consensus,
+            invalid_blocks,
             ..
         } = &self;
         // Request up to `max_get_header_requests` headers from the network.
@@ -168,9 +228,11 @@ where
         .map({
             let p2p = p2p.clone();
             let consensus_port = consensus.clone();
+            let invalid_blocks = invalid_blocks.clone();
             move |result| {
                 let p2p = p2p.clone();
                 let consensus_port = consensus_port.clone();
+                let invalid_blocks = invalid_blocks.clone();
                 async move {
                     // Short circuit on error.
                     let header = match result {
@@ -182,6 +244,7 @@ where
                         data: header,
                     } = header;
                     let id = header.entity.id();
+                    let height = *header.entity.height();
                     let block_id = SourcePeer { peer_id, data: id };
 
                     // Check the consensus is valid on this header.
@@ -190,6 +253,12 @@ where
                         .trace_err("Failed to check consensus on header")? 
                     {
                         tracing::warn!("Header {:?} failed consensus check", header);
+                        report_invalid_block(
+                            p2p.as_ref(),
+                            &invalid_blocks,
+                            height,
+                            block_id,
+                        );
                         return Ok(None)
                     }
 
@@ -219,17 +288,43 @@ where
         .then({
             let state = state.clone();
             let executor = executor.clone();
+            let p2p = p2p.clone();
+            let invalid_blocks = invalid_blocks.clone();
             move |block| {
                 let state = state.clone();
                 let executor = executor.clone();
+                let p2p = p2p.clone();
+                let invalid_blocks = invalid_blocks.clone();
                 async move {
                     // Short circuit on error.
-                    let block = match block {
+                    let SourcePeer {
+                        peer_id,
+                        data: block,
+                    } = match block {
                         Ok(b) => b,
                         Err(e) => return Err(e),
                     };
+                    let height = *block.entity.header().height();
+                    let block_id = SourcePeer {
+                        peer_id,
+                        data: block.entity.id(),
+                    };
 
-                    execute_and_commit(executor.as_ref(), &state, block).await
+                    match execute_and_commit(executor.as_ref(), &state, block).await {
+                        Ok(()) => Ok(()),
+                        Err(ImportError::InvalidBlock(err)) => {
+                            report_invalid_block(
+                                p2p.as_ref(),
+                                &invalid_blocks,
+                                height,
+                                block_id,
+                            );
+                            Err(err)
+                        }
+                        // The failure of the node says nothing about the block, so the
+                        // peer isn't blamed and the range is imported again.
+                        Err(ImportError::Local(err)) => Err(err),
+                    }
                 }
             }
             .instrument(tracing::debug_span!("execute_and_commit"))
@@ -252,6 +347,32 @@ where
     }
 }
 
+/// Reports the peer that served the invalid block and records the block,
+/// so the import can tell a faulty peer from a faulty local node.
+fn report_invalid_block<P>(
+    p2p: &P,
+    invalid_blocks: &SharedMutex<InvalidBlocks>,
+    height: BlockHeight,
+    block_id: SourcePeer<BlockId>,
+) where
+    P: PeerToPeerPort,
+{
+    let SourcePeer {
+        peer_id,
+        data: block_id,
+    } = block_id;
+    let _ = p2p
+        .report_peer(peer_id.clone(), PeerReportReason::InvalidBlock)
+        .trace_err("Failed to report peer");
+    let peers = invalid_blocks.apply(|blocks| blocks.insert(height, block_id, peer_id));
+    tracing::warn!(
+        "The invalid block {} at height {} was served by {} peers",
+        block_id,
+        *height,
+        peers
+    );
+}
+
 /// Waits for a notify or shutdown signal.
 /// Returns true if the notify signal was received.
 async fn wait_for_notify_or_shutdown(
@@ -337,7 +458,7 @@ async fn get_transactions_on_block<P>(
     p2p: &P,
     block_id: SourcePeer<BlockId>,
     header: SealedBlockHeader,
-) -> anyhow::Result<Option<SealedBlock>>
+) -> anyhow::Result<Option<SourcePeer<SealedBlock>>>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -346,20 +467,36 @@ where
         consensus,
     } = header;
 
+    let peer_id = block_id.peer_id.clone();
+
     // Request the transactions for this block.
-    Ok(p2p
+    let transactions = p2p
         .get_transactions(block_id)
         .await
         .trace_err("Failed to get transactions")?
-        .trace_none_warn("Could not find transactions for header")
-        .and_then(|transactions| {
-            let block = Block::try_from_executed(header, transactions)
-                .trace_none_warn("Failed to created header from executed transactions")?;
-            Some(SealedBlock {
-                entity: block,
-                consensus,
-            })
-        }))
+        .trace_none_warn("Could not find transactions for header");
+    let transactions = match transactions {
+        Some(transactions) => transactions,
+        None => return Ok(None),
+    };
+
+    let block = match Block::try_from_executed(header, transactions) {
+        Some(block) => block,
+        None => {
+            tracing::warn!("Failed to created header from executed transactions");
+            let _ = p2p
+                .report_peer(peer_id, PeerReportReason::InvalidTransactions)
+                .trace_err("Failed to report peer");
+            return Ok(None)
+        }
+    };
+    Ok(Some(SourcePeer {
+        peer_id,
+        data: SealedBlock {
+            entity: block,
+            consensus,
+        },
+    }))
 }
 
 #[tracing::instrument(
@@ -368,13 +505,13 @@ where
         height = **block.entity.header().height(),
         id = %block.entity.header().consensus.generated.application_hash
     ),
-    err
+    err(Debug)
 )]
 async fn execute_and_commit<E>(
     executor: &E,
     state: &SharedMutex<State>,
     block: SealedBlock,
-) -> anyhow::Result<()>
+) -> Result<(), ImportError>
 where
     E: BlockImporterPort + Send + Sync + 'static,
 {
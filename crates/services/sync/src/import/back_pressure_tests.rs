@@ -35,6 +35,7 @@ struct Input {
     Config{
         max_get_header_requests: 1,
         max_get_txns_requests: 1,
+        max_invalid_block_peers: 3,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -47,6 +48,7 @@ struct Input {
     Config{
         max_get_header_requests: 1,
         max_get_txns_requests: 1,
+        max_invalid_block_peers: 3,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -60,6 +62,7 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        max_invalid_block_peers: 3,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -73,6 +76,7 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        max_invalid_block_peers: 3,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -86,6 +90,7 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        max_invalid_block_peers: 3,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -170,6 +175,10 @@ impl PeerToPeerPort for PressurePeerToPeerPort {
         self.2.apply(|c| c.dec_transactions());
         self.0.get_transactions(block_id).await
     }
+
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
+        self.0.report_peer(peer, report)
+    }
 }
 
 #[async_trait::async_trait]
@@ -178,7 +187,7 @@ impl BlockImporterPort for PressureBlockImporterPort {
         self.0.committed_height_stream()
     }
 
-    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+    async fn execute_and_commit(&self, block: SealedBlock) -> Result<(), ImportError> {
         self.2.apply(|c| c.inc_executes());
         tokio::time::sleep(self.1).await;
         self.2.apply(|c| {
@@ -369,7 +369,7 @@ use super::*;
         executor
             .expect_execute_and_commit()
             .times(1)
-            .returning(|_| Err(anyhow::anyhow!("Some execution error")));
+            .returning(|_| Err(ImportError::InvalidBlock(anyhow::anyhow!("Some execution error"))));
         Mocks{
             consensus_port: DefaultMocks::times([1]),
             p2p: DefaultMocks::times([2, 1]),
@@ -387,7 +387,7 @@ use super::*;
             .times(1)
             .returning(|h| {
                 if **h.entity.header().height() == 4 {
-                    Err(anyhow::anyhow!("Some execution error"))
+                    Err(ImportError::InvalidBlock(anyhow::anyhow!("Some execution error")))
                 } else {
                     Ok(())
                 }
@@ -409,7 +409,7 @@ use super::*;
             .times(2)
             .returning(|h| {
                 if **h.entity.header().height() == 5 {
-                    Err(anyhow::anyhow!("Some execution error"))
+                    Err(ImportError::InvalidBlock(anyhow::anyhow!("Some execution error")))
                 } else {
                     Ok(())
                 }
@@ -472,6 +472,7 @@ async fn test_import_inner(
     let params = Config {
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        max_invalid_block_peers: 3,
     };
     let p2p = Arc::new(p2p);
 
@@ -485,6 +486,7 @@ async fn test_import_inner(
         p2p,
         executor,
         consensus,
+        invalid_blocks: SharedMutex::new(InvalidBlocks::default()),
     };
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
     let mut watcher = shutdown.into();
@@ -509,6 +511,131 @@ async fn test_import_inner(
     (s, r)
 }
 
+#[tokio::test]
+async fn import_reports_peer_serving_invalid_block() {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_header()
+        .times(1)
+        .returning(|h| Ok(Some(empty_header(h))));
+    p2p.expect_get_transactions()
+        .times(1)
+        .returning(|_| Ok(Some(vec![])));
+    p2p.expect_report_peer()
+        .times(1)
+        .withf(|_, report| *report == PeerReportReason::InvalidBlock)
+        .returning(|_, _| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Err(ImportError::InvalidBlock(anyhow::anyhow!("Invalid block"))));
+    let import = Import::new(
+        SharedMutex::new(State::new(3, 4)),
+        Arc::new(Notify::new()),
+        Config::default(),
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(DefaultMocks::times([1])),
+    );
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+
+    let result = import.import(&mut watcher).await;
+
+    // The import fails, so the range is retried with other peers.
+    assert!(result.is_err());
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(3, None));
+}
+
+#[tokio::test]
+async fn import_retries_local_failure_without_reporting_peer() {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_header()
+        .times(2)
+        .returning(|h| Ok(Some(empty_header(h))));
+    p2p.expect_get_transactions()
+        .times(2)
+        .returning(|_| Ok(Some(vec![])));
+    p2p.expect_report_peer().times(0);
+    let mut executor = MockBlockImporterPort::default();
+    let mut calls = 0;
+    executor
+        .expect_execute_and_commit()
+        .times(2)
+        .returning(move |_| {
+            calls += 1;
+            if calls == 1 {
+                Err(ImportError::Local(anyhow::anyhow!("The database is busy")))
+            } else {
+                Ok(())
+            }
+        });
+    let import = Import::new(
+        SharedMutex::new(State::new(3, 4)),
+        Arc::new(Notify::new()),
+        Config::default(),
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(DefaultMocks::times([2])),
+    );
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+
+    // The node failed to import the block, so the peer isn't reported.
+    assert!(import.import(&mut watcher).await.is_err());
+    assert!(!import.invalid_blocks.apply(|blocks| blocks.is_served_by(1)));
+    import.state.apply(|s| s.observe(4));
+
+    // The same block is imported once the node recovers.
+    import.notify.notify_one();
+    assert!(import.import(&mut watcher).await.unwrap());
+    assert_eq!(import.state.apply(|s| s.clone()), State::new(4, None));
+}
+
+#[tokio::test]
+async fn import_halts_when_peers_serve_same_invalid_block() {
+    let mut p2p = MockPeerToPeerPort::default();
+    let mut peer = 0u8;
+    p2p.expect_get_sealed_block_header()
+        .times(2)
+        .returning(move |h| {
+            peer += 1;
+            let mut header = empty_header(h);
+            header.peer_id = vec![peer].into();
+            Ok(Some(header))
+        });
+    p2p.expect_get_transactions()
+        .times(2)
+        .returning(|_| Ok(Some(vec![])));
+    p2p.expect_report_peer().times(2).returning(|_, _| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(2)
+        .returning(|_| Err(ImportError::InvalidBlock(anyhow::anyhow!("Invalid block"))));
+    let params = Config {
+        max_invalid_block_peers: 2,
+        ..Default::default()
+    };
+    let import = Import::new(
+        SharedMutex::new(State::new(3, 4)),
+        Arc::new(Notify::new()),
+        params,
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(DefaultMocks::times([2])),
+    );
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+
+    // The first peer serves the invalid block, so the import retries.
+    assert!(import.import(&mut watcher).await.is_err());
+    import.state.apply(|s| s.observe(4));
+
+    // The second peer serves the same invalid block, so the import halts.
+    assert!(!import.import(&mut watcher).await.unwrap());
+}
+
 struct Mocks {
     consensus_port: MockConsensusPort,
     p2p: MockPeerToPeerPort,
@@ -583,6 +710,7 @@ impl DefaultMocks for MockPeerToPeerPort {
         p2p.expect_get_transactions()
             .times(t.next().unwrap())
             .returning(|_| Ok(Some(vec![])));
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
         p2p
     }
 }
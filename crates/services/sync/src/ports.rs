@@ -12,9 +12,31 @@ use fuel_core_types::{
     },
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
-    services::p2p::SourcePeer,
+    services::p2p::{
+        PeerId,
+        SourcePeer,
+    },
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The reason to report a peer to the reputation system.
+pub enum PeerReportReason {
+    /// The peer served a block that failed the consensus check or the execution.
+    InvalidBlock,
+    /// The peer served transactions that don't match the header of the block.
+    InvalidTransactions,
+}
+
+#[derive(Debug)]
+/// The failure of the import of the block.
+pub enum ImportError {
+    /// The block is invalid, so the peer that served it is at fault.
+    InvalidBlock(anyhow::Error),
+    /// The node failed to import the block, e.g. because of the database error. The
+    /// block may be valid, so it is imported again without blaming the peer.
+    Local(anyhow::Error),
+}
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 /// Port for communication with the network.
@@ -25,6 +47,10 @@ pub trait PeerToPeerPort {
     /// Request sealed block header from the network
     /// at the given height.
     ///
+    /// The header is requested from the peer that serves the whole block, so
+    /// the transactions can be requested from the same peer. Fails if no
+    /// connected peer serves the block.
+    ///
     /// Returns the source peer this header was received from.
     async fn get_sealed_block_header(
         &self,
@@ -37,6 +63,10 @@ pub trait PeerToPeerPort {
         &self,
         block_id: SourcePeer<BlockId>,
     ) -> anyhow::Result<Option<Vec<Transaction>>>;
+
+    /// Report the peer for serving invalid data, so the network
+    /// can lower its reputation and ban it.
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -58,5 +88,5 @@ pub trait BlockImporterPort {
 
     /// Execute the given sealed block
     /// and commit it to the database.
-    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+    async fn execute_and_commit(&self, block: SealedBlock) -> Result<(), ImportError>;
 }
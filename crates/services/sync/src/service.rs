@@ -21,10 +21,12 @@ use fuel_core_services::{
         BoxStream,
         IntoBoxStream,
     },
+    Health,
     RunnableService,
     RunnableTask,
     Service,
     ServiceRunner,
+    ServiceRunnerBuilder,
     SharedMutex,
     StateWatcher,
 };
@@ -35,13 +37,16 @@ use tokio::sync::Notify;
 #[cfg(test)]
 mod tests;
 
-/// Creates an instance of runnable sync service.
+/// Creates an instance of runnable sync service. The import of blocks runs on the
+/// `runtime` if it is set, so the execution of blocks may be isolated from other
+/// services.
 pub fn new_service<P, E, C>(
     current_fuel_block_height: BlockHeight,
     p2p: P,
     executor: E,
     consensus: C,
     params: Config,
+    runtime: Option<tokio::runtime::Handle>,
 ) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C>>>
 where
     P: ports::PeerToPeerPort + Send + Sync + 'static,
@@ -59,9 +64,23 @@ where
         p2p,
         executor,
         consensus,
+        runtime,
     )?))
 }
 
+/// The shared state of the sync service.
+#[derive(Clone)]
+pub struct SharedState {
+    state: SharedMutex<State>,
+}
+
+impl SharedState {
+    /// Is the node in sync with heights observed from the network?
+    pub fn is_synced(&self) -> bool {
+        self.state.apply(|state| state.is_synced())
+    }
+}
+
 /// Task for syncing heights.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
@@ -72,6 +91,7 @@ where
 {
     sync_heights: SyncHeights,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    shared: SharedState,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
@@ -90,6 +110,7 @@ where
         p2p: P,
         executor: E,
         consensus: C,
+        runtime: Option<tokio::runtime::Handle>,
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
@@ -102,11 +123,17 @@ where
             state.clone(),
             notify.clone(),
         );
+        let shared = SharedState {
+            state: state.clone(),
+        };
         let import = Import::new(state, notify, params, p2p, executor, consensus);
-        let import_task_handle = ServiceRunner::new(ImportTask(import));
+        let import_task_handle = ServiceRunnerBuilder::new(ImportTask(import), ())
+            .runtime(runtime)
+            .build();
         Ok(Self {
             sync_heights,
             import_task_handle,
+            shared,
         })
     }
 }
@@ -139,12 +166,22 @@ where
 {
     const NAME: &'static str = "fuel-core-sync";
 
-    type SharedData = ();
+    type SharedData = SharedState;
 
     type Task = SyncTask<P, E, C>;
     type TaskParams = ();
 
-    fn shared_data(&self) -> Self::SharedData {}
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    fn health(shared: &Self::SharedData) -> Health {
+        if shared.is_synced() {
+            Health::Healthy
+        } else {
+            Health::Degraded("The node is behind the network".to_string())
+        }
+    }
 
     async fn into_task(
         mut self,
@@ -54,8 +54,9 @@ async fn test_new_service() {
     let params = Config {
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        max_invalid_block_peers: 3,
     };
-    let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
+    let s = new_service(4u32.into(), p2p, importer, consensus, params, None).unwrap();
 
     assert_eq!(
         s.start_and_await().await.unwrap(),
@@ -78,6 +78,11 @@ impl State {
         }
     }
 
+    /// Is there nothing to sync with the network?
+    pub fn is_synced(&self) -> bool {
+        !matches!(self.status, Status::Processing(_))
+    }
+
     #[tracing::instrument]
     /// Record that a block has been committed.
     pub fn commit(&mut self, height: u32) {
@@ -12,6 +12,15 @@ fn test_new(state: State) -> Status {
     state.status
 }
 
+#[test_case(State::new(None, None) => true)]
+#[test_case(State::new(10, None) => true)]
+#[test_case(State::new(10, 10) => true)]
+#[test_case(State::new(10, 11) => false)]
+#[test_case(State::new(None, 10) => false)]
+fn test_is_synced(state: State) -> bool {
+    state.is_synced()
+}
+
 #[test_case(State::new(None, None), 0 => Status::Committed(0))]
 #[test_case(State::new(0, None), 0 => Status::Committed(0))]
 #[test_case(State::new(1, None), 0 => Status::Committed(1))]
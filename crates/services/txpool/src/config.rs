@@ -17,6 +17,12 @@ pub struct Config {
     pub metrics: bool,
     /// Transaction TTL
     pub transaction_ttl: Duration,
+    /// Records the timeline of lifecycle stages of the last `max_tx` transactions
+    pub tx_timeline: bool,
+    /// The number of threads verifying predicates and signatures of inserted
+    /// transactions. The single transaction verifies its predicates and signatures in
+    /// parallel. Transactions are verified sequentially if it is less than 2.
+    pub verification_threads: usize,
 }
 
 impl Default for Config {
@@ -54,6 +60,8 @@ impl Config {
             chain_config,
             metrics,
             transaction_ttl,
+            tx_timeline: false,
+            verification_threads: 1,
         }
     }
 }
@@ -49,8 +49,6 @@ pub struct Dependency {
     messages: HashMap<Nonce, MessageState>,
     /// max depth of dependency.
     max_depth: usize,
-    /// utxo-validation feature flag
-    utxo_validation: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -96,13 +94,12 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(max_depth: usize) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
-            utxo_validation,
         }
     }
 
@@ -167,17 +164,20 @@ impl Dependency {
     }
 
     fn check_if_coin_input_can_spend_db_coin(
+        db: &dyn TxPoolDb,
         coin: &CompressedCoin,
         input: &Input,
     ) -> anyhow::Result<()> {
         match input {
             Input::CoinSigned(CoinSigned {
+                utxo_id,
                 owner,
                 amount,
                 asset_id,
                 ..
             })
             | Input::CoinPredicate(CoinPredicate {
+                utxo_id,
                 owner,
                 amount,
                 asset_id,
@@ -192,6 +192,16 @@ impl Dependency {
                 if *asset_id != coin.asset_id {
                     return Err(Error::NotInsertedIoWrongAssetId.into())
                 }
+                // The transaction can be included in the next block at the earliest.
+                let next_height = db.current_block_height()? + 1.into();
+                let matures_at = coin.tx_pointer.block_height() + coin.maturity;
+                if next_height < matures_at {
+                    return Err(Error::NotInsertedInputUtxoIdNotMatured {
+                        utxo_id: *utxo_id,
+                        matures_at,
+                    }
+                    .into())
+                }
                 Ok(())
             }
             _ => Err(anyhow!("Use it only for coin output check")),
@@ -344,6 +354,7 @@ impl Dependency {
         txs: &'a HashMap<TxId, TxInfo>,
         db: &dyn TxPoolDb,
         tx: &'a ArcPoolTx,
+        utxo_validation: bool,
     ) -> anyhow::Result<(
         usize,
         HashMap<UtxoId, CoinState>,
@@ -384,14 +395,14 @@ impl Dependency {
                             } else {
                                 if state.is_in_database() {
                                     // this means it is loaded from db. Get tx to compare output.
-                                    if self.utxo_validation {
+                                    if utxo_validation {
                                         let coin = db.utxo(utxo_id)?.ok_or(
                                             Error::NotInsertedInputUtxoIdNotExisting(
                                                 *utxo_id,
                                             ),
                                         )?;
                                         Self::check_if_coin_input_can_spend_db_coin(
-                                            &coin, input,
+                                            db, &coin, input,
                                         )?;
                                     }
                                 } else {
@@ -409,13 +420,15 @@ impl Dependency {
                         }
                         // if coin is not spend, it will be spend later down the line
                     } else {
-                        if self.utxo_validation {
+                        if utxo_validation {
                             // fetch from db and check if tx exist.
                             let coin = db.utxo(utxo_id)?.ok_or(
                                 Error::NotInsertedInputUtxoIdNotExisting(*utxo_id),
                             )?;
 
-                            Self::check_if_coin_input_can_spend_db_coin(&coin, input)?;
+                            Self::check_if_coin_input_can_spend_db_coin(
+                                db, &coin, input,
+                            )?;
                         }
                         max_depth = core::cmp::max(1, max_depth);
                     }
@@ -434,7 +447,7 @@ impl Dependency {
                 | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
                 | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
                     // since message id is derived, we don't need to double check all the fields
-                    if self.utxo_validation {
+                    if utxo_validation {
                         if let Some(db_message) = db.message(nonce)? {
                             // verify message id integrity
                             Self::check_if_message_input_matches_database(
@@ -546,19 +559,21 @@ impl Dependency {
         Ok((max_depth, db_coins, db_contracts, db_messages, collided))
     }
 
-    /// insert tx inside dependency
+    /// insert tx inside dependency, inputs are checked against the database
+    /// only if `utxo_validation` is enabled.
     /// return list of transactions that are removed from txpool
     pub(crate) fn insert<'a, DB>(
         &'a mut self,
         txs: &'a HashMap<TxId, TxInfo>,
         db: &DB,
         tx: &'a ArcPoolTx,
+        utxo_validation: bool,
     ) -> anyhow::Result<Vec<ArcPoolTx>>
     where
         DB: TxPoolDb,
     {
         let (max_depth, db_coins, db_contracts, db_messages, collided) =
-            self.check_for_collision(txs, db, tx)?;
+            self.check_for_collision(txs, db, tx, utxo_validation)?;
 
         // now we are sure that transaction can be included. remove all collided transactions
         let mut removed_tx = Vec::new();
@@ -10,6 +10,8 @@ pub mod config;
 mod containers;
 pub mod ports;
 pub mod service;
+pub mod skipped;
+pub mod timeline;
 mod transaction_selector;
 pub mod txpool;
 pub mod types;
@@ -25,6 +27,10 @@ pub use service::{
     new_service,
     Service,
 };
+pub use timeline::{
+    TxStage,
+    TxTimeline,
+};
 pub use txpool::TxPool;
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -4,7 +4,13 @@ use crate::{
         PeerToPeer,
         TxPoolDb,
     },
+    skipped::SkippedTransactions,
+    timeline::{
+        TxStage,
+        TxTimeline,
+    },
     transaction_selector::select_transactions,
+    txpool::TxChecker,
     Config,
     Error as TxPoolError,
     TxInfo,
@@ -12,9 +18,13 @@ use crate::{
 };
 use fuel_core_services::{
     stream::BoxStream,
+    ConfigUpdate,
     RunnableService,
     RunnableTask,
     ServiceRunner,
+    SharedSnapshot,
+    SharedState as _,
+    SnapshotReceiver,
     StateWatcher,
 };
 use fuel_core_types::{
@@ -27,9 +37,11 @@ use fuel_core_types::{
     fuel_types::{
         BlockHeight,
         Bytes32,
+        Word,
     },
     services::{
         block_importer::ImportResult,
+        executor::Error as ExecutorError,
         p2p::{
             GossipData,
             GossipsubMessageAcceptance,
@@ -40,6 +52,7 @@ use fuel_core_types::{
             ArcPoolTx,
             Error,
             InsertionResult,
+            SkipReason,
             TxStatus,
         },
     },
@@ -54,36 +67,96 @@ use tokio_stream::StreamExt;
 
 pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
 
+/// The number of pool events buffered for every subscriber of
+/// [`SharedState::pool_events_subscribe`]. The subscriber that falls behind by more
+/// events misses the oldest of them.
+pub const POOL_EVENTS_CAPACITY: usize = 1024;
+
 #[derive(Clone)]
 pub struct TxStatusChange {
     status_sender: broadcast::Sender<TxStatus>,
     update_sender: broadcast::Sender<TxUpdate>,
+    event_sender: broadcast::Sender<PoolEvent>,
+    timeline: TxTimeline,
+    skipped: SkippedTransactions,
 }
 
 impl TxStatusChange {
-    pub fn new(capacity: usize) -> Self {
+    pub fn new(
+        capacity: usize,
+        timeline: TxTimeline,
+        skipped: SkippedTransactions,
+    ) -> Self {
         let (status_sender, _) = broadcast::channel(capacity);
         let (update_sender, _) = broadcast::channel(capacity);
+        let (event_sender, _) = broadcast::channel(POOL_EVENTS_CAPACITY);
         Self {
             status_sender,
             update_sender,
+            event_sender,
+            timeline,
+            skipped,
         }
     }
 
+    pub fn timeline(&self) -> &TxTimeline {
+        &self.timeline
+    }
+
     pub fn send_complete(&self, id: Bytes32, block_height: &BlockHeight) {
         tracing::info!("Transaction {id} successfully included in block {block_height}");
+        self.timeline.record(id, TxStage::Committed);
         let _ = self.status_sender.send(TxStatus::Completed);
         self.updated(id);
+        self.event(PoolEvent::Committed {
+            tx_id: id,
+            height: *block_height,
+        });
     }
 
     pub fn send_submitted(&self, id: Bytes32) {
         tracing::info!("Transaction {id} successfully submitted to the tx pool");
+        self.timeline.record(id, TxStage::Validated);
+        self.skipped.remove(&id);
         let _ = self.status_sender.send(TxStatus::Submitted);
         self.updated(id);
     }
 
+    /// Notifies subscribers about the transaction inserted into the pool.
+    pub fn send_inserted(&self, tx: &ArcPoolTx) {
+        self.send_submitted(tx.id());
+        self.event(PoolEvent::Inserted {
+            tx_id: tx.id(),
+            gas_price: tx.price(),
+            max_gas: tx.max_gas(),
+            metered_bytes_size: tx.metered_bytes_size(),
+        });
+    }
+
+    /// Notifies subscribers about the transaction pushed out by the transaction `by`.
+    pub fn send_replaced(&self, id: Bytes32, by: Bytes32) {
+        self.squeezed_out(id, TxPoolError::Removed);
+        self.event(PoolEvent::Replaced { tx_id: id, by });
+    }
+
     pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
+        self.squeezed_out(id, reason.clone());
+        self.event(PoolEvent::Evicted { tx_id: id, reason });
+    }
+
+    /// Notifies subscribers about the transaction selected into the block at the
+    /// `height`.
+    pub fn send_selected(&self, id: Bytes32, height: BlockHeight) {
+        self.timeline.record(id, TxStage::Selected);
+        self.event(PoolEvent::Selected { tx_id: id, height });
+    }
+
+    fn squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
+        self.timeline.record(id, TxStage::SqueezedOut);
+        if let TxPoolError::SkippedTransaction(skip_reason) = &reason {
+            self.skipped.record(id, skip_reason.clone());
+        }
         let _ = self.status_sender.send(TxStatus::SqueezedOut {
             reason: reason.clone(),
         });
@@ -93,11 +166,24 @@ impl TxStatusChange {
     fn updated(&self, id: Bytes32) {
         let _ = self.update_sender.send(TxUpdate::updated(id));
     }
+
+    fn event(&self, event: PoolEvent) {
+        let _ = self.event_sender.send(event);
+    }
+}
+
+/// The summary of the pool published after each change, so the block production can
+/// read it without contending for the lock of the pool.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    pub pending_number: usize,
+    pub consumable_gas: u64,
 }
 
 pub struct SharedState<P2P, DB> {
     tx_status_sender: TxStatusChange,
     txpool: Arc<ParkingMutex<TxPool<DB>>>,
+    stats: SharedSnapshot<PoolStats>,
     p2p: Arc<P2P>,
     consensus_params: ConsensusParameters,
 }
@@ -107,6 +193,7 @@ impl<P2P, DB> Clone for SharedState<P2P, DB> {
         Self {
             tx_status_sender: self.tx_status_sender.clone(),
             txpool: self.txpool.clone(),
+            stats: self.stats.clone(),
             p2p: self.p2p.clone(),
             consensus_params: self.consensus_params,
         }
@@ -136,6 +223,22 @@ where
         self.shared.clone()
     }
 
+    fn validate_reload(
+        _: &Self::SharedData,
+        update: &ConfigUpdate,
+    ) -> anyhow::Result<ConfigUpdate> {
+        Ok(ConfigUpdate {
+            min_gas_price: update.min_gas_price,
+            ..Default::default()
+        })
+    }
+
+    fn reload(shared: &Self::SharedData, update: &ConfigUpdate) {
+        if let Some(min_gas_price) = update.min_gas_price {
+            shared.set_min_gas_price(min_gas_price);
+        }
+    }
+
     async fn into_task(
         mut self,
         _: &StateWatcher,
@@ -163,7 +266,7 @@ where
             }
 
             _ = self.ttl_timer.tick() => {
-                let removed = self.shared.txpool.lock().prune_old_txs();
+                let removed = self.shared.update_pool(|txpool| txpool.prune_old_txs());
                 for tx in removed {
                     self.shared.tx_status_sender.send_squeezed_out(tx.id(), Error::TTLReason);
                 }
@@ -173,7 +276,8 @@ where
 
             result = self.committed_block_stream.next() => {
                 if let Some(result) = result {
-                    self.shared.txpool.lock().block_update(&self.shared.tx_status_sender, &result.sealed_block);
+                    let block = &result.sealed_block;
+                    self.shared.update_pool(|txpool| txpool.block_update(&self.shared.tx_status_sender, block));
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -185,12 +289,7 @@ where
                     let id = tx.id(&self.shared.consensus_params);
                     let txs = vec!(Arc::new(tx));
                     let mut result = tracing::info_span!("Received tx via gossip", %id)
-                        .in_scope(|| {
-                            self.shared.txpool.lock().insert(
-                                &self.shared.tx_status_sender,
-                                &txs
-                            )
-                        });
+                        .in_scope(|| self.shared.check_and_insert(&txs, None));
 
                     if let Some(acceptance) = match result.pop() {
                         Some(Ok(_)) => {
@@ -234,16 +333,62 @@ impl<P2P, DB> SharedState<P2P, DB>
 where
     DB: TxPoolDb,
 {
+    /// Applies the change to the pool and publishes the new [`PoolStats`].
+    fn update_pool<R>(&self, f: impl FnOnce(&mut TxPool<DB>) -> R) -> R {
+        let mut txpool = self.txpool.lock();
+        let result = f(&mut txpool);
+        self.stats.replace(PoolStats {
+            pending_number: txpool.pending_number(),
+            consumable_gas: txpool.consumable_gas(),
+        });
+        result
+    }
+
     pub fn pending_number(&self) -> usize {
-        self.txpool.lock().pending_number()
+        self.stats.snapshot().pending_number
+    }
+
+    pub fn pending_ids(&self) -> Vec<TxId> {
+        self.txpool.lock().txs().keys().copied().collect()
+    }
+
+    pub fn pending_txs(&self) -> Vec<TxInfo> {
+        self.txpool.lock().txs().values().cloned().collect()
     }
 
     pub fn total_consumable_gas(&self) -> u64 {
-        self.txpool.lock().consumable_gas()
+        self.stats.snapshot().consumable_gas
+    }
+
+    /// Subscribes to the [`PoolStats`] published after each change of the pool.
+    pub fn pool_stats_subscribe(&self) -> SnapshotReceiver<PoolStats> {
+        self.stats.subscribe()
     }
 
     pub fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
-        self.txpool.lock().remove(&self.tx_status_sender, &ids)
+        self.update_pool(|txpool| txpool.remove(&self.tx_status_sender, &ids))
+    }
+
+    /// Removes transactions skipped by the block producer. Subscribers to the status of
+    /// the transaction learn the error of the execution that made the producer skip it,
+    /// and [`Self::skipped_reason`] returns it for the time to live of transactions.
+    pub fn remove_skipped_txs(
+        &self,
+        skipped: Vec<(TxId, ExecutorError)>,
+    ) -> Vec<ArcPoolTx> {
+        let tx_ids = skipped
+            .into_iter()
+            .map(|(id, error)| (id, Error::SkippedTransaction(error.into())))
+            .collect();
+        self.update_pool(|txpool| {
+            txpool.remove_with_reasons(&self.tx_status_sender, tx_ids)
+        })
+    }
+
+    /// Returns the error of the execution that made the block producer skip the
+    /// transaction, if it was skipped recently.
+    pub fn skipped_reason(&self, id: &TxId) -> Option<SkipReason> {
+        self.tx_status_sender.skipped.get(id)
     }
 
     pub fn find(&self, ids: Vec<TxId>) -> Vec<Option<TxInfo>> {
@@ -258,19 +403,49 @@ where
         self.txpool.lock().find_dependent(&ids)
     }
 
-    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
-        let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+    /// Selects transactions for the block at the `height`. The selection works on the
+    /// snapshot of the pool, so it doesn't block insertions. Selected transactions stay
+    /// in the pool until the block is committed and can't be selected twice.
+    pub fn select_transactions(
+        &self,
+        height: BlockHeight,
+        max_gas: u64,
+    ) -> Vec<ArcPoolTx> {
+        self.select_transactions_with(height, |txs| select_transactions(txs, max_gas))
+    }
+
+    /// Selects transactions for the block at the `height` like
+    /// [`Self::select_transactions`], but the `select` chooses them among the
+    /// includable transactions of the pool.
+    pub fn select_transactions_with<Select>(
+        &self,
+        height: BlockHeight,
+        select: Select,
+    ) -> Vec<ArcPoolTx>
+    where
+        Select: FnOnce(Vec<ArcPoolTx>) -> Vec<ArcPoolTx>,
+    {
+        let snapshot = self.txpool.lock().snapshot(height);
+        let sorted_txs = select(snapshot.txs);
+        let sorted_txs = self.update_pool(|txpool| {
+            txpool.mark_selected(snapshot.generation, height, sorted_txs)
+        });
 
         for tx in sorted_txs.iter() {
-            guard.remove_committed_tx(&tx.id());
+            self.tx_status_sender.send_selected(tx.id(), height);
         }
         sorted_txs
     }
 
+    /// Returns the includable transactions for the block at the `height` in the order
+    /// of arrival. Unlike [`Self::select_transactions_with`], it doesn't mark them as
+    /// selected.
+    pub fn includable_transactions(&self, height: BlockHeight) -> Vec<ArcPoolTx> {
+        self.txpool.lock().snapshot(height).txs
+    }
+
     pub fn remove(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
-        self.txpool.lock().remove(&self.tx_status_sender, &ids)
+        self.update_pool(|txpool| txpool.remove(&self.tx_status_sender, &ids))
     }
 
     pub fn tx_status_subscribe(&self) -> broadcast::Receiver<TxStatus> {
@@ -280,6 +455,28 @@ where
     pub fn tx_update_subscribe(&self) -> broadcast::Receiver<TxUpdate> {
         self.tx_status_sender.update_sender.subscribe()
     }
+
+    /// Subscribes to every event of the pool. The subscription buffers up to the
+    /// [`POOL_EVENTS_CAPACITY`] events, the receiver lagging behind gets the
+    /// `RecvError::Lagged` with the number of missed events.
+    pub fn pool_events_subscribe(&self) -> broadcast::Receiver<PoolEvent> {
+        self.tx_status_sender.event_sender.subscribe()
+    }
+
+    pub fn tx_timeline(&self) -> TxTimeline {
+        self.tx_status_sender.timeline.clone()
+    }
+
+    /// Returns the minimal gas price of new transactions.
+    pub fn min_gas_price(&self) -> u64 {
+        self.txpool.lock().min_gas_price()
+    }
+
+    /// Changes the minimal gas price of new transactions. Transactions already in the
+    /// pool are kept.
+    pub fn set_min_gas_price(&self, min_gas_price: u64) {
+        self.txpool.lock().set_min_gas_price(min_gas_price)
+    }
 }
 
 impl<P2P, DB> SharedState<P2P, DB>
@@ -287,16 +484,27 @@ where
     P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
     DB: TxPoolDb,
 {
-    #[tracing::instrument(name = "insert_submitted_txn", skip_all)]
     pub fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
-        let insert = { self.txpool.lock().insert(&self.tx_status_sender, &txs) };
+        self.insert_with_utxo_validation(txs, None)
+    }
+
+    /// Inserts transactions into the pool, `utxo_validation` overrides the configuration
+    /// of the pool if it is set.
+    #[tracing::instrument(name = "insert_submitted_txn", skip_all)]
+    pub fn insert_with_utxo_validation(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        let insert = self.check_and_insert(&txs, utxo_validation);
 
         for (ret, tx) in insert.iter().zip(txs.into_iter()) {
             match ret {
                 Ok(_) => {
+                    let id = tx.id(&self.consensus_params);
                     let result = self.p2p.broadcast_transaction(tx.clone());
                     if let Err(e) = result {
                         // It can be only in the case of p2p being down or requests overloading it.
@@ -304,6 +512,8 @@ where
                             "Unable to broadcast transaction, got an {} error",
                             e
                         );
+                    } else {
+                        self.tx_status_sender.timeline.record(id, TxStage::Gossiped);
                     }
                 }
                 Err(_) => {}
@@ -311,6 +521,24 @@ where
         }
         insert
     }
+
+    /// Checks `txs` without holding the lock of the pool and inserts them into the pool.
+    fn check_and_insert(
+        &self,
+        txs: &[Arc<Transaction>],
+        utxo_validation: Option<bool>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        for tx in txs {
+            self.tx_status_sender
+                .timeline
+                .record(tx.id(&self.consensus_params), TxStage::Received);
+        }
+        let checker = self.txpool.lock().checker(utxo_validation);
+        let checked = TxChecker::check_batch_with(checker, txs);
+        self.update_pool(|txpool| {
+            txpool.insert_checked_batch(&self.tx_status_sender, checked, utxo_validation)
+        })
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -347,6 +575,39 @@ impl TxUpdate {
     }
 }
 
+/// The event of the transaction pool.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolEvent {
+    /// The transaction is inserted into the pool.
+    Inserted {
+        tx_id: Bytes32,
+        gas_price: Word,
+        max_gas: Word,
+        metered_bytes_size: usize,
+    },
+    /// The transaction is pushed out by the transaction `by` with the higher gas price,
+    /// either as the conflicting one or as the cheapest one of the full pool.
+    Replaced { tx_id: Bytes32, by: Bytes32 },
+    /// The transaction is removed from the pool without the inclusion into the block.
+    Evicted { tx_id: Bytes32, reason: TxPoolError },
+    /// The transaction is selected into the block at the `height`.
+    Selected { tx_id: Bytes32, height: BlockHeight },
+    /// The transaction is included into the committed block at the `height`.
+    Committed { tx_id: Bytes32, height: BlockHeight },
+}
+
+impl PoolEvent {
+    pub fn tx_id(&self) -> &Bytes32 {
+        match self {
+            PoolEvent::Inserted { tx_id, .. }
+            | PoolEvent::Replaced { tx_id, .. }
+            | PoolEvent::Evicted { tx_id, .. }
+            | PoolEvent::Selected { tx_id, .. }
+            | PoolEvent::Committed { tx_id, .. } => tx_id,
+        }
+    }
+}
+
 pub fn new_service<P2P, Importer, DB>(
     config: Config,
     db: DB,
@@ -364,13 +625,20 @@ where
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
     let consensus_params = config.chain_config.transaction_parameters;
+    let timeline = if config.tx_timeline {
+        TxTimeline::new(config.max_tx)
+    } else {
+        TxTimeline::disabled()
+    };
+    let skipped = SkippedTransactions::new(config.transaction_ttl);
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config, db)));
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
         shared: SharedState {
-            tx_status_sender: TxStatusChange::new(100),
+            tx_status_sender: TxStatusChange::new(100, timeline, skipped),
             txpool,
+            stats: SharedSnapshot::new(PoolStats::default()),
             p2p,
             consensus_params,
         },
@@ -15,6 +15,7 @@ use fuel_core_types::{
         SeedableRng,
     },
     fuel_tx::{
+        AssetId,
         Input,
         Transaction,
         TransactionBuilder,
@@ -53,6 +54,23 @@ impl TestContext {
     pub fn setup_coin(&self) -> (Coin, Input) {
         crate::test_helpers::setup_coin(&mut self.rng.borrow_mut(), Some(&self.mock_db))
     }
+
+    /// Creates the script transaction spending the coin owned by the predicate `code`.
+    pub fn setup_predicate_tx(&self, code: Vec<u8>) -> Transaction {
+        let input = crate::test_helpers::custom_predicate(
+            &mut self.rng.borrow_mut(),
+            AssetId::BASE,
+            crate::test_helpers::TEST_COIN_AMOUNT,
+            code,
+            None,
+        );
+        let (_, gas_coin) =
+            crate::test_helpers::add_coin_to_state(input, Some(&self.mock_db));
+        TransactionBuilder::script(vec![], vec![])
+            .gas_limit(1000)
+            .add_input(gas_coin)
+            .finalize_as_transaction()
+    }
 }
 
 mockall::mock! {
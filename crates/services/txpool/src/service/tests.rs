@@ -5,10 +5,20 @@ use crate::service::test_helpers::{
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
     fuel_tx::UniqueIdentifier,
-    services::txpool::Error as TxpoolError,
+    services::{
+        executor::Error as ExecutorError,
+        txpool::Error as TxpoolError,
+    },
+};
+use std::{
+    collections::HashSet,
+    time::Duration,
 };
-use std::time::Duration;
 
 #[tokio::test]
 async fn test_start_stop() {
@@ -253,3 +263,226 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn pool_stats_follow_changes_of_pool() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+
+    let service = ctx.service();
+    let mut stats = service.shared.pool_stats_subscribe();
+    for result in service.shared.insert(vec![tx1.clone(), tx2.clone()]) {
+        assert!(result.is_ok(), "Insertion should be OK, got err:{result:?}");
+    }
+    assert!(stats.has_changed().unwrap());
+    assert_eq!(service.shared.pending_number(), 2);
+    assert_eq!(
+        service.shared.total_consumable_gas(),
+        service.shared.txpool.lock().consumable_gas()
+    );
+
+    service
+        .shared
+        .remove_txs(vec![tx1.id(&ConsensusParameters::DEFAULT)]);
+    assert_eq!(stats.borrow_and_update().pending_number, 1);
+    assert_eq!(service.shared.pending_number(), 1);
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn single_transaction_is_verified_on_several_threads() {
+    let config = Config {
+        verification_threads: 2,
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config)
+        .build_and_start()
+        .await;
+
+    let valid =
+        Arc::new(ctx.setup_predicate_tx(vec![op::ret(RegId::ONE)].into_iter().collect()));
+    let invalid = Arc::new(
+        ctx.setup_predicate_tx(vec![op::ret(RegId::ZERO)].into_iter().collect()),
+    );
+
+    let service = ctx.service();
+    let result = service.shared.insert(vec![valid.clone()]).pop().unwrap();
+    assert!(result.is_ok(), "Valid tx should be OK, got err:{result:?}");
+    let err = service
+        .shared
+        .insert(vec![invalid])
+        .pop()
+        .unwrap()
+        .expect_err("Invalid tx should be Err, got Ok");
+    assert!(
+        err.to_string().contains("PredicateVerificationFailed"),
+        "unexpected error: {err}",
+    );
+    assert_eq!(
+        service.shared.pending_ids(),
+        vec![valid.id(&ConsensusParameters::DEFAULT)]
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn subscribers_learn_why_skipped_transaction_is_removed() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx1_id = tx1.id(&ConsensusParameters::DEFAULT);
+
+    let service = ctx.service();
+    let mut subscribe_update = service.shared.tx_update_subscribe();
+    for result in service.shared.insert(vec![tx1]) {
+        assert!(result.is_ok(), "Insertion should be OK, got err:{result:?}");
+    }
+    subscribe_update.recv().await.unwrap();
+
+    let removed = service
+        .shared
+        .remove_skipped_txs(vec![(tx1_id, ExecutorError::OutputAlreadyExists)]);
+    assert_eq!(removed.len(), 1);
+
+    let update = subscribe_update.recv().await.unwrap();
+    assert_eq!(*update.tx_id(), tx1_id);
+    assert_eq!(
+        update.into_squeezed_out_reason(),
+        Some(TxpoolError::SkippedTransaction(
+            ExecutorError::OutputAlreadyExists.into()
+        ))
+    );
+    assert_eq!(service.shared.pending_number(), 0);
+    assert_eq!(
+        service.shared.skipped_reason(&tx1_id),
+        Some(ExecutorError::OutputAlreadyExists.into())
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn select_transactions_is_consistent_with_concurrent_changes() {
+    const TX_NUM: usize = 300;
+    const BLOCKS: u32 = 50;
+
+    let ctx = TestContext::new().await;
+    let txs: Vec<_> = (0..TX_NUM)
+        .map(|i| Arc::new(ctx.setup_script_tx(10 + i as u64)))
+        .collect();
+    let shared = ctx.service().shared.clone();
+
+    // Every third transaction is inserted upfront and removed concurrently with
+    // the selection, the rest is inserted concurrently with the selection.
+    let (to_remove, to_insert): (Vec<_>, Vec<_>) = txs
+        .into_iter()
+        .enumerate()
+        .partition(|(i, _)| i % 3 == 0);
+    let to_remove: Vec<_> = to_remove.into_iter().map(|(_, tx)| tx).collect();
+    let to_insert: Vec<_> = to_insert.into_iter().map(|(_, tx)| tx).collect();
+    for result in shared.insert(to_remove.clone()) {
+        assert!(result.is_ok(), "Insertion should be OK, got err:{result:?}");
+    }
+
+    let removed = Arc::new(ParkingMutex::new(HashSet::new()));
+    let inserter = {
+        let shared = shared.clone();
+        std::thread::spawn(move || {
+            for tx in to_insert {
+                let _ = shared.insert(vec![tx]);
+            }
+        })
+    };
+    let remover = {
+        let shared = shared.clone();
+        let removed = removed.clone();
+        std::thread::spawn(move || {
+            for tx in to_remove {
+                let id = tx.id(&ConsensusParameters::DEFAULT);
+                shared.remove(vec![id]);
+                removed.lock().insert(id);
+            }
+        })
+    };
+
+    let mut selected = HashSet::new();
+    for height in 1..=BLOCKS {
+        let removed_before = removed.lock().clone();
+        for tx in shared.select_transactions(height.into(), 10_000) {
+            assert!(
+                !removed_before.contains(&tx.id()),
+                "Removed transaction was selected at height {height}"
+            );
+            assert!(
+                selected.insert(tx.id()),
+                "Transaction was selected twice at height {height}"
+            );
+        }
+        std::thread::yield_now();
+    }
+    inserter.join().unwrap();
+    remover.join().unwrap();
+
+    // Transactions that weren't selected or removed are still pending.
+    let pending = shared
+        .pending_ids()
+        .into_iter()
+        .filter(|id| !selected.contains(id))
+        .count();
+    assert_eq!(shared.pending_number(), pending);
+}
+
+#[tokio::test]
+async fn pool_events_cover_insertion_selection_and_eviction() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let tx1_id = tx1.id(&ConsensusParameters::DEFAULT);
+    let tx2_id = tx2.id(&ConsensusParameters::DEFAULT);
+    let service = ctx.service();
+    let mut events = service.shared.pool_events_subscribe();
+
+    for result in service.shared.insert(vec![tx1, tx2]) {
+        assert!(result.is_ok(), "Insertion should be OK, got err:{result:?}");
+    }
+    let height = 1u32.into();
+    let selected = service.shared.select_transactions(height, u64::MAX);
+    assert_eq!(selected.len(), 2);
+    service.shared.remove(vec![tx1_id]);
+
+    for (tx_id, gas_price) in [(tx1_id, 10), (tx2_id, 20)] {
+        match events.try_recv().unwrap() {
+            PoolEvent::Inserted {
+                tx_id: inserted,
+                gas_price: price,
+                ..
+            } => {
+                assert_eq!(inserted, tx_id);
+                assert_eq!(price, gas_price);
+            }
+            event => panic!("Expected the insertion, got {event:?}"),
+        }
+    }
+    for tx in selected.iter() {
+        assert_eq!(
+            events.try_recv().unwrap(),
+            PoolEvent::Selected {
+                tx_id: tx.id(),
+                height
+            }
+        );
+    }
+    assert_eq!(
+        events.try_recv().unwrap(),
+        PoolEvent::Evicted {
+            tx_id: tx1_id,
+            reason: TxpoolError::Removed
+        }
+    );
+    assert!(events.try_recv().is_err());
+
+    service.stop_and_await().await.unwrap();
+}
@@ -0,0 +1,110 @@
+//! The reasons why the block producer skipped recent transactions. They are kept for
+//! the time to live of transactions, so clients polling the status of the transaction
+//! learn why it was removed from the pool.
+
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::txpool::SkipReason,
+};
+use parking_lot::Mutex as ParkingMutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+#[derive(Debug, Default)]
+struct Inner {
+    reasons: HashMap<TxId, (SkipReason, Instant)>,
+    /// The order of records to evict expired ones.
+    order: VecDeque<(TxId, Instant)>,
+}
+
+/// Records the reasons of skipped transactions for the `ttl`.
+#[derive(Clone, Debug)]
+pub struct SkippedTransactions {
+    ttl: Duration,
+    inner: Arc<ParkingMutex<Inner>>,
+}
+
+impl SkippedTransactions {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            inner: Default::default(),
+        }
+    }
+
+    /// Records that the block producer skipped the transaction because of the `reason`.
+    pub fn record(&self, id: TxId, reason: SkipReason) {
+        let now = Instant::now();
+        let mut inner = self.inner.lock();
+        Self::evict_expired(&mut inner, now, self.ttl);
+        inner.reasons.insert(id, (reason, now));
+        inner.order.push_back((id, now));
+    }
+
+    /// Returns the reason why the block producer skipped the transaction, if it is
+    /// not expired yet.
+    pub fn get(&self, id: &TxId) -> Option<SkipReason> {
+        let mut inner = self.inner.lock();
+        Self::evict_expired(&mut inner, Instant::now(), self.ttl);
+        inner.reasons.get(id).map(|(reason, _)| reason.clone())
+    }
+
+    /// Forgets the transaction, for example, because it was inserted into the pool
+    /// again.
+    pub fn remove(&self, id: &TxId) {
+        self.inner.lock().reasons.remove(id);
+    }
+
+    fn evict_expired(inner: &mut Inner, now: Instant, ttl: Duration) {
+        while let Some((id, recorded_at)) = inner.order.front().copied() {
+            if now.saturating_duration_since(recorded_at) < ttl {
+                break
+            }
+            inner.order.pop_front();
+            // The transaction could be recorded again later, keep the newer record.
+            if matches!(inner.reasons.get(&id), Some((_, at)) if *at == recorded_at) {
+                inner.reasons.remove(&id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::services::executor::Error as ExecutorError;
+
+    #[test]
+    fn reason_is_returned_until_it_expires() {
+        let skipped = SkippedTransactions::new(Duration::from_millis(50));
+        let id = TxId::zeroed();
+        skipped.record(id, ExecutorError::OutputAlreadyExists.into());
+
+        assert_eq!(
+            skipped.get(&id),
+            Some(ExecutorError::OutputAlreadyExists.into())
+        );
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(skipped.get(&id), None);
+    }
+
+    #[test]
+    fn removed_transaction_has_no_reason() {
+        let skipped = SkippedTransactions::new(Duration::from_secs(60));
+        let id = TxId::zeroed();
+        skipped.record(id, ExecutorError::OutputAlreadyExists.into());
+        skipped.remove(&id);
+
+        assert_eq!(skipped.get(&id), None);
+    }
+}
@@ -0,0 +1,150 @@
+//! The timeline keeps the time of each lifecycle stage of recent transactions.
+//! It is used to debug why the inclusion of the transaction took so long.
+
+use fuel_core_types::fuel_tx::TxId;
+use parking_lot::Mutex as ParkingMutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+/// The lifecycle stage of the transaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TxStage {
+    /// The transaction is received from the API or the network.
+    Received,
+    /// The transaction passed the validation and is inserted into the pool.
+    Validated,
+    /// The transaction is broadcast to the peers.
+    Gossiped,
+    /// The transaction is selected by the block producer.
+    Selected,
+    /// The transaction is executed by the block producer.
+    Executed,
+    /// The block with the transaction is committed.
+    Committed,
+    /// The transaction is removed from the pool without inclusion.
+    SqueezedOut,
+}
+
+/// The time when the transaction reached the stage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TxStageTime {
+    pub stage: TxStage,
+    /// The time since the `UNIX_EPOCH`.
+    pub time: Duration,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    capacity: usize,
+    stages: HashMap<TxId, Vec<TxStageTime>>,
+    /// The order of insertion of transactions to evict the oldest one.
+    order: VecDeque<TxId>,
+}
+
+/// Records the timeline of the last `capacity` transactions.
+/// The disabled timeline ignores all records.
+#[derive(Clone, Debug, Default)]
+pub struct TxTimeline {
+    inner: Option<Arc<ParkingMutex<Inner>>>,
+}
+
+impl TxTimeline {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Some(Arc::new(ParkingMutex::new(Inner {
+                capacity,
+                ..Default::default()
+            }))),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Records that the transaction reached the `stage` now.
+    pub fn record(&self, id: TxId, stage: TxStage) {
+        let inner = match self.inner.as_ref() {
+            Some(inner) => inner,
+            None => return,
+        };
+        let time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Now is bellow of the `UNIX_EPOCH`");
+        let mut inner = inner.lock();
+
+        if !inner.stages.contains_key(&id) {
+            if inner.order.len() >= inner.capacity {
+                if let Some(oldest) = inner.order.pop_front() {
+                    inner.stages.remove(&oldest);
+                }
+            }
+            inner.order.push_back(id);
+        }
+        inner
+            .stages
+            .entry(id)
+            .or_default()
+            .push(TxStageTime { stage, time });
+    }
+
+    /// Returns the stages of the transaction in the order they were reached.
+    pub fn get(&self, id: &TxId) -> Option<Vec<TxStageTime>> {
+        self.inner.as_ref()?.lock().stages.get(id).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_timeline_ignores_records() {
+        let timeline = TxTimeline::disabled();
+        timeline.record(TxId::zeroed(), TxStage::Received);
+        assert_eq!(timeline.get(&TxId::zeroed()), None);
+    }
+
+    #[test]
+    fn stages_are_recorded_in_order() {
+        let timeline = TxTimeline::new(10);
+        let id = TxId::zeroed();
+        timeline.record(id, TxStage::Received);
+        timeline.record(id, TxStage::Validated);
+        timeline.record(id, TxStage::Committed);
+
+        let stages: Vec<_> = timeline
+            .get(&id)
+            .unwrap()
+            .into_iter()
+            .map(|record| record.stage)
+            .collect();
+        assert_eq!(
+            stages,
+            vec![TxStage::Received, TxStage::Validated, TxStage::Committed]
+        );
+    }
+
+    #[test]
+    fn oldest_transaction_is_evicted() {
+        let timeline = TxTimeline::new(2);
+        let ids: Vec<_> = (0..3u8).map(|i| TxId::from([i; 32])).collect();
+        for id in &ids {
+            timeline.record(*id, TxStage::Received);
+        }
+
+        assert_eq!(timeline.get(&ids[0]), None);
+        assert!(timeline.get(&ids[1]).is_some());
+        assert!(timeline.get(&ids[2]).is_some());
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    timeline::TxStage,
     types::*,
     Config,
     Error,
@@ -16,12 +17,20 @@ use fuel_core_types::{
     blockchain::SealedBlock,
     fuel_tx::{
         Chargeable,
+        Checkable,
+        ConsensusParameters,
         Transaction,
         UniqueIdentifier,
     },
-    fuel_vm::checked_transaction::{
-        CheckedTransaction,
-        IntoChecked,
+    fuel_types::BlockHeight,
+    fuel_vm::{
+        checked_transaction::{
+            CheckedTransaction,
+            IntoChecked,
+        },
+        GasCosts,
+        Interpreter,
+        PredicateStorage,
     },
     services::txpool::{
         ArcPoolTx,
@@ -31,16 +40,32 @@ use fuel_core_types::{
 use std::{
     cmp::Reverse,
     collections::HashMap,
-    ops::Deref,
     sync::Arc,
 };
 
+/// The transactions includable in the next block at the generation of the pool.
+#[derive(Debug, Clone)]
+pub struct PoolSnapshot {
+    pub generation: u64,
+    pub txs: Vec<ArcPoolTx>,
+}
+
 #[derive(Debug, Clone)]
 pub struct TxPool<DB> {
     by_hash: HashMap<TxId, TxInfo>,
     by_gas_price: PriceSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    /// Transactions selected for the block at the height that isn't committed yet.
+    /// They stay in the pool until the commit, so they can't be inserted and selected
+    /// again in the meantime.
+    selected: HashMap<TxId, BlockHeight>,
+    /// The sum of the maximal gas of includable transactions. It is updated on every
+    /// change of the pool, so reading it doesn't iterate the pool.
+    consumable_gas: u64,
+    /// Increases on every change of the pool, so the selection can detect changes
+    /// made while it worked on the snapshot.
+    generation: u64,
     config: Config,
     database: DB,
 }
@@ -56,7 +81,10 @@ where
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(max_depth),
+            selected: HashMap::new(),
+            consumable_gas: 0,
+            generation: 0,
             config,
             database,
         }
@@ -70,51 +98,51 @@ where
         &self.by_dependency
     }
 
-    #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id(&self.config.chain_config.transaction_parameters)), ret, err)]
-    // this is atomic operation. Return removed(pushed out/replaced) transactions
-    fn insert_inner(
-        &mut self,
-        // TODO: Pass `&Transaction`
-        tx: Arc<Transaction>,
-    ) -> anyhow::Result<InsertionResult> {
-        let current_height = self.database.current_block_height()?;
-
-        if tx.is_mint() {
-            return Err(Error::NotSupportedTransactionType.into())
-        }
+    pub fn min_gas_price(&self) -> u64 {
+        self.config.min_gas_price
+    }
 
-        // verify gas price is at least the minimum
-        self.verify_tx_min_gas_price(&tx)?;
+    pub fn set_min_gas_price(&mut self, min_gas_price: u64) {
+        self.config.min_gas_price = min_gas_price;
+    }
 
-        let tx: CheckedTransaction = if self.config.utxo_validation {
-            tx.deref()
-                .clone()
-                .into_checked(
-                    current_height,
-                    &self.config.chain_config.transaction_parameters,
-                    &self.config.chain_config.gas_costs,
-                )?
-                .into()
-        } else {
-            tx.deref()
-                .clone()
-                .into_checked_basic(
-                    current_height,
-                    &self.config.chain_config.transaction_parameters,
-                )?
-                .into()
-        };
+    #[cfg(test)]
+    fn insert_inner(&mut self, tx: Arc<Transaction>) -> anyhow::Result<InsertionResult> {
+        self.insert_inner_with_utxo_validation(tx, self.config.utxo_validation)
+    }
 
-        let tx = Arc::new(match tx {
-            CheckedTransaction::Script(script) => PoolTransaction::Script(script),
-            CheckedTransaction::Create(create) => PoolTransaction::Create(create),
-            CheckedTransaction::Mint(_) => unreachable!(),
-        });
+    #[cfg(test)]
+    fn insert_inner_with_utxo_validation(
+        &mut self,
+        tx: Arc<Transaction>,
+        utxo_validation: bool,
+    ) -> anyhow::Result<InsertionResult> {
+        let tx = self.checker(Some(utxo_validation))?.check(&tx, false)?;
+        self.insert_checked(tx, utxo_validation)
+    }
 
-        if !tx.is_computed() {
-            return Err(Error::NoMetadata.into())
-        }
+    /// Returns the checker of transactions at the current height with the current
+    /// configuration of the pool, `utxo_validation` overrides the configuration if it
+    /// is set.
+    pub fn checker(&self, utxo_validation: Option<bool>) -> anyhow::Result<TxChecker> {
+        Ok(TxChecker {
+            current_height: self.database.current_block_height()?,
+            min_gas_price: self.config.min_gas_price,
+            utxo_validation: utxo_validation.unwrap_or(self.config.utxo_validation),
+            verification_threads: self.config.verification_threads.max(1),
+            metrics: self.config.metrics,
+            params: self.config.chain_config.transaction_parameters,
+            gas_costs: self.config.chain_config.gas_costs.clone(),
+        })
+    }
 
+    #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
+    // this is atomic operation. Return removed(pushed out/replaced) transactions
+    fn insert_checked(
+        &mut self,
+        tx: ArcPoolTx,
+        utxo_validation: bool,
+    ) -> anyhow::Result<InsertionResult> {
         // verify max gas is less than block limit
         if tx.max_gas() > self.config.chain_config.block_gas_limit {
             return Err(Error::NotInsertedMaxGasLimit {
@@ -148,13 +176,18 @@ where
                 .observe(tx.metered_bytes_size() as f64);
         }
         // check and insert dependency
-        let rem = self
-            .by_dependency
-            .insert(&self.by_hash, &self.database, &tx)?;
+        let rem = self.by_dependency.insert(
+            &self.by_hash,
+            &self.database,
+            &tx,
+            utxo_validation,
+        )?;
         let info = TxInfo::new(tx.clone());
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
+        self.consumable_gas += tx.max_gas();
+        self.generation += 1;
 
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
@@ -187,6 +220,18 @@ where
             .sort
             .iter()
             .rev()
+            .filter(|(_, tx)| !self.selected.contains_key(&tx.id()))
+            .map(|(_, tx)| tx.clone())
+            .collect()
+    }
+
+    /// Return all transactions that are includable in next block in the order of their
+    /// arrival to the pool.
+    pub fn includable_by_arrival(&self) -> Vec<ArcPoolTx> {
+        self.by_time
+            .sort
+            .iter()
+            .filter(|(_, tx)| !self.selected.contains_key(&tx.id()))
             .map(|(_, tx)| tx.clone())
             .collect()
     }
@@ -215,6 +260,10 @@ where
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
+            if self.selected.remove(tx_id).is_none() {
+                self.consumable_gas -= info.max_gas();
+            }
+            self.generation += 1;
         }
 
         info
@@ -222,54 +271,59 @@ where
 
     /// Removes transaction from `TxPool` with assumption that it is committed into the blockchain.
     // TODO: Don't remove recursively dependent transactions on block commit.
-    //  The same logic should be fixed in the `select_transactions`.
-    //  This method is used during `select_transactions`, so we need to handle the case
-    //  when transaction was skipped during block execution(`ExecutionResult.skipped_transaction`).
     pub fn remove_committed_tx(&mut self, tx_id: &TxId) -> Vec<ArcPoolTx> {
         self.remove_by_tx_id(tx_id)
     }
 
-    fn verify_tx_min_gas_price(&mut self, tx: &Transaction) -> Result<(), Error> {
-        let price = match tx {
-            Transaction::Script(script) => script.price(),
-            Transaction::Create(create) => create.price(),
-            Transaction::Mint(_) => unreachable!(),
-        };
-        if self.config.metrics {
-            // Gas Price metrics are recorded here to avoid double matching for
-            // every single transaction, but also means metrics aren't collected on gas
-            // price if there is no minimum gas price
-            TXPOOL_METRICS.gas_price_histogram.observe(price as f64);
-        }
-        if price < self.config.min_gas_price {
-            return Err(Error::NotInsertedGasPriceTooLow)
-        }
-        Ok(())
+    /// Import a set of transactions from network gossip or GraphQL endpoints.
+    pub fn insert(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: &[Arc<Transaction>],
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_with_utxo_validation(tx_status_sender, txs, None)
     }
 
     #[tracing::instrument(level = "info", skip_all)]
-    /// Import a set of transactions from network gossip or GraphQL endpoints.
-    pub fn insert(
+    /// Import a set of transactions, `utxo_validation` overrides the configuration
+    /// of the pool if it is set.
+    pub fn insert_with_utxo_validation(
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: &[Arc<Transaction>],
+        utxo_validation: Option<bool>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
-        // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
-        // should be done before transaction comes to txpool, or before it enters RwLocked region.
-        let mut res = Vec::new();
         for tx in txs.iter() {
-            res.push(self.insert_inner(tx.clone()))
+            tx_status_sender.timeline().record(
+                tx.id(&self.config.chain_config.transaction_parameters),
+                TxStage::Received,
+            );
+        }
+        let checked = TxChecker::check_batch_with(self.checker(utxo_validation), txs);
+        self.insert_checked_batch(tx_status_sender, checked, utxo_validation)
+    }
+
+    /// Inserts transactions checked by the [`TxChecker`]. Results are in the order of
+    /// `checked`, failed checks are returned as they are.
+    pub fn insert_checked_batch(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        checked: Vec<anyhow::Result<ArcPoolTx>>,
+        utxo_validation: Option<bool>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        let utxo_validation = utxo_validation.unwrap_or(self.config.utxo_validation);
+        let mut res = Vec::new();
+        for checked in checked {
+            res.push(checked.and_then(|tx| self.insert_checked(tx, utxo_validation)))
         }
         // announce to subscribers
         for ret in res.iter() {
             match ret {
                 Ok(InsertionResult { removed, inserted }) => {
                     for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        tx_status_sender.send_replaced(removed.id(), inserted.id());
                     }
-                    tx_status_sender.send_submitted(inserted.id());
+                    tx_status_sender.send_inserted(inserted);
                 }
                 Err(_) => {
                     // @dev should not broadcast tx if error occurred
@@ -313,20 +367,76 @@ where
         list
     }
 
-    /// The number of pending transaction in the pool.
+    /// The number of pending transaction in the pool, not selected for a block yet.
     pub fn pending_number(&self) -> usize {
-        self.by_hash.len()
+        self.by_hash.len() - self.selected.len()
     }
 
-    /// The amount of gas in all includable transactions combined
+    /// The amount of gas in all includable transactions combined. It is the sum of the
+    /// maximal gas of transactions, the same measure the selection fits into the block.
     pub fn consumable_gas(&self) -> u64 {
-        self.by_hash.values().map(|tx| tx.limit()).sum()
+        self.consumable_gas
     }
 
-    /// Return all sorted transactions that are includable in next block.
-    /// This is going to be heavy operation, use it only when needed.
-    pub fn includable(&mut self) -> Vec<ArcPoolTx> {
-        self.sorted_includable()
+    /// Releases the selected transactions for which `release` returns `true`, so they
+    /// are includable again. Returns `true` if any transaction was released.
+    fn release_selected(&mut self, release: impl Fn(BlockHeight) -> bool) -> bool {
+        let released: Vec<_> = self
+            .selected
+            .iter()
+            .filter(|(_, height)| release(**height))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in released.iter() {
+            self.selected.remove(id);
+            if let Some(info) = self.by_hash.get(id) {
+                self.consumable_gas += info.max_gas();
+            }
+        }
+        !released.is_empty()
+    }
+
+    /// Return the transactions that are includable in the block at the `height` in the
+    /// order of their arrival, with the current generation of the pool. The selection
+    /// can work on the snapshot without the lock. This is going to be heavy operation,
+    /// use it only when needed.
+    pub fn snapshot(&mut self, height: BlockHeight) -> PoolSnapshot {
+        // The selection for the height means that the production of this and higher
+        // blocks was abandoned, so their transactions are includable again.
+        if self.release_selected(|selected_height| selected_height >= height) {
+            self.generation += 1;
+        }
+        PoolSnapshot {
+            generation: self.generation,
+            txs: self.includable_by_arrival(),
+        }
+    }
+
+    /// Marks the `txs` chosen from the snapshot of the `generation` as selected for the
+    /// block at the `height`. If the pool changed after the snapshot, the transactions
+    /// removed or selected in the meantime are dropped from the selection.
+    pub fn mark_selected(
+        &mut self,
+        generation: u64,
+        height: BlockHeight,
+        txs: Vec<ArcPoolTx>,
+    ) -> Vec<ArcPoolTx> {
+        let txs: Vec<_> = if generation == self.generation {
+            txs
+        } else {
+            txs.into_iter()
+                .filter(|tx| {
+                    self.by_hash.contains_key(&tx.id())
+                        && !self.selected.contains_key(&tx.id())
+                })
+                .collect()
+        };
+        for tx in txs.iter() {
+            self.selected.insert(tx.id(), height);
+            self.consumable_gas -= tx.max_gas();
+        }
+        self.generation += 1;
+        txs
     }
 
     /// When block is updated we need to receive all spend outputs and remove them from txpool.
@@ -336,15 +446,20 @@ where
         block: &SealedBlock,
         // spend_outputs: [Input], added_outputs: [AddedOutputs]
     ) {
+        let height = *block.entity.header().height();
         for tx in block.entity.transactions() {
             tx_status_sender.send_complete(
                 tx.id(&self.config.chain_config.transaction_parameters),
-                block.entity.header().height(),
+                &height,
             );
             self.remove_committed_tx(
                 &tx.id(&self.config.chain_config.transaction_parameters),
             );
         }
+        // Transactions selected for this or lower heights that are still in the pool
+        // weren't included, so they are includable again.
+        self.release_selected(|selected_height| selected_height <= height);
+        self.generation += 1;
     }
 
     /// remove transaction from pool needed on user demand. Low priority
@@ -352,11 +467,25 @@ where
         &mut self,
         tx_status_sender: &TxStatusChange,
         tx_ids: &[TxId],
+    ) -> Vec<ArcPoolTx> {
+        let tx_ids = tx_ids
+            .iter()
+            .map(|tx_id| (*tx_id, Error::Removed))
+            .collect();
+        self.remove_with_reasons(tx_status_sender, tx_ids)
+    }
+
+    /// Removes transactions from the pool, notifying subscribers about the reason of
+    /// the removal of each one.
+    pub fn remove_with_reasons(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        tx_ids: Vec<(TxId, Error)>,
     ) -> Vec<ArcPoolTx> {
         let mut removed = Vec::new();
-        for tx_id in tx_ids {
-            let rem = self.remove_by_tx_id(tx_id);
-            tx_status_sender.send_squeezed_out(*tx_id, Error::Removed);
+        for (tx_id, reason) in tx_ids {
+            let rem = self.remove_by_tx_id(&tx_id);
+            tx_status_sender.send_squeezed_out(tx_id, reason);
             removed.extend(rem.into_iter());
         }
         removed
@@ -382,6 +511,168 @@ where
     }
 }
 
+/// Checks transactions without the state of the pool, so it is used without holding
+/// the lock of the pool. Predicates and signatures are verified if `utxo_validation`
+/// is enabled, it is the most expensive part of the insertion.
+#[derive(Clone)]
+pub struct TxChecker {
+    current_height: BlockHeight,
+    min_gas_price: u64,
+    utxo_validation: bool,
+    verification_threads: usize,
+    metrics: bool,
+    params: ConsensusParameters,
+    gas_costs: GasCosts,
+}
+
+impl TxChecker {
+    /// Checks `txs` with the `checker`, or fails all of them if the checker isn't
+    /// available.
+    pub fn check_batch_with(
+        checker: anyhow::Result<TxChecker>,
+        txs: &[Arc<Transaction>],
+    ) -> Vec<anyhow::Result<ArcPoolTx>> {
+        match checker {
+            Ok(checker) => checker.check_batch(txs),
+            Err(e) => txs.iter().map(|_| Err(anyhow::anyhow!("{e}"))).collect(),
+        }
+    }
+
+    /// Checks `txs` on up to `verification_threads` threads. Several transactions are
+    /// checked in parallel with each other, the only transaction verifies its
+    /// signatures and predicates in parallel. Results are in the order of `txs`.
+    pub fn check_batch(
+        &self,
+        txs: &[Arc<Transaction>],
+    ) -> Vec<anyhow::Result<ArcPoolTx>> {
+        let threads = self.verification_threads;
+        if txs.len() < 2 {
+            return txs.iter().map(|tx| self.check(tx, threads > 1)).collect()
+        }
+        let check = |chunk: &[Arc<Transaction>]| -> Vec<_> {
+            chunk.iter().map(|tx| self.check(tx, false)).collect()
+        };
+        if threads == 1 {
+            return check(txs)
+        }
+
+        let chunk_size = (txs.len() + threads - 1) / threads;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = txs
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || check(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+
+    /// Checks the `tx`. If `parallel` is set, predicates are verified on a separate
+    /// thread while signatures are recovered.
+    pub fn check(&self, tx: &Transaction, parallel: bool) -> anyhow::Result<ArcPoolTx> {
+        if tx.is_mint() {
+            return Err(Error::NotSupportedTransactionType.into())
+        }
+
+        // verify gas price is at least the minimum
+        self.verify_tx_min_gas_price(tx)?;
+
+        let tx: CheckedTransaction = if !self.utxo_validation {
+            tx.clone()
+                .into_checked_basic(self.current_height, &self.params)?
+                .into()
+        } else if !parallel {
+            tx.clone()
+                .into_checked(self.current_height, &self.params, &self.gas_costs)?
+                .into()
+        } else {
+            let tx: CheckedTransaction = tx
+                .clone()
+                .into_checked_basic(self.current_height, &self.params)?
+                .into();
+            self.verify_in_parallel(&tx)?;
+            tx
+        };
+
+        let tx = Arc::new(match tx {
+            CheckedTransaction::Script(script) => PoolTransaction::Script(script),
+            CheckedTransaction::Create(create) => PoolTransaction::Create(create),
+            CheckedTransaction::Mint(_) => unreachable!(),
+        });
+
+        if !tx.is_computed() {
+            return Err(Error::NoMetadata.into())
+        }
+        Ok(tx)
+    }
+
+    /// Verifies predicates of the `tx` on a separate thread while signatures are
+    /// recovered on the current one.
+    fn verify_in_parallel(&self, tx: &CheckedTransaction) -> anyhow::Result<()> {
+        let verify_predicates = || match tx {
+            CheckedTransaction::Script(script) => {
+                Interpreter::<PredicateStorage>::check_predicates(
+                    script.clone(),
+                    self.params,
+                    self.gas_costs.clone(),
+                )
+                .map(|_| ())
+            }
+            CheckedTransaction::Create(create) => {
+                Interpreter::<PredicateStorage>::check_predicates(
+                    create.clone(),
+                    self.params,
+                    self.gas_costs.clone(),
+                )
+                .map(|_| ())
+            }
+            CheckedTransaction::Mint(_) => Ok(()),
+        };
+        let (signatures, predicates) = std::thread::scope(|scope| {
+            let predicates = scope.spawn(verify_predicates);
+            let signatures = match tx {
+                CheckedTransaction::Script(script) => {
+                    script.transaction().check_signatures(&self.params)
+                }
+                CheckedTransaction::Create(create) => {
+                    create.transaction().check_signatures(&self.params)
+                }
+                CheckedTransaction::Mint(_) => Ok(()),
+            };
+            let predicates = predicates
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            (signatures, predicates)
+        });
+        signatures?;
+        predicates.map_err(|e| anyhow::anyhow!("PredicateVerificationFailed({e:?})"))
+    }
+
+    fn verify_tx_min_gas_price(&self, tx: &Transaction) -> Result<(), Error> {
+        let price = match tx {
+            Transaction::Script(script) => script.price(),
+            Transaction::Create(create) => create.price(),
+            Transaction::Mint(_) => unreachable!(),
+        };
+        if self.metrics {
+            // Gas Price metrics are recorded here to avoid double matching for
+            // every single transaction, but also means metrics aren't collected on gas
+            // price if there is no minimum gas price
+            TXPOOL_METRICS.gas_price_histogram.observe(price as f64);
+        }
+        if price < self.min_gas_price {
+            return Err(Error::NotInsertedGasPriceTooLow)
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test_helpers;
 #[cfg(test)]
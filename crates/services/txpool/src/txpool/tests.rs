@@ -1,4 +1,6 @@
 use crate::{
+    service::TxStatusChange,
+    skipped::SkippedTransactions,
     test_helpers::{
         add_coin_to_state,
         create_output_and_input,
@@ -18,6 +20,7 @@ use crate::{
     Error,
     MockDb,
     TxPool,
+    TxTimeline,
 };
 use fuel_core_types::{
     fuel_asm::{
@@ -36,6 +39,7 @@ use fuel_core_types::{
         AssetId,
         Input,
         Output,
+        Transaction,
         TransactionBuilder,
         UniqueIdentifier,
         UtxoId,
@@ -46,6 +50,7 @@ use std::{
     collections::HashMap,
     str::FromStr,
     sync::Arc,
+    time::Duration,
     vec,
 };
 
@@ -266,6 +271,59 @@ fn try_to_insert_tx2_missing_utxo() {
     ));
 }
 
+#[test]
+fn try_to_insert_tx_with_immature_coin() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut txpool = TxPool::new(Default::default(), MockDb::default());
+
+    let (_, input) = setup_coin(&mut rng, Some(&txpool.database));
+    let utxo_id = *input.utxo_id().unwrap();
+    txpool
+        .database
+        .data
+        .lock()
+        .unwrap()
+        .coins
+        .get_mut(&utxo_id)
+        .unwrap()
+        .maturity = 5u32.into();
+    let tx = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(input)
+            .finalize_as_transaction(),
+    );
+
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedInputUtxoIdNotMatured { utxo_id: id, matures_at })
+            if *id == utxo_id && *matures_at == 5u32.into()
+    ));
+}
+
+#[test]
+fn insert_tx_missing_utxo_succeeds_if_utxo_validation_is_overridden() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut txpool = TxPool::new(Default::default(), MockDb::default());
+
+    let (_, input) = setup_coin(&mut rng, None);
+    let tx = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(input)
+            .finalize_as_transaction(),
+    );
+
+    txpool
+        .insert_inner_with_utxo_validation(tx, false)
+        .expect("Tx should be Ok, got Err");
+}
+
 #[test]
 fn higher_priced_tx_removes_lower_priced_tx() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -836,6 +894,31 @@ async fn tx_below_min_gas_price_is_not_insertable() {
     ));
 }
 
+#[tokio::test]
+async fn raised_min_gas_price_applies_to_new_txs() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db);
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+    txpool.set_min_gas_price(11);
+
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("expected insertion failure");
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedGasPriceTooLow
+    ));
+}
+
 #[tokio::test]
 async fn tx_inserted_into_pool_when_input_message_id_exists_in_db() {
     let (message, input) = create_message_predicate_from_message(5000, 0);
@@ -1141,3 +1224,201 @@ fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+fn script_tx(rng: &mut StdRng, db: &MockDb, gas_price: Word) -> Arc<Transaction> {
+    let (_, gas_coin) = setup_coin(rng, Some(db));
+    Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    )
+}
+
+#[test]
+fn selected_tx_is_not_includable_and_cannot_be_reinserted() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx1 = script_tx(&mut rng, &db, 10);
+    let tx2 = script_tx(&mut rng, &db, 9);
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be Ok, got Err");
+    txpool
+        .insert_inner(tx2.clone())
+        .expect("Tx2 should be Ok, got Err");
+
+    let snapshot = txpool.snapshot(1u32.into());
+    let tx1_only = snapshot.txs[..1].to_vec();
+    let selected = txpool.mark_selected(snapshot.generation, 1u32.into(), tx1_only);
+
+    assert_eq!(selected.len(), 1);
+    assert_eq!(txpool.pending_number(), 1);
+    let txs = txpool.snapshot(2u32.into()).txs;
+    assert_eq!(txs.len(), 1, "Only tx2 should be includable");
+    assert_eq!(txs[0].id(), tx2.id(&fuel_tx::ConsensusParameters::DEFAULT));
+    let err = txpool
+        .insert_inner(tx1)
+        .expect_err("Selected Tx1 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedTxKnown)
+    ));
+}
+
+#[test]
+fn mark_selected_drops_tx_removed_after_snapshot() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx1 = script_tx(&mut rng, &db, 10);
+    let tx2 = script_tx(&mut rng, &db, 9);
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be Ok, got Err");
+    txpool
+        .insert_inner(tx2.clone())
+        .expect("Tx2 should be Ok, got Err");
+
+    let snapshot = txpool.snapshot(1u32.into());
+    txpool.remove_committed_tx(&tx1.id(&fuel_tx::ConsensusParameters::DEFAULT));
+    let selected = txpool.mark_selected(snapshot.generation, 1u32.into(), snapshot.txs);
+
+    assert_eq!(selected.len(), 1, "Removed Tx1 should not be selected");
+    assert_eq!(
+        selected[0].id(),
+        tx2.id(&fuel_tx::ConsensusParameters::DEFAULT)
+    );
+}
+
+#[test]
+fn snapshot_releases_txs_of_abandoned_block() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx = script_tx(&mut rng, &db, 10);
+    txpool.insert_inner(tx).expect("Tx should be Ok, got Err");
+
+    let snapshot = txpool.snapshot(1u32.into());
+    txpool.mark_selected(snapshot.generation, 1u32.into(), snapshot.txs);
+
+    // The production of the block 1 failed, so the tx is includable in the retry.
+    assert_eq!(txpool.snapshot(1u32.into()).txs.len(), 1);
+}
+
+#[test]
+fn snapshot_lists_txs_in_order_of_arrival() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx1 = script_tx(&mut rng, &db, 9);
+    let tx2 = script_tx(&mut rng, &db, 10);
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be Ok, got Err");
+    txpool
+        .insert_inner(tx2.clone())
+        .expect("Tx2 should be Ok, got Err");
+
+    let ids: Vec<_> = txpool
+        .snapshot(1u32.into())
+        .txs
+        .iter()
+        .map(|tx| tx.id())
+        .collect();
+
+    assert_eq!(
+        ids,
+        vec![
+            tx1.id(&fuel_tx::ConsensusParameters::DEFAULT),
+            tx2.id(&fuel_tx::ConsensusParameters::DEFAULT),
+        ]
+    );
+}
+
+#[test]
+fn batch_is_verified_in_parallel_in_order_of_transactions() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        verification_threads: 2,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db.clone());
+    let mut txs: Vec<_> = (0..4).map(|_| script_tx(&mut rng, &db, 10)).collect();
+    let coin = custom_predicate(
+        &mut rng,
+        AssetId::BASE,
+        TEST_COIN_AMOUNT,
+        vec![op::ret(RegId::ZERO)].into_iter().collect(),
+        None,
+    );
+    let (_, gas_coin) = add_coin_to_state(coin, Some(&db));
+    txs[2] = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+
+    let sender = TxStatusChange::new(
+        100,
+        TxTimeline::disabled(),
+        SkippedTransactions::new(Duration::from_secs(60)),
+    );
+    let results = txpool.insert(&sender, &txs);
+
+    assert_eq!(results.len(), 4);
+    for (index, result) in results.iter().enumerate() {
+        if index == 2 {
+            let err = result.as_ref().expect_err("Tx3 should be Err, got Ok");
+            assert!(
+                err.to_string().contains("PredicateVerificationFailed"),
+                "unexpected error: {err}",
+            );
+        } else {
+            let inserted = &result.as_ref().expect("Tx should be Ok, got Err").inserted;
+            assert_eq!(
+                inserted.id(),
+                txs[index].id(&fuel_tx::ConsensusParameters::DEFAULT)
+            );
+        }
+    }
+}
+
+#[test]
+fn consumable_gas_follows_selection_and_removal() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx1 = script_tx(&mut rng, &db, 10);
+    let tx2 = script_tx(&mut rng, &db, 9);
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be Ok, got Err");
+    txpool
+        .insert_inner(tx2.clone())
+        .expect("Tx2 should be Ok, got Err");
+    let max_gas = |tx: &Transaction| {
+        txpool
+            .find_one(&tx.id(&fuel_tx::ConsensusParameters::DEFAULT))
+            .expect("Tx should be in the pool")
+            .max_gas()
+    };
+    let (gas1, gas2) = (max_gas(&tx1), max_gas(&tx2));
+    assert_eq!(txpool.consumable_gas(), gas1 + gas2);
+
+    let snapshot = txpool.snapshot(1u32.into());
+    let tx1_only = snapshot.txs[..1].to_vec();
+    txpool.mark_selected(snapshot.generation, 1u32.into(), tx1_only);
+    assert_eq!(txpool.consumable_gas(), gas2);
+
+    // The production of the block 1 failed, so the tx is consumable again.
+    txpool.snapshot(1u32.into());
+    assert_eq!(txpool.consumable_gas(), gas1 + gas2);
+
+    txpool.remove_committed_tx(&tx2.id(&fuel_tx::ConsensusParameters::DEFAULT));
+    assert_eq!(txpool.consumable_gas(), gas1);
+}
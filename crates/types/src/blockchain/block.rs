@@ -3,6 +3,7 @@
 use super::{
     consensus::ConsensusType,
     header::{
+        generate_message_receipt_root,
         ApplicationHeader,
         BlockHeader,
         ConsensusHeader,
@@ -16,11 +17,15 @@ use super::{
 use crate::{
     fuel_tx::{
         ConsensusParameters,
+        Receipt,
         Transaction,
         TxId,
         UniqueIdentifier,
     },
-    fuel_types::MessageId,
+    fuel_types::{
+        Bytes32,
+        MessageId,
+    },
 };
 
 /// Fuel block with all transaction data included
@@ -104,14 +109,59 @@ impl<T> Block<T> {
 }
 
 impl CompressedBlock {
-    /// Convert from a compressed block back to a the full block.
-    pub fn uncompress(self, transactions: Vec<Transaction>) -> Block<Transaction> {
-        // TODO: should we perform an extra validation step to ensure the provided
-        //  txs match the expected ones in the block?
-        Block {
+    /// Convert from a compressed block back to the full block.
+    ///
+    /// Returns `None` if the ids of `transactions` don't match the ids of the block or
+    /// the `transactions_root` of the header, so a mismatched body can't be attached to
+    /// the header.
+    pub fn uncompress(
+        self,
+        transactions: Vec<Transaction>,
+        params: &ConsensusParameters,
+    ) -> Option<Block<Transaction>> {
+        let ids_match = self.transactions.len() == transactions.len()
+            && self
+                .transactions
+                .iter()
+                .zip(transactions.iter())
+                .all(|(id, tx)| *id == tx.id(params));
+        if !ids_match {
+            return None
+        }
+        self.attach(transactions)
+    }
+
+    /// Convert from a compressed block back to the full block, fetching each
+    /// transaction by its id only at the moment of the conversion.
+    ///
+    /// The `fetch` must return the transaction with the requested id, like the storage
+    /// indexed by transaction ids does. Returns `None` if fetched transactions don't
+    /// match the `transactions_root` of the header.
+    pub fn uncompress_with<F, E>(
+        self,
+        mut fetch: F,
+    ) -> Result<Option<Block<Transaction>>, E>
+    where
+        F: FnMut(&TxId) -> Result<Transaction, E>,
+    {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(&mut fetch)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.attach(transactions))
+    }
+
+    /// Attaches `transactions` to the header if they match its transactions count and
+    /// root.
+    fn attach(self, transactions: Vec<Transaction>) -> Option<Block<Transaction>> {
+        let matches = self.header.application.transactions_count
+            == transactions.len() as u64
+            && self.header.validate_transactions(&transactions);
+        matches.then_some(Block {
             header: self.header,
             transactions,
-        }
+        })
     }
 }
 
@@ -127,6 +177,15 @@ impl<TransactionRepresentation> Block<TransactionRepresentation> {
         self.header.id()
     }
 
+    /// Recomputes the `message_receipt_root` of the header from the receipts of all
+    /// transactions of the block in the order of execution. Only `MessageOut` receipts
+    /// contribute to the root, other receipts are ignored.
+    pub fn recompute_receipts_root(receipts: &[Receipt]) -> Bytes32 {
+        let message_ids: Vec<MessageId> =
+            receipts.iter().filter_map(Receipt::message_id).collect();
+        generate_message_receipt_root(&message_ids)
+    }
+
     /// Get the executed transactions.
     pub fn transactions(&self) -> &[TransactionRepresentation] {
         &self.transactions[..]
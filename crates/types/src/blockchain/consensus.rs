@@ -6,7 +6,6 @@ use crate::{
         PublicKey,
         Signature,
     },
-    fuel_tx::Input,
     fuel_types::{
         Address,
         Bytes32,
@@ -15,8 +14,12 @@ use crate::{
 
 // Different types of consensus are represented as separate modules
 pub mod poa;
+pub mod seal;
 
-use poa::PoAConsensus;
+use poa::{
+    PoAConsensus,
+    PoAThresholdConsensus,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,6 +30,8 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Proof of authority consensus with the committee of authorities
+    PoAThreshold(PoAThresholdConsensus),
 }
 
 impl Consensus {
@@ -34,11 +39,8 @@ impl Consensus {
     pub fn block_producer(&self, block_id: &BlockId) -> anyhow::Result<Address> {
         match &self {
             Consensus::Genesis(_) => Ok(Address::zeroed()),
-            Consensus::PoA(poa_data) => {
-                let public_key = poa_data.signature.recover(block_id.as_message())?;
-                let address = Input::owner(&public_key);
-                Ok(address)
-            }
+            Consensus::PoA(poa_data) => poa_data.block_producer(block_id),
+            Consensus::PoAThreshold(poa_data) => Ok(poa_data.block_producer()),
         }
     }
 }
@@ -1,19 +1,151 @@
 //! Proof of authority
 
-use crate::fuel_crypto::Signature;
+use crate::{
+    blockchain::{
+        consensus::seal::{
+            Committee,
+            Secp256k1,
+            SealScheme,
+            ThresholdSeal,
+        },
+        primitives::BlockId,
+    },
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+    },
+    fuel_tx::TxId,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+
+/// The signature scheme of PoA seals and pre-confirmations.
+pub type PoASealScheme = Secp256k1;
+
+/// The seal of the PoA block.
+pub type PoASeal = <PoASealScheme as SealScheme>::Seal;
 
 #[derive(Default, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S::Seal: serde::Serialize",
+        deserialize = "S::Seal: serde::Deserialize<'de>"
+    ))
+)]
 /// The consensus related data that doesn't live on the
-/// header.
-pub struct PoAConsensus {
+/// header. The seal is created by the signature scheme `S`, the [`PoASealScheme`] by
+/// default.
+pub struct PoAConsensus<S: SealScheme = PoASealScheme> {
     /// The signature of the [`FuelBlockHeader`].
-    pub signature: Signature,
+    pub signature: S::Seal,
 }
 
-impl PoAConsensus {
+impl<S: SealScheme> PoAConsensus<S> {
     /// Create a new block consensus.
-    pub fn new(signature: Signature) -> Self {
+    pub fn new(signature: S::Seal) -> Self {
         Self { signature }
     }
+
+    /// Seals the block with the `block_id` with the `signing_key`.
+    pub fn seal(block_id: &BlockId, signing_key: &S::SigningKey) -> Self {
+        Self::new(S::seal(signing_key, block_id.as_message()))
+    }
+
+    /// Retrieve the address of the authority that sealed the block with the `block_id`.
+    pub fn block_producer(&self, block_id: &BlockId) -> anyhow::Result<Address> {
+        S::signer(&self.signature, block_id.as_message())
+    }
+
+    /// Returns whether the block with the `block_id` is sealed by the `authority`.
+    pub fn is_sealed_by(&self, block_id: &BlockId, authority: &Address) -> bool {
+        S::verify(&self.signature, block_id.as_message(), authority)
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The consensus related data of the block sealed by the committee of authorities.
+pub struct PoAThresholdConsensus {
+    /// The threshold seal of the [`FuelBlockHeader`].
+    pub seal: ThresholdSeal,
+}
+
+impl PoAThresholdConsensus {
+    /// Create a new block consensus.
+    pub fn new(seal: ThresholdSeal) -> Self {
+        Self { seal }
+    }
+
+    /// Retrieve the address of the committee that sealed the block. The seal only
+    /// refers to the committee, signatures are checked against the committee of the
+    /// chain config by [`PoAThresholdConsensus::is_sealed_by`].
+    pub fn block_producer(&self) -> Address {
+        self.seal.committee
+    }
+
+    /// Returns whether the block with the `block_id` is sealed by the `committee`.
+    pub fn is_sealed_by(&self, block_id: &BlockId, committee: &Committee) -> bool {
+        committee.verify(&self.seal, block_id.as_message()).is_ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The promise of the block producer that transactions are included into the block at
+/// the `height`. It is issued once the block producer sealed the block, before the block
+/// is committed, and covers all transactions of the block with one signature.
+pub struct PreConfirmation {
+    /// The ids of the selected transactions in the order of the block.
+    pub tx_ids: Vec<TxId>,
+    /// The height of the block that includes the transactions.
+    pub height: BlockHeight,
+    /// The signature of the block producer over the [`PreConfirmation::message`].
+    pub signature: PoASeal,
+}
+
+impl PreConfirmation {
+    /// Separates pre-confirmations from other messages signed by the block producer.
+    pub const DOMAIN: &'static [u8] = b"FUEL_PRE_CONFIRMATION";
+
+    /// Create a new pre-confirmation for the chain with the `chain_id` signed by the
+    /// `signing_key`.
+    pub fn new(
+        chain_id: u64,
+        tx_ids: Vec<TxId>,
+        height: BlockHeight,
+        signing_key: &SecretKey,
+    ) -> Self {
+        let message = Self::message(chain_id, &tx_ids, height);
+        Self {
+            tx_ids,
+            height,
+            signature: PoASealScheme::seal(signing_key, &message),
+        }
+    }
+
+    /// The message signed by the block producer of the chain with the `chain_id`.
+    pub fn message(chain_id: u64, tx_ids: &[TxId], height: BlockHeight) -> Message {
+        let mut hasher = Hasher::default();
+        hasher.input(Self::DOMAIN);
+        hasher.input(chain_id.to_be_bytes());
+        hasher.input(&height.to_bytes()[..]);
+        hasher.input((tx_ids.len() as u64).to_be_bytes());
+        for tx_id in tx_ids {
+            hasher.input(tx_id.as_ref());
+        }
+        Message::from_bytes(*hasher.digest())
+    }
+
+    /// Retrieve the address of the block producer of the chain with the `chain_id` that
+    /// signed the pre-confirmation.
+    pub fn producer(&self, chain_id: u64) -> anyhow::Result<Address> {
+        let message = Self::message(chain_id, &self.tx_ids, self.height);
+        PoASealScheme::signer(&self.signature, &message)
+    }
 }
+
@@ -0,0 +1,141 @@
+//! Signature schemes of consensus seals
+
+use crate::{
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::Address,
+};
+use std::collections::BTreeSet;
+
+/// The signature scheme that seals blocks. The consensus creates and verifies seals
+/// only through the scheme, so other schemes, like aggregate signatures of the
+/// committee, can be supported next to the secp256k1 one.
+pub trait SealScheme {
+    /// The seal of the message.
+    type Seal;
+    /// The key of the authority that creates seals.
+    type SigningKey;
+
+    /// Returns the address of the authority with the `signing_key`.
+    fn authority(signing_key: &Self::SigningKey) -> Address;
+
+    /// Seals the `message` with the `signing_key`.
+    fn seal(signing_key: &Self::SigningKey, message: &Message) -> Self::Seal;
+
+    /// Returns the address of the authority that sealed the `message`.
+    fn signer(seal: &Self::Seal, message: &Message) -> anyhow::Result<Address>;
+
+    /// Returns whether the `seal` of the `message` is created by the `authority`.
+    fn verify(seal: &Self::Seal, message: &Message, authority: &Address) -> bool {
+        Self::signer(seal, message).map_or(false, |signer| &signer == authority)
+    }
+}
+
+/// The secp256k1 signature. The public key of the authority is recovered from the
+/// signature itself.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl SealScheme for Secp256k1 {
+    type Seal = Signature;
+    type SigningKey = SecretKey;
+
+    fn authority(signing_key: &SecretKey) -> Address {
+        Input::owner(&signing_key.public_key())
+    }
+
+    fn seal(signing_key: &SecretKey, message: &Message) -> Signature {
+        Signature::sign(signing_key, message)
+    }
+
+    fn signer(seal: &Signature, message: &Message) -> anyhow::Result<Address> {
+        let public_key = seal.recover(message)?;
+        Ok(Input::owner(&public_key))
+    }
+}
+
+/// The committee of authorities that seals blocks together, so no single member can
+/// seal the block alone.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Committee {
+    /// The number of distinct members required to seal the block.
+    pub threshold: u16,
+    /// The addresses of consensus keys of members.
+    pub members: Vec<Address>,
+}
+
+impl Committee {
+    /// Checks that the committee can seal blocks: the threshold is reachable by members
+    /// and a single member can't seal the block alone unless the committee is of one
+    /// member.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let distinct = self.members.iter().collect::<BTreeSet<_>>().len();
+        anyhow::ensure!(
+            distinct == self.members.len(),
+            "Members of the committee must be distinct"
+        );
+        anyhow::ensure!(
+            self.threshold > 0 && self.threshold as usize <= self.members.len(),
+            "The threshold of the committee must be within 1..={}, but it is {}",
+            self.members.len(),
+            self.threshold
+        );
+        Ok(())
+    }
+
+    /// The address of the committee. It is the authority of the chain config in place
+    /// of the address of the single consensus key.
+    pub fn address(&self) -> Address {
+        let mut hasher = Hasher::default();
+        hasher.input(self.threshold.to_be_bytes());
+        for member in &self.members {
+            hasher.input(member.as_ref());
+        }
+        Address::new(*hasher.digest())
+    }
+
+    /// Verifies that the `message` is sealed by the committee: the seal refers to the
+    /// committee and at least the threshold of distinct members signed the `message`.
+    pub fn verify(&self, seal: &ThresholdSeal, message: &Message) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            seal.committee == self.address(),
+            "The seal refers to the committee {}, but the committee is {}",
+            seal.committee,
+            self.address()
+        );
+        let mut signers = BTreeSet::new();
+        for signature in &seal.signatures {
+            let signer = Secp256k1::signer(signature, message)?;
+            anyhow::ensure!(
+                self.members.contains(&signer),
+                "The {signer} is not a member of the committee"
+            );
+            signers.insert(signer);
+        }
+        anyhow::ensure!(
+            self.threshold > 0 && signers.len() >= self.threshold as usize,
+            "The seal is signed by {} distinct members of the committee, but the \
+            threshold is {}",
+            signers.len(),
+            self.threshold
+        );
+        Ok(())
+    }
+}
+
+/// The threshold seal: signatures of members of the committee over the same message.
+/// The committee itself is fixed by the chain config, the seal only refers to it.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThresholdSeal {
+    /// The address of the committee that sealed the message, see [`Committee::address`].
+    pub committee: Address,
+    /// The secp256k1 signatures of members.
+    pub signatures: Vec<Signature>,
+}
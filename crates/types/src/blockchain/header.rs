@@ -35,7 +35,7 @@ pub struct BlockHeader {
     metadata: Option<BlockHeaderMetadata>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(any(test, feature = "test-helpers"), derive(Default))]
 /// A partially complete fuel block header that doesn't not
 /// have any generated fields because it has not been executed yet.
@@ -244,11 +244,7 @@ impl PartialBlockHeader {
         let transactions_root = generate_txns_root(transactions);
 
         // Generate the message merkle root.
-        let mut message_tree = fuel_merkle::binary::in_memory::MerkleTree::new();
-        for id in message_ids {
-            message_tree.push(id.as_ref());
-        }
-        let message_receipt_root = message_tree.root().into();
+        let message_receipt_root = generate_message_receipt_root(message_ids);
 
         let application = ApplicationHeader {
             da_height: self.application.da_height,
@@ -292,6 +288,15 @@ fn generate_txns_root(transactions: &[Transaction]) -> Bytes32 {
     transaction_tree.root().into()
 }
 
+/// Generates the merkle root of the ids of messages in the order they were produced.
+pub(crate) fn generate_message_receipt_root(message_ids: &[MessageId]) -> Bytes32 {
+    let mut message_tree = fuel_merkle::binary::in_memory::MerkleTree::new();
+    for id in message_ids {
+        message_tree.push(id.as_ref());
+    }
+    message_tree.root().into()
+}
+
 impl ApplicationHeader<GeneratedApplicationFields> {
     /// Hash the application header.
     pub fn hash(&self) -> Bytes32 {
@@ -25,7 +25,7 @@ use secrecy::{
 };
 use zeroize::Zeroize;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 /// Empty generated fields.
 pub struct Empty;
 
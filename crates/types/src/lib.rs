@@ -21,6 +21,7 @@ pub use tai64;
 pub mod blockchain;
 pub mod entities;
 pub mod services;
+pub mod time;
 
 /// Re-export of some fuel-vm types
 pub mod fuel_vm {
@@ -6,7 +6,10 @@ use crate::{
             Block,
             PartialFuelBlock,
         },
-        primitives::BlockId,
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
     },
     fuel_tx::{
         CheckError,
@@ -15,9 +18,11 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
         Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     fuel_vm::{
         Backtrace,
@@ -53,6 +58,9 @@ pub struct TransactionExecutionStatus {
     pub id: Bytes32,
     /// The result of the executed transaction.
     pub result: TransactionExecutionResult,
+    /// The gas actually used by the transaction: its minimal gas and the gas used by its
+    /// script.
+    pub gas_used: Word,
 }
 
 /// The result of transaction execution.
@@ -291,6 +299,8 @@ pub enum Error {
     MessageAlreadySpent(Nonce),
     #[error("Expected input of type {0}")]
     InputTypeMismatch(String),
+    #[error("The execution was cancelled")]
+    Cancelled,
 }
 
 impl From<Backtrace> for Error {
@@ -305,16 +315,29 @@ impl From<Backtrace> for Error {
 pub enum TransactionValidityError {
     #[error("Coin input was already spent")]
     CoinAlreadySpent(UtxoId),
-    #[error("Coin has not yet reached maturity")]
-    CoinHasNotMatured(UtxoId),
+    #[error(
+        "Coin {utxo_id:#x} has not yet reached maturity, it is spendable since the block \
+        {matures_at}"
+    )]
+    CoinHasNotMatured {
+        utxo_id: UtxoId,
+        /// The height of the first block that can spend the coin.
+        matures_at: BlockHeight,
+    },
     #[error("The specified coin doesn't exist")]
     CoinDoesNotExist(UtxoId),
     #[error("The specified message was already spent")]
     MessageAlreadySpent(Nonce),
     #[error(
-        "Message is not yet spendable, as it's DA height is newer than this block allows"
+        "Message {nonce:#x} is not yet spendable, as its DA height {da_height} is newer \
+        than this block allows"
     )]
-    MessageSpendTooEarly(Nonce),
+    MessageSpendTooEarly {
+        nonce: Nonce,
+        /// The DA height of the message. Blocks with this or higher DA height can spend
+        /// the message.
+        da_height: DaBlockHeight,
+    },
     #[error("The specified message doesn't exist")]
     MessageDoesNotExist(Nonce),
     #[error("The input message sender doesn't match the relayer message sender")]
@@ -1,9 +1,22 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    fuel_asm::{
+        Instruction,
+        PanicReason,
+        RawInstruction,
+        Word,
+    },
+    fuel_tx::{
+        Receipt,
+        TxId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        ContractId,
+    },
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +34,134 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The log emitted by the contract during the execution of the transaction.
+pub struct ContractLog {
+    /// The height of the block with the transaction.
+    pub block_height: BlockHeight,
+    /// The id of the transaction.
+    pub tx_id: TxId,
+    /// The `Log` or `LogData` receipt.
+    pub receipt: Receipt,
+}
+
+/// The decoded `Panic` receipt: why the VM panicked and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicDetails {
+    /// The reason of the panic.
+    pub reason: PanicReason,
+    /// The contract whose code panicked. `None` if the script panicked.
+    pub contract_id: Option<ContractId>,
+    /// The contract absent from inputs of the transaction, if it caused the panic.
+    pub missing_contract_id: Option<ContractId>,
+    /// The program counter of the instruction that trapped.
+    pub pc: Word,
+    /// The start of the code that panicked, `pc - is` is the offset of the instruction.
+    pub is: Word,
+    /// The raw instruction that trapped.
+    pub raw_instruction: RawInstruction,
+    /// The instruction that trapped. `None` if the raw instruction can't be decoded.
+    pub instruction: Option<Instruction>,
+}
+
+impl PanicDetails {
+    /// Decodes the `Panic` receipt. Returns `None` for other receipts.
+    pub fn from_receipt(receipt: &Receipt) -> Option<Self> {
+        match receipt {
+            Receipt::Panic {
+                id,
+                reason,
+                pc,
+                is,
+                contract_id,
+            } => Some(Self {
+                reason: *reason.reason(),
+                contract_id: (*id != ContractId::zeroed()).then_some(*id),
+                missing_contract_id: *contract_id,
+                pc: *pc,
+                is: *is,
+                raw_instruction: *reason.instruction(),
+                instruction: Instruction::try_from(*reason.instruction()).ok(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Decodes the first `Panic` receipt among `receipts` of the transaction.
+    pub fn from_receipts(receipts: &[Receipt]) -> Option<Self> {
+        receipts.iter().find_map(Self::from_receipt)
+    }
+}
+
+/// The key of the GraphQL error extension that contains the [`ErrorCode`].
+pub const ERROR_CODE_EXTENSION: &str = "code";
+
+/// The key of the GraphQL error extension that contains the machine-readable reason
+/// of the error. The reason refines the [`ErrorCode`], for example, the `TX_VALIDATION`
+/// code may have `gas_price_too_low` reason.
+pub const ERROR_REASON_EXTENSION: &str = "reason";
+
+/// The key of the GraphQL error extension that contains the height of the first block
+/// that can include the transaction rejected because of immature coins.
+pub const ERROR_VALID_AT_HEIGHT_EXTENSION: &str = "validAtHeight";
+
+/// The key of the GraphQL error extension that contains the DA height from which blocks
+/// can include the transaction rejected because of messages that are not relayed yet.
+pub const ERROR_VALID_AT_DA_HEIGHT_EXTENSION: &str = "validAtDaHeight";
+
+/// The stable machine-readable code of the error returned by the GraphQL API.
+///
+/// The code is attached to the extensions of each error produced by the node, so clients
+/// don't need to rely on the error message that may change with every release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// The requested entity doesn't exist.
+    NotFound,
+    /// The transaction is rejected by the validation rules.
+    TxValidation,
+    /// The request contains invalid arguments.
+    InvalidInput,
+    /// The request is not supported by the node or by its configuration.
+    Unsupported,
+    /// The request failed because of the internal error of the node.
+    Internal,
+}
+
+impl ErrorCode {
+    /// All known error codes.
+    pub const ALL: [ErrorCode; 5] = [
+        ErrorCode::NotFound,
+        ErrorCode::TxValidation,
+        ErrorCode::InvalidInput,
+        ErrorCode::Unsupported,
+        ErrorCode::Internal,
+    ];
+
+    /// Returns the string representation of the code used in the GraphQL extensions.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::TxValidation => "TX_VALIDATION",
+            ErrorCode::InvalidInput => "INVALID_INPUT",
+            ErrorCode::Unsupported => "UNSUPPORTED",
+            ErrorCode::Internal => "INTERNAL",
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for ErrorCode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ErrorCode::ALL
+            .into_iter()
+            .find(|code| code.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown error code `{s}`"))
+    }
+}
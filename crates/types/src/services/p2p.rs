@@ -1,10 +1,16 @@
 //! Contains types related to P2P data
 
 use crate::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
 };
-use std::fmt::Debug;
+use std::{
+    collections::BTreeSet,
+    fmt::Debug,
+};
 /// Contains types and logic for Peer Reputation
 pub mod peer_reputation;
 
@@ -92,6 +98,45 @@ pub struct BlockHeightHeartbeatData {
     pub block_height: BlockHeight,
 }
 
+/// The compact summary of the transaction pool of the node.
+/// It contains the first 8 bytes of ids of pending transactions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolSketch {
+    /// Sorted short ids of transactions.
+    pub short_ids: Vec<u64>,
+}
+
+impl PoolSketch {
+    /// Creates the sketch from the ids of transactions, `limit` is the maximum number of ids.
+    pub fn new<'a>(ids: impl IntoIterator<Item = &'a TxId>, limit: usize) -> Self {
+        let short_ids: BTreeSet<_> = ids
+            .into_iter()
+            .map(|id| {
+                let mut short_id = [0u8; 8];
+                short_id.copy_from_slice(&id.as_ref()[..8]);
+                u64::from_be_bytes(short_id)
+            })
+            .collect();
+        Self {
+            short_ids: short_ids.into_iter().take(limit).collect(),
+        }
+    }
+
+    /// Returns the share of transactions that are present only in one of sketches,
+    /// `0.0` means the same content of pools, `1.0` means no common transactions.
+    pub fn divergence(&self, other: &Self) -> f64 {
+        let ours: BTreeSet<_> = self.short_ids.iter().collect();
+        let theirs: BTreeSet<_> = other.short_ids.iter().collect();
+        let union = ours.union(&theirs).count();
+        if union == 0 {
+            return 0.0
+        }
+        let common = ours.intersection(&theirs).count();
+        (union - common) as f64 / union as f64
+    }
+}
+
 /// Opaque peer identifier.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
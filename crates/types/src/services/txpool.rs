@@ -19,6 +19,7 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
         ContractId,
         Nonce,
     },
@@ -26,8 +27,12 @@ use crate::{
         checked_transaction::Checked,
         ProgramState,
     },
+    services::executor::Error as ExecutorError,
+};
+use std::{
+    fmt,
+    sync::Arc,
 };
-use std::sync::Arc;
 use tai64::Tai64;
 
 /// The alias for transaction pool result.
@@ -232,6 +237,15 @@ pub enum Error {
     NotInsertedInputUtxoIdNotExisting(UtxoId),
     #[error("Transaction is not inserted. UTXO is spent: {0:#x}")]
     NotInsertedInputUtxoIdSpent(UtxoId),
+    #[error(
+        "Transaction is not inserted. UTXO {utxo_id:#x} is not matured, it is spendable \
+        since the block {matures_at}"
+    )]
+    NotInsertedInputUtxoIdNotMatured {
+        utxo_id: UtxoId,
+        /// The height of the first block that can spend the coin.
+        matures_at: BlockHeight,
+    },
     #[error("Transaction is not inserted. Message is spent: {0:#x}")]
     NotInsertedInputMessageSpent(Nonce),
     #[error("Transaction is not inserted. Message id {0:#x} does not match any received message from the DA layer.")]
@@ -265,7 +279,74 @@ pub enum Error {
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
     SqueezedOut(String),
+    #[error("Transaction is removed because the block producer skipped it: {0}")]
+    SkippedTransaction(SkipReason),
     // TODO: We need it for now until channels are removed from TxPool.
     #[error("Got some unexpected error: {0}")]
     Other(String),
 }
+
+/// The error of the execution that made the block producer skip the transaction.
+/// The executor error isn't comparable, so reasons are compared by their messages.
+#[derive(Debug, Clone)]
+pub struct SkipReason(pub Arc<ExecutorError>);
+
+impl PartialEq for SkipReason {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for SkipReason {}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<ExecutorError> for SkipReason {
+    fn from(error: ExecutorError) -> Self {
+        Self(Arc::new(error))
+    }
+}
+
+impl Error {
+    /// The stable machine-readable reason of the error. It doesn't change between
+    /// releases, so it can be used by clients instead of the error message.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Error::NoMetadata => "no_metadata",
+            Error::NotSupportedTransactionType => "not_supported_transaction_type",
+            Error::NotInsertedTxKnown => "tx_known",
+            Error::NotInsertedLimitHit => "limit_hit",
+            Error::NotInsertedGasPriceTooLow => "gas_price_too_low",
+            Error::NotInsertedCollision(_, _) => "collision",
+            Error::NotInsertedCollisionContractId(_) => "collision_contract_id",
+            Error::NotInsertedCollisionMessageId(_, _) => "collision_message_id",
+            Error::NotInsertedOutputNotExisting(_) => "output_not_existing",
+            Error::NotInsertedInputContractNotExisting(_) => {
+                "input_contract_not_existing"
+            }
+            Error::NotInsertedContractIdAlreadyTaken(_) => "contract_id_already_taken",
+            Error::NotInsertedInputUtxoIdNotExisting(_) => "input_utxo_id_not_existing",
+            Error::NotInsertedInputUtxoIdSpent(_) => "input_utxo_id_spent",
+            Error::NotInsertedInputUtxoIdNotMatured { .. } => "input_utxo_id_not_matured",
+            Error::NotInsertedInputMessageSpent(_) => "input_message_spent",
+            Error::NotInsertedInputMessageUnknown(_) => "input_message_unknown",
+            Error::NotInsertedContractPricedLower(_) => "contract_priced_lower",
+            Error::NotInsertedIoWrongOwner => "io_wrong_owner",
+            Error::NotInsertedIoWrongAmount => "io_wrong_amount",
+            Error::NotInsertedIoWrongAssetId => "io_wrong_asset_id",
+            Error::NotInsertedIoMessageMismatch => "io_message_mismatch",
+            Error::NotInsertedIoContractOutput => "io_contract_output",
+            Error::NotInsertedMaxDepth => "max_depth",
+            Error::NotInsertedMaxGasLimit { .. } => "max_gas_limit",
+            Error::Removed => "removed",
+            Error::TTLReason => "ttl",
+            Error::SqueezedOut(_) => "squeezed_out",
+            Error::SkippedTransaction(_) => "skipped_transaction",
+            Error::Other(_) => "other",
+        }
+    }
+}
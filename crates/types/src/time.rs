@@ -0,0 +1,18 @@
+//! Conversions between `Tai64` timestamps of blocks and the unix time.
+//!
+//! The node labels the unix time with [`Tai64::from_unix`], i.e. with the fixed 10 seconds
+//! offset between TAI and UTC at the unix epoch. Leap seconds inserted since then are not
+//! counted, so a conversion with the table of leap seconds, or without the offset, shifts
+//! the time. The functions are the exact inverse of the labeling used by the node.
+
+use tai64::Tai64;
+
+/// Returns the unix time in seconds of the `time` labeled by the node.
+pub fn tai64_to_unix(time: Tai64) -> i64 {
+    time.to_unix()
+}
+
+/// Returns the `Tai64` label the node uses for the unix time in seconds.
+pub fn unix_to_tai64(secs: i64) -> Tai64 {
+    Tai64::from_unix(secs)
+}
@@ -0,0 +1,28 @@
+//! Writes the corpus seeds for all fuzz targets into the `corpus` folder.
+//!
+//! `cargo run --example generate_corpus`
+
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+fn main() -> std::io::Result<()> {
+    let targets = [
+        ("p2p_gossipsub_decode", fuel_core_fuzz::gossipsub_seeds()),
+        ("p2p_request_decode", fuel_core_fuzz::request_seeds()),
+        ("p2p_response_decode", fuel_core_fuzz::response_seeds()),
+        ("graphql_scalars", fuel_core_fuzz::scalar_seeds()),
+    ];
+    let corpus = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("corpus");
+
+    for (target, seeds) in targets {
+        let dir = corpus.join(target);
+        fs::create_dir_all(&dir)?;
+        for (i, seed) in seeds.iter().enumerate() {
+            fs::write(dir.join(format!("seed-{i}")), seed)?;
+        }
+        println!("{target}: {} seeds", seeds.len());
+    }
+    Ok(())
+}
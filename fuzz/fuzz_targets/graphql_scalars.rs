@@ -0,0 +1,13 @@
+#![no_main]
+
+use fuel_core_fuzz::parse_scalar;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((selector, input)) = data.split_first() else {
+        return
+    };
+    if let Ok(input) = std::str::from_utf8(input) {
+        parse_scalar(*selector, input);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use fuel_core_fuzz::{
+    codec,
+    gossip_topic,
+};
+use fuel_core_p2p::codecs::GossipsubCodec;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((selector, payload)) = data.split_first() else {
+        return
+    };
+    let _ = codec().decode(payload, gossip_topic(*selector));
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use fuel_core_fuzz::codec;
+use fuel_core_p2p::codecs::NetworkCodec;
+use futures::io::Cursor;
+use libfuzzer_sys::fuzz_target;
+use libp2p::request_response::RequestResponseCodec;
+
+// The input is the raw content of the substream, including the length prefix.
+fuzz_target!(|data: &[u8]| {
+    let mut codec = codec();
+    let protocol = codec.get_req_res_protocol();
+    let mut socket = Cursor::new(data);
+    let _ = futures::executor::block_on(codec.read_request(&protocol, &mut socket));
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use fuel_core_fuzz::{
+    codec,
+    network_response,
+};
+use fuel_core_p2p::codecs::RequestResponseConverter;
+use libfuzzer_sys::fuzz_target;
+
+// Covers the deserialization of sealed blocks, sealed headers, transactions, pool
+// sketches and node records received from peers.
+fuzz_target!(|data: &[u8]| {
+    let Some((selector, payload)) = data.split_first() else {
+        return
+    };
+    let _ = codec().convert_to_response(&network_response(*selector, payload));
+});
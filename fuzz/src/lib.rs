@@ -0,0 +1,258 @@
+//! Helpers shared by the fuzz targets and the corpus generator.
+//!
+//! Every target treats the first byte of the input as a selector of the decoder
+//! (gossip topic, response kind, scalar type) and the rest of the input as the payload.
+
+use async_graphql::{
+    ScalarType,
+    Value,
+};
+use fuel_core::schema::scalars::{
+    Address,
+    AssetId,
+    BlockId,
+    Bytes32,
+    ContractId,
+    HexString,
+    MessageId,
+    Nonce,
+    Salt,
+    Signature,
+    Tai64Timestamp,
+    TransactionId,
+    TxPointer,
+    UtxoId,
+    U32,
+    U64,
+};
+use fuel_core_p2p::{
+    codecs::{
+        postcard::PostcardCodec,
+        GossipsubCodec,
+        NetworkCodec,
+        RequestResponseConverter,
+    },
+    config::MAX_RESPONSE_SIZE,
+    fuzzing::{
+        GossipTopicTag,
+        GossipsubBroadcastRequest,
+        NetworkResponse,
+        OutboundResponse,
+        RequestMessage,
+        SignedNodeRecord,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::ConsensusVote,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_tx::Transaction,
+    fuel_types::BlockHeight,
+    services::p2p::PoolSketch,
+};
+use futures::io::Cursor;
+use libp2p::{
+    identity::Keypair,
+    request_response::RequestResponseCodec,
+};
+use std::sync::Arc;
+
+/// Returns the codec configured the same way as the node does by default.
+pub fn codec() -> PostcardCodec {
+    PostcardCodec::new(MAX_RESPONSE_SIZE)
+}
+
+/// All gossip topics, indexed by the selector byte.
+pub const GOSSIP_TOPICS: [GossipTopicTag; 3] = [
+    GossipTopicTag::NewTx,
+    GossipTopicTag::NewBlock,
+    GossipTopicTag::ConsensusVote,
+];
+
+/// Returns the gossip topic for the selector byte.
+pub fn gossip_topic(selector: u8) -> GossipTopicTag {
+    GOSSIP_TOPICS[selector as usize % GOSSIP_TOPICS.len()]
+}
+
+/// Wraps the payload into the `NetworkResponse` chosen by the selector byte.
+pub fn network_response(selector: u8, payload: &[u8]) -> NetworkResponse {
+    let payload = Some(payload.to_vec());
+    match selector % 5 {
+        0 => NetworkResponse::Block(payload),
+        1 => NetworkResponse::Header(payload),
+        2 => NetworkResponse::Transactions(payload),
+        3 => NetworkResponse::PoolSketch(payload),
+        _ => NetworkResponse::NodeRecord(payload),
+    }
+}
+
+macro_rules! scalars {
+    ($($scalar:ty),* $(,)?) => {
+        /// The number of scalars covered by the `graphql_scalars` target.
+        pub const SCALARS_COUNT: u8 = [$(stringify!($scalar)),*].len() as u8;
+
+        /// Parses the `input` as the scalar chosen by the selector byte.
+        /// If parsing succeeds, the value must survive the round trip.
+        pub fn parse_scalar(selector: u8, input: &str) {
+            let scalars: &[fn(&str)] = &[$(round_trip::<$scalar>),*];
+            scalars[selector as usize % scalars.len()](input)
+        }
+    };
+}
+
+scalars!(
+    U64,
+    U32,
+    Tai64Timestamp,
+    HexString,
+    Bytes32,
+    Address,
+    BlockId,
+    AssetId,
+    ContractId,
+    Salt,
+    TransactionId,
+    MessageId,
+    Nonce,
+    Signature,
+    UtxoId,
+    TxPointer,
+);
+
+fn round_trip<T: ScalarType>(input: &str) {
+    if let Ok(scalar) = T::parse(Value::String(input.to_string())) {
+        let value = scalar.to_value();
+        let reparsed = T::parse(value.clone())
+            .unwrap_or_else(|_| panic!("Encoded scalar {value} is not parsable"));
+        assert_eq!(reparsed.to_value(), value);
+    }
+}
+
+/// Corpus seeds of the `p2p_gossipsub_decode` target.
+pub fn gossipsub_seeds() -> Vec<Vec<u8>> {
+    let codec = codec();
+    let requests = [
+        (
+            GossipTopicTag::NewTx,
+            GossipsubBroadcastRequest::NewTx(Arc::new(Transaction::default())),
+        ),
+        (
+            GossipTopicTag::NewBlock,
+            GossipsubBroadcastRequest::NewBlock(Arc::new(Block::default())),
+        ),
+        (
+            GossipTopicTag::ConsensusVote,
+            GossipsubBroadcastRequest::ConsensusVote(Arc::new(ConsensusVote::default())),
+        ),
+    ];
+
+    requests
+        .into_iter()
+        .map(|(topic, request)| {
+            let selector = GOSSIP_TOPICS
+                .iter()
+                .position(|t| *t == topic)
+                .expect("All topics are listed") as u8;
+            let payload = codec.encode(request).expect("Seed is encodable");
+            with_selector(selector, payload)
+        })
+        .collect()
+}
+
+/// Corpus seeds of the `p2p_request_decode` target.
+pub fn request_seeds() -> Vec<Vec<u8>> {
+    let mut codec = codec();
+    let protocol = codec.get_req_res_protocol();
+    let requests = [
+        RequestMessage::Block(BlockHeight::from(1u32)),
+        RequestMessage::SealedHeader(BlockHeight::from(1u32)),
+        RequestMessage::Transactions(Default::default()),
+        RequestMessage::PoolSketch,
+        RequestMessage::NodeRecord,
+    ];
+
+    requests
+        .into_iter()
+        .map(|request| {
+            let mut socket = Cursor::new(Vec::new());
+            futures::executor::block_on(codec.write_request(
+                &protocol,
+                &mut socket,
+                request,
+            ))
+            .expect("Seed is encodable");
+            socket.into_inner()
+        })
+        .collect()
+}
+
+/// Corpus seeds of the `p2p_response_decode` target.
+pub fn response_seeds() -> Vec<Vec<u8>> {
+    let codec = codec();
+    let responses = [
+        OutboundResponse::Block(Some(Arc::new(SealedBlock::default()))),
+        OutboundResponse::SealedHeader(Some(Arc::new(SealedBlockHeader::default()))),
+        OutboundResponse::Transactions(Some(Arc::new(vec![Transaction::default()]))),
+        OutboundResponse::PoolSketch(Some(Arc::new(PoolSketch::new(
+            &[Default::default()],
+            1,
+        )))),
+        OutboundResponse::NodeRecord(Some(Arc::new(node_record()))),
+    ];
+
+    responses
+        .into_iter()
+        .map(|response| {
+            let response = codec
+                .convert_to_network_response(&response)
+                .expect("Seed is encodable");
+            let (selector, payload) = match response {
+                NetworkResponse::Block(payload) => (0, payload),
+                NetworkResponse::Header(payload) => (1, payload),
+                NetworkResponse::Transactions(payload) => (2, payload),
+                NetworkResponse::PoolSketch(payload) => (3, payload),
+                NetworkResponse::NodeRecord(payload) => (4, payload),
+            };
+            with_selector(selector, payload.expect("Response is not empty"))
+        })
+        .collect()
+}
+
+/// The record of the random peer, the fuzzer mutates it to reach the verification.
+fn node_record() -> SignedNodeRecord {
+    let address = "/ip4/127.0.0.1/tcp/4001".parse().expect("Valid address");
+    SignedNodeRecord::new(&Keypair::generate_ed25519(), 1, u64::MAX, &[address])
+        .expect("Record is signable")
+}
+
+/// Corpus seeds of the `graphql_scalars` target.
+pub fn scalar_seeds() -> Vec<Vec<u8>> {
+    let inputs = [
+        "0",
+        "18446744073709551615",
+        "0x",
+        "0x00",
+        "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0x000000000000000000000000000000000000000000000000000000000000000000",
+        "0x00000001000a",
+    ];
+
+    (0..SCALARS_COUNT)
+        .flat_map(|selector| {
+            inputs
+                .iter()
+                .map(move |input| with_selector(selector, input.as_bytes().to_vec()))
+        })
+        .collect()
+}
+
+fn with_selector(selector: u8, payload: Vec<u8>) -> Vec<u8> {
+    let mut seed = Vec::with_capacity(payload.len() + 1);
+    seed.push(selector);
+    seed.extend(payload);
+    seed
+}
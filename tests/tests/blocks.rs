@@ -1,4 +1,5 @@
 use fuel_core::{
+    chain_config::default_consensus_dev_key,
     database::Database,
     schema::scalars::BlockId,
     service::{
@@ -7,7 +8,11 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    types::{
+        ErrorCode,
+        ResponseErrors,
+        TransactionStatus,
+    },
     FuelClient,
     PageDirection,
     PaginationRequest,
@@ -23,16 +28,32 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
-        consensus::Consensus,
+        consensus::{
+            poa::{
+                PoAConsensus,
+                PreConfirmation,
+            },
+            Consensus,
+        },
+        SealedBlock,
+    },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
     },
     fuel_tx::*,
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use rstest::rstest;
 use std::{
     ops::Deref,
@@ -156,6 +177,85 @@ async fn produce_block_manually() {
     assert_eq!(actual_pub_key, expected_pub_key);
 }
 
+#[tokio::test]
+async fn produced_block_records_executor_version() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    assert_eq!(client.block_executor_version(0).await.unwrap(), None);
+    assert_eq!(
+        client.block_executor_version(1).await.unwrap(),
+        Some(fuel_core::executor::EXECUTOR_VERSION)
+    );
+}
+
+#[tokio::test]
+async fn blocks_are_produced_on_dedicated_execution_runtime() {
+    use fuel_core::service::ServiceTrait;
+
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.execution_threads = Some(1);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(3, None).await.unwrap();
+
+    let block = client.block_by_height(3).await.unwrap();
+    assert!(block.is_some());
+    srv.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn produced_block_pre_confirms_transactions() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.block_production = Trigger::Never;
+    config.pre_confirmations = true;
+
+    let srv = FuelService::from_database(Database::default(), config.clone())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default();
+    client.submit(&tx).await.unwrap();
+    let mut pre_confirmations = client.subscribe_pre_confirmations().await.unwrap();
+    let produce = async {
+        // The subscription is established on the first poll of the stream
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.produce_blocks(1, None).await.unwrap()
+    };
+    let (pre_confirmation, _) = tokio::join!(pre_confirmations.next(), produce);
+
+    let pre_confirmation: PreConfirmation = pre_confirmation.unwrap().unwrap().into();
+    let expected_pub_key = config
+        .consensus_key
+        .unwrap()
+        .expose_secret()
+        .deref()
+        .public_key();
+    assert_eq!(
+        pre_confirmation.tx_ids,
+        vec![tx.id(&ConsensusParameters::DEFAULT)]
+    );
+    assert_eq!(pre_confirmation.height, 1u32.into());
+    assert_eq!(
+        pre_confirmation
+            .producer(config.chain_conf.transaction_parameters.chain_id)
+            .unwrap(),
+        Input::owner(&expected_pub_key)
+    );
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
@@ -167,10 +267,17 @@ async fn produce_block_negative() {
     let client = FuelClient::from(srv.bound_address);
 
     let new_height = client.produce_blocks(5, None).await;
+    let err = new_height.err().unwrap();
 
     assert_eq!(
         "Response errors; Manual Blocks must be enabled to use this endpoint",
-        new_height.err().unwrap().to_string()
+        err.to_string()
+    );
+    let errors = ResponseErrors::from_io_error(&err).expect("Response errors");
+    assert!(errors.has_code(ErrorCode::Unsupported));
+    assert_eq!(
+        errors.0[0].reason.as_deref(),
+        Some("manual_blocks_disabled")
     );
 
     let tx = Transaction::default();
@@ -231,6 +338,95 @@ async fn produce_block_custom_time() {
     assert_eq!(db.block_time(&5u32.into()).unwrap().0, start_timestamp + 40);
 }
 
+#[tokio::test]
+async fn block_header_reports_unix_time() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let start_timestamp = Tai64::from_unix(1_700_000_000).0;
+    client.produce_blocks(1, Some(start_timestamp)).await.unwrap();
+
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    assert_eq!(block.header.unix_time.0, 1_700_000_000);
+    assert_eq!(block.header.time.to_unix(), 1_700_000_000);
+}
+
+#[tokio::test]
+async fn externally_sealed_block_template_is_imported() {
+    let mut config = Config::local_node();
+    config.debug = true;
+    config.block_production = Trigger::Never;
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(100)
+        .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+
+    let template = client
+        .next_block_template(1_000_000, Some(Tai64::UNIX_EPOCH.0 + 100))
+        .await
+        .unwrap();
+    assert_eq!(template.header.height.0, 1);
+    // The coinbase and the script.
+    assert_eq!(template.transactions.len(), 2);
+    let block = template.block().unwrap();
+    let signature =
+        Signature::sign(&default_consensus_dev_key(), &block.id().into_message());
+    let sealed = SealedBlock {
+        entity: block,
+        consensus: Consensus::PoA(PoAConsensus::new(signature)),
+    };
+
+    let header = client.submit_block(&sealed).await.unwrap();
+
+    assert_eq!(header.id.to_string(), template.header.id.to_string());
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    assert_eq!(block.header.id.to_string(), template.header.id.to_string());
+    let status = client
+        .transaction_status(&tx.id(&ConsensusParameters::DEFAULT).to_string())
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn block_sealed_by_unknown_key_is_rejected() {
+    let mut config = Config::local_node();
+    config.debug = true;
+    config.block_production = Trigger::Never;
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let template = client.next_block_template(1_000_000, None).await.unwrap();
+    let block = template.block().unwrap();
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let signature = Signature::sign(&secret, &block.id().into_message());
+    let sealed = SealedBlock {
+        entity: block,
+        consensus: Consensus::PoA(PoAConsensus::new(signature)),
+    };
+
+    let result = client.submit_block(&sealed).await;
+
+    assert!(result.is_err());
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(chain_info.latest_block.header.height.0, 0);
+}
+
+#[tokio::test]
+async fn block_template_requires_debug_mode() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.next_block_template(1_000_000, None).await;
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn produce_block_bad_start_time() {
     let db = Database::default();
@@ -340,3 +536,52 @@ async fn block_connection_5(
         }
     };
 }
+
+#[tokio::test]
+async fn block_connection_rejects_stale_cursor() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+
+    let srv = FuelService::from_database(Default::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // The raw block height was the cursor before cursors became opaque.
+    let err = client
+        .blocks(PaginationRequest {
+            cursor: Some("1".to_string()),
+            results: 5,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .expect_err("Stale cursor was accepted");
+    assert!(err.to_string().contains("restart the pagination"));
+
+    // The cursor returned by the node is still accepted.
+    let page = client
+        .blocks(PaginationRequest {
+            cursor: None,
+            results: 2,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    let next_page = client
+        .blocks(PaginationRequest {
+            cursor: page.cursor,
+            results: 2,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        next_page
+            .results
+            .into_iter()
+            .map(|b| b.header.height.0)
+            .collect_vec(),
+        vec![2, 3]
+    );
+}
@@ -23,3 +23,36 @@ async fn chain_info() {
         chain_info.consensus_parameters.into()
     );
 }
+
+#[tokio::test]
+async fn deprecated_fields_can_be_disabled() {
+    let mut node_config = Config::local_node();
+    node_config.disable_deprecated_graphql_fields = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let schema_version = client.schema_version().await.unwrap();
+    assert!(!schema_version.deprecated_fields_enabled);
+    let base_chain_height = schema_version
+        .deprecations
+        .iter()
+        .find(|deprecated| deprecated.field == "baseChainHeight")
+        .expect("The field should be deprecated");
+    assert!(base_chain_height
+        .reason
+        .contains("latestBlock.header.height"));
+
+    // The client doesn't query deprecated fields
+    client.chain_info().await.unwrap();
+
+    let body = serde_json::json!({ "query": "{ chain { peerCount } }" });
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .unwrap();
+    let response = response.text().await.unwrap();
+    assert!(response.contains("is disabled on this node"), "{response}");
+}
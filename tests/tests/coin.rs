@@ -37,11 +37,11 @@ async fn coin() {
     assert!(coin.is_some());
 }
 
-// Backward fails, tracking in https://github.com/FuelLabs/fuel-core/issues/610
 #[rstest]
 #[tokio::test]
 async fn first_5_coins(
-    #[values(PageDirection::Forward)] pagination_direction: PageDirection,
+    #[values(PageDirection::Forward, PageDirection::Backward)]
+    pagination_direction: PageDirection,
 ) {
     let owner = Address::default();
 
@@ -185,3 +185,37 @@ async fn get_coins_forwards_backwards(
     assert!(!coins.results.is_empty());
     assert_eq!(coins.results.len(), 10);
 }
+
+#[tokio::test]
+async fn utxo_statistics_count_all_coins() {
+    let mut config = Config::local_node();
+    config.utxo_stats = Some(fuel_core::service::utxo_stats::Config {
+        interval: std::time::Duration::from_millis(100),
+    });
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let statistics = loop {
+        if let Some(statistics) = client.utxo_statistics().await.unwrap() {
+            break statistics
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    };
+
+    let count: u64 = statistics.assets.iter().map(|asset| asset.count.0).sum();
+    assert_eq!(statistics.count.0, count);
+    let count: u64 = statistics.histogram.iter().map(|bucket| bucket.count.0).sum();
+    assert_eq!(statistics.count.0, count);
+
+    let response = reqwest::get(format!("http://{}/utxo-stats.csv", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert!(response
+        .text()
+        .await
+        .unwrap()
+        .starts_with("height,kind,asset_id,count,amount\n"));
+}
@@ -80,12 +80,7 @@ async fn test_contract_balance(
 #[rstest]
 #[tokio::test]
 async fn test_5_contract_balances(
-    #[values(PageDirection::Forward)] direction: PageDirection,
-    // #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
-    // Rocksdb doesn't support reverse seeks using a prefix, we'd need to implement a custom
-    // comparator to support this usecase.
-    // > One common bug of using prefix iterating is to use prefix mode to iterate in reverse order. But it is not yet supported.
-    // https://github.com/facebook/rocksdb/wiki/Prefix-Seek#limitation
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
 ) {
     let mut test_builder = TestSetupBuilder::new(SEED);
     let (_, contract_id) = test_builder.setup_contract(
@@ -317,4 +312,19 @@ async fn can_get_message_proof() {
     assert_eq!(log[1].ra().unwrap(), 1);
     assert_eq!(log[1].rb().unwrap(), 1);
     assert_eq!(logd.data().unwrap(), db_data);
+
+    // The logs are found by the contract over the range of blocks.
+    let contract_logs = client
+        .contract_logs(format!("{id:#x}").as_str(), 0, 10)
+        .await
+        .unwrap();
+    assert_eq!(contract_logs.len(), 3);
+    assert!(contract_logs
+        .iter()
+        .all(|log| Bytes32::from(log.transaction_id.clone()) == transaction_id));
+    let other_logs = client
+        .contract_logs(format!("{:#x}", ContractId::zeroed()).as_str(), 0, 10)
+        .await
+        .unwrap();
+    assert!(other_logs.is_empty());
 }
@@ -0,0 +1,190 @@
+use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        ContractConfig,
+        StateConfig,
+    },
+    service::{
+        Config,
+        DbType,
+        ForkConfig,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn forked_config(remote: &FuelService, height: Option<u32>) -> Config {
+    let mut config = Config::local_node();
+    config.database_type = DbType::InMemory;
+    config.fork = Some(ForkConfig {
+        url: remote.bound_address.to_string(),
+        height: height.map(Into::into),
+    });
+    config
+}
+
+// The remote node runs in the same runtime, so it needs a separate worker
+// to serve requests while the forked node is blocked on fetching the state.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn forked_node_uses_state_of_remote_node() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let secret = SecretKey::random(&mut rng);
+    let utxo_id = UtxoId::new(rng.gen(), 0);
+    let coin = CoinConfig {
+        tx_id: Some(*utxo_id.tx_id()),
+        output_index: Some(utxo_id.output_index()),
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+        maturity: None,
+        owner: Input::owner(&secret.public_key()),
+        amount: 1000,
+        asset_id: AssetId::BASE,
+    };
+
+    let code: Vec<u8> = op::ret(RegId::ONE).to_bytes().into_iter().collect();
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(code.clone());
+    let contract_id =
+        contract.id(&salt, &contract.root(), &Contract::default_state_root());
+    let (slot, slot_value): (Bytes32, Bytes32) = (rng.gen(), rng.gen());
+    let asset_id: AssetId = rng.gen();
+    let contract = ContractConfig {
+        code: code.clone(),
+        salt,
+        state: Some(vec![(slot, slot_value)]),
+        balances: Some(vec![(asset_id, 100)]),
+        tx_id: None,
+        output_index: None,
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+    };
+
+    let mut remote_config = Config::local_node();
+    remote_config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![coin]),
+        contracts: Some(vec![contract]),
+        ..Default::default()
+    });
+    let remote = FuelService::new_node(remote_config).await.unwrap();
+    let remote_client = FuelClient::from(remote.bound_address);
+
+    let forked = FuelService::new_node(forked_config(&remote, None))
+        .await
+        .unwrap();
+    let client = FuelClient::from(forked.bound_address);
+
+    // the state of the remote node is visible on the forked node
+    let utxo = format!("{utxo_id:#x}");
+    let coin = client
+        .coin(&utxo)
+        .await
+        .unwrap()
+        .expect("The coin is forked");
+    assert_eq!(coin.amount.0, 1000);
+    let contract = format!("{contract_id:#x}");
+    let forked_contract = client
+        .contract(&contract)
+        .await
+        .unwrap()
+        .expect("The contract is forked");
+    assert_eq!(forked_contract.bytecode.0 .0, code);
+    let balance = client
+        .contract_balance(&contract, Some(&format!("{asset_id:#x}")))
+        .await
+        .unwrap();
+    assert_eq!(balance, 100);
+    let value = client
+        .contract_storage_slot(&contract, &format!("{slot:#x}"))
+        .await
+        .unwrap()
+        .expect("The storage slot is forked");
+    assert_eq!(Bytes32::from(value), slot_value);
+
+    // the forked node spends the coin of the remote node
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(10000)
+    .add_unsigned_coin_input(
+        secret,
+        utxo_id,
+        1000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    // but the state of the remote node is not modified
+    assert!(client.coin(&utxo).await.unwrap().is_none());
+    assert!(remote_client.coin(&utxo).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn fork_height_above_remote_height_is_rejected() {
+    let remote = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let result = FuelService::new_node(forked_config(&remote, Some(100))).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn fork_height_below_remote_height_is_rejected() {
+    let remote = FuelService::new_node(Config::local_node()).await.unwrap();
+    FuelClient::from(remote.bound_address)
+        .produce_blocks(1, None)
+        .await
+        .unwrap();
+
+    let result = FuelService::new_node(forked_config(&remote, Some(0))).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn fork_into_persistent_database_is_rejected() {
+    let remote = FuelService::new_node(Config::local_node()).await.unwrap();
+    let mut config = forked_config(&remote, None);
+    config.database_type = DbType::RocksDb;
+
+    let result = FuelService::new_node(config).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn forked_node_refuses_state_after_fork_height() {
+    let remote = FuelService::new_node(Config::local_node()).await.unwrap();
+    let forked = FuelService::new_node(forked_config(&remote, None))
+        .await
+        .unwrap();
+    FuelClient::from(remote.bound_address)
+        .produce_blocks(1, None)
+        .await
+        .unwrap();
+
+    let utxo_id = UtxoId::new(StdRng::seed_from_u64(2322).gen(), 0);
+    let result = FuelClient::from(forked.bound_address)
+        .coin(&format!("{utxo_id:#x}"))
+        .await;
+
+    assert!(result.is_err());
+}
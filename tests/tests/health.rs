@@ -1,21 +1,480 @@
 use fuel_core::{
     database::Database,
+    fuel_core_graphql_api::AdminApiToken,
     service::{
         Config,
         FuelService,
     },
+    services::{
+        RunnableService,
+        RunnableTask,
+        Service,
+        ServiceError,
+        ServiceRunner,
+        StateWatcher,
+    },
+};
+use fuel_core_client::client::{
+    documents::client_documents,
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::{
+    ConsensusParameters,
+    TransactionBuilder,
+    UniqueIdentifier,
 };
-use fuel_core_client::client::FuelClient;
+use reqwest::header::CONTENT_TYPE;
+use std::time::Duration;
+
+#[tokio::test]
+async fn api_is_served_on_additional_addresses() {
+    let mut config = Config::local_node();
+    config.additional_addrs = vec!["127.0.0.1:0".parse().unwrap()];
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+
+    let bound_addresses = &srv.shared.graph_ql.bound_addresses;
+    assert_eq!(bound_addresses.len(), 2);
+    assert_eq!(bound_addresses[0], srv.bound_address);
+    for address in bound_addresses {
+        let health = FuelClient::from(*address).health().await.unwrap();
+        assert!(health);
+    }
+}
+
+#[tokio::test]
+async fn status_reports_healthy_producer() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+
+    let response = reqwest::get(format!("http://{}/status", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let status: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(status["height"], 0);
+    assert_eq!(status["synced"], serde_json::Value::Null);
+    assert_eq!(status["healthy"], true);
+}
+
+async fn json(response: reqwest::Response) -> serde_json::Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn admin_api_pauses_and_resumes_sub_services() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/services", srv.bound_address);
+    let client = reqwest::Client::new();
+
+    let response = client.get(&url).bearer_auth("secret").send().await.unwrap();
+    let services = json(response).await;
+    let names: Vec<_> = services
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|service| service["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"txpool"));
+    assert!(names.contains(&"graph_ql"));
+
+    let response = client
+        .post(format!("{url}/poa/pause"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let poa = json(response).await;
+    assert_eq!(poa["state"], "Paused");
+    assert_eq!(poa["healthy"], false);
+
+    let response = client
+        .post(format!("{url}/poa/resume"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    let poa = json(response).await;
+    assert_eq!(poa["state"], "Started");
+    assert_eq!(
+        poa["history"].as_array().unwrap().last().unwrap(),
+        &serde_json::json!(["Paused", "Started"])
+    );
+
+    let response = client
+        .post(format!("{url}/poa/resume"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+    // The block production can't be rebuilt, so it can't be restarted either
+    let response = client
+        .post(format!("{url}/poa/restart"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+    let response = client
+        .get(format!("{url}/unknown"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+struct PanickingTask;
+
+#[async_trait::async_trait]
+impl RunnableService for PanickingTask {
+    const NAME: &'static str = "Panicking";
+
+    type SharedData = ();
+    type Task = PanickingTask;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for PanickingTask {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        panic!("The task failed on purpose")
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn panic_of_sub_service_is_reported_as_its_error() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    assert!(srv.sub_services_errors().is_empty());
+
+    let service = ServiceRunner::new(PanickingTask);
+    let registered = srv.shared.registry.register("panicking", service).unwrap();
+    registered.start().unwrap();
+    registered.await_stop().await.unwrap();
+
+    let error = ServiceError::Panicked {
+        service: "Panicking",
+        message: "The task failed on purpose".to_string(),
+    };
+    assert_eq!(
+        srv.sub_services_errors(),
+        vec![("panicking", error.clone())]
+    );
+    let response = reqwest::Client::new()
+        .get(format!(
+            "http://{}/v1/services/panicking",
+            srv.bound_address
+        ))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    let panicking = json(response).await;
+    assert_eq!(panicking["state"], "StoppedWithError");
+    assert_eq!(panicking["error"], error.to_string());
+}
+
+#[tokio::test]
+async fn admin_api_requires_token() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    let result = FuelService::from_database(Database::default(), config.clone()).await;
+    assert!(result.is_err());
+
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/services", srv.bound_address);
+    let client = reqwest::Client::new();
+
+    let response = client.get(&url).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let response = client.get(&url).bearer_auth("secreT").send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let response = client.get(&url).bearer_auth("secret").send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn admin_api_reloads_min_gas_price() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(100)
+        .finalize_as_transaction();
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/config", srv.bound_address))
+        .bearer_auth("secret")
+        .header(CONTENT_TYPE, "application/json")
+        .body(r#"{"min_gas_price": 2}"#)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let result = client.submit(&tx).await;
+    assert!(result.is_err());
+    let node_info = client.node_info().await.unwrap();
+    assert_eq!(node_info.min_gas_price.0, 2);
+}
+
+#[tokio::test]
+async fn admin_api_rejects_config_update_with_unreloadable_fields_as_a_whole() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let reload = |body: &'static str| {
+        reqwest::Client::new()
+            .post(format!("http://{}/v1/config", srv.bound_address))
+            .bearer_auth("secret")
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+    };
+
+    // The limit of peers can't be raised above the initial one.
+    let response = reload(r#"{"min_gas_price": 2, "max_peers": 4294967295}"#)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+    // The local node produces blocks instantly, without the interval.
+    let response = reload(r#"{"min_gas_price": 2, "block_time_ms": 1000}"#)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+    let response = reload(r#"{"min_gas_price": 2, "max_tx": 1}"#)
+        .await
+        .unwrap();
+    assert!(response.status().is_client_error());
+
+    let node_info = client.node_info().await.unwrap();
+    assert_eq!(node_info.min_gas_price.0, 0);
+}
+
+#[tokio::test]
+async fn admin_api_switches_block_production_trigger() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let set_trigger = |body: &'static str| {
+        reqwest::Client::new()
+            .post(format!("http://{}/v1/poa/trigger", srv.bound_address))
+            .bearer_auth("secret")
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+    };
+
+    let response = set_trigger(
+        r#"{"mode": "hybrid", "min_block_time_ms": 300, "max_tx_idle_time_ms": 200,
+            "max_block_time_ms": 100}"#,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+
+    // The instant trigger of the local node doesn't produce empty blocks
+    let response = set_trigger(r#"{"mode": "interval", "block_time_ms": 100}"#)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let height: u32 = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height
+        .into();
+    assert!(height > 0);
+}
 
 #[tokio::test]
-async fn health() {
+async fn admin_api_pauses_and_resumes_block_production_with_token() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let url = format!("http://{}/v1/poa", srv.bound_address);
+    let http = reqwest::Client::new();
+
+    let response = http.post(format!("{url}/pause")).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let response = http
+        .post(format!("{url}/pause"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    // The instant trigger doesn't seal the block with the submitted transaction
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(100)
+        .finalize_as_transaction();
+    let tx_id = format!("{:#x}", tx.id(&ConsensusParameters::DEFAULT));
+    client.submit(&tx).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    let status = client.transaction_status(&tx_id).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+
+    let response = http
+        .post(format!("{url}/resume"))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let status = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.await_transaction_commit(&tx_id),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn admin_api_is_disabled_by_default() {
     let srv = FuelService::from_database(Database::default(), Config::local_node())
         .await
         .unwrap();
+
+    let response = reqwest::get(format!("http://{}/v1/services", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn admin_api_serves_slow_operations() {
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    config.slow_log = Some(fuel_core::service::slow_log::Config {
+        graphql_threshold: Duration::ZERO,
+        storage_threshold: Duration::ZERO,
+        ..Default::default()
+    });
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
     let client = FuelClient::from(srv.bound_address);
+    client.chain_info().await.unwrap();
+
+    let response = reqwest::Client::new()
+        .get(format!("http://{}/v1/slow-log", srv.bound_address))
+        .bearer_auth("secret")
+        .send()
+        .await
+        .unwrap();
+    let operations = json(response).await;
+    let operations = operations.as_array().unwrap();
+    assert!(operations
+        .iter()
+        .any(|operation| operation["kind"] == "storage"));
+    let request = operations
+        .iter()
+        .find(|operation| operation["kind"] == "graphql")
+        .unwrap();
+    assert!(request["operation"].as_str().unwrap().contains("chain"));
+    assert!(request["rows"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn allowlist_mode_executes_only_allowed_documents() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let write_document = |name: &str| {
+        let (_, document) = client_documents()
+            .into_iter()
+            .find(|(document, _)| *document == name)
+            .unwrap();
+        std::fs::write(dir.path().join(format!("{name}.graphql")), document).unwrap();
+    };
+    write_document("chain_info");
+
+    let mut config = Config::local_node();
+    config.admin_api = true;
+    config.admin_api_token = Some(AdminApiToken::new("secret".to_string()));
+    config.graphql_allowlist_dir = Some(dir.path().to_path_buf());
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.chain_info().await.unwrap();
+    let error = client.health().await.unwrap_err();
+    assert!(error.to_string().contains("allowlist"), "{error}");
+
+    // The binary submission doesn't execute documents, so the allowlist doesn't apply.
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(100)
+        .finalize_as_transaction();
+    let id = client.submit_binary(&tx).await.unwrap();
+    assert_eq!(id, tx.id(&ConsensusParameters::DEFAULT));
 
-    let health = client.health().await.unwrap();
-    assert!(health);
+    write_document("health");
+    let reload = reqwest::Client::new()
+        .post(format!("http://{}/v1/graphql/allowlist", srv.bound_address));
+    let response = reload.try_clone().unwrap().send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let response = reload.bearer_auth("secret").send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(json(response).await["documents"], 2);
+    assert!(client.health().await.unwrap());
 }
 
 #[cfg(feature = "default")]
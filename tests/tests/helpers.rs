@@ -12,6 +12,12 @@ use fuel_core::{
 };
 use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_crypto::SecretKey,
     fuel_tx::{
         field::Inputs,
         input::coin::{
@@ -20,7 +26,11 @@ use fuel_core_types::{
         },
         *,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        bytes::SerializableVec,
+        BlockHeight,
+    },
+    fuel_vm::Call,
 };
 use itertools::Itertools;
 use rand::{
@@ -30,6 +40,47 @@ use rand::{
 };
 use std::collections::HashMap;
 
+/// The environment variable with the URL of the external node, e.g. of the staging
+/// network. If it is set, tests that use the [`TestNode`] run against the external node
+/// instead of spawning the local one. Only the `smoke` tests use the [`TestNode`].
+pub const EXTERNAL_NODE_URL: &str = "FUEL_CORE_TESTS_NODE_URL";
+
+/// Returns the URL of the external node if the tests run against it.
+pub fn external_node_url() -> Option<String> {
+    std::env::var(EXTERNAL_NODE_URL)
+        .ok()
+        .filter(|url| !url.is_empty())
+}
+
+/// The node the test runs against.
+pub enum TestNode {
+    /// The node spawned by the test.
+    Local(FuelService),
+    /// The node of the external network, see [`EXTERNAL_NODE_URL`].
+    External(String),
+}
+
+impl TestNode {
+    /// Connects to the external node if [`EXTERNAL_NODE_URL`] is set, otherwise spawns
+    /// the local node with the `config`. Assertions of the test shouldn't depend on the
+    /// `config`, so they hold for the external node too.
+    pub async fn new(config: Config) -> Self {
+        match external_node_url() {
+            Some(url) => TestNode::External(url),
+            None => TestNode::Local(FuelService::new_node(config).await.unwrap()),
+        }
+    }
+
+    pub fn client(&self) -> FuelClient {
+        match self {
+            TestNode::Local(srv) => FuelClient::from(srv.bound_address),
+            TestNode::External(url) => {
+                FuelClient::new(url).expect("The URL of the external node is invalid")
+            }
+        }
+    }
+}
+
 /// Helper for wrapping a currently running node environment
 pub struct TestContext {
     pub srv: FuelService,
@@ -46,6 +97,12 @@ impl TestContext {
     }
 }
 
+/// The gas limit of transactions created by the `TestSetupBuilder`.
+pub const TEST_GAS_LIMIT: u64 = 100_000;
+/// The amount of the base asset in the coin that pays the fee of transactions created by
+/// the `TestSetupBuilder`. The rest is returned to the sender as the change.
+pub const TEST_FEE_COIN_AMOUNT: u64 = 1_000_000_000;
+
 /// Helper for configuring the genesis block in tests
 pub struct TestSetupBuilder {
     pub rng: StdRng,
@@ -54,6 +111,7 @@ pub struct TestSetupBuilder {
     pub min_gas_price: u64,
     pub starting_block: Option<BlockHeight>,
     pub utxo_validation: bool,
+    pub debug: bool,
 }
 
 impl TestSetupBuilder {
@@ -111,6 +169,7 @@ impl TestSetupBuilder {
                         asset_id,
                         utxo_id,
                         tx_pointer,
+                        maturity,
                         ..
                     })
                     | Input::CoinPredicate(CoinPredicate {
@@ -119,6 +178,7 @@ impl TestSetupBuilder {
                         asset_id,
                         utxo_id,
                         tx_pointer,
+                        maturity,
                         ..
                     }) = input
                     {
@@ -127,7 +187,7 @@ impl TestSetupBuilder {
                             output_index: Some(utxo_id.output_index()),
                             tx_pointer_block_height: Some(tx_pointer.block_height()),
                             tx_pointer_tx_idx: Some(tx_pointer.tx_index()),
-                            maturity: None,
+                            maturity: Some(*maturity),
                             owner: *owner,
                             amount: *amount,
                             asset_id: *asset_id,
@@ -141,6 +201,90 @@ impl TestSetupBuilder {
         self
     }
 
+    /// Creates the signed transfer of the `amount` of the `asset_id` to the `recipient`.
+    /// Coins spent by the transaction are added to the genesis. The key of the sender is
+    /// generated if the `secret` is not set.
+    pub fn signed_transfer(
+        &mut self,
+        secret: Option<SecretKey>,
+        recipient: Address,
+        amount: u64,
+        asset_id: AssetId,
+    ) -> Transaction {
+        let secret = secret.unwrap_or_else(|| SecretKey::random(&mut self.rng));
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder
+            .add_unsigned_coin_input(
+                secret,
+                self.rng.gen(),
+                amount,
+                asset_id,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::coin(recipient, amount, asset_id));
+        self.finalize_funded(builder, secret)
+    }
+
+    /// Creates the signed call of the contract without arguments. The contract should be
+    /// added to the genesis with [`Self::setup_contract`]. Coins spent by the transaction
+    /// are added to the genesis. The key of the sender is generated if the `secret` is
+    /// not set.
+    pub fn signed_contract_call(
+        &mut self,
+        secret: Option<SecretKey>,
+        contract_id: ContractId,
+    ) -> Transaction {
+        let secret = secret.unwrap_or_else(|| SecretKey::random(&mut self.rng));
+        let script = [
+            // The script data contains the contract id and two empty params.
+            op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+            // Call the contract and forward no coins.
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ]
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+        let script_data = Call::new(contract_id, 0, 0).to_bytes();
+        let mut builder = TransactionBuilder::script(script, script_data);
+        builder
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            ))
+            .add_output(Output::contract(0, Default::default(), Default::default()));
+        self.finalize_funded(builder, secret)
+    }
+
+    /// Pays the fee of the transaction with the new coin of the `secret`, signs the
+    /// transaction and adds its coins to the genesis.
+    fn finalize_funded(
+        &mut self,
+        mut builder: TransactionBuilder<Script>,
+        secret: SecretKey,
+    ) -> Transaction {
+        let owner = Input::owner(&secret.public_key());
+        let script = builder
+            .gas_limit(TEST_GAS_LIMIT)
+            .gas_price(self.min_gas_price)
+            .add_unsigned_coin_input(
+                secret,
+                self.rng.gen(),
+                TEST_FEE_COIN_AMOUNT,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::change(owner, 0, AssetId::BASE))
+            .finalize();
+        self.config_coin_inputs_from_transactions(&[&script]);
+        script.into()
+    }
+
     // setup chainspec and spin up a fuel-node
     pub async fn finalize(&mut self) -> TestContext {
         let chain_config = ChainConfig {
@@ -154,6 +298,7 @@ impl TestSetupBuilder {
         };
         let config = Config {
             utxo_validation: self.utxo_validation,
+            debug: self.debug,
             txpool: fuel_core_txpool::Config {
                 chain_config: chain_config.clone(),
                 min_gas_price: self.min_gas_price,
@@ -183,6 +328,7 @@ impl Default for TestSetupBuilder {
             min_gas_price: 0,
             starting_block: None,
             utxo_validation: true,
+            debug: false,
         }
     }
 }
@@ -8,13 +8,19 @@ mod coins;
 mod contract;
 mod dap;
 mod debugger;
+mod fork;
 mod health;
 mod helpers;
+#[cfg(feature = "p2p")]
+mod local_network;
+#[cfg(feature = "p2p")]
+mod malformed_blocks;
 mod messages;
 mod node_info;
 mod poa;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod smoke;
 mod snapshot;
 #[cfg(feature = "p2p")]
 mod sync;
@@ -0,0 +1,47 @@
+use fuel_core::test_harness::*;
+use std::time::Duration;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validators_sync_blocks_of_the_producer() {
+    let mut network = LocalNetwork::new(LocalNetworkConfig::default().with_validators(2))
+        .await
+        .unwrap();
+
+    let tx = network.transfer_tx().unwrap();
+    let tx_id = network.insert_tx(tx).unwrap();
+    let height = network.produce_blocks(2).await.unwrap();
+    assert_eq!(*height, 2);
+
+    network.await_synced(Duration::from_secs(20)).await.unwrap();
+    for node in network.nodes() {
+        assert_eq!(node.latest_height().unwrap(), height, "{}", node.name());
+        assert!(node.contains_tx(&tx_id).unwrap(), "{}", node.name());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn the_same_seed_produces_the_same_chain() {
+    async fn last_block_id(
+        seed: u64,
+    ) -> fuel_core_types::blockchain::primitives::BlockId {
+        let mut network = LocalNetwork::new(
+            LocalNetworkConfig::default()
+                .with_seed(seed)
+                .with_validators(0),
+        )
+        .await
+        .unwrap();
+        let tx = network.transfer_tx().unwrap();
+        network.insert_tx(tx).unwrap();
+        let height = network.produce_blocks(3).await.unwrap();
+        network
+            .producer()
+            .db
+            .get_block_id(&height)
+            .unwrap()
+            .unwrap()
+    }
+
+    assert_eq!(last_block_id(1).await, last_block_id(1).await);
+    assert_ne!(last_block_id(1).await, last_block_id(2).await);
+}
@@ -0,0 +1,329 @@
+//! Golden adversarial vectors of sealed blocks received from peers.
+//!
+//! Every vector in the `test-vectors/malformed-blocks` folder is a JSON file with the
+//! sealed block and the reason why the node rejects it. The block is served to the node
+//! by a test peer over the p2p network: the peer gossips it and serves it to the block
+//! synchronization. The node doesn't import gossiped blocks, so only the sync may import
+//! the block after its seal is verified and the importer executes it.
+
+use fuel_core::{
+    chain_config::default_consensus_dev_key,
+    database::Database,
+    p2p_test_helpers::make_test_peer,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_p2p::test_peer::{
+    Behavior,
+    GossipsubBroadcastRequest,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    blockchain::{
+        block::{
+            Block,
+            PartialFuelBlock,
+        },
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        SealedBlock,
+    },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+    },
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+/// The limit of the size of the p2p response of the node under test.
+const MAX_BLOCK_SIZE: usize = 64 * 1024;
+
+/// The time for the node to learn the height of the test peer.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The time for the node to import the block after it learned about it.
+const IMPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The peer gossips the block with this interval, because the node may not have joined
+/// the gossip mesh yet.
+const GOSSIP_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BlockVector {
+    description: String,
+    /// The reason why the node rejects the block, `None` if the block is valid.
+    expected_error: Option<String>,
+    block: SealedBlock,
+}
+
+fn vectors_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test-vectors/malformed-blocks")
+}
+
+fn load_vector(name: &str) -> BlockVector {
+    let json = std::fs::read_to_string(vectors_dir().join(format!("{name}.json")))
+        .unwrap_or_else(|e| panic!("Vector {name} should exist: {e}"));
+    serde_json::from_str(&json)
+        .unwrap_or_else(|e| panic!("Vector {name} is malformed: {e}"))
+}
+
+/// Loads all vectors sorted by the file name.
+fn load_vectors() -> Vec<(String, BlockVector)> {
+    let mut names = std::fs::read_dir(vectors_dir())
+        .expect("The folder with vectors should exist")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "json"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let vector = load_vector(&name);
+            (name, vector)
+        })
+        .collect()
+}
+
+/// Starts the node on top of the fresh genesis and the peer that gossips the `block` and
+/// serves it to the node. Returns whether the node imported the block.
+async fn is_imported_from_peer(block: SealedBlock, max_block_size: usize) -> bool {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.p2p.as_mut().unwrap().max_block_size = max_block_size;
+
+    let (mut peer, addresses) = make_test_peer(&config, Behavior::Honest).await;
+    config.p2p.as_mut().unwrap().bootstrap_nodes = addresses;
+    let height = *block.entity.header().height();
+    let gossiped = Arc::new(block.entity.clone());
+    peer.add_block(block);
+    let peer = tokio::spawn(async move {
+        let mut gossip = tokio::time::interval(GOSSIP_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = peer.next_event() => {}
+                _ = gossip.tick() => {
+                    // Fails until the node joins the gossip mesh.
+                    let _ = peer.publish(GossipsubBroadcastRequest::NewBlock(
+                        gossiped.clone(),
+                    ));
+                }
+            }
+        }
+    });
+
+    let db = Database::default();
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let network = srv.shared.network.clone().expect("The p2p is enabled");
+
+    // The node syncs the block once it learns the height of the peer.
+    tokio::time::timeout(CONNECT_TIMEOUT, async {
+        while network.highest_peer_height() != Some(height) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The node should learn the height of the peer");
+    let imported = tokio::time::timeout(IMPORT_TIMEOUT, async {
+        while db.get_sealed_block_by_height(&height).unwrap().is_none() {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .is_ok();
+
+    peer.abort();
+    srv.stop_and_await().await.unwrap();
+    imported
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn peer_blocks_are_validated_according_to_vectors() {
+    let vectors = load_vectors();
+    assert!(!vectors.is_empty());
+
+    // Each vector is served to its own node, so they are checked concurrently.
+    let results =
+        futures::future::join_all(vectors.iter().map(|(_, vector)| {
+            is_imported_from_peer(vector.block.clone(), MAX_BLOCK_SIZE)
+        }))
+        .await;
+
+    for ((name, vector), imported) in vectors.into_iter().zip(results) {
+        assert_eq!(
+            imported,
+            vector.expected_error.is_none(),
+            "Vector `{name}` is expected to fail with {:?}: {}",
+            vector.expected_error,
+            vector.description
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocks_above_max_block_size_are_not_synced() {
+    // The size of the block depends on the limit of the node and would make the
+    // golden file huge, so the block is created from the valid vector instead.
+    let block = load_vector("valid").block.entity;
+    let mut transactions = block.transactions().to_vec();
+    transactions.push(
+        TransactionBuilder::script(vec![], vec![0; MAX_BLOCK_SIZE])
+            .finalize_as_transaction(),
+    );
+    let oversize = seal(
+        with_transactions(&block, transactions),
+        &default_consensus_dev_key(),
+    );
+
+    assert!(!is_imported_from_peer(oversize.clone(), MAX_BLOCK_SIZE).await);
+    // The block itself is valid, so the node with a higher limit imports it.
+    assert!(is_imported_from_peer(oversize, 2 * MAX_BLOCK_SIZE).await);
+}
+
+/// The block time of the vectors.
+const BLOCK_TIME: u64 = Tai64::UNIX_EPOCH.0 + 100;
+
+fn seal(mut block: Block, secret: &SecretKey) -> SealedBlock {
+    block.header_mut().recalculate_metadata();
+    let signature = Signature::sign(secret, &block.id().into_message());
+    SealedBlock {
+        entity: block,
+        consensus: Consensus::PoA(PoAConsensus::new(signature)),
+    }
+}
+
+/// Re-creates the block with new transactions, keeping the rest of the header.
+fn with_transactions(block: &Block, transactions: Vec<Transaction>) -> Block {
+    let mut partial = PartialFuelBlock::from(block.clone());
+    partial.transactions = transactions;
+    partial.generate(&[])
+}
+
+/// Regenerates the vectors. They are golden files, so regenerate them only if the
+/// format of the block has changed:
+///
+/// `cargo test -p fuel-core-tests --features p2p --test integration_tests generate_malformed_block_vectors -- --ignored`
+#[tokio::test]
+#[ignore]
+async fn generate_malformed_block_vectors() {
+    let secret = default_consensus_dev_key();
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.block_production = Trigger::Never;
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.submit(&Transaction::default()).await.unwrap();
+    client.produce_blocks(1, Some(BLOCK_TIME)).await.unwrap();
+    let valid = db
+        .get_sealed_block_by_height(&1u32.into())
+        .unwrap()
+        .expect("The block is produced");
+    let block = valid.entity.clone();
+    let transactions = block.transactions().to_vec();
+    assert_eq!(transactions.len(), 2, "The coinbase and the script");
+
+    let mut vectors = vec![(
+        "valid",
+        "The block produced by the node, it is the base for all other vectors.",
+        None,
+        valid,
+    )];
+
+    let mut bad_root = block.clone();
+    bad_root.header_mut().consensus.prev_root = [0xFF; 32].into();
+    vectors.push((
+        "bad_prev_root",
+        "The previous root doesn't match the root of the genesis block.",
+        Some("failed_verification"),
+        seal(bad_root, &secret),
+    ));
+
+    let mut bad_transactions_root = block.clone();
+    bad_transactions_root
+        .header_mut()
+        .application
+        .generated
+        .transactions_root = [0xFF; 32].into();
+    vectors.push((
+        "bad_transactions_root",
+        "The transactions root doesn't match the transactions of the block.",
+        Some("invalid_block_id"),
+        seal(bad_transactions_root, &secret),
+    ));
+
+    let mut rng = StdRng::seed_from_u64(1);
+    vectors.push((
+        "bad_signature",
+        "The block is signed by the key that is not the PoA key.",
+        Some("invalid_seal"),
+        seal(block.clone(), &SecretKey::random(&mut rng)),
+    ));
+
+    let mut duplicated = transactions.clone();
+    duplicated.push(transactions[1].clone());
+    vectors.push((
+        "duplicate_transactions",
+        "The script transaction is included twice.",
+        Some("transaction_id_collision"),
+        seal(with_transactions(&block, duplicated), &secret),
+    ));
+
+    let max_gas_per_tx = srv
+        .shared
+        .config
+        .chain_conf
+        .transaction_parameters
+        .max_gas_per_tx;
+    let oversize = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(max_gas_per_tx + 1)
+        .finalize_as_transaction();
+    vectors.push((
+        "gas_limit_above_maximum",
+        "The gas limit of the script transaction exceeds the maximum per transaction.",
+        Some("invalid_transaction"),
+        seal(
+            with_transactions(&block, vec![transactions[0].clone(), oversize]),
+            &secret,
+        ),
+    ));
+
+    std::fs::create_dir_all(vectors_dir()).unwrap();
+    for (name, description, expected_error, block) in vectors {
+        let vector = BlockVector {
+            description: description.to_string(),
+            expected_error: expected_error.map(ToString::to_string),
+            block,
+        };
+        let json = serde_json::to_string_pretty(&vector).unwrap();
+        std::fs::write(vectors_dir().join(format!("{name}.json")), json + "\n").unwrap();
+    }
+}
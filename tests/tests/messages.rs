@@ -8,11 +8,14 @@ use fuel_core::{
         FuelService,
     },
 };
-use fuel_core_client::client::{
-    types::TransactionStatus,
-    FuelClient,
-    PageDirection,
-    PaginationRequest,
+use fuel_core_client::{
+    client::{
+        types::TransactionStatus,
+        FuelClient,
+        PageDirection,
+        PaginationRequest,
+    },
+    proofs,
 };
 use fuel_core_types::{
     fuel_asm::*,
@@ -158,9 +161,7 @@ async fn messages_by_owner_returns_messages_for_the_given_owner() {
 #[rstest]
 #[tokio::test]
 async fn messages_empty_results_for_owner_with_no_messages(
-    #[values(PageDirection::Forward)] direction: PageDirection,
-    //#[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
-    // reverse iteration with prefix not supported by rocksdb
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
     #[values(Address::new([16; 32]), Address::new([0; 32]))] owner: Address,
 ) {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -384,6 +385,13 @@ async fn can_get_message_proof() {
                 .unwrap()
                 .unwrap();
 
+            // The client helper performs all the checks below.
+            let commit_block_id = result.commit_block_header.id.clone().into();
+            assert_eq!(
+                proofs::verify_message_proof(&result, &commit_block_id),
+                Ok(message_id)
+            );
+
             // 1. Generate the message id (message fields)
             // Produce message id.
             let generated_message_id = compute_message_id(
@@ -469,6 +477,28 @@ async fn can_get_message_proof() {
                 blocks_count as u64,
             ));
         }
+
+        // The batch query returns the same proofs in the requested order.
+        let ids: Vec<_> = message_ids.iter().map(|id| id.to_string()).collect();
+        let ids: Vec<_> = ids.iter().map(String::as_str).collect();
+        let batch = client
+            .message_proofs(
+                transaction_id.to_string().as_str(),
+                &ids,
+                None,
+                Some(last_height),
+            )
+            .await
+            .unwrap();
+        assert_eq!(batch.len(), message_ids.len());
+        for (proof, message_id) in batch.into_iter().zip(message_ids) {
+            let proof = proof.expect("Every message has a proof");
+            let commit_block_id = proof.commit_block_header.id.clone().into();
+            assert_eq!(
+                proofs::verify_message_proof(&proof, &commit_block_id),
+                Ok(message_id)
+            );
+        }
     }
 }
 
@@ -83,3 +83,45 @@ async fn test_metrics_endpoint() {
         assert!(value >= 0.0);
     }
 }
+
+#[tokio::test]
+async fn metrics_endpoint_reports_services_lifecycle() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let resp = reqwest::get(format!("http://{}/metrics", srv.bound_address))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    srv.stop_and_await().await.unwrap();
+
+    let graphql = "service=\"GraphQL\"";
+    let uptime = metric_values(&resp, "service_uptime_seconds", &[graphql]);
+    assert!(!uptime.is_empty());
+    assert!(uptime.iter().all(|uptime| *uptime > 0.0));
+    let started = metric_values(
+        &resp,
+        "service_state_seconds_total",
+        &[graphql, "state=\"Started\""],
+    );
+    assert!(!started.is_empty());
+    assert!(started.iter().all(|started| *started > 0.0));
+}
+
+/// Returns values of the metric `name` with all `labels`.
+fn metric_values(metrics: &str, name: &str, labels: &[&str]) -> Vec<f64> {
+    metrics
+        .lines()
+        .filter(|line| line.starts_with(&format!("{name}{{")))
+        .filter(|line| labels.iter().all(|label| line.contains(label)))
+        .map(|line| {
+            line.rsplit(' ')
+                .next()
+                .unwrap()
+                .parse::<f64>()
+                .expect("The value of the metric is a number")
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use fuel_core::service::{
+    build_info,
     Config,
     FuelService,
 };
@@ -6,6 +7,7 @@ use fuel_core_client::client::{
     schema::node_info::NodeInfo,
     FuelClient,
 };
+use fuel_core_types::fuel_types::Bytes32;
 
 #[tokio::test]
 async fn node_info() {
@@ -28,3 +30,17 @@ async fn node_info() {
     assert_eq!(max_depth, node_config.txpool.max_depth.into());
     assert_eq!(max_tx, node_config.txpool.max_tx.into());
 }
+
+#[tokio::test]
+async fn build_info() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let info = client.build_info().await.unwrap();
+
+    assert_eq!(info.version, build_info::VERSION);
+    assert!(!info.rustc_version.is_empty());
+    let config_hash: Bytes32 = info.config_hash.into();
+    assert_eq!(config_hash, node_config.consensus_config_hash().unwrap());
+}
@@ -0,0 +1,34 @@
+//! Smoke tests of the node API that can run against an external node, e.g. a release
+//! candidate deployed to staging. Only the tests of this module support the external
+//! node from the [`EXTERNAL_NODE_URL`](crate::helpers::EXTERNAL_NODE_URL), other tests
+//! always spawn local nodes.
+
+use crate::helpers::TestNode;
+use fuel_core::service::Config;
+use fuel_core_client::client::schema::HealthStatus;
+
+#[tokio::test]
+async fn health() {
+    let node = TestNode::new(Config::local_node()).await;
+    let client = node.client();
+
+    let health = client.health().await.unwrap();
+    assert!(health);
+}
+
+#[tokio::test]
+async fn services_health_reports_every_subsystem() {
+    let node = TestNode::new(Config::local_node()).await;
+    let client = node.client();
+
+    let services = client.services_health().await.unwrap();
+
+    for name in ["txpool", "poa", "graph_ql"] {
+        let service = services
+            .iter()
+            .find(|service| service.name == name)
+            .expect("The service should be reported");
+        assert_eq!(service.status, HealthStatus::Healthy);
+        assert!(service.reason.is_none());
+    }
+}
@@ -0,0 +1,32 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    secrecy::Secret,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn poa_adaptive_rejects_inverted_bounds() {
+    let mut rng = StdRng::seed_from_u64(10);
+
+    let mut config = Config::local_node();
+    config.consensus_key = Some(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Adaptive {
+        min_block_time: Duration::new(5, 0),
+        max_block_time: Duration::new(1, 0),
+    };
+
+    let result = FuelService::from_database(Database::default(), config).await;
+    assert!(result.is_err());
+}
@@ -1,3 +1,4 @@
+mod adaptive;
 mod hybrid;
 mod instant;
 mod interval;
@@ -9,7 +9,11 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    schema::tx::TxStage,
+    types::{
+        PanicDetails,
+        TransactionStatus,
+    },
     FuelClient,
     PageDirection,
     PaginationRequest,
@@ -25,6 +29,7 @@ use fuel_core_types::{
     fuel_asm::*,
     fuel_tx,
     fuel_tx::*,
+    fuel_types::bytes::SerializableVec,
     services::executor::ExecutionBlock,
     tai64::Tai64,
 };
@@ -157,6 +162,82 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_decodes_panic() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Calls the contract that isn't in inputs of the transaction
+    let script = op::call(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::CGAS);
+    let tx = Transaction::script(
+        Default::default(),
+        1_000_000,
+        Default::default(),
+        script.to_bytes().to_vec(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    )
+    .into();
+
+    let receipts = client.dry_run(&tx).await.unwrap();
+    let panic = PanicDetails::from_receipts(&receipts).expect("The script panics");
+
+    assert_eq!(panic.reason, PanicReason::ContractNotInInputs);
+    assert_eq!(panic.contract_id, None);
+    assert!(panic.missing_contract_id.is_some());
+    assert_eq!(panic.pc, panic.is);
+    assert_eq!(
+        panic.instruction.map(|instruction| instruction.opcode()),
+        Some(Opcode::CALL)
+    );
+}
+
+#[tokio::test]
+async fn transaction_cost_matches_fee_formula() {
+    let mut config = Config::local_node();
+    config.txpool.min_gas_price = 2;
+    let params = config.chain_conf.transaction_parameters;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let gas_limit = 1_000;
+    let script = Transaction::script(
+        Default::default(),
+        gas_limit,
+        Default::default(),
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        vec![],
+        vec![],
+        vec![vec![1; 64].into()],
+    );
+    let expected_fee = TransactionFee::checked_from_values(
+        &params,
+        script.metered_bytes_size() as u64,
+        gas_limit,
+        2,
+    )
+    .unwrap();
+    let mut tx: Transaction = script.clone().into();
+
+    let cost = client.transaction_cost(&tx).await.unwrap();
+
+    assert_eq!(cost.serialized_size.0, tx.to_bytes().len() as u64);
+    assert_eq!(cost.metered_bytes.0, script.metered_bytes_size() as u64);
+    assert_eq!(cost.gas_price.0, 2);
+    assert_eq!(cost.base_fee.0, expected_fee.bytes());
+    assert_eq!(cost.max_fee.0, expected_fee.total());
+    let gas_limit_usage = cost
+        .limits
+        .iter()
+        .find(|limit| limit.parameter == "maxGasPerTx")
+        .unwrap();
+    assert_eq!(gas_limit_usage.value.0, gas_limit);
+    assert!(!gas_limit_usage.exceeded);
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -203,6 +284,115 @@ async fn submit() {
     );
 }
 
+#[tokio::test]
+async fn submit_binary() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The script is larger than the usual request, but still within the consensus limit.
+    let script: Vec<u8> = std::iter::repeat(op::noop())
+        .take(10_000)
+        .chain(std::iter::once(op::ret(RegId::ONE)))
+        .flat_map(|op| u32::from(op).to_be_bytes())
+        .collect();
+
+    let tx = Transaction::script(
+        Default::default(),
+        1_000_000,
+        Default::default(),
+        script,
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    )
+    .into();
+
+    let id = client.submit_binary(&tx).await.unwrap();
+    assert_eq!(id, tx.id(&ConsensusParameters::DEFAULT));
+
+    let status = client
+        .await_transaction_commit(&id.to_string())
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn submit_with_idempotency_key_returns_original_transaction() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default();
+    let other_tx = Transaction::script(
+        1,
+        1_000_000,
+        Default::default(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    )
+    .into();
+
+    let id = client
+        .submit_with_idempotency_key(&tx, "retry")
+        .await
+        .unwrap();
+    let retried_id = client
+        .submit_with_idempotency_key(&tx, "retry")
+        .await
+        .unwrap();
+    assert_eq!(id, retried_id);
+
+    let result = client.submit_with_idempotency_key(&other_tx, "retry").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn transaction_timeline_records_lifecycle_stages() {
+    let mut config = Config::local_node();
+    config.debug = true;
+    config.txpool.tx_timeline = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default();
+    let id = tx.id(&ConsensusParameters::DEFAULT);
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let stages: Vec<_> = client
+        .transaction_timeline(&id.to_string())
+        .await
+        .unwrap()
+        .expect("The timeline should be recorded")
+        .into_iter()
+        .map(|record| record.stage)
+        .filter(|stage| *stage != TxStage::Gossiped)
+        .collect();
+    assert_eq!(
+        stages,
+        vec![
+            TxStage::Received,
+            TxStage::Validated,
+            TxStage::Selected,
+            TxStage::Executed,
+            TxStage::Committed,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn transaction_timeline_requires_debug_mode() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client
+        .transaction_timeline(&Bytes32::zeroed().to_string())
+        .await;
+    assert!(result.is_err());
+}
+
 #[ignore]
 #[tokio::test]
 async fn transaction_status_submitted() {
@@ -437,9 +627,21 @@ async fn get_transactions_by_owner_forward_and_backward_iterations() {
     };
     let response = client
         .transactions_by_owner(bob.to_string().as_str(), all_transactions_backward)
-        .await;
-    // Backward request is not supported right now.
-    assert!(response.is_err());
+        .await
+        .unwrap();
+    let transactions_backward = response
+        .results
+        .into_iter()
+        .map(|tx| {
+            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+            tx.transaction
+        })
+        .collect_vec();
+    assert_eq!(transactions_backward.len(), 5);
+    assert_eq!(
+        transactions_backward,
+        transactions_forward.iter().rev().cloned().collect_vec()
+    );
 
     ///////////////// Iteration
 
@@ -607,6 +809,73 @@ async fn get_transactions_from_manual_blocks() {
     // transactions[7] coinbase_tx1
 }
 
+#[tokio::test]
+async fn get_transactions_by_block_height() {
+    let (executor, db) = get_executor_and_db();
+    let context = initialize_client(db).await;
+    let txs: Vec<Transaction> = (0..5).map(create_mock_tx).collect();
+    let block = PartialFuelBlock {
+        header: PartialBlockHeader {
+            consensus: ConsensusHeader {
+                height: 1u32.into(),
+                time: Tai64::now(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        transactions: txs.clone(),
+    };
+    executor
+        .execute_and_commit(ExecutionBlock::Production(block))
+        .unwrap();
+
+    // Query for first 3 without receipts: [coinbase_tx, 0, 1]
+    let page_request = PaginationRequest {
+        cursor: None,
+        results: 3,
+        direction: PageDirection::Forward,
+    };
+    let response = context
+        .client
+        .transactions_by_block_height(1, false, page_request)
+        .await
+        .unwrap();
+    assert_eq!(response.results.len(), 3);
+    assert!(response.results[0].transaction.is_mint());
+    assert_eq!(
+        response.results[1].transaction.id(&ConsensusParameters::DEFAULT),
+        txs[0].id(&ConsensusParameters::DEFAULT)
+    );
+    for tx in &response.results {
+        assert!(tx.receipts.is_none());
+        assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+    }
+
+    // Query the rest with receipts from the last given cursor [1]: [2, 3, 4]
+    let next_page_request = PaginationRequest {
+        cursor: response.cursor,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let response = context
+        .client
+        .transactions_by_block_height(1, true, next_page_request)
+        .await
+        .unwrap();
+    let transactions = response
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT))
+        .collect_vec();
+    let expected = txs[2..]
+        .iter()
+        .map(|tx| tx.id(&ConsensusParameters::DEFAULT))
+        .collect_vec();
+    assert_eq!(transactions, expected);
+    assert!(response.results.iter().all(|tx| tx.receipts.is_some()));
+    assert!(!response.has_next_page);
+}
+
 #[tokio::test]
 async fn get_owned_transactions() {
     let alice = Address::from([1; 32]);
@@ -5,7 +5,11 @@ use crate::helpers::{
     TestSetupBuilder,
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    types::{
+        ErrorCode,
+        ResponseErrors,
+        TransactionStatus,
+    },
     PageDirection,
     PaginationRequest,
 };
@@ -216,6 +220,107 @@ async fn dry_run_no_utxo_validation_override() {
     assert!(res.is_err());
 }
 
+fn tx_with_non_existent_inputs(rng: &mut StdRng) -> Transaction {
+    TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(10000)
+    .add_input(Input::coin_signed(
+        rng.gen(),
+        rng.gen(),
+        1000,
+        AssetId::default(),
+        Default::default(),
+        0,
+        Default::default(),
+    ))
+    .add_witness(Default::default())
+    .finalize_as_transaction()
+}
+
+// verify that the node in the debug mode accepts a transaction with unsigned
+// non-existent coin inputs if utxo_validation is disabled for the submission
+#[tokio::test]
+async fn submit_override_utxo_validation_in_debug_mode() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let tx = tx_with_non_existent_inputs(&mut rng);
+
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.debug = true;
+    let context = test_builder.finalize().await;
+    let client = &context.client;
+
+    // the node validates utxos by default
+    let res = client.submit(&tx).await;
+    assert!(res.is_err());
+
+    let tx_id = client.submit_opt(&tx, Some(false)).await.unwrap();
+    assert_eq!(Bytes32::from(tx_id), tx.id(&ConsensusParameters::DEFAULT));
+}
+
+// verify that the override of utxo_validation during submission is rejected outside of
+// the debug mode
+#[tokio::test]
+async fn submit_override_utxo_validation_requires_debug_mode() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let tx = tx_with_non_existent_inputs(&mut rng);
+
+    let context = TestSetupBuilder::new(2322).finalize().await;
+
+    let err = context
+        .client
+        .submit_opt(&tx, Some(false))
+        .await
+        .unwrap_err();
+    let errors = ResponseErrors::from_io_error(&err).expect("Response errors");
+    assert!(errors.has_code(ErrorCode::Unsupported));
+    assert_eq!(errors.0[0].reason.as_deref(), Some("debug_disabled"));
+}
+
+#[tokio::test]
+async fn immature_coin_is_rejected_with_the_height_it_matures_at() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(10000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+        10u32.into(),
+    )
+    .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+    .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let tx = tx.into();
+
+    let err = client.dry_run(&tx).await.unwrap_err();
+    let errors = ResponseErrors::from_io_error(&err).expect("Response errors");
+    assert!(errors.has_code(ErrorCode::TxValidation));
+    assert_eq!(errors.0[0].reason.as_deref(), Some("coin_not_matured"));
+    assert_eq!(errors.0[0].valid_at_height, Some(10));
+
+    let err = client.submit(&tx).await.unwrap_err();
+    let errors = ResponseErrors::from_io_error(&err).expect("Response errors");
+    assert!(errors.has_code(ErrorCode::TxValidation));
+    assert_eq!(
+        errors.0[0].reason.as_deref(),
+        Some("input_utxo_id_not_matured")
+    );
+    assert_eq!(errors.0[0].valid_at_height, Some(10));
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
 async fn concurrent_tx_submission_produces_expected_blocks() {
     const TEST_TXS: usize = 10;
@@ -314,3 +419,35 @@ async fn concurrent_tx_submission_produces_expected_blocks() {
     assert_eq!(total_blocks.results.len(), deduped.len());
     assert_eq!(included_txs, tx_ids);
 }
+
+#[tokio::test]
+async fn signed_transactions_from_test_setup_builder_are_committed() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.min_gas_price = 1;
+    let code = op::ret(RegId::ONE).to_bytes().to_vec();
+    let (_, contract_id) = test_builder.setup_contract(code, None, None, None);
+    let recipient = Address::new([1; 32]);
+    let asset_id = AssetId::new([2; 32]);
+    let transfer = test_builder.signed_transfer(None, recipient, 100, asset_id);
+    let call = test_builder.signed_contract_call(None, contract_id);
+
+    // spin up node
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    for tx in [transfer, call] {
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+    }
+    let balance = client
+        .balance(
+            format!("{recipient:#x}").as_str(),
+            Some(format!("{asset_id:#x}").as_str()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(balance, 100);
+}
@@ -1,2 +1,3 @@
 pub mod build;
 pub mod dump;
+pub mod dump_client_queries;
@@ -1,6 +1,13 @@
 use clap::Parser;
-use fuel_core::schema::build_schema;
+use fuel_core::schema::{
+    build_schema,
+    deprecation::{
+        DEPRECATED_FIELDS,
+        SCHEMA_VERSION,
+    },
+};
 use std::{
+    collections::BTreeSet,
     env,
     fs::{
         self,
@@ -12,6 +19,8 @@ use std::{
 
 // stored in the root of the workspace
 const SCHEMA_URL: &str = "../crates/client/assets/schema.sdl";
+// the snapshots of the schema at the release of each version
+const SCHEMA_VERSIONS_URL: &str = "../crates/client/assets/schema_versions";
 
 #[derive(Debug, Parser)]
 pub struct DumpCommand {}
@@ -27,20 +36,140 @@ pub fn dump_schema() -> Result<(), Box<dyn std::error::Error>> {
             f
         })
         .expect("Failed to fetch assets path");
+    let sdl = build_schema().finish().sdl();
 
-    File::create(assets)
+    File::create(&assets)
         .and_then(|mut f| {
-            f.write_all(build_schema().finish().sdl().as_bytes())?;
+            f.write_all(sdl.as_bytes())?;
             f.sync_all()
         })
         .expect("Failed to write SDL schema to temporary file");
 
+    // The snapshot of the version is written once, when the version is released, so
+    // later changes can be checked for compatibility with it.
+    let versions = assets
+        .parent()
+        .expect("Failed to read assets dir")
+        .join("schema_versions");
+    fs::create_dir_all(&versions).expect("Failed to create schema versions dir");
+    let snapshot = versions.join(format!("v{}.sdl", SCHEMA_VERSION));
+    if !snapshot.exists() {
+        fs::write(snapshot, sdl).expect("Failed to write SDL schema snapshot");
+    }
+
     Ok(())
 }
 
+/// Returns the `Type` of every type and the `Type.field` of every field and enum value
+/// defined in the `sdl`.
+fn schema_items(sdl: &str) -> BTreeSet<String> {
+    let mut items = BTreeSet::new();
+    let mut current_type: Option<String> = None;
+    let mut in_description = false;
+
+    for line in sdl.lines().map(str::trim) {
+        if line.starts_with("\"\"\"") {
+            let single_line = line.len() > 3 && line.ends_with("\"\"\"");
+            if !single_line {
+                in_description = !in_description;
+            }
+            continue
+        }
+        if in_description || line.is_empty() {
+            continue
+        }
+        if line == "}" {
+            current_type = None;
+            continue
+        }
+        if let Some(type_name) = &current_type {
+            let field = line
+                .split(|c| c == '(' || c == ':' || c == ' ')
+                .next()
+                .unwrap_or_default();
+            items.insert(format!("{}.{}", type_name, field));
+            continue
+        }
+        let mut words = line.split_whitespace();
+        if let (Some(_kind), Some(name)) = (words.next(), words.next()) {
+            if name == "{" {
+                // The `schema` definition only lists the root types.
+                current_type = Some("schema".to_string());
+                continue
+            }
+            items.insert(name.to_string());
+            if line.ends_with('{') {
+                current_type = Some(name.to_string());
+            }
+        }
+    }
+
+    items
+}
+
 /// ensures that latest schema is always committed
 #[test]
 fn is_latest_schema_committed() {
     let current_content = fs::read(SCHEMA_URL).unwrap();
     assert_eq!(current_content, build_schema().finish().sdl().as_bytes());
 }
+
+/// ensures that the current version is committed and nothing released is removed before
+/// the sunset of its deprecation
+#[test]
+fn is_schema_compatible_with_released_versions() {
+    let current = schema_items(&build_schema().finish().sdl());
+    let current_snapshot = format!("{}/v{}.sdl", SCHEMA_VERSIONS_URL, SCHEMA_VERSION);
+    assert!(
+        PathBuf::from(&current_snapshot).exists(),
+        "The snapshot of the schema version {} is not committed",
+        SCHEMA_VERSION
+    );
+
+    for entry in fs::read_dir(SCHEMA_VERSIONS_URL).unwrap() {
+        let path = entry.unwrap().path();
+        let released = schema_items(&fs::read_to_string(&path).unwrap());
+        for item in released.difference(&current) {
+            let sunset = DEPRECATED_FIELDS.iter().any(|deprecated| {
+                format!("{}.{}", deprecated.type_name, deprecated.field) == *item
+                    && deprecated.sunset <= SCHEMA_VERSION
+            });
+            assert!(
+                sunset,
+                "`{}` of {:?} was removed without the deprecation",
+                item, path
+            );
+        }
+    }
+}
+
+/// ensures that the deprecated fields of the schema are listed with their sunset
+#[test]
+fn are_deprecated_fields_listed() {
+    let sdl = build_schema().finish().sdl();
+    let deprecated_in_schema = sdl.matches("@deprecated").count();
+    assert_eq!(deprecated_in_schema, DEPRECATED_FIELDS.len());
+
+    for deprecated in DEPRECATED_FIELDS {
+        assert!(deprecated.since <= SCHEMA_VERSION);
+        assert!(deprecated.since < deprecated.sunset);
+        if deprecated.sunset > SCHEMA_VERSION {
+            let directive = format!("@deprecated(reason: \"{}\")", deprecated.reason);
+            let type_sdl = sdl
+                .split(&format!("type {} {{", deprecated.type_name))
+                .nth(1)
+                .and_then(|rest| rest.split("\n}").next())
+                .unwrap_or_else(|| panic!("Unknown type {}", deprecated.type_name));
+            assert!(
+                type_sdl.lines().any(|line| {
+                    let line = line.trim();
+                    let field = line.split(|c| c == '(' || c == ':').next();
+                    field == Some(deprecated.field) && line.ends_with(&directive)
+                }),
+                "`{}.{}` is not deprecated in the schema",
+                deprecated.type_name,
+                deprecated.field
+            );
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use clap::Parser;
+use fuel_core_client::client::documents::client_documents;
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+/// Writes documents of all operations of `fuel-core-client` into the allowlist
+/// directory of the node, one `<name>.graphql` file per document.
+#[derive(Debug, Parser)]
+pub struct DumpClientQueriesCommand {
+    /// The directory to write documents to.
+    #[clap(long, short)]
+    output: PathBuf,
+}
+
+pub fn dump_client_queries(
+    command: DumpClientQueriesCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(&command.output)?;
+    for (name, document) in client_documents() {
+        fs::write(command.output.join(format!("{name}.graphql")), document)?;
+    }
+    Ok(())
+}
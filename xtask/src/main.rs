@@ -10,6 +10,10 @@ use commands::{
         dump_schema,
         DumpCommand,
     },
+    dump_client_queries::{
+        dump_client_queries,
+        DumpClientQueriesCommand,
+    },
 };
 
 mod commands;
@@ -25,6 +29,7 @@ pub struct Opt {
 enum Xtask {
     Build(BuildCommand),
     Dump(DumpCommand),
+    DumpClientQueries(DumpClientQueriesCommand),
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -33,5 +38,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     match opt.command {
         Xtask::Build(_) => cargo_build_and_dump_schema(),
         Xtask::Dump(_) => dump_schema(),
+        Xtask::DumpClientQueries(command) => dump_client_queries(command),
     }
 }